@@ -0,0 +1,130 @@
+//! Benchmarks for the pure math modules. The app is a single binary crate, so the modules are
+//! compiled in directly rather than imported.
+//!
+//! Run with `cargo bench`.
+
+#[path = "../src/amortization.rs"]
+#[allow(dead_code)]
+mod amortization;
+#[path = "../src/annuity.rs"]
+#[allow(dead_code)]
+mod annuity;
+#[path = "../src/compounding.rs"]
+#[allow(dead_code)]
+mod compounding;
+#[path = "../src/fin_numeric.rs"]
+#[allow(dead_code)]
+mod fin_numeric;
+#[path = "../src/loan.rs"]
+#[allow(dead_code)]
+mod loan;
+#[path = "../src/npv.rs"]
+#[allow(dead_code)]
+mod npv;
+#[path = "../src/rate_shock.rs"]
+#[allow(dead_code)]
+mod rate_shock;
+#[path = "../src/simulation.rs"]
+#[allow(dead_code)]
+mod simulation;
+
+use amortization::{amortization_schedule, AmortizationInputs};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use npv::{compute_irr, CashFlow, CashFlowIndexing, CashFlowSeries};
+use simulation::{allocation_presets, block_bootstrap, simulate_portfolio_returns, Rng};
+
+fn compounding_benchmarks(c: &mut Criterion) {
+    c.bench_function("compute_fv", |b| {
+        b.iter(|| {
+            compounding::compute_fv(
+                black_box(10_000.0f64),
+                black_box(0.05),
+                black_box(12.0),
+                black_box(30.0),
+            )
+        })
+    });
+    c.bench_function("solve_rate", |b| {
+        b.iter(|| {
+            compounding::solve_rate(
+                black_box(10_000.0f64),
+                black_box(20_000.0),
+                black_box(12.0),
+                black_box(10.0),
+            )
+        })
+    });
+    c.bench_function("fv_escalating_contributions", |b| {
+        b.iter(|| {
+            annuity::fv_escalating_contributions(
+                black_box(500.0f64),
+                black_box(0.06),
+                black_box(0.03),
+                black_box(12.0),
+                black_box(30.0),
+            )
+        })
+    });
+}
+
+fn loan_benchmarks(c: &mut Criterion) {
+    let loan = AmortizationInputs {
+        principal: 300_000.0,
+        annual_rate: 0.065,
+        years: 30.0,
+        periods_per_year: 12.0,
+        extra_payment: 100.0,
+    };
+    // A 10-year project: the outlay up front, then growing inflows, with a dip in year 6
+    let project = CashFlowSeries {
+        indexing: CashFlowIndexing::Period,
+        flows: (0..=10)
+            .map(|period| CashFlow {
+                period,
+                date: String::new(),
+                amount: match period {
+                    0 => -100_000.0,
+                    6 => -5_000.0,
+                    _ => 12_000.0 + 1_000.0 * period as f64,
+                },
+            })
+            .collect(),
+    };
+
+    c.bench_function("amortization_schedule_30_years_monthly", |b| {
+        b.iter(|| amortization_schedule(black_box(&loan)))
+    });
+    c.bench_function("compute_irr_11_flows", |b| {
+        b.iter(|| compute_irr(black_box(&project)))
+    });
+}
+
+fn simulation_benchmarks(c: &mut Criterion) {
+    let allocation = allocation_presets().remove(2);
+    let history: Vec<f64> = (0..100)
+        .map(|i| (i as f64 * 0.37).sin() * 0.2 + 0.07)
+        .collect();
+
+    c.bench_function("monte_carlo_1000_paths_40_years", |b| {
+        b.iter(|| {
+            let mut rng = Rng::new(black_box(1));
+            simulate_portfolio_returns(&allocation, 40, 1_000, &mut rng)
+        })
+    });
+    c.bench_function("block_bootstrap_1000_paths_40_years", |b| {
+        b.iter(|| {
+            let mut rng = Rng::new(black_box(1));
+            (0..1_000)
+                .map(|_| block_bootstrap(&history, 5, 40, &mut rng))
+                .collect::<Vec<_>>()
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    compounding_benchmarks,
+    loan_benchmarks,
+    simulation_benchmarks
+);
+criterion_main!(benches);
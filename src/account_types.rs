@@ -0,0 +1,123 @@
+use crate::tax::{tax_table, FilingStatus};
+use serde::{Deserialize, Serialize};
+
+/// Tax treatment of a savings bucket
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccountType {
+    /// Brokerage account: contributions are after-tax, only the gains are taxed (as long-term gains)
+    Taxable,
+    /// Traditional 401(k)/IRA: contributions are pre-tax, every withdrawn dollar is ordinary income
+    Traditional,
+    /// Roth 401(k)/IRA: contributions are after-tax, qualified withdrawals are tax-free
+    Roth,
+}
+
+impl AccountType {
+    pub fn label(self) -> &'static str {
+        match self {
+            AccountType::Taxable => "Taxable",
+            AccountType::Traditional => "Traditional",
+            AccountType::Roth => "Roth",
+        }
+    }
+}
+
+/// How savings are split across account types, as shares that sum to 1.0
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AccountSplit {
+    pub taxable: f64,
+    pub traditional: f64,
+    pub roth: f64,
+}
+
+/// All traditional, the most heavily taxed split
+impl Default for AccountSplit {
+    fn default() -> Self {
+        AccountSplit {
+            taxable: 0.0,
+            traditional: 1.0,
+            roth: 0.0,
+        }
+    }
+}
+
+impl AccountSplit {
+    /// Rescales the shares so they sum to 1.0 (all-traditional if every share is zero)
+    pub fn normalized(&self) -> Self {
+        let total = self.taxable + self.traditional + self.roth;
+        if total <= 0.0 {
+            return AccountSplit::default();
+        }
+        AccountSplit {
+            taxable: self.taxable / total,
+            traditional: self.traditional / total,
+            roth: self.roth / total,
+        }
+    }
+}
+
+/// Annual retirement withdrawals by bucket
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccountWithdrawals {
+    pub taxable: f64,
+    /// Share of the taxable withdrawal that is gain rather than original cost basis
+    pub taxable_gain_share: f64,
+    pub traditional: f64,
+    pub roth: f64,
+}
+
+impl AccountWithdrawals {
+    /// Splits a total annual withdrawal according to `split`
+    pub fn from_split(total: f64, split: AccountSplit, taxable_gain_share: f64) -> Self {
+        let split = split.normalized();
+        AccountWithdrawals {
+            taxable: total * split.taxable,
+            taxable_gain_share,
+            traditional: total * split.traditional,
+            roth: total * split.roth,
+        }
+    }
+
+    pub fn gross(&self) -> f64 {
+        self.taxable + self.traditional + self.roth
+    }
+}
+
+/// After-tax view of a year's retirement withdrawals
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AfterTaxIncome {
+    pub gross: f64,
+    pub federal_tax: f64,
+    pub net: f64,
+    /// Federal tax as a share of gross withdrawals
+    pub effective_rate: f64,
+}
+
+/// Federal tax on a year's withdrawals: traditional withdrawals plus `other_ordinary_income`
+/// (pension, taxable Social Security) are ordinary income, taxable gains get capital-gains rates,
+/// and Roth withdrawals are tax-free.
+pub fn after_tax_income(
+    withdrawals: &AccountWithdrawals,
+    other_ordinary_income: f64,
+    status: FilingStatus,
+    year: i32,
+) -> AfterTaxIncome {
+    let table = tax_table(year, status);
+    let gains = withdrawals.taxable * withdrawals.taxable_gain_share.clamp(0.0, 1.0);
+    let tax_with_withdrawals =
+        table.total_tax(withdrawals.traditional + other_ordinary_income, gains);
+    let tax_without = table.total_tax(other_ordinary_income, 0.0);
+    let federal_tax = tax_with_withdrawals - tax_without;
+    let gross = withdrawals.gross();
+
+    AfterTaxIncome {
+        gross,
+        federal_tax,
+        net: gross - federal_tax,
+        effective_rate: if gross > 0.0 {
+            federal_tax / gross
+        } else {
+            0.0
+        },
+    }
+}
@@ -0,0 +1,119 @@
+use crate::simulation::{allocation_presets, Allocation};
+use dioxus::prelude::*;
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+const CELL_INPUT_STYLE: &str =
+    "width: 70px; padding: 2px 4px; border-radius: 4px; font-family: monospace; text-align: right;";
+
+fn asset_name(allocation: &Allocation, index: usize) -> &'static str {
+    allocation.assets.get(index).map_or("", |asset| asset.name)
+}
+
+/// Preset portfolios to start from, the weight of each asset class, and the correlation matrix
+/// between them. Only the cells above the diagonal are edited; the ones below mirror them.
+#[component]
+pub fn AllocationEditor(allocation: Signal<Allocation>) -> Element {
+    let presets = allocation_presets();
+    let current = allocation();
+    let total_weight: f64 = current.weights.iter().sum();
+
+    rsx! {
+        div { style: "display: flex; align-items: center; gap: 8px; margin-bottom: 15px;",
+            label { style: "margin-right: 2px; font-weight: bold; color: #333; min-width: 150px;",
+                "Presets:"
+            }
+            for (index , preset) in presets.iter().enumerate() {
+                button {
+                    key: "{preset.name}",
+                    style: "padding: 2px 8px; border-radius: 4px;",
+                    aria_pressed: "{preset.weights == current.weights && preset.correlations == current.correlations}",
+                    onclick: move |_| allocation.set(allocation_presets().swap_remove(index)),
+                    {preset.name}
+                }
+            }
+        }
+        table { style: "color: #333; border-collapse: collapse; font-size: 13px; margin-bottom: 5px;",
+            thead {
+                tr {
+                    th { scope: "col", style: CELL_STYLE, "Asset Class" }
+                    th { scope: "col", style: CELL_STYLE, "Weight (%)" }
+                    th { scope: "col", style: CELL_STYLE, "Mean Return" }
+                    th { scope: "col", style: CELL_STYLE, "Volatility" }
+                }
+            }
+            tbody {
+                for (i , asset) in current.assets.iter().enumerate() {
+                    tr { key: "{asset.name}",
+                        th { scope: "row", style: CELL_STYLE, {asset.name} }
+                        td { style: CELL_STYLE,
+                            input {
+                                r#type: "number",
+                                "aria-label": "{asset.name} weight (%)",
+                                style: CELL_INPUT_STYLE,
+                                min: "0",
+                                max: "100",
+                                step: "1",
+                                value: "{current.weights[i] * 100.0:.1}",
+                                onchange: move |event| {
+                                    if let Ok(percent) = event.value().parse::<f64>() {
+                                        if let Some(weight) = allocation.write().weights.get_mut(i) {
+                                            *weight = percent / 100.0;
+                                        }
+                                    }
+                                },
+                            }
+                        }
+                        td { style: CELL_STYLE, "{asset.mean * 100.0:.1}%" }
+                        td { style: CELL_STYLE, "{asset.volatility * 100.0:.1}%" }
+                    }
+                }
+            }
+        }
+        div {
+            style: if (total_weight - 1.0).abs() > 1e-6 { "margin-bottom: 15px; font-size: 12px; color: #ff0000;" } else { "margin-bottom: 15px; font-size: 12px; color: #333;" },
+            "Weights total {total_weight * 100.0:.1}%."
+        }
+        div { style: "color: #333; font-weight: bold; margin-bottom: 5px;", "Correlations:" }
+        table { style: "color: #333; border-collapse: collapse; font-size: 13px; margin-bottom: 15px;",
+            thead {
+                tr {
+                    td {}
+                    for asset in current.assets.iter() {
+                        th { key: "{asset.name}", scope: "col", style: CELL_STYLE, {asset.name} }
+                    }
+                }
+            }
+            tbody {
+                for (i , row) in current.correlations.iter().enumerate() {
+                    tr { key: "{i}",
+                        th { scope: "row", style: CELL_STYLE,
+                            {asset_name(&current, i)}
+                        }
+                        for (j , correlation) in row.iter().enumerate() {
+                            td { key: "{j}", style: CELL_STYLE,
+                                if j > i {
+                                    input {
+                                        r#type: "number",
+                                        "aria-label": "Correlation of {asset_name(&current, i)} and {asset_name(&current, j)}",
+                                        style: CELL_INPUT_STYLE,
+                                        min: "-1",
+                                        max: "1",
+                                        step: "0.05",
+                                        value: "{correlation:.2}",
+                                        onchange: move |event| {
+                                            if let Ok(value) = event.value().parse::<f64>() {
+                                                allocation.write().set_correlation(i, j, value);
+                                            }
+                                        },
+                                    }
+                                } else {
+                                    "{correlation:.2}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
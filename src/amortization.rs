@@ -0,0 +1,362 @@
+use crate::loan::loan_payment;
+use serde::{Deserialize, Serialize};
+
+/// Days in the year interest accrues over; each payment period is an equal share of it
+pub const DAYS_PER_YEAR: f64 = 365.0;
+
+/// Inputs to the amortization schedule calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AmortizationInputs {
+    /// Amount borrowed
+    pub principal: f64,
+    /// Annual interest rate as a decimal, e.g., 0.06 for 6%
+    pub annual_rate: f64,
+    pub years: f64,
+    /// Payments per year, e.g., 12 for monthly
+    pub periods_per_year: f64,
+    /// Extra principal paid with every payment
+    #[serde(default)]
+    pub extra_payment: f64,
+}
+
+/// One payment of the schedule
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AmortizationRow {
+    /// 1-based payment number
+    pub period: usize,
+    pub payment: f64,
+    pub interest: f64,
+    /// Part of the payment that pays down the loan
+    pub principal: f64,
+    /// Balance owed after this payment
+    pub balance: f64,
+    /// Days of interest this payment covers
+    pub accrual_days: f64,
+    /// Annual rate in effect for the period, as a decimal
+    pub annual_rate: f64,
+    /// Interest paid through this payment
+    pub cumulative_interest: f64,
+    /// Principal repaid through this payment
+    pub cumulative_principal: f64,
+    /// Payments still due after this one
+    pub remaining_periods: usize,
+}
+
+impl AmortizationRow {
+    /// Everything paid through this payment
+    pub fn cumulative_payments(&self) -> f64 {
+        self.cumulative_interest + self.cumulative_principal
+    }
+
+    /// Years of the term left after this payment
+    pub fn remaining_years(&self, periods_per_year: f64) -> f64 {
+        self.remaining_periods as f64 / periods_per_year
+    }
+}
+
+/// A year's payments added up
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AmortizationYear {
+    /// 1-based year of the loan
+    pub year: usize,
+    /// First and last payment numbers in the year
+    pub first_period: usize,
+    pub last_period: usize,
+    pub payment: f64,
+    pub interest: f64,
+    pub principal: f64,
+    /// Balance owed after the year's last payment
+    pub balance: f64,
+}
+
+/// Every payment of a loan, with totals
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmortizationSchedule {
+    /// The level payment, before any extra; the last payment may differ by the rounding of
+    /// earlier ones
+    pub payment: f64,
+    pub rows: Vec<AmortizationRow>,
+    pub total_payments: f64,
+    pub total_interest: f64,
+}
+
+// Computes the payment schedule of a level-payment (amortizing) loan.
+///
+/// # Parameters:
+/// - `inputs`: Amount borrowed (P), annual rate (r), term in years (t), payments per year (n)
+///
+/// # Formula:
+/// PMT = P * (r/n) / (1 - (1 + r/n)^-(nt))
+/// interest_k = balance_(k-1) * r/n
+/// principal_k = PMT + extra - interest_k
+/// balance_k = balance_(k-1) - principal_k
+///
+/// # Returns:
+/// One row per payment, rounded to the cent, with running totals and the term left. The last
+/// payment pays off whatever balance the rounding left, so the loan ends at exactly zero; with
+/// extra payments that comes before the end of the term. An empty schedule if the term has no
+/// payments.
+pub fn amortization_schedule(inputs: &AmortizationInputs) -> AmortizationSchedule {
+    let round_cents = |value: f64| (value * 100.0).round() / 100.0;
+    let n_periods = (inputs.years * inputs.periods_per_year).round() as usize;
+    let periodic_rate = inputs.annual_rate / inputs.periods_per_year;
+    let payment = if n_periods == 0 {
+        0.0
+    } else {
+        round_cents(loan_payment(
+            inputs.principal,
+            inputs.annual_rate,
+            n_periods as f64 / inputs.periods_per_year,
+            inputs.periods_per_year,
+        ))
+    };
+
+    let accrual_days = DAYS_PER_YEAR / inputs.periods_per_year;
+
+    let mut balance = inputs.principal;
+    let mut cumulative_interest = 0.0;
+    let mut cumulative_principal = 0.0;
+    let mut rows: Vec<AmortizationRow> = Vec::new();
+    for period in 1..=n_periods {
+        if period > 1 && balance <= 0.0 {
+            break;
+        }
+        let interest = round_cents(balance * periodic_rate);
+        let principal = if period == n_periods {
+            balance
+        } else {
+            (payment + inputs.extra_payment - interest).min(balance)
+        };
+        balance = round_cents(balance - principal);
+        let principal = round_cents(principal);
+        cumulative_interest = round_cents(cumulative_interest + interest);
+        cumulative_principal = round_cents(cumulative_principal + principal);
+        rows.push(AmortizationRow {
+            period,
+            payment: round_cents(interest + principal),
+            interest,
+            principal,
+            balance,
+            accrual_days,
+            annual_rate: inputs.annual_rate,
+            cumulative_interest,
+            cumulative_principal,
+            remaining_periods: 0,
+        });
+    }
+    let n_paid = rows.len();
+    for row in rows.iter_mut() {
+        row.remaining_periods = n_paid - row.period;
+    }
+
+    AmortizationSchedule {
+        payment,
+        total_payments: round_cents(rows.iter().map(|row| row.payment).sum()),
+        total_interest: round_cents(rows.iter().map(|row| row.interest).sum()),
+        rows,
+    }
+}
+
+impl AmortizationSchedule {
+    /// The schedule's payments grouped by loan year, with each year's subtotals
+    pub fn by_year(&self, periods_per_year: f64) -> Vec<AmortizationYear> {
+        let round_cents = |value: f64| (value * 100.0).round() / 100.0;
+        let mut years: Vec<AmortizationYear> = Vec::new();
+        for row in self.rows.iter() {
+            let year = ((row.period - 1) as f64 / periods_per_year).floor() as usize + 1;
+            match years.last_mut() {
+                Some(last) if last.year == year => {
+                    last.last_period = row.period;
+                    last.payment = round_cents(last.payment + row.payment);
+                    last.interest = round_cents(last.interest + row.interest);
+                    last.principal = round_cents(last.principal + row.principal);
+                    last.balance = row.balance;
+                }
+                _ => years.push(AmortizationYear {
+                    year,
+                    first_period: row.period,
+                    last_period: row.period,
+                    payment: row.payment,
+                    interest: row.interest,
+                    principal: row.principal,
+                    balance: row.balance,
+                }),
+            }
+        }
+        years
+    }
+}
+
+// Solves for the extra principal to add to every payment so a loan is paid off within a target
+// time, by searching the schedule itself so the answer accounts for rounding to the cent.
+///
+/// # Parameters:
+/// - `inputs`: The loan; its `extra_payment` is ignored
+/// - `payoff_years`: Years from the first payment's period until the loan should be paid off
+///
+/// # Formula:
+/// Find the smallest extra, in cents, whose schedule has at most round(payoff_years * n)
+/// payments. More extra never lengthens the schedule, so bisection converges on it.
+///
+/// # Returns:
+/// The extra payment, or zero if the loan's own term already meets the target. An error if the
+/// target has no payments in it.
+pub fn payoff_extra_payment(inputs: &AmortizationInputs, payoff_years: f64) -> Result<f64, String> {
+    if payoff_years.is_nan() || payoff_years <= 0.0 {
+        return Err("Enter a payoff time greater than zero.".to_string());
+    }
+    let target_periods = (payoff_years * inputs.periods_per_year).round() as usize;
+    if target_periods == 0 {
+        return Err("The payoff time must include at least one payment.".to_string());
+    }
+    let n_payments = |extra_cents: u64| {
+        amortization_schedule(&AmortizationInputs {
+            extra_payment: extra_cents as f64 / 100.0,
+            ..*inputs
+        })
+        .rows
+        .len()
+    };
+    if n_payments(0) <= target_periods {
+        return Ok(0.0);
+    }
+
+    // Paying the whole principal as extra clears the loan with the first payment
+    let (mut low, mut high) = (0_u64, (inputs.principal.max(0.0) * 100.0).ceil() as u64);
+    while high - low > 1 {
+        let middle = low + (high - low) / 2;
+        if n_payments(middle) <= target_periods {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+    Ok(high as f64 / 100.0)
+}
+
+/// Most a servicer may hold beyond the year's bills, in months of disbursements, under the
+/// Real Estate Settlement Procedures Act
+pub const ESCROW_CUSHION_MONTHS: f64 = 2.0;
+
+/// Smallest surplus a servicer must refund at the annual analysis; less is credited toward the
+/// next year's deposits
+pub const ESCROW_REFUND_THRESHOLD: f64 = 50.0;
+
+/// Property tax and homeowners insurance paid through escrow
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct EscrowInputs {
+    /// First year's property tax bill
+    pub annual_tax: f64,
+    /// First year's insurance premium
+    pub annual_insurance: f64,
+    /// Yearly growth of the insurance premium as a decimal, e.g., 0.03 for 3%
+    #[serde(alias = "annual_growth")]
+    pub insurance_growth: f64,
+    /// Yearly growth of the home's assessed value, and so of the tax bill, before any cap
+    #[serde(default)]
+    pub tax_growth: f64,
+    /// Most the assessed value may rise in a year, e.g., 0.02 under California's Proposition 13;
+    /// None where the home is reassessed at market value
+    #[serde(default)]
+    pub tax_growth_cap: Option<f64>,
+}
+
+impl EscrowInputs {
+    /// Yearly growth of the tax bill once any reassessment cap is applied
+    pub fn capped_tax_growth(&self) -> f64 {
+        match self.tax_growth_cap {
+            Some(cap) => self.tax_growth.min(cap),
+            None => self.tax_growth,
+        }
+    }
+
+    /// Tax and insurance bills due in a 1-based year of the loan, rounded to the cent
+    pub fn bills_in(&self, year: usize) -> f64 {
+        let grown = |bill: f64, growth: f64| bill * (1.0 + growth).powi(year as i32 - 1);
+        let bills = grown(self.annual_tax, self.capped_tax_growth())
+            + grown(self.annual_insurance, self.insurance_growth);
+        (bills * 100.0).round() / 100.0
+    }
+}
+
+/// One year of the escrow account, from the deposits set at the last analysis to the analysis
+/// that ends the year
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EscrowYear {
+    /// 1-based year of the loan
+    pub year: usize,
+    /// Escrow collected with each payment this year, including any shortage being repaid
+    pub deposit: f64,
+    /// Part of `deposit` repaying the shortage found at the last analysis
+    pub shortage_repayment: f64,
+    /// Tax and insurance bills paid from escrow this year
+    pub disbursements: f64,
+    /// Balance after the year's bills and any refund
+    pub balance: f64,
+    /// How far the balance fell below the cushion at this year's analysis
+    pub shortage: f64,
+    /// Surplus returned to the borrower at this year's analysis
+    pub refund: f64,
+}
+
+// Projects an escrow account year by year the way a servicer runs it: deposits are set from
+// the latest bills, and each annual analysis spreads any shortage over the next year.
+///
+/// # Parameters:
+/// - `escrow`: First year's tax and insurance, how fast each grows, and any cap on tax growth
+/// - `periods_per_year`: Deposits per year, one with each loan payment
+/// - `years`: Years to project, e.g., the years until the loan is paid off
+///
+/// # Formula:
+/// bills_y = tax * (1 + min(g_tax, cap))^(y-1) + insurance * (1 + g_insurance)^(y-1)
+/// cushion_y = bills_y * ESCROW_CUSHION_MONTHS / 12, funded at closing for year 1
+/// deposit_(y+1) = (bills_y + cushion_y - balance_y) / n
+///
+/// The analysis only knows the bills just paid, so when they grow the next year's deposits
+/// fall short by the growth and the following year repays it.
+///
+/// # Returns:
+/// One row per year, rounded to the cent. Surpluses of `ESCROW_REFUND_THRESHOLD` or more are
+/// refunded, so the next year starts at the cushion; smaller ones lower the next deposits.
+pub fn escrow_analysis(
+    escrow: &EscrowInputs,
+    periods_per_year: f64,
+    years: usize,
+) -> Vec<EscrowYear> {
+    let round_cents = |value: f64| (value * 100.0).round() / 100.0;
+    let cushion_for = |bills: f64| round_cents(bills * ESCROW_CUSHION_MONTHS / 12.0);
+
+    let mut projected = escrow.bills_in(1);
+    let mut balance = cushion_for(projected);
+    let mut shortage_repayment = 0.0;
+    let mut rows: Vec<EscrowYear> = Vec::new();
+    for year in 1..=years {
+        let deposit =
+            round_cents((projected + cushion_for(projected) - balance) / periods_per_year).max(0.0);
+        let disbursements = escrow.bills_in(year);
+        balance = round_cents(balance + deposit * periods_per_year - disbursements);
+
+        // The analysis projects next year's bills from the ones just paid
+        projected = disbursements;
+        let cushion = cushion_for(projected);
+        let shortage = round_cents((cushion - balance).max(0.0));
+        let surplus = round_cents(balance - cushion);
+        let refund = if surplus >= ESCROW_REFUND_THRESHOLD {
+            surplus
+        } else {
+            0.0
+        };
+        balance = round_cents(balance - refund);
+        rows.push(EscrowYear {
+            year,
+            deposit,
+            shortage_repayment,
+            disbursements,
+            balance,
+            shortage,
+            refund,
+        });
+        shortage_repayment = round_cents(shortage / periods_per_year);
+    }
+    rows
+}
@@ -0,0 +1,592 @@
+use crate::amortization::{
+    amortization_schedule, escrow_analysis, payoff_extra_payment, AmortizationInputs,
+    AmortizationRow, EscrowInputs,
+};
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range, years_bucket};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::loan::loan_payment_shocks;
+use crate::numeric_input_component::NumericInput;
+use crate::rate_shock::DEFAULT_SHIFTS_BP;
+use crate::rate_shock_component::RateShockPanel;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Everything the user has entered, as autosaved between visits. The loan's fields stay at the
+/// top level so inputs saved before escrow was added still restore.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct AmortizationSession {
+    #[serde(flatten)]
+    loan: AmortizationInputs,
+    #[serde(default)]
+    escrow: EscrowInputs,
+}
+
+const DEFAULT_SESSION: AmortizationSession = AmortizationSession {
+    loan: AmortizationInputs {
+        principal: 250_000.0,
+        annual_rate: 0.06,
+        years: 30.0,
+        periods_per_year: 12.0,
+        extra_payment: 0.0,
+    },
+    escrow: EscrowInputs {
+        annual_tax: 0.0,
+        annual_insurance: 0.0,
+        insurance_growth: 0.0,
+        tax_growth: 0.0,
+        tax_growth_cap: None,
+    },
+};
+
+const FREQUENCY_OPTIONS: &[(f64, &str, &str)] = &[
+    (12.0, "monthly", "Monthly"),
+    (26.0, "biweekly", "Biweekly"),
+    (4.0, "quarterly", "Quarterly"),
+    (1.0, "annual", "Annual"),
+];
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+
+/// What one payment covers and where the loan stands after it, shown under a clicked row
+#[component]
+fn PeriodDetail(row: AmortizationRow, periods_per_year: f64) -> Element {
+    let periodic_rate = row.annual_rate / periods_per_year * 100.0;
+    let remaining_years = row.remaining_years(periods_per_year);
+
+    rsx! {
+        tr { style: "background: #fafafa;",
+            td { colspan: "5", style: "text-align: left; padding: 6px 8px 10px 24px;",
+                div {
+                    "Interest accrual: {row.accrual_days:.1} days at {row.annual_rate * 100.0:.3}% a year ({periodic_rate:.4}% for the period)"
+                }
+                div {
+                    "Paid to date: ${format_dollars(row.cumulative_payments())} (${format_dollars(row.cumulative_principal)} principal, ${format_dollars(row.cumulative_interest)} interest)"
+                }
+                if row.remaining_periods == 0 {
+                    div { "Remaining: none; this payment retires the loan" }
+                } else {
+                    div {
+                        "Remaining: {row.remaining_periods} payments ({remaining_years:.2} years) on a balance of ${format_dollars(row.balance)}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn AmortizationUI() -> Element {
+    let mut principal_signal = use_signal(|| 250_000.0 as f64);
+    let mut principal_input = use_signal(|| "250000".to_string());
+    let principal_input_valid = use_signal(|| true);
+    let mut rate_signal = use_signal(|| 6.0 as f64);
+    let mut rate_input = use_signal(|| "6.0".to_string());
+    let rate_input_valid = use_signal(|| true);
+    let mut years_signal = use_signal(|| 30.0 as f64);
+    let mut years_input = use_signal(|| "30".to_string());
+    let years_input_valid = use_signal(|| true);
+    let mut frequency_signal = use_signal(|| 12.0 as f64);
+    let mut extra_signal = use_signal(|| 0.0 as f64);
+    let mut extra_input = use_signal(|| "0".to_string());
+    let extra_input_valid = use_signal(|| true);
+    let mut payoff_mode = use_signal(|| false);
+    let mut payoff_years_signal = use_signal(|| 20.0 as f64);
+    let mut payoff_years_input = use_signal(|| "20".to_string());
+    let payoff_years_input_valid = use_signal(|| true);
+    let mut tax_signal = use_signal(|| 0.0 as f64);
+    let mut tax_input = use_signal(|| "0".to_string());
+    let tax_input_valid = use_signal(|| true);
+    let mut insurance_signal = use_signal(|| 0.0 as f64);
+    let mut insurance_input = use_signal(|| "0".to_string());
+    let insurance_input_valid = use_signal(|| true);
+    let mut tax_growth_signal = use_signal(|| 0.0 as f64);
+    let mut tax_growth_input = use_signal(|| "0".to_string());
+    let tax_growth_input_valid = use_signal(|| true);
+    let mut tax_capped = use_signal(|| false);
+    let mut tax_cap_signal = use_signal(|| 2.0 as f64);
+    let mut tax_cap_input = use_signal(|| "2".to_string());
+    let tax_cap_input_valid = use_signal(|| true);
+    let mut insurance_growth_signal = use_signal(|| 0.0 as f64);
+    let mut insurance_growth_input = use_signal(|| "0".to_string());
+    let insurance_growth_input_valid = use_signal(|| true);
+    let mut expanded_period = use_signal(|| None::<usize>);
+    let mut collapsed_years = use_signal(HashSet::<usize>::new);
+    let mut annual_view = use_signal(|| false);
+    let escrow_inputs = move || EscrowInputs {
+        annual_tax: tax_signal(),
+        annual_insurance: insurance_signal(),
+        insurance_growth: insurance_growth_signal() / 100.0,
+        tax_growth: tax_growth_signal() / 100.0,
+        tax_growth_cap: tax_capped().then(|| tax_cap_signal() / 100.0),
+    };
+    let start_fresh = use_autosave(
+        "amortization",
+        DEFAULT_SESSION,
+        move || AmortizationSession {
+            loan: AmortizationInputs {
+                principal: principal_signal(),
+                annual_rate: rate_signal() / 100.0,
+                years: years_signal(),
+                periods_per_year: frequency_signal(),
+                extra_payment: extra_signal(),
+            },
+            escrow: escrow_inputs(),
+        },
+        use_callback(move |session: AmortizationSession| {
+            let (inputs, escrow) = (session.loan, session.escrow);
+            principal_signal.set(inputs.principal);
+            principal_input.set(format!("{}", inputs.principal));
+            rate_signal.set(inputs.annual_rate * 100.0);
+            rate_input.set(format!("{}", inputs.annual_rate * 100.0));
+            years_signal.set(inputs.years);
+            years_input.set(format!("{}", inputs.years));
+            frequency_signal.set(inputs.periods_per_year);
+            extra_signal.set(inputs.extra_payment);
+            extra_input.set(format!("{}", inputs.extra_payment));
+            payoff_mode.set(false);
+            tax_signal.set(escrow.annual_tax);
+            tax_input.set(format!("{}", escrow.annual_tax));
+            insurance_signal.set(escrow.annual_insurance);
+            insurance_input.set(format!("{}", escrow.annual_insurance));
+            tax_growth_signal.set(escrow.tax_growth * 100.0);
+            tax_growth_input.set(format!("{}", escrow.tax_growth * 100.0));
+            tax_capped.set(escrow.tax_growth_cap.is_some());
+            if let Some(cap) = escrow.tax_growth_cap {
+                tax_cap_signal.set(cap * 100.0);
+                tax_cap_input.set(format!("{}", cap * 100.0));
+            }
+            insurance_growth_signal.set(escrow.insurance_growth * 100.0);
+            insurance_growth_input.set(format!("{}", escrow.insurance_growth * 100.0));
+        }),
+    );
+    use_track_calculator("amortization");
+    use_track_input_range("amortization", "principal", principal_signal, amount_bucket);
+    use_track_input_range("amortization", "years", years_signal, years_bucket);
+
+    let loan = AmortizationInputs {
+        principal: principal_signal(),
+        annual_rate: rate_signal() / 100.0,
+        years: years_signal(),
+        periods_per_year: frequency_signal(),
+        extra_payment: 0.0,
+    };
+    let payoff_years = payoff_years_signal();
+    let solved_extra = payoff_mode().then(|| payoff_extra_payment(&loan, payoff_years));
+    let extra_payment = match &solved_extra {
+        Some(Ok(extra)) => *extra,
+        Some(Err(_)) => 0.0,
+        None => extra_signal(),
+    };
+    let schedule = amortization_schedule(&AmortizationInputs {
+        extra_payment,
+        ..loan
+    });
+    let interest_saved = if extra_payment > 0.0 {
+        amortization_schedule(&loan).total_interest - schedule.total_interest
+    } else {
+        0.0
+    };
+    let frequency_name = FREQUENCY_OPTIONS
+        .iter()
+        .find(|(periods, _, _)| *periods == frequency_signal())
+        .map_or("", |(_, _, display)| *display)
+        .to_ascii_lowercase();
+    let periods_per_year = frequency_signal();
+    let payoff_in_years = schedule.rows.len() as f64 / periods_per_year;
+    let years = schedule.by_year(periods_per_year);
+    let escrow = escrow_inputs();
+    let escrow_years = if escrow.annual_tax + escrow.annual_insurance > 0.0 {
+        escrow_analysis(&escrow, periods_per_year, years.len())
+    } else {
+        vec![]
+    };
+    // Principal and interest, any extra, and escrow; the loan's last payment may be smaller
+    let payment_with_escrow = |deposit: f64| schedule.payment + extra_payment + deposit;
+    let mut toggle_year = move |year: usize| {
+        let mut collapsed = collapsed_years.write();
+        if !collapsed.remove(&year) {
+            collapsed.insert(year);
+        }
+    };
+    let mut toggle_period = move |period: usize| {
+        expanded_period.set((expanded_period() != Some(period)).then_some(period));
+    };
+    // What the payment would reset to if the rate moved, as on an adjustable-rate loan
+    let shocks = if schedule.payment > 0.0 {
+        loan_payment_shocks(
+            loan.principal,
+            loan.annual_rate,
+            (loan.years * periods_per_year).round() / periods_per_year,
+            periods_per_year,
+            DEFAULT_SHIFTS_BP,
+        )
+    } else {
+        vec![]
+    };
+    let payment = format_dollars(schedule.payment);
+    let total_payments = format_dollars(schedule.total_payments);
+    let total_interest = format_dollars(schedule.total_interest);
+    let total_principal = format_dollars(schedule.total_payments - schedule.total_interest);
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Loan Amount ($):".to_string(),
+            placeholder: "Enter the amount borrowed (e.g., 250000)".to_string(),
+            input_signal: principal_input,
+            value_signal: principal_signal,
+            valid_signal: principal_input_valid,
+            field_name: "Loan amount".to_string(),
+            css_prefix: "principal".to_string(),
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Interest Rate (%):".to_string(),
+            placeholder: "Enter the annual rate (e.g., 6.0)".to_string(),
+            input_signal: rate_input,
+            value_signal: rate_signal,
+            valid_signal: rate_input_valid,
+            field_name: "Interest rate".to_string(),
+            css_prefix: "rate".to_string(),
+            step: Some(0.125),
+            allow_negative: true,
+        }
+        NumericInput {
+            label: "Term (years):".to_string(),
+            placeholder: "Enter the loan term (e.g., 30)".to_string(),
+            input_signal: years_input,
+            value_signal: years_signal,
+            valid_signal: years_input_valid,
+            field_name: "Term".to_string(),
+            css_prefix: "years".to_string(),
+            step: Some(1.0),
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Payments:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(periods, _, _)) = FREQUENCY_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        frequency_signal.set(periods);
+                    }
+                },
+                for (periods , value , display) in FREQUENCY_OPTIONS.iter() {
+                    option { value: *value, selected: frequency_signal() == *periods, {*display} }
+                }
+            }
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Extra Payments:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| payoff_mode.set(event.value() == "payoff"),
+                option { value: "fixed", selected: !payoff_mode(), "Fixed amount" }
+                option { value: "payoff", selected: payoff_mode(), "Pay off by" }
+            }
+        }
+        if payoff_mode() {
+            NumericInput {
+                label: "Pay Off In (years):".to_string(),
+                placeholder: "Enter when you want the loan paid off (e.g., 20)".to_string(),
+                input_signal: payoff_years_input,
+                value_signal: payoff_years_signal,
+                valid_signal: payoff_years_input_valid,
+                field_name: "Payoff time".to_string(),
+                css_prefix: "payoff".to_string(),
+                step: Some(1.0),
+            }
+        } else {
+            NumericInput {
+                label: "Extra Payment ($):".to_string(),
+                placeholder: "Enter extra principal paid with each payment (e.g., 100)".to_string(),
+                input_signal: extra_input,
+                value_signal: extra_signal,
+                valid_signal: extra_input_valid,
+                field_name: "Extra payment".to_string(),
+                css_prefix: "extra".to_string(),
+                step: Some(50.0),
+                max_decimals: Some(2),
+            }
+        }
+
+        NumericInput {
+            label: "Property Tax ($/yr):".to_string(),
+            placeholder: "Enter the yearly tax bill paid through escrow, or 0 (e.g., 3000)".to_string(),
+            input_signal: tax_input,
+            value_signal: tax_signal,
+            valid_signal: tax_input_valid,
+            field_name: "Property tax".to_string(),
+            css_prefix: "tax".to_string(),
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Property Tax Growth (%/yr):".to_string(),
+            placeholder: "Enter how fast the home's assessed value rises (e.g., 4.0)".to_string(),
+            input_signal: tax_growth_input,
+            value_signal: tax_growth_signal,
+            valid_signal: tax_growth_input_valid,
+            field_name: "Property tax growth".to_string(),
+            css_prefix: "tax-growth".to_string(),
+            allow_negative: true,
+            step: Some(0.5),
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Reassessment:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| tax_capped.set(event.value() == "capped"),
+                option { value: "market", selected: !tax_capped(), "At market value" }
+                option { value: "capped", selected: tax_capped(), "Capped" }
+            }
+        }
+        if tax_capped() {
+            NumericInput {
+                label: "Reassessment Cap (%/yr):".to_string(),
+                placeholder: "Enter the most the assessed value may rise a year (e.g., 2.0)".to_string(),
+                input_signal: tax_cap_input,
+                value_signal: tax_cap_signal,
+                valid_signal: tax_cap_input_valid,
+                field_name: "Reassessment cap".to_string(),
+                css_prefix: "tax-cap".to_string(),
+                step: Some(0.5),
+            }
+        }
+        NumericInput {
+            label: "Insurance ($/yr):".to_string(),
+            placeholder: "Enter the yearly homeowners insurance premium, or 0 (e.g., 1200)".to_string(),
+            input_signal: insurance_input,
+            value_signal: insurance_signal,
+            valid_signal: insurance_input_valid,
+            field_name: "Insurance".to_string(),
+            css_prefix: "insurance".to_string(),
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Insurance Growth (%/yr):".to_string(),
+            placeholder: "Enter how fast the premium rises each year (e.g., 3.0)".to_string(),
+            input_signal: insurance_growth_input,
+            value_signal: insurance_growth_signal,
+            valid_signal: insurance_growth_input_valid,
+            field_name: "Insurance growth".to_string(),
+            css_prefix: "insurance-growth".to_string(),
+            allow_negative: true,
+            step: Some(0.5),
+        }
+
+        div {
+            id: "AmortizationCalculation",
+            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+            "Payment: ${payment} {frequency_name}*"
+        }
+        div { style: "margin-bottom: 15px; font-size: 16px;",
+            "{schedule.rows.len()} payments totaling ${total_payments}, of which ${total_interest} is interest."
+        }
+        {
+            match solved_extra {
+                Some(Ok(extra)) if extra > 0.0 => rsx! {
+                    div {
+                        id: "PayoffCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        "Pay ${format_dollars(extra)} extra with each payment to pay the loan off in {payoff_years} years, saving ${format_dollars(interest_saved)} in interest."
+                    }
+                },
+                Some(Ok(_)) => rsx! {
+                    div { id: "PayoffCalculation", style: "margin-bottom: 15px; font-size: 16px;",
+                        "The loan's own term pays it off within {payoff_years} years; no extra is needed."
+                    }
+                },
+                Some(Err(error)) => rsx! {
+                    div {
+                        id: "PayoffCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+                None if extra_payment > 0.0 => rsx! {
+                    div { id: "PayoffCalculation", style: "margin-bottom: 15px; font-size: 16px;",
+                        "The extra ${format_dollars(extra_payment)} a payment pays the loan off in {payoff_in_years:.2} years, saving ${format_dollars(interest_saved)} in interest."
+                    }
+                },
+                None => rsx! {},
+            }
+        }
+        if !shocks.is_empty() {
+            RateShockPanel { value_label: "Payment".to_string(), shocks }
+        }
+        if let (Some(first), Some(last)) = (escrow_years.first(), escrow_years.last()) {
+            div {
+                id: "EscrowCalculation",
+                style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                "With escrow: ${format_dollars(payment_with_escrow(first.deposit))} {frequency_name} in year 1, ${format_dollars(payment_with_escrow(last.deposit))} by year {last.year}"
+            }
+            details { style: "color: #333; margin-bottom: 15px;",
+                summary { style: "cursor: pointer; font-weight: bold;", "Escrow analysis by year" }
+                table { style: "color: #333; border-collapse: collapse; font-size: 13px; margin-top: 10px;",
+                    thead {
+                        tr {
+                            th { scope: "col", style: CELL_STYLE, "Year" }
+                            th { scope: "col", style: CELL_STYLE, "Payment" }
+                            th { scope: "col", style: CELL_STYLE, "Escrow" }
+                            th { scope: "col", style: CELL_STYLE, "Shortage Repaid" }
+                            th { scope: "col", style: CELL_STYLE, "Bills Paid" }
+                            th { scope: "col", style: CELL_STYLE, "Shortage" }
+                            th { scope: "col", style: CELL_STYLE, "Refund" }
+                            th { scope: "col", style: CELL_STYLE, "Escrow Balance" }
+                        }
+                    }
+                    tbody {
+                        for year in escrow_years.iter() {
+                            tr { key: "{year.year}",
+                                th { scope: "row", style: CELL_STYLE, "{year.year}" }
+                                td { style: CELL_STYLE, {format_dollars(payment_with_escrow(year.deposit))} }
+                                td { style: CELL_STYLE, {format_dollars(year.deposit)} }
+                                td { style: CELL_STYLE, {format_dollars(year.shortage_repayment)} }
+                                td { style: CELL_STYLE, {format_dollars(year.disbursements)} }
+                                td { style: CELL_STYLE, {format_dollars(year.shortage)} }
+                                td { style: CELL_STYLE, {format_dollars(year.refund)} }
+                                td { style: CELL_STYLE, {format_dollars(year.balance)} }
+                            }
+                        }
+                    }
+                }
+                p { style: "font-size: 12px;",
+                    "Each year's analysis sets the next year's escrow from the bills just paid, plus a two-month cushion. Bills that rise leave a shortage, repaid over the following year."
+                }
+            }
+        }
+
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Show:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| annual_view.set(event.value() == "annual"),
+                option { value: "payments", selected: !annual_view(), "Every payment" }
+                option { value: "annual", selected: annual_view(), "Annually" }
+            }
+        }
+
+        div { style: "max-height: 400px; overflow-y: auto; margin-bottom: 15px;", tabindex: "0",
+            table { style: "color: #333; border-collapse: collapse; font-size: 13px;",
+                thead { style: "position: sticky; top: 0; background: #f4f4f4;",
+                    tr {
+                        th { scope: "col", style: CELL_STYLE,
+                            if annual_view() {
+                                "Year"
+                            } else {
+                                "#"
+                            }
+                        }
+                        th { scope: "col", style: CELL_STYLE, "Payment" }
+                        th { scope: "col", style: CELL_STYLE, "Interest" }
+                        th { scope: "col", style: CELL_STYLE, "Principal" }
+                        th { scope: "col", style: CELL_STYLE, "Balance" }
+                    }
+                }
+                tbody {
+                    for year in years.iter().copied() {
+                        if annual_view() {
+                            tr { key: "{year.year}",
+                                th { scope: "row", style: CELL_STYLE, "{year.year}" }
+                                td { style: CELL_STYLE, {format_dollars(year.payment)} }
+                                td { style: CELL_STYLE, {format_dollars(year.interest)} }
+                                td { style: CELL_STYLE, {format_dollars(year.principal)} }
+                                td { style: CELL_STYLE, {format_dollars(year.balance)} }
+                            }
+                        } else {
+                            tr {
+                                key: "year-{year.year}",
+                                style: "cursor: pointer; background: #eef2f7; font-weight: bold;",
+                                tabindex: "0",
+                                "aria-expanded": !collapsed_years.read().contains(&year.year),
+                                title: "Show or hide this year's payments",
+                                onclick: move |_| toggle_year(year.year),
+                                onkeydown: move |event| {
+                                    if matches!(event.key(), Key::Enter) {
+                                        toggle_year(year.year);
+                                    }
+                                },
+                                th { scope: "row", style: CELL_STYLE,
+                                    if collapsed_years.read().contains(&year.year) {
+                                        "▸ Year {year.year}"
+                                    } else {
+                                        "▾ Year {year.year}"
+                                    }
+                                }
+                                td { style: CELL_STYLE, {format_dollars(year.payment)} }
+                                td { style: CELL_STYLE, {format_dollars(year.interest)} }
+                                td { style: CELL_STYLE, {format_dollars(year.principal)} }
+                                td { style: CELL_STYLE, {format_dollars(year.balance)} }
+                            }
+                            if !collapsed_years.read().contains(&year.year) {
+                                for row in schedule.rows[year.first_period - 1..year.last_period].iter().copied() {
+                                    tr {
+                                        key: "{row.period}",
+                                        style: "cursor: pointer;",
+                                        tabindex: "0",
+                                        "aria-expanded": expanded_period() == Some(row.period),
+                                        title: "Show this payment's details",
+                                        onclick: move |_| toggle_period(row.period),
+                                        onkeydown: move |event| {
+                                            if matches!(event.key(), Key::Enter) {
+                                                toggle_period(row.period);
+                                            }
+                                        },
+                                        th { scope: "row", style: CELL_STYLE, "{row.period}" }
+                                        td { style: CELL_STYLE, {format_dollars(row.payment)} }
+                                        td { style: CELL_STYLE, {format_dollars(row.interest)} }
+                                        td { style: CELL_STYLE, {format_dollars(row.principal)} }
+                                        td { style: CELL_STYLE, {format_dollars(row.balance)} }
+                                    }
+                                    if expanded_period() == Some(row.period) {
+                                        PeriodDetail { row, periods_per_year }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                tfoot { style: "position: sticky; bottom: 0; background: #f4f4f4; font-weight: bold;",
+                    tr {
+                        th { scope: "row", style: CELL_STYLE, "Total" }
+                        td { style: CELL_STYLE, {total_payments.clone()} }
+                        td { style: CELL_STYLE, {total_interest.clone()} }
+                        td { style: CELL_STYLE, {total_principal} }
+                        td {}
+                    }
+                }
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "amortization" }
+        AssumptionsFooter { calculator: "amortization" }
+        div { style: "font-size: 10px",
+            p {
+                "*Principal and interest only; taxes and insurance paid through escrow are shown separately, and fees are not included. Click a year to fold its payments away, or a payment for its details."
+            }
+            br {}
+            br {}
+        }
+    }
+}
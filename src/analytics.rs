@@ -0,0 +1,187 @@
+use crate::storage::{load_item, save_item, use_storage_listener};
+use dioxus::prelude::*;
+use std::collections::BTreeMap;
+use tracing::debug;
+
+const CONSENT_KEY: &str = "fintools.analytics_consent";
+
+/// Whether the user has agreed to usage analytics. Nothing is recorded unless `Granted`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Consent {
+    #[default]
+    Unknown,
+    Granted,
+    Denied,
+}
+
+impl Consent {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Consent::Unknown => "unknown",
+            Consent::Granted => "granted",
+            Consent::Denied => "denied",
+        }
+    }
+
+    fn from_str(value: &str) -> Self {
+        match value {
+            "granted" => Consent::Granted,
+            "denied" => Consent::Denied,
+            _ => Consent::Unknown,
+        }
+    }
+}
+
+/// A usage event. Input events carry a coarse range bucket, never the value the user typed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnalyticsEvent {
+    CalculatorUsed {
+        calculator: &'static str,
+    },
+    InputRange {
+        calculator: &'static str,
+        field: &'static str,
+        bucket: &'static str,
+    },
+    Feedback {
+        calculator: &'static str,
+        helpful: bool,
+    },
+}
+
+impl AnalyticsEvent {
+    fn key(&self) -> String {
+        match self {
+            AnalyticsEvent::CalculatorUsed { calculator } => format!("{}: used", calculator),
+            AnalyticsEvent::InputRange {
+                calculator,
+                field,
+                bucket,
+            } => format!("{}: {} {}", calculator, field, bucket),
+            AnalyticsEvent::Feedback {
+                calculator,
+                helpful,
+            } => format!(
+                "{}: {}",
+                calculator,
+                if *helpful { "helpful" } else { "not helpful" }
+            ),
+        }
+    }
+}
+
+/// Consent choice and aggregated event counts for this session
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Analytics {
+    pub consent: Consent,
+    counts: BTreeMap<String, u32>,
+}
+
+impl Analytics {
+    /// Counts an event if the user has opted in
+    pub fn record(&mut self, event: AnalyticsEvent) {
+        if self.consent != Consent::Granted {
+            return;
+        }
+        let key = event.key();
+        debug!("analytics event: {}", key);
+        *self.counts.entry(key).or_insert(0) += 1;
+    }
+
+    /// Everything recorded so far, shown on the settings page for transparency
+    pub fn counts(&self) -> &BTreeMap<String, u32> {
+        &self.counts
+    }
+
+    /// Stores the user's choice; declining also discards anything already recorded
+    pub fn set_consent(&mut self, consent: Consent) {
+        self.consent = consent;
+        if consent != Consent::Granted {
+            self.counts.clear();
+        }
+        save_item(CONSENT_KEY, consent.as_str());
+    }
+}
+
+/// Order-of-magnitude bucket for a dollar amount
+pub fn amount_bucket(value: f64) -> &'static str {
+    match value {
+        v if v < 1_000.0 => "under $1k",
+        v if v < 10_000.0 => "$1k-$10k",
+        v if v < 100_000.0 => "$10k-$100k",
+        v if v < 1_000_000.0 => "$100k-$1M",
+        _ => "$1M+",
+    }
+}
+
+/// Bucket for a time horizon in years
+pub fn years_bucket(years: f64) -> &'static str {
+    match years {
+        y if y < 5.0 => "under 5 years",
+        y if y < 15.0 => "5-15 years",
+        y if y < 30.0 => "15-30 years",
+        _ => "30+ years",
+    }
+}
+
+/// Provides the analytics state to the app and restores the stored consent choice.
+/// Call once, in `App`.
+pub fn use_analytics_provider() {
+    let mut analytics = use_context_provider(|| Signal::new(Analytics::default()));
+    use_future(move || async move {
+        if let Some(stored) = load_item(CONSENT_KEY).await {
+            analytics.write().consent = Consent::from_str(&stored);
+        }
+    });
+    // Keep the choice in sync when it's changed in another tab.
+    use_storage_listener(
+        CONSENT_KEY,
+        use_callback(move |stored: Option<String>| {
+            let consent = Consent::from_str(stored.as_deref().unwrap_or_default());
+            let mut analytics = analytics.write();
+            analytics.consent = consent;
+            if consent != Consent::Granted {
+                analytics.counts.clear();
+            }
+        }),
+    );
+}
+
+pub fn use_analytics() -> Signal<Analytics> {
+    use_context::<Signal<Analytics>>()
+}
+
+/// Records that a calculator was opened
+pub fn use_track_calculator(calculator: &'static str) {
+    let mut analytics = use_analytics();
+    use_effect(move || {
+        analytics
+            .write()
+            .record(AnalyticsEvent::CalculatorUsed { calculator })
+    });
+}
+
+/// Records the range bucket of an input whenever the value moves into a different bucket
+pub fn use_track_input_range(
+    calculator: &'static str,
+    field: &'static str,
+    value_signal: Signal<f64>,
+    bucket: fn(f64) -> &'static str,
+) {
+    let analytics = try_use_context::<Signal<Analytics>>();
+    let mut last_bucket = use_signal(|| None::<&'static str>);
+    use_effect(move || {
+        let current = bucket(value_signal());
+        if *last_bucket.peek() == Some(current) {
+            return;
+        }
+        last_bucket.set(Some(current));
+        if let Some(mut analytics) = analytics {
+            analytics.write().record(AnalyticsEvent::InputRange {
+                calculator,
+                field,
+                bucket: current,
+            });
+        }
+    });
+}
@@ -0,0 +1,558 @@
+use crate::compounding::{self, truncate_to_two_decimal_places, Compounding, ComputeError};
+use crate::fin_numeric::FinNumeric;
+use serde::{Deserialize, Serialize};
+
+// Computes the future value of a series of equal periodic payments (ordinary annuity).
+///
+/// # Parameters:
+/// - `payment`: Payment made at the end of each period (PMT)
+/// - `periodic_rate`: Interest rate per period (i), e.g., 0.04 / 12 for 4% compounded monthly
+/// - `n_periods`: Number of payments (N)
+///
+/// # Formula:
+/// FV = PMT * ((1 + i)^N - 1) / i
+///
+/// # Returns:
+/// The future value (FV) truncated to two decimal places.
+pub fn fv_annuity<T>(payment: T, periodic_rate: T, n_periods: T) -> T
+where
+    T: FinNumeric,
+{
+    compounding::fv_annuity(payment, periodic_rate, n_periods, false)
+}
+
+// Computes the future value of periodic payments that increase by a fixed rate each period (growing annuity).
+///
+/// # Parameters:
+/// - `first_payment`: First payment, made at the end of the first period (PMT)
+/// - `periodic_rate`: Interest rate per period (i)
+/// - `growth_rate`: Payment increase per period (g), e.g., 0.03 for +3%
+/// - `n_periods`: Number of payments (N)
+///
+/// # Formula:
+/// FV = PMT * ((1 + i)^N - (1 + g)^N) / (i - g)
+/// FV = PMT * N * (1 + i)^(N - 1)   when i = g
+///
+/// # Returns:
+/// The future value (FV) truncated to two decimal places.
+pub fn fv_growing_annuity<T>(first_payment: T, periodic_rate: T, growth_rate: T, n_periods: T) -> T
+where
+    T: FinNumeric,
+{
+    truncate_to_two_decimal_places(
+        first_payment * growing_annuity_fv_factor(periodic_rate, growth_rate, n_periods),
+    )
+}
+
+// Computes the present value of periodic payments that increase by a fixed rate each period
+// (growing annuity).
+///
+/// # Parameters:
+/// - `first_payment`: First payment, made at the end of the first period (PMT)
+/// - `periodic_rate`: Interest rate per period (i)
+/// - `growth_rate`: Payment increase per period (g), e.g., 0.03 for +3%
+/// - `n_periods`: Number of payments (N)
+///
+/// # Formula:
+/// PV = PMT / (i - g) * (1 - ((1 + g) / (1 + i))^N)
+/// PV = PMT * N / (1 + i)   when i = g
+///
+/// # Returns:
+/// The present value (PV) truncated to two decimal places.
+pub fn pv_growing_annuity<T>(first_payment: T, periodic_rate: T, growth_rate: T, n_periods: T) -> T
+where
+    T: FinNumeric,
+{
+    truncate_to_two_decimal_places(
+        first_payment * growing_annuity_fv_factor(periodic_rate, growth_rate, n_periods)
+            / T::compound_growth(periodic_rate, n_periods),
+    )
+}
+
+/// Future value of a growing series of payments per dollar of the first payment:
+/// ((1 + i)^N - (1 + g)^N) / (i - g), or N * (1 + i)^(N - 1) when i = g
+fn growing_annuity_fv_factor<T>(periodic_rate: T, growth_rate: T, n_periods: T) -> T
+where
+    T: FinNumeric,
+{
+    let rate_growth = T::compound_growth(periodic_rate, n_periods);
+    if (periodic_rate - growth_rate).abs() < T::epsilon() {
+        return n_periods * rate_growth / (T::one() + periodic_rate);
+    }
+    let payment_growth = T::compound_growth(growth_rate, n_periods);
+
+    (rate_growth - payment_growth) / (periodic_rate - growth_rate)
+}
+
+// Computes the future value of contributions made several times per year that step up once a year
+// (e.g., a monthly retirement contribution raised by 3% every January).
+///
+/// # Parameters:
+/// - `contribution`: Contribution per period during the first year (PMT)
+/// - `annual_interest_rate`: Annual interest rate (r), e.g., 0.04 for 4%
+/// - `annual_increase`: Annual contribution increase (g), e.g., 0.03 for +3%/yr
+/// - `n_per_year`: Contributions (and compounding periods) per year (n)
+/// - `n_years`: Time in whole years (t)
+///
+/// # Formula:
+/// Each year's contributions form a level annuity worth A = PMT * ((1 + r/n)^n - 1) / (r/n)
+/// at year end; those year-end values then grow as an annual growing annuity:
+/// FV = A * ((1 + r/n)^(nt) - (1 + g)^t) / ((1 + r/n)^n - (1 + g))
+///
+/// # Returns:
+/// The future value (FV) truncated to two decimal places.
+pub fn fv_escalating_contributions<T>(
+    contribution: T,
+    annual_interest_rate: T,
+    annual_increase: T,
+    n_per_year: T,
+    n_years: T,
+) -> T
+where
+    T: FinNumeric,
+{
+    let periodic_rate = annual_interest_rate / n_per_year;
+    let effective_annual_rate = (T::one() + periodic_rate).powf(n_per_year) - T::one();
+    let first_year_value = if periodic_rate == T::zero() {
+        contribution * n_per_year
+    } else {
+        contribution * effective_annual_rate / periodic_rate
+    };
+
+    fv_growing_annuity(
+        first_year_value,
+        effective_annual_rate,
+        annual_increase,
+        n_years,
+    )
+}
+
+// Computes the present value of a level payment that continues forever (a perpetuity), such as
+// a preferred stock's fixed dividend.
+///
+/// # Parameters:
+/// - `payment`: Payment at the end of each period, starting one period from now (PMT)
+/// - `periodic_rate`: Discount rate per period (i)
+///
+/// # Formula:
+/// PV = PMT / i
+///
+/// # Returns:
+/// The present value (PV) truncated to two decimal places, or an error unless i > 0; at a zero
+/// or negative rate the payments are worth an unlimited amount.
+pub fn pv_perpetuity<T>(payment: T, periodic_rate: T) -> Result<T, ComputeError>
+where
+    T: FinNumeric,
+{
+    if periodic_rate <= T::zero() {
+        return Err(ComputeError::InvalidInput(
+            "The discount rate must be greater than zero for a perpetuity to have a value",
+        ));
+    }
+
+    Ok(truncate_to_two_decimal_places(payment / periodic_rate))
+}
+
+// Computes the present value of a payment that grows by a fixed rate each period forever (a
+// growing perpetuity, as in the Gordon growth model).
+///
+/// # Parameters:
+/// - `first_payment`: Payment at the end of the first period (PMT)
+/// - `periodic_rate`: Discount rate per period (i)
+/// - `growth_rate`: Payment increase per period (g), e.g., 0.02 for +2%
+///
+/// # Formula:
+/// PV = PMT / (i - g)
+///
+/// # Returns:
+/// The present value (PV) truncated to two decimal places, or an error unless g < i; when
+/// payments grow as fast as the discount rate, or faster, the sum never converges.
+pub fn pv_growing_perpetuity<T>(
+    first_payment: T,
+    periodic_rate: T,
+    growth_rate: T,
+) -> Result<T, ComputeError>
+where
+    T: FinNumeric,
+{
+    if growth_rate >= periodic_rate {
+        return Err(ComputeError::InvalidInput(
+            "The growth rate must be below the discount rate for a growing perpetuity to have a \
+             value",
+        ));
+    }
+    if growth_rate <= -T::one() {
+        return Err(ComputeError::InvalidInput(
+            "The growth rate must be greater than -100%",
+        ));
+    }
+
+    Ok(truncate_to_two_decimal_places(
+        first_payment / (periodic_rate - growth_rate),
+    ))
+}
+
+/// Inputs to the perpetuity calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PerpetuityInputs {
+    /// The first year's payment, e.g., a preferred stock's annual dividend
+    pub payment: f64,
+    /// Annual discount rate (required return) as a decimal, e.g., 0.06 for 6%
+    pub discount_rate: f64,
+    /// Annual payment growth as a decimal; zero for a level perpetuity
+    pub growth_rate: f64,
+}
+
+/// Value today of the payments in `inputs`, the first arriving in a year
+pub fn value_perpetuity(inputs: &PerpetuityInputs) -> Result<f64, ComputeError> {
+    if inputs.growth_rate == 0.0 {
+        pv_perpetuity(inputs.payment, inputs.discount_rate)
+    } else {
+        pv_growing_perpetuity(inputs.payment, inputs.discount_rate, inputs.growth_rate)
+    }
+}
+
+/// Which of an annuity's amounts the calculator solves for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnnuityUnknown {
+    Payment,
+    PresentValue,
+    FutureValue,
+}
+
+/// Inputs to the annuity calculator. Exactly one of `payment`, `present_value`, and
+/// `future_value` is left out (`None`) and solved for. With a growth rate, `payment` is the
+/// first payment.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AnnuityInputs {
+    /// Deposit each period, or a withdrawal when negative
+    pub payment: Option<f64>,
+    /// Balance at the start
+    pub present_value: Option<f64>,
+    /// Balance after the last payment
+    pub future_value: Option<f64>,
+    /// Annual interest rate as a decimal, e.g., 0.05 for 5%
+    pub annual_rate: f64,
+    pub years: f64,
+    /// Payments (and compounding periods) per year, e.g., 12 for monthly
+    pub periods_per_year: f64,
+    /// Whether payments are made at the start of each period rather than the end
+    pub annuity_due: bool,
+    /// Annual rate the payment grows by as a decimal, e.g., 0.03 for raises of 3% a year
+    #[serde(default)]
+    pub growth_rate: f64,
+}
+
+/// All three amounts of an annuity, with the one that was solved for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnnuityOutputs {
+    pub unknown: AnnuityUnknown,
+    /// The first payment; every payment when there's no growth
+    pub payment: f64,
+    pub present_value: f64,
+    pub future_value: f64,
+    /// The payment in the final period, after growth
+    pub last_payment: f64,
+}
+
+// Solves for whichever of payment, present value, or future value is missing, treating the
+// present value as a starting balance that grows alongside the payments.
+///
+/// # Parameters:
+/// - `inputs`: Two of PMT, PV, and FV, with the rate, term, payment frequency, timing, and
+///   payment growth
+///
+/// # Formula:
+/// i = r / n, N = n * t
+/// FV = PV * (1 + i)^N + PMT * ((1 + i)^N - 1) / i   (times (1 + i) for an annuity due)
+/// With annual payment growth G, each payment is (1 + G)^(1/n) times the last, g = that - 1:
+/// FV = PV * (1 + i)^N + PMT * ((1 + i)^N - (1 + g)^N) / (i - g)   (times (1 + i) if due)
+///
+/// # Returns:
+/// All three amounts, truncated to two decimal places, or why they can't be computed.
+pub fn solve_annuity(inputs: &AnnuityInputs) -> Result<AnnuityOutputs, String> {
+    if inputs.periods_per_year <= 0.0 || inputs.years <= 0.0 {
+        return Err("The term and payments per year must be greater than zero".to_string());
+    }
+    let periodic_rate = inputs.annual_rate / inputs.periods_per_year;
+    if periodic_rate <= -1.0 {
+        return Err("The interest rate per period must be greater than -100%".to_string());
+    }
+    if inputs.growth_rate <= -1.0 {
+        return Err("The payment growth rate must be greater than -100%".to_string());
+    }
+    let n_periods = (inputs.periods_per_year * inputs.years).round();
+    let growth = f64::compound_growth(periodic_rate, n_periods);
+    let due = inputs.annuity_due;
+    // Growing payments use the growing-annuity factor; level ones keep the `compounding` formulas
+    let payment_growth = (1.0 + inputs.growth_rate).powf(1.0 / inputs.periods_per_year) - 1.0;
+    let growing_factor = (inputs.growth_rate != 0.0).then(|| {
+        let factor = growing_annuity_fv_factor(periodic_rate, payment_growth, n_periods);
+        if due {
+            factor * (1.0 + periodic_rate)
+        } else {
+            factor
+        }
+    });
+    let fv_payments = |payment: f64| match growing_factor {
+        Some(factor) => truncate_to_two_decimal_places(payment * factor),
+        None => compounding::fv_annuity(payment, periodic_rate, n_periods, due),
+    };
+    let pv_payments = |payment: f64| match growing_factor {
+        Some(factor) => truncate_to_two_decimal_places(payment * factor / growth),
+        None => compounding::pv_annuity(payment, periodic_rate, n_periods, due),
+    };
+    let payment_for_fv = |future_value: f64| match growing_factor {
+        Some(factor) => truncate_to_two_decimal_places(future_value / factor),
+        None => compounding::annuity_payment_for_fv(future_value, periodic_rate, n_periods, due),
+    };
+    let last_payment = |payment: f64| {
+        truncate_to_two_decimal_places(
+            payment * f64::compound_growth(payment_growth, (n_periods - 1.0).max(0.0)),
+        )
+    };
+
+    let outputs = match (inputs.payment, inputs.present_value, inputs.future_value) {
+        (Some(payment), Some(present_value), None) => AnnuityOutputs {
+            unknown: AnnuityUnknown::FutureValue,
+            payment,
+            present_value,
+            future_value: truncate_to_two_decimal_places(
+                present_value * growth + fv_payments(payment),
+            ),
+            last_payment: last_payment(payment),
+        },
+        (Some(payment), None, Some(future_value)) => AnnuityOutputs {
+            unknown: AnnuityUnknown::PresentValue,
+            payment,
+            present_value: truncate_to_two_decimal_places(
+                future_value / growth - pv_payments(payment),
+            ),
+            future_value,
+            last_payment: last_payment(payment),
+        },
+        (None, Some(present_value), Some(future_value)) => {
+            let payment = payment_for_fv(future_value - present_value * growth);
+            AnnuityOutputs {
+                unknown: AnnuityUnknown::Payment,
+                payment,
+                present_value,
+                future_value,
+                last_payment: last_payment(payment),
+            }
+        }
+        _ => {
+            return Err(
+                "Leave exactly one of payment, present value, and future value blank".to_string(),
+            )
+        }
+    };
+    if ![
+        outputs.payment,
+        outputs.present_value,
+        outputs.future_value,
+        outputs.last_payment,
+    ]
+    .iter()
+    .all(|value| value.is_finite())
+    {
+        return Err(
+            "The result is too large to compute; try fewer years or a lower rate".to_string(),
+        );
+    }
+    Ok(outputs)
+}
+
+// Computes the deposit needed each period to reach a savings goal, when interest may compound
+// more or less often than deposits are made.
+///
+/// # Parameters:
+/// - `target`: Amount wanted at the goal date (FV)
+/// - `current_savings`: Amount already saved, earning the same rate (PV)
+/// - `annual_interest_rate`: Annual interest rate (r), e.g., 0.04 for 4%
+/// - `n_per_year_compounded`: Compounding periods per year (n), infinite for continuous
+/// - `deposits_per_year`: Deposits per year (d), e.g., 12 for monthly
+/// - `n_years`: Time to the goal in years (t)
+/// - `annuity_due`: Whether deposits are made at the start of each period rather than the end
+///
+/// # Formula:
+/// i = (1 + r/n)^(n/d) - 1, the rate per deposit period (e^(r/d) - 1 when continuous)
+/// N = d * t, rounded to whole deposits
+/// PMT = (FV - PV * (1 + i)^N) * i / ((1 + i)^N - 1)   (divided by (1 + i) for an annuity due)
+///
+/// # Returns:
+/// The deposit per period (PMT) truncated to two decimal places, or zero when the current
+/// savings reach the goal on their own.
+pub fn savings_goal_payment<T>(
+    target: T,
+    current_savings: T,
+    annual_interest_rate: T,
+    n_per_year_compounded: T,
+    deposits_per_year: T,
+    n_years: T,
+    annuity_due: bool,
+) -> T
+where
+    T: FinNumeric,
+{
+    let deposit_rate = compounding::growth_factor(
+        annual_interest_rate,
+        n_per_year_compounded,
+        T::one() / deposits_per_year,
+    ) - T::one();
+    let n_deposits = (deposits_per_year * n_years).round_dp(0);
+    let shortfall = target - current_savings * T::compound_growth(deposit_rate, n_deposits);
+    if shortfall <= T::zero() {
+        return T::zero();
+    }
+
+    compounding::annuity_payment_for_fv(shortfall, deposit_rate, n_deposits, annuity_due)
+}
+
+/// Inputs to the savings goal solver
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SavingsGoalInputs {
+    /// Amount wanted at the goal date
+    pub target: f64,
+    /// Amount already saved
+    pub current_savings: f64,
+    /// Annual interest rate as a decimal, e.g., 0.04 for 4%
+    pub annual_rate: f64,
+    pub compounding: Compounding,
+    /// Deposits per year, e.g., 12 for monthly
+    pub deposits_per_year: f64,
+    /// Time to the goal date
+    pub years: f64,
+    /// Whether deposits are made at the start of each period rather than the end
+    pub annuity_due: bool,
+    /// Yearly step-up in the deposit as a decimal, e.g., 0.03 for +3%/yr; zero for level deposits
+    #[serde(default)]
+    pub annual_increase: f64,
+}
+
+/// The deposit that reaches a savings goal, and where the money comes from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SavingsGoalOutputs {
+    /// Deposit per period, in the first year when deposits increase
+    pub payment: f64,
+    /// Deposit per period in the final year; `payment` when deposits are level
+    pub final_payment: f64,
+    /// Number of deposits before the goal date
+    pub n_deposits: f64,
+    /// Sum of the deposits
+    pub total_deposits: f64,
+    /// Balance at the goal date: the target, or more if current savings outgrow it
+    pub ending_balance: f64,
+    /// Interest earned on the savings and the deposits
+    pub interest: f64,
+}
+
+/// Solves for the periodic deposit that reaches `inputs.target` by the goal date
+pub fn solve_savings_goal(inputs: &SavingsGoalInputs) -> Result<SavingsGoalOutputs, String> {
+    let n = inputs.compounding.periods_per_year();
+    if n.is_nan() || n <= 0.0 {
+        return Err("Compounding periods per year must be greater than zero".to_string());
+    }
+    if inputs.deposits_per_year <= 0.0 || inputs.years <= 0.0 {
+        return Err("The time to the goal must be greater than zero".to_string());
+    }
+    if !inputs.compounding.is_continuous() && inputs.annual_rate / n <= -1.0 {
+        return Err("The interest rate per period must be greater than -100%".to_string());
+    }
+    if inputs.annual_increase.is_nan() || inputs.annual_increase <= -1.0 {
+        return Err("The annual increase must be greater than -100%".to_string());
+    }
+    let escalating = inputs.annual_increase != 0.0;
+    if escalating && inputs.years.fract() != 0.0 {
+        return Err(
+            "Deposits that increase every year need a whole number of years to the goal"
+                .to_string(),
+        );
+    }
+    let n_deposits = (inputs.deposits_per_year * inputs.years).round();
+    if n_deposits < 1.0 {
+        return Err("Allow time for at least one deposit before the goal date".to_string());
+    }
+    let payment = if escalating {
+        escalating_goal_payment(inputs, n)
+    } else {
+        savings_goal_payment(
+            inputs.target,
+            inputs.current_savings,
+            inputs.annual_rate,
+            n,
+            inputs.deposits_per_year,
+            inputs.years,
+            inputs.annuity_due,
+        )
+    };
+    let savings_growth =
+        compounding::growth_factor(inputs.annual_rate, n, n_deposits / inputs.deposits_per_year);
+    let (total_deposits, final_payment) = if escalating {
+        // A year's deposits summed without interest: a growing annuity at a zero rate
+        let total = fv_growing_annuity(
+            payment * inputs.deposits_per_year,
+            0.0,
+            inputs.annual_increase,
+            inputs.years,
+        );
+        let last_year = (1.0 + inputs.annual_increase).powf(inputs.years - 1.0);
+        (total, truncate_to_two_decimal_places(payment * last_year))
+    } else {
+        (
+            truncate_to_two_decimal_places(payment * n_deposits),
+            payment,
+        )
+    };
+    let ending_balance = if payment > 0.0 {
+        inputs.target
+    } else {
+        truncate_to_two_decimal_places(inputs.current_savings * savings_growth)
+    };
+    if !payment.is_finite() || !ending_balance.is_finite() {
+        return Err(
+            "The result is too large to compute; try fewer years or a lower rate".to_string(),
+        );
+    }
+    Ok(SavingsGoalOutputs {
+        payment,
+        final_payment,
+        n_deposits,
+        total_deposits,
+        ending_balance,
+        interest: truncate_to_two_decimal_places(
+            ending_balance - inputs.current_savings - total_deposits,
+        ),
+    })
+}
+
+/// The first year's deposit, raised by `inputs.annual_increase` every year after, that reaches
+/// the goal. `fv_escalating_contributions` is linear in the deposit, so the shortfall itself is
+/// valued as a first-year deposit and scaled back down; at that size its cents truncation doesn't
+/// move the result.
+fn escalating_goal_payment(inputs: &SavingsGoalInputs, n_per_year_compounded: f64) -> f64 {
+    let deposits_per_year = inputs.deposits_per_year;
+    let deposit_rate = compounding::growth_factor(
+        inputs.annual_rate,
+        n_per_year_compounded,
+        1.0 / deposits_per_year,
+    ) - 1.0;
+    let shortfall = inputs.target
+        - inputs.current_savings
+            * compounding::growth_factor(inputs.annual_rate, n_per_year_compounded, inputs.years);
+    if shortfall <= 0.0 {
+        return 0.0;
+    }
+    let mut shortfall_value = fv_escalating_contributions(
+        shortfall,
+        deposit_rate * deposits_per_year,
+        inputs.annual_increase,
+        deposits_per_year,
+        inputs.years,
+    );
+    if inputs.annuity_due {
+        shortfall_value *= 1.0 + deposit_rate;
+    }
+
+    truncate_to_two_decimal_places(shortfall * shortfall / shortfall_value)
+}
@@ -0,0 +1,286 @@
+use crate::analytics::{use_track_calculator, use_track_input_range, years_bucket};
+use crate::annuity::{solve_annuity, AnnuityInputs, AnnuityUnknown};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::feedback_component::FeedbackWidget;
+use crate::number_format::dollars;
+use crate::numeric_input_component::NumericInput;
+use dioxus::prelude::*;
+
+/// $500 a month on top of $10,000 for ten years at 5%, solving for the ending balance
+const DEFAULT_INPUTS: AnnuityInputs = AnnuityInputs {
+    payment: Some(500.0),
+    present_value: Some(10_000.0),
+    future_value: None,
+    annual_rate: 0.05,
+    years: 10.0,
+    periods_per_year: 12.0,
+    annuity_due: false,
+    growth_rate: 0.0,
+};
+
+const FREQUENCY_OPTIONS: &[(f64, &str, &str)] = &[
+    (12.0, "monthly", "Monthly"),
+    (4.0, "quarterly", "Quarterly"),
+    (1.0, "annual", "Annual"),
+];
+
+const TIMING_OPTIONS: &[(bool, &str, &str)] = &[
+    (false, "end", "End of period"),
+    (true, "begin", "Start of period"),
+];
+
+/// Input text for an optional amount: blank when it's the one being solved for
+fn amount_text(amount: Option<f64>) -> String {
+    amount
+        .map(|amount| format!("{}", amount))
+        .unwrap_or_default()
+}
+
+#[component]
+pub fn AnnuityUI() -> Element {
+    let mut payment_signal = use_signal(|| 500.0 as f64);
+    let mut payment_input = use_signal(|| "500".to_string());
+    let payment_input_valid = use_signal(|| true);
+    let mut present_value_signal = use_signal(|| 10_000.0 as f64);
+    let mut present_value_input = use_signal(|| "10000".to_string());
+    let present_value_input_valid = use_signal(|| true);
+    let mut future_value_signal = use_signal(|| 0.0 as f64);
+    let mut future_value_input = use_signal(String::new);
+    let future_value_input_valid = use_signal(|| true);
+    let mut rate_signal = use_signal(|| 5.0 as f64);
+    let mut rate_input = use_signal(|| "5.0".to_string());
+    let rate_input_valid = use_signal(|| true);
+    let mut years_signal = use_signal(|| 10.0 as f64);
+    let mut years_input = use_signal(|| "10".to_string());
+    let years_input_valid = use_signal(|| true);
+    let mut frequency_signal = use_signal(|| 12.0 as f64);
+    let mut due_signal = use_signal(|| false);
+    let mut growth_signal = use_signal(|| 0.0 as f64);
+    let mut growth_input = use_signal(|| "0".to_string());
+    let growth_input_valid = use_signal(|| true);
+
+    // A blank field is the one to solve for; its signal keeps the last value typed
+    let amount =
+        |input: Signal<String>, value: Signal<f64>| (!input().trim().is_empty()).then(|| value());
+    let inputs = move || AnnuityInputs {
+        payment: amount(payment_input, payment_signal),
+        present_value: amount(present_value_input, present_value_signal),
+        future_value: amount(future_value_input, future_value_signal),
+        annual_rate: rate_signal() / 100.0,
+        years: years_signal(),
+        periods_per_year: frequency_signal(),
+        annuity_due: due_signal(),
+        growth_rate: growth_signal() / 100.0,
+    };
+    let start_fresh = use_autosave(
+        "annuity",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: AnnuityInputs| {
+            payment_signal.set(inputs.payment.unwrap_or(0.0));
+            payment_input.set(amount_text(inputs.payment));
+            present_value_signal.set(inputs.present_value.unwrap_or(0.0));
+            present_value_input.set(amount_text(inputs.present_value));
+            future_value_signal.set(inputs.future_value.unwrap_or(0.0));
+            future_value_input.set(amount_text(inputs.future_value));
+            rate_signal.set(inputs.annual_rate * 100.0);
+            rate_input.set(format!("{}", inputs.annual_rate * 100.0));
+            years_signal.set(inputs.years);
+            years_input.set(format!("{}", inputs.years));
+            frequency_signal.set(inputs.periods_per_year);
+            due_signal.set(inputs.annuity_due);
+            growth_signal.set(inputs.growth_rate * 100.0);
+            growth_input.set(format!("{}", inputs.growth_rate * 100.0));
+        }),
+    );
+    use_track_calculator("annuity");
+    use_track_input_range("annuity", "years", years_signal, years_bucket);
+
+    let frequency_name = FREQUENCY_OPTIONS
+        .iter()
+        .find(|(periods, _, _)| *periods == frequency_signal())
+        .map_or("", |(_, _, display)| *display)
+        .to_ascii_lowercase();
+    let growth_rate = growth_signal();
+    let outputs = solve_annuity(&inputs());
+    let result = outputs.as_ref().map(|outputs| match outputs.unknown {
+        AnnuityUnknown::Payment if outputs.payment < 0.0 => format!(
+            "Withdrawal: {} {}",
+            dollars(-outputs.payment),
+            frequency_name
+        ),
+        AnnuityUnknown::Payment => {
+            format!("Deposit: {} {}", dollars(outputs.payment), frequency_name)
+        }
+        AnnuityUnknown::PresentValue => format!(
+            "Starting balance needed: {}",
+            dollars(outputs.present_value)
+        ),
+        AnnuityUnknown::FutureValue => {
+            format!("Ending balance: {}", dollars(outputs.future_value))
+        }
+    });
+    let growth_note = outputs
+        .as_ref()
+        .ok()
+        .filter(|_| growth_rate != 0.0)
+        .map(|outputs| {
+            format!(
+                "Payments start at {} and change {}% a year, ending at {}.",
+                dollars(outputs.payment),
+                growth_rate,
+                dollars(outputs.last_payment)
+            )
+        });
+
+    rsx! {
+        hr {}
+        br {}
+
+        div { style: "color: #333; margin-bottom: 15px; max-width: 600px;",
+            "Fill in two of payment, present value, and future value, and leave the one to solve for blank. Withdrawals are negative payments; enter a loan as a negative present value."
+        }
+        NumericInput {
+            label: "Payment ($):".to_string(),
+            placeholder: "Leave blank to solve for the payment".to_string(),
+            input_signal: payment_input,
+            value_signal: payment_signal,
+            valid_signal: payment_input_valid,
+            field_name: "Payment".to_string(),
+            css_prefix: "payment".to_string(),
+            allow_negative: true,
+            step: Some(50.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Present Value ($):".to_string(),
+            placeholder: "Leave blank to solve for the present value".to_string(),
+            input_signal: present_value_input,
+            value_signal: present_value_signal,
+            valid_signal: present_value_input_valid,
+            field_name: "Present value".to_string(),
+            css_prefix: "present-value".to_string(),
+            allow_negative: true,
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Future Value ($):".to_string(),
+            placeholder: "Leave blank to solve for the future value".to_string(),
+            input_signal: future_value_input,
+            value_signal: future_value_signal,
+            valid_signal: future_value_input_valid,
+            field_name: "Future value".to_string(),
+            css_prefix: "future-value".to_string(),
+            allow_negative: true,
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Interest Rate (%):".to_string(),
+            placeholder: "Enter the annual rate (e.g., 5.0)".to_string(),
+            input_signal: rate_input,
+            value_signal: rate_signal,
+            valid_signal: rate_input_valid,
+            field_name: "Interest rate".to_string(),
+            css_prefix: "rate".to_string(),
+            allow_negative: true,
+            step: Some(0.125),
+        }
+        NumericInput {
+            label: "Years:".to_string(),
+            placeholder: "Enter the number of years (e.g., 10)".to_string(),
+            input_signal: years_input,
+            value_signal: years_signal,
+            valid_signal: years_input_valid,
+            field_name: "Years".to_string(),
+            css_prefix: "years".to_string(),
+            step: Some(1.0),
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Payments:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(periods, _, _)) = FREQUENCY_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        frequency_signal.set(periods);
+                    }
+                },
+                for (periods , value , display) in FREQUENCY_OPTIONS.iter() {
+                    option { value: *value, selected: frequency_signal() == *periods, {*display} }
+                }
+            }
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Paid At:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(due, _, _)) = TIMING_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        due_signal.set(due);
+                    }
+                },
+                for (due , value , display) in TIMING_OPTIONS.iter() {
+                    option { value: *value, selected: due_signal() == *due, {*display} }
+                }
+            }
+        }
+        NumericInput {
+            label: "Payment Growth (%/yr):".to_string(),
+            placeholder: "Enter yearly raises to the payment, or 0 (e.g., 3.0)".to_string(),
+            input_signal: growth_input,
+            value_signal: growth_signal,
+            valid_signal: growth_input_valid,
+            field_name: "Payment growth".to_string(),
+            css_prefix: "growth".to_string(),
+            allow_negative: true,
+            step: Some(0.5),
+        }
+
+        {
+            match result {
+                Ok(result) => rsx! {
+                    div {
+                        id: "AnnuityCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        "{result}*"
+                    }
+                    if let Some(growth_note) = growth_note {
+                        div { style: "margin-bottom: 15px; font-size: 16px;", "{growth_note}" }
+                    }
+                },
+                Err(error) => rsx! {
+                    div {
+                        id: "AnnuityCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "annuity" }
+        AssumptionsFooter { calculator: "annuity" }
+        div { style: "font-size: 10px",
+            p {
+                "*Payments at the start of each period (an annuity due) earn one more period of interest than payments at the end (an ordinary annuity)."
+            }
+            br {}
+            br {}
+        }
+    }
+}
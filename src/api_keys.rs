@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Start of every issued key, so a leaked key is easy to spot in logs and secret scanners
+pub const KEY_PREFIX: &str = "fvk_";
+
+/// Requests a key may make per minute when its configuration doesn't say
+pub const DEFAULT_REQUESTS_PER_MINUTE: u32 = 60;
+
+/// Environment variable the server reads its keys from, one `label:key[:requests_per_minute]`
+/// entry per line or comma
+pub const API_KEYS_VAR: &str = "API_KEYS";
+
+/// How many requests a key may make in a window. Requests refill evenly across the window, so a
+/// client can burst up to `requests` and then continue at the average rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub per: Duration,
+}
+
+impl RateLimit {
+    pub fn per_minute(requests: u32) -> Self {
+        RateLimit {
+            requests,
+            per: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Why a request was turned away
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiKeyError {
+    /// No key was sent
+    Missing,
+    /// The key isn't one the server was configured with
+    Unknown,
+    /// The key has used its requests; the next one is available after `retry_after`
+    RateLimited { retry_after: Duration },
+}
+
+impl std::fmt::Display for ApiKeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiKeyError::Missing => write!(f, "An API key is required"),
+            ApiKeyError::Unknown => write!(f, "The API key is not valid"),
+            ApiKeyError::RateLimited { retry_after } => write!(
+                f,
+                "Rate limit reached; retry in {} seconds",
+                retry_after.as_secs_f64().ceil()
+            ),
+        }
+    }
+}
+
+/// Requests left for one key, refilled continuously (a token bucket)
+#[derive(Debug, Clone)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+#[derive(Debug, Clone)]
+struct KeyEntry {
+    label: String,
+    limit: RateLimit,
+    /// Started on the key's first request
+    bucket: Option<Bucket>,
+}
+
+/// The keys a server accepts and the requests each has left
+#[derive(Debug, Clone, Default)]
+pub struct ApiKeyStore {
+    keys: HashMap<String, KeyEntry>,
+}
+
+impl ApiKeyStore {
+    /// Reads keys from configuration text: `label:key` or `label:key:requests_per_minute`
+    /// entries, separated by commas or newlines. Blank entries are skipped.
+    pub fn parse(config: &str) -> Result<Self, String> {
+        let mut store = ApiKeyStore::default();
+        for entry in config.split([',', '\n']).map(str::trim) {
+            if entry.is_empty() {
+                continue;
+            }
+            let parts: Vec<&str> = entry.split(':').map(str::trim).collect();
+            let (label, key, requests) = match parts.as_slice() {
+                [label, key] => (*label, *key, DEFAULT_REQUESTS_PER_MINUTE),
+                [label, key, requests] => {
+                    let requests = requests
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|requests| *requests > 0)
+                        .ok_or_else(|| {
+                            format!(
+                                "Key \"{}\": requests per minute must be a whole number above zero",
+                                label
+                            )
+                        })?;
+                    (*label, *key, requests)
+                }
+                _ => {
+                    return Err(format!(
+                        "Expected label:key or label:key:requests_per_minute, got \"{}\"",
+                        entry
+                    ))
+                }
+            };
+            if !key.starts_with(KEY_PREFIX) {
+                return Err(format!(
+                    "Key \"{}\" must start with {}; issue one with --issue-api-key",
+                    label, KEY_PREFIX
+                ));
+            }
+            store.add(key, label, RateLimit::per_minute(requests));
+        }
+        Ok(store)
+    }
+
+    /// Accepts `key`, replacing any key with the same text
+    pub fn add(&mut self, key: &str, label: &str, limit: RateLimit) {
+        self.keys.insert(
+            key.to_string(),
+            KeyEntry {
+                label: label.to_string(),
+                limit,
+                bucket: None,
+            },
+        );
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Label the key was issued under, for logging who made a request
+    pub fn label(&self, key: &str) -> Option<&str> {
+        self.keys.get(key).map(|entry| entry.label.as_str())
+    }
+
+    /// Checks a request made at `now` with `key`, using up one of its requests. Returns the
+    /// number of whole requests left.
+    pub fn check(&mut self, key: Option<&str>, now: Instant) -> Result<u32, ApiKeyError> {
+        let key = key.map(str::trim).filter(|key| !key.is_empty());
+        let entry = self
+            .keys
+            .get_mut(key.ok_or(ApiKeyError::Missing)?)
+            .ok_or(ApiKeyError::Unknown)?;
+        let capacity = entry.limit.requests as f64;
+        let refill_per_second = capacity / entry.limit.per.as_secs_f64();
+
+        let bucket = entry.bucket.get_or_insert(Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * refill_per_second).min(capacity);
+        bucket.updated = now;
+
+        if bucket.tokens < 1.0 {
+            let wait = (1.0 - bucket.tokens) / refill_per_second;
+            return Err(ApiKeyError::RateLimited {
+                retry_after: Duration::from_secs_f64(wait),
+            });
+        }
+        bucket.tokens -= 1.0;
+        Ok(bucket.tokens.floor() as u32)
+    }
+}
+
+/// A new key from 16 random bytes: the prefix and 32 hex digits
+pub fn generate_key(entropy: [u8; 16]) -> String {
+    let hex: String = entropy.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}{}", KEY_PREFIX, hex)
+}
+
+/// The `API_KEYS` entry that enables a key
+pub fn config_entry(label: &str, key: &str, requests_per_minute: u32) -> String {
+    format!("{}:{}:{}", label, key, requests_per_minute)
+}
+
+/// 16 bytes from the operating system's secure random source
+#[cfg(feature = "server")]
+pub fn os_entropy() -> std::io::Result<[u8; 16]> {
+    use std::io::Read;
+
+    let mut bytes = [0u8; 16];
+    std::fs::File::open("/dev/urandom")?.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// The server's keys, read once from `API_KEYS`. A missing or invalid variable leaves the store
+/// empty, so every keyed request is refused rather than let through.
+#[cfg(feature = "server")]
+static SERVER_KEYS: std::sync::LazyLock<std::sync::Mutex<ApiKeyStore>> =
+    std::sync::LazyLock::new(|| {
+        let config = std::env::var(API_KEYS_VAR).unwrap_or_default();
+        let store = ApiKeyStore::parse(&config).unwrap_or_else(|error| {
+            tracing::error!("Ignoring {}: {}", API_KEYS_VAR, error);
+            ApiKeyStore::default()
+        });
+        std::sync::Mutex::new(store)
+    });
+
+/// Guard for programmatic endpoints: call it first with the key the request sent
+#[cfg(feature = "server")]
+pub fn authorize(key: Option<&str>) -> Result<u32, ApiKeyError> {
+    let mut keys = SERVER_KEYS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    keys.check(key, Instant::now())
+}
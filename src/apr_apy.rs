@@ -0,0 +1,59 @@
+use crate::compounding::{effective_to_nominal, nominal_to_effective, Compounding};
+use serde::{Deserialize, Serialize};
+
+/// Which of the two rates was entered; the other is computed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum RateKind {
+    /// Nominal annual rate, before compounding within the year
+    #[default]
+    Apr,
+    /// Effective annual rate, once compounding is included
+    Apy,
+}
+
+/// Inputs to the APR and APY converter
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AprApyInputs {
+    /// The entered rate as a decimal, e.g., 0.05 for 5%
+    pub rate: f64,
+    pub entered: RateKind,
+    pub compounding: Compounding,
+}
+
+/// Both forms of the rate, as decimals
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AprApyOutputs {
+    pub apr: f64,
+    pub apy: f64,
+}
+
+/// Converts the entered rate to the other form at the chosen compounding frequency
+pub fn convert_rate(inputs: &AprApyInputs) -> Result<AprApyOutputs, String> {
+    let n = inputs.compounding.periods_per_year();
+    if n.is_nan() || n <= 0.0 {
+        return Err("Compounding periods per year must be greater than zero".to_string());
+    }
+    if !inputs.rate.is_finite() {
+        return Err("Enter a rate".to_string());
+    }
+    match inputs.entered {
+        RateKind::Apr => {
+            if !inputs.compounding.is_continuous() && inputs.rate / n <= -1.0 {
+                return Err("The APR per period must be greater than -100%".to_string());
+            }
+            Ok(AprApyOutputs {
+                apr: inputs.rate,
+                apy: nominal_to_effective(inputs.rate, n),
+            })
+        }
+        RateKind::Apy => {
+            if inputs.rate <= -1.0 {
+                return Err("The APY must be greater than -100%".to_string());
+            }
+            Ok(AprApyOutputs {
+                apr: effective_to_nominal(inputs.rate, n),
+                apy: inputs.rate,
+            })
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use crate::analytics::use_track_calculator;
+use crate::apr_apy::{convert_rate, AprApyInputs, RateKind};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::compounding::Compounding;
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::{CompoundingSelect, COMPOUNDING_OPTIONS};
+use crate::glossary_component::Term;
+use crate::math_component::MathFormula;
+use crate::numeric_input_component::NumericInput;
+use dioxus::prelude::*;
+
+/// 5% APR compounded monthly
+const DEFAULT_INPUTS: AprApyInputs = AprApyInputs {
+    rate: 0.05,
+    entered: RateKind::Apr,
+    compounding: Compounding::Monthly,
+};
+
+const DIRECTION_OPTIONS: &[(RateKind, &str, &str)] = &[
+    (RateKind::Apr, "apr", "APR to APY"),
+    (RateKind::Apy, "apy", "APY to APR"),
+];
+
+#[component]
+pub fn AprApyUI() -> Element {
+    let mut rate_signal = use_signal(|| 5.0 as f64);
+    let mut rate_input = use_signal(|| "5.0".to_string());
+    let rate_input_valid = use_signal(|| true);
+    let mut entered_signal = use_signal(|| RateKind::Apr);
+    let mut compounding_signal = use_signal(|| Compounding::Monthly);
+
+    let inputs = move || AprApyInputs {
+        rate: rate_signal() / 100.0,
+        entered: entered_signal(),
+        compounding: compounding_signal(),
+    };
+    let start_fresh = use_autosave(
+        "apr_apy",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: AprApyInputs| {
+            rate_signal.set(inputs.rate * 100.0);
+            rate_input.set(format!("{}", inputs.rate * 100.0));
+            entered_signal.set(inputs.entered);
+            compounding_signal.set(inputs.compounding);
+        }),
+    );
+    use_track_calculator("apr_apy");
+
+    let (entered_name, computed_name) = match entered_signal() {
+        RateKind::Apr => ("APR", "APY"),
+        RateKind::Apy => ("APY", "APR"),
+    };
+    let periods_string = compounding_signal().to_string().to_ascii_lowercase();
+    let result = convert_rate(&inputs()).map(|outputs| match entered_signal() {
+        RateKind::Apr => outputs.apy,
+        RateKind::Apy => outputs.apr,
+    });
+    // The same entered rate at every compounding frequency, to show how much the frequency matters
+    let comparison: Vec<(&str, Option<f64>)> = COMPOUNDING_OPTIONS
+        .iter()
+        .map(|(compounding, _, display)| {
+            let outputs = convert_rate(&AprApyInputs {
+                compounding: *compounding,
+                ..inputs()
+            });
+            let rate = outputs.ok().map(|outputs| match entered_signal() {
+                RateKind::Apr => outputs.apy,
+                RateKind::Apy => outputs.apr,
+            });
+            (*display, rate)
+        })
+        .collect();
+
+    rsx! {
+        hr {}
+        br {}
+
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Convert:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(entered, _, _)) = DIRECTION_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        entered_signal.set(entered);
+                    }
+                },
+                for (entered , value , display) in DIRECTION_OPTIONS.iter() {
+                    option { value: *value, selected: entered_signal() == *entered, {*display} }
+                }
+            }
+        }
+        CompoundingSelect { compounding: compounding_signal }
+        NumericInput {
+            label: format!("{} (%):", entered_name),
+            placeholder: format!("Enter the {} (e.g., 5.0)", entered_name),
+            input_signal: rate_input,
+            value_signal: rate_signal,
+            valid_signal: rate_input_valid,
+            field_name: entered_name.to_string(),
+            css_prefix: "rate".to_string(),
+            allow_negative: true,
+            step: Some(0.125),
+        }
+
+        div {
+            id: "AprApyCalculationConfig",
+            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+            "The {computed_name} for a {rate_signal():.3}% {entered_name} with compounding {periods_string}: "
+        }
+        {
+            match result {
+                Ok(rate) => rsx! {
+                    div {
+                        id: "AprApyCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        "{computed_name}: {rate * 100.0:.3}%*"
+                    }
+                },
+                Err(error) => rsx! {
+                    div {
+                        id: "AprApyCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        table { style: "color: #333; border-collapse: collapse; margin-bottom: 15px;",
+            thead {
+                tr {
+                    th { style: "text-align: left; padding: 2px 10px 2px 0;", "Compounding" }
+                    th { style: "text-align: right; padding: 2px 0;", "{computed_name}" }
+                }
+            }
+            tbody {
+                for (display , rate) in comparison {
+                    tr {
+                        td { style: "padding: 2px 10px 2px 0;", {display} }
+                        td { style: "text-align: right; padding: 2px 0; font-family: monospace;",
+                            {rate.map_or("—".to_string(), |rate| format!("{:.3}%", rate * 100.0))}
+                        }
+                    }
+                }
+            }
+        }
+
+        details { style: "color: #333; margin-bottom: 15px;",
+            summary { style: "cursor: pointer; font-weight: bold;", "Show the math" }
+            div { style: "margin-top: 10px;",
+                if compounding_signal().is_continuous() {
+                    MathFormula { tex: r"APY = e^{r} - 1".to_string(), display: true }
+                    MathFormula { tex: r"r = \ln(1 + APY)".to_string(), display: true }
+                } else {
+                    MathFormula {
+                        tex: r"APY = \left(1 + \frac{r}{n}\right)^{n} - 1".to_string(),
+                        display: true,
+                    }
+                    MathFormula {
+                        tex: r"r = n\left((1 + APY)^{1/n} - 1\right)".to_string(),
+                        display: true,
+                    }
+                }
+                p { style: "font-size: 12px;",
+                    "r = "
+                    Term { term: "apr", "APR" }
+                    ", "
+                    Term { term: "apy", "APY" }
+                    " = effective annual yield, n = compounding periods per year"
+                }
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "apr_apy" }
+        AssumptionsFooter { calculator: "apr_apy" }
+        div { style: "font-size: 10px",
+            p {
+                "*Rates are shown to three decimal places; lenders and banks may round differently."
+            }
+            br {}
+            br {}
+        }
+    }
+}
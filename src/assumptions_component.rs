@@ -0,0 +1,24 @@
+use crate::calculators::{find_calculator, DISCLAIMERS};
+use dioxus::prelude::*;
+
+/// "Assumptions & disclaimers" list for a calculator, read from its registry entry
+#[component]
+pub fn AssumptionsFooter(calculator: &'static str) -> Element {
+    let assumptions = find_calculator(calculator)
+        .map(|calculator| calculator.assumptions)
+        .unwrap_or_default();
+
+    rsx! {
+        div { style: "color: #333; font-size: 12px; margin-bottom: 15px;",
+            div { style: "font-weight: bold;", "Assumptions & disclaimers" }
+            ul {
+                for assumption in assumptions.iter() {
+                    li { {*assumption} }
+                }
+                for disclaimer in DISCLAIMERS.iter() {
+                    li { {*disclaimer} }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use crate::compounding::{growth_factor, ContributionTiming};
+use crate::future_value::{compute_future_value, FutureValueInputs, CONTRIBUTIONS_PER_YEAR};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Version of the app that produced a record
+pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Version of the calculation rules. Bump it whenever a formula, rounding rule, or numeric type
+/// changes, so old audit records can be checked against the rules that produced them.
+pub const MATH_CORE_VERSION: &str = "1.4.0";
+
+/// A named value computed on the way to a result
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditValue {
+    pub name: &'static str,
+    pub value: f64,
+}
+
+/// Everything needed to verify one result independently: the inputs, the formulas, and each
+/// intermediate value in the order it was computed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditRecord {
+    pub app_version: &'static str,
+    pub math_core_version: &'static str,
+    /// Calculator slug, e.g., "future_value"
+    pub calculator: &'static str,
+    /// Which result this record explains, e.g., "future_value" or "required_rate"
+    pub result_name: &'static str,
+    /// The calculator's input struct, serialized
+    pub inputs: Value,
+    /// Formulas used, in TeX
+    pub formulas: Vec<&'static str>,
+    pub intermediate_values: Vec<AuditValue>,
+    /// The result as shown, or `None` if it could not be computed
+    pub result: Option<f64>,
+}
+
+impl AuditRecord {
+    fn new<T: Serialize>(calculator: &'static str, result_name: &'static str, inputs: &T) -> Self {
+        AuditRecord {
+            app_version: APP_VERSION,
+            math_core_version: MATH_CORE_VERSION,
+            calculator,
+            result_name,
+            inputs: serde_json::to_value(inputs).unwrap_or(Value::Null),
+            formulas: vec![],
+            intermediate_values: vec![],
+            result: None,
+        }
+    }
+
+    fn value(mut self, name: &'static str, value: f64) -> Self {
+        self.intermediate_values.push(AuditValue { name, value });
+        self
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// The rate per contribution formula, which has a simpler form under continuous compounding
+fn contribution_rate_formula(inputs: &FutureValueInputs) -> &'static str {
+    if inputs.compounding.is_continuous() {
+        r"i = e^{r/m} - 1"
+    } else {
+        r"i = \left(1 + \frac{r}{n}\right)^{n/m} - 1"
+    }
+}
+
+/// Starts a record with n, the periods per year, which continuous compounding leaves out since
+/// it has no finite n
+fn compounding_record(inputs: &FutureValueInputs, result_name: &'static str) -> AuditRecord {
+    let record = AuditRecord::new("future_value", result_name, inputs);
+    if inputs.compounding.is_continuous() {
+        record
+    } else {
+        record.value(
+            "n (periods per year)",
+            inputs.compounding.periods_per_year(),
+        )
+    }
+}
+
+/// Audit record for the future value: FV = P(1 + r/n)^(nt), or Pe^(rt) when compounding
+/// continuously, plus the monthly contributions, truncated to the cent
+pub fn audit_future_value(inputs: &FutureValueInputs) -> AuditRecord {
+    let n = inputs.compounding.periods_per_year();
+    let growth = growth_factor(inputs.annual_rate, n, inputs.years);
+
+    let mut record = compounding_record(inputs, "future_value");
+    if inputs.compounding.is_continuous() {
+        record = record
+            .value("rt", inputs.annual_rate * inputs.years)
+            .value("e^(rt) (growth factor)", growth);
+        record.formulas = vec![r"FV = Pe^{rt}"];
+    } else {
+        record = record
+            .value("r / n (rate per period)", inputs.annual_rate / n)
+            .value("nt (number of periods)", n * inputs.years)
+            .value("(1 + r/n)^(nt) (growth factor)", growth);
+        record.formulas = vec![r"FV = P\left(1 + \frac{r}{n}\right)^{nt}"];
+    }
+    if inputs.monthly_contribution == 0.0 {
+        let name = if inputs.compounding.is_continuous() {
+            "Pe^(rt) (before truncation)"
+        } else {
+            "P(1 + r/n)^(nt) (before truncation)"
+        };
+        record = record.value(name, inputs.principal * growth);
+    } else {
+        let m = CONTRIBUTIONS_PER_YEAR;
+        let contribution_rate = growth_factor(inputs.annual_rate, n, 1.0 / m) - 1.0;
+        let n_contributions = m * inputs.years;
+        let mut contributions = if contribution_rate == 0.0 {
+            inputs.monthly_contribution * n_contributions
+        } else {
+            inputs.monthly_contribution * ((1.0 + contribution_rate).powf(n_contributions) - 1.0)
+                / contribution_rate
+        };
+        if inputs.contribution_timing == ContributionTiming::Begin {
+            contributions *= 1.0 + contribution_rate;
+        }
+        record = record
+            .value("m (contributions per year)", m)
+            .value("i (rate per contribution)", contribution_rate)
+            .value("mt (number of contributions)", n_contributions)
+            .value("value of the contributions", contributions)
+            .value(
+                "FV (before truncation)",
+                inputs.principal * growth + contributions,
+            );
+        record.formulas.push(contribution_rate_formula(inputs));
+        record.formulas.push(match inputs.contribution_timing {
+            ContributionTiming::End => r"FV_{PMT} = PMT \frac{(1 + i)^{mt} - 1}{i}",
+            ContributionTiming::Begin => r"FV_{PMT} = PMT \frac{(1 + i)^{mt} - 1}{i}(1 + i)",
+        });
+    }
+    record.result = compute_future_value(inputs).future_value.ok();
+    record
+}
+
+/// Audit record for the annual rate needed to reach the target
+pub fn audit_required_rate(inputs: &FutureValueInputs) -> AuditRecord {
+    let n = inputs.compounding.periods_per_year();
+    let continuous = inputs.compounding.is_continuous();
+    let mut record = compounding_record(inputs, "required_rate");
+
+    if inputs.monthly_contribution > 0.0 {
+        // No closed form with deposits: the rate is found by bisection on the future value
+        record = record
+            .value("PMT (monthly contribution)", inputs.monthly_contribution)
+            .value("m (contributions per year)", CONTRIBUTIONS_PER_YEAR);
+        record.formulas = vec![
+            if continuous {
+                r"Pe^{rt} + PMT \frac{(1 + i)^{mt} - 1}{i} = FV"
+            } else {
+                r"P\left(1 + \frac{r}{n}\right)^{nt} + PMT \frac{(1 + i)^{mt} - 1}{i} = FV"
+            },
+            contribution_rate_formula(inputs),
+        ];
+    } else if continuous {
+        let growth = inputs.target_value / inputs.principal;
+        record = record
+            .value("FV / P (total growth)", growth)
+            .value("ln(FV / P)", growth.ln());
+        record.formulas = vec![r"r = \frac{\ln(FV / P)}{t}"];
+    } else {
+        let growth = inputs.target_value / inputs.principal;
+        let exponent = 1.0 / (n * inputs.years);
+        let periodic_growth = growth.powf(exponent);
+        record = record
+            .value("FV / P (total growth)", growth)
+            .value("1 / (nt)", exponent)
+            .value("(FV / P)^(1/(nt)) (growth per period)", periodic_growth)
+            .value("rate per period", periodic_growth - 1.0);
+        record.formulas = vec![r"r = n\left(\left(\frac{FV}{P}\right)^{1 / (nt)} - 1\right)"];
+    }
+    record.result = Some(compute_future_value(inputs).required_rate).filter(|r| r.is_finite());
+    record
+}
+
+/// Audit record for the years needed to reach the target
+pub fn audit_required_years(inputs: &FutureValueInputs) -> AuditRecord {
+    let n = inputs.compounding.periods_per_year();
+    let mut record = compounding_record(inputs, "required_years");
+
+    if inputs.monthly_contribution > 0.0 {
+        let m = CONTRIBUTIONS_PER_YEAR;
+        let contribution_rate = growth_factor(inputs.annual_rate, n, 1.0 / m) - 1.0;
+        let mut payment = inputs.monthly_contribution;
+        if inputs.contribution_timing == ContributionTiming::Begin {
+            payment *= 1.0 + contribution_rate;
+        }
+        record = record
+            .value("m (contributions per year)", m)
+            .value("i (rate per contribution)", contribution_rate);
+        record.formulas.push(contribution_rate_formula(inputs));
+        if contribution_rate == 0.0 {
+            record.formulas.push(r"t = \frac{FV - P}{m \cdot PMT}");
+        } else {
+            let k = payment / contribution_rate;
+            record = record.value("k", k).value(
+                "(FV + k) / (P + k)",
+                (inputs.target_value + k) / (inputs.principal + k),
+            );
+            record.formulas.push(match inputs.contribution_timing {
+                ContributionTiming::End => r"k = \frac{PMT}{i}",
+                ContributionTiming::Begin => r"k = \frac{PMT(1 + i)}{i}",
+            });
+            record
+                .formulas
+                .push(r"t = \frac{\ln\left(\frac{FV + k}{P + k}\right)}{m \ln(1 + i)}");
+        }
+    } else {
+        let growth = inputs.target_value / inputs.principal;
+        record = record
+            .value("FV / P (total growth)", growth)
+            .value("ln(FV / P)", growth.ln());
+        if inputs.compounding.is_continuous() {
+            record.formulas = vec![r"t = \frac{\ln(FV / P)}{r}"];
+        } else {
+            record = record.value("ln(1 + r/n)", (1.0 + inputs.annual_rate / n).ln());
+            record.formulas = vec![r"t = \frac{\ln(FV / P)}{n \ln\left(1 + \frac{r}{n}\right)}"];
+        }
+    }
+    record.result = Some(compute_future_value(inputs).required_years).filter(|t| t.is_finite());
+    record
+}
@@ -0,0 +1,18 @@
+use crate::audit::AuditRecord;
+use crate::scenario_report::download_file;
+use dioxus::prelude::*;
+
+/// Button that downloads the audit record behind a result as JSON
+#[component]
+pub fn AuditExportButton(record: AuditRecord) -> Element {
+    let file_name = format!("{}-{}-audit.json", record.calculator, record.result_name);
+    let json = record.to_json();
+
+    rsx! {
+        button {
+            style: "padding: 6px 12px; border-radius: 4px; margin-bottom: 15px;",
+            onclick: move |_| download_file(&file_name, "application/json", &json),
+            "Export Audit Record"
+        }
+    }
+}
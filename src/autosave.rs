@@ -0,0 +1,85 @@
+use crate::storage::{load_item, remove_item, save_item};
+use dioxus::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Delay after the last change before inputs are written to storage
+const AUTOSAVE_DELAY_MS: u32 = 500;
+
+fn autosave_key(calculator: &str) -> String {
+    format!("fintools.autosave.{}", calculator)
+}
+
+/// Waits `ms` milliseconds using the page's timer, which works on both web and desktop
+async fn sleep_ms(ms: u32) {
+    let js = format!(
+        "await new Promise(resolve => setTimeout(resolve, {})); return true;",
+        ms
+    );
+    let _ = document::eval(&js).join::<bool>().await;
+}
+
+/// Autosaves a calculator's inputs and restores them when the user comes back.
+///
+/// `snapshot` reads the calculator's input signals into a serializable struct; it is re-run
+/// whenever those signals change and the result is saved once typing pauses. On mount, any
+/// saved inputs are passed to `restore`, which should write them back into the signals.
+///
+/// Returns a "start fresh" callback that forgets the saved inputs and restores `defaults`.
+pub fn use_autosave<T>(
+    calculator: &'static str,
+    defaults: T,
+    snapshot: impl Fn() -> T + 'static,
+    restore: Callback<T>,
+) -> Callback<()>
+where
+    T: Serialize + DeserializeOwned + Clone + 'static,
+{
+    let mut restored = use_signal(|| false);
+    let mut generation = use_signal(|| 0u64);
+
+    use_future(move || async move {
+        if let Some(json) = load_item(&autosave_key(calculator)).await {
+            if let Ok(saved) = serde_json::from_str::<T>(&json) {
+                restore.call(saved);
+            }
+        }
+        restored.set(true);
+    });
+
+    use_effect(move || {
+        let json = serde_json::to_string(&snapshot());
+        // Don't overwrite saved inputs with the defaults before they've been restored.
+        if !restored() {
+            return;
+        }
+        let Ok(json) = json else {
+            return;
+        };
+        let this_change = *generation.peek() + 1;
+        generation.set(this_change);
+        spawn(async move {
+            sleep_ms(AUTOSAVE_DELAY_MS).await;
+            if *generation.peek() == this_change {
+                save_item(&autosave_key(calculator), &json);
+            }
+        });
+    });
+
+    use_callback(move |_: ()| {
+        remove_item(&autosave_key(calculator));
+        restore.call(defaults.clone());
+    })
+}
+
+/// Button that clears a calculator's autosaved inputs
+#[component]
+pub fn StartFreshButton(start_fresh: Callback<()>) -> Element {
+    rsx! {
+        button {
+            style: "padding: 6px 12px; border-radius: 4px; margin-bottom: 15px;",
+            onclick: move |_| start_fresh.call(()),
+            "Start Fresh"
+        }
+    }
+}
@@ -1,22 +1,150 @@
+use crate::glossary::link_glossary_terms;
+use crate::markdown::render_markdown;
 use crate::Route;
 use dioxus::prelude::*;
 
+/// A blog post. `legacy_id` is the number the post had under the old `/blog/:id` URLs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlogPost {
+    pub slug: &'static str,
+    pub legacy_id: i32,
+    pub title: &'static str,
+    /// Post body in Markdown
+    pub body: &'static str,
+}
+
+/// All posts, oldest first
+pub const POSTS: &[BlogPost] = &[
+    BlogPost {
+        slug: "how-compound-interest-works",
+        legacy_id: 1,
+        title: "How compound interest works",
+        body: "The [[future value]] calculator answers a simple question: if you invest an amount today at a fixed rate, what will it be worth later?
+
+## Compounding frequency
+
+The answer depends on how often interest is [[compounding|compounded]]. Each time interest is added, the next period's interest is earned on a slightly larger balance, so monthly compounding grows faster than annual compounding at the same stated rate.
+
+## The formula
+
+$$FV = P\\left(1 + \\frac{r}{n}\\right)^{nt}$$
+
+where $P$ is the [[principal]], $r$ the annual rate, $n$ the number of compounding periods per year, and $t$ the number of years.",
+    },
+    BlogPost {
+        slug: "sequence-of-returns-risk",
+        legacy_id: 2,
+        title: "Why the order of returns matters in retirement",
+        body: "Two retirees can earn exactly the same returns, in a different order, and end up in very different places.
+
+## Saving vs. withdrawing
+
+While you are saving, the order of returns doesn't change your ending balance. Once you start withdrawing, it does — this is [[sequence of returns risk]]: selling investments after a crash locks in losses, and the money withdrawn is no longer there to recover.
+
+## Try it
+
+The sequence risk demo shows this side by side.",
+    },
+];
+
+pub fn find_post(slug: &str) -> Option<(usize, &'static BlogPost)> {
+    POSTS.iter().enumerate().find(|(_, post)| post.slug == slug)
+}
+
+/// Blog post index
+#[component]
+pub fn BlogIndex() -> Element {
+    rsx! {
+        div { id: "blog",
+            h1 { "Blog" }
+            for post in POSTS.iter().rev() {
+                p {
+                    Link {
+                        to: Route::Blog {
+                            slug: post.slug.to_string(),
+                        },
+                        {post.title}
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Blog page
 #[component]
-pub fn Blog(id: i32) -> Element {
+pub fn Blog(slug: String) -> Element {
+    let navigator = use_navigator();
+
+    // Old links used numeric ids (`/blog/1`); send them to the post's slug URL.
+    let legacy_post = slug
+        .parse::<i32>()
+        .ok()
+        .and_then(|id| POSTS.iter().find(|post| post.legacy_id == id));
+    use_effect(use_reactive!(|legacy_post| {
+        if let Some(post) = legacy_post {
+            navigator.replace(Route::Blog {
+                slug: post.slug.to_string(),
+            });
+        }
+    }));
+    if legacy_post.is_some() {
+        return rsx! {};
+    }
+
+    let Some((index, post)) = find_post(&slug) else {
+        return rsx! {
+            div { id: "blog",
+                h1 { "Post not found" }
+                Link { to: Route::BlogIndex {}, "All posts" }
+            }
+        };
+    };
+    let rendered = render_markdown(&link_glossary_terms(post.body));
+    let reading_time = rendered.reading_time_minutes();
+    let previous = index.checked_sub(1).map(|i| &POSTS[i]);
+    let next = POSTS.get(index + 1);
+
     rsx! {
         div { id: "blog",
 
             // Content
-            h1 { "This is blog #{id}!" }
-            p {
-                "In blog #{id}, we show how the Dioxus router works and how URL parameters can be passed as props to our route components."
+            h1 { {post.title} }
+            p { style: "font-size: 12px;", "{reading_time} min read" }
+            if rendered.toc.len() > 1 {
+                nav {
+                    strong { "Contents" }
+                    ul {
+                        for entry in rendered.toc.iter() {
+                            li { style: "margin-left: {(entry.level.saturating_sub(2)) * 15}px;",
+                                a { href: "#{entry.anchor}", {entry.title.clone()} }
+                            }
+                        }
+                    }
+                }
             }
+            div { dangerous_inner_html: rendered.html.clone() }
 
             // Navigation links
-            Link { to: Route::Blog { id: id - 1 }, "Previous" }
+            if let Some(previous) = previous {
+                Link {
+                    to: Route::Blog {
+                        slug: previous.slug.to_string(),
+                    },
+                    "Previous"
+                }
+            }
+            span { " <---> " }
+            Link { to: Route::BlogIndex {}, "All posts" }
             span { " <---> " }
-            Link { to: Route::Blog { id: id + 1 }, "Next" }
+            if let Some(next) = next {
+                Link {
+                    to: Route::Blog {
+                        slug: next.slug.to_string(),
+                    },
+                    "Next"
+                }
+            }
         }
     }
 }
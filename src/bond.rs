@@ -0,0 +1,347 @@
+use crate::fin_numeric::FinNumeric;
+use crate::rate_shock::{shock_table, RateShock};
+use serde::{Deserialize, Serialize};
+
+// Computes the price of a fixed-coupon bond on a coupon date.
+///
+/// # Parameters:
+/// - `face_value`: Amount repaid at maturity (F)
+/// - `coupon_rate`: Annual coupon rate (c), e.g., 0.05 for 5%
+/// - `yield_rate`: Annual yield to maturity (y)
+/// - `years`: Years to maturity (t)
+/// - `frequency`: Coupon payments per year (m)
+///
+/// # Formula:
+/// P = (F * c / m) * (1 - (1 + y/m)^-(mt)) / (y/m) + F * (1 + y/m)^-(mt)
+///
+/// # Returns:
+/// The price, not rounded.
+pub fn bond_price<T>(face_value: T, coupon_rate: T, yield_rate: T, years: T, frequency: T) -> T
+where
+    T: FinNumeric,
+{
+    let coupon = face_value * coupon_rate / frequency;
+    let periodic_yield = yield_rate / frequency;
+    let n_periods = frequency * years;
+    if periodic_yield == T::zero() {
+        return coupon * n_periods + face_value;
+    }
+
+    let discount = T::one() / (T::one() + periodic_yield).powf(n_periods);
+
+    coupon * (T::one() - discount) / periodic_yield + face_value * discount
+}
+
+// Computes the modified duration of a fixed-coupon bond: the percentage change in its dirty
+// price for a one-unit change in yield.
+///
+/// # Parameters:
+/// - `face_value`: Amount repaid at maturity (F)
+/// - `coupon_rate`: Annual coupon rate (c)
+/// - `yield_rate`: Annual yield to maturity (y)
+/// - `years`: Years to maturity (t); need not fall on a coupon date
+/// - `frequency`: Coupon payments per year (m)
+///
+/// # Formula:
+/// N = ceil(m * t) coupons remain, the next one w = m * t - (N - 1) periods away
+/// P = sum over k of CF_k / (1 + y/m)^(k - 1 + w), the dirty price as in `price_bond`
+/// D_mac = sum over k of ((k - 1 + w)/m) * CF_k / (1 + y/m)^(k - 1 + w) / P
+/// D_mod = D_mac / (1 + y/m)
+///
+/// # Returns:
+/// Modified duration in years.
+pub fn modified_duration<T>(
+    face_value: T,
+    coupon_rate: T,
+    yield_rate: T,
+    years: T,
+    frequency: T,
+) -> T
+where
+    T: FinNumeric,
+{
+    let coupon = face_value * coupon_rate / frequency;
+    let growth = T::one() + yield_rate / frequency;
+    let periods_to_maturity = frequency * years;
+    // Snap to a whole number of periods, as `price_bond` does
+    let whole = periods_to_maturity.round_dp(0);
+    let n_periods = if (periods_to_maturity - whole).abs() < T::from_f64(1e-9) {
+        whole
+    } else {
+        (periods_to_maturity + T::from_f64(0.5)).round_dp(0)
+    };
+    let n_periods = n_periods.to_f64() as i64;
+    let to_next_coupon = periods_to_maturity - T::from_i64(n_periods - 1);
+
+    let mut price = T::zero();
+    let mut weighted_time = T::zero();
+    for k in 1..=n_periods {
+        let periods = T::from_i64(k - 1) + to_next_coupon;
+        let cash_flow = if k == n_periods {
+            coupon + face_value
+        } else {
+            coupon
+        };
+        let present_value = cash_flow / growth.powf(periods);
+        price = price + present_value;
+        weighted_time = weighted_time + periods / frequency * present_value;
+    }
+
+    weighted_time / price / growth
+}
+
+/// Coupon payments per year for the bonds in a ladder (US Treasuries pay semiannually)
+pub const LADDER_FREQUENCY: f64 = 2.0;
+
+/// One bond in a ladder
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LadderBond {
+    pub face_value: f64,
+    /// Annual coupon rate as a decimal
+    pub coupon_rate: f64,
+    /// Years to maturity, rounded to whole coupon periods
+    pub years_to_maturity: f64,
+    /// Purchase price as a percent of face value, e.g., 98.5
+    pub price: f64,
+}
+
+impl LadderBond {
+    fn n_periods(&self) -> usize {
+        (self.years_to_maturity * LADDER_FREQUENCY).round().max(0.0) as usize
+    }
+
+    pub fn cost(&self) -> f64 {
+        self.face_value * self.price / 100.0
+    }
+
+    /// (coupon, principal) paid at the end of each coupon period
+    pub fn cash_flows(&self) -> Vec<(f64, f64)> {
+        let coupon = self.face_value * self.coupon_rate / LADDER_FREQUENCY;
+        let n_periods = self.n_periods();
+        (1..=n_periods)
+            .map(|k| {
+                let principal = if k == n_periods { self.face_value } else { 0.0 };
+                (coupon, principal)
+            })
+            .collect()
+    }
+}
+
+/// Cash received from a ladder in one year
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LadderYear {
+    /// Year from today, starting at 1
+    pub year: usize,
+    pub coupons: f64,
+    pub principal: f64,
+}
+
+/// Combined coupon and maturity cash flows of a ladder, bucketed by year
+pub fn ladder_calendar(bonds: &[LadderBond]) -> Vec<LadderYear> {
+    let periods_per_year = LADDER_FREQUENCY as usize;
+    let n_years = bonds
+        .iter()
+        .map(|bond| bond.n_periods().div_ceil(periods_per_year))
+        .max()
+        .unwrap_or(0);
+    let mut calendar: Vec<LadderYear> = (1..=n_years)
+        .map(|year| LadderYear {
+            year,
+            coupons: 0.0,
+            principal: 0.0,
+        })
+        .collect();
+
+    for bond in bonds {
+        for (i, (coupon, principal)) in bond.cash_flows().into_iter().enumerate() {
+            let year = &mut calendar[i / periods_per_year];
+            year.coupons += coupon;
+            year.principal += principal;
+        }
+    }
+    calendar
+}
+
+// Computes the yield of a whole ladder: the single rate that discounts every coupon and
+// maturity payment back to the total purchase cost (internal rate of return).
+///
+/// # Parameters:
+/// - `bonds`: The bonds in the ladder
+///
+/// # Formula:
+/// sum over bonds and periods k of CF_k / (1 + y/m)^k = sum of purchase costs
+///
+/// # Returns:
+/// Annual yield as a decimal (bond-equivalent, compounded `LADDER_FREQUENCY` times a year),
+/// or `None` if the ladder has no cash flows or the yield is outside -50% to 100%.
+pub fn ladder_yield(bonds: &[LadderBond]) -> Option<f64> {
+    let cost: f64 = bonds.iter().map(LadderBond::cost).sum();
+    let present_value = |annual_yield: f64| -> f64 {
+        let growth = 1.0 + annual_yield / LADDER_FREQUENCY;
+        bonds
+            .iter()
+            .flat_map(|bond| bond.cash_flows().into_iter().enumerate())
+            .map(|(i, (coupon, principal))| (coupon + principal) / growth.powi(i as i32 + 1))
+            .sum()
+    };
+    if cost <= 0.0 || bonds.iter().all(|bond| bond.n_periods() == 0) {
+        return None;
+    }
+
+    // Present value falls as the yield rises, so bisect for the yield that matches the cost
+    let (mut low, mut high) = (-0.5, 1.0);
+    if present_value(low) < cost || present_value(high) > cost {
+        return None;
+    }
+    for _ in 0..100 {
+        let mid = (low + high) / 2.0;
+        if present_value(mid) > cost {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Some((low + high) / 2.0)
+}
+
+/// Longest maturity the bond price calculator lists payments for
+pub const MAX_PRICING_YEARS: f64 = 100.0;
+
+/// Inputs to the bond price calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BondPriceInputs {
+    pub face_value: f64,
+    /// Annual coupon rate as a decimal, e.g., 0.05 for 5%
+    pub coupon_rate: f64,
+    /// Coupon payments per year, e.g., 2 for semiannual
+    pub frequency: f64,
+    /// Annual yield to maturity as a decimal, compounded `frequency` times a year
+    pub yield_rate: f64,
+    /// Years from settlement to maturity; need not fall on a coupon date
+    pub years_to_maturity: f64,
+}
+
+/// One remaining payment of a bond
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BondCashFlow {
+    /// 1-based number of the payment from settlement
+    pub period: usize,
+    /// Years from settlement until the payment
+    pub years: f64,
+    pub coupon: f64,
+    pub principal: f64,
+    /// The payment discounted to settlement at the yield
+    pub present_value: f64,
+}
+
+/// A bond's price on its settlement date, with the payments behind it
+#[derive(Debug, Clone, PartialEq)]
+pub struct BondPricing {
+    /// Quoted price, without accrued interest
+    pub clean_price: f64,
+    /// Coupon interest earned by the seller since the last coupon date
+    pub accrued_interest: f64,
+    /// Price the buyer pays: clean price plus accrued interest
+    pub dirty_price: f64,
+    pub cash_flows: Vec<BondCashFlow>,
+}
+
+// Prices a fixed-coupon bond between coupon dates.
+///
+/// # Parameters:
+/// - `inputs`: Face value (F), coupon rate (c), payments per year (m), yield (y), and years
+///   to maturity (t)
+///
+/// # Formula:
+/// N = ceil(m * t) coupons remain, the next one w = m * t - (N - 1) periods away
+/// Dirty price = sum over k of CF_k / (1 + y/m)^(k - 1 + w)
+/// Accrued interest = (F * c / m) * (1 - w)
+/// Clean price = dirty price - accrued interest
+///
+/// # Returns:
+/// The prices, not rounded, and every remaining payment, or why the bond can't be priced.
+pub fn price_bond(inputs: &BondPriceInputs) -> Result<BondPricing, String> {
+    if inputs.frequency.is_nan() || inputs.frequency <= 0.0 {
+        return Err("Coupon payments per year must be greater than zero".to_string());
+    }
+    if inputs.years_to_maturity.is_nan() || inputs.years_to_maturity <= 0.0 {
+        return Err("The time to maturity must be greater than zero".to_string());
+    }
+    if inputs.years_to_maturity > MAX_PRICING_YEARS {
+        return Err(format!(
+            "Enter a maturity of {} years or less",
+            MAX_PRICING_YEARS
+        ));
+    }
+    let periodic_yield = inputs.yield_rate / inputs.frequency;
+    if periodic_yield.is_nan() || periodic_yield <= -1.0 {
+        return Err("The yield per period must be greater than -100%".to_string());
+    }
+    let coupon = inputs.face_value * inputs.coupon_rate / inputs.frequency;
+    let periods_to_maturity = inputs.years_to_maturity * inputs.frequency;
+    // Snap to a whole number of periods so rounding in the input doesn't add a tiny coupon
+    let n_periods = if (periods_to_maturity - periods_to_maturity.round()).abs() < 1e-9 {
+        periods_to_maturity.round()
+    } else {
+        periods_to_maturity.ceil()
+    };
+    let to_next_coupon = periods_to_maturity - (n_periods - 1.0);
+
+    let cash_flows: Vec<BondCashFlow> = (1..=n_periods as usize)
+        .map(|period| {
+            let periods = period as f64 - 1.0 + to_next_coupon;
+            let principal = if period == n_periods as usize {
+                inputs.face_value
+            } else {
+                0.0
+            };
+            BondCashFlow {
+                period,
+                years: periods / inputs.frequency,
+                coupon,
+                principal,
+                present_value: (coupon + principal) / (1.0 + periodic_yield).powf(periods),
+            }
+        })
+        .collect();
+    let dirty_price: f64 = cash_flows.iter().map(|flow| flow.present_value).sum();
+    let accrued_interest = coupon * (1.0 - to_next_coupon);
+    if !dirty_price.is_finite() {
+        return Err("The price is too large to compute; try a higher yield".to_string());
+    }
+
+    Ok(BondPricing {
+        clean_price: dirty_price - accrued_interest,
+        accrued_interest,
+        dirty_price,
+        cash_flows,
+    })
+}
+
+/// Dirty price after each parallel yield shift, estimated from modified duration and repriced
+/// exactly. Accrued interest doesn't move with the yield, so the clean price changes as much.
+pub fn bond_price_shocks(
+    inputs: &BondPriceInputs,
+    shifts_bp: &[i32],
+) -> Result<Vec<RateShock>, String> {
+    price_bond(inputs)?;
+    let duration = modified_duration(
+        inputs.face_value,
+        inputs.coupon_rate,
+        inputs.yield_rate,
+        inputs.years_to_maturity,
+        inputs.frequency,
+    );
+    Ok(shock_table(
+        |yield_rate| {
+            price_bond(&BondPriceInputs {
+                yield_rate,
+                ..*inputs
+            })
+            .map_or(f64::NAN, |pricing| pricing.dirty_price)
+        },
+        inputs.yield_rate,
+        -duration,
+        shifts_bp,
+    ))
+}
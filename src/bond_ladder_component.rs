@@ -0,0 +1,307 @@
+use crate::analytics::use_track_calculator;
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::bond::{ladder_calendar, ladder_yield, LadderBond};
+use crate::chart::{ChartSeries, StackedBarChart};
+use crate::error_summary_component::{ErrorSummary, FieldError};
+use crate::feedback_component::FeedbackWidget;
+use crate::table_paste::parse_pasted_table;
+use crate::table_paste_component::TablePasteSection;
+use dioxus::prelude::*;
+use num_format::{Locale, ToFormattedString};
+
+const COUPON_COLOR: &str = "#2471a3";
+const PRINCIPAL_COLOR: &str = "#1e8449";
+
+/// Column order of the ladder table, which is also the order pasted ranges are read in
+const LADDER_COLUMNS: &[&str] = &[
+    "Face ($)",
+    "Coupon (%)",
+    "Maturity (years)",
+    "Price (% of face)",
+];
+
+/// A five-rung ladder of $10,000 bonds maturing one year apart, bought at par
+fn default_ladder() -> Vec<LadderBond> {
+    (1..=5)
+        .map(|year| LadderBond {
+            face_value: 10_000.0,
+            coupon_rate: 0.04 + 0.001 * year as f64,
+            years_to_maturity: year as f64,
+            price: 100.0,
+        })
+        .collect()
+}
+
+/// Parses a table cell, accepting zero (e.g., a zero-coupon bond) but not negatives
+fn parse_cell(text: &str) -> Option<f64> {
+    text.replace(",", "")
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite() && *value >= 0.0)
+}
+
+/// Id of a ladder cell, which the error summary links to
+fn cell_id(row_id: u64, column: usize) -> String {
+    format!("ladder-{}-{}", row_id, column)
+}
+
+/// Editable number cell; `on_value` is called with every valid entry, `on_valid` with whether
+/// each entry is valid
+#[component]
+fn LadderCell(
+    id: String,
+    label: String,
+    initial: f64,
+    on_value: EventHandler<f64>,
+    on_valid: EventHandler<bool>,
+) -> Element {
+    let mut text = use_signal(|| format!("{}", initial));
+    let valid = parse_cell(&text()).is_some();
+
+    rsx! {
+        td { style: "padding: 2px 5px;",
+            input {
+                id,
+                value: "{text}",
+                "aria-label": "{label}",
+                "aria-invalid": "{!valid}",
+                style: if valid { "padding: 4px 6px; width: 90px; border-radius: 4px; font-family: monospace; border: 1px solid #ccc;" } else { "padding: 4px 6px; width: 90px; border-radius: 4px; font-family: monospace; border: 2px solid #ff0000; background-color: #ffe6e6; color: #333;" },
+                oninput: move |event| {
+                    text.set(event.value());
+                    let value = parse_cell(&event.value());
+                    on_valid.call(value.is_some());
+                    if let Some(value) = value {
+                        on_value.call(value);
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+pub fn BondLadderUI() -> Element {
+    // Each row keeps a stable id so its cells keep their text when other rows are removed
+    let mut bonds = use_signal(|| {
+        default_ladder()
+            .into_iter()
+            .enumerate()
+            .map(|(i, bond)| (i as u64, bond))
+            .collect::<Vec<_>>()
+    });
+    let mut next_id = use_signal(|| 5u64);
+    let hover_index = use_signal(|| None::<usize>);
+    // Cells, as (row id, column), whose text isn't a valid number
+    let mut invalid_cells = use_signal(Vec::<(u64, usize)>::new);
+    let start_fresh = use_autosave(
+        "bond_ladder",
+        default_ladder(),
+        move || bonds().iter().map(|(_, bond)| *bond).collect::<Vec<_>>(),
+        use_callback(move |saved: Vec<LadderBond>| {
+            // New ids remount the rows so their cells show the restored values
+            let first_id = *next_id.peek();
+            next_id.set(first_id + saved.len() as u64);
+            invalid_cells.set(vec![]);
+            bonds.set(
+                saved
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, bond)| (first_id + i as u64, bond))
+                    .collect(),
+            );
+        }),
+    );
+    use_track_calculator("bond_ladder");
+
+    let mut update = move |id: u64, change: fn(&mut LadderBond, f64), value: f64| {
+        if let Some((_, bond)) = bonds.write().iter_mut().find(|(row_id, _)| *row_id == id) {
+            change(bond, value);
+        }
+    };
+    let mut set_valid = move |cell: (u64, usize), valid: bool| {
+        let mut invalid = invalid_cells.write();
+        invalid.retain(|invalid_cell| *invalid_cell != cell);
+        if !valid {
+            invalid.push(cell);
+        }
+    };
+    // Listed in table order, row by row
+    let invalid = invalid_cells();
+    let mut errors = vec![];
+    for (row, (id, _)) in bonds().iter().enumerate() {
+        for (column, name) in LADDER_COLUMNS.iter().enumerate() {
+            if invalid.contains(&(*id, column)) {
+                errors.push(FieldError {
+                    field_id: cell_id(*id, column),
+                    message: format!("Row {}, {}: enter a number of zero or more", row + 1, name),
+                });
+            }
+        }
+    }
+    let ladder: Vec<LadderBond> = bonds().iter().map(|(_, bond)| *bond).collect();
+    let calendar = ladder_calendar(&ladder);
+    let total_cost: f64 = ladder.iter().map(LadderBond::cost).sum();
+    let total_received: f64 = calendar.iter().map(|y| y.coupons + y.principal).sum();
+    let portfolio_yield = ladder_yield(&ladder);
+    let total_cost = (total_cost as i64).to_formatted_string(&Locale::en);
+    let total_received = (total_received as i64).to_formatted_string(&Locale::en);
+
+    rsx! {
+        hr {}
+        br {}
+
+        ErrorSummary { errors }
+
+        table { style: "color: #333; border-collapse: collapse; margin-bottom: 15px;",
+            thead {
+                tr {
+                    for column in LADDER_COLUMNS.iter() {
+                        th { style: "text-align: left; padding: 2px 5px;", {*column} }
+                    }
+                    th {}
+                }
+            }
+            tbody {
+                for (row , (id , bond)) in bonds().into_iter().enumerate() {
+                    tr { key: "{id}",
+                        LadderCell {
+                            id: cell_id(id, 0),
+                            label: format!("Row {} {}", row + 1, LADDER_COLUMNS[0]),
+                            initial: bond.face_value,
+                            on_value: move |v| update(id, |b, v| b.face_value = v, v),
+                            on_valid: move |valid| set_valid((id, 0), valid),
+                        }
+                        LadderCell {
+                            id: cell_id(id, 1),
+                            label: format!("Row {} {}", row + 1, LADDER_COLUMNS[1]),
+                            initial: bond.coupon_rate * 100.0,
+                            on_value: move |v: f64| update(id, |b, v| b.coupon_rate = v / 100.0, v),
+                            on_valid: move |valid| set_valid((id, 1), valid),
+                        }
+                        LadderCell {
+                            id: cell_id(id, 2),
+                            label: format!("Row {} {}", row + 1, LADDER_COLUMNS[2]),
+                            initial: bond.years_to_maturity,
+                            on_value: move |v| update(id, |b, v| b.years_to_maturity = v, v),
+                            on_valid: move |valid| set_valid((id, 2), valid),
+                        }
+                        LadderCell {
+                            id: cell_id(id, 3),
+                            label: format!("Row {} {}", row + 1, LADDER_COLUMNS[3]),
+                            initial: bond.price,
+                            on_value: move |v| update(id, |b, v| b.price = v, v),
+                            on_valid: move |valid| set_valid((id, 3), valid),
+                        }
+                        td {
+                            button {
+                                style: "padding: 2px 8px; border-radius: 4px;",
+                                onclick: move |_| {
+                                    bonds.write().retain(|(row_id, _)| *row_id != id);
+                                    invalid_cells.write().retain(|(row_id, _)| *row_id != id);
+                                },
+                                "Remove"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        button {
+            style: "padding: 6px 12px; border-radius: 4px; margin-bottom: 15px;",
+            onclick: move |_| {
+                let id = next_id();
+                next_id.set(id + 1);
+                let last_maturity = bonds
+                    .peek()
+                    .iter()
+                    .map(|(_, bond)| bond.years_to_maturity)
+                    .fold(0.0, f64::max);
+                bonds
+                    .write()
+                    .push((
+                        id,
+                        LadderBond {
+                            face_value: 10_000.0,
+                            coupon_rate: 0.04,
+                            years_to_maturity: last_maturity + 1.0,
+                            price: 100.0,
+                        },
+                    ));
+            },
+            "Add Bond"
+        }
+
+        TablePasteSection {
+            columns: "Face, Coupon (%), Maturity (years), and Price (% of face)".to_string(),
+            placeholder: "10000\t4.5\t1\t100".to_string(),
+            on_paste: move |text: String| {
+                let pasted = parse_pasted_table(&text, LADDER_COLUMNS, parse_cell);
+                let first_id = next_id();
+                next_id.set(first_id + pasted.rows.len() as u64);
+                bonds
+                    .write()
+                    .extend(
+                        pasted
+                            .rows
+                            .iter()
+                            .enumerate()
+                            .map(|(i, row)| {
+                                (
+                                    first_id + i as u64,
+                                    LadderBond {
+                                        face_value: row[0],
+                                        coupon_rate: row[1] / 100.0,
+                                        years_to_maturity: row[2],
+                                        price: row[3],
+                                    },
+                                )
+                            }),
+                    );
+                pasted.report()
+            },
+        }
+
+        div {
+            id: "BondLadderCalculation",
+            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+            {
+                match portfolio_yield {
+                    Some(y) => rsx! { "Portfolio yield: {y * 100.0:.3}%*" },
+                    None => rsx! { "Portfolio yield: not available for this ladder" },
+                }
+            }
+        }
+        div { style: "margin-bottom: 15px; font-size: 16px;",
+            "Cost ${total_cost}, total received ${total_received}"
+        }
+
+        StackedBarChart {
+            title: "Cash received by year".to_string(),
+            series: vec![
+                ChartSeries {
+                    label: "Coupons".to_string(),
+                    color: COUPON_COLOR.to_string(),
+                    values: calendar.iter().map(|y| y.coupons).collect(),
+                },
+                ChartSeries {
+                    label: "Maturing principal".to_string(),
+                    color: PRINCIPAL_COLOR.to_string(),
+                    values: calendar.iter().map(|y| y.principal).collect(),
+                },
+            ],
+            x_start: 1.0,
+            hover_index,
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "bond_ladder" }
+        AssumptionsFooter { calculator: "bond_ladder" }
+        div { style: "font-size: 10px",
+            p { "*Yield is the internal rate of return of the whole ladder at the prices entered." }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::bond::{bond_price_shocks, price_bond, BondPriceInputs};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::numeric_input_component::NumericInput;
+use crate::rate_shock::DEFAULT_SHIFTS_BP;
+use crate::rate_shock_component::RateShockPanel;
+use dioxus::prelude::*;
+
+/// A $1,000 5% semiannual bond yielding 4%, bought three months after a coupon date
+const DEFAULT_INPUTS: BondPriceInputs = BondPriceInputs {
+    face_value: 1_000.0,
+    coupon_rate: 0.05,
+    frequency: 2.0,
+    yield_rate: 0.04,
+    years_to_maturity: 9.75,
+};
+
+const FREQUENCY_OPTIONS: &[(f64, &str, &str)] = &[
+    (2.0, "semiannual", "Semiannual"),
+    (1.0, "annual", "Annual"),
+    (4.0, "quarterly", "Quarterly"),
+    (12.0, "monthly", "Monthly"),
+];
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+
+#[component]
+pub fn BondPriceUI() -> Element {
+    let mut face_signal = use_signal(|| 1_000.0 as f64);
+    let mut face_input = use_signal(|| "1000".to_string());
+    let face_input_valid = use_signal(|| true);
+    let mut coupon_signal = use_signal(|| 5.0 as f64);
+    let mut coupon_input = use_signal(|| "5.0".to_string());
+    let coupon_input_valid = use_signal(|| true);
+    let mut frequency_signal = use_signal(|| 2.0 as f64);
+    let mut yield_signal = use_signal(|| 4.0 as f64);
+    let mut yield_input = use_signal(|| "4.0".to_string());
+    let yield_input_valid = use_signal(|| true);
+    let mut years_signal = use_signal(|| 9.75 as f64);
+    let mut years_input = use_signal(|| "9.75".to_string());
+    let years_input_valid = use_signal(|| true);
+
+    let inputs = move || BondPriceInputs {
+        face_value: face_signal(),
+        coupon_rate: coupon_signal() / 100.0,
+        frequency: frequency_signal(),
+        yield_rate: yield_signal() / 100.0,
+        years_to_maturity: years_signal(),
+    };
+    let start_fresh = use_autosave(
+        "bond_price",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: BondPriceInputs| {
+            face_signal.set(inputs.face_value);
+            face_input.set(format!("{}", inputs.face_value));
+            coupon_signal.set(inputs.coupon_rate * 100.0);
+            coupon_input.set(format!("{}", inputs.coupon_rate * 100.0));
+            frequency_signal.set(inputs.frequency);
+            yield_signal.set(inputs.yield_rate * 100.0);
+            yield_input.set(format!("{}", inputs.yield_rate * 100.0));
+            years_signal.set(inputs.years_to_maturity);
+            years_input.set(format!("{}", inputs.years_to_maturity));
+        }),
+    );
+    use_track_calculator("bond_price");
+    use_track_input_range("bond_price", "face_value", face_signal, amount_bucket);
+
+    let face_value = face_signal();
+    let shocks = bond_price_shocks(&inputs(), DEFAULT_SHIFTS_BP).unwrap_or_default();
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Face Value ($):".to_string(),
+            placeholder: "Enter the amount repaid at maturity (e.g., 1000)".to_string(),
+            input_signal: face_input,
+            value_signal: face_signal,
+            valid_signal: face_input_valid,
+            field_name: "Face value".to_string(),
+            css_prefix: "face".to_string(),
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Coupon Rate (%):".to_string(),
+            placeholder: "Enter the annual coupon rate (e.g., 5.0)".to_string(),
+            input_signal: coupon_input,
+            value_signal: coupon_signal,
+            valid_signal: coupon_input_valid,
+            field_name: "Coupon rate".to_string(),
+            css_prefix: "coupon".to_string(),
+            step: Some(0.125),
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Coupons:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(periods, _, _)) = FREQUENCY_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        frequency_signal.set(periods);
+                    }
+                },
+                for (periods , value , display) in FREQUENCY_OPTIONS.iter() {
+                    option { value: *value, selected: frequency_signal() == *periods, {*display} }
+                }
+            }
+        }
+        NumericInput {
+            label: "Yield to Maturity (%):".to_string(),
+            placeholder: "Enter the annual yield (e.g., 4.0)".to_string(),
+            input_signal: yield_input,
+            value_signal: yield_signal,
+            valid_signal: yield_input_valid,
+            field_name: "Yield to maturity".to_string(),
+            css_prefix: "yield".to_string(),
+            allow_negative: true,
+            step: Some(0.125),
+        }
+        NumericInput {
+            label: "Years to Maturity:".to_string(),
+            placeholder: "Enter the years left, e.g., 9.75 for 9 years 9 months".to_string(),
+            input_signal: years_input,
+            value_signal: years_signal,
+            valid_signal: years_input_valid,
+            field_name: "Years to maturity".to_string(),
+            css_prefix: "years".to_string(),
+            step: Some(0.25),
+        }
+
+        {
+            match price_bond(&inputs()) {
+                Ok(pricing) => rsx! {
+                    div {
+                        id: "BondPriceCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        "Clean price: ${format_dollars(pricing.clean_price)}"
+                        if face_value > 0.0 {
+                            " ({pricing.clean_price / face_value * 100.0:.3} per 100 of face)"
+                        }
+                        "*"
+                    }
+                    div { style: "margin-bottom: 15px; font-size: 16px;",
+                        "Accrued interest: ${format_dollars(pricing.accrued_interest)}. The buyer pays ${format_dollars(pricing.dirty_price)} in all (the dirty price)."
+                    }
+                    RateShockPanel { value_label: "Dirty price".to_string(), shocks }
+
+                    div {
+                        style: "max-height: 400px; overflow-y: auto; margin-bottom: 15px;",
+                        tabindex: "0",
+                        table { style: "color: #333; border-collapse: collapse; font-size: 13px;",
+                            thead { style: "position: sticky; top: 0; background: #f4f4f4;",
+                                tr {
+                                    th { scope: "col", style: CELL_STYLE, "#" }
+                                    th { scope: "col", style: CELL_STYLE, "Years" }
+                                    th { scope: "col", style: CELL_STYLE, "Coupon" }
+                                    th { scope: "col", style: CELL_STYLE, "Principal" }
+                                    th { scope: "col", style: CELL_STYLE, "Present Value" }
+                                }
+                            }
+                            tbody {
+                                for flow in pricing.cash_flows.iter() {
+                                    tr { key: "{flow.period}",
+                                        th { scope: "row", style: CELL_STYLE, "{flow.period}" }
+                                        td { style: CELL_STYLE, "{flow.years:.2}" }
+                                        td { style: CELL_STYLE, {format_dollars(flow.coupon)} }
+                                        td { style: CELL_STYLE, {format_dollars(flow.principal)} }
+                                        td { style: CELL_STYLE, {format_dollars(flow.present_value)} }
+                                    }
+                                }
+                            }
+                            tfoot { style: "position: sticky; bottom: 0; background: #f4f4f4; font-weight: bold;",
+                                tr {
+                                    th { scope: "row", style: CELL_STYLE, "Total" }
+                                    td {}
+                                    td {}
+                                    td {}
+                                    td { style: CELL_STYLE, {format_dollars(pricing.dirty_price)} }
+                                }
+                            }
+                        }
+                    }
+                },
+                Err(error) => rsx! {
+                    div {
+                        id: "BondPriceCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "bond_price" }
+        AssumptionsFooter { calculator: "bond_price" }
+        div { style: "font-size: 10px",
+            p {
+                "*Dollar amounts are truncated to the cent; dealers quote prices per 100 of face value."
+            }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::data_year::{use_data_year, DataYearBanner};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::numeric_input_component::NumericInput;
+use crate::paycheck::{compute_bonus_tax, BonusInputs, Earner, SUPPLEMENTAL_WITHHOLDING_RATE};
+use crate::tax::FilingStatus;
+use dioxus::prelude::*;
+
+/// A $10,000 bonus on a $50,000 salary, where the 22% withholding is well above the 12% bracket
+const DEFAULT_INPUTS: BonusInputs = BonusInputs {
+    status: FilingStatus::Single,
+    earner: Earner {
+        salary: 50_000.0,
+        pretax_deductions: 3_000.0,
+    },
+    bonus: 10_000.0,
+};
+
+const STATUS_OPTIONS: &[(FilingStatus, &str)] = &[
+    (FilingStatus::Single, "single"),
+    (FilingStatus::MarriedFilingJointly, "joint"),
+];
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+const LABEL_STYLE: &str = "text-align: left; padding: 2px 8px;";
+
+#[component]
+pub fn BonusUI() -> Element {
+    let tax_year = use_data_year("tax_brackets");
+    let mut status_signal = use_signal(|| FilingStatus::Single);
+    let mut salary_signal = use_signal(|| 50_000.0 as f64);
+    let mut salary_input = use_signal(|| "50000".to_string());
+    let salary_input_valid = use_signal(|| true);
+    let mut pretax_signal = use_signal(|| 3_000.0 as f64);
+    let mut pretax_input = use_signal(|| "3000".to_string());
+    let pretax_input_valid = use_signal(|| true);
+    let mut bonus_signal = use_signal(|| 10_000.0 as f64);
+    let mut bonus_input = use_signal(|| "10000".to_string());
+    let bonus_input_valid = use_signal(|| true);
+
+    let inputs = move || BonusInputs {
+        status: status_signal(),
+        earner: Earner {
+            salary: salary_signal(),
+            pretax_deductions: pretax_signal(),
+        },
+        bonus: bonus_signal(),
+    };
+    let start_fresh = use_autosave(
+        "bonus",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: BonusInputs| {
+            status_signal.set(inputs.status);
+            salary_signal.set(inputs.earner.salary);
+            salary_input.set(format!("{}", inputs.earner.salary));
+            pretax_signal.set(inputs.earner.pretax_deductions);
+            pretax_input.set(format!("{}", inputs.earner.pretax_deductions));
+            bonus_signal.set(inputs.bonus);
+            bonus_input.set(format!("{}", inputs.bonus));
+        }),
+    );
+    use_track_calculator("bonus");
+    use_track_input_range("bonus", "bonus", bonus_signal, amount_bucket);
+
+    rsx! {
+        hr {}
+        br {}
+
+        DataYearBanner { data_set: "tax_brackets", year: tax_year }
+
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Filing Status:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(status, _)) = STATUS_OPTIONS
+                        .iter()
+                        .find(|(_, value_str)| *value_str == value.as_str())
+                    {
+                        status_signal.set(status);
+                    }
+                },
+                for (status , value) in STATUS_OPTIONS.iter() {
+                    option { value: *value, selected: status_signal() == *status, {status.label()} }
+                }
+            }
+        }
+        NumericInput {
+            label: "Salary ($/yr):".to_string(),
+            placeholder: "Enter your regular pay for the year, before the bonus (e.g., 50000)".to_string(),
+            input_signal: salary_input,
+            value_signal: salary_signal,
+            valid_signal: salary_input_valid,
+            field_name: "Salary".to_string(),
+            css_prefix: "salary".to_string(),
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Pretax Deductions ($/yr):".to_string(),
+            placeholder: "Enter 401(k), HSA, and other pretax deductions (e.g., 3000)".to_string(),
+            input_signal: pretax_input,
+            value_signal: pretax_signal,
+            valid_signal: pretax_input_valid,
+            field_name: "Pretax deductions".to_string(),
+            css_prefix: "pretax".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Bonus ($):".to_string(),
+            placeholder: "Enter the bonus before withholding (e.g., 10000)".to_string(),
+            input_signal: bonus_input,
+            value_signal: bonus_signal,
+            valid_signal: bonus_input_valid,
+            field_name: "Bonus".to_string(),
+            css_prefix: "bonus".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+
+        {
+            match compute_bonus_tax(&inputs(), tax_year()) {
+                Ok(bonus) => {
+                    // Rounded so a difference of whole cents isn't shown a cent short
+                    let difference = (bonus.difference * 100.0).round() / 100.0;
+                    let settlement = if difference > 0.0 {
+                        format!(
+                            "${} more is withheld than the bonus adds to your tax; it comes back as a refund when you file.",
+                            format_dollars(difference)
+                        )
+                    } else if difference < 0.0 {
+                        format!(
+                            "Withholding falls ${} short of what the bonus adds to your tax; you'll owe it when you file.",
+                            format_dollars(-difference)
+                        )
+                    } else {
+                        "Withholding matches what the bonus adds to your tax.".to_string()
+                    };
+                    rsx! {
+                        div {
+                            id: "BonusCalculation",
+                            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                            "The bonus adds ${format_dollars(bonus.actual_tax)} to your federal income tax, {bonus.actual_rate * 100.0:.1}% of it*"
+                        }
+                        table { style: "color: #333; border-collapse: collapse; font-size: 14px; margin-bottom: 15px;",
+                            thead {
+                                tr {
+                                    th {}
+                                    th { scope: "col", style: CELL_STYLE, "Withheld" }
+                                    th { scope: "col", style: CELL_STYLE, "Owed" }
+                                }
+                            }
+                            tbody {
+                                tr {
+                                    th { scope: "row", style: LABEL_STYLE, "Federal income tax" }
+                                    td { style: CELL_STYLE, "${format_dollars(bonus.withheld)}" }
+                                    td { style: CELL_STYLE, "${format_dollars(bonus.actual_tax)}" }
+                                }
+                                tr {
+                                    th { scope: "row", style: LABEL_STYLE, "Social Security & Medicare" }
+                                    td { style: CELL_STYLE, "${format_dollars(bonus.fica)}" }
+                                    td { style: CELL_STYLE, "${format_dollars(bonus.fica)}" }
+                                }
+                            }
+                        }
+                        div { id: "BonusSettlement", style: "margin-bottom: 15px; font-size: 16px;",
+                            "{settlement}"
+                        }
+                        div { style: "margin-bottom: 15px; font-size: 16px;",
+                            "A bonus isn't taxed at a higher rate: it lands in your {bonus.marginal_rate * 100.0:.0}% bracket like any other pay. It's withheld at a flat {SUPPLEMENTAL_WITHHOLDING_RATE * 100.0:.0}%, while your regular paychecks withhold about {bonus.salary_average_rate * 100.0:.1}%, your salary's average rate, so the bonus check can look taxed harder."
+                        }
+                    }
+                }
+                Err(error) => rsx! {
+                    div {
+                        id: "BonusCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "bonus" }
+        AssumptionsFooter { calculator: "bonus" }
+        div { style: "font-size: 10px",
+            p {
+                "*Federal taxes only. Assumes your employer withholds from the bonus at the flat supplemental rate rather than adding it to a regular paycheck."
+            }
+            br {}
+            br {}
+        }
+    }
+}
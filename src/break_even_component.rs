@@ -0,0 +1,163 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::feedback_component::FeedbackWidget;
+use crate::numeric_input_component::NumericInput;
+use crate::rates::{compute_break_even, BreakEvenInputs, BreakEvenWinner};
+use dioxus::prelude::*;
+use num_format::{Locale, ToFormattedString};
+
+const DEFAULT_INPUTS: BreakEvenInputs = BreakEvenInputs {
+    amount: 10_000.0,
+    years: 10.0,
+    nominal_yield: 0.045,
+    real_yield: 0.02,
+    expected_inflation: 0.03,
+};
+
+#[component]
+pub fn BreakEvenInflationUI() -> Element {
+    let mut amount_signal = use_signal(|| 10_000.0 as f64);
+    let mut amount_input = use_signal(|| "10000".to_string());
+    let amount_input_valid = use_signal(|| true);
+    let mut years_signal = use_signal(|| 10.0 as f64);
+    let mut years_input = use_signal(|| "10".to_string());
+    let years_input_valid = use_signal(|| true);
+    let mut nominal_signal = use_signal(|| 4.5 as f64);
+    let mut nominal_input = use_signal(|| "4.5".to_string());
+    let nominal_input_valid = use_signal(|| true);
+    let mut real_signal = use_signal(|| 2.0 as f64);
+    let mut real_input = use_signal(|| "2.0".to_string());
+    let real_input_valid = use_signal(|| true);
+    let mut inflation_signal = use_signal(|| 3.0 as f64);
+    let mut inflation_input = use_signal(|| "3.0".to_string());
+    let inflation_input_valid = use_signal(|| true);
+    let start_fresh = use_autosave(
+        "break_even_inflation",
+        DEFAULT_INPUTS,
+        move || BreakEvenInputs {
+            amount: amount_signal(),
+            years: years_signal(),
+            nominal_yield: nominal_signal() / 100.0,
+            real_yield: real_signal() / 100.0,
+            expected_inflation: inflation_signal() / 100.0,
+        },
+        use_callback(move |inputs: BreakEvenInputs| {
+            amount_signal.set(inputs.amount);
+            amount_input.set(format!("{}", inputs.amount));
+            years_signal.set(inputs.years);
+            years_input.set(format!("{}", inputs.years));
+            nominal_signal.set(inputs.nominal_yield * 100.0);
+            nominal_input.set(format!("{:.2}", inputs.nominal_yield * 100.0));
+            real_signal.set(inputs.real_yield * 100.0);
+            real_input.set(format!("{:.2}", inputs.real_yield * 100.0));
+            inflation_signal.set(inputs.expected_inflation * 100.0);
+            inflation_input.set(format!("{:.2}", inputs.expected_inflation * 100.0));
+        }),
+    );
+    use_track_calculator("break_even_inflation");
+    use_track_input_range(
+        "break_even_inflation",
+        "amount",
+        amount_signal,
+        amount_bucket,
+    );
+
+    let outputs = compute_break_even(&BreakEvenInputs {
+        amount: amount_signal(),
+        years: years_signal(),
+        nominal_yield: nominal_signal() / 100.0,
+        real_yield: real_signal() / 100.0,
+        expected_inflation: inflation_signal() / 100.0,
+    });
+    let break_even = outputs.break_even_inflation * 100.0;
+    let tips_return = outputs.tips_return * 100.0;
+    let tips_value = (outputs.tips_value as i64).to_formatted_string(&Locale::en);
+    let nominal_value = (outputs.nominal_value as i64).to_formatted_string(&Locale::en);
+    let verdict = match outputs.winner {
+        BreakEvenWinner::Tips => "TIPS come out ahead at your inflation assumption",
+        BreakEvenWinner::Nominal => {
+            "The nominal Treasury comes out ahead at your inflation assumption"
+        }
+        BreakEvenWinner::Tie => "Both bonds end up the same at your inflation assumption",
+    };
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Amount Invested ($):".to_string(),
+            placeholder: "Enter amount (e.g., 10000)".to_string(),
+            input_signal: amount_input,
+            value_signal: amount_signal,
+            valid_signal: amount_input_valid,
+            field_name: "Amount".to_string(),
+            css_prefix: "amount".to_string(),
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Years to Maturity:".to_string(),
+            placeholder: "Enter years (e.g., 10)".to_string(),
+            input_signal: years_input,
+            value_signal: years_signal,
+            valid_signal: years_input_valid,
+            field_name: "Years to maturity".to_string(),
+            css_prefix: "years".to_string(),
+            step: Some(1.0),
+        }
+        NumericInput {
+            label: "Nominal Treasury Yield (%):".to_string(),
+            placeholder: "Enter yield (e.g., 4.5)".to_string(),
+            input_signal: nominal_input,
+            value_signal: nominal_signal,
+            valid_signal: nominal_input_valid,
+            field_name: "Nominal yield".to_string(),
+            css_prefix: "nominal".to_string(),
+            step: Some(0.125),
+            allow_negative: true,
+        }
+        NumericInput {
+            label: "TIPS Real Yield (%):".to_string(),
+            placeholder: "Enter real yield (e.g., 2.0)".to_string(),
+            input_signal: real_input,
+            value_signal: real_signal,
+            valid_signal: real_input_valid,
+            field_name: "Real yield".to_string(),
+            css_prefix: "real".to_string(),
+            step: Some(0.125),
+            allow_negative: true,
+        }
+        NumericInput {
+            label: "Expected Inflation (%):".to_string(),
+            placeholder: "Enter inflation (e.g., 3.0)".to_string(),
+            input_signal: inflation_input,
+            value_signal: inflation_signal,
+            valid_signal: inflation_input_valid,
+            field_name: "Expected inflation".to_string(),
+            css_prefix: "inflation".to_string(),
+            step: Some(0.125),
+            allow_negative: true,
+        }
+
+        div {
+            id: "BreakEvenCalculation",
+            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+            "Break-even inflation: {break_even:.2}%*"
+        }
+        div { style: "margin-bottom: 15px; font-size: 16px;",
+            "At {inflation_signal():.2}% inflation the TIPS earns {tips_return:.2}% a year: "
+            "${tips_value} vs ${nominal_value} from the nominal Treasury. {verdict}."
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "break_even_inflation" }
+        AssumptionsFooter { calculator: "break_even_inflation" }
+        div { style: "font-size: 10px",
+            p { "*Yields are entered by hand; check current Treasury and TIPS yields for the same maturity." }
+            br {}
+            br {}
+        }
+    }
+}
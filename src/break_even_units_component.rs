@@ -0,0 +1,175 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::business_math::{compute_unit_break_even, cost_revenue_curves, UnitBreakEvenInputs};
+use crate::chart::{ChartSeries, LineChart};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::numeric_input_component::NumericInput;
+use dioxus::prelude::*;
+use num_format::{Locale, ToFormattedString};
+
+/// A small shop with $50,000 a year of overhead, selling a $40 product that costs $15 to make
+const DEFAULT_INPUTS: UnitBreakEvenInputs = UnitBreakEvenInputs {
+    fixed_costs: 50_000.0,
+    variable_cost: 15.0,
+    price: 40.0,
+};
+
+const COST_COLOR: &str = "#c0392b";
+const REVENUE_COLOR: &str = "#2471a3";
+
+/// Points plotted on each curve; the chart runs to about twice the break-even volume
+const CHART_POINTS: usize = 21;
+
+/// Smallest 1, 2, or 5 times a power of ten at or above `raw`, so the axis labels stay round
+fn round_step(raw: f64) -> f64 {
+    if raw.is_nan() || raw <= 0.0 {
+        return 1.0;
+    }
+    let magnitude = 10_f64.powf(raw.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|multiple| multiple * magnitude)
+        .find(|step| *step >= raw)
+        .unwrap_or(10.0 * magnitude)
+        .max(1.0)
+}
+
+#[component]
+pub fn BreakEvenUnitsUI() -> Element {
+    let mut fixed_signal = use_signal(|| 50_000.0 as f64);
+    let mut fixed_input = use_signal(|| "50000".to_string());
+    let fixed_input_valid = use_signal(|| true);
+    let mut variable_signal = use_signal(|| 15.0 as f64);
+    let mut variable_input = use_signal(|| "15".to_string());
+    let variable_input_valid = use_signal(|| true);
+    let mut price_signal = use_signal(|| 40.0 as f64);
+    let mut price_input = use_signal(|| "40".to_string());
+    let price_input_valid = use_signal(|| true);
+    let hover_index = use_signal(|| None);
+
+    let inputs = move || UnitBreakEvenInputs {
+        fixed_costs: fixed_signal(),
+        variable_cost: variable_signal(),
+        price: price_signal(),
+    };
+    let start_fresh = use_autosave(
+        "break_even_units",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: UnitBreakEvenInputs| {
+            fixed_signal.set(inputs.fixed_costs);
+            fixed_input.set(format!("{}", inputs.fixed_costs));
+            variable_signal.set(inputs.variable_cost);
+            variable_input.set(format!("{}", inputs.variable_cost));
+            price_signal.set(inputs.price);
+            price_input.set(format!("{}", inputs.price));
+        }),
+    );
+    use_track_calculator("break_even_units");
+    use_track_input_range(
+        "break_even_units",
+        "fixed_costs",
+        fixed_signal,
+        amount_bucket,
+    );
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Fixed Costs ($):".to_string(),
+            placeholder: "Enter costs that don't change with volume (e.g., 50000)".to_string(),
+            input_signal: fixed_input,
+            value_signal: fixed_signal,
+            valid_signal: fixed_input_valid,
+            field_name: "Fixed costs".to_string(),
+            css_prefix: "fixed".to_string(),
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Variable Cost ($/unit):".to_string(),
+            placeholder: "Enter the cost of each unit made and sold (e.g., 15)".to_string(),
+            input_signal: variable_input,
+            value_signal: variable_signal,
+            valid_signal: variable_input_valid,
+            field_name: "Variable cost".to_string(),
+            css_prefix: "variable".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Price ($/unit):".to_string(),
+            placeholder: "Enter the selling price of each unit (e.g., 40)".to_string(),
+            input_signal: price_input,
+            value_signal: price_signal,
+            valid_signal: price_input_valid,
+            field_name: "Price".to_string(),
+            css_prefix: "price".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(2),
+        }
+
+        {
+            match compute_unit_break_even(&inputs()) {
+                Ok(break_even) => {
+                    let step = round_step(2.0 * break_even.units / (CHART_POINTS - 1) as f64);
+                    let (cost, revenue) = cost_revenue_curves(&inputs(), step, CHART_POINTS);
+                    let whole_units =
+                        (break_even.whole_units as i64).to_formatted_string(&Locale::en);
+                    rsx! {
+                        div {
+                            id: "BreakEvenUnitsCalculation",
+                            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                            "Break-even: {whole_units} units, or ${format_dollars(break_even.revenue)} of revenue*"
+                        }
+                        div { style: "margin-bottom: 15px; font-size: 16px;",
+                            "Each unit contributes ${format_dollars(break_even.contribution_margin)} toward fixed costs, {break_even.contribution_margin_ratio * 100.0:.1}% of its price."
+                        }
+                        LineChart {
+                            title: "Total cost vs revenue by units sold".to_string(),
+                            series: vec![
+                                ChartSeries {
+                                    label: "Total cost".to_string(),
+                                    color: COST_COLOR.to_string(),
+                                    values: cost,
+                                },
+                                ChartSeries {
+                                    label: "Revenue".to_string(),
+                                    color: REVENUE_COLOR.to_string(),
+                                    values: revenue,
+                                },
+                            ],
+                            x_start: 0.0,
+                            x_step: step,
+                            x_label: "Units".to_string(),
+                            hover_index,
+                            y_max: None,
+                        }
+                    }
+                }
+                Err(error) => rsx! {
+                    div {
+                        id: "BreakEvenUnitsCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "break_even_units" }
+        AssumptionsFooter { calculator: "break_even_units" }
+        div { style: "font-size: 10px",
+            p {
+                "*Rounded up to whole units; the revenue is at the exact break-even volume, where profit is zero."
+            }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use serde::Serialize;
+
+/// Identifies the exact build a deployment runs, for monitoring and bug reports. `GIT_SHA` and
+/// `BUILD_TIME` are read at build time; `make` sets both, and other builds report "unknown".
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub git_sha: &'static str,
+    pub build_time: &'static str,
+    /// Cargo features compiled in, e.g., ["bonds", "fullstack"]
+    pub features: Vec<&'static str>,
+}
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const GIT_SHA: &str = match option_env!("GIT_SHA") {
+    Some(sha) if !sha.is_empty() => sha,
+    _ => "unknown",
+};
+
+pub const BUILD_TIME: &str = match option_env!("BUILD_TIME") {
+    Some(time) if !time.is_empty() => time,
+    _ => "unknown",
+};
+
+/// The optional Cargo features this binary was built with
+pub fn enabled_features() -> Vec<&'static str> {
+    [
+        ("loans", cfg!(feature = "loans")),
+        ("bonds", cfg!(feature = "bonds")),
+        ("simulation", cfg!(feature = "simulation")),
+        ("server-data", cfg!(feature = "server-data")),
+        ("fullstack", cfg!(feature = "fullstack")),
+        ("server", cfg!(feature = "server")),
+    ]
+    .into_iter()
+    .filter_map(|(name, enabled)| enabled.then_some(name))
+    .collect()
+}
+
+pub fn build_info() -> BuildInfo {
+    BuildInfo {
+        version: VERSION,
+        git_sha: GIT_SHA,
+        build_time: BUILD_TIME,
+        features: enabled_features(),
+    }
+}
+
+/// Short label for bug reports, e.g., "0.1.0 (3fe5332)"
+pub fn build_label() -> String {
+    let sha = GIT_SHA.get(..7).unwrap_or(GIT_SHA);
+    format!("{} ({})", VERSION, sha)
+}
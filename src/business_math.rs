@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+
+/// Inputs to the break-even calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct UnitBreakEvenInputs {
+    /// Costs that don't change with volume, e.g., rent and salaries
+    pub fixed_costs: f64,
+    /// Cost of making and selling one more unit
+    pub variable_cost: f64,
+    /// Selling price of one unit
+    pub price: f64,
+}
+
+/// Where revenue covers total cost
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitBreakEven {
+    /// Units at which profit is exactly zero; may be fractional
+    pub units: f64,
+    /// Whole units that must be sold to stop losing money
+    pub whole_units: f64,
+    pub revenue: f64,
+    /// What each unit sold contributes toward fixed costs: price - variable cost
+    pub contribution_margin: f64,
+    /// Contribution margin as a share of the price
+    pub contribution_margin_ratio: f64,
+}
+
+// Computes the break-even point in units and revenue.
+///
+/// # Parameters:
+/// - `inputs`: Fixed costs (F), variable cost per unit (V), and price per unit (P)
+///
+/// # Formula:
+/// units = F / (P - V)
+/// revenue = units * P = F / ((P - V) / P)
+///
+/// # Returns:
+/// The break-even units and revenue, and the contribution margin. An error if an entry is
+/// negative or the price doesn't exceed the variable cost, as no volume would then break even.
+pub fn compute_unit_break_even(inputs: &UnitBreakEvenInputs) -> Result<UnitBreakEven, String> {
+    let entries = [inputs.fixed_costs, inputs.variable_cost, inputs.price];
+    if entries.iter().any(|entry| entry.is_nan() || *entry < 0.0) {
+        return Err("Costs and the price can't be negative".to_string());
+    }
+    let contribution_margin = inputs.price - inputs.variable_cost;
+    if contribution_margin <= 0.0 {
+        return Err(
+            "The price must exceed the variable cost per unit, or no volume breaks even"
+                .to_string(),
+        );
+    }
+
+    let units = inputs.fixed_costs / contribution_margin;
+    Ok(UnitBreakEven {
+        units,
+        whole_units: units.ceil(),
+        revenue: units * inputs.price,
+        contribution_margin,
+        contribution_margin_ratio: contribution_margin / inputs.price,
+    })
+}
+
+/// Total cost and revenue at `n_points` volumes, `step` units apart from zero, as (cost,
+/// revenue)
+pub fn cost_revenue_curves(
+    inputs: &UnitBreakEvenInputs,
+    step: f64,
+    n_points: usize,
+) -> (Vec<f64>, Vec<f64>) {
+    (0..n_points)
+        .map(|i| {
+            let units = i as f64 * step;
+            (
+                inputs.fixed_costs + inputs.variable_cost * units,
+                inputs.price * units,
+            )
+        })
+        .unzip()
+}
+
+/// Inputs to the weighted average cost of capital (WACC) calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WaccInputs {
+    /// Market value of the company's equity
+    pub equity_value: f64,
+    /// Market value of its debt
+    pub debt_value: f64,
+    /// Return shareholders require, as a decimal
+    pub cost_of_equity: f64,
+    /// Interest rate on the debt before tax, as a decimal
+    pub cost_of_debt: f64,
+    /// Corporate tax rate the interest is deducted at, as a decimal
+    pub tax_rate: f64,
+}
+
+/// The blended cost of capital and how it is weighted
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wacc {
+    /// Equity's share of total capital
+    pub equity_weight: f64,
+    /// Debt's share of total capital
+    pub debt_weight: f64,
+    /// Cost of debt after its interest is deducted: cost of debt * (1 - tax rate)
+    pub after_tax_cost_of_debt: f64,
+    pub wacc: f64,
+}
+
+// Computes the weighted average cost of capital, the return a company must earn to pay all
+// its investors, and the usual discount rate for its free cash flows.
+///
+/// # Parameters:
+/// - `inputs`: Market values of equity (E) and debt (D), cost of equity (Re), cost of debt (Rd),
+///   and tax rate (T)
+///
+/// # Formula:
+/// WACC = E / (E + D) * Re + D / (E + D) * Rd * (1 - T)
+///
+/// # Returns:
+/// The WACC as a decimal, with the weights and after-tax cost of debt. An error if an entry is
+/// negative, there is no capital, or the tax rate is over 100%.
+pub fn compute_wacc(inputs: &WaccInputs) -> Result<Wacc, String> {
+    let entries = [
+        inputs.equity_value,
+        inputs.debt_value,
+        inputs.cost_of_equity,
+        inputs.cost_of_debt,
+        inputs.tax_rate,
+    ];
+    if entries.iter().any(|entry| entry.is_nan() || *entry < 0.0) {
+        return Err("Values, costs, and the tax rate can't be negative".to_string());
+    }
+    let total = inputs.equity_value + inputs.debt_value;
+    if total <= 0.0 {
+        return Err("Enter the value of the company's equity, debt, or both".to_string());
+    }
+    if inputs.tax_rate > 1.0 {
+        return Err("The tax rate can't be over 100%".to_string());
+    }
+
+    let equity_weight = inputs.equity_value / total;
+    let debt_weight = inputs.debt_value / total;
+    let after_tax_cost_of_debt = inputs.cost_of_debt * (1.0 - inputs.tax_rate);
+    Ok(Wacc {
+        equity_weight,
+        debt_weight,
+        after_tax_cost_of_debt,
+        wacc: equity_weight * inputs.cost_of_equity + debt_weight * after_tax_cost_of_debt,
+    })
+}
+
+/// Inputs to the capital asset pricing model (CAPM) calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CapmInputs {
+    /// Yield on a risk-free investment, e.g., a Treasury, as a decimal
+    pub risk_free_rate: f64,
+    /// How much the stock moves with the market; 1 moves with it
+    pub beta: f64,
+    /// Market return expected above the risk-free rate, as a decimal
+    pub market_risk_premium: f64,
+}
+
+// Computes the return investors expect from a stock under the CAPM, often used as its cost of
+// equity.
+///
+/// # Parameters:
+/// - `inputs`: Risk-free rate (Rf), beta (β), and market risk premium (E(Rm) - Rf)
+///
+/// # Formula:
+/// E(R) = Rf + β * (E(Rm) - Rf)
+///
+/// # Returns:
+/// The expected yearly return as a decimal
+pub fn capm_expected_return(inputs: &CapmInputs) -> f64 {
+    inputs.risk_free_rate + inputs.beta * inputs.market_risk_premium
+}
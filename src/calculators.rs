@@ -0,0 +1,746 @@
+use crate::theme::ThemeConfig;
+use crate::Route;
+
+/// A calculator the app can show
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CalculatorInfo {
+    /// Stable identifier used by themes, analytics, autosave, and scenarios
+    pub slug: &'static str,
+    /// Navbar label
+    pub name: &'static str,
+    /// One sentence on what the calculator answers, used as the page's meta description
+    pub description: &'static str,
+    /// Whether the calculator's cargo feature is compiled in
+    pub included: bool,
+    /// Every assumption baked into the calculator's math, listed under its results
+    pub assumptions: &'static [&'static str],
+}
+
+impl CalculatorInfo {
+    pub fn route(&self) -> Route {
+        match self.slug {
+            "present_value" => Route::PresentValueUI {},
+            "annuity" => Route::AnnuityUI {},
+            "perpetuity" => Route::PerpetuityUI {},
+            "apr_apy" => Route::AprApyUI {},
+            "retirement" => Route::RetirementUI {},
+            "retirement_income" => Route::RetirementIncomeUI {},
+            "long_term_care" => Route::LongTermCareUI {},
+            "spia" => Route::SpiaUI {},
+            "savings_goal" => Route::SavingsGoalUI {},
+            "sinking_fund" => Route::SinkingFundUI {},
+            "paycheck" => Route::PaycheckUI {},
+            "self_employment" => Route::SelfEmploymentUI {},
+            "bonus" => Route::BonusUI {},
+            "charitable" => Route::CharitableUI {},
+            "sequence_risk" => Route::SequenceRiskUI {},
+            "monte_carlo" => Route::MonteCarloUI {},
+            "break_even_inflation" => Route::BreakEvenInflationUI {},
+            "bond_ladder" => Route::BondLadderUI {},
+            "bond_price" => Route::BondPriceUI {},
+            "savings_bond" => Route::SavingsBondUI {},
+            "cd" => Route::CdUI {},
+            "lease" => Route::LeaseUI {},
+            "lease_vs_buy" => Route::LeaseVsBuyUI {},
+            "amortization" => Route::AmortizationUI {},
+            "loan_compare" => Route::LoanCompareUI {},
+            "closing_costs" => Route::ClosingCostsUI {},
+            "debt_payoff" => Route::DebtPayoffUI {},
+            "npv" => Route::NpvUI {},
+            "dcf" => Route::DcfUI {},
+            "capm" => Route::CapmUI {},
+            "wacc" => Route::WaccUI {},
+            "returns_stats" => Route::ReturnsStatsUI {},
+            "options" => Route::OptionsUI {},
+            "break_even_units" => Route::BreakEvenUnitsUI {},
+            _ => Route::FutureValueUI {},
+        }
+    }
+}
+
+/// Disclaimers shown under every calculator, after its assumptions
+pub const DISCLAIMERS: &[&str] = &[
+    "Results are estimates for education and planning, not financial, tax, or legal advice.",
+    "Verify every calculation independently before relying on it for a decision.",
+];
+
+/// Every calculator, in navbar order
+pub const CALCULATORS: &[CalculatorInfo] = &[
+    CalculatorInfo {
+        slug: "future_value",
+        name: "Future Value Calculator",
+        description: "See what an investment grows to with compound interest and monthly \
+             contributions, or solve for the rate or time needed to reach a goal.",
+        included: true,
+        assumptions: &[
+            "The interest rate is a nominal annual rate, fixed for the whole term.",
+            "Interest compounds at the end of each period. Daily compounding uses 365 periods a \
+             year, weekly uses 52; there are no leap-day or business-day adjustments. \
+             Continuous compounding is the limit of ever-shorter periods, growing by e^(rt).",
+            "A fractional number of years compounds for a fractional number of periods.",
+            "Monthly contributions are a fixed amount, earning the same rate as the principal; \
+             when compounding is not monthly, each deposit grows at the equivalent monthly rate.",
+            "Solving for rate or time includes monthly contributions. The rate is found by \
+             bisection between -99% and 1,000%; the time has a closed form.",
+            "No withdrawals, taxes, or fees.",
+            "Expected inflation, when entered, is constant for the whole term; today's dollars \
+             are the future value divided by (1 + inflation)^years.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "present_value",
+        name: "Present Value Calculator",
+        description: "Find how much to invest today to reach a target amount, at a fixed interest \
+             rate and compounding frequency.",
+        included: true,
+        assumptions: &[
+            "The interest rate is a nominal annual rate, fixed for the whole term.",
+            "Interest compounds at the end of each period. Daily compounding uses 365 periods a \
+             year, weekly uses 52; there are no leap-day or business-day adjustments. \
+             Continuous compounding is the limit of ever-shorter periods, growing by e^(rt).",
+            "The amount is invested once, today, with no further contributions, taxes, fees, or \
+             inflation.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "annuity",
+        name: "Annuity",
+        description: "Solve for the payment, present value, or future value of a series of level \
+             or growing payments, made at the start or end of each period.",
+        included: true,
+        assumptions: &[
+            "Payments are made once per compounding period, at the end of each period unless an \
+             annuity due is chosen. They are equal unless a growth rate is entered; then each \
+             payment rises by the same fraction, compounding to the yearly rate over a year.",
+            "The interest rate is a nominal annual rate divided evenly among the periods of each \
+             year, fixed for the whole term; the term is rounded to a whole number of payments.",
+            "The present value is a starting balance earning the same rate as the payments.",
+            "No taxes, fees, or inflation.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "perpetuity",
+        name: "Perpetuity",
+        description: "Value a payment that continues forever, level or growing, such as a \
+             preferred stock dividend.",
+        included: true,
+        assumptions: &[
+            "The first payment arrives one year from today and payments continue every year \
+             without end.",
+            "The discount rate is an annual required return, fixed forever. A growing payment \
+             rises by the same percentage every year, which must stay below the discount rate \
+             for the value to be finite.",
+            "The issuer never calls, suspends, or defaults on the payments; no taxes or fees.",
+            "Math runs in 64-bit floating point; the value is shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "apr_apy",
+        name: "APR ⇄ APY",
+        description: "Convert a nominal annual rate (APR) to the effective annual yield (APY), or \
+             back, at any compounding frequency.",
+        included: true,
+        assumptions: &[
+            "The APR is a nominal annual rate divided evenly among the compounding periods; \
+             daily compounding uses 365 periods a year.",
+            "Continuous compounding is the limit of ever-shorter periods: APY = e^APR - 1.",
+            "APY here is the effective annual rate of interest alone, without fees; a lender's \
+             APR may include fees and differ from the nominal rate.",
+            "Math runs in 64-bit floating point; rates are shown to three decimal places.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "retirement",
+        name: "Retirement Savings",
+        description: "Project what your retirement savings grow to by the age you retire, year \
+             by year, in future and today's dollars.",
+        included: true,
+        assumptions: &[
+            "The expected return is an annual rate, fixed until retirement, earned monthly at \
+             the equivalent monthly rate (1 + r)^(1/12) - 1.",
+            "With a glide path, each year's return is the path's return at the age that year \
+             starts, interpolated in a straight line between breakpoints.",
+            "Contributions are deposited at the end of every month and stay level within each \
+             year of the projection; the annual increase raises them once a year, from the \
+             second year on.",
+            "Each projection year's contributions are capped at the account's IRS limit for the \
+             selected tax year, held at that year's amount, including the catch-up allowed at \
+             the age reached that year (50 and over, and more at 60 through 63 for a 401(k)). \
+             Catch-up contributions are added only when chosen.",
+            "The time to retirement is rounded to whole months; a final partial year is shown \
+             as its own row.",
+            "Inflation is constant; today's dollars are the balance divided by \
+             (1 + inflation)^years.",
+            "Inflation scenarios rerun the projection with inflation lowered and raised by the \
+             spread chosen; each scenario's rate is constant too.",
+            "No withdrawals, taxes, fees, or contribution limits.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "retirement_income",
+        name: "Retirement Income",
+        description: "Combine Social Security, a pension, an annuity, and portfolio \
+             withdrawals into projected monthly income against expenses and healthcare costs, \
+             year by year.",
+        included: true,
+        assumptions: &[
+            "Amounts are entered in dollars of the first year of retirement. Social Security, \
+             portfolio withdrawals, and expenses rise with inflation each year; the pension \
+             rises with its own COLA.",
+            "Social Security is the estimate entered for the claiming age; nothing is paid \
+             before it, and no earnings test or spousal benefits are applied.",
+            "The annuity is a level monthly payout that pays the purchase down to zero over \
+             its term at the rate entered, then stops.",
+            "The portfolio pays its first-year withdrawal rate at the start of each year, \
+             raised with inflation, and earns a fixed return; withdrawals stop when it runs \
+             out.",
+            "Medicare Part B premiums start at 65 for each person covered, two when filing \
+             jointly, from the selected year's figures. IRMAA surcharges on Parts B and D use \
+             the income entered, which Medicare takes from the tax return two years earlier; \
+             the tiers are assumed to rise with income, so the tier never changes.",
+            "Premiums and out-of-pocket costs grow at the healthcare inflation rate rather than \
+             general inflation. Drug and Medigap plan premiums count as out of pocket.",
+            "Inflation scenarios rerun the plan with general inflation lowered and raised by \
+             the spread chosen; healthcare inflation stays as entered.",
+            "Federal income tax uses the selected year's brackets and standard deduction, \
+             raised with inflation. The pension, the annuity, traditional withdrawals, and 85% \
+             of Social Security are ordinary income; the gain share of brokerage withdrawals is \
+             taxed as long-term gains; Roth withdrawals are tax-free. State tax is not included.",
+            "Shortfalls compare income after federal tax with expenses; ages are counted in \
+             whole years.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "long_term_care",
+        name: "Long-Term Care",
+        description: "Weigh the probability-weighted cost of long-term care against an \
+             insurance policy's premiums and benefits, in present value.",
+        included: true,
+        assumptions: &[
+            "Each year you may die or start needing care at the rates in the table for your \
+             age, which are simplified planning figures rather than an insurer's; edit them to \
+             fit your health or family history.",
+            "Care comes at most once and lasts the years entered, costing today's yearly cost \
+             grown at the care cost growth rate; the projection ends at age 110.",
+            "Premiums are paid at the start of each year until care starts and never rise. The \
+             policy pays up to its yearly benefit, grown by any inflation protection, for its \
+             benefit years; no elimination period.",
+            "Amounts are weighted by their chances and discounted at the rate entered, all at \
+             the start of each year. No taxes, and the insurer is assumed to pay every claim.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "spia",
+        name: "Annuity Quote Comparison",
+        description: "Compare immediate annuity quotes by payout rate, break-even age, and the \
+             return each earns depending on how long you live.",
+        included: true,
+        assumptions: &[
+            "The premium is paid today and payouts start a month later, monthly for life; no \
+             period-certain guarantee, refund, or payment to a survivor.",
+            "A COLA rider raises the payout by its rate once a year, on each anniversary of \
+             the first payout.",
+            "Each return is the IRR of the premium and the payouts through the age shown, found \
+             by the NPV calculator's cash-flow solver and compounded to a yearly rate.",
+            "The break-even age is when the payouts add up to the premium, ignoring interest.",
+            "No taxes, fees beyond the quote, or insurer default.",
+            "Math runs in 64-bit floating point; rates are shown to two decimal places.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "savings_goal",
+        name: "Savings Goal",
+        description: "Find the monthly or other regular deposit needed to reach a savings goal \
+             by a target date, at a fixed rate and compounding frequency.",
+        included: true,
+        assumptions: &[
+            "The interest rate is a nominal annual rate, fixed until the goal date. When \
+             deposits are more or less frequent than compounding, each deposit earns the \
+             equivalent rate for its own period.",
+            "Deposits are equal and made at the end of each period unless the start is chosen; \
+             the time to the goal is rounded to a whole number of deposits.",
+            "An annual increase raises the deposit once a year, from the second year on, and \
+             needs a whole number of years to the goal.",
+            "Savings already set aside earn the same rate as the deposits.",
+            "No withdrawals, taxes, fees, or inflation.",
+            "Math runs in 64-bit floating point; the deposit is rounded to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "sinking_fund",
+        name: "Sinking Fund",
+        description: "Find the regular deposit that builds a fund up to a known future \
+             obligation, such as a bond redemption or a roof replacement, with every deposit \
+             scheduled.",
+        included: true,
+        assumptions: &[
+            "The interest rate is a nominal annual rate, fixed until the due date. When \
+             deposits are more or less frequent than compounding, each deposit earns the \
+             equivalent rate for its own period.",
+            "Deposits are equal and made at the end of each period unless the start is chosen; \
+             the time to the due date is rounded to a whole number of deposits.",
+            "The fund's current balance earns the same rate as the deposits.",
+            "Each period's interest is rounded to the cent, and the last deposit makes up \
+             whatever the rounding left.",
+            "No withdrawals before the due date, taxes, fees, or inflation.",
+            "Math runs in 64-bit floating point; dollar results are rounded to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "paycheck",
+        name: "Paycheck",
+        description: "Estimate take-home pay for one earner or a married couple filing jointly, \
+             and how much of a second income federal taxes keep.",
+        included: true,
+        assumptions: &[
+            "Federal income tax and FICA only; no state or local income tax.",
+            "Everyone takes the standard deduction for the filing status and has no income \
+             besides wages, and no credits.",
+            "Pretax deductions such as 401(k) and HSA contributions lower taxable income but \
+             not the wages FICA applies to.",
+            "Social Security is 6.2% of each earner's wages up to the year's wage base; Medicare \
+             is 1.45% of all wages, plus 0.9% of wages above $200,000, or above $250,000 of a \
+             couple's combined wages when filing jointly.",
+            "The second income's tax is the household's taxes less what the first earner would \
+             owe filing alone under the same status.",
+            "Taxes are figured for the year and spread evenly across paychecks.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "self_employment",
+        name: "W-2 vs 1099",
+        description: "Compare a W-2 salary with a 1099 contract rate after self-employment tax, \
+             benefits, and an employer's retirement match, and find the rate that breaks even.",
+        included: true,
+        assumptions: &[
+            "Federal income tax and FICA only; no state or local income tax, and no other \
+             income or credits.",
+            "Both offers take the standard deduction and save the same amount pretax for \
+             retirement, within the year's limits.",
+            "An employee pays 7.65% FICA on wages up to the Social Security wage base, plus \
+             0.9% additional Medicare above the threshold; the employer pays the other half.",
+            "A contractor pays 15.3% self-employment tax on 92.35% of net profit, up to the \
+             wage base for Social Security, and deducts half of it; the qualified business \
+             income deduction is not taken.",
+            "The employer's match and benefits count at their cost to the employer; a \
+             contractor would pay for the same benefits out of their own pay.",
+            "The break-even rate and equivalent salary are found by bisection.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "bonus",
+        name: "Bonus Withholding",
+        description: "See how much federal tax is withheld from a bonus and how much it actually \
+             adds to your tax bill, to find out whether it comes back at tax time.",
+        included: true,
+        assumptions: &[
+            "The bonus is withheld at the flat 22% supplemental rate, or 37% on supplemental \
+             pay above $1 million in the year, not added to a regular paycheck.",
+            "The tax the bonus adds is federal income tax on the year's pay with the bonus, \
+             less the tax without it; no state or local income tax.",
+            "The standard deduction for the filing status; no other income, deductions, or \
+             credits. When filing jointly, a spouse has no pay.",
+            "Regular paychecks are taken to withhold the salary's average income tax rate.",
+            "Social Security and Medicare on the bonus are withheld at what they cost.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "charitable",
+        name: "Charitable Giving",
+        description: "Estimate the tax benefit of giving cash or appreciated stock, and of \
+             bunching several years of gifts to itemize.",
+        included: true,
+        assumptions: &[
+            "Federal income tax only, under the brackets and standard deduction for the \
+             filing status; no state tax, credits, or net investment income tax.",
+            "Income is all ordinary and is also the adjusted gross income (AGI) the deduction \
+             limits use.",
+            "Gifts go to public charities. Cash is deductible up to 60% of AGI and stock held \
+             over a year up to 30%, at market value; any excess is not carried forward here.",
+            "The gains tax avoided is the long-term rate on the stock's gain, as if it were \
+             sold and the cash given instead.",
+            "Bunching gives several years' gifts in one year and takes the larger of the \
+             standard deduction and other itemized deductions in the years between, with \
+             income and tax law unchanged.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "sequence_risk",
+        name: "Sequence Risk",
+        description: "See how the order of investment returns changes how long retirement savings \
+             last when you withdraw from them.",
+        included: cfg!(feature = "simulation"),
+        assumptions: &[
+            "The withdrawal is a fixed dollar amount taken at the start of each year, with no \
+             inflation adjustment.",
+            "Returns step evenly from the average minus the spread to the average plus the \
+             spread; Retiree A gets them in rising order and Retiree B in falling order.",
+            "Returns are applied once a year to the balance left after the withdrawal.",
+            "Once the balance reaches zero it stays at zero.",
+            "No taxes, fees, or required minimum distributions.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "monte_carlo",
+        name: "Monte Carlo Retirement",
+        description: "Simulate thousands of random market paths for a retirement and see how \
+             often the savings last, with a fan chart of the range of balances.",
+        included: cfg!(feature = "simulation"),
+        assumptions: &[
+            "The withdrawal is taken at the start of each year and raised with inflation, which \
+             stays constant; the rest of the balance earns that year's simulated return.",
+            "Mean-and-volatility returns are independent normal draws each year. Historical \
+             returns are resampled in blocks of consecutive years, wrapping around the end of \
+             the history.",
+            "An asset allocation draws each asset class's return from a normal distribution \
+             with its own mean and volatility, correlated through the matrix (by Cholesky \
+             decomposition), and weights them into the portfolio's return; the weights are \
+             restored every year.",
+            "A simulation succeeds if it pays every withdrawal in full; once a withdrawal falls \
+             short the balance stays at zero.",
+            "Balances are shown in today's dollars, divided by (1 + inflation)^years.",
+            "Random numbers come from a seeded generator, so the same seed repeats the same \
+             paths.",
+            "No taxes, fees, or required minimum distributions.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "break_even_inflation",
+        name: "Break-even Inflation",
+        description: "Compare a Treasury bond with a TIPS bond and find the inflation rate at \
+             which they pay the same.",
+        included: cfg!(feature = "bonds"),
+        assumptions: &[
+            "Both bonds are held to maturity and have the same maturity.",
+            "Returns are annual yields compounded once a year; coupons are reinvested at the \
+             bond's own yield.",
+            "Inflation is constant at the assumed rate for the whole term, and the TIPS \
+             principal is adjusted by it in full (no deflation floor, no indexation lag).",
+            "Break-even inflation uses the exact Fisher relation (1 + n) / (1 + r) - 1, not \
+             the n - r shortcut.",
+            "No taxes, including the annual tax on TIPS inflation adjustments.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "bond_ladder",
+        name: "Bond Ladder",
+        description: "Build a ladder of bonds maturing in different years and see its yield and \
+             the cash it pays each year.",
+        included: cfg!(feature = "bonds"),
+        assumptions: &[
+            "Every bond pays coupons twice a year, starting six months from today, and \
+             maturities are rounded to whole six-month periods.",
+            "Bonds are bought today at the prices entered and held to maturity; no defaults \
+             or calls.",
+            "Cash flows are grouped into the year they are received; coupons are not reinvested.",
+            "Portfolio yield is the internal rate of return of all cash flows against the total \
+             cost, compounded semiannually (bond-equivalent yield).",
+            "No taxes, fees, or accrued interest at purchase.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "bond_price",
+        name: "Bond Pricing",
+        description: "Price a fixed-coupon bond from its yield, with accrued interest and every \
+             remaining payment.",
+        included: cfg!(feature = "bonds"),
+        assumptions: &[
+            "Coupons are level and paid on a regular schedule ending at maturity; the bond is \
+             held to maturity, with no default or call.",
+            "The yield is compounded once per coupon period, and payments are discounted by \
+             fractional periods from settlement (the street convention).",
+            "Accrued interest grows in a straight line over the coupon period; day counts such as \
+             30/360 or actual/actual are approximated by the fraction of a period entered.",
+            "The rate shock table shifts the yield 1 and 2 points each way. Its estimate uses \
+             the dirty price's modified duration; the exact column reprices the bond, so the \
+             gap between them is convexity.",
+            "No taxes or commissions.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "savings_bond",
+        name: "Savings Bond",
+        description: "Estimate what a Series EE savings bond is worth today and when it doubles.",
+        included: cfg!(feature = "bonds"),
+        assumptions: &[
+            "Series EE rules for bonds issued May 2005 or later: a fixed rate, added monthly \
+             and compounded semiannually.",
+            "At 20 years the Treasury makes a one-time adjustment so the bond is worth at least \
+             twice its purchase price; it then keeps earning the fixed rate.",
+            "Interest stops at 30 years.",
+            "Bonds can't be cashed in the first 12 months; cashing before 5 years forfeits the \
+             last 3 months of interest.",
+            "Holding periods are rounded to whole months. No federal income tax.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "cd",
+        name: "CD: Break or Hold",
+        description: "Decide whether to break a CD early, paying the penalty, to reinvest at a \
+             higher rate.",
+        included: cfg!(feature = "bonds"),
+        assumptions: &[
+            "Balances grow at the quoted APY, compounded to the exact month.",
+            "The early-withdrawal penalty is N months of simple interest on the deposit at the \
+             CD's APY, and it can reduce the deposit itself.",
+            "Money from a broken CD is reinvested right away at the new APY until the original \
+             maturity date, with no penalty on the new deposit.",
+            "No taxes; interest and penalties are treated as received on the day they occur.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "lease",
+        name: "Car Lease",
+        description: "Work out a car lease's monthly payment from its price, residual value, and \
+             money factor.",
+        included: cfg!(feature = "loans"),
+        assumptions: &[
+            "Money factor = APR / 2400 with APR in percent (the industry convention; it is an \
+             approximation of the true APR).",
+            "The payment is depreciation, (capitalized cost - residual) / term, plus a rent \
+             charge, (capitalized cost + residual) x money factor.",
+            "No down payment, trade-in, sales tax, or fees are included.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "lease_vs_buy",
+        name: "Lease vs Buy",
+        description: "Compare leasing a vehicle or equipment with financing its purchase over the \
+             same term, in today's dollars.",
+        included: cfg!(feature = "loans"),
+        assumptions: &[
+            "The lease and the loan run the same term, and the same down payment is paid at \
+             signing either way.",
+            "Lease payments are due at the start of each month and loan payments at the end; \
+             the loan is a fixed-rate loan with level monthly payments.",
+            "The lease payment is depreciation plus a rent charge, with money factor = APR / \
+             2400 with APR in percent.",
+            "A buyer's vehicle is worth the residual value when the term ends; a lessee returns \
+             it and owes nothing more.",
+            "Today's dollars discount each payment and the ending value monthly at the discount \
+             rate divided by 12.",
+            "No sales tax, fees, mileage charges, maintenance, or insurance.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "amortization",
+        name: "Amortization",
+        description: "See every payment of a fixed-rate loan, split into interest and principal, \
+             with the balance left after each.",
+        included: cfg!(feature = "loans"),
+        assumptions: &[
+            "A fixed-rate loan with level payments; the rate is a nominal annual rate divided \
+             evenly among the payments of each year.",
+            "Payments and interest are rounded to the cent each period; the last payment pays \
+             off whatever balance the rounding left.",
+            "Extra payments, if any, go entirely to principal with every payment. No fees.",
+            "Property tax grows with the home's assessed value. Where reassessment is capped, \
+             as under California's Proposition 13, it grows at the lesser of that growth and \
+             the cap.",
+            "Property tax and insurance, if entered, are paid from escrow once a year. Each \
+             annual analysis sets the next year's escrow from the bills just paid plus a \
+             two-month cushion, spreads any shortage over the next year, and refunds \
+             surpluses of $50 or more.",
+            "The rate shock table shows the payment at rates 1 and 2 points higher and lower \
+             over the full term, as when an adjustable rate resets; the estimate is a straight \
+             line from the payment's sensitivity to the rate.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "loan_compare",
+        name: "Loan Comparison",
+        description: "Compare up to five loan offers side by side: monthly payment, APR with fees \
+             and points, total interest, and five-year cost.",
+        included: cfg!(feature = "loans"),
+        assumptions: &[
+            "Each offer is a fixed-rate loan with level monthly payments for its whole term.",
+            "Fees and points are paid in cash at closing. Each point costs 1% of the loan \
+             amount.",
+            "APR is the rate at which the payments are worth the loan amount less fees and \
+             points, times 12. Lenders' disclosed APRs may count fees differently.",
+            "The five-year cost is fees and points, the interest paid in the first 60 \
+             payments, and any prepayment penalty for paying the loan off after them; principal \
+             repaid is not a cost.",
+            "A step-down penalty falls by an equal step each loan year. No loan that matures \
+             within five years is charged a penalty.",
+            "No escrow, mortgage insurance, or taxes.",
+            "Math runs in 64-bit floating point; dollar figures are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "closing_costs",
+        name: "Closing Costs",
+        description: "Itemize a mortgage's closing costs, from lender and title fees to prepaid \
+             interest and escrow, starting from regional presets.",
+        included: cfg!(feature = "loans"),
+        assumptions: &[
+            "Regional presets are rough typicals as a share of the loan amount, not quotes; \
+             replace them with the fees on your Loan Estimate.",
+            "Prepaid interest accrues on a 365-day year from closing to the end of the month.",
+            "The initial escrow deposit is the chosen months of yearly tax and insurance.",
+            "Only lender, title, and government fees are sent to the loan comparison; prepaids \
+             would be owed with any lender.",
+            "Mortgage insurance, HOA transfer fees, and the down payment are not included.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "debt_payoff",
+        name: "Debt Payoff",
+        description: "Compare paying debts off smallest balance first (snowball) or highest rate \
+             first (avalanche): time to debt-free and total interest.",
+        included: cfg!(feature = "loans"),
+        assumptions: &[
+            "Interest accrues monthly at each debt's annual rate / 12, rounded to the cent, and \
+             rates never change.",
+            "The budget is the same every month. Every debt gets its minimum, and the rest goes \
+             to one debt at a time in the strategy's order; paid-off minimums roll into it.",
+            "No new charges, fees, or promotional rates.",
+            "Payoff times are counted in months from the first payment.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "npv",
+        name: "NPV & IRR",
+        description: "Find the net present value and internal rate of return (IRR or XIRR) of \
+             cash flows by period or by date.",
+        included: true,
+        assumptions: &[
+            "Each cash flow is discounted at the same fixed rate, compounded once per period.",
+            "Dated flows are measured in years of 365 days from the earliest date, as in a \
+             spreadsheet's XNPV; the rate is then an annual rate.",
+            "The IRR (XIRR for dated flows) is found by bisection to about 1e-12; when the NPV \
+             crosses zero more than once, the lowest rate between -99% and 1,000% is shown.",
+            "Amounts are taken as entered, with no taxes, fees, or inflation.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "dcf",
+        name: "DCF Valuation",
+        description: "Value a business from projected free cash flows, a discount rate, and \
+             terminal growth, with the present value of each year.",
+        included: true,
+        assumptions: &[
+            "Each year's free cash flow arrives at the end of the year and is discounted at the \
+             same fixed rate, compounded yearly.",
+            "After the last projected year, free cash flow grows at the terminal rate forever \
+             (the Gordon growth model), which needs a discount rate above that growth.",
+            "The result is enterprise value, before subtracting debt or adding cash.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "capm",
+        name: "CAPM Expected Return",
+        description: "Find a stock's expected return, or cost of equity, from the risk-free \
+             rate, its beta, and the market risk premium.",
+        included: true,
+        assumptions: &[
+            "The capital asset pricing model: investors are paid only for market risk, in \
+             proportion to beta.",
+            "The risk-free rate, beta, and market risk premium are taken as entered and held \
+             constant; no estimate of beta from price history.",
+            "Math runs in 64-bit floating point; rates are shown to two decimal places.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "wacc",
+        name: "WACC",
+        description: "Find a company's weighted average cost of capital from the mix and cost \
+             of its equity and debt, and use it as the DCF discount rate.",
+        included: true,
+        assumptions: &[
+            "Equity and debt are weighted by the market values entered, held at that mix.",
+            "Interest is fully deductible at the tax rate entered, so debt costs its rate \
+             times (1 - tax rate); no preferred stock or other capital.",
+            "The costs are yearly rates taken as entered; the cost of equity can come from the \
+             CAPM or another model.",
+            "Math runs in 64-bit floating point; rates are shown to two decimal places.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "returns_stats",
+        name: "Sharpe & Sortino Ratios",
+        description: "Paste or upload a series of periodic returns to find their mean, \
+             volatility, downside deviation, and Sharpe and Sortino ratios.",
+        included: true,
+        assumptions: &[
+            "Returns are taken as entered, one per period, with no gaps; the mean is arithmetic \
+             and the standard deviation uses the sample (n - 1) formula.",
+            "The annual risk-free rate is compounded down to a per-period rate, and it is also \
+             the target return downside deviation is measured against, averaged over every \
+             period.",
+            "Annualized figures multiply the mean by the periods per year and the deviations \
+             and ratios by its square root, which assumes returns are independent from period \
+             to period.",
+            "Math runs in 64-bit floating point; rates are shown to two decimal places.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "options",
+        name: "Option Pricing",
+        description: "Price European calls and puts with the Black-Scholes model, and American \
+             or European options with a binomial tree, from the spot and strike prices, \
+             volatility, rates, and time to expiration.",
+        included: true,
+        assumptions: &[
+            "Black-Scholes prices European options, exercised only at expiration; the \
+             binomial tree prices either style. Each option is on one share.",
+            "The binomial tree is Cox-Ross-Rubinstein's, with up and down moves set by the \
+             volatility; American options are exercised at a step whenever that's worth more \
+             than holding.",
+            "The underlying follows a lognormal random walk with the constant volatility \
+             entered; no jumps, and the volatility doesn't change with the strike.",
+            "The risk-free rate and dividend yield are continuously compounded and constant; \
+             dividends are paid as a continuous yield rather than on set dates.",
+            "No transaction costs, taxes, or limits on borrowing or short selling.",
+            "The normal distribution uses a rational approximation accurate to about 1e-14.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+    CalculatorInfo {
+        slug: "break_even_units",
+        name: "Break-Even Point",
+        description: "Find how many units must be sold to cover fixed costs, and the revenue \
+             that takes, from the price and variable cost per unit.",
+        included: true,
+        assumptions: &[
+            "Fixed costs stay the same at every volume, and each unit costs the same to make and \
+             sells for the same price.",
+            "Every unit made is sold; there is no inventory, discounting, or capacity limit.",
+            "Break-even units = fixed costs / (price - variable cost), rounded up to a whole \
+             unit; break-even revenue is the exact break-even units times the price.",
+            "No taxes or financing costs.",
+            "Math runs in 64-bit floating point; dollar results are shown truncated to the cent.",
+        ],
+    },
+];
+
+/// Looks up a calculator by slug
+pub fn find_calculator(slug: &str) -> Option<&'static CalculatorInfo> {
+    CALCULATORS
+        .iter()
+        .find(|calculator| calculator.slug == slug)
+}
+
+/// The calculators compiled in and turned on by a theme, in navbar order
+pub fn enabled_calculators(theme: &ThemeConfig) -> Vec<CalculatorInfo> {
+    CALCULATORS
+        .iter()
+        .filter(|calculator| calculator.included && theme.calculator_enabled(calculator.slug))
+        .copied()
+        .collect()
+}
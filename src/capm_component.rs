@@ -0,0 +1,118 @@
+use crate::analytics::use_track_calculator;
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::business_math::{capm_expected_return, CapmInputs};
+use crate::feedback_component::FeedbackWidget;
+use crate::link::{CalculatorLink, SendLinkButton};
+use crate::numeric_input_component::NumericInput;
+use dioxus::prelude::*;
+
+/// A stock a little more volatile than the market, against a 4% Treasury yield
+const DEFAULT_INPUTS: CapmInputs = CapmInputs {
+    risk_free_rate: 0.04,
+    beta: 1.2,
+    market_risk_premium: 0.055,
+};
+
+#[component]
+pub fn CapmUI() -> Element {
+    // Rates are entered in percent
+    let mut risk_free_signal = use_signal(|| 4.0 as f64);
+    let mut risk_free_input = use_signal(|| "4.0".to_string());
+    let risk_free_input_valid = use_signal(|| true);
+    let mut beta_signal = use_signal(|| 1.2 as f64);
+    let mut beta_input = use_signal(|| "1.2".to_string());
+    let beta_input_valid = use_signal(|| true);
+    let mut premium_signal = use_signal(|| 5.5 as f64);
+    let mut premium_input = use_signal(|| "5.5".to_string());
+    let premium_input_valid = use_signal(|| true);
+
+    let inputs = move || CapmInputs {
+        risk_free_rate: risk_free_signal() / 100.0,
+        beta: beta_signal(),
+        market_risk_premium: premium_signal() / 100.0,
+    };
+    let start_fresh = use_autosave(
+        "capm",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: CapmInputs| {
+            risk_free_signal.set(inputs.risk_free_rate * 100.0);
+            risk_free_input.set(format!("{}", inputs.risk_free_rate * 100.0));
+            beta_signal.set(inputs.beta);
+            beta_input.set(format!("{}", inputs.beta));
+            premium_signal.set(inputs.market_risk_premium * 100.0);
+            premium_input.set(format!("{}", inputs.market_risk_premium * 100.0));
+        }),
+    );
+    use_track_calculator("capm");
+
+    let expected_return = capm_expected_return(&inputs());
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Risk-Free Rate (%):".to_string(),
+            placeholder: "Enter a Treasury yield matching your horizon (e.g., 4.0)".to_string(),
+            input_signal: risk_free_input,
+            value_signal: risk_free_signal,
+            valid_signal: risk_free_input_valid,
+            field_name: "Risk-free rate".to_string(),
+            css_prefix: "risk-free".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+        NumericInput {
+            label: "Beta:".to_string(),
+            placeholder: "Enter how much the stock moves with the market (e.g., 1.2)".to_string(),
+            input_signal: beta_input,
+            value_signal: beta_signal,
+            valid_signal: beta_input_valid,
+            field_name: "Beta".to_string(),
+            css_prefix: "beta".to_string(),
+            allow_negative: true,
+            step: Some(0.1),
+        }
+        NumericInput {
+            label: "Market Risk Premium (%):".to_string(),
+            placeholder: "Enter the market's expected return above the risk-free rate (e.g., 5.5)".to_string(),
+            input_signal: premium_input,
+            value_signal: premium_signal,
+            valid_signal: premium_input_valid,
+            field_name: "Market risk premium".to_string(),
+            css_prefix: "premium".to_string(),
+            step: Some(0.25),
+        }
+
+        div {
+            id: "CapmCalculation",
+            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+            "Expected return: {expected_return * 100.0:.2}%*"
+        }
+        div {
+            id: "CapmFormula",
+            style: "margin-bottom: 15px; font-size: 14px; font-family: monospace;",
+            p { "E(R) = Rf + β × (E(Rm) − Rf)" }
+            p {
+                "= {risk_free_signal():.2}% + {beta_signal():.2} × {premium_signal():.2}% = {expected_return * 100.0:.2}%"
+            }
+        }
+        if expected_return.is_finite() {
+            SendLinkButton { link: CalculatorLink::CostOfEquity(expected_return) }
+            SendLinkButton { link: CalculatorLink::DiscountRate(expected_return) }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "capm" }
+        AssumptionsFooter { calculator: "capm" }
+        div { style: "font-size: 10px",
+            p {
+                "*The yearly return investors need to hold the stock, and its cost of equity. Send it to the WACC calculator to blend it with debt, or straight to a DCF for a company with no debt."
+            }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+// Computes the balance of a certificate of deposit after a number of months.
+///
+/// # Parameters:
+/// - `deposit`: Amount deposited (P)
+/// - `apy`: Annual percentage yield (APY), e.g., 0.045 for 4.5%
+/// - `months`: Months since the deposit (m)
+///
+/// # Formula:
+/// B = P * (1 + APY)^(m/12)
+///
+/// # Returns:
+/// The balance, not rounded.
+pub fn cd_balance(deposit: f64, apy: f64, months: f64) -> f64 {
+    deposit * (1.0 + apy).powf(months / 12.0)
+}
+
+// Computes an early-withdrawal penalty of N months of interest.
+///
+/// # Parameters:
+/// - `deposit`: Amount deposited (P)
+/// - `apy`: The CD's annual percentage yield (APY)
+/// - `penalty_months`: Months of interest forfeited (N)
+///
+/// # Formula:
+/// penalty = P * APY * N / 12   (simple interest on the deposit, the usual bank convention)
+///
+/// # Returns:
+/// The penalty in dollars. It can exceed the interest earned so far, eating into the deposit.
+pub fn early_withdrawal_penalty(deposit: f64, apy: f64, penalty_months: f64) -> f64 {
+    deposit * apy * penalty_months / 12.0
+}
+
+/// Inputs to the CD break-or-hold calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CdInputs {
+    pub deposit: f64,
+    /// The current CD's APY as a decimal
+    pub apy: f64,
+    pub term_months: f64,
+    /// Months since the CD was opened
+    pub months_elapsed: f64,
+    /// Early-withdrawal penalty in months of interest
+    pub penalty_months: f64,
+    /// APY available today for the rest of the term
+    pub new_apy: f64,
+}
+
+/// Results of the CD break-or-hold calculator
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CdOutputs {
+    /// Balance at maturity if the CD is held
+    pub hold_value: f64,
+    pub penalty: f64,
+    /// Cash received on breaking the CD today, after the penalty
+    pub break_proceeds: f64,
+    /// Proceeds reinvested at the new APY until the original maturity date
+    pub break_value: f64,
+    /// New APY at which breaking and holding end up the same
+    pub break_even_apy: f64,
+}
+
+impl CdOutputs {
+    pub fn breaking_wins(&self) -> bool {
+        self.break_value > self.hold_value
+    }
+}
+
+/// Compares holding a CD to maturity with breaking it and reinvesting at today's rate
+pub fn compute_cd(inputs: &CdInputs) -> CdOutputs {
+    let months_elapsed = inputs.months_elapsed.clamp(0.0, inputs.term_months);
+    let months_remaining = inputs.term_months - months_elapsed;
+    let hold_value = cd_balance(inputs.deposit, inputs.apy, inputs.term_months);
+    let penalty = early_withdrawal_penalty(inputs.deposit, inputs.apy, inputs.penalty_months);
+    let break_proceeds =
+        (cd_balance(inputs.deposit, inputs.apy, months_elapsed) - penalty).max(0.0);
+    let break_value = cd_balance(break_proceeds, inputs.new_apy, months_remaining);
+    let break_even_apy = if break_proceeds > 0.0 && months_remaining > 0.0 {
+        (hold_value / break_proceeds).powf(12.0 / months_remaining) - 1.0
+    } else {
+        f64::NAN
+    };
+
+    CdOutputs {
+        hold_value,
+        penalty,
+        break_proceeds,
+        break_value,
+        break_even_apy,
+    }
+}
@@ -0,0 +1,174 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::cd::{compute_cd, CdInputs};
+use crate::feedback_component::FeedbackWidget;
+use crate::number_format::dollars;
+use crate::numeric_input_component::NumericInput;
+use dioxus::prelude::*;
+
+const DEFAULT_INPUTS: CdInputs = CdInputs {
+    deposit: 10_000.0,
+    apy: 0.03,
+    term_months: 60.0,
+    months_elapsed: 12.0,
+    penalty_months: 6.0,
+    new_apy: 0.05,
+};
+
+#[component]
+pub fn CdUI() -> Element {
+    let mut deposit_signal = use_signal(|| 10_000.0 as f64);
+    let mut deposit_input = use_signal(|| "10000".to_string());
+    let deposit_input_valid = use_signal(|| true);
+    let mut apy_signal = use_signal(|| 3.0 as f64);
+    let mut apy_input = use_signal(|| "3.0".to_string());
+    let apy_input_valid = use_signal(|| true);
+    let mut term_signal = use_signal(|| 60.0 as f64);
+    let mut term_input = use_signal(|| "60".to_string());
+    let term_input_valid = use_signal(|| true);
+    let mut elapsed_signal = use_signal(|| 12.0 as f64);
+    let mut elapsed_input = use_signal(|| "12".to_string());
+    let elapsed_input_valid = use_signal(|| true);
+    let mut penalty_signal = use_signal(|| 6.0 as f64);
+    let mut penalty_input = use_signal(|| "6".to_string());
+    let penalty_input_valid = use_signal(|| true);
+    let mut new_apy_signal = use_signal(|| 5.0 as f64);
+    let mut new_apy_input = use_signal(|| "5.0".to_string());
+    let new_apy_input_valid = use_signal(|| true);
+    let start_fresh = use_autosave(
+        "cd",
+        DEFAULT_INPUTS,
+        move || CdInputs {
+            deposit: deposit_signal(),
+            apy: apy_signal() / 100.0,
+            term_months: term_signal(),
+            months_elapsed: elapsed_signal(),
+            penalty_months: penalty_signal(),
+            new_apy: new_apy_signal() / 100.0,
+        },
+        use_callback(move |inputs: CdInputs| {
+            deposit_signal.set(inputs.deposit);
+            deposit_input.set(format!("{}", inputs.deposit));
+            apy_signal.set(inputs.apy * 100.0);
+            apy_input.set(format!("{:.2}", inputs.apy * 100.0));
+            term_signal.set(inputs.term_months);
+            term_input.set(format!("{}", inputs.term_months));
+            elapsed_signal.set(inputs.months_elapsed);
+            elapsed_input.set(format!("{}", inputs.months_elapsed));
+            penalty_signal.set(inputs.penalty_months);
+            penalty_input.set(format!("{}", inputs.penalty_months));
+            new_apy_signal.set(inputs.new_apy * 100.0);
+            new_apy_input.set(format!("{:.2}", inputs.new_apy * 100.0));
+        }),
+    );
+    use_track_calculator("cd");
+    use_track_input_range("cd", "deposit", deposit_signal, amount_bucket);
+
+    let outputs = compute_cd(&CdInputs {
+        deposit: deposit_signal(),
+        apy: apy_signal() / 100.0,
+        term_months: term_signal(),
+        months_elapsed: elapsed_signal(),
+        penalty_months: penalty_signal(),
+        new_apy: new_apy_signal() / 100.0,
+    });
+    let hold_value = dollars(outputs.hold_value);
+    let break_value = dollars(outputs.break_value);
+    let penalty = dollars(outputs.penalty);
+    let proceeds = dollars(outputs.break_proceeds);
+    let verdict = if outputs.breaking_wins() {
+        "Breaking the CD and reinvesting comes out ahead"
+    } else {
+        "Holding the CD to maturity comes out ahead"
+    };
+    let break_even_apy = outputs.break_even_apy * 100.0;
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Deposit ($):".to_string(),
+            placeholder: "Enter amount deposited (e.g., 10000)".to_string(),
+            input_signal: deposit_input,
+            value_signal: deposit_signal,
+            valid_signal: deposit_input_valid,
+            field_name: "Deposit".to_string(),
+            css_prefix: "deposit".to_string(),
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Current CD APY (%):".to_string(),
+            placeholder: "Enter the CD's APY (e.g., 3.0)".to_string(),
+            input_signal: apy_input,
+            value_signal: apy_signal,
+            valid_signal: apy_input_valid,
+            field_name: "CD APY".to_string(),
+            css_prefix: "apy".to_string(),
+            step: Some(0.125),
+        }
+        NumericInput {
+            label: "Term (months):".to_string(),
+            placeholder: "Enter the CD's term (e.g., 60)".to_string(),
+            input_signal: term_input,
+            value_signal: term_signal,
+            valid_signal: term_input_valid,
+            field_name: "Term".to_string(),
+            css_prefix: "term".to_string(),
+            step: Some(1.0),
+        }
+        NumericInput {
+            label: "Months Since Opening:".to_string(),
+            placeholder: "Enter months elapsed (e.g., 12)".to_string(),
+            input_signal: elapsed_input,
+            value_signal: elapsed_signal,
+            valid_signal: elapsed_input_valid,
+            field_name: "Months since opening".to_string(),
+            css_prefix: "elapsed".to_string(),
+            step: Some(1.0),
+        }
+        NumericInput {
+            label: "Penalty (months of interest):".to_string(),
+            placeholder: "Enter the penalty (e.g., 6)".to_string(),
+            input_signal: penalty_input,
+            value_signal: penalty_signal,
+            valid_signal: penalty_input_valid,
+            field_name: "Penalty".to_string(),
+            css_prefix: "penalty".to_string(),
+            step: Some(1.0),
+        }
+        NumericInput {
+            label: "New APY Available (%):".to_string(),
+            placeholder: "Enter today's APY for the rest of the term (e.g., 5.0)".to_string(),
+            input_signal: new_apy_input,
+            value_signal: new_apy_signal,
+            valid_signal: new_apy_input_valid,
+            field_name: "New APY".to_string(),
+            css_prefix: "new-apy".to_string(),
+            step: Some(0.125),
+        }
+
+        div {
+            id: "CdCalculation",
+            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+            "{verdict}: {break_value} by breaking vs {hold_value} by holding*"
+        }
+        div { style: "margin-bottom: 15px; font-size: 16px;",
+            "Breaking today costs a {penalty} penalty and pays out {proceeds}. "
+            if break_even_apy.is_finite() {
+                "Breaking pays off if the new APY is above {break_even_apy:.2}%."
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "cd" }
+        AssumptionsFooter { calculator: "cd" }
+        div { style: "font-size: 10px",
+            p { "*Penalty rules vary by bank; check your CD's disclosure." }
+            br {}
+            br {}
+        }
+    }
+}
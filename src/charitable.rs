@@ -0,0 +1,125 @@
+use crate::tax::{tax_table, FilingStatus, TaxTable};
+use serde::{Deserialize, Serialize};
+
+/// Most cash given to public charities that can be deducted, as a share of AGI
+pub const CASH_AGI_LIMIT: f64 = 0.60;
+/// Most appreciated stock held over a year that can be deducted at market value, as a share of AGI
+pub const STOCK_AGI_LIMIT: f64 = 0.30;
+
+/// Inputs to the charitable giving calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CharitableInputs {
+    pub status: FilingStatus,
+    /// Adjusted gross income, all ordinary
+    pub income: f64,
+    /// Itemized deductions other than gifts, e.g., state and local taxes and mortgage interest
+    pub other_itemized: f64,
+    /// Cash given in a usual year
+    pub cash_gift: f64,
+    /// Market value of long-term appreciated stock given in a usual year
+    pub stock_value: f64,
+    /// What that stock cost
+    pub stock_basis: f64,
+    /// Years of gifts given at once when bunching, e.g., 3 to give every third year
+    pub bunch_years: u32,
+}
+
+/// Federal tax in one year, given what was donated that year
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GivingYear {
+    /// Gifts that count toward itemized deductions, after the AGI limits
+    pub gifts_deducted: f64,
+    /// The larger of the standard deduction and the itemized total
+    pub deduction: f64,
+    pub itemizes: bool,
+    pub tax: f64,
+    /// Long-term gains tax that selling the donated stock would have cost
+    pub capital_gains_avoided: f64,
+}
+
+/// A usual year of giving, and bunching several years of it into one
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CharitableBenefit {
+    /// Tax in a year with no gifts; bunching's off years pay this
+    pub baseline_tax: f64,
+    pub annual: GivingYear,
+    /// Tax the usual gifts save each year
+    pub annual_savings: f64,
+    /// The year all the bunched gifts are given
+    pub bunched: GivingYear,
+    /// Tax saved over the bunching cycle compared with giving every year
+    pub bunching_savings: f64,
+}
+
+/// Tax in a year giving `years` usual years of gifts at once
+fn giving_year(inputs: &CharitableInputs, table: &TaxTable, years: f64) -> GivingYear {
+    let cash = inputs.cash_gift * years;
+    let stock = inputs.stock_value * years;
+    let cash_deducted = cash.min(CASH_AGI_LIMIT * inputs.income);
+    let stock_deducted = stock
+        .min(STOCK_AGI_LIMIT * inputs.income)
+        .min(CASH_AGI_LIMIT * inputs.income - cash_deducted);
+    let gifts_deducted = cash_deducted + stock_deducted;
+    let itemized = inputs.other_itemized + gifts_deducted;
+    let deduction = itemized.max(table.standard_deduction);
+    let taxable = (inputs.income - deduction).max(0.0);
+    let gain = (inputs.stock_value - inputs.stock_basis).max(0.0) * years;
+
+    GivingYear {
+        gifts_deducted,
+        deduction,
+        itemizes: itemized > table.standard_deduction,
+        tax: table.ordinary_tax(taxable),
+        capital_gains_avoided: table.capital_gains_tax(taxable, gain),
+    }
+}
+
+// Estimates the federal tax benefit of charitable gifts, given yearly or bunched.
+///
+/// # Parameters:
+/// - `inputs`: Income, other itemized deductions, the usual yearly gifts of cash and stock, and
+///   how many years of gifts to bunch together
+/// - `year`: Tax year whose brackets and standard deduction apply
+///
+/// # Formula:
+/// deduction = max(standard, other itemized + min(cash, 60% AGI) + min(stock, 30% AGI))
+/// tax = brackets(AGI - deduction)
+/// bunching savings = N * tax(one year of gifts) - tax(N years of gifts) - (N - 1) * tax(no gifts)
+/// Stock is deducted at market value, and its gain is never taxed; the gains tax avoided is
+/// the long-term rate on the gain stacked on the year's taxable income.
+///
+/// # Returns:
+/// Tax in a usual giving year and in a bunched year, and what each saves. An error if an entry
+/// is negative or no years are bunched.
+pub fn compute_charitable_benefit(
+    inputs: &CharitableInputs,
+    year: i32,
+) -> Result<CharitableBenefit, String> {
+    let entries = [
+        inputs.income,
+        inputs.other_itemized,
+        inputs.cash_gift,
+        inputs.stock_value,
+        inputs.stock_basis,
+    ];
+    if entries.iter().any(|entry| entry.is_nan() || *entry < 0.0) {
+        return Err("Income, deductions, and gifts can't be negative".to_string());
+    }
+    if inputs.bunch_years == 0 {
+        return Err("Bunch at least one year of gifts".to_string());
+    }
+
+    let table = tax_table(year, inputs.status);
+    let baseline = giving_year(inputs, table, 0.0);
+    let annual = giving_year(inputs, table, 1.0);
+    let years = inputs.bunch_years as f64;
+    let bunched = giving_year(inputs, table, years);
+
+    Ok(CharitableBenefit {
+        baseline_tax: baseline.tax,
+        annual,
+        annual_savings: baseline.tax - annual.tax,
+        bunched,
+        bunching_savings: years * annual.tax - bunched.tax - (years - 1.0) * baseline.tax,
+    })
+}
@@ -0,0 +1,294 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::charitable::{compute_charitable_benefit, CharitableInputs, GivingYear};
+use crate::data_year::{use_data_year, DataYearBanner};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::numeric_input_component::NumericInput;
+use crate::tax::FilingStatus;
+use dioxus::prelude::*;
+
+/// A couple whose usual gifts fall short of the standard deduction, so only bunching itemizes
+const DEFAULT_INPUTS: CharitableInputs = CharitableInputs {
+    status: FilingStatus::MarriedFilingJointly,
+    income: 150_000.0,
+    other_itemized: 15_000.0,
+    cash_gift: 8_000.0,
+    stock_value: 4_000.0,
+    stock_basis: 1_000.0,
+    bunch_years: 3,
+};
+
+const STATUS_OPTIONS: &[(FilingStatus, &str)] = &[
+    (FilingStatus::Single, "single"),
+    (FilingStatus::MarriedFilingJointly, "joint"),
+];
+
+const BUNCH_OPTIONS: &[u32] = &[2, 3, 4, 5];
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+const LABEL_STYLE: &str = "text-align: left; padding: 2px 8px;";
+
+/// The deduction a year takes, named by kind
+fn deduction_text(year: &GivingYear) -> String {
+    format!(
+        "${} {}",
+        format_dollars(year.deduction),
+        if year.itemizes {
+            "itemized"
+        } else {
+            "standard"
+        }
+    )
+}
+
+#[component]
+pub fn CharitableUI() -> Element {
+    let tax_year = use_data_year("tax_brackets");
+    let mut status_signal = use_signal(|| FilingStatus::MarriedFilingJointly);
+    let mut income_signal = use_signal(|| 150_000.0 as f64);
+    let mut income_input = use_signal(|| "150000".to_string());
+    let income_input_valid = use_signal(|| true);
+    let mut itemized_signal = use_signal(|| 15_000.0 as f64);
+    let mut itemized_input = use_signal(|| "15000".to_string());
+    let itemized_input_valid = use_signal(|| true);
+    let mut cash_signal = use_signal(|| 8_000.0 as f64);
+    let mut cash_input = use_signal(|| "8000".to_string());
+    let cash_input_valid = use_signal(|| true);
+    let mut stock_signal = use_signal(|| 4_000.0 as f64);
+    let mut stock_input = use_signal(|| "4000".to_string());
+    let stock_input_valid = use_signal(|| true);
+    let mut basis_signal = use_signal(|| 1_000.0 as f64);
+    let mut basis_input = use_signal(|| "1000".to_string());
+    let basis_input_valid = use_signal(|| true);
+    let mut bunch_signal = use_signal(|| 3u32);
+
+    let inputs = move || CharitableInputs {
+        status: status_signal(),
+        income: income_signal(),
+        other_itemized: itemized_signal(),
+        cash_gift: cash_signal(),
+        stock_value: stock_signal(),
+        stock_basis: basis_signal(),
+        bunch_years: bunch_signal(),
+    };
+    let start_fresh = use_autosave(
+        "charitable",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: CharitableInputs| {
+            status_signal.set(inputs.status);
+            income_signal.set(inputs.income);
+            income_input.set(format!("{}", inputs.income));
+            itemized_signal.set(inputs.other_itemized);
+            itemized_input.set(format!("{}", inputs.other_itemized));
+            cash_signal.set(inputs.cash_gift);
+            cash_input.set(format!("{}", inputs.cash_gift));
+            stock_signal.set(inputs.stock_value);
+            stock_input.set(format!("{}", inputs.stock_value));
+            basis_signal.set(inputs.stock_basis);
+            basis_input.set(format!("{}", inputs.stock_basis));
+            bunch_signal.set(inputs.bunch_years);
+        }),
+    );
+    use_track_calculator("charitable");
+    use_track_input_range("charitable", "cash_gift", cash_signal, amount_bucket);
+
+    rsx! {
+        hr {}
+        br {}
+
+        DataYearBanner { data_set: "tax_brackets", year: tax_year }
+
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Filing Status:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(status, _)) = STATUS_OPTIONS
+                        .iter()
+                        .find(|(_, value_str)| *value_str == value.as_str())
+                    {
+                        status_signal.set(status);
+                    }
+                },
+                for (status , value) in STATUS_OPTIONS.iter() {
+                    option { value: *value, selected: status_signal() == *status, {status.label()} }
+                }
+            }
+        }
+        NumericInput {
+            label: "Income ($/yr):".to_string(),
+            placeholder: "Enter your adjusted gross income (e.g., 150000)".to_string(),
+            input_signal: income_input,
+            value_signal: income_signal,
+            valid_signal: income_input_valid,
+            field_name: "Income".to_string(),
+            css_prefix: "income".to_string(),
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Other Itemized Deductions ($/yr):".to_string(),
+            placeholder: "Enter state and local taxes, mortgage interest, and the like (e.g., 15000)".to_string(),
+            input_signal: itemized_input,
+            value_signal: itemized_signal,
+            valid_signal: itemized_input_valid,
+            field_name: "Other itemized deductions".to_string(),
+            css_prefix: "itemized".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Cash Gifts ($/yr):".to_string(),
+            placeholder: "Enter the cash you give in a usual year (e.g., 8000)".to_string(),
+            input_signal: cash_input,
+            value_signal: cash_signal,
+            valid_signal: cash_input_valid,
+            field_name: "Cash gifts".to_string(),
+            css_prefix: "cash".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Stock Gifts, Market Value ($/yr):".to_string(),
+            placeholder: "Enter the value of stock held over a year you give (e.g., 4000)".to_string(),
+            input_signal: stock_input,
+            value_signal: stock_signal,
+            valid_signal: stock_input_valid,
+            field_name: "Stock gifts".to_string(),
+            css_prefix: "stock".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Stock Gifts, Cost Basis ($/yr):".to_string(),
+            placeholder: "Enter what that stock cost you (e.g., 1000)".to_string(),
+            input_signal: basis_input,
+            value_signal: basis_signal,
+            valid_signal: basis_input_valid,
+            field_name: "Cost basis".to_string(),
+            css_prefix: "basis".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Bunch Gifts Every:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    if let Ok(years) = event.value().parse::<u32>() {
+                        bunch_signal.set(years);
+                    }
+                },
+                for years in BUNCH_OPTIONS.iter() {
+                    option { value: "{years}", selected: bunch_signal() == *years, "{years} years" }
+                }
+            }
+        }
+
+        {
+            match compute_charitable_benefit(&inputs(), tax_year()) {
+                Ok(benefit) => {
+                    let years = bunch_signal();
+                    // Rounded so savings of whole cents aren't shown a cent short
+                    let annual_savings = (benefit.annual_savings * 100.0).round() / 100.0;
+                    let bunching_savings = (benefit.bunching_savings * 100.0).round() / 100.0;
+                    let annual_total = benefit.annual.tax * years as f64;
+                    let bunched_total =
+                        benefit.bunched.tax + benefit.baseline_tax * (years - 1) as f64;
+                    let bunching = if bunching_savings > 0.0 {
+                        format!(
+                            "Bunching {} years of gifts into one saves ${} more over those {} years.",
+                            years,
+                            format_dollars(bunching_savings),
+                            years
+                        )
+                    } else {
+                        format!(
+                            "Bunching {} years of gifts into one saves no more than giving every year.",
+                            years
+                        )
+                    };
+                    rsx! {
+                        div {
+                            id: "CharitableCalculation",
+                            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                            "Giving every year saves ${format_dollars(annual_savings)} a year in federal income tax*"
+                        }
+                        div { id: "CharitableBunching", style: "margin-bottom: 15px; font-size: 16px;",
+                            "{bunching}"
+                        }
+                        table { style: "color: #333; border-collapse: collapse; font-size: 14px; margin-bottom: 15px;",
+                            thead {
+                                tr {
+                                    th {}
+                                    th { scope: "col", style: CELL_STYLE, "Give every year" }
+                                    th { scope: "col", style: CELL_STYLE, "Bunch {years} years" }
+                                }
+                            }
+                            tbody {
+                                tr {
+                                    th { scope: "row", style: LABEL_STYLE, "Gifts deducted in a giving year" }
+                                    td { style: CELL_STYLE, "${format_dollars(benefit.annual.gifts_deducted)}" }
+                                    td { style: CELL_STYLE, "${format_dollars(benefit.bunched.gifts_deducted)}" }
+                                }
+                                tr {
+                                    th { scope: "row", style: LABEL_STYLE, "Deduction in a giving year" }
+                                    td { style: CELL_STYLE, {deduction_text(&benefit.annual)} }
+                                    td { style: CELL_STYLE, {deduction_text(&benefit.bunched)} }
+                                }
+                                tr {
+                                    th { scope: "row", style: LABEL_STYLE, "Tax in a giving year" }
+                                    td { style: CELL_STYLE, "${format_dollars(benefit.annual.tax)}" }
+                                    td { style: CELL_STYLE, "${format_dollars(benefit.bunched.tax)}" }
+                                }
+                                tr {
+                                    th { scope: "row", style: LABEL_STYLE, "Tax over {years} years" }
+                                    td { style: CELL_STYLE, "${format_dollars(annual_total)}" }
+                                    td { style: CELL_STYLE, "${format_dollars(bunched_total)}" }
+                                }
+                                tr {
+                                    th { scope: "row", style: LABEL_STYLE, "Gains tax avoided by giving stock" }
+                                    td { style: CELL_STYLE,
+                                        "${format_dollars(benefit.annual.capital_gains_avoided)}"
+                                    }
+                                    td { style: CELL_STYLE,
+                                        "${format_dollars(benefit.bunched.capital_gains_avoided)}"
+                                    }
+                                }
+                            }
+                        }
+                        div { style: "margin-bottom: 15px; font-size: 16px;",
+                            "Giving stock you've held over a year instead of selling it and giving the cash avoids the tax on its gain, and it's still deducted at its market value."
+                        }
+                    }
+                }
+                Err(error) => rsx! {
+                    div {
+                        id: "CharitableCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "charitable" }
+        AssumptionsFooter { calculator: "charitable" }
+        div { style: "font-size: 10px",
+            p {
+                "*Federal income tax only, compared with giving nothing. Bunching gives several years' gifts in one year, often through a donor-advised fund, and takes the standard deduction in the years between."
+            }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,787 @@
+use crate::inflation_scenarios::{InflationCase, ScenarioResults};
+use crate::number_format::{use_number_format, AxisFormat};
+#[cfg(feature = "simulation")]
+use crate::simulation::PercentileBands;
+use dioxus::prelude::*;
+
+const CHART_WIDTH: f64 = 600.0;
+const CHART_HEIGHT: f64 = 240.0;
+const MARGIN_LEFT: f64 = 80.0;
+const MARGIN_RIGHT: f64 = 10.0;
+const MARGIN_TOP: f64 = 10.0;
+const MARGIN_BOTTOM: f64 = 30.0;
+
+/// One line on a chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartSeries {
+    pub label: String,
+    pub color: String,
+    pub values: Vec<f64>,
+}
+
+fn plot_width() -> f64 {
+    CHART_WIDTH - MARGIN_LEFT - MARGIN_RIGHT
+}
+
+fn plot_height() -> f64 {
+    CHART_HEIGHT - MARGIN_TOP - MARGIN_BOTTOM
+}
+
+/// X pixel position of point `index` out of `n_points`
+fn x_position(index: usize, n_points: usize) -> f64 {
+    if n_points < 2 {
+        return MARGIN_LEFT;
+    }
+    MARGIN_LEFT + index as f64 / (n_points - 1) as f64 * plot_width()
+}
+
+/// Y pixel position of `value` on an axis running from zero to `y_max`
+fn y_position(value: f64, y_max: f64) -> f64 {
+    let fraction = if y_max > 0.0 {
+        (value / y_max).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    MARGIN_TOP + (1.0 - fraction) * plot_height()
+}
+
+/// Header and rows for the table view of `series`: one row per x value, one column per series
+fn series_table(
+    x_label: &str,
+    x_start: f64,
+    x_step: f64,
+    series: &[ChartSeries],
+    format_value: impl Fn(f64) -> String,
+) -> (Vec<String>, Vec<Vec<String>>) {
+    let n_points = series.iter().map(|s| s.values.len()).max().unwrap_or(0);
+    let columns = std::iter::once(x_label.to_string())
+        .chain(series.iter().map(|s| s.label.clone()))
+        .collect();
+    let rows = (0..n_points)
+        .map(|i| {
+            std::iter::once(format!("{}", x_start + i as f64 * x_step))
+                .chain(series.iter().map(|s| {
+                    s.values
+                        .get(i)
+                        .map(|v| format_value(*v))
+                        .unwrap_or_default()
+                }))
+                .collect()
+        })
+        .collect();
+    (columns, rows)
+}
+
+/// Chart title with a toggle between the chart and its table view
+#[component]
+fn ChartHeader(title: String, show_table: Signal<bool>) -> Element {
+    rsx! {
+        div { style: "display: flex; align-items: center; gap: 10px; margin-bottom: 5px;",
+            div { style: "color: #333; font-weight: bold;", {title} }
+            button {
+                style: "padding: 2px 8px; border-radius: 4px; font-size: 12px;",
+                aria_pressed: "{show_table}",
+                onclick: move |_| show_table.toggle(),
+                if show_table() {
+                    "View as chart"
+                } else {
+                    "View as table"
+                }
+            }
+        }
+    }
+}
+
+/// A chart's data as an HTML table, for screen readers and for copying into a spreadsheet. The
+/// first cell of each row labels it.
+#[component]
+fn ChartTable(caption: String, columns: Vec<String>, rows: Vec<Vec<String>>) -> Element {
+    rsx! {
+        div { style: "max-height: 240px; overflow-y: auto; margin-bottom: 5px;", tabindex: "0",
+            table { style: "color: #333; border-collapse: collapse; font-size: 12px;",
+                caption { style: "position: absolute; width: 1px; height: 1px; overflow: hidden; clip: rect(0 0 0 0);",
+                    {caption}
+                }
+                thead {
+                    tr {
+                        for column in columns.iter() {
+                            th {
+                                scope: "col",
+                                style: "text-align: right; padding: 2px 8px; border-bottom: 1px solid #999;",
+                                {column.clone()}
+                            }
+                        }
+                    }
+                }
+                tbody {
+                    for row in rows.iter() {
+                        tr {
+                            for (i , cell) in row.iter().enumerate() {
+                                if i == 0 {
+                                    th { scope: "row", style: "text-align: right; padding: 2px 8px;", {cell.clone()} }
+                                } else {
+                                    td { style: "text-align: right; padding: 2px 8px;", {cell.clone()} }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Line chart of one or more series over a shared x axis (e.g., years).
+///
+/// Point `i` of every series is plotted at x label `x_start + i * x_step`. Passing the same
+/// `hover_index` signal to several charts keeps their hover markers in sync; `y_max` pins the y
+/// axis so side-by-side charts share a scale.
+#[component]
+pub fn LineChart(
+    title: String,
+    series: Vec<ChartSeries>,
+    x_start: f64,
+    hover_index: Signal<Option<usize>>,
+    y_max: Option<f64>,
+    #[props(default)] y_format: AxisFormat,
+    #[props(default = "Year".to_string())] x_label: String,
+    #[props(default = 1.0)] x_step: f64,
+) -> Element {
+    let number_format = use_number_format();
+    let format_axis_value = move |value: f64| y_format.format(value, &number_format);
+    let n_points = series.iter().map(|s| s.values.len()).max().unwrap_or(0);
+    let y_max = y_max.unwrap_or_else(|| {
+        series
+            .iter()
+            .flat_map(|s| s.values.iter().copied())
+            .fold(0.0, f64::max)
+    });
+    let x_tick_step = std::cmp::max(1, n_points / 8);
+    let hovered = hover_index().filter(|i| *i < n_points);
+    let show_table = use_signal(|| false);
+    let (table_columns, table_rows) =
+        series_table(&x_label, x_start, x_step, &series, &format_axis_value);
+
+    rsx! {
+        div { style: "margin-bottom: 15px;",
+            ChartHeader { title: title.clone(), show_table }
+            if show_table() {
+                ChartTable { caption: title.clone(), columns: table_columns, rows: table_rows }
+            } else {
+                svg {
+                    "role": "img",
+                    "aria-label": "{title}",
+                    width: "{CHART_WIDTH}",
+                    height: "{CHART_HEIGHT}",
+                    style: "background: #f4f4f4; border-radius: 4px;",
+                    onmousemove: move |event: MouseEvent| {
+                        if n_points == 0 {
+                            return;
+                        }
+                        let x = event.element_coordinates().x - MARGIN_LEFT;
+                        let step = plot_width() / std::cmp::max(1, n_points - 1) as f64;
+                        let index = (x / step).round().clamp(0.0, (n_points - 1) as f64) as usize;
+                        hover_index.set(Some(index));
+                    },
+                    onmouseleave: move |_| hover_index.set(None),
+
+                    // Axes
+                    line {
+                        x1: "{MARGIN_LEFT}",
+                        y1: "{MARGIN_TOP}",
+                        x2: "{MARGIN_LEFT}",
+                        y2: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                        stroke: "#333",
+                    }
+                    line {
+                        x1: "{MARGIN_LEFT}",
+                        y1: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                        x2: "{CHART_WIDTH - MARGIN_RIGHT}",
+                        y2: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                        stroke: "#333",
+                    }
+                    for fraction in [0.0, 0.5, 1.0] {
+                        text {
+                            x: "{MARGIN_LEFT - 5.0}",
+                            y: "{y_position(y_max * fraction, y_max) + 4.0}",
+                            text_anchor: "end",
+                            font_size: "11",
+                            fill: "#333",
+                            {format_axis_value(y_max * fraction)}
+                        }
+                    }
+                    for index in (0..n_points).step_by(x_tick_step) {
+                        text {
+                            x: "{x_position(index, n_points)}",
+                            y: "{CHART_HEIGHT - MARGIN_BOTTOM + 15.0}",
+                            text_anchor: "middle",
+                            font_size: "11",
+                            fill: "#333",
+                            "{x_start + index as f64 * x_step}"
+                        }
+                    }
+
+                    // Series
+                    for s in series.iter() {
+                        polyline {
+                            fill: "none",
+                            stroke: "{s.color}",
+                            stroke_width: "2",
+                            points: s
+                                .values
+                                .iter()
+                                .enumerate()
+                                .map(|(i, v)| format!("{:.1},{:.1}", x_position(i, n_points), y_position(*v, y_max)))
+                                .collect::<Vec<_>>()
+                                .join(" "),
+                        }
+                    }
+
+                    // Hover marker
+                    if let Some(index) = hovered {
+                        line {
+                            x1: "{x_position(index, n_points)}",
+                            y1: "{MARGIN_TOP}",
+                            x2: "{x_position(index, n_points)}",
+                            y2: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                            stroke: "#999",
+                            stroke_dasharray: "4 2",
+                        }
+                        for s in series.iter().filter(|s| index < s.values.len()) {
+                            circle {
+                                cx: "{x_position(index, n_points)}",
+                                cy: "{y_position(s.values[index], y_max)}",
+                                r: "4",
+                                fill: "{s.color}",
+                            }
+                        }
+                    }
+                }
+
+                // Legend and hover readout
+                div { style: "display: flex; gap: 15px; font-size: 12px; color: #333;",
+                    if let Some(index) = hovered {
+                        span { style: "font-weight: bold;", "{x_start + index as f64 * x_step}:" }
+                    }
+                    for s in series.iter() {
+                        span { style: "color: {s.color}; font-weight: bold;",
+                            {s.label.clone()}
+                            if let Some(value) = hovered.and_then(|i| s.values.get(i)) {
+                                " {format_axis_value(*value)}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// SVG polygon points for the area between two series
+fn band_points(lower: &[f64], upper: &[f64], y_max: f64) -> String {
+    let n_points = lower.len();
+    upper
+        .iter()
+        .enumerate()
+        .chain(lower.iter().enumerate().rev())
+        .map(|(i, v)| {
+            format!(
+                "{:.1},{:.1}",
+                x_position(i, n_points),
+                y_position(*v, y_max)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Fan chart of simulated outcomes over time: a light p10-p90 band, a darker p25-p75 band, and
+/// the median line. Hovering shows all five percentile values for that year.
+#[cfg(feature = "simulation")]
+#[component]
+pub fn FanChart(
+    title: String,
+    bands: PercentileBands,
+    x_start: f64,
+    color: String,
+    hover_index: Signal<Option<usize>>,
+    #[props(default)] y_format: AxisFormat,
+    #[props(default = "Year".to_string())] x_label: String,
+) -> Element {
+    let number_format = use_number_format();
+    let format_axis_value = move |value: f64| y_format.format(value, &number_format);
+    let n_points = bands.len();
+    let y_max = bands.p90.iter().copied().fold(0.0, f64::max);
+    let x_tick_step = std::cmp::max(1, n_points / 8);
+    let hovered = hover_index().filter(|i| *i < n_points);
+    let show_table = use_signal(|| false);
+    let table_columns = [x_label.as_str(), "p10", "p25", "Median", "p75", "p90"]
+        .iter()
+        .map(|column| column.to_string())
+        .collect::<Vec<_>>();
+    let table_rows = (0..n_points)
+        .map(|i| {
+            vec![
+                format!("{}", x_start + i as f64),
+                format_axis_value(bands.p10[i]),
+                format_axis_value(bands.p25[i]),
+                format_axis_value(bands.p50[i]),
+                format_axis_value(bands.p75[i]),
+                format_axis_value(bands.p90[i]),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let median_points = bands
+        .p50
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            format!(
+                "{:.1},{:.1}",
+                x_position(i, n_points),
+                y_position(*v, y_max)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    rsx! {
+        div { style: "margin-bottom: 15px;",
+            ChartHeader { title: title.clone(), show_table }
+            if show_table() {
+                ChartTable { caption: title.clone(), columns: table_columns, rows: table_rows }
+            } else {
+                svg {
+                    "role": "img",
+                    "aria-label": "{title}",
+                    width: "{CHART_WIDTH}",
+                    height: "{CHART_HEIGHT}",
+                    style: "background: #f4f4f4; border-radius: 4px;",
+                    onmousemove: move |event: MouseEvent| {
+                        if n_points == 0 {
+                            return;
+                        }
+                        let x = event.element_coordinates().x - MARGIN_LEFT;
+                        let step = plot_width() / std::cmp::max(1, n_points - 1) as f64;
+                        let index = (x / step).round().clamp(0.0, (n_points - 1) as f64) as usize;
+                        hover_index.set(Some(index));
+                    },
+                    onmouseleave: move |_| hover_index.set(None),
+
+                    // Axes
+                    line {
+                        x1: "{MARGIN_LEFT}",
+                        y1: "{MARGIN_TOP}",
+                        x2: "{MARGIN_LEFT}",
+                        y2: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                        stroke: "#333",
+                    }
+                    line {
+                        x1: "{MARGIN_LEFT}",
+                        y1: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                        x2: "{CHART_WIDTH - MARGIN_RIGHT}",
+                        y2: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                        stroke: "#333",
+                    }
+                    for fraction in [0.0, 0.5, 1.0] {
+                        text {
+                            x: "{MARGIN_LEFT - 5.0}",
+                            y: "{y_position(y_max * fraction, y_max) + 4.0}",
+                            text_anchor: "end",
+                            font_size: "11",
+                            fill: "#333",
+                            {format_axis_value(y_max * fraction)}
+                        }
+                    }
+                    for index in (0..n_points).step_by(x_tick_step) {
+                        text {
+                            x: "{x_position(index, n_points)}",
+                            y: "{CHART_HEIGHT - MARGIN_BOTTOM + 15.0}",
+                            text_anchor: "middle",
+                            font_size: "11",
+                            fill: "#333",
+                            "{x_start + index as f64}"
+                        }
+                    }
+
+                    // Bands and median
+                    polygon {
+                        points: band_points(&bands.p10, &bands.p90, y_max),
+                        fill: "{color}",
+                        fill_opacity: "0.2",
+                    }
+                    polygon {
+                        points: band_points(&bands.p25, &bands.p75, y_max),
+                        fill: "{color}",
+                        fill_opacity: "0.35",
+                    }
+                    polyline {
+                        fill: "none",
+                        stroke: "{color}",
+                        stroke_width: "2",
+                        points: median_points,
+                    }
+
+                    // Hover marker
+                    if let Some(index) = hovered {
+                        line {
+                            x1: "{x_position(index, n_points)}",
+                            y1: "{MARGIN_TOP}",
+                            x2: "{x_position(index, n_points)}",
+                            y2: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                            stroke: "#999",
+                            stroke_dasharray: "4 2",
+                        }
+                    }
+                }
+
+                // Hover readout
+                div { style: "display: flex; gap: 15px; font-size: 12px; color: #333;",
+                    if let Some(index) = hovered {
+                        span { style: "font-weight: bold;", "{x_start + index as f64}:" }
+                        span { "p10 {format_axis_value(bands.p10[index])}" }
+                        span { "p25 {format_axis_value(bands.p25[index])}" }
+                        span { style: "color: {color}; font-weight: bold;",
+                            "median {format_axis_value(bands.p50[index])}"
+                        }
+                        span { "p75 {format_axis_value(bands.p75[index])}" }
+                        span { "p90 {format_axis_value(bands.p90[index])}" }
+                    } else {
+                        span { "Hover over the chart to see percentile values for a year" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A projection under low, base, and high inflation: the base line over a band spanning the low
+/// and high lines. Hovering shows all three values for that point.
+#[component]
+pub fn InflationBandChart(
+    title: String,
+    results: ScenarioResults<Vec<f64>>,
+    rates: ScenarioResults<f64>,
+    x_start: f64,
+    color: String,
+    hover_index: Signal<Option<usize>>,
+    #[props(default)] y_format: AxisFormat,
+    #[props(default = "Year".to_string())] x_label: String,
+) -> Element {
+    let number_format = use_number_format();
+    let series: Vec<ChartSeries> = InflationCase::ALL
+        .iter()
+        .map(|case| ChartSeries {
+            label: format!(
+                "{} ({}%)",
+                case.label(),
+                number_format.decimal(rates.get(*case) * 100.0, 2)
+            ),
+            color: color.clone(),
+            values: results.get(*case).clone(),
+        })
+        .collect();
+    let format_axis_value = move |value: f64| y_format.format(value, &number_format);
+    // The base scenario's legend entry is bold
+    let legend_styles: Vec<String> = InflationCase::ALL
+        .iter()
+        .map(|case| match case {
+            InflationCase::Base => format!("color: {}; font-weight: bold;", color),
+            _ => format!("color: {};", color),
+        })
+        .collect();
+    let n_points = results.base.len();
+    let y_max = series
+        .iter()
+        .flat_map(|s| s.values.iter().copied())
+        .fold(0.0, f64::max);
+    let x_tick_step = std::cmp::max(1, n_points / 8);
+    let hovered = hover_index().filter(|i| *i < n_points);
+    let show_table = use_signal(|| false);
+    let (table_columns, table_rows) =
+        series_table(&x_label, x_start, 1.0, &series, &format_axis_value);
+    let line_points = |values: &[f64]| {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                format!(
+                    "{:.1},{:.1}",
+                    x_position(i, n_points),
+                    y_position(*v, y_max)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
+    rsx! {
+        div { style: "margin-bottom: 15px;",
+            ChartHeader { title: title.clone(), show_table }
+            if show_table() {
+                ChartTable { caption: title.clone(), columns: table_columns, rows: table_rows }
+            } else {
+                svg {
+                    "role": "img",
+                    "aria-label": "{title}",
+                    width: "{CHART_WIDTH}",
+                    height: "{CHART_HEIGHT}",
+                    style: "background: #f4f4f4; border-radius: 4px;",
+                    onmousemove: move |event: MouseEvent| {
+                        if n_points == 0 {
+                            return;
+                        }
+                        let x = event.element_coordinates().x - MARGIN_LEFT;
+                        let step = plot_width() / std::cmp::max(1, n_points - 1) as f64;
+                        let index = (x / step).round().clamp(0.0, (n_points - 1) as f64) as usize;
+                        hover_index.set(Some(index));
+                    },
+                    onmouseleave: move |_| hover_index.set(None),
+
+                    // Axes
+                    line {
+                        x1: "{MARGIN_LEFT}",
+                        y1: "{MARGIN_TOP}",
+                        x2: "{MARGIN_LEFT}",
+                        y2: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                        stroke: "#333",
+                    }
+                    line {
+                        x1: "{MARGIN_LEFT}",
+                        y1: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                        x2: "{CHART_WIDTH - MARGIN_RIGHT}",
+                        y2: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                        stroke: "#333",
+                    }
+                    for fraction in [0.0, 0.5, 1.0] {
+                        text {
+                            x: "{MARGIN_LEFT - 5.0}",
+                            y: "{y_position(y_max * fraction, y_max) + 4.0}",
+                            text_anchor: "end",
+                            font_size: "11",
+                            fill: "#333",
+                            {format_axis_value(y_max * fraction)}
+                        }
+                    }
+                    for index in (0..n_points).step_by(x_tick_step) {
+                        text {
+                            x: "{x_position(index, n_points)}",
+                            y: "{CHART_HEIGHT - MARGIN_BOTTOM + 15.0}",
+                            text_anchor: "middle",
+                            font_size: "11",
+                            fill: "#333",
+                            "{x_start + index as f64}"
+                        }
+                    }
+
+                    // Band between the low and high scenarios, dashed at its edges, and the base line
+                    polygon {
+                        points: band_points(&results.low, &results.high, y_max),
+                        fill: "{color}",
+                        fill_opacity: "0.2",
+                    }
+                    for values in [&results.low, &results.high] {
+                        polyline {
+                            fill: "none",
+                            stroke: "{color}",
+                            stroke_width: "1",
+                            stroke_dasharray: "4 2",
+                            points: line_points(values),
+                        }
+                    }
+                    polyline {
+                        fill: "none",
+                        stroke: "{color}",
+                        stroke_width: "2",
+                        points: line_points(&results.base),
+                    }
+
+                    // Hover marker
+                    if let Some(index) = hovered {
+                        line {
+                            x1: "{x_position(index, n_points)}",
+                            y1: "{MARGIN_TOP}",
+                            x2: "{x_position(index, n_points)}",
+                            y2: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                            stroke: "#999",
+                            stroke_dasharray: "4 2",
+                        }
+                    }
+                }
+
+                // Legend and hover readout
+                div { style: "display: flex; gap: 15px; font-size: 12px; color: #333;",
+                    if let Some(index) = hovered {
+                        span { style: "font-weight: bold;", "{x_start + index as f64}:" }
+                    }
+                    for (s , legend_style) in series.iter().zip(legend_styles.iter()) {
+                        span { style: "{legend_style}",
+                            {s.label.clone()}
+                            if let Some(value) = hovered.and_then(|i| s.values.get(i)) {
+                                " {format_axis_value(*value)}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// X pixel position of the left edge of bar slot `index` out of `n_slots`
+fn slot_left(index: usize, n_slots: usize) -> f64 {
+    MARGIN_LEFT + index as f64 * plot_width() / std::cmp::max(1, n_slots) as f64
+}
+
+/// Stacked bar chart of one or more series over a shared x axis, e.g., a cash-flow calendar.
+/// Bar `i` stacks point `i` of every series, in series order, at x label `x_start + i`.
+#[component]
+pub fn StackedBarChart(
+    title: String,
+    series: Vec<ChartSeries>,
+    x_start: f64,
+    hover_index: Signal<Option<usize>>,
+    #[props(default)] y_format: AxisFormat,
+    #[props(default = "Year".to_string())] x_label: String,
+) -> Element {
+    let number_format = use_number_format();
+    let format_axis_value = move |value: f64| y_format.format(value, &number_format);
+    let n_slots = series.iter().map(|s| s.values.len()).max().unwrap_or(0);
+    let totals: Vec<f64> = (0..n_slots)
+        .map(|i| series.iter().filter_map(|s| s.values.get(i)).sum())
+        .collect();
+    let y_max = totals.iter().copied().fold(0.0, f64::max);
+    let slot_width = plot_width() / std::cmp::max(1, n_slots) as f64;
+    let x_tick_step = std::cmp::max(1, n_slots / 8);
+    let hovered = hover_index().filter(|i| *i < n_slots);
+    let show_table = use_signal(|| false);
+    let (mut table_columns, mut table_rows) =
+        series_table(&x_label, x_start, 1.0, &series, &format_axis_value);
+    table_columns.push("Total".to_string());
+    for (row, total) in table_rows.iter_mut().zip(totals.iter()) {
+        row.push(format_axis_value(*total));
+    }
+    // (x, y, height, color) of every bar segment
+    let segments: Vec<(f64, f64, f64, String)> = (0..n_slots)
+        .flat_map(|i| {
+            let mut stacked = 0.0;
+            series
+                .iter()
+                .filter_map(|s| s.values.get(i).map(|v| (s, *v)))
+                .map(|(s, value)| {
+                    let top = y_position(stacked + value, y_max);
+                    let bottom = y_position(stacked, y_max);
+                    stacked += value;
+                    (
+                        slot_left(i, n_slots) + slot_width * 0.15,
+                        top,
+                        bottom - top,
+                        s.color.clone(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    rsx! {
+        div { style: "margin-bottom: 15px;",
+            ChartHeader { title: title.clone(), show_table }
+            if show_table() {
+                ChartTable { caption: title.clone(), columns: table_columns, rows: table_rows }
+            } else {
+                svg {
+                    "role": "img",
+                    "aria-label": "{title}",
+                    width: "{CHART_WIDTH}",
+                    height: "{CHART_HEIGHT}",
+                    style: "background: #f4f4f4; border-radius: 4px;",
+                    onmousemove: move |event: MouseEvent| {
+                        if n_slots == 0 {
+                            return;
+                        }
+                        let x = event.element_coordinates().x - MARGIN_LEFT;
+                        let index = (x / slot_width).floor().clamp(0.0, (n_slots - 1) as f64) as usize;
+                        hover_index.set(Some(index));
+                    },
+                    onmouseleave: move |_| hover_index.set(None),
+
+                    // Axes
+                    line {
+                        x1: "{MARGIN_LEFT}",
+                        y1: "{MARGIN_TOP}",
+                        x2: "{MARGIN_LEFT}",
+                        y2: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                        stroke: "#333",
+                    }
+                    line {
+                        x1: "{MARGIN_LEFT}",
+                        y1: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                        x2: "{CHART_WIDTH - MARGIN_RIGHT}",
+                        y2: "{CHART_HEIGHT - MARGIN_BOTTOM}",
+                        stroke: "#333",
+                    }
+                    for fraction in [0.0, 0.5, 1.0] {
+                        text {
+                            x: "{MARGIN_LEFT - 5.0}",
+                            y: "{y_position(y_max * fraction, y_max) + 4.0}",
+                            text_anchor: "end",
+                            font_size: "11",
+                            fill: "#333",
+                            {format_axis_value(y_max * fraction)}
+                        }
+                    }
+                    for index in (0..n_slots).step_by(x_tick_step) {
+                        text {
+                            x: "{slot_left(index, n_slots) + slot_width / 2.0}",
+                            y: "{CHART_HEIGHT - MARGIN_BOTTOM + 15.0}",
+                            text_anchor: "middle",
+                            font_size: "11",
+                            fill: "#333",
+                            "{x_start + index as f64}"
+                        }
+                    }
+
+                    // Hover highlight behind the bars
+                    if let Some(index) = hovered {
+                        rect {
+                            x: "{slot_left(index, n_slots)}",
+                            y: "{MARGIN_TOP}",
+                            width: "{slot_width}",
+                            height: "{plot_height()}",
+                            fill: "#dddddd",
+                        }
+                    }
+
+                    // Bars
+                    for (x, y, height, color) in segments.into_iter() {
+                        rect {
+                            x: "{x:.1}",
+                            y: "{y:.1}",
+                            width: "{slot_width * 0.7:.1}",
+                            height: "{height:.1}",
+                            fill: "{color}",
+                        }
+                    }
+                }
+
+                // Legend and hover readout
+                div { style: "display: flex; gap: 15px; font-size: 12px; color: #333;",
+                    if let Some(index) = hovered {
+                        span { style: "font-weight: bold;", "{x_start + index as f64}:" }
+                    }
+                    for s in series.iter() {
+                        span { style: "color: {s.color}; font-weight: bold;",
+                            {s.label.clone()}
+                            if let Some(value) = hovered.and_then(|i| s.values.get(i)) {
+                                " {format_axis_value(*value)}"
+                            }
+                        }
+                    }
+                    if let Some(total) = hovered.and_then(|i| totals.get(i)) {
+                        span { style: "font-weight: bold;", "Total {format_axis_value(*total)}" }
+                    }
+                }
+            }
+        }
+    }
+}
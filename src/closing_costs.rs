@@ -0,0 +1,157 @@
+use crate::amortization::DAYS_PER_YEAR;
+use serde::{Deserialize, Serialize};
+
+/// Typical closing costs for a region, each a share of the loan amount, to start an estimate
+/// from before the lender's Loan Estimate arrives
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClosingCostPreset {
+    /// Value of the preset's select option
+    pub slug: &'static str,
+    pub region: &'static str,
+    /// Origination, underwriting, appraisal, and credit report
+    pub lender_fee_rate: f64,
+    /// Title insurance, settlement, and recording
+    pub title_fee_rate: f64,
+    /// Transfer and mortgage recording taxes
+    pub government_fee_rate: f64,
+}
+
+/// Rough regional typicals; real fees vary by lender, county, and loan
+pub const CLOSING_COST_PRESETS: &[ClosingCostPreset] = &[
+    ClosingCostPreset {
+        slug: "national",
+        region: "National average",
+        lender_fee_rate: 0.010,
+        title_fee_rate: 0.006,
+        government_fee_rate: 0.004,
+    },
+    ClosingCostPreset {
+        slug: "northeast",
+        region: "Northeast",
+        lender_fee_rate: 0.010,
+        title_fee_rate: 0.008,
+        government_fee_rate: 0.010,
+    },
+    ClosingCostPreset {
+        slug: "midwest",
+        region: "Midwest",
+        lender_fee_rate: 0.009,
+        title_fee_rate: 0.005,
+        government_fee_rate: 0.002,
+    },
+    ClosingCostPreset {
+        slug: "south",
+        region: "South",
+        lender_fee_rate: 0.010,
+        title_fee_rate: 0.006,
+        government_fee_rate: 0.003,
+    },
+    ClosingCostPreset {
+        slug: "west",
+        region: "West",
+        lender_fee_rate: 0.009,
+        title_fee_rate: 0.007,
+        government_fee_rate: 0.003,
+    },
+];
+
+/// Inputs to the closing cost estimator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClosingCostInputs {
+    pub loan_amount: f64,
+    /// Annual interest rate as a decimal, e.g., 0.06 for 6%
+    pub annual_rate: f64,
+    /// Origination, underwriting, appraisal, and credit report, in dollars
+    pub lender_fees: f64,
+    /// Title insurance, settlement, and recording, in dollars
+    pub title_fees: f64,
+    /// Transfer and mortgage recording taxes, in dollars
+    pub government_fees: f64,
+    /// Days of interest from the closing date to the end of its month
+    pub prepaid_interest_days: f64,
+    /// Yearly property tax and homeowners insurance
+    pub annual_escrow_bills: f64,
+    /// Months of tax and insurance deposited into escrow at closing
+    pub escrow_months: f64,
+}
+
+impl ClosingCostInputs {
+    /// The inputs with the preset's fees for this loan amount, rounded to the dollar
+    pub fn with_preset(&self, preset: &ClosingCostPreset) -> Self {
+        let fee = |rate: f64| (self.loan_amount * rate).round();
+        ClosingCostInputs {
+            lender_fees: fee(preset.lender_fee_rate),
+            title_fees: fee(preset.title_fee_rate),
+            government_fees: fee(preset.government_fee_rate),
+            ..*self
+        }
+    }
+}
+
+/// Closing costs by category
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClosingCosts {
+    pub lender_fees: f64,
+    pub title_fees: f64,
+    pub government_fees: f64,
+    /// Interest from closing until the first full month the first payment covers
+    pub prepaid_interest: f64,
+    /// Tax and insurance deposited to start the escrow account
+    pub initial_escrow: f64,
+}
+
+impl ClosingCosts {
+    /// Fees paid to get the loan, which lenders count toward its cost; prepaids aren't fees, as
+    /// they'd be owed anyway
+    pub fn fees(&self) -> f64 {
+        self.lender_fees + self.title_fees + self.government_fees
+    }
+
+    /// Prepaid interest and the initial escrow deposit
+    pub fn prepaids(&self) -> f64 {
+        self.prepaid_interest + self.initial_escrow
+    }
+
+    /// Everything due at closing, before the down payment
+    pub fn total(&self) -> f64 {
+        self.fees() + self.prepaids()
+    }
+}
+
+// Itemizes the costs of closing a mortgage.
+///
+/// # Parameters:
+/// - `inputs`: The loan, its fees by category, and the prepaids' days and months
+///
+/// # Formula:
+/// prepaid interest = loan * rate / 365 * days
+/// initial escrow = yearly tax and insurance / 12 * months
+///
+/// # Returns:
+/// Each category in dollars, rounded to the cent. An error if any input is negative.
+pub fn estimate_closing_costs(inputs: &ClosingCostInputs) -> Result<ClosingCosts, String> {
+    let entries = [
+        inputs.loan_amount,
+        inputs.annual_rate,
+        inputs.lender_fees,
+        inputs.title_fees,
+        inputs.government_fees,
+        inputs.prepaid_interest_days,
+        inputs.annual_escrow_bills,
+        inputs.escrow_months,
+    ];
+    if entries.iter().any(|entry| entry.is_nan() || *entry < 0.0) {
+        return Err("Closing cost entries can't be negative".to_string());
+    }
+    let round_cents = |value: f64| (value * 100.0).round() / 100.0;
+
+    Ok(ClosingCosts {
+        lender_fees: round_cents(inputs.lender_fees),
+        title_fees: round_cents(inputs.title_fees),
+        government_fees: round_cents(inputs.government_fees),
+        prepaid_interest: round_cents(
+            inputs.loan_amount * inputs.annual_rate / DAYS_PER_YEAR * inputs.prepaid_interest_days,
+        ),
+        initial_escrow: round_cents(inputs.annual_escrow_bills / 12.0 * inputs.escrow_months),
+    })
+}
@@ -0,0 +1,274 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::closing_costs::{estimate_closing_costs, ClosingCostInputs, CLOSING_COST_PRESETS};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::link::{CalculatorLink, SendLinkButton};
+use crate::numeric_input_component::NumericInput;
+use dioxus::prelude::*;
+
+/// A $300,000 loan at 6.5% with the national average preset's fees, closing mid-month
+const DEFAULT_INPUTS: ClosingCostInputs = ClosingCostInputs {
+    loan_amount: 300_000.0,
+    annual_rate: 0.065,
+    lender_fees: 3_000.0,
+    title_fees: 1_800.0,
+    government_fees: 1_200.0,
+    prepaid_interest_days: 15.0,
+    annual_escrow_bills: 4_200.0,
+    escrow_months: 3.0,
+};
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+const LABEL_STYLE: &str = "text-align: left; padding: 2px 8px;";
+
+#[component]
+pub fn ClosingCostsUI() -> Element {
+    let mut loan_signal = use_signal(|| 300_000.0 as f64);
+    let mut loan_input = use_signal(|| "300000".to_string());
+    let loan_input_valid = use_signal(|| true);
+    let mut rate_signal = use_signal(|| 6.5 as f64);
+    let mut rate_input = use_signal(|| "6.5".to_string());
+    let rate_input_valid = use_signal(|| true);
+    let mut preset_signal = use_signal(|| "national");
+    let mut lender_signal = use_signal(|| 3_000.0 as f64);
+    let mut lender_input = use_signal(|| "3000".to_string());
+    let lender_input_valid = use_signal(|| true);
+    let mut title_signal = use_signal(|| 1_800.0 as f64);
+    let mut title_input = use_signal(|| "1800".to_string());
+    let title_input_valid = use_signal(|| true);
+    let mut government_signal = use_signal(|| 1_200.0 as f64);
+    let mut government_input = use_signal(|| "1200".to_string());
+    let government_input_valid = use_signal(|| true);
+    let mut days_signal = use_signal(|| 15.0 as f64);
+    let mut days_input = use_signal(|| "15".to_string());
+    let days_input_valid = use_signal(|| true);
+    let mut bills_signal = use_signal(|| 4_200.0 as f64);
+    let mut bills_input = use_signal(|| "4200".to_string());
+    let bills_input_valid = use_signal(|| true);
+    let mut months_signal = use_signal(|| 3.0 as f64);
+    let mut months_input = use_signal(|| "3".to_string());
+    let months_input_valid = use_signal(|| true);
+
+    let inputs = move || ClosingCostInputs {
+        loan_amount: loan_signal(),
+        annual_rate: rate_signal() / 100.0,
+        lender_fees: lender_signal(),
+        title_fees: title_signal(),
+        government_fees: government_signal(),
+        prepaid_interest_days: days_signal(),
+        annual_escrow_bills: bills_signal(),
+        escrow_months: months_signal(),
+    };
+    // Writes fees into the signals and their fields, as restoring or choosing a preset does
+    let mut set_fees = move |fees: ClosingCostInputs| {
+        lender_signal.set(fees.lender_fees);
+        lender_input.set(format!("{}", fees.lender_fees));
+        title_signal.set(fees.title_fees);
+        title_input.set(format!("{}", fees.title_fees));
+        government_signal.set(fees.government_fees);
+        government_input.set(format!("{}", fees.government_fees));
+    };
+    let start_fresh = use_autosave(
+        "closing_costs",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: ClosingCostInputs| {
+            loan_signal.set(inputs.loan_amount);
+            loan_input.set(format!("{}", inputs.loan_amount));
+            rate_signal.set(inputs.annual_rate * 100.0);
+            rate_input.set(format!("{}", inputs.annual_rate * 100.0));
+            set_fees(inputs);
+            days_signal.set(inputs.prepaid_interest_days);
+            days_input.set(format!("{}", inputs.prepaid_interest_days));
+            bills_signal.set(inputs.annual_escrow_bills);
+            bills_input.set(format!("{}", inputs.annual_escrow_bills));
+            months_signal.set(inputs.escrow_months);
+            months_input.set(format!("{}", inputs.escrow_months));
+        }),
+    );
+    use_track_calculator("closing_costs");
+    use_track_input_range("closing_costs", "loan_amount", loan_signal, amount_bucket);
+
+    let costs = estimate_closing_costs(&inputs());
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Loan Amount ($):".to_string(),
+            placeholder: "Enter the amount borrowed (e.g., 300000)".to_string(),
+            input_signal: loan_input,
+            value_signal: loan_signal,
+            valid_signal: loan_input_valid,
+            field_name: "Loan amount".to_string(),
+            css_prefix: "loan".to_string(),
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Interest Rate (%):".to_string(),
+            placeholder: "Enter the annual rate (e.g., 6.5)".to_string(),
+            input_signal: rate_input,
+            value_signal: rate_signal,
+            valid_signal: rate_input_valid,
+            field_name: "Interest rate".to_string(),
+            css_prefix: "rate".to_string(),
+            step: Some(0.125),
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Region:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(preset) = CLOSING_COST_PRESETS
+                        .iter()
+                        .find(|preset| preset.slug == value.as_str())
+                    {
+                        preset_signal.set(preset.slug);
+                        set_fees(inputs().with_preset(preset));
+                    }
+                },
+                for preset in CLOSING_COST_PRESETS.iter() {
+                    option {
+                        value: preset.slug,
+                        selected: preset_signal() == preset.slug,
+                        {preset.region}
+                    }
+                }
+            }
+        }
+        NumericInput {
+            label: "Lender Fees ($):".to_string(),
+            placeholder: "Enter origination, underwriting, appraisal, and credit report (e.g., 3000)".to_string(),
+            input_signal: lender_input,
+            value_signal: lender_signal,
+            valid_signal: lender_input_valid,
+            field_name: "Lender fees".to_string(),
+            css_prefix: "lender".to_string(),
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Title & Settlement ($):".to_string(),
+            placeholder: "Enter title insurance, settlement, and recording (e.g., 1800)".to_string(),
+            input_signal: title_input,
+            value_signal: title_signal,
+            valid_signal: title_input_valid,
+            field_name: "Title and settlement".to_string(),
+            css_prefix: "title".to_string(),
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Government Fees ($):".to_string(),
+            placeholder: "Enter transfer and recording taxes (e.g., 1200)".to_string(),
+            input_signal: government_input,
+            value_signal: government_signal,
+            valid_signal: government_input_valid,
+            field_name: "Government fees".to_string(),
+            css_prefix: "government".to_string(),
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Prepaid Interest (days):".to_string(),
+            placeholder: "Enter the days from closing to the end of the month (e.g., 15)".to_string(),
+            input_signal: days_input,
+            value_signal: days_signal,
+            valid_signal: days_input_valid,
+            field_name: "Prepaid interest days".to_string(),
+            css_prefix: "days".to_string(),
+            step: Some(1.0),
+        }
+        NumericInput {
+            label: "Tax & Insurance ($/yr):".to_string(),
+            placeholder: "Enter the yearly property tax and insurance (e.g., 4200)".to_string(),
+            input_signal: bills_input,
+            value_signal: bills_signal,
+            valid_signal: bills_input_valid,
+            field_name: "Tax and insurance".to_string(),
+            css_prefix: "bills".to_string(),
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Escrow Deposit (months):".to_string(),
+            placeholder: "Enter the months of tax and insurance the lender collects (e.g., 3)".to_string(),
+            input_signal: months_input,
+            value_signal: months_signal,
+            valid_signal: months_input_valid,
+            field_name: "Escrow deposit months".to_string(),
+            css_prefix: "months".to_string(),
+            step: Some(1.0),
+        }
+
+        {
+            match costs {
+                Ok(costs) => rsx! {
+                    div {
+                        id: "ClosingCostsCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        "Due at closing: ${format_dollars(costs.total())}*"
+                    }
+                    table { style: "color: #333; border-collapse: collapse; font-size: 14px; margin-bottom: 15px;",
+                        tbody {
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Lender fees" }
+                                td { style: CELL_STYLE, {format_dollars(costs.lender_fees)} }
+                            }
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Title & settlement" }
+                                td { style: CELL_STYLE, {format_dollars(costs.title_fees)} }
+                            }
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Government fees" }
+                                td { style: CELL_STYLE, {format_dollars(costs.government_fees)} }
+                            }
+                            tr { style: "font-weight: bold;",
+                                th { scope: "row", style: LABEL_STYLE, "Fees" }
+                                td { style: CELL_STYLE, {format_dollars(costs.fees())} }
+                            }
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Prepaid interest" }
+                                td { style: CELL_STYLE, {format_dollars(costs.prepaid_interest)} }
+                            }
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Initial escrow deposit" }
+                                td { style: CELL_STYLE, {format_dollars(costs.initial_escrow)} }
+                            }
+                            tr { style: "font-weight: bold;",
+                                th { scope: "row", style: LABEL_STYLE, "Prepaids" }
+                                td { style: CELL_STYLE, {format_dollars(costs.prepaids())} }
+                            }
+                        }
+                    }
+                    SendLinkButton { link: CalculatorLink::ClosingFees(costs.fees()) }
+                },
+                Err(error) => rsx! {
+                    div {
+                        id: "ClosingCostsCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "closing_costs" }
+        AssumptionsFooter { calculator: "closing_costs" }
+        div { style: "font-size: 10px",
+            p {
+                "*Before the down payment. Only the fees count toward a loan's APR; prepaid interest and escrow would be owed with any lender."
+            }
+            br {}
+            br {}
+        }
+    }
+}
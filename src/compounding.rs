@@ -1,7 +1,8 @@
-use num::Float;
+use crate::fin_numeric::FinNumeric;
 use num_format::{Locale, ToFormattedString};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Compounding {
     Annual,
     Semiannually,
@@ -9,10 +10,14 @@ pub enum Compounding {
     Monthly,
     Weekly,
     Daily,
+    /// The limit as the number of periods grows without bound: FV = P * e^(rt)
+    Continuous,
     Other(f64),
 }
 
 impl Compounding {
+    /// Compounding periods per year (n). Continuous compounding has no finite n and returns
+    /// infinity, which the functions in this module treat as the e^(rt) limit.
     pub fn periods_per_year(&self) -> f64 {
         match self {
             Compounding::Annual => 1.0,
@@ -21,6 +26,7 @@ impl Compounding {
             Compounding::Monthly => 12.0,
             Compounding::Weekly => 52.0,
             Compounding::Daily => 365.0,
+            Compounding::Continuous => f64::INFINITY,
             Compounding::Other(periods) => *periods,
         }
     }
@@ -33,14 +39,110 @@ impl Compounding {
             Compounding::Monthly => "Monthly",
             Compounding::Weekly => "Weekly",
             Compounding::Daily => "Daily",
+            Compounding::Continuous => "Continuously",
             Compounding::Other(_) => "Custom",
         }
     }
+
+    pub fn is_continuous(&self) -> bool {
+        *self == Compounding::Continuous
+    }
+}
+
+/// When in each period a recurring contribution is made
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum ContributionTiming {
+    /// At the end of each period (ordinary annuity)
+    #[default]
+    End,
+    /// At the start of each period (annuity due), so each deposit earns one more period
+    Begin,
+}
+
+/// Reasons a computation can't produce a meaningful number
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComputeError {
+    /// The result is too large for the number type (e.g., daily compounding over centuries)
+    Overflow,
+    /// An input is outside the range the formula accepts
+    InvalidInput(&'static str),
+}
+
+impl std::fmt::Display for ComputeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComputeError::Overflow => {
+                write!(
+                    f,
+                    "The result is too large to compute; try fewer years or a lower rate"
+                )
+            }
+            ComputeError::InvalidInput(reason) => write!(f, "{}", reason),
+        }
+    }
 }
 
 /// Truncates a floating-point number to two decimal places
-pub fn truncate_to_two_decimal_places<T: Float>(value: T) -> T {
-    (value * T::from(100.0).unwrap()).round() / T::from(100.0).unwrap()
+pub fn truncate_to_two_decimal_places<T: FinNumeric>(value: T) -> T {
+    value.round_dp(2)
+}
+
+/// Whether `n_per_year_compounded` stands for continuous compounding (`Compounding::Continuous`)
+fn is_continuous<T: FinNumeric>(n_per_year_compounded: T) -> bool {
+    n_per_year_compounded.to_f64().is_infinite()
+}
+
+/// Growth factor over `n_years`: (1 + r/n)^(nt), or e^(rt) when compounding continuously
+pub fn growth_factor<T: FinNumeric>(
+    annual_interest_rate: T,
+    n_per_year_compounded: T,
+    n_years: T,
+) -> T {
+    if is_continuous(n_per_year_compounded) {
+        (annual_interest_rate * n_years).exp()
+    } else {
+        T::compound_growth(
+            annual_interest_rate / n_per_year_compounded,
+            n_per_year_compounded * n_years,
+        )
+    }
+}
+
+/// Natural log of `growth_factor`, for checking a result's size before computing it
+fn log_growth_factor<T: FinNumeric>(
+    annual_interest_rate: T,
+    n_per_year_compounded: T,
+    n_years: T,
+) -> T {
+    if is_continuous(n_per_year_compounded) {
+        annual_interest_rate * n_years
+    } else {
+        n_per_year_compounded * n_years * (annual_interest_rate / n_per_year_compounded).ln_1p()
+    }
+}
+
+/// Effective annual rate (APY) of a nominal annual rate (APR) compounded n times a year.
+///
+/// # Formula:
+/// APY = (1 + r/n)^n - 1
+/// APY = e^r - 1   when compounding continuously (n infinite)
+pub fn nominal_to_effective<T: FinNumeric>(nominal_rate: T, n_per_year_compounded: T) -> T {
+    growth_factor(nominal_rate, n_per_year_compounded, T::one()) - T::one()
+}
+
+/// Nominal annual rate (APR), compounded n times a year, that earns an effective annual rate
+/// (APY); the inverse of `nominal_to_effective`.
+///
+/// # Formula:
+/// r = n * ((1 + APY)^(1/n) - 1)
+/// r = ln(1 + APY)   when compounding continuously (n infinite)
+pub fn effective_to_nominal<T: FinNumeric>(effective_rate: T, n_per_year_compounded: T) -> T {
+    if is_continuous(n_per_year_compounded) {
+        effective_rate.ln_1p()
+    } else {
+        n_per_year_compounded
+            * ((T::one() + effective_rate).powf(T::one() / n_per_year_compounded) - T::one())
+    }
 }
 
 // Computes the future value (FV) of an investment, including interest.
@@ -53,6 +155,7 @@ pub fn truncate_to_two_decimal_places<T: Float>(value: T) -> T {
 ///
 /// # Formula:
 /// FV = P * (1 + r/n)^nt
+/// FV = P * e^(rt)   when compounding continuously (n infinite)
 ///
 /// # Returns:
 /// The future value (FV) truncated to two decimal places.
@@ -63,12 +166,331 @@ pub fn compute_fv<T>(
     n_years: T,
 ) -> T
 where
-    T: Float,
+    T: FinNumeric,
 {
-    let nt = n_per_year_compounded * n_years;
-    let compound_rate = T::one() + annual_interest_rate / n_per_year_compounded;
+    let growth = growth_factor(annual_interest_rate, n_per_year_compounded, n_years);
+
+    truncate_to_two_decimal_places(initial_value * growth)
+}
+
+/// Like `compute_fv`, but checks the inputs and the size of the result first, returning an error
+/// instead of overflowing (or panicking, for Decimal) on extreme inputs.
+pub fn checked_compute_fv<T>(
+    initial_value: T,
+    annual_interest_rate: T,
+    n_per_year_compounded: T,
+    n_years: T,
+) -> Result<T, ComputeError>
+where
+    T: FinNumeric,
+{
+    if n_per_year_compounded <= T::zero() {
+        return Err(ComputeError::InvalidInput(
+            "Compounding periods per year must be greater than zero",
+        ));
+    }
+    if n_years < T::zero() || initial_value < T::zero() {
+        return Err(ComputeError::InvalidInput(
+            "Principal and years cannot be negative",
+        ));
+    }
+    let periodic_rate = annual_interest_rate / n_per_year_compounded;
+    if periodic_rate <= -T::one() {
+        return Err(ComputeError::InvalidInput(
+            "The interest rate per period must be greater than -100%",
+        ));
+    }
+    if initial_value == T::zero() {
+        return Ok(T::zero());
+    }
+
+    // Estimate the size of the result in log space before computing it.
+    let log_fv = initial_value.ln()
+        + log_growth_factor(annual_interest_rate, n_per_year_compounded, n_years);
+    if log_fv >= T::max_ln() - T::one() {
+        return Err(ComputeError::Overflow);
+    }
+
+    Ok(compute_fv(
+        initial_value,
+        annual_interest_rate,
+        n_per_year_compounded,
+        n_years,
+    ))
+}
+
+// Computes the future value (FV) of a principal plus equal recurring contributions.
+///
+/// # Parameters:
+/// - `initial_value`: Initial principal amount (P)
+/// - `contribution`: Amount deposited each contribution period (PMT)
+/// - `annual_interest_rate`: Annual interest rate (r), e.g., 0.04 for 4%
+/// - `n_per_year_compounded`: Number of compounding periods per year (n)
+/// - `m_per_year_contributed`: Number of contributions per year (m), e.g., 12 for monthly
+/// - `n_years`: Time in years (t)
+/// - `timing`: Whether contributions are made at the start or end of each period
+///
+/// # Formula:
+/// i = (1 + r/n)^(n/m) - 1   (rate per contribution period), or e^(r/m) - 1 when continuous
+/// FV = P * (1 + r/n)^nt + PMT * ((1 + i)^mt - 1) / i
+/// FV = P * (1 + r/n)^nt + PMT * ((1 + i)^mt - 1) / i * (1 + i)   when contributions begin each period
+/// FV = P * (1 + r/n)^nt + PMT * mt   when i = 0
+///
+/// # Returns:
+/// The future value (FV) truncated to two decimal places.
+pub fn compute_fv_with_contributions<T>(
+    initial_value: T,
+    contribution: T,
+    annual_interest_rate: T,
+    n_per_year_compounded: T,
+    m_per_year_contributed: T,
+    n_years: T,
+    timing: ContributionTiming,
+) -> T
+where
+    T: FinNumeric,
+{
+    let lump_sum =
+        initial_value * growth_factor(annual_interest_rate, n_per_year_compounded, n_years);
+
+    let contribution_rate = growth_factor(
+        annual_interest_rate,
+        n_per_year_compounded,
+        T::one() / m_per_year_contributed,
+    ) - T::one();
+    let n_contributions = m_per_year_contributed * n_years;
+    let mut contributions = if contribution_rate.abs() < T::epsilon() {
+        contribution * n_contributions
+    } else {
+        contribution * (T::compound_growth(contribution_rate, n_contributions) - T::one())
+            / contribution_rate
+    };
+    if timing == ContributionTiming::Begin {
+        contributions = contributions * (T::one() + contribution_rate);
+    }
+
+    truncate_to_two_decimal_places(lump_sum + contributions)
+}
+
+/// Like `compute_fv_with_contributions`, but checks the inputs and the size of the result first,
+/// returning an error instead of overflowing (or panicking, for Decimal) on extreme inputs.
+pub fn checked_compute_fv_with_contributions<T>(
+    initial_value: T,
+    contribution: T,
+    annual_interest_rate: T,
+    n_per_year_compounded: T,
+    m_per_year_contributed: T,
+    n_years: T,
+    timing: ContributionTiming,
+) -> Result<T, ComputeError>
+where
+    T: FinNumeric,
+{
+    checked_compute_fv(
+        initial_value,
+        annual_interest_rate,
+        n_per_year_compounded,
+        n_years,
+    )?;
+    if contribution < T::zero() {
+        return Err(ComputeError::InvalidInput(
+            "Contributions cannot be negative",
+        ));
+    }
+    if m_per_year_contributed <= T::zero() {
+        return Err(ComputeError::InvalidInput(
+            "Contributions per year must be greater than zero",
+        ));
+    }
+
+    // Estimate the size of the contributions' future value in log space before computing it.
+    // It's below PMT * (1 + i)^mt / i when i > 0, times (1 + i) when contributions begin each
+    // period, and below PMT * mt otherwise.
+    if contribution > T::zero() && n_years > T::zero() {
+        let log_growth = log_growth_factor(annual_interest_rate, n_per_year_compounded, n_years);
+        let contribution_rate = growth_factor(
+            annual_interest_rate,
+            n_per_year_compounded,
+            T::one() / m_per_year_contributed,
+        ) - T::one();
+        let log_fv = if contribution_rate > T::epsilon() {
+            let log_due = if timing == ContributionTiming::Begin {
+                contribution_rate.ln_1p()
+            } else {
+                T::zero()
+            };
+            contribution.ln() + log_growth - contribution_rate.ln() + log_due
+        } else {
+            contribution.ln() + (m_per_year_contributed * n_years).ln()
+        };
+        let limit = T::max_ln() - T::one();
+        if log_fv >= limit || log_growth >= limit {
+            return Err(ComputeError::Overflow);
+        }
+    }
+
+    Ok(compute_fv_with_contributions(
+        initial_value,
+        contribution,
+        annual_interest_rate,
+        n_per_year_compounded,
+        m_per_year_contributed,
+        n_years,
+        timing,
+    ))
+}
+
+/// Future value of a level series of payments per dollar paid: ((1 + i)^N - 1) / i, times
+/// (1 + i) for an annuity due, or N when i = 0
+fn annuity_fv_factor<T>(periodic_rate: T, n_periods: T, annuity_due: bool) -> T
+where
+    T: FinNumeric,
+{
+    if periodic_rate.abs() < T::epsilon() {
+        return n_periods;
+    }
+    let factor = (T::compound_growth(periodic_rate, n_periods) - T::one()) / periodic_rate;
+    if annuity_due {
+        factor * (T::one() + periodic_rate)
+    } else {
+        factor
+    }
+}
+
+// Computes the future value (FV) of equal periodic payments (an annuity).
+///
+/// # Parameters:
+/// - `payment`: Amount paid each period (PMT)
+/// - `periodic_rate`: Interest rate per period (i), e.g., 0.04 / 12 for 4% compounded monthly
+/// - `n_periods`: Number of payments (N)
+/// - `annuity_due`: Whether payments are made at the start of each period rather than the end
+///
+/// # Formula:
+/// FV = PMT * ((1 + i)^N - 1) / i
+/// FV = PMT * ((1 + i)^N - 1) / i * (1 + i)   for an annuity due
+/// FV = PMT * N   when i = 0
+///
+/// # Returns:
+/// The future value (FV) truncated to two decimal places.
+pub fn fv_annuity<T>(payment: T, periodic_rate: T, n_periods: T, annuity_due: bool) -> T
+where
+    T: FinNumeric,
+{
+    truncate_to_two_decimal_places(
+        payment * annuity_fv_factor(periodic_rate, n_periods, annuity_due),
+    )
+}
+
+// Computes the present value (PV) of equal periodic payments (an annuity).
+///
+/// # Parameters:
+/// - `payment`: Amount paid each period (PMT)
+/// - `periodic_rate`: Interest rate per period (i)
+/// - `n_periods`: Number of payments (N)
+/// - `annuity_due`: Whether payments are made at the start of each period rather than the end
+///
+/// # Formula:
+/// PV = PMT * (1 - (1 + i)^-N) / i
+/// PV = PMT * (1 - (1 + i)^-N) / i * (1 + i)   for an annuity due
+/// PV = PMT * N   when i = 0
+///
+/// # Returns:
+/// The present value (PV) truncated to two decimal places.
+pub fn pv_annuity<T>(payment: T, periodic_rate: T, n_periods: T, annuity_due: bool) -> T
+where
+    T: FinNumeric,
+{
+    let growth = T::compound_growth(periodic_rate, n_periods);
+
+    truncate_to_two_decimal_places(
+        payment * annuity_fv_factor(periodic_rate, n_periods, annuity_due) / growth,
+    )
+}
+
+// Computes the payment that an amount today buys as an annuity (e.g., a loan payment).
+///
+/// # Parameters:
+/// - `present_value`: Amount today (PV)
+/// - `periodic_rate`: Interest rate per period (i)
+/// - `n_periods`: Number of payments (N)
+/// - `annuity_due`: Whether payments are made at the start of each period rather than the end
+///
+/// # Formula:
+/// PMT = PV * i / (1 - (1 + i)^-N)
+/// PMT = PV * i / (1 - (1 + i)^-N) / (1 + i)   for an annuity due
+/// PMT = PV / N   when i = 0
+///
+/// # Returns:
+/// The payment per period (PMT) truncated to two decimal places, or zero when N is zero.
+pub fn annuity_payment_for_pv<T>(
+    present_value: T,
+    periodic_rate: T,
+    n_periods: T,
+    annuity_due: bool,
+) -> T
+where
+    T: FinNumeric,
+{
+    if n_periods <= T::zero() {
+        return T::zero();
+    }
+    let growth = T::compound_growth(periodic_rate, n_periods);
+
+    truncate_to_two_decimal_places(
+        present_value * growth / annuity_fv_factor(periodic_rate, n_periods, annuity_due),
+    )
+}
 
-    truncate_to_two_decimal_places(initial_value * compound_rate.powf(nt))
+// Computes the payment needed to build up a future amount (e.g., a savings goal).
+///
+/// # Parameters:
+/// - `future_value`: Amount wanted after the last payment (FV)
+/// - `periodic_rate`: Interest rate per period (i)
+/// - `n_periods`: Number of payments (N)
+/// - `annuity_due`: Whether payments are made at the start of each period rather than the end
+///
+/// # Formula:
+/// PMT = FV * i / ((1 + i)^N - 1)
+/// PMT = FV * i / ((1 + i)^N - 1) / (1 + i)   for an annuity due
+/// PMT = FV / N   when i = 0
+///
+/// # Returns:
+/// The payment per period (PMT) truncated to two decimal places, or zero when N is zero.
+pub fn annuity_payment_for_fv<T>(
+    future_value: T,
+    periodic_rate: T,
+    n_periods: T,
+    annuity_due: bool,
+) -> T
+where
+    T: FinNumeric,
+{
+    if n_periods <= T::zero() {
+        return T::zero();
+    }
+
+    truncate_to_two_decimal_places(
+        future_value / annuity_fv_factor(periodic_rate, n_periods, annuity_due),
+    )
+}
+
+// Restates a future value in today's dollars by taking out expected inflation.
+///
+/// # Parameters:
+/// - `nominal_fv`: Future value in the dollars of that future date (FV)
+/// - `inflation_rate`: Expected annual inflation rate (i), e.g., 0.03 for 3%
+/// - `n_years`: Time in years (t)
+///
+/// # Formula:
+/// Real FV = FV / (1 + i)^t
+///
+/// # Returns:
+/// The real future value truncated to two decimal places.
+pub fn compute_real_fv<T>(nominal_fv: T, inflation_rate: T, n_years: T) -> T
+where
+    T: FinNumeric,
+{
+    truncate_to_two_decimal_places(nominal_fv / T::compound_growth(inflation_rate, n_years))
 }
 
 // Computes the present value (PV) of an investment
@@ -81,6 +503,7 @@ where
 ///
 /// # Formula:
 /// PV = FV / (1 + r/n)^nt
+/// PV = FV / e^(rt)   when compounding continuously (n infinite)
 ///
 /// # Returns:
 /// The present value (PV) truncated to two decimal places.
@@ -91,10 +514,246 @@ pub fn compute_pv<T>(
     n_years: T,
 ) -> T
 where
-    T: Float,
+    T: FinNumeric,
 {
+    let growth = growth_factor(annual_interest_rate, n_per_year_compounded, n_years);
+
+    truncate_to_two_decimal_places(future_value / growth)
+}
+
+/// Like `compute_pv`, but checks the inputs and the size of the growth factor first, returning an
+/// error instead of overflowing (or panicking, for Decimal) on extreme inputs.
+pub fn checked_compute_pv<T>(
+    future_value: T,
+    annual_interest_rate: T,
+    n_per_year_compounded: T,
+    n_years: T,
+) -> Result<T, ComputeError>
+where
+    T: FinNumeric,
+{
+    if n_per_year_compounded <= T::zero() {
+        return Err(ComputeError::InvalidInput(
+            "Compounding periods per year must be greater than zero",
+        ));
+    }
+    if n_years < T::zero() || future_value < T::zero() {
+        return Err(ComputeError::InvalidInput(
+            "Future value and years cannot be negative",
+        ));
+    }
+    let periodic_rate = annual_interest_rate / n_per_year_compounded;
+    if periodic_rate <= -T::one() {
+        return Err(ComputeError::InvalidInput(
+            "The interest rate per period must be greater than -100%",
+        ));
+    }
+    if future_value == T::zero() {
+        return Ok(T::zero());
+    }
+
+    // The growth factor is the divisor, so it is the part that can overflow.
+    let log_growth = log_growth_factor(annual_interest_rate, n_per_year_compounded, n_years);
+    if log_growth >= T::max_ln() - T::one() {
+        return Err(ComputeError::Overflow);
+    }
+
+    Ok(compute_pv(
+        future_value,
+        annual_interest_rate,
+        n_per_year_compounded,
+        n_years,
+    ))
+}
+
+// Solves for the annual interest rate needed to grow a principal into a target future value.
+///
+/// # Parameters:
+/// - `initial_value`: Initial principal amount (P)
+/// - `target_value`: Desired future amount (FV)
+/// - `n_per_year_compounded`: Number of compounding periods per year (n)
+/// - `n_years`: Time in years (t)
+///
+/// # Formula:
+/// r = n * ((FV / P)^(1/nt) - 1)
+/// r = ln(FV / P) / t   when compounding continuously (n infinite)
+///
+/// # Returns:
+/// The required annual interest rate as a decimal, e.g., 0.04 for 4%.
+pub fn solve_rate<T>(initial_value: T, target_value: T, n_per_year_compounded: T, n_years: T) -> T
+where
+    T: FinNumeric,
+{
+    let growth = target_value / initial_value;
+    if is_continuous(n_per_year_compounded) {
+        return growth.ln() / n_years;
+    }
     let nt = n_per_year_compounded * n_years;
-    let compound_rate = T::one() + annual_interest_rate / n_per_year_compounded;
 
-    truncate_to_two_decimal_places(future_value / compound_rate.powf(nt))
+    n_per_year_compounded * (growth.powf(T::one() / nt) - T::one())
+}
+
+/// Lowest and highest annual rates `solve_rate_with_contributions` searches between
+const SOLVE_RATE_RANGE: (f64, f64) = (-0.99, 10.0);
+
+// Solves for the annual interest rate needed to grow a principal plus recurring contributions
+// into a target future value. There is no closed form once contributions are added, so the rate
+// is found by bisection.
+///
+/// # Parameters:
+/// - `initial_value`: Initial principal amount (P)
+/// - `contribution`: Amount deposited each contribution period (PMT)
+/// - `target_value`: Desired future amount (FV)
+/// - `n_per_year_compounded`: Number of compounding periods per year (n)
+/// - `m_per_year_contributed`: Number of contributions per year (m)
+/// - `n_years`: Time in years (t)
+/// - `timing`: Whether contributions are made at the start or end of each period
+///
+/// # Formula:
+/// FV(r) = P * (1 + r/n)^nt + PMT * ((1 + i)^mt - 1) / i, where i = (1 + r/n)^(n/m) - 1
+/// Solve FV(r) = target for r; FV(r) rises with r, so bisection converges on it
+/// r = n * ((FV / P)^(1/nt) - 1)   when PMT = 0
+///
+/// # Returns:
+/// The required annual interest rate as a decimal, or NaN if no rate between -99% and 1,000%
+/// reaches the target.
+pub fn solve_rate_with_contributions(
+    initial_value: f64,
+    contribution: f64,
+    target_value: f64,
+    n_per_year_compounded: f64,
+    m_per_year_contributed: f64,
+    n_years: f64,
+    timing: ContributionTiming,
+) -> f64 {
+    if contribution == 0.0 {
+        return solve_rate(initial_value, target_value, n_per_year_compounded, n_years);
+    }
+    let future_value = |rate: f64| {
+        compute_fv_with_contributions(
+            initial_value,
+            contribution,
+            rate,
+            n_per_year_compounded,
+            m_per_year_contributed,
+            n_years,
+            timing,
+        )
+    };
+
+    let (mut low, mut high) = SOLVE_RATE_RANGE;
+    if !(future_value(low) <= target_value && future_value(high) >= target_value) {
+        return f64::NAN;
+    }
+    for _ in 0..100 {
+        let mid = (low + high) / 2.0;
+        if future_value(mid) < target_value {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    (low + high) / 2.0
+}
+
+// Solves for the number of years needed to grow a principal into a target future value.
+///
+/// # Parameters:
+/// - `initial_value`: Initial principal amount (P)
+/// - `target_value`: Desired future amount (FV)
+/// - `annual_interest_rate`: Annual interest rate (r), e.g., 0.04 for 4%
+/// - `n_per_year_compounded`: Number of compounding periods per year (n)
+///
+/// # Formula:
+/// t = ln(FV / P) / (n * ln(1 + r/n))
+/// t = ln(FV / P) / r   when compounding continuously (n infinite)
+///
+/// # Returns:
+/// The required time in (possibly fractional) years, or `None` if the principal already meets
+/// the target or never grows to it.
+pub fn solve_years<T>(
+    initial_value: T,
+    target_value: T,
+    annual_interest_rate: T,
+    n_per_year_compounded: T,
+) -> Option<T>
+where
+    T: FinNumeric,
+{
+    if initial_value <= T::zero() || target_value <= initial_value {
+        return None;
+    }
+    let log_growth = log_growth_factor(annual_interest_rate, n_per_year_compounded, T::one());
+    if log_growth <= T::zero() {
+        return None;
+    }
+    Some((target_value / initial_value).ln() / log_growth)
+}
+
+/// Solves for the number of years needed to reach a target future value with a principal plus
+/// equal recurring contributions, as computed by `compute_fv_with_contributions`.
+///
+/// # Formula:
+/// i = (1 + r/n)^(n/m) - 1   (rate per contribution period), or e^(r/m) - 1 when continuous
+/// k = PMT / i, or PMT * (1 + i) / i when contributions begin each period
+/// t = ln((FV + k) / (P + k)) / (m * ln(1 + i))
+/// t = (FV - P) / (PMT * m)   when i = 0
+///
+/// # Returns:
+/// The required time in (possibly fractional) years, or NaN if the principal already meets the
+/// target or the target is never reached (e.g., the balance shrinks faster than the deposits
+/// add to it).
+pub fn solve_years_with_contributions(
+    initial_value: f64,
+    contribution: f64,
+    target_value: f64,
+    annual_interest_rate: f64,
+    n_per_year_compounded: f64,
+    m_per_year_contributed: f64,
+    timing: ContributionTiming,
+) -> f64 {
+    if target_value <= initial_value {
+        return f64::NAN;
+    }
+    if contribution == 0.0 {
+        return solve_years(
+            initial_value,
+            target_value,
+            annual_interest_rate,
+            n_per_year_compounded,
+        )
+        .unwrap_or(f64::NAN);
+    }
+    let contribution_rate = growth_factor(
+        annual_interest_rate,
+        n_per_year_compounded,
+        1.0 / m_per_year_contributed,
+    ) - 1.0;
+    let years = if contribution_rate.abs() < f64::EPSILON {
+        (target_value - initial_value) / (contribution * m_per_year_contributed)
+    } else {
+        let mut payment = contribution;
+        if timing == ContributionTiming::Begin {
+            payment *= 1.0 + contribution_rate;
+        }
+        // The balance approaches -k, so the target is only reached if it lies beyond the start
+        let k = payment / contribution_rate;
+        ((target_value + k) / (initial_value + k)).ln()
+            / (m_per_year_contributed * contribution_rate.ln_1p())
+    };
+    if years.is_finite() && years > 0.0 {
+        years
+    } else {
+        f64::NAN
+    }
+}
+
+/// Formats fractional years as whole years and months, e.g., 7.5 -> "7 years, 6 months"
+pub fn format_years_months(years: f64) -> String {
+    let total_months = (years * 12.0).round() as i64;
+    let (whole_years, months) = (total_months / 12, total_months % 12);
+    let year_label = if whole_years == 1 { "year" } else { "years" };
+    let month_label = if months == 1 { "month" } else { "months" };
+
+    format!("{} {}, {} {}", whole_years, year_label, months, month_label)
 }
@@ -0,0 +1,30 @@
+use crate::analytics::{use_analytics, Consent};
+use dioxus::prelude::*;
+
+/// Asks for analytics consent until the user chooses; hidden afterwards
+#[component]
+pub fn ConsentBanner() -> Element {
+    let mut analytics = use_analytics();
+
+    if analytics().consent != Consent::Unknown {
+        return rsx! {};
+    }
+
+    rsx! {
+        div { style: "position: fixed; bottom: 0; left: 0; right: 0; background: #1e222d; color: white; padding: 12px 20px; display: flex; align-items: center; gap: 10px; font-size: 14px;",
+            span { style: "flex: 1;",
+                "Help decide which tools to build next? With your permission we count which calculators are used and rough input ranges (e.g., \"$10k-$100k\") — never the numbers you enter."
+            }
+            button {
+                style: "padding: 6px 12px; border-radius: 4px;",
+                onclick: move |_| analytics.write().set_consent(Consent::Granted),
+                "Allow"
+            }
+            button {
+                style: "padding: 6px 12px; border-radius: 4px;",
+                onclick: move |_| analytics.write().set_consent(Consent::Denied),
+                "No thanks"
+            }
+        }
+    }
+}
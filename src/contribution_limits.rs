@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+
+/// IRS annual contribution limits for a single tax year (in dollars).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContributionLimits {
+    pub year: i32,
+    /// 401(k)/403(b)/457 elective deferral limit
+    pub elective_deferral: f64,
+    /// Additional elective deferral allowed at age 50 and over
+    pub catch_up: f64,
+    /// Additional elective deferral allowed at ages 60 through 63 (replaces `catch_up`), if any
+    pub catch_up_60_to_63: Option<f64>,
+    /// Traditional/Roth IRA limit
+    pub ira: f64,
+    /// Additional IRA contribution allowed at age 50 and over
+    pub ira_catch_up: f64,
+}
+
+/// Contribution limits by tax year, oldest first.
+pub const CONTRIBUTION_LIMITS: &[ContributionLimits] = &[
+    ContributionLimits {
+        year: 2020,
+        elective_deferral: 19_500.0,
+        catch_up: 6_500.0,
+        catch_up_60_to_63: None,
+        ira: 6_000.0,
+        ira_catch_up: 1_000.0,
+    },
+    ContributionLimits {
+        year: 2021,
+        elective_deferral: 19_500.0,
+        catch_up: 6_500.0,
+        catch_up_60_to_63: None,
+        ira: 6_000.0,
+        ira_catch_up: 1_000.0,
+    },
+    ContributionLimits {
+        year: 2022,
+        elective_deferral: 20_500.0,
+        catch_up: 6_500.0,
+        catch_up_60_to_63: None,
+        ira: 6_000.0,
+        ira_catch_up: 1_000.0,
+    },
+    ContributionLimits {
+        year: 2023,
+        elective_deferral: 22_500.0,
+        catch_up: 7_500.0,
+        catch_up_60_to_63: None,
+        ira: 6_500.0,
+        ira_catch_up: 1_000.0,
+    },
+    ContributionLimits {
+        year: 2024,
+        elective_deferral: 23_000.0,
+        catch_up: 7_500.0,
+        catch_up_60_to_63: None,
+        ira: 7_000.0,
+        ira_catch_up: 1_000.0,
+    },
+    ContributionLimits {
+        year: 2025,
+        elective_deferral: 23_500.0,
+        catch_up: 7_500.0,
+        catch_up_60_to_63: Some(11_250.0),
+        ira: 7_000.0,
+        ira_catch_up: 1_000.0,
+    },
+    ContributionLimits {
+        year: 2026,
+        elective_deferral: 24_500.0,
+        catch_up: 8_000.0,
+        catch_up_60_to_63: Some(11_250.0),
+        ira: 7_500.0,
+        ira_catch_up: 1_100.0,
+    },
+];
+
+/// Looks up the limits for a tax year, falling back to the most recent year on record
+pub fn limits_for_year(year: i32) -> &'static ContributionLimits {
+    CONTRIBUTION_LIMITS
+        .iter()
+        .rev()
+        .find(|limits| limits.year <= year)
+        .unwrap_or(&CONTRIBUTION_LIMITS[0])
+}
+
+impl ContributionLimits {
+    /// Maximum employee elective deferral for someone of `age` at year end, including catch-up
+    pub fn max_elective_deferral(&self, age: u32) -> f64 {
+        match (age, self.catch_up_60_to_63) {
+            (60..=63, Some(super_catch_up)) => self.elective_deferral + super_catch_up,
+            (50.., _) => self.elective_deferral + self.catch_up,
+            _ => self.elective_deferral,
+        }
+    }
+
+    /// Maximum IRA contribution for someone of `age` at year end, including catch-up
+    pub fn max_ira(&self, age: u32) -> f64 {
+        if age >= 50 {
+            self.ira + self.ira_catch_up
+        } else {
+            self.ira
+        }
+    }
+}
+
+/// Result of checking an annual contribution against the applicable limit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CappedContribution {
+    /// Contribution actually allowed (never above the limit)
+    pub allowed: f64,
+    /// Amount by which the requested contribution exceeded the limit (zero if within it)
+    pub excess: f64,
+    pub limit: f64,
+}
+
+/// Caps an annual elective deferral at the limit for the given year and age
+pub fn cap_elective_deferral(annual_contribution: f64, year: i32, age: u32) -> CappedContribution {
+    let limit = limits_for_year(year).max_elective_deferral(age);
+
+    CappedContribution {
+        allowed: annual_contribution.min(limit),
+        excess: (annual_contribution - limit).max(0.0),
+        limit,
+    }
+}
+
+/// Caps an annual IRA contribution at the limit for the given year and age
+pub fn cap_ira_contribution(annual_contribution: f64, year: i32, age: u32) -> CappedContribution {
+    let limit = limits_for_year(year).max_ira(age);
+
+    CappedContribution {
+        allowed: annual_contribution.min(limit),
+        excess: (annual_contribution - limit).max(0.0),
+        limit,
+    }
+}
+
+/// The kind of account contributions go into, which decides the limit they're held to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LimitedAccount {
+    /// No IRS limit, e.g., a taxable brokerage account
+    #[default]
+    Unlimited,
+    /// 401(k), 403(b), or 457 elective deferrals
+    Workplace,
+    /// Traditional or Roth IRA
+    Ira,
+}
+
+impl LimitedAccount {
+    pub fn label(self) -> &'static str {
+        match self {
+            LimitedAccount::Unlimited => "No limit",
+            LimitedAccount::Workplace => "401(k)",
+            LimitedAccount::Ira => "IRA",
+        }
+    }
+
+    /// Caps an annual contribution at this account's limit for the given year and age
+    pub fn cap(self, annual_contribution: f64, year: i32, age: u32) -> CappedContribution {
+        match self {
+            LimitedAccount::Unlimited => CappedContribution {
+                allowed: annual_contribution,
+                excess: 0.0,
+                limit: f64::INFINITY,
+            },
+            LimitedAccount::Workplace => cap_elective_deferral(annual_contribution, year, age),
+            LimitedAccount::Ira => cap_ira_contribution(annual_contribution, year, age),
+        }
+    }
+
+    /// The catch-up contribution allowed above the base limit at `age`: zero before 50
+    pub fn catch_up(self, year: i32, age: u32) -> f64 {
+        let limits = limits_for_year(year);
+        match self {
+            LimitedAccount::Unlimited => 0.0,
+            LimitedAccount::Workplace => {
+                limits.max_elective_deferral(age) - limits.elective_deferral
+            }
+            LimitedAccount::Ira => limits.max_ira(age) - limits.ira,
+        }
+    }
+}
+
+// Computes the annual employer match on an employee contribution.
+///
+/// # Parameters:
+/// - `salary`: Annual salary
+/// - `employee_contribution`: Annual employee elective deferral
+/// - `match_rate`: Portion of each contributed dollar the employer matches, e.g., 0.5 for 50%
+/// - `match_cap`: Share of salary eligible for matching, e.g., 0.06 for "up to 6% of pay"
+///
+/// # Formula:
+/// match = match_rate * min(contribution, match_cap * salary)
+pub fn employer_match(
+    salary: f64,
+    employee_contribution: f64,
+    match_rate: f64,
+    match_cap: f64,
+) -> f64 {
+    match_rate * employee_contribution.min(match_cap * salary)
+}
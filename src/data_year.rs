@@ -0,0 +1,179 @@
+use crate::contribution_limits::CONTRIBUTION_LIMITS;
+use crate::healthcare::MEDICARE_PREMIUMS;
+use crate::storage::{load_item, save_item};
+use crate::tax::TAX_TABLES;
+use dioxus::prelude::*;
+
+/// Annual reference data a calculator can be pinned to, such as one year's tax brackets
+#[derive(Debug, Clone, Copy)]
+pub struct DataSet {
+    /// Stable identifier, used in the storage key of the selected year
+    pub slug: &'static str,
+    pub name: &'static str,
+    /// The years on record, oldest first
+    pub years: fn() -> Vec<i32>,
+}
+
+/// Every annual data set, in the order the settings page lists them
+pub const DATA_SETS: &[DataSet] = &[
+    DataSet {
+        slug: "tax_brackets",
+        name: "Federal tax brackets",
+        years: tax_years,
+    },
+    DataSet {
+        slug: "contribution_limits",
+        name: "Contribution limits",
+        years: contribution_limit_years,
+    },
+    DataSet {
+        slug: "medicare_premiums",
+        name: "Medicare premiums",
+        years: medicare_premium_years,
+    },
+];
+
+fn tax_years() -> Vec<i32> {
+    let mut years: Vec<i32> = TAX_TABLES.iter().map(|table| table.year).collect();
+    years.sort();
+    years.dedup();
+    years
+}
+
+fn contribution_limit_years() -> Vec<i32> {
+    CONTRIBUTION_LIMITS
+        .iter()
+        .map(|limits| limits.year)
+        .collect()
+}
+
+fn medicare_premium_years() -> Vec<i32> {
+    MEDICARE_PREMIUMS
+        .iter()
+        .map(|premiums| premiums.year)
+        .collect()
+}
+
+pub fn find_data_set(slug: &str) -> Option<&'static DataSet> {
+    DATA_SETS.iter().find(|data_set| data_set.slug == slug)
+}
+
+/// Why a selected data year deserves a banner
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DataYearNotice {
+    /// A later year, up to the current one, is on record
+    NewerAvailable { selected: i32, newer: i32 },
+    /// The selected year is behind the calendar, and nothing newer is on record yet
+    NoneNewer { selected: i32, current: i32 },
+}
+
+impl DataYearNotice {
+    pub fn message(&self, data_name: &str) -> String {
+        match self {
+            DataYearNotice::NewerAvailable { selected, newer } => format!(
+                "{} are set to {}. The {} figures are available.",
+                data_name, selected, newer
+            ),
+            DataYearNotice::NoneNewer { selected, current } => format!(
+                "{} are from {}; the {} figures aren't in this build yet.",
+                data_name, selected, current
+            ),
+        }
+    }
+}
+
+/// Checks a selected data year against the calendar. Returns `None` while it is current, and
+/// otherwise offers the latest year on record that has started, if it is newer than the one
+/// selected. Years published ahead of time are not offered before they begin.
+pub fn data_year_notice(
+    selected: i32,
+    available: &[i32],
+    current_year: i32,
+) -> Option<DataYearNotice> {
+    if selected >= current_year {
+        return None;
+    }
+    let newer = available
+        .iter()
+        .copied()
+        .filter(|year| *year > selected && *year <= current_year)
+        .max();
+    Some(match newer {
+        Some(newer) => DataYearNotice::NewerAvailable { selected, newer },
+        None => DataYearNotice::NoneNewer {
+            selected,
+            current: current_year,
+        },
+    })
+}
+
+fn data_year_key(slug: &str) -> String {
+    format!("fintools.data_year.{}", slug)
+}
+
+/// The year selected for a data set, restored from localStorage. Until one is chosen it is the
+/// latest year on record.
+pub fn use_data_year(slug: &'static str) -> Signal<i32> {
+    let latest = find_data_set(slug)
+        .and_then(|data_set| (data_set.years)().last().copied())
+        .unwrap_or_default();
+    let mut year = use_signal(|| latest);
+    use_future(move || async move {
+        if let Some(stored) = load_item(&data_year_key(slug)).await {
+            if let Ok(stored) = stored.parse::<i32>() {
+                year.set(stored);
+            }
+        }
+    });
+    year
+}
+
+/// Selects and remembers the year used for a data set
+pub fn set_data_year(slug: &str, mut year_signal: Signal<i32>, year: i32) {
+    save_item(&data_year_key(slug), &year.to_string());
+    year_signal.set(year);
+}
+
+/// The calendar year on the user's clock
+pub async fn browser_year() -> Option<i32> {
+    document::eval("return new Date().getFullYear();")
+        .join::<i32>()
+        .await
+        .ok()
+}
+
+/// Banner shown while a data set is pinned to a year the calendar has moved past. It offers to
+/// switch to the newest year on record; dismissing it hides it until the page is reopened.
+#[component]
+pub fn DataYearBanner(data_set: &'static str, year: Signal<i32>) -> Element {
+    let current_year = use_resource(browser_year);
+    let mut dismissed = use_signal(|| false);
+    let Some(data_set) = find_data_set(data_set) else {
+        return rsx! {};
+    };
+    let notice = current_year()
+        .flatten()
+        .and_then(|current| data_year_notice(year(), &(data_set.years)(), current));
+
+    rsx! {
+        if let Some(notice) = notice.filter(|_| !dismissed()) {
+            div {
+                role: "status",
+                style: "display: flex; align-items: center; gap: 10px; background: #fff4ce; color: #333; border-radius: 4px; padding: 6px 10px; margin-bottom: 15px; font-size: 14px;",
+                span { {notice.message(data_set.name)} }
+                if let DataYearNotice::NewerAvailable { newer, .. } = notice {
+                    button {
+                        style: "padding: 2px 8px; border-radius: 4px;",
+                        onclick: move |_| set_data_year(data_set.slug, year, newer),
+                        "Use {newer}"
+                    }
+                }
+                button {
+                    style: "padding: 2px 8px; border-radius: 4px;",
+                    onclick: move |_| dismissed.set(true),
+                    "Dismiss"
+                }
+            }
+        }
+    }
+}
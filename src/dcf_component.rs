@@ -0,0 +1,348 @@
+use crate::analytics::use_track_calculator;
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::error_summary_component::{ErrorSummary, FieldError};
+use crate::feedback_component::FeedbackWidget;
+use crate::link::{use_link_receiver, CalculatorLink};
+use crate::npv::{compute_dcf, CashFlow, CashFlowIndexing, CashFlowSeries, DcfInputs};
+use crate::npv_component::{FlowCell, FlowField};
+use crate::number_format::dollars;
+use crate::numeric_input_component::{numeric_field_error, NumericInput};
+use crate::table_paste::parse_pasted_table;
+use crate::table_paste_component::TablePasteSection;
+use crate::wizard_component::Wizard;
+use dioxus::prelude::*;
+
+/// Five years of free cash flow growing by $10,000 a year, discounted at 9% with 2.5% growth after
+fn default_inputs() -> DcfInputs {
+    DcfInputs {
+        discount_rate: 0.09,
+        terminal_growth: 0.025,
+        series: projected_series(&[100_000.0, 110_000.0, 120_000.0, 130_000.0, 140_000.0]),
+    }
+}
+
+/// Free cash flows for years 1, 2, 3, and so on
+fn projected_series(amounts: &[f64]) -> CashFlowSeries {
+    CashFlowSeries {
+        indexing: CashFlowIndexing::Period,
+        flows: amounts
+            .iter()
+            .enumerate()
+            .map(|(i, amount)| CashFlow {
+                period: i as u32 + 1,
+                date: String::new(),
+                amount: *amount,
+            })
+            .collect(),
+    }
+}
+
+const WIZARD_TITLES: &[&str] = &[
+    "What return do investors in the business require?",
+    "How fast will cash flow grow after the forecast, forever?",
+    "What free cash flow do you forecast for each year?",
+];
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 5px; font-family: monospace;";
+const LABEL_STYLE: &str = "text-align: left; padding: 2px 5px;";
+
+#[component]
+pub fn DcfUI() -> Element {
+    // Each row keeps a stable id so its cell keeps its text when other rows are removed
+    let mut flows = use_signal(|| {
+        default_inputs()
+            .series
+            .flows
+            .into_iter()
+            .enumerate()
+            .map(|(i, flow)| (i as u64, flow.amount))
+            .collect::<Vec<_>>()
+    });
+    let mut next_id = use_signal(|| 5u64);
+    let mut rate_signal = use_signal(|| 9.0 as f64);
+    let mut rate_input = use_signal(|| "9.0".to_string());
+    let rate_input_valid = use_signal(|| true);
+    let mut growth_signal = use_signal(|| 2.5 as f64);
+    let mut growth_input = use_signal(|| "2.5".to_string());
+    let growth_input_valid = use_signal(|| true);
+    // Row ids whose free cash flow text isn't an amount
+    let mut invalid_rows = use_signal(Vec::<u64>::new);
+    let mut guided = use_signal(|| false);
+    let mut wizard_step = use_signal(|| 0usize);
+    let apply_link = use_callback(move |link: CalculatorLink| match link {
+        CalculatorLink::DiscountRate(rate) => {
+            rate_signal.set(rate * 100.0);
+            rate_input.set(format!("{:.2}", rate * 100.0));
+            true
+        }
+        _ => false,
+    });
+    let received_link = use_link_receiver(apply_link);
+    let inputs = move || DcfInputs {
+        discount_rate: rate_signal() / 100.0,
+        terminal_growth: growth_signal() / 100.0,
+        series: projected_series(
+            &flows()
+                .into_iter()
+                .map(|(_, amount)| amount)
+                .collect::<Vec<_>>(),
+        ),
+    };
+    let start_fresh = use_autosave(
+        "dcf",
+        default_inputs(),
+        inputs,
+        use_callback(move |saved: DcfInputs| {
+            rate_signal.set(saved.discount_rate * 100.0);
+            rate_input.set(format!("{}", saved.discount_rate * 100.0));
+            growth_signal.set(saved.terminal_growth * 100.0);
+            growth_input.set(format!("{}", saved.terminal_growth * 100.0));
+            // New ids remount the rows so their cells show the restored values
+            let first_id = *next_id.peek();
+            next_id.set(first_id + saved.series.flows.len() as u64);
+            invalid_rows.set(vec![]);
+            flows.set(
+                saved
+                    .series
+                    .flows
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, flow)| (first_id + i as u64, flow.amount))
+                    .collect(),
+            );
+            if let Some(link) = received_link() {
+                apply_link.call(link);
+            }
+        }),
+    );
+    use_track_calculator("dcf");
+
+    // Problems with each wizard step's inputs, in the order of `WIZARD_TITLES`
+    let invalid = invalid_rows();
+    let mut field_errors: [Vec<FieldError>; 3] = [
+        numeric_field_error(&rate_input(), rate_input_valid(), "Discount rate", "rate")
+            .into_iter()
+            .collect(),
+        numeric_field_error(
+            &growth_input(),
+            growth_input_valid(),
+            "Terminal growth",
+            "growth",
+        )
+        .into_iter()
+        .collect(),
+        vec![],
+    ];
+    for (row, (id, _)) in flows().iter().enumerate() {
+        if invalid.contains(id) {
+            field_errors[2].push(FieldError {
+                field_id: FlowField::Amount.cell_id(*id),
+                message: format!(
+                    "Year {}, free cash flow: {}",
+                    row + 1,
+                    FlowField::Amount.hint()
+                ),
+            });
+        }
+    }
+    // Listed in page order, the rates first
+    let errors = field_errors.concat();
+    let outputs = compute_dcf(&inputs());
+    let present_values = outputs
+        .as_ref()
+        .map(|outputs| outputs.present_values.clone())
+        .unwrap_or_default();
+    let discount_factor = move |year: usize| 1.0 / (1.0 + rate_signal() / 100.0).powi(year as i32);
+    let rows: Vec<(usize, u64, f64, Option<f64>)> = flows()
+        .into_iter()
+        .enumerate()
+        .map(|(i, (id, amount))| (i + 1, id, amount, present_values.get(i).copied()))
+        .collect();
+    let n_years = rows.len();
+    let fields = [
+        rsx! {
+            NumericInput {
+                label: "Discount Rate (%/yr):".to_string(),
+                placeholder: "Enter the required return, e.g., the WACC (e.g., 9.0)".to_string(),
+                input_signal: rate_input,
+                value_signal: rate_signal,
+                valid_signal: rate_input_valid,
+                field_name: "Discount rate".to_string(),
+                css_prefix: "rate".to_string(),
+                step: Some(0.25),
+            }
+        },
+        rsx! {
+            NumericInput {
+                label: "Terminal Growth (%/yr):".to_string(),
+                placeholder: "Enter the growth of cash flow after the last year, forever (e.g., 2.5)".to_string(),
+                input_signal: growth_input,
+                value_signal: growth_signal,
+                valid_signal: growth_input_valid,
+                field_name: "Terminal growth".to_string(),
+                css_prefix: "growth".to_string(),
+                allow_negative: true,
+                step: Some(0.25),
+            }
+        },
+        rsx! {
+            table { style: "color: #333; border-collapse: collapse; margin-bottom: 15px;",
+                thead {
+                    tr {
+                        th { style: LABEL_STYLE, "Year" }
+                        th { style: LABEL_STYLE, "Free Cash Flow ($)" }
+                        th { style: CELL_STYLE, "Discount Factor" }
+                        th { style: CELL_STYLE, "Present Value" }
+                        th {}
+                    }
+                }
+                tbody {
+                    for (year , id , amount , present_value) in rows.into_iter() {
+                        tr { key: "{id}",
+                            td { style: LABEL_STYLE, "{year}" }
+                            FlowCell {
+                                id: FlowField::Amount.cell_id(id),
+                                label: format!("Year {} free cash flow", year),
+                                initial: amount.to_string(),
+                                field: FlowField::Amount,
+                                on_value: move |text: String| {
+                                    if let Some((_, amount)) = flows
+                                        .write()
+                                        .iter_mut()
+                                        .find(|(row_id, _)| *row_id == id)
+                                    {
+                                        *amount = text.parse().unwrap_or(*amount);
+                                    }
+                                },
+                                on_valid: move |valid: bool| {
+                                    let mut invalid = invalid_rows.write();
+                                    invalid.retain(|row_id| *row_id != id);
+                                    if !valid {
+                                        invalid.push(id);
+                                    }
+                                },
+                            }
+                            td { style: CELL_STYLE, "{discount_factor(year):.4}" }
+                            td { style: CELL_STYLE, {present_value.map(dollars).unwrap_or_default()} }
+                            td {
+                                button {
+                                    style: "padding: 2px 8px; border-radius: 4px;",
+                                    onclick: move |_| {
+                                        flows.write().retain(|(row_id, _)| *row_id != id);
+                                        invalid_rows.write().retain(|row_id| *row_id != id);
+                                    },
+                                    "Remove"
+                                }
+                            }
+                        }
+                    }
+                    if let Ok(outputs) = &outputs {
+                        tr {
+                            th { scope: "row", style: LABEL_STYLE, "Terminal" }
+                            td { style: CELL_STYLE, {dollars(outputs.terminal_value)} }
+                            td { style: CELL_STYLE, "{discount_factor(n_years):.4}" }
+                            td { style: CELL_STYLE, {dollars(outputs.terminal_present_value)} }
+                            td {}
+                        }
+                    }
+                }
+            }
+            button {
+                style: "padding: 6px 12px; border-radius: 4px; margin-bottom: 15px;",
+                onclick: move |_| {
+                    let id = next_id();
+                    next_id.set(id + 1);
+                    let amount = flows.peek().last().map(|(_, amount)| *amount).unwrap_or(0.0);
+                    flows.write().push((id, amount));
+                },
+                "Add Year"
+            }
+            TablePasteSection {
+                columns: "Free Cash Flow ($), one year to a row".to_string(),
+                placeholder: "100000".to_string(),
+                on_paste: move |text: String| {
+                    let pasted = parse_pasted_table(
+                        &text,
+                        &["Free Cash Flow ($)"],
+                        |cell| cell.parse::<f64>().ok().filter(|amount| amount.is_finite()),
+                    );
+                    let first_id = next_id();
+                    next_id.set(first_id + pasted.rows.len() as u64);
+                    let added = pasted.rows.iter().enumerate().map(|(i, row)| (first_id + i as u64, row[0]));
+                    flows.write().extend(added);
+                    pasted.report()
+                },
+            }
+        },
+    ];
+
+    rsx! {
+        hr {}
+        br {}
+
+        if guided() {
+            Wizard {
+                titles: WIZARD_TITLES.iter().map(|title| title.to_string()).collect::<Vec<_>>(),
+                step: wizard_step,
+                errors: field_errors[wizard_step().min(field_errors.len() - 1)].clone(),
+                on_finish: move |_| guided.set(false),
+                {fields[wizard_step().min(fields.len() - 1)].clone()}
+            }
+        } else {
+            ErrorSummary { errors }
+
+            button {
+                style: "padding: 6px 12px; border-radius: 4px; margin-bottom: 15px;",
+                onclick: move |_| {
+                    wizard_step.set(0);
+                    guided.set(true);
+                },
+                "Guided Setup"
+            }
+            for field in fields.iter() {
+                {field.clone()}
+            }
+
+            {
+                match outputs {
+                    Ok(outputs) => {
+                        let terminal_share = if outputs.enterprise_value != 0.0 {
+                            outputs.terminal_present_value / outputs.enterprise_value
+                        } else {
+                            0.0
+                        };
+                        rsx! {
+                            div {
+                                id: "DcfCalculation",
+                                style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                                "Enterprise value: {dollars(outputs.enterprise_value)}*"
+                            }
+                            div { style: "margin-bottom: 15px; font-size: 16px;",
+                                "The terminal value makes up {terminal_share * 100.0:.1}% of it."
+                            }
+                        }
+                    }
+                    Err(error) => rsx! {
+                        div {
+                            id: "DcfCalculation",
+                            style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                            "{error}"
+                        }
+                    },
+                }
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "dcf" }
+        AssumptionsFooter { calculator: "dcf" }
+        div { style: "font-size: 10px",
+            p {
+                "*Enterprise value is the worth of the whole business to all its investors; subtract net debt to value the equity."
+            }
+            br {}
+            br {}
+        }
+    }
+}
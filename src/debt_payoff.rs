@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+/// Most debts the payoff comparison takes
+pub const MAX_DEBTS: usize = 10;
+
+/// Longest payoff simulated, in months; a budget that takes longer is treated as never paying
+/// the debts off
+pub const MAX_PAYOFF_MONTHS: usize = 1_200;
+
+/// One debt in the payoff comparison, paid monthly
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Debt {
+    pub balance: f64,
+    /// Annual interest rate as a decimal, e.g., 0.22 for 22%
+    pub annual_rate: f64,
+    /// Least the lender accepts each month
+    pub minimum_payment: f64,
+}
+
+/// Inputs to the debt payoff comparison
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DebtPayoffInputs {
+    pub debts: Vec<Debt>,
+    /// Paid toward the debts every month, minimums included
+    pub monthly_budget: f64,
+}
+
+/// Which debt gets the money left after the minimums
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayoffStrategy {
+    /// Smallest balance first, for quick wins
+    Snowball,
+    /// Highest rate first, for the least interest
+    Avalanche,
+}
+
+/// How a strategy plays out
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebtPayoff {
+    /// Months until the last debt is paid off
+    pub months: usize,
+    pub total_interest: f64,
+    pub total_paid: f64,
+    /// Month each debt is paid off, in the order the debts were entered
+    pub payoff_months: Vec<usize>,
+    /// Indexes of the debts in the order the strategy targets them
+    pub order: Vec<usize>,
+}
+
+/// Order a strategy pays debts off in, as indexes into `debts`. Ties go to the other measure,
+/// then to the order entered.
+pub fn payoff_order(debts: &[Debt], strategy: PayoffStrategy) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..debts.len()).collect();
+    order.sort_by(|&a, &b| {
+        let (a, b) = (&debts[a], &debts[b]);
+        let by_balance = a.balance.total_cmp(&b.balance);
+        let by_rate = b.annual_rate.total_cmp(&a.annual_rate);
+        match strategy {
+            PayoffStrategy::Snowball => by_balance.then(by_rate),
+            PayoffStrategy::Avalanche => by_rate.then(by_balance),
+        }
+    });
+    order
+}
+
+// Simulates paying off several debts from one monthly budget.
+///
+/// # Parameters:
+/// - `debts`: Each debt's balance, annual rate, and minimum payment
+/// - `monthly_budget`: Paid toward the debts every month
+/// - `strategy`: Which debt the budget left after minimums goes to
+///
+/// # Formula:
+/// Each month, every balance accrues balance * r/12, every debt gets its minimum (or what's
+/// left of it), and the rest of the budget goes to debts in the strategy's order. A debt's
+/// minimum rolls into the rest once it's paid off, since the budget stays the same.
+///
+/// # Returns:
+/// The months to pay everything off, the interest and total paid, and when each debt is paid
+/// off. An error if there are no debts, an entry is negative, the budget doesn't cover the
+/// minimums, or the debts aren't paid off within `MAX_PAYOFF_MONTHS`.
+pub fn simulate_payoff(
+    debts: &[Debt],
+    monthly_budget: f64,
+    strategy: PayoffStrategy,
+) -> Result<DebtPayoff, String> {
+    if debts.is_empty() {
+        return Err("Add at least one debt".to_string());
+    }
+    let mut entries = debts
+        .iter()
+        .flat_map(|debt| [debt.balance, debt.annual_rate, debt.minimum_payment])
+        .chain([monthly_budget]);
+    if entries.any(|entry| entry.is_nan() || entry < 0.0) {
+        return Err("Balances, rates, payments, and the budget can't be negative".to_string());
+    }
+    let minimums: f64 = debts.iter().map(|debt| debt.minimum_payment).sum();
+    if monthly_budget < minimums {
+        return Err(format!(
+            "The budget must cover the minimum payments, which add up to {:.2}",
+            minimums
+        ));
+    }
+
+    let round_cents = |value: f64| (value * 100.0).round() / 100.0;
+    let order = payoff_order(debts, strategy);
+    let mut balances: Vec<f64> = debts.iter().map(|debt| round_cents(debt.balance)).collect();
+    let mut payoff_months = vec![0; debts.len()];
+    // Whole cents, so the total doesn't drift with the order the months add up in
+    let mut interest_cents: i64 = 0;
+    let mut total_paid = 0.0;
+    let mut month = 0;
+    while balances.iter().any(|balance| *balance > 0.0) {
+        if month == MAX_PAYOFF_MONTHS {
+            return Err(format!(
+                "This budget doesn't pay the debts off within {} years",
+                MAX_PAYOFF_MONTHS / 12
+            ));
+        }
+        month += 1;
+        for (balance, debt) in balances.iter_mut().zip(debts) {
+            let interest = round_cents(*balance * debt.annual_rate / 12.0);
+            *balance = round_cents(*balance + interest);
+            interest_cents += (interest * 100.0).round() as i64;
+        }
+        let mut available = monthly_budget;
+        for (balance, debt) in balances.iter_mut().zip(debts) {
+            let payment = debt.minimum_payment.min(*balance);
+            *balance = round_cents(*balance - payment);
+            available -= payment;
+        }
+        for &index in order.iter() {
+            let payment = available.min(balances[index]);
+            balances[index] = round_cents(balances[index] - payment);
+            available -= payment;
+        }
+        total_paid += monthly_budget - available;
+        for (index, balance) in balances.iter().enumerate() {
+            if *balance <= 0.0 && payoff_months[index] == 0 {
+                payoff_months[index] = month;
+            }
+        }
+    }
+
+    Ok(DebtPayoff {
+        months: month,
+        total_interest: interest_cents as f64 / 100.0,
+        total_paid: round_cents(total_paid),
+        payoff_months,
+        order,
+    })
+}
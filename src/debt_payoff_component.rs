@@ -0,0 +1,402 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::debt_payoff::{
+    simulate_payoff, Debt, DebtPayoff, DebtPayoffInputs, PayoffStrategy, MAX_DEBTS,
+};
+use crate::error_summary_component::{ErrorSummary, FieldError};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::loan::lowest_offer;
+use crate::number_format::dollars;
+use crate::numeric_input_component::NumericInput;
+use crate::table_paste::parse_pasted_table;
+use crate::table_paste_component::TablePasteSection;
+use dioxus::prelude::*;
+
+/// Column order of the debts table, which is also the order pasted ranges are read in
+const DEBT_COLUMNS: &[&str] = &["Balance ($)", "Rate (%)", "Minimum ($)"];
+
+/// The strategies compared, as (strategy, display)
+const STRATEGIES: &[(PayoffStrategy, &str)] = &[
+    (PayoffStrategy::Snowball, "Snowball"),
+    (PayoffStrategy::Avalanche, "Avalanche"),
+];
+
+/// Rows of the comparison: what each measures and how it's shown
+const COMPARISON_ROWS: &[(&str, fn(&DebtPayoff) -> f64, fn(f64) -> String)] = &[
+    (
+        "Debt-free in",
+        |p| p.months as f64,
+        |months| format_months(months as usize),
+    ),
+    ("Total interest", |p| p.total_interest, dollars),
+    ("Total paid", |p| p.total_paid, dollars),
+];
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+const BEST_CELL_STYLE: &str =
+    "text-align: right; padding: 2px 8px; background: #d5f5e3; font-weight: bold;";
+
+/// A credit card, a car loan, a store card, and a personal loan, where the smallest balance
+/// isn't the costliest, so the two orders differ
+fn default_debts() -> Vec<Debt> {
+    vec![
+        Debt {
+            balance: 8_000.0,
+            annual_rate: 0.24,
+            minimum_payment: 200.0,
+        },
+        Debt {
+            balance: 4_000.0,
+            annual_rate: 0.06,
+            minimum_payment: 150.0,
+        },
+        Debt {
+            balance: 1_200.0,
+            annual_rate: 0.18,
+            minimum_payment: 40.0,
+        },
+        Debt {
+            balance: 6_000.0,
+            annual_rate: 0.11,
+            minimum_payment: 180.0,
+        },
+    ]
+}
+
+/// Months as years and months, e.g., "2 years 3 months"
+fn format_months(months: usize) -> String {
+    let plural = |count: usize, unit: &str| {
+        format!("{} {}{}", count, unit, if count == 1 { "" } else { "s" })
+    };
+    match (months / 12, months % 12) {
+        (0, months) => plural(months, "month"),
+        (years, 0) => plural(years, "year"),
+        (years, months) => format!("{} {}", plural(years, "year"), plural(months, "month")),
+    }
+}
+
+/// Parses a table cell, accepting zero (e.g., an interest-free balance) but not negatives
+fn parse_cell(text: &str) -> Option<f64> {
+    text.replace(",", "")
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite() && *value >= 0.0)
+}
+
+/// Id of a debt cell, which the error summary links to
+fn cell_id(row_id: u64, column: usize) -> String {
+    format!("debt-{}-{}", row_id, column)
+}
+
+/// Editable number cell; `on_value` is called with every valid entry, `on_valid` with whether
+/// each entry is valid
+#[component]
+fn DebtCell(
+    id: String,
+    label: String,
+    initial: f64,
+    on_value: EventHandler<f64>,
+    on_valid: EventHandler<bool>,
+) -> Element {
+    let mut text = use_signal(|| format!("{}", initial));
+    let valid = parse_cell(&text()).is_some();
+
+    rsx! {
+        td { style: "padding: 2px 5px;",
+            input {
+                id,
+                value: "{text}",
+                "aria-label": "{label}",
+                "aria-invalid": "{!valid}",
+                style: if valid { "padding: 4px 6px; width: 90px; border-radius: 4px; font-family: monospace; border: 1px solid #ccc;" } else { "padding: 4px 6px; width: 90px; border-radius: 4px; font-family: monospace; border: 2px solid #ff0000; background-color: #ffe6e6; color: #333;" },
+                oninput: move |event| {
+                    text.set(event.value());
+                    let value = parse_cell(&event.value());
+                    on_valid.call(value.is_some());
+                    if let Some(value) = value {
+                        on_value.call(value);
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+pub fn DebtPayoffUI() -> Element {
+    // Each row keeps a stable id so its cells keep their text when other rows are removed
+    let mut debts = use_signal(|| {
+        default_debts()
+            .into_iter()
+            .enumerate()
+            .map(|(i, debt)| (i as u64, debt))
+            .collect::<Vec<_>>()
+    });
+    let mut next_id = use_signal(|| default_debts().len() as u64);
+    // Cells, as (row id, column), whose text isn't a valid number
+    let mut invalid_cells = use_signal(Vec::<(u64, usize)>::new);
+    let mut budget_signal = use_signal(|| 900.0 as f64);
+    let mut budget_input = use_signal(|| "900".to_string());
+    let budget_input_valid = use_signal(|| true);
+    let start_fresh = use_autosave(
+        "debt_payoff",
+        DebtPayoffInputs {
+            debts: default_debts(),
+            monthly_budget: 900.0,
+        },
+        move || DebtPayoffInputs {
+            debts: debts().iter().map(|(_, debt)| *debt).collect(),
+            monthly_budget: budget_signal(),
+        },
+        use_callback(move |saved: DebtPayoffInputs| {
+            // New ids remount the rows so their cells show the restored values
+            let first_id = *next_id.peek();
+            next_id.set(first_id + saved.debts.len() as u64);
+            invalid_cells.set(vec![]);
+            debts.set(
+                saved
+                    .debts
+                    .into_iter()
+                    .take(MAX_DEBTS)
+                    .enumerate()
+                    .map(|(i, debt)| (first_id + i as u64, debt))
+                    .collect(),
+            );
+            budget_signal.set(saved.monthly_budget);
+            budget_input.set(format!("{}", saved.monthly_budget));
+        }),
+    );
+    use_track_calculator("debt_payoff");
+    use_track_input_range("debt_payoff", "budget", budget_signal, amount_bucket);
+
+    let mut update = move |id: u64, change: fn(&mut Debt, f64), value: f64| {
+        if let Some((_, debt)) = debts.write().iter_mut().find(|(row_id, _)| *row_id == id) {
+            change(debt, value);
+        }
+    };
+    let mut set_valid = move |cell: (u64, usize), valid: bool| {
+        let mut invalid = invalid_cells.write();
+        invalid.retain(|invalid_cell| *invalid_cell != cell);
+        if !valid {
+            invalid.push(cell);
+        }
+    };
+    // Listed in table order, row by row
+    let invalid = invalid_cells();
+    let mut errors = vec![];
+    for (row, (id, _)) in debts().iter().enumerate() {
+        for (column, name) in DEBT_COLUMNS.iter().enumerate() {
+            if invalid.contains(&(*id, column)) {
+                errors.push(FieldError {
+                    field_id: cell_id(*id, column),
+                    message: format!("Debt {}, {}: enter a number of zero or more", row + 1, name),
+                });
+            }
+        }
+    }
+    let entered: Vec<Debt> = debts().iter().map(|(_, debt)| *debt).collect();
+    let payoffs: Result<Vec<_>, String> = STRATEGIES
+        .iter()
+        .map(|(strategy, _)| simulate_payoff(&entered, budget_signal(), *strategy))
+        .collect();
+    // Each row of the comparison: its label, each strategy's figure, and which is lowest
+    let mut comparison: Vec<(String, Vec<String>, Option<usize>)> = vec![];
+    if let Ok(payoffs) = &payoffs {
+        for (label, measure, format) in COMPARISON_ROWS.iter() {
+            let values: Vec<Option<f64>> = payoffs.iter().map(|p| Some(measure(p))).collect();
+            let cells = values
+                .iter()
+                .flatten()
+                .map(|value| format(*value))
+                .collect();
+            comparison.push((label.to_string(), cells, lowest_offer(&values)));
+        }
+        for index in 0..entered.len() {
+            let cells = payoffs
+                .iter()
+                .map(|p| format!("Month {}", p.payoff_months[index]))
+                .collect();
+            comparison.push((format!("Debt {} paid off", index + 1), cells, None));
+        }
+    }
+    let summary = match &payoffs {
+        Ok(payoffs) => {
+            let (snowball, avalanche) = (&payoffs[0], &payoffs[1]);
+            // Rounded so a difference of whole cents isn't shown a cent short
+            let saved =
+                ((snowball.total_interest - avalanche.total_interest) * 100.0).round() / 100.0;
+            if saved > 0.0 {
+                format!(
+                    "The avalanche saves ${} in interest over the snowball*",
+                    format_dollars(saved)
+                )
+            } else if saved < 0.0 {
+                format!(
+                    "The snowball saves ${} in interest over the avalanche*",
+                    format_dollars(-saved)
+                )
+            } else {
+                "Both orders cost the same with these debts*".to_string()
+            }
+        }
+        Err(error) => error.clone(),
+    };
+
+    rsx! {
+        hr {}
+        br {}
+
+        ErrorSummary { errors }
+
+        table { style: "color: #333; border-collapse: collapse; margin-bottom: 15px;",
+            thead {
+                tr {
+                    th {}
+                    for column in DEBT_COLUMNS.iter() {
+                        th { scope: "col", style: "text-align: left; padding: 2px 5px;", {*column} }
+                    }
+                    th {}
+                }
+            }
+            tbody {
+                for (row , (id , debt)) in debts().into_iter().enumerate() {
+                    tr { key: "{id}",
+                        th { scope: "row", style: "text-align: left; padding: 2px 5px;",
+                            "Debt {row + 1}"
+                        }
+                        DebtCell {
+                            id: cell_id(id, 0),
+                            label: format!("Debt {} {}", row + 1, DEBT_COLUMNS[0]),
+                            initial: debt.balance,
+                            on_value: move |v| update(id, |d, v| d.balance = v, v),
+                            on_valid: move |valid| set_valid((id, 0), valid),
+                        }
+                        DebtCell {
+                            id: cell_id(id, 1),
+                            label: format!("Debt {} {}", row + 1, DEBT_COLUMNS[1]),
+                            initial: debt.annual_rate * 100.0,
+                            on_value: move |v: f64| update(id, |d, v| d.annual_rate = v / 100.0, v),
+                            on_valid: move |valid| set_valid((id, 1), valid),
+                        }
+                        DebtCell {
+                            id: cell_id(id, 2),
+                            label: format!("Debt {} {}", row + 1, DEBT_COLUMNS[2]),
+                            initial: debt.minimum_payment,
+                            on_value: move |v| update(id, |d, v| d.minimum_payment = v, v),
+                            on_valid: move |valid| set_valid((id, 2), valid),
+                        }
+                        td {
+                            button {
+                                style: "padding: 2px 8px; border-radius: 4px;",
+                                disabled: debts().len() <= 1,
+                                onclick: move |_| {
+                                    debts.write().retain(|(row_id, _)| *row_id != id);
+                                    invalid_cells.write().retain(|(row_id, _)| *row_id != id);
+                                },
+                                "Remove"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        button {
+            style: "padding: 6px 12px; border-radius: 4px; margin-bottom: 15px;",
+            disabled: debts().len() >= MAX_DEBTS,
+            onclick: move |_| {
+                let id = next_id();
+                next_id.set(id + 1);
+                let debt = Debt {
+                    balance: 1_000.0,
+                    annual_rate: 0.2,
+                    minimum_payment: 25.0,
+                };
+                debts.write().push((id, debt));
+            },
+            "Add Debt"
+        }
+        TablePasteSection {
+            columns: "Balance ($), Rate (%), and Minimum ($)".to_string(),
+            placeholder: "8000\t24\t200".to_string(),
+            on_paste: move |text: String| {
+                let mut pasted = parse_pasted_table(&text, DEBT_COLUMNS, parse_cell);
+                let room = MAX_DEBTS.saturating_sub(debts.peek().len());
+                pasted.keep_first(room, &format!("the table holds at most {} debts", MAX_DEBTS));
+                let first_id = next_id();
+                next_id.set(first_id + pasted.rows.len() as u64);
+                let added = pasted.rows.iter().enumerate().map(|(i, row)| {
+                    let debt = Debt {
+                        balance: row[0],
+                        annual_rate: row[1] / 100.0,
+                        minimum_payment: row[2],
+                    };
+                    (first_id + i as u64, debt)
+                });
+                debts.write().extend(added);
+                pasted.report()
+            },
+        }
+
+        NumericInput {
+            label: "Monthly Budget ($):".to_string(),
+            placeholder: "Enter what you can pay toward all the debts each month (e.g., 900)".to_string(),
+            input_signal: budget_input,
+            value_signal: budget_signal,
+            valid_signal: budget_input_valid,
+            field_name: "Monthly budget".to_string(),
+            css_prefix: "budget".to_string(),
+            step: Some(50.0),
+            max_decimals: Some(2),
+        }
+
+        if payoffs.is_ok() {
+            div {
+                id: "DebtPayoffCalculation",
+                style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                "{summary}"
+            }
+            table { style: "color: #333; border-collapse: collapse; font-size: 14px; margin-bottom: 15px;",
+                thead {
+                    tr {
+                        th {}
+                        for (_ , name) in STRATEGIES.iter() {
+                            th { scope: "col", style: CELL_STYLE, {*name} }
+                        }
+                    }
+                }
+                tbody {
+                    for (label , cells , best) in comparison.into_iter() {
+                        tr { key: "{label}",
+                            th { scope: "row", style: "text-align: left; padding: 2px 8px;", "{label}" }
+                            for (index , cell) in cells.into_iter().enumerate() {
+                                td { style: if best == Some(index) { BEST_CELL_STYLE } else { CELL_STYLE },
+                                    "{cell}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        } else {
+            div {
+                id: "DebtPayoffCalculation",
+                style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                "{summary}"
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "debt_payoff" }
+        AssumptionsFooter { calculator: "debt_payoff" }
+        div { style: "font-size: 10px",
+            p {
+                "*The snowball pays the smallest balance first; the avalanche, the highest rate. Both pay every minimum each month and put the rest of the budget toward one debt at a time."
+            }
+            br {}
+            br {}
+        }
+    }
+}
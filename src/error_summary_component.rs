@@ -0,0 +1,68 @@
+use dioxus::prelude::*;
+
+/// Id of the summary box, focused when a submit finds problems
+pub const ERROR_SUMMARY_ID: &str = "error-summary";
+
+/// A field that failed validation, listed in the error summary
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    /// Id of the input to focus when the error is clicked
+    pub field_id: String,
+    pub message: String,
+}
+
+/// Moves keyboard focus to the element with this id, scrolling it into view
+pub fn focus_element(id: &str) {
+    if let Ok(id) = serde_json::to_string(id) {
+        let _ = document::eval(&format!(
+            "const element = document.getElementById({}); \
+             if (element) {{ element.scrollIntoView({{ block: 'center' }}); element.focus(); }}",
+            id
+        ));
+    }
+}
+
+/// Box listing every validation problem, each linked to the field it's about. It's announced to
+/// screen readers when it appears; callers move focus to it (`ERROR_SUMMARY_ID`) after a failed
+/// submit. Renders nothing when there are no errors.
+#[component]
+pub fn ErrorSummary(errors: Vec<FieldError>) -> Element {
+    if errors.is_empty() {
+        return rsx! {};
+    }
+    let count = if errors.len() == 1 {
+        "There is 1 problem".to_string()
+    } else {
+        format!("There are {} problems", errors.len())
+    };
+
+    rsx! {
+        div {
+            id: ERROR_SUMMARY_ID,
+            tabindex: "-1",
+            "role": "alert",
+            "aria-labelledby": "error-summary-title",
+            style: "color: #333; border: 3px solid #ff0000; border-radius: 4px; padding: 10px 15px; margin-bottom: 15px; max-width: 600px;",
+            div {
+                id: "error-summary-title",
+                style: "font-weight: bold; margin-bottom: 5px;",
+                "{count}"
+            }
+            ul { style: "margin: 0; padding-left: 20px;",
+                for error in errors.into_iter() {
+                    li {
+                        a {
+                            href: "#{error.field_id}",
+                            style: "color: #ff0000; font-weight: bold;",
+                            onclick: move |event| {
+                                event.prevent_default();
+                                focus_element(&error.field_id);
+                            },
+                            "{error.message}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::analytics::{Analytics, AnalyticsEvent};
+use crate::build_info::build_label;
+use dioxus::prelude::*;
+
+const ISSUES_URL: &str = "https://github.com/aaelony/dx_fintools_fs/issues/new";
+
+/// Percent-encodes a string for use in a URL query parameter
+fn url_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Link to a new GitHub issue prefilled with the calculator and the exact build
+fn report_problem_url(calculator: &str) -> String {
+    let title = format!("[{}] ", calculator);
+    let body = format!(
+        "Calculator: {}\nBuild: {}\n\nWhat happened?\n\nWhat did you expect?\n",
+        calculator,
+        build_label()
+    );
+    format!(
+        "{}?title={}&body={}",
+        ISSUES_URL,
+        url_encode(&title),
+        url_encode(&body)
+    )
+}
+
+/// "Was this helpful?" buttons and a "Report a problem" link, shown under each calculator.
+/// Votes are counted through analytics, so they are only kept with the user's consent.
+#[component]
+pub fn FeedbackWidget(calculator: &'static str) -> Element {
+    let analytics = try_use_context::<Signal<Analytics>>();
+    let mut voted = use_signal(|| false);
+    let mut vote = move |helpful: bool| {
+        if let Some(mut analytics) = analytics {
+            analytics.write().record(AnalyticsEvent::Feedback {
+                calculator,
+                helpful,
+            });
+        }
+        voted.set(true);
+    };
+
+    rsx! {
+        div { style: "display: flex; align-items: center; gap: 10px; color: #333; font-size: 12px; margin-bottom: 15px;",
+            if voted() {
+                span { "Thanks for the feedback!" }
+            } else {
+                span { "Was this helpful?" }
+                button {
+                    style: "padding: 2px 8px; border-radius: 4px;",
+                    onclick: move |_| vote(true),
+                    "Yes"
+                }
+                button {
+                    style: "padding: 2px 8px; border-radius: 4px;",
+                    onclick: move |_| vote(false),
+                    "No"
+                }
+            }
+            a {
+                href: report_problem_url(calculator),
+                target: "_blank",
+                rel: "noopener noreferrer",
+                style: "color: #1e222d;",
+                "Report a problem"
+            }
+        }
+    }
+}
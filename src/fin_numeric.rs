@@ -0,0 +1,162 @@
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, MathematicalOps, RoundingStrategy};
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Number type the financial math is generic over.
+///
+/// Implemented for `f64` (fast, approximate) and `Decimal` (exact base-10 arithmetic, so values
+/// like $0.10 and integer compounding periods carry no binary rounding error). The compounding,
+/// annuity, loan, and bond pricing functions take `T: FinNumeric`, but only the future value
+/// calculator offers an exact mode; the other calculators compute in f64.
+pub trait FinNumeric:
+    Copy
+    + Debug
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    /// Converts from `f64`, returning zero for values the type can't represent (NaN, infinity)
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn abs(self) -> Self;
+    /// `self` raised to `exponent`; exact when `exponent` is a whole number
+    fn powf(self, exponent: Self) -> Self;
+    fn ln(self) -> Self;
+    fn exp(self) -> Self;
+    /// Rounds half away from zero to `decimal_places`
+    fn round_dp(self, decimal_places: u32) -> Self;
+    /// Smallest difference treated as meaningful when comparing results
+    fn epsilon() -> Self;
+    /// Natural log of the largest representable value; results whose log exceeds this overflow
+    fn max_ln() -> Self;
+
+    /// Growth factor (1 + periodic_rate)^periods
+    fn compound_growth(periodic_rate: Self, periods: Self) -> Self {
+        (Self::one() + periodic_rate).powf(periods)
+    }
+
+    /// Natural log of (1 + self)
+    fn ln_1p(self) -> Self {
+        (Self::one() + self).ln()
+    }
+
+    fn from_i64(value: i64) -> Self {
+        Self::from_f64(value as f64)
+    }
+}
+
+impl FinNumeric for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+
+    fn powf(self, exponent: Self) -> Self {
+        f64::powf(self, exponent)
+    }
+
+    fn ln(self) -> Self {
+        f64::ln(self)
+    }
+
+    fn exp(self) -> Self {
+        f64::exp(self)
+    }
+
+    fn round_dp(self, decimal_places: u32) -> Self {
+        let scale = 10f64.powi(decimal_places as i32);
+        (self * scale).round() / scale
+    }
+
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
+
+    fn max_ln() -> Self {
+        f64::MAX.ln()
+    }
+
+    /// Computed in log space: `1.0 + periodic_rate` would round away tiny per-period rates
+    /// (e.g., daily compounding over centuries) before they are raised to the power
+    fn compound_growth(periodic_rate: Self, periods: Self) -> Self {
+        (periods * periodic_rate.ln_1p()).exp()
+    }
+
+    fn ln_1p(self) -> Self {
+        f64::ln_1p(self)
+    }
+}
+
+impl FinNumeric for Decimal {
+    fn zero() -> Self {
+        Decimal::ZERO
+    }
+
+    fn one() -> Self {
+        Decimal::ONE
+    }
+
+    fn from_f64(value: f64) -> Self {
+        <Decimal as FromPrimitive>::from_f64(value).unwrap_or(Decimal::ZERO)
+    }
+
+    fn to_f64(self) -> f64 {
+        ToPrimitive::to_f64(&self).unwrap_or(f64::NAN)
+    }
+
+    fn from_i64(value: i64) -> Self {
+        Decimal::from(value)
+    }
+
+    fn abs(self) -> Self {
+        Decimal::abs(&self)
+    }
+
+    fn powf(self, exponent: Self) -> Self {
+        match (exponent.fract().is_zero(), exponent.to_i64()) {
+            (true, Some(whole)) => MathematicalOps::powi(&self, whole),
+            _ => MathematicalOps::powd(&self, exponent),
+        }
+    }
+
+    fn ln(self) -> Self {
+        MathematicalOps::ln(&self)
+    }
+
+    fn exp(self) -> Self {
+        MathematicalOps::exp(&self)
+    }
+
+    fn round_dp(self, decimal_places: u32) -> Self {
+        self.round_dp_with_strategy(decimal_places, RoundingStrategy::MidpointAwayFromZero)
+    }
+
+    fn epsilon() -> Self {
+        Decimal::new(1, 20)
+    }
+
+    fn max_ln() -> Self {
+        MathematicalOps::ln(&Decimal::MAX)
+    }
+}
@@ -0,0 +1,154 @@
+use crate::compounding::{
+    checked_compute_fv, checked_compute_fv_with_contributions, compute_real_fv,
+    solve_rate_with_contributions, solve_years_with_contributions, Compounding, ComputeError,
+    ContributionTiming,
+};
+use crate::fin_numeric::FinNumeric;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Inputs to the future value calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FutureValueInputs {
+    pub principal: f64,
+    /// Target amount used when solving for rate or time
+    pub target_value: f64,
+    /// Annual interest rate as a decimal, e.g., 0.04 for 4%
+    pub annual_rate: f64,
+    pub years: f64,
+    pub compounding: Compounding,
+    /// Deposited every month on top of the principal; used in every calculator mode
+    #[serde(default)]
+    pub monthly_contribution: f64,
+    #[serde(default)]
+    pub contribution_timing: ContributionTiming,
+    /// Expected annual inflation as a decimal, used to restate the future value in today's
+    /// dollars; zero leaves it unchanged
+    #[serde(default)]
+    pub inflation_rate: f64,
+}
+
+/// Contributions are made monthly whatever the compounding period
+pub const CONTRIBUTIONS_PER_YEAR: f64 = 12.0;
+
+/// Number type a calculation runs in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum Precision {
+    /// 64-bit floating point, good to about 15 significant digits
+    #[default]
+    Float,
+    /// Decimal arithmetic, exact to the cent for whole numbers of periods at any principal
+    Exact,
+}
+
+/// Results of the future value calculator, one per calculator mode
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FutureValueOutputs {
+    /// Future value of the principal and contributions at the given rate and years
+    pub future_value: Result<f64, ComputeError>,
+    /// The future value in today's dollars, after the expected inflation
+    pub real_future_value: Result<f64, ComputeError>,
+    /// Annual rate needed to reach the target in the given years, with contributions
+    pub required_rate: f64,
+    /// Years needed to reach the target at the given rate, with contributions
+    pub required_years: f64,
+}
+
+/// Computes every result the future value calculator can show from its inputs
+pub fn compute_future_value(inputs: &FutureValueInputs) -> FutureValueOutputs {
+    let periods_per_year = inputs.compounding.periods_per_year();
+    let future_value = with_contributions(
+        checked_compute_fv(
+            inputs.principal,
+            inputs.annual_rate,
+            periods_per_year,
+            inputs.years,
+        ),
+        inputs,
+    );
+
+    FutureValueOutputs {
+        future_value,
+        real_future_value: future_value.and_then(|fv| in_todays_dollars(fv, inputs)),
+        required_rate: solve_rate_with_contributions(
+            inputs.principal,
+            inputs.monthly_contribution,
+            inputs.target_value,
+            periods_per_year,
+            CONTRIBUTIONS_PER_YEAR,
+            inputs.years,
+            inputs.contribution_timing,
+        ),
+        required_years: solve_years_with_contributions(
+            inputs.principal,
+            inputs.monthly_contribution,
+            inputs.target_value,
+            inputs.annual_rate,
+            periods_per_year,
+            CONTRIBUTIONS_PER_YEAR,
+            inputs.contribution_timing,
+        ),
+    }
+}
+
+/// Adds the monthly contributions to a checked lump-sum future value, checking that the
+/// contributions' own future value fits the number type too.
+fn with_contributions<T: FinNumeric>(
+    lump_sum: Result<T, ComputeError>,
+    inputs: &FutureValueInputs,
+) -> Result<T, ComputeError> {
+    let lump_sum = lump_sum?;
+    if inputs.monthly_contribution < 0.0 {
+        return Err(ComputeError::InvalidInput(
+            "Monthly contribution cannot be negative",
+        ));
+    }
+    if inputs.monthly_contribution == 0.0 {
+        return Ok(lump_sum);
+    }
+    checked_compute_fv_with_contributions(
+        T::from_f64(inputs.principal),
+        T::from_f64(inputs.monthly_contribution),
+        T::from_f64(inputs.annual_rate),
+        T::from_f64(inputs.compounding.periods_per_year()),
+        T::from_f64(CONTRIBUTIONS_PER_YEAR),
+        T::from_f64(inputs.years),
+        inputs.contribution_timing,
+    )
+}
+
+/// Restates a future value in today's dollars at the inputs' expected inflation
+pub fn in_todays_dollars<T: FinNumeric>(
+    future_value: T,
+    inputs: &FutureValueInputs,
+) -> Result<T, ComputeError> {
+    if inputs.inflation_rate <= -1.0 {
+        return Err(ComputeError::InvalidInput(
+            "Inflation must be greater than -100%",
+        ));
+    }
+    Ok(compute_real_fv(
+        future_value,
+        T::from_f64(inputs.inflation_rate),
+        T::from_f64(inputs.years),
+    ))
+}
+
+/// Future value computed in Decimal, for exact mode. Continuous compounding is left out: e^(rt)
+/// is irrational, so Decimal would be no more exact than f64.
+pub fn exact_future_value(inputs: &FutureValueInputs) -> Result<Decimal, ComputeError> {
+    if inputs.compounding.is_continuous() {
+        return Err(ComputeError::InvalidInput(
+            "Exact mode needs a whole number of compounding periods; continuous compounding has none",
+        ));
+    }
+    with_contributions(
+        checked_compute_fv(
+            Decimal::from_f64(inputs.principal),
+            Decimal::from_f64(inputs.annual_rate),
+            Decimal::from_f64(inputs.compounding.periods_per_year()),
+            Decimal::from_f64(inputs.years),
+        ),
+        inputs,
+    )
+}
@@ -1,54 +1,123 @@
-use crate::compounding::{compute_fv, Compounding};
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range, years_bucket};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::audit::{audit_future_value, audit_required_rate, audit_required_years};
+use crate::audit_component::AuditExportButton;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::compounding::{format_years_months, Compounding, ContributionTiming};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value::{
+    compute_future_value, exact_future_value, in_todays_dollars, FutureValueInputs, Precision,
+};
+use crate::glossary_component::Term;
+use crate::link::{use_link_receiver, CalculatorLink, SendLinkButton};
+use crate::math_component::MathFormula;
 use crate::numeric_input_component::NumericInput;
+use crate::saved_scenarios_component::SavedScenarios;
+use crate::scenario::Scenario;
+use crate::share_component::ShareLinkButton;
+use crate::snapshot_component::SnapshotHistory;
+use crate::tour_component::{use_tour_steps, TourStep};
 use dioxus::prelude::*;
 use dioxus_primitives::slider::{Slider, SliderRange, SliderThumb, SliderTrack, SliderValue};
 // use num::Float;
 use num_format::{Locale, ToFormattedString};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 
-const COMPOUNDING_OPTIONS: &[(Compounding, &str, &str)] = &[
+pub const COMPOUNDING_OPTIONS: &[(Compounding, &str, &str)] = &[
     (Compounding::Annual, "annual", "Annual"),
     (Compounding::Semiannually, "semiannual", "Semi-annually"),
     (Compounding::Quarterly, "quarterly", "Quarterly"),
     (Compounding::Monthly, "monthly", "Monthly"),
     (Compounding::Weekly, "weekly", "Weekly"),
     (Compounding::Daily, "daily", "Daily"),
+    (Compounding::Continuous, "continuous", "Continuous"),
 ];
 
-#[component]
-pub fn FutureValueUI() -> Element {
-    let mut current_value = use_signal(|| 0.03875);
-    let interest_rate = current_value();
-    let years_signal = use_signal(|| 7.0);
-    let mut periods_per_year_signal = use_signal(|| Compounding::Annual);
-    let principal_signal = use_signal(|| 1000.00 as f64);
-    let amount_input_valid = use_signal(|| true);
-    let principal_input = use_signal(|| "1000.00".to_string());
-    let years_input = use_signal(|| "7.0".to_string());
-    let years_input_valid = use_signal(|| true);
-    // let principal_amount = 10_000.0f64;
-    let principal_amount = principal_signal();
-    let years: f64 = years_signal();
-    let periods_per_year = periods_per_year_signal().periods_per_year();
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum CalculatorMode {
+    FutureValue,
+    SolveRate,
+    SolveYears,
+}
 
-    let fv = compute_fv(principal_amount, interest_rate, periods_per_year, years);
+const MODE_OPTIONS: &[(CalculatorMode, &str, &str)] = &[
+    (CalculatorMode::FutureValue, "future_value", "Future Value"),
+    (CalculatorMode::SolveRate, "solve_rate", "Solve for Rate"),
+    (CalculatorMode::SolveYears, "solve_years", "Solve for Time"),
+];
 
-    let fv_dollars = (fv as i64).to_formatted_string(&Locale::en);
-    let fv_cents = (fv * 100.0) as i64 % 100;
-    let fv = format!("{}.{:02}", fv_dollars, fv_cents);
+/// Everything the user has entered, as autosaved between visits
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct FutureValueSession {
+    mode: CalculatorMode,
+    inputs: FutureValueInputs,
+    #[serde(default)]
+    precision: Precision,
+}
 
-    let periods_string = periods_per_year_signal().to_string().to_ascii_lowercase();
+const DEFAULT_SESSION: FutureValueSession = FutureValueSession {
+    mode: CalculatorMode::FutureValue,
+    inputs: FutureValueInputs {
+        principal: 1000.00,
+        target_value: 2000.00,
+        annual_rate: 0.03875,
+        years: 7.0,
+        compounding: Compounding::Annual,
+        monthly_contribution: 0.0,
+        contribution_timing: ContributionTiming::End,
+        inflation_rate: 0.0,
+    },
+    precision: Precision::Float,
+};
 
-    rsx! {
-        document::Link { rel: "stylesheet", href: asset!("/assets/slider.css") }
-        hr {}
-        br {}
+const TIMING_OPTIONS: &[(ContributionTiming, &str, &str)] = &[
+    (ContributionTiming::End, "end", "End of month"),
+    (ContributionTiming::Begin, "begin", "Start of month"),
+];
 
+/// Formats a dollar amount with thousands separators and two decimal places, e.g., "-1,234.56"
+pub fn format_dollars(value: f64) -> String {
+    let magnitude = value.abs();
+    let dollars = magnitude as i64;
+    let cents = (magnitude * 100.0) as i64 % 100;
+    // No "-0.00" for small negatives that truncate to zero
+    let sign = if value < 0.0 && (dollars != 0 || cents != 0) {
+        "-"
+    } else {
+        ""
+    };
+    format!(
+        "{}{}.{:02}",
+        sign,
+        dollars.to_formatted_string(&Locale::en),
+        cents
+    )
+}
 
+/// Formats an exact dollar amount with thousands separators, truncated to the cent like
+/// `format_dollars`
+fn format_exact_dollars(value: Decimal) -> String {
+    let cents = format!("{:.2}", value.trunc_with_scale(2));
+    let (dollars, cents) = cents.split_once('.').unwrap_or((&cents, "00"));
+    let digits: Vec<char> = dollars.chars().collect();
+    let grouped = digits
+        .rchunks(3)
+        .rev()
+        .map(|group| group.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{}.{}", grouped, cents)
+}
 
-        // Compounding period dropdown
+/// Compounding period dropdown, shared by the calculators that take a compounding period
+#[component]
+pub fn CompoundingSelect(compounding: Signal<Compounding>) -> Element {
+    rsx! {
         div { style: "display: flex; align-items: center; margin-bottom: 15px;",
             label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
-                "Compounding Period:"
+                Term { term: "compounding", "Compounding" }
+                " Period:"
             }
             select {
                 style: {
@@ -60,21 +129,21 @@ pub fn FutureValueUI() -> Element {
                 },
                 onchange: move |event| {
                     let value = event.value();
-                    if let Some(&(compounding, _, _)) = COMPOUNDING_OPTIONS
+                    if let Some(&(option, _, _)) = COMPOUNDING_OPTIONS
                         .iter()
                         .find(|(_, value_str, _)| *value_str == value.as_str())
                     {
-                        periods_per_year_signal.set(compounding);
+                        compounding.set(option);
                     }
                 },
                 {
                     COMPOUNDING_OPTIONS
                         .iter()
-                        .map(|(compounding, value, display)| {
+                        .map(|(option, value, display)| {
                             rsx! {
                                 option {
                                     value: *value,
-                                    selected: matches!(periods_per_year_signal(), comp if comp == *compounding),
+                                    selected: compounding() == *option,
                                     style: if *value == "annual" { "background: gray; color: white;" } else { "" },
                                     {*display}
                                 }
@@ -82,9 +151,216 @@ pub fn FutureValueUI() -> Element {
                         })
                 }
             }
+        }
+    }
+}
+
+#[component]
+pub fn FutureValueUI() -> Element {
+    let mut mode_signal = use_signal(|| CalculatorMode::FutureValue);
+    let mut precision_signal = use_signal(|| Precision::Float);
+    let mut current_value = use_signal(|| 0.03875);
+    let interest_rate = current_value();
+    let mut years_signal = use_signal(|| 7.0);
+    let mut periods_per_year_signal = use_signal(|| Compounding::Annual);
+    let mut principal_signal = use_signal(|| 1000.00 as f64);
+    let amount_input_valid = use_signal(|| true);
+    let mut principal_input = use_signal(|| "1000.00".to_string());
+    let mut years_input = use_signal(|| "7.0".to_string());
+    let years_input_valid = use_signal(|| true);
+    let mut target_signal = use_signal(|| 2000.00 as f64);
+    let mut target_input = use_signal(|| "2000.00".to_string());
+    let target_input_valid = use_signal(|| true);
+    let mut contribution_signal = use_signal(|| 0.0 as f64);
+    let mut contribution_input = use_signal(|| "0".to_string());
+    let contribution_input_valid = use_signal(|| true);
+    let mut timing_signal = use_signal(|| ContributionTiming::End);
+    // Entered in percent
+    let mut inflation_signal = use_signal(|| 0.0 as f64);
+    let mut inflation_input = use_signal(|| "0".to_string());
+    let inflation_input_valid = use_signal(|| true);
+    let apply_link = use_callback(move |link: CalculatorLink| match link {
+        CalculatorLink::AnnualRate(rate) => {
+            mode_signal.set(CalculatorMode::FutureValue);
+            current_value.set(rate);
+            true
+        }
+        _ => false,
+    });
+    let received_link = use_link_receiver(apply_link);
+    let restore_inputs = use_callback(move |inputs: FutureValueInputs| {
+        current_value.set(inputs.annual_rate);
+        periods_per_year_signal.set(inputs.compounding);
+        principal_signal.set(inputs.principal);
+        principal_input.set(format!("{:.2}", inputs.principal));
+        years_signal.set(inputs.years);
+        years_input.set(format!("{:.1}", inputs.years));
+        target_signal.set(inputs.target_value);
+        target_input.set(format!("{:.2}", inputs.target_value));
+        contribution_signal.set(inputs.monthly_contribution);
+        contribution_input.set(format!("{}", inputs.monthly_contribution));
+        timing_signal.set(inputs.contribution_timing);
+        inflation_signal.set(inputs.inflation_rate * 100.0);
+        inflation_input.set(format!("{}", inputs.inflation_rate * 100.0));
+    });
+    let start_fresh = use_autosave(
+        "future_value",
+        DEFAULT_SESSION,
+        move || FutureValueSession {
+            mode: mode_signal(),
+            precision: precision_signal(),
+            inputs: FutureValueInputs {
+                principal: principal_signal(),
+                target_value: target_signal(),
+                annual_rate: current_value(),
+                years: years_signal(),
+                compounding: periods_per_year_signal(),
+                monthly_contribution: contribution_signal(),
+                contribution_timing: timing_signal(),
+                inflation_rate: inflation_signal() / 100.0,
+            },
+        },
+        use_callback(move |session: FutureValueSession| {
+            mode_signal.set(session.mode);
+            precision_signal.set(session.precision);
+            restore_inputs.call(session.inputs);
+            if let Some(link) = received_link() {
+                apply_link.call(link);
+            }
+        }),
+    );
+    use_track_calculator("future_value");
+    use_track_input_range("future_value", "principal", principal_signal, amount_bucket);
+    use_track_input_range("future_value", "years", years_signal, years_bucket);
+    use_tour_steps("future_value", || {
+        let mut steps = vec![TourStep {
+            target: "#principal-input",
+            title: "Enter your numbers",
+            body: "Results update as you type. Invalid entries are flagged next to the field, \
+                   and Start Fresh brings back the defaults.",
+        }];
+        // Sharing needs the server
+        if cfg!(feature = "fullstack") {
+            steps.push(TourStep {
+                target: "#ShareLinkButton",
+                title: "Share a read-only link",
+                body: "Send someone these inputs and results. They can copy them into their own \
+                       scenarios, and the link expires.",
+            });
+        }
+        steps
+    });
+    // let principal_amount = 10_000.0f64;
+    let principal_amount = principal_signal();
+    let years: f64 = years_signal();
+
+    let target_value = target_signal();
+    let solving_rate = mode_signal() == CalculatorMode::SolveRate;
+    let solving_years = mode_signal() == CalculatorMode::SolveYears;
+
+    let inputs = FutureValueInputs {
+        principal: principal_amount,
+        target_value,
+        annual_rate: interest_rate,
+        years,
+        compounding: periods_per_year_signal(),
+        monthly_contribution: contribution_signal(),
+        contribution_timing: timing_signal(),
+        inflation_rate: inflation_signal() / 100.0,
+    };
+    let outputs = compute_future_value(&inputs);
+    let scenario = Scenario::new("future_value", "Future value projection", &inputs).ok();
+    let send_fv = outputs
+        .future_value
+        .ok()
+        .map(CalculatorLink::RetirementBalance);
+    let exact = precision_signal() == Precision::Exact;
+    let fv = if exact {
+        exact_future_value(&inputs).map(format_exact_dollars)
+    } else {
+        outputs.future_value.map(format_dollars)
+    };
+    let real_fv = if exact {
+        exact_future_value(&inputs)
+            .and_then(|fv| in_todays_dollars(fv, &inputs))
+            .map(format_exact_dollars)
+    } else {
+        outputs.real_future_value.map(format_dollars)
+    };
+    let inflation_percent = inflation_signal();
+    let precision_note = if exact && !solving_rate && !solving_years {
+        "Computed exactly in decimal arithmetic"
+    } else if exact {
+        "Computed in 64-bit floating point (exact mode applies to the future value only)"
+    } else {
+        "Computed in 64-bit floating point"
+    };
+    let solved_rate = outputs.required_rate;
+    let solved_years = if target_value <= principal_amount {
+        "already reached".to_string()
+    } else if outputs.required_years.is_finite() {
+        format_years_months(outputs.required_years)
+    } else {
+        "never reached at this rate".to_string()
+    };
+    let target = format_dollars(target_value);
+    let audit_record = match mode_signal() {
+        CalculatorMode::FutureValue => audit_future_value(&inputs),
+        CalculatorMode::SolveRate => audit_required_rate(&inputs),
+        CalculatorMode::SolveYears => audit_required_years(&inputs),
+    };
+    let contribution_note = if contribution_signal() > 0.0 {
+        let when = match timing_signal() {
+            ContributionTiming::End => "end",
+            ContributionTiming::Begin => "start",
+        };
+        format!(
+            ", plus ${} deposited at the {} of each month",
+            format_dollars(contribution_signal()),
+            when
+        )
+    } else {
+        String::new()
+    };
+
+    let periods_string = periods_per_year_signal().to_string().to_ascii_lowercase();
 
+    rsx! {
+        document::Link { rel: "stylesheet", href: asset!("/assets/slider.css") }
+        hr {}
+        br {}
+
+        // Calculator mode dropdown
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Calculate:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(mode, _, _)) = MODE_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        mode_signal.set(mode);
+                    }
+                },
+                {
+                    MODE_OPTIONS
+                        .iter()
+                        .map(|(mode, value, display)| {
+                            rsx! {
+                                option { value: *value, selected: mode_signal() == *mode, {*display} }
+                            }
+                        })
+                }
+            }
         }
 
+        // Compounding period dropdown
+        CompoundingSelect { compounding: periods_per_year_signal }
+
         // -------------------------------------------------------------------
         // Input Principal
         NumericInput {
@@ -95,23 +371,100 @@ pub fn FutureValueUI() -> Element {
             valid_signal: amount_input_valid,
             field_name: "Principal amount".to_string(),
             css_prefix: "principal".to_string(),
+            step: Some(100.0),
+            max_decimals: Some(2),
         }
 
         // -------------------------------------------------------------------
         // Input Years
+        if !solving_years {
+            NumericInput {
+                label: "Number of Years:".to_string(),
+                placeholder: "Enter number of years (e.g. 5.0)".to_string(),
+                input_signal: years_input,
+                value_signal: years_signal,
+                valid_signal: years_input_valid,
+                field_name: "Number of years".to_string(),
+                css_prefix: "years".to_string(),
+                step: Some(1.0),
+            }
+        }
+
+        // -------------------------------------------------------------------
+        // Input Target Future Value
+        if solving_rate || solving_years {
+            NumericInput {
+                label: "Target Future Value ($):".to_string(),
+                placeholder: "Enter target future value (e.g., 20000.00)".to_string(),
+                input_signal: target_input,
+                value_signal: target_signal,
+                valid_signal: target_input_valid,
+                field_name: "Target future value".to_string(),
+                css_prefix: "target".to_string(),
+                step: Some(100.0),
+                max_decimals: Some(2),
+            }
+        }
+
+        // -------------------------------------------------------------------
+        // Input Monthly Contribution
         NumericInput {
-            label: "Number of Years:".to_string(),
-            placeholder: "Enter number of years (e.g. 5.0)".to_string(),
-            input_signal: years_input,
-            value_signal: years_signal,
-            valid_signal: years_input_valid,
-            field_name: "Number of years".to_string(),
-            css_prefix: "years".to_string(),
+            label: "Monthly Contribution ($):".to_string(),
+            placeholder: "Enter a recurring monthly deposit (e.g., 100.00)".to_string(),
+            input_signal: contribution_input,
+            value_signal: contribution_signal,
+            valid_signal: contribution_input_valid,
+            field_name: "Monthly contribution".to_string(),
+            css_prefix: "contribution".to_string(),
+            step: Some(10.0),
+            max_decimals: Some(2),
+            allow_negative: true,
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Deposit At:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(timing, _, _)) = TIMING_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        timing_signal.set(timing);
+                    }
+                },
+                for (timing , value , display) in TIMING_OPTIONS.iter() {
+                    option { value: *value, selected: timing_signal() == *timing, {*display} }
+                }
+            }
         }
 
-        // Input slider for interest rate
+        // -------------------------------------------------------------------
+        // Input Expected Inflation
+        if !solving_rate && !solving_years {
+            NumericInput {
+                label: "Expected Inflation (%):".to_string(),
+                placeholder: "Optional, to show today's dollars (e.g., 2.5)".to_string(),
+                input_signal: inflation_input,
+                value_signal: inflation_signal,
+                valid_signal: inflation_input_valid,
+                field_name: "Expected inflation".to_string(),
+                css_prefix: "inflation".to_string(),
+                step: Some(0.25),
+                max_decimals: Some(3),
+                allow_negative: true,
+            }
+        }
+
+        // Input slider for interest rate (an output indicator when solving for rate)
         div { style: "color: #333; font-weight: bold;",
-            "Interest Rate:"
+            if solving_rate {
+                "Required Interest Rate:"
+            } else {
+                "Interest Rate:"
+            }
             Slider {
                 class: "slider",
                 label: "Interest Rate Slider",
@@ -119,6 +472,12 @@ pub fn FutureValueUI() -> Element {
                 min: 0.0,
                 max: 50.0,
                 step: 0.01,
+                disabled: solving_rate,
+                value: Some(
+                    SliderValue::Single(
+                        if solving_rate { (solved_rate * 100.0).clamp(0.0, 50.0) } else { interest_rate * 100.0 },
+                    ),
+                ),
                 default_value: SliderValue::Single(3.875),
                 on_value_change: move |value: SliderValue| {
                     let SliderValue::Single(v) = value;
@@ -131,16 +490,196 @@ pub fn FutureValueUI() -> Element {
             }
         }
         br {}
-        div {
-            id: "FutureValueCalculationConfig",
-            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
-            "The future value of {principal_amount} at {interest_rate * 100.0:.3}% for {years} years with compounding {periods_string}: "
+        if solving_years {
+            div {
+                id: "SolveYearsCalculationConfig",
+                style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                "The time needed to grow {principal_amount}{contribution_note} into ${target} at {interest_rate * 100.0:.3}% with compounding {periods_string}: "
+            }
+            div {
+                id: "SolveYearsCalculation",
+                style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                " {solved_years}*"
+            }
+        } else if solving_rate {
+            div {
+                id: "SolveRateCalculationConfig",
+                style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                "The annual interest rate needed to grow {principal_amount}{contribution_note} into ${target} in {years} years with compounding {periods_string}: "
+            }
+            div {
+                id: "SolveRateCalculation",
+                style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                " {solved_rate * 100.0:.3}%*"
+            }
+            if solved_rate.is_finite() {
+                SendLinkButton { link: CalculatorLink::AnnualRate(solved_rate) }
+            }
+        } else {
+            div {
+                id: "FutureValueCalculationConfig",
+                style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                "The future value of {principal_amount} at {interest_rate * 100.0:.3}% for {years} years with compounding {periods_string}{contribution_note}: "
+            }
+            {
+                match fv {
+                    Ok(fv) => rsx! {
+                        div { style: "display: flex; gap: 30px; margin-bottom: 15px;",
+                            div {
+                                div { style: "font-size: 12px; color: #333;", "Nominal" }
+                                div {
+                                    id: "FutureValueCalculation",
+                                    style: "font-size: 16px; font-weight: bold;",
+                                    " ${fv}*"
+                                }
+                            }
+                            if inflation_percent != 0.0 {
+                                div {
+                                    div { style: "font-size: 12px; color: #333;",
+                                        "In today's dollars ({inflation_percent}% inflation)"
+                                    }
+                                    match real_fv {
+                                        Ok(real_fv) => rsx! {
+                                            div {
+                                                id: "RealFutureValueCalculation",
+                                                style: "font-size: 16px; font-weight: bold;",
+                                                " ${real_fv}*"
+                                            }
+                                        },
+                                        Err(error) => rsx! {
+                                            div {
+                                                id: "RealFutureValueCalculation",
+                                                style: "font-size: 14px; color: #ff0000;",
+                                                "{error}"
+                                            }
+                                        },
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Err(error) => rsx! {
+                        div {
+                            id: "FutureValueCalculation",
+                            style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                            "{error}"
+                        }
+                    },
+                }
+            }
+            if let Some(link) = send_fv.filter(|_| cfg!(feature = "simulation")) {
+                SendLinkButton { link }
+            }
+        }
+        // Precision indicator and exact mode toggle
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Exact Mode:"
+            }
+            input {
+                r#type: "checkbox",
+                checked: exact,
+                onchange: move |event| {
+                    precision_signal
+                        .set(if event.checked() { Precision::Exact } else { Precision::Float });
+                },
+            }
+            span { id: "PrecisionIndicator", style: "margin-left: 10px; color: #333; font-size: 14px;",
+                {precision_note}
+            }
         }
-        div {
-            id: "FutureValueCalculation",
-            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
-            " ${fv}*"
+        // Show the math
+        details { style: "color: #333; margin-bottom: 15px;",
+            summary { style: "cursor: pointer; font-weight: bold;", "Show the math" }
+            div { style: "margin-top: 10px;",
+                if solving_years && contribution_signal() > 0.0 {
+                    MathFormula {
+                        tex: r"t = \frac{\ln\left(\frac{FV + PMT/i}{P + PMT/i}\right)}{12 \ln(1 + i)}".to_string(),
+                        display: true,
+                    }
+                    MathFormula {
+                        tex: r"i = \left(1 + \frac{r}{n}\right)^{n/12} - 1".to_string(),
+                        display: true,
+                    }
+                    p { style: "font-size: 12px;",
+                        "PMT = monthly contribution, i = rate per month. Deposits at the start of each month count as PMT(1 + i)."
+                    }
+                } else if solving_years {
+                    MathFormula {
+                        tex: r"t = \frac{\ln(FV / P)}{n \ln\left(1 + \frac{r}{n}\right)}".to_string(),
+                        display: true,
+                    }
+                } else if solving_rate && contribution_signal() > 0.0 {
+                    MathFormula {
+                        tex: r"P\left(1 + \frac{r}{n}\right)^{nt} + PMT\,\frac{(1 + i)^{12t} - 1}{i} = FV".to_string(),
+                        display: true,
+                    }
+                    p { style: "font-size: 12px;",
+                        "PMT = monthly contribution, i = rate per month. There is no formula for r once deposits are added, so the rate is found by repeatedly halving the range of rates that could reach the target."
+                    }
+                } else if solving_rate {
+                    MathFormula {
+                        tex: r"r = n\left(\left(\frac{FV}{P}\right)^{1 / (nt)} - 1\right)".to_string(),
+                        display: true,
+                    }
+                } else if contribution_signal() > 0.0 {
+                    MathFormula {
+                        tex: r"FV = P\left(1 + \frac{r}{n}\right)^{nt} + PMT\,\frac{(1 + i)^{12t} - 1}{i}".to_string(),
+                        display: true,
+                    }
+                    MathFormula {
+                        tex: r"i = \left(1 + \frac{r}{n}\right)^{n/12} - 1".to_string(),
+                        display: true,
+                    }
+                    p { style: "font-size: 12px;",
+                        "PMT = monthly contribution, i = rate per month. Deposits at the start of each month are multiplied by (1 + i) once more."
+                    }
+                } else {
+                    MathFormula {
+                        tex: r"FV = P\left(1 + \frac{r}{n}\right)^{nt}".to_string(),
+                        display: true,
+                    }
+                }
+                p { style: "font-size: 12px;",
+                    "P = principal, FV = future value, r = annual rate, n = compounding periods per year, t = years"
+                }
+                if periods_per_year_signal().is_continuous() {
+                    MathFormula {
+                        tex: r"\lim_{n \to \infty}\left(1 + \frac{r}{n}\right)^{nt} = e^{rt}".to_string(),
+                        display: true,
+                    }
+                    p { style: "font-size: 12px;",
+                        "Compounding continuously lets n grow without bound, so (1 + r/n)^(nt) above becomes e^(rt), and the monthly rate i becomes e^(r/12) - 1."
+                    }
+                }
+                if !solving_rate && !solving_years && inflation_percent != 0.0 {
+                    MathFormula {
+                        tex: r"FV_{real} = \frac{FV}{(1 + \pi)^{t}}".to_string(),
+                        display: true,
+                    }
+                    p { style: "font-size: 12px;",
+                        "π = expected annual inflation. Dividing by the growth in prices restates the future value in today's dollars."
+                    }
+                }
+            }
+        }
+
+        AuditExportButton { record: audit_record }
+        if let Some(scenario) = scenario {
+            SavedScenarios {
+                scenario: scenario.clone(),
+                on_load: move |scenario: Scenario| {
+                    if let Ok(inputs) = scenario.inputs::<FutureValueInputs>() {
+                        restore_inputs.call(inputs);
+                    }
+                },
+            }
+            SnapshotHistory { scenario: scenario.clone() }
+            ShareLinkButton { scenario }
         }
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "future_value" }
+        AssumptionsFooter { calculator: "future_value" }
         div { style: "font-size: 10px",
             p { "*please verify all calculations before relying on any features fordecision-making." }
 
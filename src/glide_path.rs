@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+/// A point on a glide path: the expected annual return in effect at a given age.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GlidePoint {
+    pub age: f64,
+    /// Expected annual return, e.g., 0.08 for 8%
+    pub annual_return: f64,
+}
+
+/// Expected-return assumption that changes with age, defined by editable breakpoints.
+///
+/// Between breakpoints the return is interpolated linearly; before the first and after the last
+/// breakpoint it is held flat. A single breakpoint is a constant return.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GlidePath {
+    points: Vec<GlidePoint>,
+}
+
+impl GlidePath {
+    /// Creates a glide path from breakpoints in any order
+    pub fn new(mut points: Vec<GlidePoint>) -> Self {
+        points.sort_by(|a, b| a.age.total_cmp(&b.age));
+        GlidePath { points }
+    }
+
+    /// A constant expected return at every age
+    pub fn constant(annual_return: f64) -> Self {
+        GlidePath::new(vec![GlidePoint {
+            age: 0.0,
+            annual_return,
+        }])
+    }
+
+    /// A straight line from `start_return` at `start_age` to `end_return` at `end_age`,
+    /// e.g., 8% at 40 down to 5% at 65
+    pub fn linear(start_age: f64, start_return: f64, end_age: f64, end_return: f64) -> Self {
+        GlidePath::new(vec![
+            GlidePoint {
+                age: start_age,
+                annual_return: start_return,
+            },
+            GlidePoint {
+                age: end_age,
+                annual_return: end_return,
+            },
+        ])
+    }
+
+    pub fn points(&self) -> &[GlidePoint] {
+        &self.points
+    }
+
+    /// Adds a breakpoint, replacing any existing breakpoint at the same age
+    pub fn set_point(&mut self, point: GlidePoint) {
+        self.points.retain(|p| p.age != point.age);
+        self.points.push(point);
+        self.points.sort_by(|a, b| a.age.total_cmp(&b.age));
+    }
+
+    /// Removes the breakpoint at `index`, keeping at least one breakpoint
+    pub fn remove_point(&mut self, index: usize) {
+        if self.points.len() > 1 && index < self.points.len() {
+            self.points.remove(index);
+        }
+    }
+
+    /// Expected annual return at `age`
+    pub fn return_at(&self, age: f64) -> f64 {
+        let (first, last) = match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return 0.0,
+        };
+        if age <= first.age {
+            return first.annual_return;
+        }
+        if age >= last.age {
+            return last.annual_return;
+        }
+
+        self.points
+            .windows(2)
+            .find(|pair| age <= pair[1].age)
+            .map(|pair| {
+                let (a, b) = (pair[0], pair[1]);
+                let fraction = (age - a.age) / (b.age - a.age);
+                a.annual_return + fraction * (b.annual_return - a.annual_return)
+            })
+            .unwrap_or(last.annual_return)
+    }
+
+    /// Expected annual return for each whole year of age from `start_age` up to (excluding) `end_age`,
+    /// sampled at the start of each year — the series to plot on a projection chart
+    pub fn yearly_returns(&self, start_age: u32, end_age: u32) -> Vec<(u32, f64)> {
+        (start_age..end_age)
+            .map(|age| (age, self.return_at(age as f64)))
+            .collect()
+    }
+}
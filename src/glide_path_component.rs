@@ -0,0 +1,122 @@
+use crate::glide_path::{GlidePath, GlidePoint};
+use crate::numeric_input_component::NumericInput;
+use dioxus::prelude::*;
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+
+/// How far the return glides down by retirement when a glide path is first turned on
+const DEFAULT_GLIDE: f64 = 0.03;
+
+/// Checkbox that lets the expected return follow a glide path instead of holding constant, and
+/// the path's breakpoints: each is an age and the return in effect there. Turning it on starts
+/// a straight line from `annual_return` at `start_age` to 3 points lower at `end_age`.
+#[component]
+pub fn GlidePathEditor(
+    glide_path: Signal<Option<GlidePath>>,
+    start_age: f64,
+    end_age: f64,
+    annual_return: f64,
+) -> Element {
+    let age_signal = use_signal(|| 50.0);
+    let age_input = use_signal(|| "50".to_string());
+    let age_input_valid = use_signal(|| true);
+    let return_signal = use_signal(|| 6.0);
+    let return_input = use_signal(|| "6.0".to_string());
+    let return_input_valid = use_signal(|| true);
+
+    rsx! {
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Glide Path:"
+            }
+            input {
+                r#type: "checkbox",
+                "aria-label": "Let the expected return change with age",
+                checked: glide_path().is_some(),
+                onchange: move |event| {
+                    glide_path
+                        .set(
+                            event
+                                .checked()
+                                .then(|| GlidePath::linear(
+                                    start_age,
+                                    annual_return,
+                                    end_age,
+                                    annual_return - DEFAULT_GLIDE,
+                                )),
+                        )
+                },
+            }
+        }
+        if let Some(path) = glide_path() {
+            div { style: "margin-bottom: 15px;",
+                p { style: "font-size: 12px; color: #333;",
+                    "The return moves in a straight line between breakpoints and holds flat before the first and after the last, in place of the expected return above."
+                }
+                table { style: "color: #333; border-collapse: collapse; font-size: 13px; margin-bottom: 10px;",
+                    thead {
+                        tr {
+                            th { scope: "col", style: CELL_STYLE, "Age" }
+                            th { scope: "col", style: CELL_STYLE, "Return" }
+                            th { scope: "col", style: CELL_STYLE }
+                        }
+                    }
+                    tbody {
+                        for (index , point) in path.points().iter().enumerate() {
+                            tr { key: "{point.age}",
+                                th { scope: "row", style: CELL_STYLE, "{point.age}" }
+                                td { style: CELL_STYLE, "{point.annual_return * 100.0:.2}%" }
+                                td {
+                                    button {
+                                        style: "padding: 2px 8px; border-radius: 4px;",
+                                        disabled: path.points().len() <= 1,
+                                        onclick: move |_| {
+                                            if let Some(path) = glide_path.write().as_mut() {
+                                                path.remove_point(index);
+                                            }
+                                        },
+                                        "Remove"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                NumericInput {
+                    label: "Breakpoint Age:".to_string(),
+                    placeholder: "Enter an age on the path (e.g., 50)".to_string(),
+                    input_signal: age_input,
+                    value_signal: age_signal,
+                    valid_signal: age_input_valid,
+                    field_name: "Breakpoint age".to_string(),
+                    css_prefix: "glide-age".to_string(),
+                    step: Some(1.0),
+                }
+                NumericInput {
+                    label: "Return at Age (%):".to_string(),
+                    placeholder: "Enter the return from that age (e.g., 6.0)".to_string(),
+                    input_signal: return_input,
+                    value_signal: return_signal,
+                    valid_signal: return_input_valid,
+                    field_name: "Return at age".to_string(),
+                    css_prefix: "glide-return".to_string(),
+                    allow_negative: true,
+                    step: Some(0.25),
+                }
+                button {
+                    style: "padding: 6px 12px; border-radius: 4px;",
+                    disabled: !age_input_valid() || !return_input_valid(),
+                    onclick: move |_| {
+                        if let Some(path) = glide_path.write().as_mut() {
+                            path.set_point(GlidePoint {
+                                age: age_signal(),
+                                annual_return: return_signal() / 100.0,
+                            });
+                        }
+                    },
+                    "Set Breakpoint"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+/// A financial term and its plain-language definition
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlossaryEntry {
+    pub term: &'static str,
+    /// Anchor on the glossary page, e.g., "future-value"
+    pub slug: &'static str,
+    pub definition: &'static str,
+}
+
+/// All glossary terms, alphabetical
+pub const GLOSSARY: &[GlossaryEntry] = &[
+    GlossaryEntry {
+        term: "Amortization",
+        slug: "amortization",
+        definition: "Paying off a loan with regular payments that cover that period's interest plus part of the balance.",
+    },
+    GlossaryEntry {
+        term: "Annuity",
+        slug: "annuity",
+        definition: "A series of equal payments made at regular intervals, such as monthly deposits or loan payments.",
+    },
+    GlossaryEntry {
+        term: "APR",
+        slug: "apr",
+        definition: "Annual percentage rate: the stated yearly interest rate, before accounting for compounding within the year.",
+    },
+    GlossaryEntry {
+        term: "APY",
+        slug: "apy",
+        definition: "Annual percentage yield: the rate actually earned in a year once compounding is included.",
+    },
+    GlossaryEntry {
+        term: "Catch-up contribution",
+        slug: "catch-up-contribution",
+        definition: "Extra retirement-plan contributions the IRS allows once you turn 50.",
+    },
+    GlossaryEntry {
+        term: "Compounding",
+        slug: "compounding",
+        definition: "Adding earned interest to the balance so that future interest is earned on the interest too.",
+    },
+    GlossaryEntry {
+        term: "Correlation",
+        slug: "correlation",
+        definition: "How closely two investments move together, from -1 (opposite) through 0 (unrelated) to 1 (in lockstep).",
+    },
+    GlossaryEntry {
+        term: "Future value",
+        slug: "future-value",
+        definition: "What an amount invested today will be worth at a later date, given a rate of return.",
+    },
+    GlossaryEntry {
+        term: "Glide path",
+        slug: "glide-path",
+        definition: "A plan for shifting to safer, lower-return investments as retirement approaches.",
+    },
+    GlossaryEntry {
+        term: "Inflation",
+        slug: "inflation",
+        definition: "The general rise in prices over time, which reduces what a dollar can buy.",
+    },
+    GlossaryEntry {
+        term: "Monte Carlo simulation",
+        slug: "monte-carlo-simulation",
+        definition: "Running a plan through thousands of randomly generated market scenarios to see the range of outcomes.",
+    },
+    GlossaryEntry {
+        term: "Percentile",
+        slug: "percentile",
+        definition: "The value below which a given share of outcomes fall; the 10th percentile is worse than 90% of outcomes.",
+    },
+    GlossaryEntry {
+        term: "Present value",
+        slug: "present-value",
+        definition: "What a future amount is worth today, after discounting it at a rate of return.",
+    },
+    GlossaryEntry {
+        term: "Principal",
+        slug: "principal",
+        definition: "The original amount invested or borrowed, before any interest.",
+    },
+    GlossaryEntry {
+        term: "Roth account",
+        slug: "roth-account",
+        definition: "A retirement account funded with after-tax money whose qualified withdrawals are tax-free.",
+    },
+    GlossaryEntry {
+        term: "Sequence of returns risk",
+        slug: "sequence-of-returns-risk",
+        definition: "The danger that poor returns early in retirement, while withdrawing, permanently shrink a portfolio.",
+    },
+    GlossaryEntry {
+        term: "Traditional account",
+        slug: "traditional-account",
+        definition: "A retirement account funded with pre-tax money; withdrawals are taxed as ordinary income.",
+    },
+    GlossaryEntry {
+        term: "Volatility",
+        slug: "volatility",
+        definition: "How much returns swing from year to year, usually measured as their standard deviation.",
+    },
+];
+
+/// Finds a glossary entry by term (case-insensitive) or slug
+pub fn lookup(term: &str) -> Option<&'static GlossaryEntry> {
+    GLOSSARY
+        .iter()
+        .find(|entry| entry.term.eq_ignore_ascii_case(term) || entry.slug == term)
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Replaces `[[term]]` (or `[[term|shown text]]`) in Markdown with an inline glossary link
+/// carrying the definition as a hover popover. Unknown terms are left as plain text. Links are
+/// relative to a `/blog/:slug` page so they work under the GitHub Pages base path.
+pub fn link_glossary_terms(markdown: &str) -> String {
+    let mut output = String::with_capacity(markdown.len());
+    let mut rest = markdown;
+
+    while let Some(start) = rest.find("[[") {
+        let Some(length) = rest[start + 2..].find("]]") else {
+            break;
+        };
+        output.push_str(&rest[..start]);
+        let inner = &rest[start + 2..start + 2 + length];
+        let (term, shown) = inner.split_once('|').unwrap_or((inner, inner));
+        match lookup(term.trim()) {
+            Some(entry) => output.push_str(&format!(
+                "<a class=\"glossary-term\" href=\"../glossary#{}\">{}<span class=\"glossary-definition\">{}</span></a>",
+                entry.slug,
+                escape_html(shown.trim()),
+                escape_html(entry.definition)
+            )),
+            None => output.push_str(shown),
+        }
+        rest = &rest[start + 2 + length + 2..];
+    }
+    output.push_str(rest);
+    output
+}
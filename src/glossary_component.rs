@@ -0,0 +1,42 @@
+use crate::glossary::{lookup, GLOSSARY};
+use crate::Route;
+use dioxus::prelude::*;
+
+/// A glossary word: dotted underline, definition popover on hover or focus, and a link to the
+/// glossary page. `term` is the glossary term or slug; `children` is the text shown.
+#[component]
+pub fn Term(term: String, children: Element) -> Element {
+    let Some(entry) = lookup(&term) else {
+        return rsx! {
+            {children}
+        };
+    };
+
+    rsx! {
+        Link {
+            class: "glossary-term",
+            to: Route::GlossaryUI {},
+            {children}
+            span { class: "glossary-definition", {entry.definition} }
+        }
+    }
+}
+
+/// Glossary index page
+#[component]
+pub fn GlossaryUI() -> Element {
+    rsx! {
+        div { id: "blog",
+            h1 { "Glossary" }
+            dl {
+                for entry in GLOSSARY.iter() {
+                    dt { id: entry.slug, style: "font-weight: bold; margin-top: 10px;",
+                        {entry.term}
+                    }
+                    dd { {entry.definition} }
+                }
+            }
+            Link { to: Route::BlogIndex {}, "Blog" }
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::tax::FilingStatus;
+use serde::{Deserialize, Serialize};
+
+/// Age Medicare starts
+pub const MEDICARE_AGE: f64 = 65.0;
+
+/// Monthly Medicare premiums per person above an income level. Income above `single_floor`
+/// (or `joint_floor` when filing jointly) and up to the next tier's floor falls in this tier.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IrmaaTier {
+    pub single_floor: f64,
+    pub joint_floor: f64,
+    /// Part B premium, the standard premium plus any income-related adjustment (IRMAA)
+    pub part_b: f64,
+    /// Part D income-related adjustment, paid on top of the drug plan's own premium
+    pub part_d: f64,
+}
+
+/// Medicare Part B premiums and IRMAA tiers for a single year
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MedicarePremiums {
+    pub year: i32,
+    /// Lowest income first; the first tier is the standard premium
+    pub tiers: &'static [IrmaaTier],
+}
+
+const fn tier(single_floor: f64, joint_floor: f64, part_b: f64, part_d: f64) -> IrmaaTier {
+    IrmaaTier {
+        single_floor,
+        joint_floor,
+        part_b,
+        part_d,
+    }
+}
+
+/// Medicare premiums by year, oldest first
+pub const MEDICARE_PREMIUMS: &[MedicarePremiums] = &[
+    MedicarePremiums {
+        year: 2025,
+        tiers: &[
+            tier(0.0, 0.0, 185.00, 0.0),
+            tier(106_000.0, 212_000.0, 259.00, 13.70),
+            tier(133_000.0, 266_000.0, 370.00, 35.30),
+            tier(167_000.0, 334_000.0, 480.90, 57.00),
+            tier(200_000.0, 400_000.0, 591.90, 78.60),
+            tier(500_000.0, 750_000.0, 628.90, 85.80),
+        ],
+    },
+    MedicarePremiums {
+        year: 2026,
+        tiers: &[
+            tier(0.0, 0.0, 202.90, 0.0),
+            tier(109_000.0, 218_000.0, 284.10, 14.50),
+            tier(137_000.0, 274_000.0, 405.80, 37.50),
+            tier(171_000.0, 342_000.0, 527.50, 60.40),
+            tier(205_000.0, 410_000.0, 649.20, 83.30),
+            tier(500_000.0, 750_000.0, 689.90, 91.00),
+        ],
+    },
+];
+
+/// Looks up the premiums for a year, falling back to the most recent year on record
+pub fn medicare_premiums(year: i32) -> &'static MedicarePremiums {
+    MEDICARE_PREMIUMS
+        .iter()
+        .rev()
+        .find(|premiums| premiums.year <= year)
+        .unwrap_or(&MEDICARE_PREMIUMS[0])
+}
+
+impl MedicarePremiums {
+    /// The tier `income` falls in, counting from 0 for the standard premium
+    pub fn irmaa_tier(&self, income: f64, status: FilingStatus) -> (usize, &IrmaaTier) {
+        let floor = |tier: &IrmaaTier| match status {
+            FilingStatus::Single => tier.single_floor,
+            FilingStatus::MarriedFilingJointly => tier.joint_floor,
+        };
+        self.tiers
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(i, tier)| *i == 0 || income > floor(tier))
+            .unwrap_or((0, &self.tiers[0]))
+    }
+}
+
+/// Inputs to the healthcare cost projection
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct HealthcareInputs {
+    /// Joint filers are taken to be two people on Medicare
+    pub status: FilingStatus,
+    /// Modified AGI that sets the IRMAA tier, in today's dollars
+    pub income: f64,
+    /// Monthly out-of-pocket costs, e.g., deductibles, copays, and Medigap and drug plan
+    /// premiums, in dollars of the first year
+    pub out_of_pocket: f64,
+    /// Yearly growth of premiums and out-of-pocket costs as a decimal
+    pub healthcare_inflation: f64,
+}
+
+/// Monthly healthcare costs in one year
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HealthcareCost {
+    /// Part B premiums at the standard rate, for everyone covered
+    pub medicare: f64,
+    /// Part B and Part D income-related adjustments above the standard premium
+    pub irmaa: f64,
+    pub out_of_pocket: f64,
+    pub total: f64,
+}
+
+// Projects monthly healthcare costs for a year of retirement.
+///
+/// # Parameters:
+/// - `inputs`: Filing status, income, out-of-pocket costs, and healthcare inflation (h)
+/// - `premiums`: Medicare premiums and IRMAA tiers for the first year
+/// - `age`: Age during the year; Medicare premiums start at 65
+/// - `years`: Years since the first year (t)
+///
+/// # Formula:
+/// premiums_t = people * (Part B + Part D adjustment for the income's tier) * (1 + h)^t
+/// out-of-pocket_t = out-of-pocket * (1 + h)^t
+///
+/// # Returns:
+/// Monthly premiums, income-related adjustments, and out-of-pocket costs. The income tiers are
+/// taken to rise with income, so the tier stays the same.
+pub fn healthcare_cost(
+    inputs: &HealthcareInputs,
+    premiums: &MedicarePremiums,
+    age: f64,
+    years: usize,
+) -> HealthcareCost {
+    let growth = (1.0 + inputs.healthcare_inflation).powi(years as i32);
+    let people = match inputs.status {
+        FilingStatus::Single => 1.0,
+        FilingStatus::MarriedFilingJointly => 2.0,
+    };
+    let (medicare, irmaa) = if age >= MEDICARE_AGE {
+        let standard = premiums.tiers[0].part_b;
+        let (_, tier) = premiums.irmaa_tier(inputs.income, inputs.status);
+        (
+            people * standard * growth,
+            people * (tier.part_b - standard + tier.part_d) * growth,
+        )
+    } else {
+        (0.0, 0.0)
+    };
+    let out_of_pocket = inputs.out_of_pocket * growth;
+
+    HealthcareCost {
+        medicare,
+        irmaa,
+        out_of_pocket,
+        total: medicare + irmaa + out_of_pocket,
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+/// One of the three inflation scenarios a projection runs under
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InflationCase {
+    Low,
+    Base,
+    High,
+}
+
+impl InflationCase {
+    pub const ALL: [InflationCase; 3] =
+        [InflationCase::Low, InflationCase::Base, InflationCase::High];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InflationCase::Low => "Low inflation",
+            InflationCase::Base => "Base inflation",
+            InflationCase::High => "High inflation",
+        }
+    }
+}
+
+/// Whether a calculator also runs its projection at lower and higher inflation, and how much
+/// lower and higher. The base rate is the calculator's own inflation input.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct InflationScenarios {
+    pub enabled: bool,
+    /// Gap between the base rate and each of the low and high rates as a decimal
+    pub spread: f64,
+}
+
+impl Default for InflationScenarios {
+    fn default() -> Self {
+        InflationScenarios {
+            enabled: false,
+            spread: 0.01,
+        }
+    }
+}
+
+impl InflationScenarios {
+    /// The low, base, and high rates around `base`. An error if the spread is negative or the
+    /// low rate is -100% or less.
+    pub fn rates(&self, base: f64) -> Result<ScenarioResults<f64>, String> {
+        if self.spread.is_nan() || self.spread < 0.0 {
+            return Err("The inflation spread can't be negative".to_string());
+        }
+        if base.is_nan() || base - self.spread <= -1.0 {
+            return Err("Low inflation must be greater than -100%".to_string());
+        }
+        Ok(ScenarioResults {
+            low: base - self.spread,
+            base,
+            high: base + self.spread,
+        })
+    }
+}
+
+/// Inputs to a long-horizon projection that can be rerun at another inflation rate
+pub trait InflationAdjustable: Sized {
+    /// The inflation rate the inputs project at, as a decimal
+    fn inflation_rate(&self) -> f64;
+
+    /// The same inputs projecting at `rate` instead
+    fn with_inflation_rate(&self, rate: f64) -> Self;
+}
+
+/// A value under each inflation scenario
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScenarioResults<T> {
+    pub low: T,
+    pub base: T,
+    pub high: T,
+}
+
+impl<T> ScenarioResults<T> {
+    pub fn get(&self, case: InflationCase) -> &T {
+        match case {
+            InflationCase::Low => &self.low,
+            InflationCase::Base => &self.base,
+            InflationCase::High => &self.high,
+        }
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> ScenarioResults<U> {
+        ScenarioResults {
+            low: f(&self.low),
+            base: f(&self.base),
+            high: f(&self.high),
+        }
+    }
+}
+
+/// Runs `project` on `inputs` at the low, base, and high inflation rates around the inputs' own
+/// rate.
+///
+/// # Returns:
+/// The rates and each scenario's result. An error if the scenarios can't be built or any run
+/// fails.
+pub fn run_inflation_scenarios<I: InflationAdjustable, T>(
+    inputs: &I,
+    scenarios: &InflationScenarios,
+    project: impl Fn(&I) -> Result<T, String>,
+) -> Result<(ScenarioResults<f64>, ScenarioResults<T>), String> {
+    let rates = scenarios.rates(inputs.inflation_rate())?;
+    let results = ScenarioResults {
+        low: project(&inputs.with_inflation_rate(rates.low))?,
+        base: project(&inputs.with_inflation_rate(rates.base))?,
+        high: project(&inputs.with_inflation_rate(rates.high))?,
+    };
+    Ok((rates, results))
+}
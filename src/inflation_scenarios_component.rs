@@ -0,0 +1,51 @@
+use crate::inflation_scenarios::InflationScenarios;
+use dioxus::prelude::*;
+
+const SPREAD_OPTIONS: &[(f64, &str, &str)] = &[
+    (0.005, "0.5", "±0.5%"),
+    (0.01, "1", "±1%"),
+    (0.015, "1.5", "±1.5%"),
+    (0.02, "2", "±2%"),
+];
+
+/// Checkbox that reruns a calculator's projection at lower and higher inflation than its own
+/// inflation input, and how far apart the three rates sit
+#[component]
+pub fn InflationScenarioControls(scenarios: Signal<InflationScenarios>) -> Element {
+    let current = scenarios();
+
+    rsx! {
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Inflation Scenarios:"
+            }
+            input {
+                r#type: "checkbox",
+                "aria-label": "Compare low, base, and high inflation",
+                checked: current.enabled,
+                onchange: move |event| scenarios.write().enabled = event.checked(),
+            }
+            select {
+                style: "margin-left: 10px; border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                "aria-label": "Inflation spread",
+                disabled: !current.enabled,
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(spread, _, _)) = SPREAD_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        scenarios.write().spread = spread;
+                    }
+                },
+                for (spread , value , display) in SPREAD_OPTIONS.iter() {
+                    option {
+                        value: *value,
+                        selected: (current.spread - *spread).abs() < 1e-9,
+                        {*display}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use crate::loan::loan_payment;
+use serde::{Deserialize, Serialize};
+
+/// Lease money factors are quoted as APR / 2400 when APR is in percent (APR / 24 as a decimal)
+const MONEY_FACTOR_SCALE: f64 = 24.0;
+
+// Converts an APR to a lease money factor.
+///
+/// # Parameters:
+/// - `apr`: Annual percentage rate as a decimal, e.g., 0.06 for 6%
+///
+/// # Formula:
+/// MF = APR / 24
+///
+/// # Returns:
+/// The money factor, e.g., 0.0025 for 6% APR.
+pub fn apr_to_money_factor(apr: f64) -> f64 {
+    apr / MONEY_FACTOR_SCALE
+}
+
+// Converts a lease money factor to an APR.
+///
+/// # Parameters:
+/// - `money_factor`: Money factor (MF), e.g., 0.0025
+///
+/// # Formula:
+/// APR = MF * 24
+///
+/// # Returns:
+/// The APR as a decimal.
+pub fn money_factor_to_apr(money_factor: f64) -> f64 {
+    money_factor * MONEY_FACTOR_SCALE
+}
+
+/// Inputs to the car lease calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LeaseInputs {
+    /// Negotiated price of the car (capitalized cost), after any down payment
+    pub capitalized_cost: f64,
+    /// Value of the car at lease end, in dollars
+    pub residual_value: f64,
+    pub term_months: f64,
+    pub money_factor: f64,
+}
+
+/// Monthly payment of a car lease, before sales tax
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LeaseOutputs {
+    /// Pays for the car's loss of value: (cap cost - residual) / term
+    pub depreciation_fee: f64,
+    /// The lease's interest: (cap cost + residual) * MF
+    pub rent_charge: f64,
+    pub monthly_payment: f64,
+    pub total_rent_charges: f64,
+    /// APR equivalent of the money factor
+    pub apr: f64,
+}
+
+// Computes a car lease payment.
+///
+/// # Parameters:
+/// - `inputs`: Capitalized cost (C), residual value (R), term in months (n), money factor (MF)
+///
+/// # Formula:
+/// payment = (C - R) / n + (C + R) * MF
+///
+/// # Returns:
+/// The payment split into its depreciation and rent-charge parts.
+pub fn compute_lease(inputs: &LeaseInputs) -> LeaseOutputs {
+    let depreciation_fee = (inputs.capitalized_cost - inputs.residual_value) / inputs.term_months;
+    let rent_charge = (inputs.capitalized_cost + inputs.residual_value) * inputs.money_factor;
+
+    LeaseOutputs {
+        depreciation_fee,
+        rent_charge,
+        monthly_payment: depreciation_fee + rent_charge,
+        total_rent_charges: rent_charge * inputs.term_months,
+        apr: money_factor_to_apr(inputs.money_factor),
+    }
+}
+
+/// Inputs to the lease vs buy comparison. The lease and the loan run the same term.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LeaseVsBuyInputs {
+    /// Negotiated price of the vehicle or equipment
+    pub price: f64,
+    /// Paid at signing either way: a cap cost reduction on the lease, or down on the loan
+    pub down_payment: f64,
+    /// Value at the end of the term: the lease's residual, and what an owner could sell it for
+    pub residual_value: f64,
+    pub money_factor: f64,
+    /// Annual rate on the loan as a decimal, e.g., 0.065 for 6.5%
+    pub loan_rate: f64,
+    pub term_months: f64,
+    /// Annual rate later dollars are discounted at, e.g., what savings would otherwise earn
+    pub discount_rate: f64,
+}
+
+/// What one way of paying for the vehicle costs over the term
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OwnershipCost {
+    pub monthly_payment: f64,
+    /// Down payment plus every monthly payment
+    pub total_paid: f64,
+    /// What's left at the end: the vehicle's value if bought, nothing if leased
+    pub ending_value: f64,
+    /// Total paid less the ending value
+    pub net_cost: f64,
+    /// Net cost in today's dollars, at the discount rate
+    pub present_cost: f64,
+}
+
+/// Leasing and buying side by side
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LeaseVsBuy {
+    pub lease: OwnershipCost,
+    pub buy: OwnershipCost,
+}
+
+// Compares leasing with financing a purchase over the same term.
+///
+/// # Parameters:
+/// - `inputs`: Price (P), down payment (D), residual value (R), money factor, loan rate (r),
+///   term in months (n), and discount rate (d)
+///
+/// # Formula:
+/// lease payment = lease(P - D, R, n, MF), due at the start of each month
+/// loan payment = PMT(P - D, r, n), due at the end of each month
+/// a(n) = (1 - (1 + d/12)^-n) / (d/12)
+/// lease PV cost = D + lease payment * a(n) * (1 + d/12)
+/// buy PV cost = D + loan payment * a(n) - R / (1 + d/12)^n
+///
+/// # Returns:
+/// Each side's payment, total paid, value at the end, and net cost in nominal and today's
+/// dollars. An error if an entry is negative, the down payment or residual exceeds the price,
+/// or the term is under a month.
+pub fn compare_lease_vs_buy(inputs: &LeaseVsBuyInputs) -> Result<LeaseVsBuy, String> {
+    let entries = [
+        inputs.price,
+        inputs.down_payment,
+        inputs.residual_value,
+        inputs.money_factor,
+        inputs.loan_rate,
+        inputs.discount_rate,
+    ];
+    if entries.iter().any(|entry| entry.is_nan() || *entry < 0.0) {
+        return Err("Prices, rates, and the money factor can't be negative".to_string());
+    }
+    if inputs.down_payment > inputs.price {
+        return Err("The down payment can't exceed the price".to_string());
+    }
+    if inputs.residual_value > inputs.price {
+        return Err("The residual value can't exceed the price".to_string());
+    }
+    if inputs.term_months.is_nan() || inputs.term_months < 1.0 {
+        return Err("Enter a term of at least one month".to_string());
+    }
+
+    let financed = inputs.price - inputs.down_payment;
+    let term = inputs.term_months;
+    let monthly_discount = inputs.discount_rate / 12.0;
+    let discount_factor = (1.0 + monthly_discount).powf(-term);
+    let annuity_factor = if monthly_discount == 0.0 {
+        term
+    } else {
+        (1.0 - discount_factor) / monthly_discount
+    };
+
+    let lease_payment = compute_lease(&LeaseInputs {
+        capitalized_cost: financed,
+        residual_value: inputs.residual_value,
+        term_months: term,
+        money_factor: inputs.money_factor,
+    })
+    .monthly_payment;
+    let lease_total = inputs.down_payment + lease_payment * term;
+    let lease = OwnershipCost {
+        monthly_payment: lease_payment,
+        total_paid: lease_total,
+        ending_value: 0.0,
+        net_cost: lease_total,
+        present_cost: inputs.down_payment
+            + lease_payment * annuity_factor * (1.0 + monthly_discount),
+    };
+
+    let buy_payment = loan_payment(financed, inputs.loan_rate, term / 12.0, 12.0);
+    let buy_total = inputs.down_payment + buy_payment * term;
+    let buy = OwnershipCost {
+        monthly_payment: buy_payment,
+        total_paid: buy_total,
+        ending_value: inputs.residual_value,
+        net_cost: buy_total - inputs.residual_value,
+        present_cost: inputs.down_payment + buy_payment * annuity_factor
+            - inputs.residual_value * discount_factor,
+    };
+
+    Ok(LeaseVsBuy { lease, buy })
+}
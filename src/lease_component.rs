@@ -0,0 +1,169 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::feedback_component::FeedbackWidget;
+use crate::lease::{apr_to_money_factor, compute_lease, money_factor_to_apr, LeaseInputs};
+use crate::numeric_input_component::NumericInput;
+use dioxus::prelude::*;
+
+const DEFAULT_INPUTS: LeaseInputs = LeaseInputs {
+    capitalized_cost: 35_000.0,
+    residual_value: 21_000.0,
+    term_months: 36.0,
+    money_factor: 0.0025,
+};
+
+/// Tolerance below which the two converter fields are considered in sync
+const SYNC_EPSILON: f64 = 1e-12;
+
+/// Two linked fields, APR and money factor: editing either one updates the other
+#[component]
+pub fn MoneyFactorConverter(money_factor_signal: Signal<f64>) -> Element {
+    let mut mf_input = use_signal(move || format!("{:.5}", money_factor_signal.peek()));
+    let mf_input_valid = use_signal(|| true);
+    let mut apr_signal =
+        use_signal(move || money_factor_to_apr(*money_factor_signal.peek()) * 100.0);
+    let mut apr_input = use_signal(move || format!("{:.2}", apr_signal.peek()));
+    let apr_input_valid = use_signal(|| true);
+
+    // APR edited (or money factor restored): update the money factor
+    use_effect(move || {
+        let money_factor = apr_to_money_factor(apr_signal() / 100.0);
+        if (money_factor - *money_factor_signal.peek()).abs() > SYNC_EPSILON {
+            money_factor_signal.set(money_factor);
+            mf_input.set(format!("{:.5}", money_factor));
+        }
+    });
+    // Money factor edited: update the APR
+    use_effect(move || {
+        let apr = money_factor_to_apr(money_factor_signal()) * 100.0;
+        if (apr - *apr_signal.peek()).abs() > SYNC_EPSILON {
+            apr_signal.set(apr);
+            apr_input.set(format!("{:.2}", apr));
+            mf_input.set(format!("{:.5}", money_factor_signal.peek()));
+        }
+    });
+
+    rsx! {
+        NumericInput {
+            label: "Money Factor:".to_string(),
+            placeholder: "Enter the money factor (e.g., 0.00250)".to_string(),
+            input_signal: mf_input,
+            value_signal: money_factor_signal,
+            valid_signal: mf_input_valid,
+            field_name: "Money factor".to_string(),
+            css_prefix: "money-factor".to_string(),
+            step: Some(0.0001),
+        }
+        NumericInput {
+            label: "Equivalent APR (%):".to_string(),
+            placeholder: "Enter an APR (e.g., 6.0)".to_string(),
+            input_signal: apr_input,
+            value_signal: apr_signal,
+            valid_signal: apr_input_valid,
+            field_name: "APR".to_string(),
+            css_prefix: "apr".to_string(),
+            step: Some(0.125),
+        }
+    }
+}
+
+#[component]
+pub fn LeaseUI() -> Element {
+    let mut cost_signal = use_signal(|| 35_000.0 as f64);
+    let mut cost_input = use_signal(|| "35000".to_string());
+    let cost_input_valid = use_signal(|| true);
+    let mut residual_signal = use_signal(|| 21_000.0 as f64);
+    let mut residual_input = use_signal(|| "21000".to_string());
+    let residual_input_valid = use_signal(|| true);
+    let mut term_signal = use_signal(|| 36.0 as f64);
+    let mut term_input = use_signal(|| "36".to_string());
+    let term_input_valid = use_signal(|| true);
+    let mut money_factor_signal = use_signal(|| 0.0025 as f64);
+    let start_fresh = use_autosave(
+        "lease",
+        DEFAULT_INPUTS,
+        move || LeaseInputs {
+            capitalized_cost: cost_signal(),
+            residual_value: residual_signal(),
+            term_months: term_signal(),
+            money_factor: money_factor_signal(),
+        },
+        use_callback(move |inputs: LeaseInputs| {
+            cost_signal.set(inputs.capitalized_cost);
+            cost_input.set(format!("{}", inputs.capitalized_cost));
+            residual_signal.set(inputs.residual_value);
+            residual_input.set(format!("{}", inputs.residual_value));
+            term_signal.set(inputs.term_months);
+            term_input.set(format!("{}", inputs.term_months));
+            money_factor_signal.set(inputs.money_factor);
+        }),
+    );
+    use_track_calculator("lease");
+    use_track_input_range("lease", "cost", cost_signal, amount_bucket);
+
+    let outputs = compute_lease(&LeaseInputs {
+        capitalized_cost: cost_signal(),
+        residual_value: residual_signal(),
+        term_months: term_signal(),
+        money_factor: money_factor_signal(),
+    });
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Capitalized Cost ($):".to_string(),
+            placeholder: "Enter the negotiated price (e.g., 35000)".to_string(),
+            input_signal: cost_input,
+            value_signal: cost_signal,
+            valid_signal: cost_input_valid,
+            field_name: "Capitalized cost".to_string(),
+            css_prefix: "cost".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Residual Value ($):".to_string(),
+            placeholder: "Enter the value at lease end (e.g., 21000)".to_string(),
+            input_signal: residual_input,
+            value_signal: residual_signal,
+            valid_signal: residual_input_valid,
+            field_name: "Residual value".to_string(),
+            css_prefix: "residual".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Term (months):".to_string(),
+            placeholder: "Enter the lease term (e.g., 36)".to_string(),
+            input_signal: term_input,
+            value_signal: term_signal,
+            valid_signal: term_input_valid,
+            field_name: "Term".to_string(),
+            css_prefix: "term".to_string(),
+            step: Some(1.0),
+        }
+        MoneyFactorConverter { money_factor_signal }
+
+        div {
+            id: "LeaseCalculation",
+            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+            "Monthly payment: ${outputs.monthly_payment:.2}*"
+        }
+        div { style: "margin-bottom: 15px; font-size: 16px;",
+            "Depreciation ${outputs.depreciation_fee:.2} + rent charge ${outputs.rent_charge:.2} a month. "
+            "Rent charges total ${outputs.total_rent_charges:.2}, the same as borrowing at {outputs.apr * 100.0:.2}% APR."
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "lease" }
+        AssumptionsFooter { calculator: "lease" }
+        div { style: "font-size: 10px",
+            p { "*Before sales tax, acquisition and disposition fees." }
+            br {}
+            br {}
+        }
+    }
+}
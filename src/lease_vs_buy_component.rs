@@ -0,0 +1,250 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::lease::{compare_lease_vs_buy, LeaseVsBuyInputs, OwnershipCost};
+use crate::lease_component::MoneyFactorConverter;
+use crate::loan::lowest_offer;
+use crate::numeric_input_component::NumericInput;
+use dioxus::prelude::*;
+
+/// A $35,000 car with $3,000 down, worth 60% of its price after a three-year lease or loan
+const DEFAULT_INPUTS: LeaseVsBuyInputs = LeaseVsBuyInputs {
+    price: 35_000.0,
+    down_payment: 3_000.0,
+    residual_value: 21_000.0,
+    money_factor: 0.0025,
+    loan_rate: 0.065,
+    term_months: 36.0,
+    discount_rate: 0.05,
+};
+
+/// Column headings, in the order of `LeaseVsBuy`'s sides
+const SIDES: &[&str] = &["Lease", "Buy"];
+
+/// Rows of the comparison: what each measures, and whether the lower side is highlighted
+const COMPARISON_ROWS: &[(&str, fn(&OwnershipCost) -> f64, bool)] = &[
+    ("Monthly payment", |c| c.monthly_payment, true),
+    ("Total paid", |c| c.total_paid, true),
+    ("Value at the end", |c| c.ending_value, false),
+    ("Net cost", |c| c.net_cost, true),
+    ("Cost in today's dollars", |c| c.present_cost, true),
+];
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+const BEST_CELL_STYLE: &str =
+    "text-align: right; padding: 2px 8px; background: #d5f5e3; font-weight: bold;";
+
+#[component]
+pub fn LeaseVsBuyUI() -> Element {
+    let mut price_signal = use_signal(|| 35_000.0 as f64);
+    let mut price_input = use_signal(|| "35000".to_string());
+    let price_input_valid = use_signal(|| true);
+    let mut down_signal = use_signal(|| 3_000.0 as f64);
+    let mut down_input = use_signal(|| "3000".to_string());
+    let down_input_valid = use_signal(|| true);
+    let mut residual_signal = use_signal(|| 21_000.0 as f64);
+    let mut residual_input = use_signal(|| "21000".to_string());
+    let residual_input_valid = use_signal(|| true);
+    let mut term_signal = use_signal(|| 36.0 as f64);
+    let mut term_input = use_signal(|| "36".to_string());
+    let term_input_valid = use_signal(|| true);
+    let mut money_factor_signal = use_signal(|| 0.0025 as f64);
+    let mut loan_rate_signal = use_signal(|| 6.5 as f64);
+    let mut loan_rate_input = use_signal(|| "6.5".to_string());
+    let loan_rate_input_valid = use_signal(|| true);
+    let mut discount_signal = use_signal(|| 5.0 as f64);
+    let mut discount_input = use_signal(|| "5".to_string());
+    let discount_input_valid = use_signal(|| true);
+
+    let inputs = move || LeaseVsBuyInputs {
+        price: price_signal(),
+        down_payment: down_signal(),
+        residual_value: residual_signal(),
+        money_factor: money_factor_signal(),
+        loan_rate: loan_rate_signal() / 100.0,
+        term_months: term_signal(),
+        discount_rate: discount_signal() / 100.0,
+    };
+    let start_fresh = use_autosave(
+        "lease_vs_buy",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: LeaseVsBuyInputs| {
+            price_signal.set(inputs.price);
+            price_input.set(format!("{}", inputs.price));
+            down_signal.set(inputs.down_payment);
+            down_input.set(format!("{}", inputs.down_payment));
+            residual_signal.set(inputs.residual_value);
+            residual_input.set(format!("{}", inputs.residual_value));
+            term_signal.set(inputs.term_months);
+            term_input.set(format!("{}", inputs.term_months));
+            money_factor_signal.set(inputs.money_factor);
+            loan_rate_signal.set(inputs.loan_rate * 100.0);
+            loan_rate_input.set(format!("{}", inputs.loan_rate * 100.0));
+            discount_signal.set(inputs.discount_rate * 100.0);
+            discount_input.set(format!("{}", inputs.discount_rate * 100.0));
+        }),
+    );
+    use_track_calculator("lease_vs_buy");
+    use_track_input_range("lease_vs_buy", "price", price_signal, amount_bucket);
+
+    let comparison = compare_lease_vs_buy(&inputs());
+    // Each row of the comparison: its label, each side's figure, and which is highlighted
+    let mut rows: Vec<(&str, Vec<String>, Option<usize>)> = vec![];
+    let summary = match &comparison {
+        Ok(comparison) => {
+            let sides = [comparison.lease, comparison.buy];
+            for (label, measure, highlight) in COMPARISON_ROWS.iter() {
+                let values: Vec<Option<f64>> =
+                    sides.iter().map(|side| Some(measure(side))).collect();
+                let cells = sides
+                    .iter()
+                    .map(|side| format!("${}", format_dollars(measure(side))))
+                    .collect();
+                let best = if *highlight {
+                    lowest_offer(&values)
+                } else {
+                    None
+                };
+                rows.push((label, cells, best));
+            }
+            // Rounded so a difference of whole cents isn't shown a cent short
+            let saved = ((comparison.lease.present_cost - comparison.buy.present_cost) * 100.0)
+                .round()
+                / 100.0;
+            if saved > 0.0 {
+                format!(
+                    "Buying costs ${} less than leasing in today's dollars*",
+                    format_dollars(saved)
+                )
+            } else if saved < 0.0 {
+                format!(
+                    "Leasing costs ${} less than buying in today's dollars*",
+                    format_dollars(-saved)
+                )
+            } else {
+                "Leasing and buying cost the same in today's dollars*".to_string()
+            }
+        }
+        Err(error) => error.clone(),
+    };
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Price ($):".to_string(),
+            placeholder: "Enter the negotiated price (e.g., 35000)".to_string(),
+            input_signal: price_input,
+            value_signal: price_signal,
+            valid_signal: price_input_valid,
+            field_name: "Price".to_string(),
+            css_prefix: "price".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Down Payment ($):".to_string(),
+            placeholder: "Enter the cash paid at signing either way (e.g., 3000)".to_string(),
+            input_signal: down_input,
+            value_signal: down_signal,
+            valid_signal: down_input_valid,
+            field_name: "Down payment".to_string(),
+            css_prefix: "down".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Residual Value ($):".to_string(),
+            placeholder: "Enter the value at the end of the term (e.g., 21000)".to_string(),
+            input_signal: residual_input,
+            value_signal: residual_signal,
+            valid_signal: residual_input_valid,
+            field_name: "Residual value".to_string(),
+            css_prefix: "residual".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Term (months):".to_string(),
+            placeholder: "Enter the lease and loan term (e.g., 36)".to_string(),
+            input_signal: term_input,
+            value_signal: term_signal,
+            valid_signal: term_input_valid,
+            field_name: "Term".to_string(),
+            css_prefix: "term".to_string(),
+            step: Some(1.0),
+        }
+        MoneyFactorConverter { money_factor_signal }
+        NumericInput {
+            label: "Loan Rate (%):".to_string(),
+            placeholder: "Enter the loan's annual rate (e.g., 6.5)".to_string(),
+            input_signal: loan_rate_input,
+            value_signal: loan_rate_signal,
+            valid_signal: loan_rate_input_valid,
+            field_name: "Loan rate".to_string(),
+            css_prefix: "loan-rate".to_string(),
+            step: Some(0.125),
+        }
+        NumericInput {
+            label: "Discount Rate (%):".to_string(),
+            placeholder: "Enter what your savings would otherwise earn (e.g., 5)".to_string(),
+            input_signal: discount_input,
+            value_signal: discount_signal,
+            valid_signal: discount_input_valid,
+            field_name: "Discount rate".to_string(),
+            css_prefix: "discount".to_string(),
+            step: Some(0.25),
+        }
+
+        if comparison.is_ok() {
+            div {
+                id: "LeaseVsBuyCalculation",
+                style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                "{summary}"
+            }
+            table { style: "color: #333; border-collapse: collapse; font-size: 14px; margin-bottom: 15px;",
+                thead {
+                    tr {
+                        th {}
+                        for side in SIDES.iter() {
+                            th { scope: "col", style: CELL_STYLE, {*side} }
+                        }
+                    }
+                }
+                tbody {
+                    for (label , cells , best) in rows.into_iter() {
+                        tr { key: "{label}",
+                            th { scope: "row", style: "text-align: left; padding: 2px 8px;", "{label}" }
+                            for (index , cell) in cells.into_iter().enumerate() {
+                                td { style: if best == Some(index) { BEST_CELL_STYLE } else { CELL_STYLE },
+                                    "{cell}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        } else {
+            div {
+                id: "LeaseVsBuyCalculation",
+                style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                "{summary}"
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "lease_vs_buy" }
+        AssumptionsFooter { calculator: "lease_vs_buy" }
+        div { style: "font-size: 10px",
+            p {
+                "*Payments are discounted monthly at the discount rate, and the owner's vehicle is counted at its residual value when the term ends. Before sales tax, fees, maintenance, and insurance."
+            }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::Route;
+use dioxus::prelude::*;
+
+/// A value sent from one calculator's results into another calculator's inputs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CalculatorLink {
+    /// A savings balance to draw down in the sequence risk calculator
+    RetirementBalance(f64),
+    /// An annual interest rate (as a fraction) for the future value calculator
+    AnnualRate(f64),
+    /// Closing fees, not counting prepaids, for a new offer in the loan comparison
+    ClosingFees(f64),
+    /// A yearly discount rate (as a fraction) for the DCF valuation
+    DiscountRate(f64),
+    /// A cost of equity (as a fraction) for the WACC calculator
+    CostOfEquity(f64),
+}
+
+impl CalculatorLink {
+    /// Page of the calculator that receives this value
+    pub fn target(&self) -> Route {
+        match self {
+            CalculatorLink::RetirementBalance(_) => Route::SequenceRiskUI {},
+            CalculatorLink::AnnualRate(_) => Route::FutureValueUI {},
+            CalculatorLink::ClosingFees(_) => Route::LoanCompareUI {},
+            CalculatorLink::DiscountRate(_) => Route::DcfUI {},
+            CalculatorLink::CostOfEquity(_) => Route::WaccUI {},
+        }
+    }
+
+    /// Text of the button that sends this value
+    pub fn label(&self) -> &'static str {
+        match self {
+            CalculatorLink::RetirementBalance(_) => "Send to Sequence Risk as starting balance",
+            CalculatorLink::AnnualRate(_) => "Send to Future Value as interest rate",
+            CalculatorLink::ClosingFees(_) => "Send to Loan Comparison as a new offer's fees",
+            CalculatorLink::DiscountRate(_) => "Send to DCF Valuation as discount rate",
+            CalculatorLink::CostOfEquity(_) => "Send to WACC as cost of equity",
+        }
+    }
+}
+
+/// Provides the inbox that holds a link until the receiving calculator picks it up
+pub fn use_link_provider() {
+    use_context_provider(|| Signal::new(None::<CalculatorLink>));
+}
+
+/// Picks up links sent to this calculator, including ones sent while it is already open.
+///
+/// `apply` writes a link into the calculator's input signals and returns whether the link
+/// was meant for this calculator; accepted links are removed from the inbox. Returns the
+/// last accepted link so it can be applied again after autosaved inputs are restored.
+pub fn use_link_receiver(apply: Callback<CalculatorLink, bool>) -> Signal<Option<CalculatorLink>> {
+    let inbox = try_use_context::<Signal<Option<CalculatorLink>>>();
+    let mut received = use_signal(|| None::<CalculatorLink>);
+
+    use_effect(move || {
+        let Some(mut inbox) = inbox else {
+            return;
+        };
+        let Some(link) = inbox() else {
+            return;
+        };
+        if apply.call(link) {
+            received.set(Some(link));
+            inbox.set(None);
+        }
+    });
+
+    received
+}
+
+/// Button that sends a result to another calculator and opens it
+#[component]
+pub fn SendLinkButton(link: CalculatorLink) -> Element {
+    let inbox = try_use_context::<Signal<Option<CalculatorLink>>>();
+
+    rsx! {
+        if let Some(mut inbox) = inbox {
+            button {
+                style: "padding: 6px 12px; border-radius: 4px; margin-bottom: 15px;",
+                onclick: move |_| {
+                    inbox.set(Some(link));
+                    navigator().push(link.target());
+                },
+                {link.label()}
+            }
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use crate::fin_numeric::FinNumeric;
+use crate::rate_shock::{rate_sensitivity, shock_table, RateShock};
+use serde::{Deserialize, Serialize};
+
+/// Most offers the loan comparison takes side by side
+pub const MAX_LOAN_OFFERS: usize = 5;
+
+/// Months the loan comparison's shorter-horizon cost covers, for borrowers who expect to sell or
+/// refinance
+pub const COST_HORIZON_MONTHS: usize = 60;
+
+// Computes the level payment that pays off a loan (amortizing loan payment).
+///
+/// # Parameters:
+/// - `principal`: Amount borrowed (P)
+/// - `annual_rate`: Annual interest rate (r), e.g., 0.06 for 6%
+/// - `years`: Loan term in years (t)
+/// - `periods_per_year`: Payments per year (n)
+///
+/// # Formula:
+/// PMT = P * (r/n) / (1 - (1 + r/n)^-(nt))
+/// PMT = P / (nt)   when r = 0
+///
+/// # Returns:
+/// The payment per period, not rounded.
+pub fn loan_payment<T>(principal: T, annual_rate: T, years: T, periods_per_year: T) -> T
+where
+    T: FinNumeric,
+{
+    let periodic_rate = annual_rate / periods_per_year;
+    let n_periods = periods_per_year * years;
+    if periodic_rate == T::zero() {
+        return principal / n_periods;
+    }
+
+    let discount = T::one() / (T::one() + periodic_rate).powf(n_periods);
+
+    principal * periodic_rate / (T::one() - discount)
+}
+
+/// Payment after each parallel rate shift (e.g., an adjustable-rate loan resetting), estimated
+/// from the payment's rate sensitivity and recomputed exactly
+pub fn loan_payment_shocks(
+    principal: f64,
+    annual_rate: f64,
+    years: f64,
+    periods_per_year: f64,
+    shifts_bp: &[i32],
+) -> Vec<RateShock> {
+    let payment_at = |rate| loan_payment(principal, rate, years, periods_per_year);
+    let sensitivity = rate_sensitivity(payment_at, annual_rate);
+    shock_table(payment_at, annual_rate, sensitivity, shifts_bp)
+}
+
+/// A charge for paying a loan off early, as written in the note. Each applies only while the
+/// loan is younger than its `years`; paying on schedule to the end never triggers one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum PrepaymentPenalty {
+    #[default]
+    None,
+    /// A flat dollar fee
+    FixedFee { fee: f64, years: f64 },
+    /// A share of the balance paid off, as a decimal
+    PercentOfBalance { rate: f64, years: f64 },
+    /// A share of the balance that falls evenly to zero, one step a year; e.g., 5% over five
+    /// years charges 5%, 4%, 3%, 2%, then 1%
+    StepDown { initial_rate: f64, years: f64 },
+}
+
+impl PrepaymentPenalty {
+    /// Penalty for paying off `balance` after `months` monthly payments
+    pub fn charge(&self, balance: f64, months: usize) -> f64 {
+        let within = |years: f64| (months as f64) < years * 12.0;
+        match *self {
+            PrepaymentPenalty::None => 0.0,
+            PrepaymentPenalty::FixedFee { fee, years } if within(years) => fee,
+            PrepaymentPenalty::PercentOfBalance { rate, years } if within(years) => balance * rate,
+            PrepaymentPenalty::StepDown {
+                initial_rate,
+                years,
+            } if within(years) => {
+                let loan_year = (months / 12) as f64;
+                balance * initial_rate * (years.ceil() - loan_year) / years.ceil()
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// One lender's offer in the loan comparison, paid monthly
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LoanOffer {
+    /// Amount borrowed
+    pub amount: f64,
+    /// Note rate as a decimal, e.g., 0.065 for 6.5%
+    pub annual_rate: f64,
+    pub years: f64,
+    /// Lender and closing fees paid up front, in dollars
+    pub fees: f64,
+    /// Discount points, each 1% of the amount, paid up front
+    pub points: f64,
+    #[serde(default)]
+    pub prepayment_penalty: PrepaymentPenalty,
+}
+
+impl LoanOffer {
+    /// Fees plus points, in dollars
+    pub fn upfront_cost(&self) -> f64 {
+        self.fees + self.amount * self.points / 100.0
+    }
+}
+
+/// What an offer costs, by the measures the comparison lines up
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoanOfferCosts {
+    pub monthly_payment: f64,
+    /// The rate that makes the payments worth the amount received after fees and points, as a
+    /// decimal
+    pub apr: f64,
+    /// Interest over the full term, not counting fees or points
+    pub total_interest: f64,
+    /// Prepayment penalty for paying the loan off after `COST_HORIZON_MONTHS` months, e.g., to
+    /// refinance or sell
+    pub horizon_penalty: f64,
+    /// Fees and points, the interest paid in the first `COST_HORIZON_MONTHS` months, and the
+    /// penalty for paying off then
+    pub horizon_cost: f64,
+}
+
+// Computes the annual percentage rate of a loan with up-front costs: the rate at which the
+// payments are worth exactly the money the borrower actually receives.
+///
+/// # Parameters:
+/// - `amount_financed`: Amount borrowed less fees and points paid up front
+/// - `payment`: Level monthly payment (PMT)
+/// - `n_periods`: Number of monthly payments (N)
+///
+/// # Formula:
+/// Solve amount_financed = PMT * (1 - (1 + i)^-N) / i for i; the payments' value falls as i
+/// rises, so bisection converges on it
+/// APR = 12 * i
+///
+/// # Returns:
+/// The APR as a decimal, or zero when the payments add up to no more than the amount financed.
+pub fn apr_with_costs(amount_financed: f64, payment: f64, n_periods: f64) -> f64 {
+    if payment * n_periods <= amount_financed {
+        return 0.0;
+    }
+    let value_at = |rate: f64| payment * (1.0 - (1.0 + rate).powf(-n_periods)) / rate;
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..200 {
+        let middle = (low + high) / 2.0;
+        if middle == low || middle == high {
+            break;
+        }
+        if value_at(middle) > amount_financed {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    12.0 * (low + high) / 2.0
+}
+
+/// Payment, APR, and costs of a loan offer, or why the offer can't be priced
+pub fn loan_offer_costs(offer: &LoanOffer) -> Result<LoanOfferCosts, String> {
+    if offer.amount.is_nan() || offer.amount <= 0.0 {
+        return Err("Enter a loan amount greater than zero".to_string());
+    }
+    if offer.annual_rate.is_nan() || offer.annual_rate < 0.0 {
+        return Err("Enter a rate of zero or more".to_string());
+    }
+    let n_periods = (offer.years * 12.0).round();
+    if n_periods.is_nan() || n_periods < 1.0 {
+        return Err("Enter a term of at least one month".to_string());
+    }
+    if offer.fees < 0.0 || offer.points < 0.0 {
+        return Err("Fees and points can't be negative".to_string());
+    }
+    let penalty_terms = match offer.prepayment_penalty {
+        PrepaymentPenalty::None => (0.0, 0.0),
+        PrepaymentPenalty::FixedFee { fee, years } => (fee, years),
+        PrepaymentPenalty::PercentOfBalance { rate, years } => (rate, years),
+        PrepaymentPenalty::StepDown {
+            initial_rate,
+            years,
+        } => (initial_rate, years),
+    };
+    if penalty_terms.0 < 0.0 || penalty_terms.1 < 0.0 {
+        return Err("The prepayment penalty and its years can't be negative".to_string());
+    }
+    let amount_financed = offer.amount - offer.upfront_cost();
+    if amount_financed <= 0.0 {
+        return Err("Fees and points can't use up the whole loan".to_string());
+    }
+
+    let payment = loan_payment(offer.amount, offer.annual_rate, n_periods / 12.0, 12.0);
+    let periodic_rate = offer.annual_rate / 12.0;
+    let horizon = (COST_HORIZON_MONTHS as f64).min(n_periods);
+    let balance_at_horizon = if periodic_rate == 0.0 {
+        offer.amount - payment * horizon
+    } else {
+        let growth = (1.0 + periodic_rate).powf(horizon);
+        offer.amount * growth - payment * (growth - 1.0) / periodic_rate
+    };
+    let horizon_interest = payment * horizon - (offer.amount - balance_at_horizon);
+    // A loan that matures by the horizon is paid off on schedule, not early
+    let horizon_penalty = if horizon < n_periods {
+        offer
+            .prepayment_penalty
+            .charge(balance_at_horizon, horizon as usize)
+    } else {
+        0.0
+    };
+
+    Ok(LoanOfferCosts {
+        monthly_payment: payment,
+        apr: apr_with_costs(amount_financed, payment, n_periods),
+        total_interest: payment * n_periods - offer.amount,
+        horizon_penalty,
+        horizon_cost: offer.upfront_cost() + horizon_interest + horizon_penalty,
+    })
+}
+
+/// Index of the lowest value, skipping offers that couldn't be priced; ties go to the first.
+/// None when every priced offer has the same value, as there's nothing to choose between.
+pub fn lowest_offer(values: &[Option<f64>]) -> Option<usize> {
+    let priced: Vec<(usize, f64)> = values
+        .iter()
+        .enumerate()
+        .filter_map(|(index, value)| value.map(|value| (index, value)))
+        .collect();
+    if priced.len() > 1 && priced.windows(2).all(|pair| pair[0].1 == pair[1].1) {
+        return None;
+    }
+    priced
+        .into_iter()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+}
@@ -0,0 +1,475 @@
+use crate::analytics::use_track_calculator;
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::error_summary_component::{ErrorSummary, FieldError};
+use crate::feedback_component::FeedbackWidget;
+use crate::link::{use_link_receiver, CalculatorLink};
+use crate::loan::{
+    loan_offer_costs, lowest_offer, LoanOffer, LoanOfferCosts, PrepaymentPenalty,
+    COST_HORIZON_MONTHS, MAX_LOAN_OFFERS,
+};
+use crate::number_format::dollars;
+use dioxus::prelude::*;
+
+/// Number columns of the offers table, in order; the penalty kind's select sits between points
+/// and the penalty's amount
+const OFFER_COLUMNS: &[&str] = &[
+    "Amount ($)",
+    "Rate (%)",
+    "Term (years)",
+    "Fees ($)",
+    "Points",
+    "Penalty ($ or %)",
+    "Penalty Years",
+];
+
+/// Kinds of prepayment penalty, as (select value, display)
+const PENALTY_OPTIONS: &[(&str, &str)] = &[
+    ("none", "None"),
+    ("fixed", "Fixed fee ($)"),
+    ("percent", "% of balance"),
+    ("step", "Step-down (%)"),
+];
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+const BEST_CELL_STYLE: &str =
+    "text-align: right; padding: 2px 8px; background: #d5f5e3; font-weight: bold;";
+
+/// Rows of the comparison: what each measures and how it's shown
+const COMPARISON_ROWS: &[(&str, fn(&LoanOfferCosts) -> f64, fn(f64) -> String)] = &[
+    ("Monthly payment", |costs| costs.monthly_payment, dollars),
+    ("APR with fees", |costs| costs.apr, percent),
+    ("Total interest", |costs| costs.total_interest, dollars),
+    ("Penalty at 5 years", |costs| costs.horizon_penalty, dollars),
+    ("5-year cost", |costs| costs.horizon_cost, dollars),
+];
+
+fn percent(value: f64) -> String {
+    format!("{:.3}%", value * 100.0)
+}
+
+/// Three $300,000 offers: a 30-year loan with no points, one buying the rate down with a point,
+/// and a 15-year loan
+fn default_offers() -> Vec<LoanOffer> {
+    vec![
+        LoanOffer {
+            amount: 300_000.0,
+            annual_rate: 0.065,
+            years: 30.0,
+            fees: 1_500.0,
+            points: 0.0,
+            prepayment_penalty: PrepaymentPenalty::None,
+        },
+        LoanOffer {
+            amount: 300_000.0,
+            annual_rate: 0.06125,
+            years: 30.0,
+            fees: 1_500.0,
+            points: 1.0,
+            prepayment_penalty: PrepaymentPenalty::None,
+        },
+        LoanOffer {
+            amount: 300_000.0,
+            annual_rate: 0.05875,
+            years: 15.0,
+            fees: 2_500.0,
+            points: 0.0,
+            prepayment_penalty: PrepaymentPenalty::None,
+        },
+    ]
+}
+
+/// An offer as edited. The penalty's kind, amount, and years are kept apart so switching kinds
+/// keeps what was typed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct OfferRow {
+    offer: LoanOffer,
+    penalty_kind: &'static str,
+    /// Dollars for a fixed fee, otherwise a percent
+    penalty_amount: f64,
+    penalty_years: f64,
+}
+
+impl OfferRow {
+    fn new(offer: LoanOffer) -> Self {
+        let (penalty_kind, penalty_amount, penalty_years) = match offer.prepayment_penalty {
+            PrepaymentPenalty::None => ("none", 0.0, 3.0),
+            PrepaymentPenalty::FixedFee { fee, years } => ("fixed", fee, years),
+            PrepaymentPenalty::PercentOfBalance { rate, years } => ("percent", rate * 100.0, years),
+            PrepaymentPenalty::StepDown {
+                initial_rate,
+                years,
+            } => ("step", initial_rate * 100.0, years),
+        };
+        OfferRow {
+            offer,
+            penalty_kind,
+            penalty_amount,
+            penalty_years,
+        }
+    }
+
+    /// The offer with the penalty chosen
+    fn offer(&self) -> LoanOffer {
+        let (amount, years) = (self.penalty_amount, self.penalty_years);
+        let prepayment_penalty = match self.penalty_kind {
+            "fixed" => PrepaymentPenalty::FixedFee { fee: amount, years },
+            "percent" => PrepaymentPenalty::PercentOfBalance {
+                rate: amount / 100.0,
+                years,
+            },
+            "step" => PrepaymentPenalty::StepDown {
+                initial_rate: amount / 100.0,
+                years,
+            },
+            _ => PrepaymentPenalty::None,
+        };
+        LoanOffer {
+            prepayment_penalty,
+            ..self.offer
+        }
+    }
+}
+
+/// Parses a table cell, accepting zero (e.g., no points) but not negatives
+fn parse_cell(text: &str) -> Option<f64> {
+    text.replace(",", "")
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite() && *value >= 0.0)
+}
+
+/// Id of an offer cell, which the error summary links to
+fn cell_id(row_id: u64, column: usize) -> String {
+    format!("offer-{}-{}", row_id, column)
+}
+
+/// Editable number cell; `on_value` is called with every valid entry, `on_valid` with whether
+/// each entry is valid
+#[component]
+fn OfferCell(
+    id: String,
+    label: String,
+    initial: f64,
+    on_value: EventHandler<f64>,
+    on_valid: EventHandler<bool>,
+) -> Element {
+    let mut text = use_signal(|| format!("{}", initial));
+    let valid = parse_cell(&text()).is_some();
+
+    rsx! {
+        td { style: "padding: 2px 5px;",
+            input {
+                id,
+                value: "{text}",
+                "aria-label": "{label}",
+                "aria-invalid": "{!valid}",
+                style: if valid { "padding: 4px 6px; width: 90px; border-radius: 4px; font-family: monospace; border: 1px solid #ccc;" } else { "padding: 4px 6px; width: 90px; border-radius: 4px; font-family: monospace; border: 2px solid #ff0000; background-color: #ffe6e6; color: #333;" },
+                oninput: move |event| {
+                    text.set(event.value());
+                    let value = parse_cell(&event.value());
+                    on_valid.call(value.is_some());
+                    if let Some(value) = value {
+                        on_value.call(value);
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+pub fn LoanCompareUI() -> Element {
+    // Each row keeps a stable id so its cells keep their text when other rows are removed
+    let mut offers = use_signal(|| {
+        default_offers()
+            .into_iter()
+            .enumerate()
+            .map(|(i, offer)| (i as u64, OfferRow::new(offer)))
+            .collect::<Vec<_>>()
+    });
+    let mut next_id = use_signal(|| default_offers().len() as u64);
+    // Cells, as (row id, column), whose text isn't a valid number
+    let mut invalid_cells = use_signal(Vec::<(u64, usize)>::new);
+    let apply_link = use_callback(move |link: CalculatorLink| match link {
+        CalculatorLink::ClosingFees(fees) => {
+            // A new offer like the last one, with the estimate's fees; with no room left, it
+            // takes the last one's place
+            let id = *next_id.peek();
+            next_id.set(id + 1);
+            let mut rows = offers.write();
+            let mut row = rows
+                .last()
+                .map(|(_, row)| *row)
+                .unwrap_or(OfferRow::new(default_offers()[0]));
+            row.offer.fees = fees;
+            if rows.len() >= MAX_LOAN_OFFERS {
+                if let Some((replaced, _)) = rows.pop() {
+                    invalid_cells
+                        .write()
+                        .retain(|(row_id, _)| *row_id != replaced);
+                }
+            }
+            rows.push((id, row));
+            true
+        }
+        _ => false,
+    });
+    let received_link = use_link_receiver(apply_link);
+    let start_fresh = use_autosave(
+        "loan_compare",
+        default_offers(),
+        move || {
+            offers()
+                .iter()
+                .map(|(_, row)| row.offer())
+                .collect::<Vec<_>>()
+        },
+        use_callback(move |saved: Vec<LoanOffer>| {
+            // New ids remount the rows so their cells show the restored values
+            let first_id = *next_id.peek();
+            next_id.set(first_id + saved.len() as u64);
+            invalid_cells.set(vec![]);
+            offers.set(
+                saved
+                    .into_iter()
+                    .take(MAX_LOAN_OFFERS)
+                    .enumerate()
+                    .map(|(i, offer)| (first_id + i as u64, OfferRow::new(offer)))
+                    .collect(),
+            );
+            if let Some(link) = received_link() {
+                apply_link.call(link);
+            }
+        }),
+    );
+    use_track_calculator("loan_compare");
+
+    let mut update = move |id: u64, change: fn(&mut OfferRow, f64), value: f64| {
+        if let Some((_, row)) = offers.write().iter_mut().find(|(row_id, _)| *row_id == id) {
+            change(row, value);
+        }
+    };
+    let mut set_valid = move |cell: (u64, usize), valid: bool| {
+        let mut invalid = invalid_cells.write();
+        invalid.retain(|invalid_cell| *invalid_cell != cell);
+        if !valid {
+            invalid.push(cell);
+        }
+    };
+    // Listed in table order, row by row
+    let invalid = invalid_cells();
+    let mut errors = vec![];
+    for (row, (id, _)) in offers().iter().enumerate() {
+        for (column, name) in OFFER_COLUMNS.iter().enumerate() {
+            if invalid.contains(&(*id, column)) {
+                errors.push(FieldError {
+                    field_id: cell_id(*id, column),
+                    message: format!(
+                        "Offer {}, {}: enter a number of zero or more",
+                        row + 1,
+                        name
+                    ),
+                });
+            }
+        }
+    }
+    let costs: Vec<Result<LoanOfferCosts, String>> = offers()
+        .iter()
+        .map(|(_, row)| loan_offer_costs(&row.offer()))
+        .collect();
+    let comparison: Vec<(&str, Vec<Option<String>>, Option<usize>)> = COMPARISON_ROWS
+        .iter()
+        .map(|(label, measure, format)| {
+            let values: Vec<Option<f64>> = costs
+                .iter()
+                .map(|costs| costs.as_ref().ok().map(measure))
+                .collect();
+            let cells = values.iter().map(|value| value.map(format)).collect();
+            (*label, cells, lowest_offer(&values))
+        })
+        .collect();
+    let offer_errors: Vec<(usize, String)> = costs
+        .iter()
+        .enumerate()
+        .filter_map(|(index, costs)| costs.as_ref().err().map(|error| (index + 1, error.clone())))
+        .collect();
+
+    rsx! {
+        hr {}
+        br {}
+
+        ErrorSummary { errors }
+
+        table { style: "color: #333; border-collapse: collapse; margin-bottom: 15px;",
+            thead {
+                tr {
+                    th {}
+                    for column in OFFER_COLUMNS[..5].iter() {
+                        th { scope: "col", style: "text-align: left; padding: 2px 5px;", {*column} }
+                    }
+                    th { scope: "col", style: "text-align: left; padding: 2px 5px;", "Penalty" }
+                    for column in OFFER_COLUMNS[5..].iter() {
+                        th { scope: "col", style: "text-align: left; padding: 2px 5px;", {*column} }
+                    }
+                    th {}
+                }
+            }
+            tbody {
+                for (row , (id , offer_row)) in offers().into_iter().enumerate() {
+                    tr { key: "{id}",
+                        th { scope: "row", style: "text-align: left; padding: 2px 5px;",
+                            "Offer {row + 1}"
+                        }
+                        OfferCell {
+                            id: cell_id(id, 0),
+                            label: format!("Offer {} {}", row + 1, OFFER_COLUMNS[0]),
+                            initial: offer_row.offer.amount,
+                            on_value: move |v| update(id, |r, v| r.offer.amount = v, v),
+                            on_valid: move |valid| set_valid((id, 0), valid),
+                        }
+                        OfferCell {
+                            id: cell_id(id, 1),
+                            label: format!("Offer {} {}", row + 1, OFFER_COLUMNS[1]),
+                            initial: offer_row.offer.annual_rate * 100.0,
+                            on_value: move |v: f64| update(id, |r, v| r.offer.annual_rate = v / 100.0, v),
+                            on_valid: move |valid| set_valid((id, 1), valid),
+                        }
+                        OfferCell {
+                            id: cell_id(id, 2),
+                            label: format!("Offer {} {}", row + 1, OFFER_COLUMNS[2]),
+                            initial: offer_row.offer.years,
+                            on_value: move |v| update(id, |r, v| r.offer.years = v, v),
+                            on_valid: move |valid| set_valid((id, 2), valid),
+                        }
+                        OfferCell {
+                            id: cell_id(id, 3),
+                            label: format!("Offer {} {}", row + 1, OFFER_COLUMNS[3]),
+                            initial: offer_row.offer.fees,
+                            on_value: move |v| update(id, |r, v| r.offer.fees = v, v),
+                            on_valid: move |valid| set_valid((id, 3), valid),
+                        }
+                        OfferCell {
+                            id: cell_id(id, 4),
+                            label: format!("Offer {} {}", row + 1, OFFER_COLUMNS[4]),
+                            initial: offer_row.offer.points,
+                            on_value: move |v| update(id, |r, v| r.offer.points = v, v),
+                            on_valid: move |valid| set_valid((id, 4), valid),
+                        }
+                        td { style: "padding: 2px 5px;",
+                            select {
+                                "aria-label": "Offer {row + 1} prepayment penalty",
+                                style: "border: 1px solid #ccc; background: gray; padding: 4px 6px; border-radius: 4px; font-family: monospace;",
+                                onchange: move |event| {
+                                    let value = event.value();
+                                    if let Some(&(kind, _)) = PENALTY_OPTIONS
+                                        .iter()
+                                        .find(|(kind, _)| *kind == value.as_str())
+                                    {
+                                        if let Some((_, row)) = offers
+                                            .write()
+                                            .iter_mut()
+                                            .find(|(row_id, _)| *row_id == id)
+                                        {
+                                            row.penalty_kind = kind;
+                                        }
+                                    }
+                                },
+                                for (kind , display) in PENALTY_OPTIONS.iter() {
+                                    option {
+                                        value: *kind,
+                                        selected: offer_row.penalty_kind == *kind,
+                                        {*display}
+                                    }
+                                }
+                            }
+                        }
+                        OfferCell {
+                            id: cell_id(id, 5),
+                            label: format!("Offer {} {}", row + 1, OFFER_COLUMNS[5]),
+                            initial: offer_row.penalty_amount,
+                            on_value: move |v| update(id, |r, v| r.penalty_amount = v, v),
+                            on_valid: move |valid| set_valid((id, 5), valid),
+                        }
+                        OfferCell {
+                            id: cell_id(id, 6),
+                            label: format!("Offer {} {}", row + 1, OFFER_COLUMNS[6]),
+                            initial: offer_row.penalty_years,
+                            on_value: move |v| update(id, |r, v| r.penalty_years = v, v),
+                            on_valid: move |valid| set_valid((id, 6), valid),
+                        }
+                        td {
+                            button {
+                                style: "padding: 2px 8px; border-radius: 4px;",
+                                disabled: offers().len() <= 1,
+                                onclick: move |_| {
+                                    offers.write().retain(|(row_id, _)| *row_id != id);
+                                    invalid_cells.write().retain(|(row_id, _)| *row_id != id);
+                                },
+                                "Remove"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        button {
+            style: "padding: 6px 12px; border-radius: 4px; margin-bottom: 15px;",
+            disabled: offers().len() >= MAX_LOAN_OFFERS,
+            onclick: move |_| {
+                let id = next_id();
+                next_id.set(id + 1);
+                // Start from the last offer, the likeliest to be close to the next one
+                let last = offers.peek().last().map(|(_, row)| *row);
+                offers.write().push((id, last.unwrap_or(OfferRow::new(default_offers()[0]))));
+            },
+            "Add Offer"
+        }
+
+        table {
+            id: "LoanCompareCalculation",
+            style: "color: #333; border-collapse: collapse; font-size: 14px; margin-bottom: 15px;",
+            caption { style: "text-align: left; font-weight: bold; margin-bottom: 5px;",
+                "The lowest figure in each row is highlighted*"
+            }
+            thead {
+                tr {
+                    th {}
+                    for row in 0..offers().len() {
+                        th { scope: "col", style: CELL_STYLE, "Offer {row + 1}" }
+                    }
+                }
+            }
+            tbody {
+                for (label , cells , best) in comparison.into_iter() {
+                    tr { key: "{label}",
+                        th { scope: "row", style: "text-align: left; padding: 2px 8px;", "{label}" }
+                        for (index , cell) in cells.into_iter().enumerate() {
+                            td {
+                                style: if best == Some(index) { BEST_CELL_STYLE } else { CELL_STYLE },
+                                {cell.unwrap_or_else(|| "—".to_string())}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        for (offer , error) in offer_errors.into_iter() {
+            div { style: "margin-bottom: 5px; font-size: 14px; color: #ff0000;",
+                "Offer {offer}: {error}"
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "loan_compare" }
+        AssumptionsFooter { calculator: "loan_compare" }
+        div { style: "font-size: 10px",
+            p {
+                "*APR counts fees and points as paid up front. The 5-year cost is fees and points, the interest paid in the first {COST_HORIZON_MONTHS} months, and any prepayment penalty for paying the loan off then."
+            }
+            br {}
+            br {}
+        }
+    }
+}
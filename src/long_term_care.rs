@@ -0,0 +1,237 @@
+use serde::{Deserialize, Serialize};
+
+/// Oldest age the projection runs to
+pub const FINAL_AGE: f64 = 110.0;
+
+/// Yearly chances, from an age on, of dying and of starting to need long-term care
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CareRate {
+    /// First age the rates apply at, until the next band's age
+    pub from_age: f64,
+    /// Chance of dying within the year as a decimal
+    pub mortality: f64,
+    /// Chance of starting a care episode within the year as a decimal
+    pub incidence: f64,
+}
+
+/// Simplified rates for planning, loosely following published unisex mortality and care
+/// incidence studies. Edit them here, or in the calculator's table, to model a family history
+/// or a more recent study.
+pub const DEFAULT_CARE_RATES: &[CareRate] = &[
+    CareRate {
+        from_age: 60.0,
+        mortality: 0.008,
+        incidence: 0.004,
+    },
+    CareRate {
+        from_age: 65.0,
+        mortality: 0.012,
+        incidence: 0.008,
+    },
+    CareRate {
+        from_age: 70.0,
+        mortality: 0.019,
+        incidence: 0.013,
+    },
+    CareRate {
+        from_age: 75.0,
+        mortality: 0.030,
+        incidence: 0.023,
+    },
+    CareRate {
+        from_age: 80.0,
+        mortality: 0.050,
+        incidence: 0.040,
+    },
+    CareRate {
+        from_age: 85.0,
+        mortality: 0.085,
+        incidence: 0.065,
+    },
+    CareRate {
+        from_age: 90.0,
+        mortality: 0.145,
+        incidence: 0.095,
+    },
+    CareRate {
+        from_age: 95.0,
+        mortality: 0.240,
+        incidence: 0.120,
+    },
+    CareRate {
+        from_age: 100.0,
+        mortality: 0.350,
+        incidence: 0.140,
+    },
+];
+
+/// Inputs to the long-term care planner. Dollar amounts are in today's dollars.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LongTermCareInputs {
+    pub current_age: f64,
+    /// Yearly cost of care, e.g., a nursing home or full-time home care
+    pub annual_cost: f64,
+    /// Yearly growth of care costs as a decimal
+    pub cost_growth: f64,
+    /// Years a care episode lasts
+    pub care_years: f64,
+    /// Yearly insurance premium, paid until care starts
+    pub annual_premium: f64,
+    /// Most the policy pays in a year of care
+    pub annual_benefit: f64,
+    /// Years of care the policy pays for
+    pub benefit_years: f64,
+    /// Yearly growth of the benefit as a decimal; 0 without inflation protection
+    pub inflation_protection: f64,
+    /// Rate future amounts are discounted at as a decimal
+    pub discount_rate: f64,
+    /// Mortality and care incidence by age, youngest band first
+    pub rates: Vec<CareRate>,
+}
+
+/// Chances and expected amounts in one year
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CareYear {
+    /// Age at the start of the year
+    pub age: f64,
+    /// Chance of being alive and not yet needing care at the start of the year
+    pub healthy: f64,
+    /// Chance of being in a care episode during the year
+    pub in_care: f64,
+    /// Probability-weighted cost of care
+    pub expected_cost: f64,
+    /// Probability-weighted premium
+    pub expected_premium: f64,
+    /// Probability-weighted insurance benefit
+    pub expected_benefit: f64,
+}
+
+/// Expected long-term care costs and insurance, year by year and in present value
+#[derive(Debug, Clone, PartialEq)]
+pub struct CareProjection {
+    pub years: Vec<CareYear>,
+    /// Chance of ever needing care
+    pub lifetime_probability: f64,
+    pub pv_costs: f64,
+    pub pv_premiums: f64,
+    pub pv_benefits: f64,
+    /// Present value of the costs the policy doesn't pay
+    pub pv_uncovered: f64,
+}
+
+/// The rates that apply at `age`: the last band starting at or before it, or the first band
+fn rates_at(rates: &[CareRate], age: f64) -> CareRate {
+    rates
+        .iter()
+        .rev()
+        .find(|rate| rate.from_age <= age)
+        .copied()
+        .unwrap_or(rates[0])
+}
+
+// Projects probability-weighted long-term care costs and insurance premiums and benefits from the
+// current age to 110, and their present values.
+///
+/// # Parameters:
+/// - `inputs`: Age, care cost (C) and its growth (g), care length (D), premium (P), yearly
+///   benefit (B), benefit years (N), inflation protection (p), discount rate (r), and rates by
+///   age: mortality (q) and care incidence (i)
+///
+/// # Formula:
+/// healthy_0 = 1; healthy_(t+1) = healthy_t * (1 - q_t - i_t)
+/// starts_t = healthy_t * i_t, each starting one care episode of D years
+/// expected cost_t = C * (1 + g)^t * Σ starts_s for episodes under way (t - s < D)
+/// expected benefit_t = min(B * (1 + p)^t, C * (1 + g)^t) * Σ starts_s for t - s < min(D, N)
+/// expected premium_t = P * healthy_t
+/// PV = Σ amount_t / (1 + r)^t, with everything paid at the start of the year
+///
+/// # Returns:
+/// Each year's chances and expected amounts, the lifetime chance of needing care, and the
+/// present values. An error if an entry is negative, a rate is over 100%, or a band's mortality
+/// and incidence add to more than 100%.
+pub fn project_long_term_care(inputs: &LongTermCareInputs) -> Result<CareProjection, String> {
+    let entries = [
+        inputs.current_age,
+        inputs.annual_cost,
+        inputs.care_years,
+        inputs.annual_premium,
+        inputs.annual_benefit,
+        inputs.benefit_years,
+    ];
+    if entries.iter().any(|entry| entry.is_nan() || *entry < 0.0) {
+        return Err("Ages, costs, premiums, benefits, and years can't be negative".to_string());
+    }
+    if inputs.current_age >= FINAL_AGE {
+        return Err(format!("The current age must be under {}", FINAL_AGE));
+    }
+    let growth_rates = [
+        inputs.cost_growth,
+        inputs.inflation_protection,
+        inputs.discount_rate,
+    ];
+    if growth_rates
+        .iter()
+        .any(|rate| rate.is_nan() || *rate <= -1.0)
+    {
+        return Err("Rates must be greater than -100%".to_string());
+    }
+    if inputs.rates.is_empty() {
+        return Err("Enter at least one age band of rates".to_string());
+    }
+    for rate in inputs.rates.iter() {
+        let chances = [rate.mortality, rate.incidence];
+        if chances.iter().any(|chance| !(0.0..=1.0).contains(chance))
+            || rate.mortality + rate.incidence > 1.0
+        {
+            return Err(format!(
+                "The rates from age {} must be 0% to 100% and add to 100% or less",
+                rate.from_age
+            ));
+        }
+    }
+
+    let n_years = (FINAL_AGE - inputs.current_age).ceil() as usize;
+    let care_years = (inputs.care_years.round() as usize).max(1);
+    let paid_years = care_years.min(inputs.benefit_years.round() as usize);
+    let mut healthy = 1.0;
+    let mut starts: Vec<f64> = Vec::with_capacity(n_years);
+    let mut projection = CareProjection {
+        years: Vec::with_capacity(n_years),
+        lifetime_probability: 0.0,
+        pv_costs: 0.0,
+        pv_premiums: 0.0,
+        pv_benefits: 0.0,
+        pv_uncovered: 0.0,
+    };
+    for t in 0..n_years {
+        let age = inputs.current_age + t as f64;
+        let rate = rates_at(&inputs.rates, age);
+        starts.push(healthy * rate.incidence);
+
+        // Chance an episode started within the last `years` years
+        let under_way = |years: usize| starts[t + 1 - years.min(t + 1)..].iter().sum::<f64>();
+        let in_care = under_way(care_years);
+        let cost = inputs.annual_cost * (1.0 + inputs.cost_growth).powi(t as i32);
+        let benefit =
+            (inputs.annual_benefit * (1.0 + inputs.inflation_protection).powi(t as i32)).min(cost);
+        let year = CareYear {
+            age,
+            healthy,
+            in_care,
+            expected_cost: cost * in_care,
+            expected_premium: inputs.annual_premium * healthy,
+            expected_benefit: benefit * under_way(paid_years),
+        };
+
+        let discount = (1.0 + inputs.discount_rate).powi(t as i32);
+        projection.pv_costs += year.expected_cost / discount;
+        projection.pv_premiums += year.expected_premium / discount;
+        projection.pv_benefits += year.expected_benefit / discount;
+        projection.years.push(year);
+        healthy *= 1.0 - rate.mortality - rate.incidence;
+    }
+    projection.lifetime_probability = starts.iter().sum();
+    projection.pv_uncovered = projection.pv_costs - projection.pv_benefits;
+
+    Ok(projection)
+}
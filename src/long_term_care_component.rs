@@ -0,0 +1,425 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::chart::{ChartSeries, LineChart};
+use crate::error_summary_component::{ErrorSummary, FieldError};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::long_term_care::{
+    project_long_term_care, CareRate, CareYear, LongTermCareInputs, DEFAULT_CARE_RATES,
+};
+use crate::numeric_input_component::NumericInput;
+use dioxus::prelude::*;
+
+/// A 60-year-old weighing a policy paying $72,000 a year for three years against a $100,000 a
+/// year cost of care
+fn default_inputs() -> LongTermCareInputs {
+    LongTermCareInputs {
+        current_age: 60.0,
+        annual_cost: 100_000.0,
+        cost_growth: 0.03,
+        care_years: 3.0,
+        annual_premium: 3_000.0,
+        annual_benefit: 72_000.0,
+        benefit_years: 3.0,
+        inflation_protection: 0.03,
+        discount_rate: 0.04,
+        rates: DEFAULT_CARE_RATES.to_vec(),
+    }
+}
+
+/// Editable columns of the rates table
+const RATE_COLUMNS: &[&str] = &["Mortality (%/yr)", "Care incidence (%/yr)"];
+
+const COST_COLOR: &str = "#c0392b";
+const BENEFIT_COLOR: &str = "#229954";
+const PREMIUM_COLOR: &str = "#2471a3";
+
+/// Parses a rate cell entered in percent, from 0 to 100
+fn parse_percent(text: &str) -> Option<f64> {
+    text.trim()
+        .trim_end_matches('%')
+        .parse::<f64>()
+        .ok()
+        .filter(|value| (0.0..=100.0).contains(value))
+}
+
+/// Id of a rate cell, which the error summary links to
+fn cell_id(row: usize, column: usize) -> String {
+    format!("care-rate-{}-{}", row, column)
+}
+
+/// Editable percent cell; `on_value` is called with every valid entry, `on_valid` with whether
+/// each entry is valid
+#[component]
+fn RateCell(
+    id: String,
+    label: String,
+    initial: f64,
+    on_value: EventHandler<f64>,
+    on_valid: EventHandler<bool>,
+) -> Element {
+    let mut text = use_signal(|| format!("{}", initial));
+    let valid = parse_percent(&text()).is_some();
+
+    rsx! {
+        td { style: "padding: 2px 5px;",
+            input {
+                id,
+                value: "{text}",
+                "aria-label": "{label}",
+                "aria-invalid": "{!valid}",
+                style: if valid { "padding: 4px 6px; width: 90px; border-radius: 4px; font-family: monospace; border: 1px solid #ccc;" } else { "padding: 4px 6px; width: 90px; border-radius: 4px; font-family: monospace; border: 2px solid #ff0000; background-color: #ffe6e6; color: #333;" },
+                oninput: move |event| {
+                    text.set(event.value());
+                    let value = parse_percent(&event.value());
+                    on_valid.call(value.is_some());
+                    if let Some(value) = value {
+                        on_value.call(value);
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+pub fn LongTermCareUI() -> Element {
+    let mut age_signal = use_signal(|| 60.0 as f64);
+    let mut age_input = use_signal(|| "60".to_string());
+    let age_input_valid = use_signal(|| true);
+    let mut cost_signal = use_signal(|| 100_000.0 as f64);
+    let mut cost_input = use_signal(|| "100000".to_string());
+    let cost_input_valid = use_signal(|| true);
+    // Growth, protection, and discount rates are entered in percent
+    let mut cost_growth_signal = use_signal(|| 3.0 as f64);
+    let mut cost_growth_input = use_signal(|| "3.0".to_string());
+    let cost_growth_input_valid = use_signal(|| true);
+    let mut care_years_signal = use_signal(|| 3.0 as f64);
+    let mut care_years_input = use_signal(|| "3".to_string());
+    let care_years_input_valid = use_signal(|| true);
+    let mut premium_signal = use_signal(|| 3_000.0 as f64);
+    let mut premium_input = use_signal(|| "3000".to_string());
+    let premium_input_valid = use_signal(|| true);
+    let mut benefit_signal = use_signal(|| 72_000.0 as f64);
+    let mut benefit_input = use_signal(|| "72000".to_string());
+    let benefit_input_valid = use_signal(|| true);
+    let mut benefit_years_signal = use_signal(|| 3.0 as f64);
+    let mut benefit_years_input = use_signal(|| "3".to_string());
+    let benefit_years_input_valid = use_signal(|| true);
+    let mut protection_signal = use_signal(|| 3.0 as f64);
+    let mut protection_input = use_signal(|| "3.0".to_string());
+    let protection_input_valid = use_signal(|| true);
+    let mut discount_signal = use_signal(|| 4.0 as f64);
+    let mut discount_input = use_signal(|| "4.0".to_string());
+    let discount_input_valid = use_signal(|| true);
+    let mut rates = use_signal(|| DEFAULT_CARE_RATES.to_vec());
+    // Bumped on restore so the rate cells remount and show the restored values
+    let mut rates_generation = use_signal(|| 0u64);
+    // Cells, as (row, column), whose text isn't a valid percent
+    let mut invalid_cells = use_signal(Vec::<(usize, usize)>::new);
+    let hover_index = use_signal(|| None);
+
+    let inputs = move || LongTermCareInputs {
+        current_age: age_signal(),
+        annual_cost: cost_signal(),
+        cost_growth: cost_growth_signal() / 100.0,
+        care_years: care_years_signal(),
+        annual_premium: premium_signal(),
+        annual_benefit: benefit_signal(),
+        benefit_years: benefit_years_signal(),
+        inflation_protection: protection_signal() / 100.0,
+        discount_rate: discount_signal() / 100.0,
+        rates: rates(),
+    };
+    let start_fresh = use_autosave(
+        "long_term_care",
+        default_inputs(),
+        inputs,
+        use_callback(move |inputs: LongTermCareInputs| {
+            age_signal.set(inputs.current_age);
+            age_input.set(format!("{}", inputs.current_age));
+            cost_signal.set(inputs.annual_cost);
+            cost_input.set(format!("{}", inputs.annual_cost));
+            cost_growth_signal.set(inputs.cost_growth * 100.0);
+            cost_growth_input.set(format!("{}", inputs.cost_growth * 100.0));
+            care_years_signal.set(inputs.care_years);
+            care_years_input.set(format!("{}", inputs.care_years));
+            premium_signal.set(inputs.annual_premium);
+            premium_input.set(format!("{}", inputs.annual_premium));
+            benefit_signal.set(inputs.annual_benefit);
+            benefit_input.set(format!("{}", inputs.annual_benefit));
+            benefit_years_signal.set(inputs.benefit_years);
+            benefit_years_input.set(format!("{}", inputs.benefit_years));
+            protection_signal.set(inputs.inflation_protection * 100.0);
+            protection_input.set(format!("{}", inputs.inflation_protection * 100.0));
+            discount_signal.set(inputs.discount_rate * 100.0);
+            discount_input.set(format!("{}", inputs.discount_rate * 100.0));
+            rates.set(inputs.rates);
+            invalid_cells.set(vec![]);
+            rates_generation += 1;
+        }),
+    );
+    use_track_calculator("long_term_care");
+    use_track_input_range("long_term_care", "annual_cost", cost_signal, amount_bucket);
+
+    let mut update = move |row: usize, change: fn(&mut CareRate, f64), value: f64| {
+        if let Some(rate) = rates.write().get_mut(row) {
+            change(rate, value);
+        }
+    };
+    let mut set_valid = move |cell: (usize, usize), valid: bool| {
+        let mut invalid = invalid_cells.write();
+        invalid.retain(|invalid_cell| *invalid_cell != cell);
+        if !valid {
+            invalid.push(cell);
+        }
+    };
+    let invalid = invalid_cells();
+    let mut errors = vec![];
+    for (row, rate) in rates().iter().enumerate() {
+        for (column, name) in RATE_COLUMNS.iter().enumerate() {
+            if invalid.contains(&(row, column)) {
+                errors.push(FieldError {
+                    field_id: cell_id(row, column),
+                    message: format!("From age {}, {}: enter 0 to 100", rate.from_age, name),
+                });
+            }
+        }
+    }
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Current Age:".to_string(),
+            placeholder: "Enter your age today (e.g., 60)".to_string(),
+            input_signal: age_input,
+            value_signal: age_signal,
+            valid_signal: age_input_valid,
+            field_name: "Current age".to_string(),
+            css_prefix: "age".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(0),
+        }
+        NumericInput {
+            label: "Cost of Care ($/yr):".to_string(),
+            placeholder: "Enter today's yearly cost of the care you'd want (e.g., 100000)".to_string(),
+            input_signal: cost_input,
+            value_signal: cost_signal,
+            valid_signal: cost_input_valid,
+            field_name: "Cost of care".to_string(),
+            css_prefix: "cost".to_string(),
+            step: Some(5000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Care Cost Growth (%/yr):".to_string(),
+            placeholder: "Enter how fast care costs rise (e.g., 3.0)".to_string(),
+            input_signal: cost_growth_input,
+            value_signal: cost_growth_signal,
+            valid_signal: cost_growth_input_valid,
+            field_name: "Care cost growth".to_string(),
+            css_prefix: "cost-growth".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+        NumericInput {
+            label: "Years of Care:".to_string(),
+            placeholder: "Enter how long a stay in care lasts (e.g., 3)".to_string(),
+            input_signal: care_years_input,
+            value_signal: care_years_signal,
+            valid_signal: care_years_input_valid,
+            field_name: "Years of care".to_string(),
+            css_prefix: "care-years".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(0),
+        }
+        NumericInput {
+            label: "Premium ($/yr):".to_string(),
+            placeholder: "Enter the policy's yearly premium, or 0 to go without (e.g., 3000)".to_string(),
+            input_signal: premium_input,
+            value_signal: premium_signal,
+            valid_signal: premium_input_valid,
+            field_name: "Premium".to_string(),
+            css_prefix: "premium".to_string(),
+            allow_negative: true,
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Benefit ($/yr):".to_string(),
+            placeholder: "Enter the most the policy pays in a year of care (e.g., 72000)".to_string(),
+            input_signal: benefit_input,
+            value_signal: benefit_signal,
+            valid_signal: benefit_input_valid,
+            field_name: "Benefit".to_string(),
+            css_prefix: "benefit".to_string(),
+            allow_negative: true,
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Benefit Years:".to_string(),
+            placeholder: "Enter how many years of care the policy pays for (e.g., 3)".to_string(),
+            input_signal: benefit_years_input,
+            value_signal: benefit_years_signal,
+            valid_signal: benefit_years_input_valid,
+            field_name: "Benefit years".to_string(),
+            css_prefix: "benefit-years".to_string(),
+            allow_negative: true,
+            step: Some(1.0),
+            max_decimals: Some(0),
+        }
+        NumericInput {
+            label: "Inflation Protection (%/yr):".to_string(),
+            placeholder: "Enter the benefit's yearly increase, or 0 without a rider (e.g., 3.0)".to_string(),
+            input_signal: protection_input,
+            value_signal: protection_signal,
+            valid_signal: protection_input_valid,
+            field_name: "Inflation protection".to_string(),
+            css_prefix: "protection".to_string(),
+            allow_negative: true,
+            step: Some(0.5),
+        }
+        NumericInput {
+            label: "Discount Rate (%/yr):".to_string(),
+            placeholder: "Enter the return your money could earn instead (e.g., 4.0)".to_string(),
+            input_signal: discount_input,
+            value_signal: discount_signal,
+            valid_signal: discount_input_valid,
+            field_name: "Discount rate".to_string(),
+            css_prefix: "discount".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+
+        details { style: "color: #333; margin-bottom: 15px;",
+            summary { style: "cursor: pointer; font-weight: bold;", "Mortality and care rates by age" }
+            p { style: "font-size: 12px;",
+                "Yearly chances of dying, and of starting to need care, from each age on. Adjust them for your health or family history."
+            }
+            ErrorSummary { errors }
+            table { style: "color: #333; border-collapse: collapse;",
+                thead {
+                    tr {
+                        th { style: "text-align: left; padding: 2px 5px;", "From age" }
+                        for column in RATE_COLUMNS.iter() {
+                            th { style: "text-align: left; padding: 2px 5px;", {*column} }
+                        }
+                    }
+                }
+                tbody {
+                    for (row , rate) in rates().into_iter().enumerate() {
+                        tr { key: "{rates_generation}-{row}",
+                            th { scope: "row", style: "text-align: left; padding: 2px 5px;",
+                                "{rate.from_age}"
+                            }
+                            RateCell {
+                                id: cell_id(row, 0),
+                                label: format!("From age {} {}", rate.from_age, RATE_COLUMNS[0]),
+                                initial: rate.mortality * 100.0,
+                                on_value: move |v: f64| update(row, |r, v| r.mortality = v / 100.0, v),
+                                on_valid: move |valid| set_valid((row, 0), valid),
+                            }
+                            RateCell {
+                                id: cell_id(row, 1),
+                                label: format!("From age {} {}", rate.from_age, RATE_COLUMNS[1]),
+                                initial: rate.incidence * 100.0,
+                                on_value: move |v: f64| update(row, |r, v| r.incidence = v / 100.0, v),
+                                on_valid: move |valid| set_valid((row, 1), valid),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        {
+            match project_long_term_care(&inputs()) {
+                Ok(projection) => {
+                    let first_age = projection.years[0].age;
+                    // Round before taking the difference so it matches the amounts shown
+                    let cents = |value: f64| (value * 100.0).round() / 100.0;
+                    let net = cents(projection.pv_benefits) - cents(projection.pv_premiums);
+                    let verdict = if net >= 0.0 {
+                        format!(
+                            "The expected benefits outweigh the premiums by ${}.",
+                            format_dollars(net),
+                        )
+                    } else {
+                        format!(
+                            "The premiums outweigh the expected benefits by ${}.",
+                            format_dollars(-net),
+                        )
+                    };
+                    let amounts = |value: fn(&CareYear) -> f64| {
+                        projection.years.iter().map(value).collect::<Vec<f64>>()
+                    };
+                    let series = vec![
+                        ChartSeries {
+                            label: "Cost of care".to_string(),
+                            color: COST_COLOR.to_string(),
+                            values: amounts(|year| year.expected_cost),
+                        },
+                        ChartSeries {
+                            label: "Insurance benefits".to_string(),
+                            color: BENEFIT_COLOR.to_string(),
+                            values: amounts(|year| year.expected_benefit),
+                        },
+                        ChartSeries {
+                            label: "Premiums".to_string(),
+                            color: PREMIUM_COLOR.to_string(),
+                            values: amounts(|year| year.expected_premium),
+                        },
+                    ];
+                    rsx! {
+                        div {
+                            id: "LongTermCareCalculation",
+                            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                            "Expected cost of care, in present value: ${format_dollars(cents(projection.pv_costs))}*"
+                        }
+                        div {
+                            id: "LongTermCareSummary",
+                            style: "margin-bottom: 15px; font-size: 14px;",
+                            p {
+                                "Chance of ever needing care: {projection.lifetime_probability * 100.0:.1}%."
+                            }
+                            p {
+                                "Insurance: ${format_dollars(cents(projection.pv_benefits))} of expected benefits for ${format_dollars(cents(projection.pv_premiums))} of expected premiums, leaving ${format_dollars(cents(projection.pv_uncovered))} of care to pay yourself."
+                            }
+                            p { "{verdict}" }
+                        }
+                        LineChart {
+                            title: "Expected yearly amounts".to_string(),
+                            series,
+                            x_start: first_age,
+                            x_label: "Age".to_string(),
+                            hover_index,
+                            y_max: None,
+                        }
+                    }
+                }
+                Err(error) => rsx! {
+                    div {
+                        id: "LongTermCareCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "long_term_care" }
+        AssumptionsFooter { calculator: "long_term_care" }
+        div { style: "font-size: 10px",
+            p {
+                "*Each year's cost of care weighted by the chance of being in care then, discounted to today. Actual costs are all or nothing: most people need little or no paid care, and a few need years of it."
+            }
+            br {}
+            br {}
+        }
+    }
+}
@@ -3,13 +3,216 @@ use dioxus::prelude::*;
 use tracing::debug;
 use tracing_subscriber;
 
+mod account_types;
+#[cfg(feature = "simulation")]
+mod allocation_component;
+#[cfg(feature = "loans")]
+mod amortization;
+#[cfg(feature = "loans")]
+mod amortization_component;
+mod analytics;
+mod annuity;
+mod annuity_component;
+#[cfg(feature = "server")]
+// `authorize` is for the programmatic endpoints, which don't exist yet
+#[allow(dead_code)]
+mod api_keys;
+mod apr_apy;
+mod apr_apy_component;
+mod assumptions_component;
+mod audit;
+mod audit_component;
+mod autosave;
 mod blog;
+#[cfg(feature = "bonds")]
+mod bond;
+#[cfg(feature = "bonds")]
+mod bond_ladder_component;
+#[cfg(feature = "bonds")]
+mod bond_price_component;
+mod bonus_component;
+#[cfg(feature = "bonds")]
+mod break_even_component;
+mod break_even_units_component;
+mod build_info;
+mod business_math;
+mod calculators;
+mod capm_component;
+#[cfg(feature = "bonds")]
+mod cd;
+#[cfg(feature = "bonds")]
+mod cd_component;
+mod charitable;
+mod charitable_component;
+mod chart;
+#[cfg(feature = "loans")]
+mod closing_costs;
+#[cfg(feature = "loans")]
+mod closing_costs_component;
 mod compounding;
+mod consent_banner_component;
+mod contribution_limits;
+mod data_year;
+mod dcf_component;
+#[cfg(feature = "loans")]
+mod debt_payoff;
+#[cfg(feature = "loans")]
+mod debt_payoff_component;
+mod error_summary_component;
+mod feedback_component;
+mod fin_numeric;
+mod future_value;
 mod future_value_component;
+mod glide_path;
+mod glide_path_component;
+mod glossary;
+mod glossary_component;
+mod healthcare;
+mod inflation_scenarios;
+mod inflation_scenarios_component;
+#[cfg(feature = "loans")]
+mod lease;
+#[cfg(feature = "loans")]
+mod lease_component;
+#[cfg(feature = "loans")]
+mod lease_vs_buy_component;
+mod link;
+#[cfg(feature = "loans")]
+mod loan;
+#[cfg(feature = "loans")]
+mod loan_compare_component;
+mod long_term_care;
+mod long_term_care_component;
+mod markdown;
+mod math_component;
+mod mathml;
+#[cfg(feature = "simulation")]
+mod monte_carlo;
+#[cfg(feature = "simulation")]
+mod monte_carlo_component;
+mod npv;
+mod npv_component;
+mod number_format;
 mod numeric_input_component;
+mod options;
+mod options_component;
+mod pages;
+mod paycheck;
+mod paycheck_component;
+mod perpetuity_component;
+mod present_value;
+mod present_value_component;
+mod rate_shock;
+mod rate_shock_component;
+#[cfg(feature = "bonds")]
+mod rates;
+mod retirement;
+mod retirement_component;
+mod retirement_income;
+mod retirement_income_component;
+mod returns_stats;
+mod returns_stats_component;
+mod saved_scenarios_component;
+#[cfg(feature = "bonds")]
+mod savings_bond;
+#[cfg(feature = "bonds")]
+mod savings_bond_component;
+mod savings_goal_component;
+mod scenario;
+mod scenario_comparison_component;
+mod scenario_report;
+#[cfg(feature = "simulation")]
+mod seed_input_component;
+mod self_employment;
+mod self_employment_component;
+#[cfg(feature = "simulation")]
+mod sequence_risk;
+#[cfg(feature = "simulation")]
+mod sequence_risk_component;
+mod settings_component;
+mod share;
+mod share_component;
+#[cfg(feature = "simulation")]
+mod simulation;
+mod sinking_fund;
+mod sinking_fund_component;
+mod snapshot;
+mod snapshot_component;
+mod spia;
+mod spia_component;
+mod storage;
+mod table_paste;
+mod table_paste_component;
+mod tax;
+mod theme;
+mod tour_component;
+mod verify;
+mod verify_component;
+mod wacc_component;
+mod webhook;
+mod wizard_component;
 
-use blog::Blog;
+#[cfg(feature = "loans")]
+use amortization_component::AmortizationUI;
+use analytics::use_analytics_provider;
+use annuity_component::AnnuityUI;
+use apr_apy_component::AprApyUI;
+use blog::{Blog, BlogIndex};
+#[cfg(feature = "bonds")]
+use bond_ladder_component::BondLadderUI;
+#[cfg(feature = "bonds")]
+use bond_price_component::BondPriceUI;
+use bonus_component::BonusUI;
+#[cfg(feature = "bonds")]
+use break_even_component::BreakEvenInflationUI;
+use break_even_units_component::BreakEvenUnitsUI;
+use calculators::enabled_calculators;
+use capm_component::CapmUI;
+#[cfg(feature = "bonds")]
+use cd_component::CdUI;
+use charitable_component::CharitableUI;
+#[cfg(feature = "loans")]
+use closing_costs_component::ClosingCostsUI;
+use consent_banner_component::ConsentBanner;
+use dcf_component::DcfUI;
+#[cfg(feature = "loans")]
+use debt_payoff_component::DebtPayoffUI;
 use future_value_component::FutureValueUI;
+use glossary_component::GlossaryUI;
+#[cfg(feature = "loans")]
+use lease_component::LeaseUI;
+#[cfg(feature = "loans")]
+use lease_vs_buy_component::LeaseVsBuyUI;
+use link::use_link_provider;
+#[cfg(feature = "loans")]
+use loan_compare_component::LoanCompareUI;
+use long_term_care_component::LongTermCareUI;
+#[cfg(feature = "simulation")]
+use monte_carlo_component::MonteCarloUI;
+use npv_component::NpvUI;
+use number_format::use_number_format_provider;
+use options_component::OptionsUI;
+use pages::{page_description, page_title, structured_data};
+use paycheck_component::PaycheckUI;
+use perpetuity_component::PerpetuityUI;
+use present_value_component::PresentValueUI;
+use retirement_component::RetirementUI;
+use retirement_income_component::RetirementIncomeUI;
+use returns_stats_component::ReturnsStatsUI;
+#[cfg(feature = "bonds")]
+use savings_bond_component::SavingsBondUI;
+use savings_goal_component::SavingsGoalUI;
+use self_employment_component::SelfEmploymentUI;
+#[cfg(feature = "simulation")]
+use sequence_risk_component::SequenceRiskUI;
+use settings_component::SettingsUI;
+use share_component::SharedScenarioUI;
+use sinking_fund_component::SinkingFundUI;
+use spia_component::SpiaUI;
+use theme::{use_theme, use_theme_provider};
+use tour_component::{use_tour_provider, Tour};
+use verify_component::VerifyUI;
+use wacc_component::WaccUI;
 
 #[derive(Debug, Clone, Routable, PartialEq)]
 #[rustfmt::skip]
@@ -19,8 +222,86 @@ pub enum Route { // components in the enum are called and MUST exist.
     //Home {},
     //#[route("/fv-calculator")]
     FutureValueUI  {},
-    #[route("/blog/:id")]
-    Blog { id: i32 },
+    #[route("/present-value")]
+    PresentValueUI {},
+    #[route("/annuity")]
+    AnnuityUI {},
+    #[route("/perpetuity")]
+    PerpetuityUI {},
+    #[route("/apr-apy")]
+    AprApyUI {},
+    #[route("/retirement")]
+    RetirementUI {},
+    #[route("/retirement-income")]
+    RetirementIncomeUI {},
+    #[route("/long-term-care")]
+    LongTermCareUI {},
+    #[route("/annuity-quotes")]
+    SpiaUI {},
+    #[route("/savings-goal")]
+    SavingsGoalUI {},
+    #[route("/sinking-fund")]
+    SinkingFundUI {},
+    #[route("/paycheck")]
+    PaycheckUI {},
+    #[route("/w2-vs-1099")]
+    SelfEmploymentUI {},
+    #[route("/bonus-withholding")]
+    BonusUI {},
+    #[route("/charitable-giving")]
+    CharitableUI {},
+    #[route("/sequence-risk")]
+    SequenceRiskUI {},
+    #[route("/monte-carlo")]
+    MonteCarloUI {},
+    #[route("/break-even-inflation")]
+    BreakEvenInflationUI {},
+    #[route("/bond-ladder")]
+    BondLadderUI {},
+    #[route("/bond-price")]
+    BondPriceUI {},
+    #[route("/savings-bond")]
+    SavingsBondUI {},
+    #[route("/cd")]
+    CdUI {},
+    #[route("/lease")]
+    LeaseUI {},
+    #[route("/lease-vs-buy")]
+    LeaseVsBuyUI {},
+    #[route("/amortization")]
+    AmortizationUI {},
+    #[route("/loan-compare")]
+    LoanCompareUI {},
+    #[route("/closing-costs")]
+    ClosingCostsUI {},
+    #[route("/debt-payoff")]
+    DebtPayoffUI {},
+    #[route("/npv")]
+    NpvUI {},
+    #[route("/dcf")]
+    DcfUI {},
+    #[route("/capm")]
+    CapmUI {},
+    #[route("/wacc")]
+    WaccUI {},
+    #[route("/returns-statistics")]
+    ReturnsStatsUI {},
+    #[route("/options")]
+    OptionsUI {},
+    #[route("/break-even-units")]
+    BreakEvenUnitsUI {},
+    #[route("/settings")]
+    SettingsUI {},
+    #[route("/glossary")]
+    GlossaryUI {},
+    #[route("/blog")]
+    BlogIndex {},
+    #[route("/blog/:slug")]
+    Blog { slug: String },
+    #[route("/shared/:token")]
+    SharedScenarioUI { token: String },
+    #[route("/verify")]
+    VerifyUI {},
 }
 
 const FAVICON: Asset = asset!("/assets/favicon.ico");
@@ -32,11 +313,94 @@ fn main() {
     tracing_subscriber::fmt()
         .with_max_level(tracing::Level::DEBUG)
         .init();
+
+    // With the `server` feature the server renders each page to HTML, with default results, and
+    // the WASM bundle hydrates it. `dx bundle --ssg` pre-renders `static_routes` into the static
+    // directory, so a static host gets the same HTML.
+    #[cfg(feature = "server")]
+    {
+        // `--issue-api-key <label>` prints a new key for the programmatic endpoints and exits
+        let args: Vec<String> = std::env::args().collect();
+        if let Some(position) = args.iter().position(|arg| arg == "--issue-api-key") {
+            let label = args.get(position + 1).map_or("default", String::as_str);
+            let key = api_keys::generate_key(
+                api_keys::os_entropy().expect("Could not read random bytes for the key"),
+            );
+            println!("{}", key);
+            println!(
+                "Add this entry to {} to enable it:\n{}",
+                api_keys::API_KEYS_VAR,
+                api_keys::config_entry(label, &key, api_keys::DEFAULT_REQUESTS_PER_MINUTE)
+            );
+            return;
+        }
+
+        let public_dir = std::env::current_exe()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .join("public");
+        // Served (and bundled by --ssg) from the static directory alongside the pages
+        if let Err(error) = std::fs::write(public_dir.join("sitemap.xml"), pages::sitemap_xml()) {
+            tracing::warn!("Could not write sitemap.xml: {}", error);
+        }
+        dioxus::LaunchBuilder::new()
+            .with_cfg(server_only! {
+                ServeConfig::builder().incremental(
+                    dioxus::fullstack::IncrementalRendererConfig::new()
+                        .static_dir(public_dir.clone())
+                        .clear_cache(false),
+                )
+            })
+            .launch(App);
+    }
+    #[cfg(not(feature = "server"))]
     dioxus::launch(App);
 }
 
+/// The pages `dx bundle --ssg` pre-renders
+#[cfg(feature = "fullstack")]
+#[server(endpoint = "static_routes", output = server_fn::codec::Json)]
+async fn static_routes() -> Result<Vec<String>, ServerFnError> {
+    Ok(pages::page_routes()
+        .iter()
+        .map(ToString::to_string)
+        .collect())
+}
+
+/// Liveness check for uptime monitors and load balancers: GET /healthz answers "ok"
+#[cfg(feature = "fullstack")]
+#[server(
+    prefix = "",
+    endpoint = "healthz",
+    input = server_fn::codec::GetUrl,
+    output = server_fn::codec::Json
+)]
+async fn healthz() -> Result<String, ServerFnError> {
+    Ok("ok".to_string())
+}
+
+/// GET /version: the version, git sha, build time, and features of the running build, so a
+/// deployment can be matched to the commit that produced it
+#[cfg(feature = "fullstack")]
+#[server(
+    prefix = "",
+    endpoint = "version",
+    input = server_fn::codec::GetUrl,
+    output = server_fn::codec::Json
+)]
+async fn version() -> Result<serde_json::Value, ServerFnError> {
+    Ok(serde_json::to_value(build_info::build_info())?)
+}
+
 #[component]
 fn App() -> Element {
+    use_analytics_provider();
+    use_link_provider();
+    use_number_format_provider();
+    use_tour_provider();
+    let theme = use_theme_provider();
+
     rsx! {
         document::Link { rel: "icon", href: FAVICON }
         document::Link { rel: "stylesheet", href: MAIN_CSS }
@@ -47,8 +411,10 @@ fn App() -> Element {
             content: "width=device-width, initial-scale=1.0",
         }
         document::Meta { charset: "utf-8" }
+        style { {theme().css()} }
 
         Router::<Route> {}
+        ConsentBanner {}
     }
 }
 
@@ -71,17 +437,90 @@ fn Home() -> Element {
     }
 }
 
+/// Stands in for a calculator whose cargo feature is off, so `Route` is the same in every build
+#[component]
+fn CalculatorNotIncluded() -> Element {
+    rsx! {
+        hr {}
+        br {}
+        div { "This calculator is not included in this build." }
+    }
+}
+
+/// Defines placeholder pages for calculators whose cargo feature is off
+macro_rules! calculators_not_included {
+    ($($calculator:ident),* $(,)?) => {
+        $(
+            #[component]
+            fn $calculator() -> Element {
+                rsx! {
+                    CalculatorNotIncluded {}
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "simulation"))]
+calculators_not_included!(SequenceRiskUI, MonteCarloUI);
+#[cfg(not(feature = "bonds"))]
+calculators_not_included!(
+    BreakEvenInflationUI,
+    BondLadderUI,
+    BondPriceUI,
+    SavingsBondUI,
+    CdUI
+);
+#[cfg(not(feature = "loans"))]
+calculators_not_included!(
+    LeaseUI,
+    LeaseVsBuyUI,
+    AmortizationUI,
+    LoanCompareUI,
+    ClosingCostsUI,
+    DebtPayoffUI
+);
+
 /// Shared navbar component.
 #[component]
 fn Navbar() -> Element {
+    let theme = use_theme();
+    let route = use_route::<Route>();
+    let title = format!("{} | {}", page_title(&route), theme.brand_name);
+    let description = page_description(&route);
+    let structured_data = structured_data(&route, &theme.brand_name).to_string();
+
     rsx! {
+        document::Title { "{title}" }
+        if !description.is_empty() {
+            document::Meta { name: "description", content: description }
+        }
+        document::Script { r#type: "application/ld+json", {structured_data} }
         div { id: "navbar",
+            if let Some(logo) = theme.logo.clone() {
+                img {
+                    src: logo,
+                    alt: theme.brand_name.clone(),
+                    style: "height: 24px; margin-right: 20px;",
+                }
+            } else {
+                span { style: "font-weight: bold; margin-right: 20px;", {theme.brand_name.clone()} }
+            }
             // Link { to: Route::Home {}, "Home" }
-            Link { to: Route::FutureValueUI {}, "Future Value Calculator" }
-                //Link { to: Route::Blog { id: 1 }, "Blog" }
+            for calculator in enabled_calculators(&theme) {
+                Link { to: calculator.route(), {calculator.name} }
+            }
+            Link { to: Route::BlogIndex {}, "Blog" }
+            Link { to: Route::GlossaryUI {}, "Glossary" }
+            Link { to: Route::SettingsUI {}, "Settings" }
         }
 
         Outlet::<Route> {}
+        Tour {}
+
+        if !theme.footer_text.is_empty() {
+            div { style: "font-size: 12px; margin-top: 20px;", {theme.footer_text.clone()} }
+        }
     }
 }
 
@@ -0,0 +1,109 @@
+use crate::mathml::tex_to_mathml;
+use pulldown_cmark::{html, CowStr, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+/// Average adult reading speed used for reading-time estimates
+const WORDS_PER_MINUTE: usize = 200;
+
+/// A heading in a rendered document, linkable via `#anchor`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TocEntry {
+    /// 1 for `#`, 2 for `##`, ...
+    pub level: usize,
+    pub title: String,
+    pub anchor: String,
+}
+
+/// HTML for a Markdown document plus what's needed for its table of contents and reading time
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedMarkdown {
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+    pub word_count: usize,
+}
+
+impl RenderedMarkdown {
+    /// Estimated reading time in whole minutes (at least one)
+    pub fn reading_time_minutes(&self) -> usize {
+        std::cmp::max(1, self.word_count.div_ceil(WORDS_PER_MINUTE))
+    }
+}
+
+/// Lowercase, hyphen-separated anchor for a heading, e.g., "Why it matters" -> "why-it-matters"
+fn slugify(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn heading_level(level: HeadingLevel) -> usize {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+/// Renders Markdown to HTML, giving every heading a unique anchor id and collecting them into a
+/// table of contents. `$...$` and `$$...$$` math is rendered as MathML.
+pub fn render_markdown(source: &str) -> RenderedMarkdown {
+    let mut events: Vec<Event> = Parser::new_ext(source, Options::all()).collect();
+    let mut toc = Vec::new();
+    let mut word_count = 0;
+
+    for i in 0..events.len() {
+        match &events[i] {
+            Event::Text(text) | Event::Code(text) => {
+                word_count += text.split_whitespace().count();
+            }
+            Event::InlineMath(tex) => {
+                let mathml = tex_to_mathml(tex, false);
+                events[i] = Event::InlineHtml(CowStr::from(mathml));
+            }
+            Event::DisplayMath(tex) => {
+                let mathml = tex_to_mathml(tex, true);
+                events[i] = Event::Html(CowStr::from(mathml));
+            }
+            Event::Start(Tag::Heading { level, .. }) => {
+                let level = heading_level(*level);
+                let title: String = events[i + 1..]
+                    .iter()
+                    .take_while(|event| !matches!(event, Event::End(TagEnd::Heading(_))))
+                    .filter_map(|event| match event {
+                        Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+                        _ => None,
+                    })
+                    .collect();
+                let base = slugify(&title);
+                let mut anchor = base.clone();
+                let mut suffix = 1;
+                while toc.iter().any(|entry: &TocEntry| entry.anchor == anchor) {
+                    anchor = format!("{}-{}", base, suffix);
+                    suffix += 1;
+                }
+                if let Event::Start(Tag::Heading { id, .. }) = &mut events[i] {
+                    *id = Some(CowStr::from(anchor.clone()));
+                }
+                toc.push(TocEntry {
+                    level,
+                    title,
+                    anchor,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, events.into_iter());
+
+    RenderedMarkdown {
+        html: rendered,
+        toc,
+        word_count,
+    }
+}
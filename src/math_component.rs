@@ -0,0 +1,10 @@
+use crate::mathml::tex_to_mathml;
+use dioxus::prelude::*;
+
+/// Renders a TeX formula, e.g., `FV = P\left(1 + \frac{r}{n}\right)^{nt}`
+#[component]
+pub fn MathFormula(tex: String, #[props(default = false)] display: bool) -> Element {
+    rsx! {
+        span { dangerous_inner_html: tex_to_mathml(&tex, display) }
+    }
+}
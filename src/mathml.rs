@@ -0,0 +1,214 @@
+//! Converts a small subset of TeX math (as typed in blog posts and formula panels) into MathML,
+//! which browsers render natively without a JavaScript math library.
+//!
+//! Supported: numbers, letters, operators, `^`/`_` scripts, `{}` groups, `\frac`, `\sqrt`,
+//! `\text`, `\left`/`\right`, Greek letters, and common symbols like `\cdot` and `\sum`.
+
+use std::iter::Peekable;
+use std::str::Chars;
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// MathML for a `\command`
+fn command(name: &str) -> String {
+    let symbol = match name {
+        "alpha" => "α",
+        "beta" => "β",
+        "gamma" => "γ",
+        "delta" => "δ",
+        "Delta" => "Δ",
+        "epsilon" => "ε",
+        "mu" => "μ",
+        "pi" => "π",
+        "rho" => "ρ",
+        "sigma" => "σ",
+        "Sigma" => "Σ",
+        "tau" => "τ",
+        "theta" => "θ",
+        "infty" => "∞",
+        _ => "",
+    };
+    if !symbol.is_empty() {
+        return format!("<mi>{}</mi>", symbol);
+    }
+    if matches!(name, "ln" | "log" | "exp" | "max" | "min") {
+        return format!("<mi>{}</mi>", name);
+    }
+    let operator = match name {
+        "cdot" => "·",
+        "times" => "×",
+        "div" => "÷",
+        "pm" => "±",
+        "le" | "leq" => "≤",
+        "ge" | "geq" => "≥",
+        "ne" | "neq" => "≠",
+        "approx" => "≈",
+        "to" | "rightarrow" => "→",
+        "sum" => "∑",
+        "prod" => "∏",
+        _ => name,
+    };
+    format!("<mo>{}</mo>", escape(operator))
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().is_some_and(|c| c.is_whitespace()) {
+            self.chars.next();
+        }
+    }
+
+    /// Raw text of a `{...}` group (for `\text`), or the next character if there is no group
+    fn raw_group(&mut self) -> String {
+        self.skip_whitespace();
+        if self.chars.peek() != Some(&'{') {
+            return self.chars.next().map(String::from).unwrap_or_default();
+        }
+        self.chars.next();
+        let mut depth = 1;
+        let mut text = String::new();
+        for c in self.chars.by_ref() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+            text.push(c);
+        }
+        text
+    }
+
+    /// Parses atoms until the end of input or a closing `}`
+    fn row(&mut self) -> String {
+        let mut nodes = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                None => break,
+                Some('}') => {
+                    self.chars.next();
+                    break;
+                }
+                _ => {
+                    if let Some(node) = self.scripted_atom() {
+                        nodes.push(node);
+                    }
+                }
+            }
+        }
+        if nodes.len() == 1 {
+            nodes.remove(0)
+        } else {
+            format!("<mrow>{}</mrow>", nodes.concat())
+        }
+    }
+
+    /// An atom followed by optional `^` and `_` scripts
+    fn scripted_atom(&mut self) -> Option<String> {
+        let base = self.atom()?;
+        let (mut superscript, mut subscript) = (None, None);
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some('^') if superscript.is_none() => {
+                    self.chars.next();
+                    superscript = self.atom();
+                }
+                Some('_') if subscript.is_none() => {
+                    self.chars.next();
+                    subscript = self.atom();
+                }
+                _ => break,
+            }
+        }
+        Some(match (superscript, subscript) {
+            (Some(sup), Some(sub)) => format!("<msubsup>{}{}{}</msubsup>", base, sub, sup),
+            (Some(sup), None) => format!("<msup>{}{}</msup>", base, sup),
+            (None, Some(sub)) => format!("<msub>{}{}</msub>", base, sub),
+            (None, None) => base,
+        })
+    }
+
+    fn atom(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        let c = self.chars.next()?;
+        Some(match c {
+            '{' => self.row(),
+            '\\' => {
+                let mut name = String::new();
+                while let Some(&next) = self.chars.peek() {
+                    if !next.is_ascii_alphabetic() {
+                        break;
+                    }
+                    name.push(next);
+                    self.chars.next();
+                }
+                match name.as_str() {
+                    "frac" => {
+                        let numerator = self.atom().unwrap_or_default();
+                        let denominator = self.atom().unwrap_or_default();
+                        format!("<mfrac>{}{}</mfrac>", numerator, denominator)
+                    }
+                    "sqrt" => format!("<msqrt>{}</msqrt>", self.atom().unwrap_or_default()),
+                    "text" => format!("<mtext>{}</mtext>", escape(&self.raw_group())),
+                    "left" | "right" => {
+                        self.skip_whitespace();
+                        match self.chars.next() {
+                            Some('.') | None => String::new(),
+                            Some(delimiter) => {
+                                format!("<mo>{}</mo>", escape(&delimiter.to_string()))
+                            }
+                        }
+                    }
+                    // Escaped single characters such as `\%` or `\{`
+                    "" => match self.chars.next() {
+                        Some(symbol) => format!("<mo>{}</mo>", escape(&symbol.to_string())),
+                        None => String::new(),
+                    },
+                    _ => command(&name),
+                }
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let mut number = c.to_string();
+                while let Some(&next) = self.chars.peek() {
+                    if !(next.is_ascii_digit() || next == '.' || next == ',') {
+                        break;
+                    }
+                    number.push(next);
+                    self.chars.next();
+                }
+                format!("<mn>{}</mn>", number)
+            }
+            c if c.is_alphabetic() => format!("<mi>{}</mi>", c),
+            c => format!("<mo>{}</mo>", escape(&c.to_string())),
+        })
+    }
+}
+
+/// Converts TeX math to a MathML `<math>` element; `display` renders it as a centered block
+pub fn tex_to_mathml(tex: &str, display: bool) -> String {
+    let mut parser = Parser {
+        chars: tex.chars().peekable(),
+    };
+    let body = parser.row();
+    let display = if display { "block" } else { "inline" };
+    format!(
+        "<math display=\"{}\"><semantics>{}<annotation encoding=\"application/x-tex\">{}</annotation></semantics></math>",
+        display,
+        body,
+        escape(tex)
+    )
+}
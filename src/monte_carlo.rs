@@ -0,0 +1,143 @@
+use crate::simulation::{PercentileBands, RandomSource, ReturnModel};
+
+/// Most simulated paths one run can ask for
+pub const MAX_PATHS: usize = 10_000;
+
+/// Longest retirement, in years, that can be simulated
+pub const MAX_YEARS: usize = 100;
+
+/// Inputs to the Monte Carlo retirement simulation
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonteCarloInputs {
+    /// Savings at the start of retirement
+    pub starting_balance: f64,
+    /// First year's withdrawal, then raised with inflation
+    pub annual_withdrawal: f64,
+    /// Yearly inflation as a decimal
+    pub inflation_rate: f64,
+    pub years: usize,
+    /// Number of random return paths to simulate
+    pub paths: usize,
+    pub model: ReturnModel,
+}
+
+/// How the simulated retirements turned out
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonteCarloResult {
+    /// Share of paths that paid every withdrawal in full
+    pub success_rate: f64,
+    /// Percentiles of the balance in today's dollars at the start of each year, plus the end
+    pub bands: PercentileBands,
+    /// Median first year (counting from zero) a withdrawal falls short, among the paths that
+    /// fall short
+    pub median_shortfall_year: Option<usize>,
+}
+
+/// One retirement: the balance in today's dollars at the start of each year and at the end, and
+/// the first year a withdrawal can't be paid in full
+fn simulate_path(inputs: &MonteCarloInputs, returns: &[f64]) -> (Vec<f64>, Option<usize>) {
+    let mut balance = inputs.starting_balance;
+    let mut shortfall_year = None;
+    let mut path = Vec::with_capacity(returns.len() + 1);
+    path.push(balance);
+
+    for (year, annual_return) in returns.iter().enumerate() {
+        let inflation = (1.0 + inputs.inflation_rate).powi(year as i32);
+        let withdrawal = inputs.annual_withdrawal * inflation;
+        if balance < withdrawal && shortfall_year.is_none() {
+            shortfall_year = Some(year);
+        }
+        // A return below -100% still only loses what's invested
+        balance = (balance - withdrawal).max(0.0) * (1.0 + annual_return).max(0.0);
+        path.push(balance / (inflation * (1.0 + inputs.inflation_rate)));
+    }
+    (path, shortfall_year)
+}
+
+// Simulates retirements under random return sequences, withdrawing an inflation-adjusted amount
+// at the start of each year.
+///
+/// # Parameters:
+/// - `inputs`: Starting balance (B), first withdrawal (W), inflation (i), years (n), paths, and
+///   the return model: one return series, historical returns, or an asset allocation
+/// - `rng`: Random source; the same seed reproduces the same paths
+///
+/// # Formula:
+/// withdrawal_t = W * (1 + i)^t
+/// balance_(t+1) = max(balance_t - withdrawal_t, 0) * (1 + r_t), with r_t drawn from the model
+/// success = every withdrawal_t ≤ balance_t
+/// today's dollars = balance_t / (1 + i)^t
+///
+/// # Returns:
+/// The share of successful paths, balance percentiles by year, and the median year the failing
+/// paths fall short. An error if an entry is negative, the years or paths are out of range, or
+/// the return model can't be sampled (e.g., weights that don't add up to 100%).
+pub fn simulate_retirement<R: RandomSource>(
+    inputs: &MonteCarloInputs,
+    rng: &mut R,
+) -> Result<MonteCarloResult, String> {
+    let entries = [inputs.starting_balance, inputs.annual_withdrawal];
+    if entries.iter().any(|entry| entry.is_nan() || *entry < 0.0) {
+        return Err("The balance and withdrawal can't be negative".to_string());
+    }
+    if inputs.inflation_rate.is_nan() || inputs.inflation_rate <= -1.0 {
+        return Err("Inflation must be greater than -100%".to_string());
+    }
+    if inputs.years == 0 || inputs.years > MAX_YEARS {
+        return Err(format!("Simulate 1 to {} years", MAX_YEARS));
+    }
+    if inputs.paths == 0 || inputs.paths > MAX_PATHS {
+        return Err(format!("Run 1 to {} simulations", MAX_PATHS));
+    }
+    match &inputs.model {
+        ReturnModel::Normal { mean, volatility } => {
+            if !mean.is_finite() || volatility.is_nan() || *volatility < 0.0 {
+                return Err("Enter a mean return and a volatility of zero or more".to_string());
+            }
+        }
+        ReturnModel::Bootstrap {
+            history,
+            block_size,
+        } => {
+            if history.is_empty() {
+                return Err("Enter at least one historical return".to_string());
+            }
+            if *block_size == 0 {
+                return Err("The block size must be at least one year".to_string());
+            }
+        }
+        ReturnModel::Portfolio(allocation) => {
+            let total: f64 = allocation.weights.iter().sum();
+            if allocation
+                .weights
+                .iter()
+                .any(|weight| weight.is_nan() || *weight < 0.0)
+                || (total - 1.0).abs() > 1e-6
+            {
+                return Err("The asset weights must be zero or more and add up to 100%".to_string());
+            }
+            if allocation.sampler().is_none() {
+                return Err(
+                    "These correlations can't all hold at once; try values closer to zero"
+                        .to_string(),
+                );
+            }
+        }
+    }
+
+    let mut paths = Vec::with_capacity(inputs.paths);
+    let mut shortfall_years = Vec::new();
+    for _ in 0..inputs.paths {
+        let returns = inputs.model.sample_path(inputs.years, rng);
+        let (path, shortfall_year) = simulate_path(inputs, &returns);
+        paths.push(path);
+        shortfall_years.extend(shortfall_year);
+    }
+    shortfall_years.sort_unstable();
+
+    Ok(MonteCarloResult {
+        success_rate: 1.0 - shortfall_years.len() as f64 / inputs.paths as f64,
+        bands: PercentileBands::from_paths(&paths),
+        median_shortfall_year: shortfall_years.get(shortfall_years.len() / 2).copied(),
+    })
+}
@@ -0,0 +1,388 @@
+use crate::allocation_component::AllocationEditor;
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::chart::FanChart;
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::monte_carlo::{simulate_retirement, MonteCarloInputs, MAX_PATHS};
+use crate::numeric_input_component::NumericInput;
+use crate::seed_input_component::SeedInput;
+use crate::simulation::{
+    allocation_presets, parse_returns, Allocation, ReturnModel, Rng, DEFAULT_SEED,
+};
+use dioxus::prelude::*;
+use num_format::{Locale, ToFormattedString};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum ReturnSource {
+    Normal,
+    Historical,
+    Portfolio,
+}
+
+const SOURCE_OPTIONS: &[(ReturnSource, &str, &str)] = &[
+    (ReturnSource::Normal, "normal", "Mean and volatility"),
+    (ReturnSource::Historical, "historical", "Historical returns"),
+    (ReturnSource::Portfolio, "portfolio", "Asset allocation"),
+];
+
+/// The 60/40 preset, where an asset allocation starts
+fn default_allocation() -> Allocation {
+    allocation_presets()
+        .into_iter()
+        .find(|preset| preset.name == "60/40")
+        .unwrap_or_else(|| allocation_presets().remove(0))
+}
+
+fn default_weights() -> Vec<f64> {
+    default_allocation().weights
+}
+
+fn default_correlations() -> Vec<Vec<f64>> {
+    default_allocation().correlations
+}
+
+/// Everything the user has entered, as autosaved between visits
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct MonteCarloSession {
+    starting_balance: f64,
+    annual_withdrawal: f64,
+    inflation_rate: f64,
+    years: usize,
+    paths: usize,
+    source: ReturnSource,
+    mean_return: f64,
+    volatility: f64,
+    /// Pasted annual returns, resampled when the source is historical
+    history_text: String,
+    block_size: usize,
+    /// Weight of each preset asset class, used when the source is an asset allocation
+    #[serde(default = "default_weights")]
+    weights: Vec<f64>,
+    /// Correlations between the asset classes, in the same order as `weights`
+    #[serde(default = "default_correlations")]
+    correlations: Vec<Vec<f64>>,
+    seed: u64,
+}
+
+/// A $1 million portfolio paying $40,000 a year, the 4% rule, for 30 years in a balanced
+/// portfolio
+fn default_session() -> MonteCarloSession {
+    MonteCarloSession {
+        starting_balance: 1_000_000.0,
+        annual_withdrawal: 40_000.0,
+        inflation_rate: 0.025,
+        years: 30,
+        paths: 2_000,
+        source: ReturnSource::Normal,
+        mean_return: 0.06,
+        volatility: 0.12,
+        history_text: String::new(),
+        block_size: 5,
+        weights: default_weights(),
+        correlations: default_correlations(),
+        seed: DEFAULT_SEED,
+    }
+}
+
+const BALANCE_COLOR: &str = "#229954";
+
+#[component]
+pub fn MonteCarloUI() -> Element {
+    let mut balance_signal = use_signal(|| 1_000_000.0 as f64);
+    let mut balance_input = use_signal(|| "1000000".to_string());
+    let balance_input_valid = use_signal(|| true);
+    let mut withdrawal_signal = use_signal(|| 40_000.0 as f64);
+    let mut withdrawal_input = use_signal(|| "40000".to_string());
+    let withdrawal_input_valid = use_signal(|| true);
+    // Rates are entered in percent
+    let mut inflation_signal = use_signal(|| 2.5 as f64);
+    let mut inflation_input = use_signal(|| "2.5".to_string());
+    let inflation_input_valid = use_signal(|| true);
+    let mut years_signal = use_signal(|| 30.0 as f64);
+    let mut years_input = use_signal(|| "30".to_string());
+    let years_input_valid = use_signal(|| true);
+    let mut paths_signal = use_signal(|| 2_000.0 as f64);
+    let mut paths_input = use_signal(|| "2000".to_string());
+    let paths_input_valid = use_signal(|| true);
+    let mut source_signal = use_signal(|| ReturnSource::Normal);
+    let mut mean_signal = use_signal(|| 6.0 as f64);
+    let mut mean_input = use_signal(|| "6.0".to_string());
+    let mean_input_valid = use_signal(|| true);
+    let mut volatility_signal = use_signal(|| 12.0 as f64);
+    let mut volatility_input = use_signal(|| "12.0".to_string());
+    let volatility_input_valid = use_signal(|| true);
+    let mut history_text = use_signal(String::new);
+    let mut block_signal = use_signal(|| 5.0 as f64);
+    let mut block_input = use_signal(|| "5".to_string());
+    let block_input_valid = use_signal(|| true);
+    let mut allocation_signal = use_signal(default_allocation);
+    let mut seed_signal = use_signal(|| DEFAULT_SEED);
+    let hover_index = use_signal(|| None);
+
+    let start_fresh = use_autosave(
+        "monte_carlo",
+        default_session(),
+        move || MonteCarloSession {
+            starting_balance: balance_signal(),
+            annual_withdrawal: withdrawal_signal(),
+            inflation_rate: inflation_signal() / 100.0,
+            years: years_signal().round() as usize,
+            paths: paths_signal().round() as usize,
+            source: source_signal(),
+            mean_return: mean_signal() / 100.0,
+            volatility: volatility_signal() / 100.0,
+            history_text: history_text(),
+            block_size: block_signal().round() as usize,
+            weights: allocation_signal().weights,
+            correlations: allocation_signal().correlations,
+            seed: seed_signal(),
+        },
+        use_callback(move |session: MonteCarloSession| {
+            balance_signal.set(session.starting_balance);
+            balance_input.set(format!("{}", session.starting_balance));
+            withdrawal_signal.set(session.annual_withdrawal);
+            withdrawal_input.set(format!("{}", session.annual_withdrawal));
+            inflation_signal.set(session.inflation_rate * 100.0);
+            inflation_input.set(format!("{}", session.inflation_rate * 100.0));
+            years_signal.set(session.years as f64);
+            years_input.set(format!("{}", session.years));
+            paths_signal.set(session.paths as f64);
+            paths_input.set(format!("{}", session.paths));
+            source_signal.set(session.source);
+            mean_signal.set(session.mean_return * 100.0);
+            mean_input.set(format!("{}", session.mean_return * 100.0));
+            volatility_signal.set(session.volatility * 100.0);
+            volatility_input.set(format!("{}", session.volatility * 100.0));
+            history_text.set(session.history_text);
+            block_signal.set(session.block_size as f64);
+            block_input.set(format!("{}", session.block_size));
+            allocation_signal.set(Allocation {
+                weights: session.weights,
+                correlations: session.correlations,
+                ..default_allocation()
+            });
+            seed_signal.set(session.seed);
+        }),
+    );
+    use_track_calculator("monte_carlo");
+    use_track_input_range("monte_carlo", "balance", balance_signal, amount_bucket);
+
+    let model = match source_signal() {
+        ReturnSource::Normal => Ok(ReturnModel::Normal {
+            mean: mean_signal() / 100.0,
+            volatility: volatility_signal() / 100.0,
+        }),
+        ReturnSource::Historical => {
+            parse_returns(&history_text()).map(|history| ReturnModel::Bootstrap {
+                history,
+                block_size: block_signal().round() as usize,
+            })
+        }
+        ReturnSource::Portfolio => Ok(ReturnModel::Portfolio(allocation_signal())),
+    };
+    let years = years_signal().round() as usize;
+    let result = model.and_then(|model| {
+        simulate_retirement(
+            &MonteCarloInputs {
+                starting_balance: balance_signal(),
+                annual_withdrawal: withdrawal_signal(),
+                inflation_rate: inflation_signal() / 100.0,
+                years,
+                paths: paths_signal().round() as usize,
+                model,
+            },
+            &mut Rng::new(seed_signal()),
+        )
+    });
+    let paths = (paths_signal().round() as u64).to_formatted_string(&Locale::en);
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Starting Balance ($):".to_string(),
+            placeholder: "Enter savings at retirement (e.g., 1000000)".to_string(),
+            input_signal: balance_input,
+            value_signal: balance_signal,
+            valid_signal: balance_input_valid,
+            field_name: "Starting balance".to_string(),
+            css_prefix: "balance".to_string(),
+            step: Some(10000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Annual Withdrawal ($):".to_string(),
+            placeholder: "Enter the first year's withdrawal, then raised with inflation (e.g., 40000)".to_string(),
+            input_signal: withdrawal_input,
+            value_signal: withdrawal_signal,
+            valid_signal: withdrawal_input_valid,
+            field_name: "Annual withdrawal".to_string(),
+            css_prefix: "withdrawal".to_string(),
+            allow_negative: true,
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Inflation (%/yr):".to_string(),
+            placeholder: "Enter expected inflation (e.g., 2.5)".to_string(),
+            input_signal: inflation_input,
+            value_signal: inflation_signal,
+            valid_signal: inflation_input_valid,
+            field_name: "Inflation".to_string(),
+            css_prefix: "inflation".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+        NumericInput {
+            label: "Years in Retirement:".to_string(),
+            placeholder: "Enter how many years the money has to last (e.g., 30)".to_string(),
+            input_signal: years_input,
+            value_signal: years_signal,
+            valid_signal: years_input_valid,
+            field_name: "Years in retirement".to_string(),
+            css_prefix: "years".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(0),
+        }
+        NumericInput {
+            label: "Simulations:".to_string(),
+            placeholder: format!("Enter how many return paths to simulate, up to {} (e.g., 2000)", MAX_PATHS),
+            input_signal: paths_input,
+            value_signal: paths_signal,
+            valid_signal: paths_input_valid,
+            field_name: "Simulations".to_string(),
+            css_prefix: "paths".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(0),
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Returns From:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 200px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(source, _, _)) = SOURCE_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        source_signal.set(source);
+                    }
+                },
+                for (source , value , display) in SOURCE_OPTIONS.iter() {
+                    option { value: *value, selected: source_signal() == *source, {*display} }
+                }
+            }
+        }
+        if source_signal() == ReturnSource::Portfolio {
+            AllocationEditor { allocation: allocation_signal }
+        } else if source_signal() == ReturnSource::Normal {
+            NumericInput {
+                label: "Mean Return (%/yr):".to_string(),
+                placeholder: "Enter the average annual return (e.g., 6.0)".to_string(),
+                input_signal: mean_input,
+                value_signal: mean_signal,
+                valid_signal: mean_input_valid,
+                field_name: "Mean return".to_string(),
+                css_prefix: "mean".to_string(),
+                allow_negative: true,
+                step: Some(0.5),
+            }
+            NumericInput {
+                label: "Volatility (%/yr):".to_string(),
+                placeholder: "Enter the standard deviation of annual returns (e.g., 12.0)".to_string(),
+                input_signal: volatility_input,
+                value_signal: volatility_signal,
+                valid_signal: volatility_input_valid,
+                field_name: "Volatility".to_string(),
+                css_prefix: "volatility".to_string(),
+                allow_negative: true,
+                step: Some(1.0),
+            }
+        } else {
+            div { style: "color: #333; margin-bottom: 15px;",
+                label { style: "font-weight: bold;", r#for: "history-text", "Historical Annual Returns:" }
+                p { style: "font-size: 12px;",
+                    "Paste annual returns separated by commas, spaces, or new lines. Write percentages with a % sign (7.5%); other values are read as decimals (0.075). They're resampled in blocks of consecutive years, so runs of good and bad years stay together."
+                }
+                textarea {
+                    id: "history-text",
+                    rows: "6",
+                    style: "width: 420px; font-family: monospace; border-radius: 4px;",
+                    placeholder: "12.5%, -4.2%, 21.0%",
+                    value: "{history_text}",
+                    oninput: move |event| history_text.set(event.value()),
+                }
+            }
+            NumericInput {
+                label: "Block Size (years):".to_string(),
+                placeholder: "Enter how many consecutive years to resample together (e.g., 5)".to_string(),
+                input_signal: block_input,
+                value_signal: block_signal,
+                valid_signal: block_input_valid,
+                field_name: "Block size".to_string(),
+                css_prefix: "block".to_string(),
+                step: Some(1.0),
+                max_decimals: Some(0),
+            }
+        }
+        SeedInput { seed_signal }
+
+        {
+            match result {
+                Ok(result) => {
+                    let last = result.bands.len() - 1;
+                    let shortfall = result
+                        .median_shortfall_year
+                        .map(|year| {
+                            format!(
+                                " When the money runs short, it's typically in year {}.",
+                                year + 1
+                            )
+                        })
+                        .unwrap_or_default();
+                    rsx! {
+                        div {
+                            id: "MonteCarloCalculation",
+                            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                            "Success rate: {result.success_rate * 100.0:.1}% of {paths} simulations*"
+                        }
+                        div { style: "margin-bottom: 15px; font-size: 16px;",
+                            "After {years} years the median balance is ${format_dollars(result.bands.p50[last])} in today's dollars, and 1 in 10 simulations ends below ${format_dollars(result.bands.p10[last])}.{shortfall}"
+                        }
+                        FanChart {
+                            title: "Balance in today's dollars".to_string(),
+                            bands: result.bands,
+                            x_start: 0.0,
+                            color: BALANCE_COLOR.to_string(),
+                            hover_index,
+                        }
+                    }
+                }
+                Err(error) => rsx! {
+                    div {
+                        id: "MonteCarloCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "monte_carlo" }
+        AssumptionsFooter { calculator: "monte_carlo" }
+        div { style: "font-size: 10px",
+            p {
+                "*Share of simulations that paid every withdrawal in full. Simulated returns are illustrative, not a forecast; the same seed always gives the same results."
+            }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use serde::{Deserialize, Serialize};
+
+/// Days per year used to turn dates into fractional years (the XNPV convention)
+const DAYS_PER_YEAR: f64 = 365.0;
+
+/// How the cash flows of a series are placed in time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum CashFlowIndexing {
+    /// By period number, 0 being today
+    #[default]
+    Period,
+    /// By calendar date, measured from the earliest flow
+    Date,
+}
+
+/// One amount received (positive) or paid (negative)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CashFlow {
+    /// Period number, used when the series is indexed by period
+    pub period: u32,
+    /// ISO date (YYYY-MM-DD), used when the series is indexed by date
+    pub date: String,
+    pub amount: f64,
+}
+
+/// A list of cash flows, indexed by period or by date
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CashFlowSeries {
+    pub indexing: CashFlowIndexing,
+    pub flows: Vec<CashFlow>,
+}
+
+/// Inputs to the NPV calculator
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NpvInputs {
+    /// Discount rate per period (or per year, for dated flows) as a decimal, e.g., 0.08 for 8%
+    pub discount_rate: f64,
+    pub series: CashFlowSeries,
+}
+
+/// Net present value of a series, and the present value of each flow in it
+#[derive(Debug, Clone, PartialEq)]
+pub struct NpvOutputs {
+    pub npv: f64,
+    pub present_values: Vec<f64>,
+}
+
+/// Days since 1970-01-01 of an ISO date (YYYY-MM-DD), or `None` if it isn't a real date
+pub fn parse_date(text: &str) -> Option<i64> {
+    let mut parts = text.trim().splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return None,
+    };
+    if !(1..=days_in_month).contains(&day) {
+        return None;
+    }
+
+    // Days from civil date, counting years from March so leap days fall at the end
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some(era * 146_097 + day_of_era - 719_468)
+}
+
+impl CashFlowSeries {
+    /// When each flow happens, in periods (or years, for dated flows) from the start
+    pub fn times(&self) -> Result<Vec<f64>, String> {
+        match self.indexing {
+            CashFlowIndexing::Period => Ok(self.flows.iter().map(|f| f.period as f64).collect()),
+            CashFlowIndexing::Date => {
+                let days = self
+                    .flows
+                    .iter()
+                    .enumerate()
+                    .map(|(i, flow)| {
+                        parse_date(&flow.date).ok_or_else(|| {
+                            format!(
+                                "Row {}: \"{}\" is not a date (use YYYY-MM-DD)",
+                                i + 1,
+                                flow.date
+                            )
+                        })
+                    })
+                    .collect::<Result<Vec<i64>, String>>()?;
+                let start = days.iter().copied().min().unwrap_or(0);
+                Ok(days
+                    .iter()
+                    .map(|day| (day - start) as f64 / DAYS_PER_YEAR)
+                    .collect())
+            }
+        }
+    }
+}
+
+// Computes the net present value (NPV) of a series of cash flows.
+///
+/// # Parameters:
+/// - `series`: Cash flows (CF) and when they happen (t), in periods or in years from the earliest date
+/// - `discount_rate`: Discount rate per period or per year (r), e.g., 0.08 for 8%
+///
+/// # Formula:
+/// NPV = Σ CF_t / (1 + r)^t
+///
+/// # Returns:
+/// The NPV and each flow's present value, not rounded, or why they can't be computed.
+pub fn compute_npv(series: &CashFlowSeries, discount_rate: f64) -> Result<NpvOutputs, String> {
+    if discount_rate <= -1.0 {
+        return Err("The discount rate must be greater than -100%".to_string());
+    }
+    let present_values: Vec<f64> = series
+        .times()?
+        .iter()
+        .zip(&series.flows)
+        .map(|(t, flow)| flow.amount / (1.0 + discount_rate).powf(*t))
+        .collect();
+
+    Ok(NpvOutputs {
+        npv: present_values.iter().sum(),
+        present_values,
+    })
+}
+
+/// Rates tried, lowest first, when looking for a bracket around the IRR
+const IRR_BRACKETS: &[f64] = &[
+    -0.99, -0.9, -0.5, -0.2, 0.0, 0.1, 0.2, 0.5, 1.0, 2.0, 5.0, 10.0,
+];
+
+// Computes the internal rate of return (IRR) of a series: the discount rate that makes its NPV
+// zero. For dated flows this is XIRR, using actual day counts over 365-day years.
+///
+/// # Parameters:
+/// - `series`: Cash flows (CF) and when they happen (t), in periods or in years from the earliest date
+///
+/// # Formula:
+/// Σ CF_t / (1 + IRR)^t = 0
+///
+/// # Returns:
+/// The rate per period (or per year, for dated flows) as a decimal, or why there isn't one. The
+/// series needs at least one positive and one negative flow; if its NPV crosses zero more than
+/// once, the lowest such rate between -99% and 1,000% is returned.
+pub fn compute_irr(series: &CashFlowSeries) -> Result<f64, String> {
+    let has_inflow = series.flows.iter().any(|flow| flow.amount > 0.0);
+    let has_outflow = series.flows.iter().any(|flow| flow.amount < 0.0);
+    if !has_inflow || !has_outflow {
+        return Err("The IRR needs at least one positive and one negative cash flow".to_string());
+    }
+    let times = series.times()?;
+    let npv = |rate: f64| -> f64 {
+        times
+            .iter()
+            .zip(&series.flows)
+            .map(|(t, flow)| flow.amount / (1.0 + rate).powf(*t))
+            .sum()
+    };
+
+    // Find the first pair of rates the NPV changes sign between, then bisect
+    let (mut low, mut high) = IRR_BRACKETS
+        .windows(2)
+        .map(|pair| (pair[0], pair[1]))
+        .find(|(low, high)| npv(*low).signum() != npv(*high).signum() || npv(*low) == 0.0)
+        .ok_or_else(|| "No rate between -99% and 1,000% makes the NPV zero".to_string())?;
+    let low_sign = npv(low).signum();
+    for _ in 0..200 {
+        let mid = (low + high) / 2.0;
+        if npv(mid).signum() == low_sign {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Ok((low + high) / 2.0)
+}
+
+/// Inputs to the discounted cash flow (DCF) valuation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DcfInputs {
+    /// Discount rate per year as a decimal, e.g., 0.09 for 9%
+    pub discount_rate: f64,
+    /// Yearly growth of free cash flow after the last projected year, forever
+    pub terminal_growth: f64,
+    /// Projected free cash flows, by year from today
+    pub series: CashFlowSeries,
+}
+
+/// Enterprise value, and how much of it each projected year and the terminal value contribute
+#[derive(Debug, Clone, PartialEq)]
+pub struct DcfOutputs {
+    /// Present value of each projected flow, in series order
+    pub present_values: Vec<f64>,
+    /// Value at the last projected year of every flow after it
+    pub terminal_value: f64,
+    pub terminal_present_value: f64,
+    pub enterprise_value: f64,
+}
+
+// Values a business as the present value of its projected free cash flows plus a terminal value.
+///
+/// # Parameters:
+/// - `inputs`: Free cash flows (FCF) and when they happen (t), the discount rate (r), and the
+///   terminal growth rate (g)
+///
+/// # Formula:
+/// TV = FCF_N * (1 + g) / (r - g), the Gordon growth model at the last year N
+/// EV = Σ FCF_t / (1 + r)^t + TV / (1 + r)^N
+///
+/// # Returns:
+/// The enterprise value and its parts, not rounded. An error if there are no flows or the
+/// discount rate doesn't exceed the terminal growth rate, as the terminal value would be infinite.
+pub fn compute_dcf(inputs: &DcfInputs) -> Result<DcfOutputs, String> {
+    if inputs.series.flows.is_empty() {
+        return Err("Enter at least one year of free cash flow".to_string());
+    }
+    if inputs.terminal_growth.is_nan() || inputs.terminal_growth >= inputs.discount_rate {
+        return Err("The discount rate must exceed the terminal growth rate".to_string());
+    }
+    let npv = compute_npv(&inputs.series, inputs.discount_rate)?;
+
+    // The terminal value grows from the latest projected flow
+    let (last_time, last_flow) = inputs
+        .series
+        .times()?
+        .into_iter()
+        .zip(&inputs.series.flows)
+        .fold((f64::MIN, 0.0), |(time, amount), (t, flow)| {
+            if t >= time {
+                (t, flow.amount)
+            } else {
+                (time, amount)
+            }
+        });
+    let terminal_value = last_flow * (1.0 + inputs.terminal_growth)
+        / (inputs.discount_rate - inputs.terminal_growth);
+    let terminal_present_value = terminal_value / (1.0 + inputs.discount_rate).powf(last_time);
+
+    Ok(DcfOutputs {
+        enterprise_value: npv.npv + terminal_present_value,
+        present_values: npv.present_values,
+        terminal_value,
+        terminal_present_value,
+    })
+}
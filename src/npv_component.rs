@@ -0,0 +1,456 @@
+use crate::analytics::use_track_calculator;
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::error_summary_component::{ErrorSummary, FieldError};
+use crate::feedback_component::FeedbackWidget;
+use crate::npv::{
+    compute_irr, compute_npv, parse_date, CashFlow, CashFlowIndexing, CashFlowSeries, NpvInputs,
+};
+use crate::number_format::dollars;
+use crate::numeric_input_component::{input_width_style, numeric_field_error, NumericInput};
+use crate::table_paste::parse_pasted_columns;
+use crate::table_paste_component::TablePasteSection;
+use dioxus::prelude::*;
+
+/// An outlay today followed by three years of returns, discounted at 8%
+fn default_inputs() -> NpvInputs {
+    NpvInputs {
+        discount_rate: 0.08,
+        series: CashFlowSeries {
+            indexing: CashFlowIndexing::Period,
+            flows: [-10_000.0, 3000.0, 4000.0, 5000.0]
+                .iter()
+                .enumerate()
+                .map(|(i, amount)| CashFlow {
+                    period: i as u32,
+                    date: format!("{}-01-01", 2025 + i),
+                    amount: *amount,
+                })
+                .collect(),
+        },
+    }
+}
+
+/// A flow of `amount` one period and one year after `last`, or in the first period if there is none
+fn following_flow(last: Option<&CashFlow>, amount: f64) -> CashFlow {
+    match last {
+        Some(last) => CashFlow {
+            period: last.period + 1,
+            date: next_year(&last.date),
+            amount,
+        },
+        None => CashFlow {
+            period: 0,
+            date: "2025-01-01".to_string(),
+            amount,
+        },
+    }
+}
+
+/// The same day a year after an ISO date (March 1 after a leap day), or the text unchanged if it isn't a date
+fn next_year(date: &str) -> String {
+    let year = match date
+        .split_once('-')
+        .map(|(year, rest)| (year.parse::<i32>(), rest))
+    {
+        Some((Ok(year), rest)) if parse_date(date).is_some() => (year + 1, rest),
+        _ => return date.to_string(),
+    };
+    let next = format!("{}-{}", year.0, year.1);
+    if parse_date(&next).is_some() {
+        next
+    } else {
+        format!("{}-03-01", year.0)
+    }
+}
+
+/// What a cash flow cell holds, which decides what it accepts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FlowField {
+    Period,
+    Date,
+    Amount,
+}
+
+impl FlowField {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FlowField::Period => "Period",
+            FlowField::Date => "Date",
+            FlowField::Amount => "Amount ($)",
+        }
+    }
+
+    /// How to fix an entry the field doesn't accept
+    pub fn hint(&self) -> &'static str {
+        match self {
+            FlowField::Period => "enter a whole number of periods, 0 or more",
+            FlowField::Date => "enter a real date as YYYY-MM-DD",
+            FlowField::Amount => "enter an amount, negative for money paid out",
+        }
+    }
+
+    /// Id of this field's cell in a row, which the error summary links to
+    pub fn cell_id(&self, row_id: u64) -> String {
+        let slug = match self {
+            FlowField::Period => "period",
+            FlowField::Date => "date",
+            FlowField::Amount => "amount",
+        };
+        format!("flow-{}-{}", row_id, slug)
+    }
+
+    fn accepts(&self, text: &str) -> bool {
+        let text = text.trim();
+        match self {
+            FlowField::Period => text.parse::<u32>().is_ok(),
+            FlowField::Date => parse_date(text).is_some(),
+            FlowField::Amount => text
+                .replace(",", "")
+                .parse::<f64>()
+                .is_ok_and(|amount| amount.is_finite()),
+        }
+    }
+}
+
+/// Editable cash flow cell; `on_value` is called with every valid entry, `on_valid` with
+/// whether each entry is valid
+#[component]
+pub fn FlowCell(
+    id: String,
+    label: String,
+    initial: String,
+    field: FlowField,
+    on_value: EventHandler<String>,
+    on_valid: EventHandler<bool>,
+) -> Element {
+    let mut text = use_signal(|| initial);
+    let valid = field.accepts(&text());
+
+    rsx! {
+        td { style: "padding: 2px 5px;",
+            input {
+                id,
+                // Browsers show a date picker and submit YYYY-MM-DD, the format `parse_date` reads
+                r#type: if field == FlowField::Date { "date" } else { "text" },
+                value: "{text}",
+                "aria-label": "{label}",
+                "aria-invalid": "{!valid}",
+                style: format!(
+                    "padding: 4px 6px; {} border-radius: 4px; font-family: monospace; {}",
+                    input_width_style(&text()),
+                    if valid {
+                        "border: 1px solid #ccc;"
+                    } else {
+                        "border: 2px solid #ff0000; background-color: #ffe6e6; color: #333;"
+                    },
+                ),
+                oninput: move |event| {
+                    text.set(event.value());
+                    let valid = field.accepts(&event.value());
+                    on_valid.call(valid);
+                    if valid {
+                        on_value.call(event.value().trim().replace(",", ""));
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+pub fn NpvUI() -> Element {
+    // Each row keeps a stable id so its cells keep their text when other rows are removed
+    let mut flows = use_signal(|| {
+        default_inputs()
+            .series
+            .flows
+            .into_iter()
+            .enumerate()
+            .map(|(i, flow)| (i as u64, flow))
+            .collect::<Vec<_>>()
+    });
+    let mut next_id = use_signal(|| 4u64);
+    let mut indexing_signal = use_signal(|| CashFlowIndexing::Period);
+    let mut rate_signal = use_signal(|| 8.0 as f64);
+    let mut rate_input = use_signal(|| "8.0".to_string());
+    let rate_input_valid = use_signal(|| true);
+    // Cells, as (row id, field), whose text the field doesn't accept
+    let mut invalid_cells = use_signal(Vec::<(u64, FlowField)>::new);
+    let series = move || CashFlowSeries {
+        indexing: indexing_signal(),
+        flows: flows().into_iter().map(|(_, flow)| flow).collect(),
+    };
+    let start_fresh = use_autosave(
+        "npv",
+        default_inputs(),
+        move || NpvInputs {
+            discount_rate: rate_signal() / 100.0,
+            series: series(),
+        },
+        use_callback(move |saved: NpvInputs| {
+            rate_signal.set(saved.discount_rate * 100.0);
+            rate_input.set(format!("{}", saved.discount_rate * 100.0));
+            indexing_signal.set(saved.series.indexing);
+            // New ids remount the rows so their cells show the restored values
+            let first_id = *next_id.peek();
+            next_id.set(first_id + saved.series.flows.len() as u64);
+            invalid_cells.set(vec![]);
+            flows.set(
+                saved
+                    .series
+                    .flows
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, flow)| (first_id + i as u64, flow))
+                    .collect(),
+            );
+        }),
+    );
+    use_track_calculator("npv");
+
+    let mut update = move |id: u64, change: fn(&mut CashFlow, &str), text: String| {
+        if let Some((_, flow)) = flows.write().iter_mut().find(|(row_id, _)| *row_id == id) {
+            change(flow, &text);
+        }
+    };
+    let mut set_valid = move |cell: (u64, FlowField), valid: bool| {
+        let mut invalid = invalid_cells.write();
+        invalid.retain(|invalid_cell| *invalid_cell != cell);
+        if !valid {
+            invalid.push(cell);
+        }
+    };
+    let dated = indexing_signal() == CashFlowIndexing::Date;
+    let time_field = if dated {
+        FlowField::Date
+    } else {
+        FlowField::Period
+    };
+    let paste_example = if dated {
+        "2025-01-01\t-10000"
+    } else {
+        "0\t-10000"
+    };
+    // Listed in table order, row by row
+    let invalid = invalid_cells();
+    let mut errors: Vec<FieldError> =
+        numeric_field_error(&rate_input(), rate_input_valid(), "Discount rate", "rate")
+            .into_iter()
+            .collect();
+    for (row, (id, _)) in flows().iter().enumerate() {
+        for field in [time_field, FlowField::Amount] {
+            if invalid.contains(&(*id, field)) {
+                errors.push(FieldError {
+                    field_id: field.cell_id(*id),
+                    message: format!("Row {}, {}: {}", row + 1, field.name(), field.hint()),
+                });
+            }
+        }
+    }
+    let outputs = compute_npv(&series(), rate_signal() / 100.0);
+    let irr_text = match compute_irr(&series()) {
+        Ok(irr) if dated => format!("XIRR: {:.3}% a year", irr * 100.0),
+        Ok(irr) => format!("IRR: {:.3}% per period", irr * 100.0),
+        Err(error) => format!("{}: {}", if dated { "XIRR" } else { "IRR" }, error),
+    };
+    let present_values = outputs
+        .as_ref()
+        .map(|outputs| outputs.present_values.clone())
+        .unwrap_or_default();
+    let rows: Vec<(u64, CashFlow, Option<f64>)> = flows()
+        .into_iter()
+        .enumerate()
+        .map(|(i, (id, flow))| (id, flow, present_values.get(i).copied()))
+        .collect();
+    rsx! {
+        hr {}
+        br {}
+
+        ErrorSummary { errors }
+
+        NumericInput {
+            label: "Discount Rate (%):".to_string(),
+            placeholder: "Enter the rate per period (e.g., 8.0)".to_string(),
+            input_signal: rate_input,
+            value_signal: rate_signal,
+            valid_signal: rate_input_valid,
+            field_name: "Discount rate".to_string(),
+            css_prefix: "rate".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Cash Flows By:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    // The remounted cells start from the stored, valid values
+                    invalid_cells.write().retain(|(_, field)| *field == FlowField::Amount);
+                    indexing_signal
+                        .set(
+                            if event.value() == "date" {
+                                CashFlowIndexing::Date
+                            } else {
+                                CashFlowIndexing::Period
+                            },
+                        );
+                },
+                option { value: "period", selected: !dated, "Period" }
+                option { value: "date", selected: dated, "Date" }
+            }
+        }
+
+        table { style: "color: #333; border-collapse: collapse; margin-bottom: 15px;",
+            thead {
+                tr {
+                    th { style: "text-align: left; padding: 2px 5px;",
+                        if dated {
+                            "Date (YYYY-MM-DD)"
+                        } else {
+                            "Period"
+                        }
+                    }
+                    th { style: "text-align: left; padding: 2px 5px;", "Amount ($)" }
+                    th { style: "text-align: right; padding: 2px 5px;", "Present Value" }
+                    th {}
+                }
+            }
+            tbody {
+                for (row , (id , flow , present_value)) in rows.into_iter().enumerate() {
+                    tr { key: "{id}-{dated}",
+                        if dated {
+                            FlowCell {
+                                id: FlowField::Date.cell_id(id),
+                                label: format!("Row {} date", row + 1),
+                                initial: flow.date.clone(),
+                                field: FlowField::Date,
+                                on_value: move |text: String| update(id, |f, text| f.date = text.to_string(), text),
+                                on_valid: move |valid| set_valid((id, FlowField::Date), valid),
+                            }
+                        } else {
+                            FlowCell {
+                                id: FlowField::Period.cell_id(id),
+                                label: format!("Row {} period", row + 1),
+                                initial: flow.period.to_string(),
+                                field: FlowField::Period,
+                                on_value: move |text: String| {
+                                    update(
+                                        id,
+                                        |f, text| f.period = text.parse().unwrap_or(f.period),
+                                        text,
+                                    )
+                                },
+                                on_valid: move |valid| set_valid((id, FlowField::Period), valid),
+                            }
+                        }
+                        FlowCell {
+                            id: FlowField::Amount.cell_id(id),
+                            label: format!("Row {} amount", row + 1),
+                            initial: flow.amount.to_string(),
+                            field: FlowField::Amount,
+                            on_value: move |text: String| {
+                                update(id, |f, text| f.amount = text.parse().unwrap_or(f.amount), text)
+                            },
+                            on_valid: move |valid| set_valid((id, FlowField::Amount), valid),
+                        }
+                        td { style: "text-align: right; padding: 2px 5px; font-family: monospace;",
+                            {present_value.map(dollars).unwrap_or_default()}
+                        }
+                        td {
+                            button {
+                                style: "padding: 2px 8px; border-radius: 4px;",
+                                onclick: move |_| {
+                                    flows.write().retain(|(row_id, _)| *row_id != id);
+                                    invalid_cells.write().retain(|(row_id, _)| *row_id != id);
+                                },
+                                "Remove"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        button {
+            style: "padding: 6px 12px; border-radius: 4px; margin-bottom: 15px;",
+            onclick: move |_| {
+                let id = next_id();
+                next_id.set(id + 1);
+                let next_flow = {
+                    let flows = flows.peek();
+                    let last = flows.last().map(|(_, flow)| flow);
+                    following_flow(last, last.map_or(0.0, |flow| flow.amount))
+                };
+                flows.write().push((id, next_flow));
+            },
+            "Add Cash Flow"
+        }
+        TablePasteSection {
+            columns: format!("{} and Amount ($)", time_field.name()),
+            placeholder: paste_example.to_string(),
+            on_paste: move |text: String| {
+                let fields = [time_field, FlowField::Amount];
+                let pasted = parse_pasted_columns(
+                    &text,
+                    &fields.map(|field| field.name()),
+                    |column, cell| fields[column].accepts(cell).then(|| cell.to_string()),
+                );
+                let first_id = next_id();
+                next_id.set(first_id + pasted.rows.len() as u64);
+                // The time column that wasn't pasted carries on from the row before
+                let mut last = flows.peek().last().map(|(_, flow)| flow.clone());
+                let mut added = vec![];
+                for (i, row) in pasted.rows.iter().enumerate() {
+                    let mut flow = following_flow(last.as_ref(), row[1].parse().unwrap_or(0.0));
+                    if dated {
+                        flow.date = row[0].clone();
+                    } else {
+                        flow.period = row[0].parse().unwrap_or(flow.period);
+                    }
+                    last = Some(flow.clone());
+                    added.push((first_id + i as u64, flow));
+                }
+                flows.write().extend(added);
+                pasted.report()
+            },
+        }
+
+        {
+            match outputs {
+                Ok(outputs) => rsx! {
+                    div {
+                        id: "NpvCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        "Net present value: {dollars(outputs.npv)}*"
+                    }
+                },
+                Err(error) => rsx! {
+                    div {
+                        id: "NpvCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+        div {
+            id: "IrrCalculation",
+            style: "margin-bottom: 15px; font-size: 16px;",
+            "{irr_text}"
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "npv" }
+        AssumptionsFooter { calculator: "npv" }
+        div { style: "font-size: 10px",
+            p { "*A positive NPV means the flows are worth more than they cost at this discount rate." }
+            p {
+                "The IRR is the discount rate at which the NPV is zero. With dated flows it is the XIRR, an annual rate using the actual days between flows, for irregular histories such as contributions to an investment."
+            }
+            br {}
+            br {}
+        }
+    }
+}
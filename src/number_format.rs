@@ -0,0 +1,174 @@
+use crate::storage::{load_item, save_item, use_storage_listener};
+use dioxus::prelude::*;
+use num_format::{Locale, ToFormattedString};
+use serde::{Deserialize, Serialize};
+
+const NUMBER_FORMAT_KEY: &str = "number_format";
+
+/// Locales offered on the settings page, as (num_format locale name, label)
+pub const LOCALES: &[(&str, &str)] = &[
+    ("en", "1,234,567.89"),
+    ("de", "1.234.567,89"),
+    ("fr", "1 234 567,89"),
+    ("en-IN", "12,34,567.89"),
+];
+
+/// Currencies offered on the settings page, as (ISO code, symbol)
+pub const CURRENCIES: &[(&str, &str)] = &[
+    ("USD", "$"),
+    ("EUR", "€"),
+    ("GBP", "£"),
+    ("JPY", "¥"),
+    ("INR", "₹"),
+];
+
+/// How numbers and money are shown across the app, chosen on the settings page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NumberFormat {
+    /// A `num_format` locale name, e.g., "en" or "de"
+    pub locale: String,
+    /// ISO currency code, e.g., "USD"
+    pub currency: String,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat {
+            locale: "en".to_string(),
+            currency: "USD".to_string(),
+        }
+    }
+}
+
+impl NumberFormat {
+    /// The locale's separators, falling back to English for an unknown name
+    pub fn num_locale(&self) -> Locale {
+        Locale::from_name(&self.locale).unwrap_or(Locale::en)
+    }
+
+    /// The currency's symbol, or its code for a currency not in `CURRENCIES`
+    pub fn currency_symbol(&self) -> &str {
+        CURRENCIES
+            .iter()
+            .find(|(code, _)| *code == self.currency)
+            .map(|(_, symbol)| *symbol)
+            .unwrap_or(&self.currency)
+    }
+
+    /// A whole number with the locale's thousands separators
+    pub fn integer(&self, value: f64) -> String {
+        (value.round() as i64).to_formatted_string(&self.num_locale())
+    }
+
+    /// A number with `decimals` places, using the locale's separators
+    pub fn decimal(&self, value: f64, decimals: usize) -> String {
+        let text = format!("{:.*}", decimals, value.abs());
+        let (whole, fraction) = text.split_once('.').unwrap_or((&text, ""));
+        let whole = whole
+            .parse::<i64>()
+            .unwrap_or(0)
+            .to_formatted_string(&self.num_locale());
+        // No "-0.0" for small negatives that round to zero
+        let sign = if value < 0.0 && text.parse::<f64>().unwrap_or(0.0) != 0.0 {
+            "-"
+        } else {
+            ""
+        };
+        if fraction.is_empty() {
+            format!("{}{}", sign, whole)
+        } else {
+            format!(
+                "{}{}{}{}",
+                sign,
+                whole,
+                self.num_locale().decimal(),
+                fraction
+            )
+        }
+    }
+
+    /// An amount of the currency with `decimals` places and the sign ahead of the symbol, e.g.,
+    /// "-$1,234.56"
+    pub fn currency(&self, value: f64, decimals: usize) -> String {
+        let amount = self.decimal(value, decimals);
+        match amount.strip_prefix('-') {
+            Some(magnitude) => format!("-{}{}", self.currency_symbol(), magnitude),
+            None => format!("{}{}", self.currency_symbol(), amount),
+        }
+    }
+}
+
+/// Dollars and cents for the calculators' result text, which stays in English, truncated to the
+/// cent like `format_dollars`, e.g., "-$1,234.56"
+pub fn dollars(value: f64) -> String {
+    NumberFormat::default().currency((value * 100.0).trunc() / 100.0, 2)
+}
+
+/// How a chart labels its value axis
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum AxisFormat {
+    /// Whole currency units, e.g., "$250,000"
+    #[default]
+    Currency,
+    /// A fraction shown as a percent, e.g., 0.045 as "4.5%"
+    Percent,
+    /// Abbreviated with K, M, or B, e.g., "1.2M"
+    Compact,
+}
+
+impl AxisFormat {
+    pub fn format(&self, value: f64, number_format: &NumberFormat) -> String {
+        match self {
+            AxisFormat::Currency => number_format.currency(value, 0),
+            AxisFormat::Percent => format!("{}%", number_format.decimal(value * 100.0, 1)),
+            AxisFormat::Compact => {
+                let (scaled, suffix) = match value.abs() {
+                    v if v >= 1e9 => (value / 1e9, "B"),
+                    v if v >= 1e6 => (value / 1e6, "M"),
+                    v if v >= 1e3 => (value / 1e3, "K"),
+                    _ => return number_format.integer(value),
+                };
+                format!("{}{}", number_format.decimal(scaled, 1), suffix)
+            }
+        }
+    }
+}
+
+/// Provides the number format, restored from localStorage and kept in sync across tabs
+pub fn use_number_format_provider() {
+    let mut number_format = use_context_provider(|| Signal::new(NumberFormat::default()));
+    use_future(move || async move {
+        if let Some(stored) = load_item(NUMBER_FORMAT_KEY).await {
+            if let Ok(stored) = serde_json::from_str(&stored) {
+                number_format.set(stored);
+            }
+        }
+    });
+    use_storage_listener(
+        NUMBER_FORMAT_KEY,
+        use_callback(move |stored: Option<String>| {
+            let stored = stored
+                .and_then(|stored| serde_json::from_str(&stored).ok())
+                .unwrap_or_default();
+            number_format.set(stored);
+        }),
+    );
+}
+
+/// The current number format, or the default one when no provider is mounted (e.g., in tests)
+pub fn use_number_format() -> NumberFormat {
+    try_use_context::<Signal<NumberFormat>>()
+        .map(|number_format| number_format())
+        .unwrap_or_default()
+}
+
+/// Changes the number format everywhere and remembers it
+pub fn set_number_format(number_format: NumberFormat) {
+    if let Ok(json) = serde_json::to_string(&number_format) {
+        save_item(NUMBER_FORMAT_KEY, &json);
+    }
+    if let Some(mut signal) = try_consume_context::<Signal<NumberFormat>>() {
+        signal.set(number_format);
+    }
+}
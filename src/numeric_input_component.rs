@@ -1,3 +1,4 @@
+use crate::error_summary_component::FieldError;
 use dioxus::prelude::*;
 
 /// Validates numeric input and updates the corresponding signals
@@ -6,9 +7,10 @@ fn validate_numeric_input(
     cleaned_input: &str,
     validity_signal: &mut Signal<bool>,
     value_signal: &mut Signal<f64>,
+    allow_negative: bool,
 ) -> bool {
     match cleaned_input.parse::<f64>() {
-        Ok(value) if value > 0.0 && value.is_finite() => {
+        Ok(value) if (allow_negative || value > 0.0) && value.is_finite() => {
             validity_signal.set(true);
             value_signal.set(value);
             true
@@ -34,6 +36,108 @@ fn get_numeric_error_message(input_text: &str, field_name: &str) -> String {
     }
 }
 
+/// Id of the input rendered by `NumericInput` with this `css_prefix`, for labels and error links
+pub fn numeric_input_id(css_prefix: &str) -> String {
+    format!("{}-input", css_prefix)
+}
+
+/// The error summary entry for a `NumericInput`, or `None` if its text is valid or empty
+pub fn numeric_field_error(
+    input_text: &str,
+    valid: bool,
+    field_name: &str,
+    css_prefix: &str,
+) -> Option<FieldError> {
+    (!valid && !input_text.trim().is_empty()).then(|| FieldError {
+        field_id: numeric_input_id(css_prefix),
+        message: get_numeric_error_message(input_text, field_name),
+    })
+}
+
+/// Narrowest and widest an input gets as text is typed, in pixels
+const MIN_INPUT_WIDTH: usize = 100;
+const MAX_INPUT_WIDTH: usize = 320;
+
+/// Width of an input holding `text`, in `ch` units so it follows the font and zoom: one `ch` per
+/// character (digits are `ch` wide in nearly every font) plus one for the caret, clamped between
+/// `MIN_INPUT_WIDTH` and `MAX_INPUT_WIDTH`. Counts characters, not bytes, so non-ASCII separators
+/// take one place.
+pub fn input_width_style(text: &str) -> String {
+    format!(
+        "box-sizing: content-box; width: {}ch; min-width: {}px; max-width: {}px;",
+        text.chars().count() + 1,
+        MIN_INPUT_WIDTH,
+        MAX_INPUT_WIDTH,
+    )
+}
+
+/// Applies the input mask while typing: drops characters that can't be part of a number (a minus
+/// sign is kept only in front, and only with `allow_negative`), a second decimal point, integer
+/// digits past `max_integer_digits`, and decimals past `max_decimals`. Thousands separators
+/// (commas and spaces) are kept.
+pub fn mask_numeric_input(
+    text: &str,
+    max_integer_digits: Option<usize>,
+    max_decimals: Option<usize>,
+    allow_negative: bool,
+) -> String {
+    let mut masked = String::new();
+    let (mut integer_digits, mut decimals) = (0, 0);
+    let mut seen_point = false;
+    for c in text.trim_start().chars() {
+        match c {
+            '-' if allow_negative && masked.is_empty() => masked.push(c),
+            '.' if !seen_point && max_decimals != Some(0) => {
+                seen_point = true;
+                masked.push(c);
+            }
+            ',' | ' ' if !seen_point => masked.push(c),
+            '0'..='9' if seen_point => {
+                if max_decimals.map_or(true, |max| decimals < max) {
+                    decimals += 1;
+                    masked.push(c);
+                }
+            }
+            '0'..='9' => {
+                if max_integer_digits.map_or(true, |max| integer_digits < max) {
+                    integer_digits += 1;
+                    masked.push(c);
+                }
+            }
+            _ => {}
+        }
+    }
+    masked
+}
+
+/// Number of decimal places in `step`, e.g., 3 for 0.125
+fn step_decimals(step: f64) -> usize {
+    format!("{}", step)
+        .split_once('.')
+        .map_or(0, |(_, decimals)| decimals.len())
+}
+
+/// The input text after stepping `steps` times (negative steps go down) from the typed value,
+/// or from `current` if the text isn't a number. Returns `None` when the result would be invalid,
+/// e.g., zero or below without `allow_negative`.
+pub fn step_input(
+    text: &str,
+    current: f64,
+    step: f64,
+    steps: f64,
+    allow_negative: bool,
+) -> Option<String> {
+    let value = text
+        .replace(",", "")
+        .replace(" ", "")
+        .parse::<f64>()
+        .unwrap_or(current);
+    let decimals = step_decimals(step);
+    let stepped = format!("{:.*}", decimals, value + step * steps);
+    let stepped_value = stepped.parse::<f64>().ok()?;
+    (stepped_value.is_finite() && (allow_negative || stepped_value > 0.0)).then_some(stepped)
+}
+
 #[component]
 pub fn NumericInput(
     label: String,
@@ -43,37 +147,111 @@ pub fn NumericInput(
     valid_signal: Signal<bool>,
     field_name: String,
     css_prefix: String,
+    /// Accept zero and negative values, e.g., for real yields or inflation
+    #[props(default = false)]
+    allow_negative: bool,
+    /// Amount the Up/Down arrow keys (and Alt+scroll) change the value by, e.g., 100 for a
+    /// principal or 0.125 for a rate; Shift moves ten steps. `None` turns stepping off.
+    #[props(default)]
+    step: Option<f64>,
+    /// Most digits allowed before the decimal point; extra digits are dropped as they are typed
+    #[props(default = Some(15))]
+    max_integer_digits: Option<usize>,
+    /// Most digits allowed after the decimal point, e.g., 2 for dollars and cents
+    #[props(default)]
+    max_decimals: Option<usize>,
 ) -> Element {
+    let mut apply_step = move |steps: f64| {
+        let Some(step) = step else {
+            return false;
+        };
+        let Some(stepped) = step_input(
+            &input_signal.peek(),
+            *value_signal.peek(),
+            step,
+            steps,
+            allow_negative,
+        ) else {
+            return true;
+        };
+        input_signal.set(stepped.clone());
+        validate_numeric_input(
+            &stepped,
+            &mut valid_signal,
+            &mut value_signal,
+            allow_negative,
+        );
+        true
+    };
+
     rsx! {
         div { style: "display: flex; align-items: center; margin-bottom: 15px;",
-            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+            label {
+                r#for: numeric_input_id(&css_prefix),
+                style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
                 {label}
             }
             input {
+                id: numeric_input_id(&css_prefix),
                 placeholder,
+                "aria-invalid": "{!valid_signal()}",
+                "aria-describedby": "{css_prefix}-error",
                 value: "{input_signal}",
                 class: if valid_signal() { format!("{}-input-valid", css_prefix) } else { format!("{}-input-invalid", css_prefix) },
-                style: {
-                    let input_width = std::cmp::max(100, input_signal().len() * 9 + 20);
-                    format!(
-                        "padding: 6px 8px; width: {}px; border-radius: 4px; font-family: monospace;",
-                        input_width,
-                    )
-                },
+                style: format!(
+                    "padding: 6px 8px; {} border-radius: 4px; font-family: monospace;",
+                    input_width_style(&input_signal()),
+                ),
                 oninput: move |event| {
-                    let input_text = event.value();
+                    let input_text = mask_numeric_input(
+                        &event.value(),
+                        max_integer_digits,
+                        max_decimals,
+                        allow_negative,
+                    );
                     input_signal.set(input_text.clone());
                     if input_text.trim().is_empty() {
                         valid_signal.set(true);
                         return;
                     }
                     let cleaned_input = input_text.replace(",", "").replace(" ", "");
-                    validate_numeric_input(&cleaned_input, &mut valid_signal, &mut value_signal);
+                    validate_numeric_input(
+                        &cleaned_input,
+                        &mut valid_signal,
+                        &mut value_signal,
+                        allow_negative,
+                    );
+                },
+                onkeydown: move |event| {
+                    let direction = match event.key() {
+                        Key::ArrowUp => 1.0,
+                        Key::ArrowDown => -1.0,
+                        _ => return,
+                    };
+                    let size = if event.modifiers().shift() { 10.0 } else { 1.0 };
+                    if apply_step(direction * size) {
+                        event.prevent_default();
+                    }
+                },
+                onwheel: move |event| {
+                    if !event.modifiers().alt() {
+                        return;
+                    }
+                    let delta_y = event.delta().strip_units().y;
+                    if delta_y == 0.0 {
+                        return;
+                    }
+                    let size = if event.modifiers().shift() { 10.0 } else { 1.0 };
+                    if apply_step(-delta_y.signum() * size) {
+                        event.prevent_default();
+                    }
                 },
             }
         }
         if !valid_signal() && !input_signal().trim().is_empty() {
-            div { style: "color: #ff0000; font-size: 12px; margin-left: 160px; margin-bottom: 10px;",
+            div {
+                id: "{css_prefix}-error",
+                style: "color: #ff0000; font-size: 12px; margin-left: 160px; margin-bottom: 10px;",
                 {get_numeric_error_message(&input_signal(), &field_name)}
             }
         }
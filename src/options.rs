@@ -0,0 +1,289 @@
+use serde::{Deserialize, Serialize};
+
+/// Inputs to the option pricing calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OptionInputs {
+    /// Current price of the underlying
+    pub spot: f64,
+    pub strike: f64,
+    /// Annual volatility of the underlying's returns as a decimal
+    pub volatility: f64,
+    /// Continuously compounded annual risk-free rate as a decimal
+    pub risk_free_rate: f64,
+    /// Continuously compounded annual dividend yield as a decimal
+    pub dividend_yield: f64,
+    /// Time to expiration in years
+    pub years: f64,
+}
+
+/// When an option can be exercised
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum Exercise {
+    /// Only at expiration
+    European,
+    /// At any time up to expiration
+    #[default]
+    American,
+}
+
+/// Most steps the binomial tree can be built with
+pub const MAX_STEPS: u32 = 2000;
+
+/// How the binomial tree is built
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BinomialInputs {
+    /// Time steps between today and expiration
+    pub steps: u32,
+    pub exercise: Exercise,
+}
+
+impl Default for BinomialInputs {
+    fn default() -> Self {
+        BinomialInputs {
+            steps: 100,
+            exercise: Exercise::American,
+        }
+    }
+}
+
+/// European call and put prices, and the terms they're built from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OptionPrices {
+    pub call: f64,
+    pub put: f64,
+    pub d1: f64,
+    pub d2: f64,
+}
+
+/// Standard normal cumulative distribution function, the chance a standard normal draw is at
+/// most `x`. Uses Hart's double-precision rational approximation (as given by West, 2005),
+/// accurate to about 1e-14.
+pub fn normal_cdf(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    let z = x.abs();
+    let tail = if z > 37.0 {
+        0.0
+    } else {
+        let density = (-z * z / 2.0).exp();
+        if z < 7.071_067_811_865_47 {
+            let numerator = [
+                0.035_262_496_599_891_1,
+                0.700_383_064_443_688,
+                6.373_962_203_531_65,
+                33.912_866_078_383,
+                112.079_291_497_871,
+                221.213_596_169_931,
+                220.206_867_912_376,
+            ]
+            .iter()
+            .fold(0.0, |sum, coefficient| sum * z + coefficient);
+            let denominator = [
+                0.088_388_347_648_318_4,
+                1.755_667_163_182_64,
+                16.064_177_579_207,
+                86.780_732_202_946_1,
+                296.564_248_779_674,
+                637.333_633_378_831,
+                793.826_512_519_948,
+                440.413_735_824_752,
+            ]
+            .iter()
+            .fold(0.0, |sum, coefficient| sum * z + coefficient);
+            density * numerator / denominator
+        } else {
+            // Continued fraction for the far tail
+            let fraction = [4.0, 3.0, 2.0, 1.0]
+                .iter()
+                .fold(z + 0.65, |fraction, k| z + k / fraction);
+            density / fraction / (2.0 * std::f64::consts::PI).sqrt()
+        }
+    };
+    if x > 0.0 {
+        1.0 - tail
+    } else {
+        tail
+    }
+}
+
+/// An error if the spot, strike, volatility, or time isn't positive, or a rate isn't a number
+fn validate(inputs: &OptionInputs) -> Result<(), String> {
+    if inputs.spot.is_nan() || inputs.spot <= 0.0 || inputs.strike.is_nan() || inputs.strike <= 0.0
+    {
+        return Err("The spot and strike prices must be greater than zero".to_string());
+    }
+    if inputs.volatility.is_nan() || inputs.volatility <= 0.0 {
+        return Err("Volatility must be greater than zero".to_string());
+    }
+    if inputs.years.is_nan() || inputs.years <= 0.0 {
+        return Err("Time to expiration must be greater than zero".to_string());
+    }
+    if !inputs.risk_free_rate.is_finite() || !inputs.dividend_yield.is_finite() {
+        return Err("Enter a risk-free rate and dividend yield".to_string());
+    }
+    Ok(())
+}
+
+// Prices European call and put options with the Black-Scholes-Merton model.
+///
+/// # Parameters:
+/// - `inputs`: Spot (S), strike (K), volatility (σ), risk-free rate (r), dividend yield (q), and
+///   years to expiration (T)
+///
+/// # Formula:
+/// d1 = (ln(S / K) + (r - q + σ² / 2) * T) / (σ * √T)
+/// d2 = d1 - σ * √T
+/// call = S * e^(-qT) * N(d1) - K * e^(-rT) * N(d2)
+/// put = K * e^(-rT) * N(-d2) - S * e^(-qT) * N(-d1)
+///
+/// # Returns:
+/// The call and put prices with d1 and d2. An error if the spot, strike, volatility, or time
+/// isn't positive, or a rate isn't a number.
+pub fn black_scholes(inputs: &OptionInputs) -> Result<OptionPrices, String> {
+    validate(inputs)?;
+
+    let root_t = inputs.years.sqrt();
+    let d1 = ((inputs.spot / inputs.strike).ln()
+        + (inputs.risk_free_rate - inputs.dividend_yield
+            + inputs.volatility * inputs.volatility / 2.0)
+            * inputs.years)
+        / (inputs.volatility * root_t);
+    let d2 = d1 - inputs.volatility * root_t;
+    let discounted_spot = inputs.spot * (-inputs.dividend_yield * inputs.years).exp();
+    let discounted_strike = inputs.strike * (-inputs.risk_free_rate * inputs.years).exp();
+
+    Ok(OptionPrices {
+        call: discounted_spot * normal_cdf(d1) - discounted_strike * normal_cdf(d2),
+        put: discounted_strike * normal_cdf(-d2) - discounted_spot * normal_cdf(-d1),
+        d1,
+        d2,
+    })
+}
+
+/// The underlying's price and the option values at one node of the binomial tree
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatticeNode {
+    pub spot: f64,
+    pub call: f64,
+    pub put: f64,
+    /// Whether exercising the call here is worth more than holding it
+    pub call_exercised: bool,
+    /// Whether exercising the put here is worth more than holding it
+    pub put_exercised: bool,
+}
+
+/// Binomial call and put prices, and the tree's moves
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BinomialPrices {
+    pub call: f64,
+    pub put: f64,
+    /// Factor the price is multiplied by on an up move
+    pub up: f64,
+    /// Factor the price is multiplied by on a down move
+    pub down: f64,
+    /// Risk-neutral chance of an up move
+    pub probability: f64,
+}
+
+/// Builds the tree from expiration back to today, handing each step's nodes, lowest price first,
+/// to `visit`
+fn roll_back(
+    inputs: &OptionInputs,
+    binomial: &BinomialInputs,
+    mut visit: impl FnMut(&[LatticeNode]),
+) -> Result<BinomialPrices, String> {
+    validate(inputs)?;
+    if binomial.steps == 0 || binomial.steps > MAX_STEPS {
+        return Err(format!("The tree needs 1 to {} steps", MAX_STEPS));
+    }
+
+    let steps = binomial.steps as i32;
+    let dt = inputs.years / steps as f64;
+    let up = (inputs.volatility * dt.sqrt()).exp();
+    let down = 1.0 / up;
+    let probability =
+        (((inputs.risk_free_rate - inputs.dividend_yield) * dt).exp() - down) / (up - down);
+    if !(0.0..=1.0).contains(&probability) {
+        return Err("The rates are too far apart for this volatility; add steps".to_string());
+    }
+    let discount = (-inputs.risk_free_rate * dt).exp();
+    let spot_at = |step: i32, ups: i32| inputs.spot * up.powi(ups) * down.powi(step - ups);
+
+    let mut nodes: Vec<LatticeNode> = (0..=steps)
+        .map(|ups| {
+            let spot = spot_at(steps, ups);
+            LatticeNode {
+                spot,
+                call: (spot - inputs.strike).max(0.0),
+                put: (inputs.strike - spot).max(0.0),
+                call_exercised: false,
+                put_exercised: false,
+            }
+        })
+        .collect();
+    visit(&nodes);
+    for step in (0..steps).rev() {
+        nodes = (0..=step)
+            .map(|ups| {
+                let (below, above) = (nodes[ups as usize], nodes[ups as usize + 1]);
+                let spot = spot_at(step, ups);
+                let call = discount * (probability * above.call + (1.0 - probability) * below.call);
+                let put = discount * (probability * above.put + (1.0 - probability) * below.put);
+                let american = binomial.exercise == Exercise::American;
+                let (call_now, put_now) = (spot - inputs.strike, inputs.strike - spot);
+                LatticeNode {
+                    spot,
+                    call: if american { call.max(call_now) } else { call },
+                    put: if american { put.max(put_now) } else { put },
+                    call_exercised: american && call_now > call,
+                    put_exercised: american && put_now > put,
+                }
+            })
+            .collect();
+        visit(&nodes);
+    }
+
+    Ok(BinomialPrices {
+        call: nodes[0].call,
+        put: nodes[0].put,
+        up,
+        down,
+        probability,
+    })
+}
+
+// Prices European or American call and put options with the Cox-Ross-Rubinstein binomial tree.
+///
+/// # Parameters:
+/// - `inputs`: Spot (S), strike (K), volatility (σ), risk-free rate (r), dividend yield (q), and
+///   years to expiration (T)
+/// - `binomial`: Steps (n) and whether early exercise is allowed
+///
+/// # Formula:
+/// Δt = T / n, u = e^(σ√Δt), d = 1 / u, p = (e^((r - q)Δt) - d) / (u - d)
+/// At expiration: call = max(S_T - K, 0), put = max(K - S_T, 0)
+/// Each earlier node: V = e^(-rΔt) * (p * V_up + (1 - p) * V_down), and for American options
+/// the larger of that and the value of exercising now
+///
+/// # Returns:
+/// The call and put prices with the tree's moves. An error if `black_scholes` would reject the
+/// inputs, the steps aren't 1 to `MAX_STEPS`, or the up-move chance falls outside 0% to 100%.
+pub fn binomial_prices(
+    inputs: &OptionInputs,
+    binomial: &BinomialInputs,
+) -> Result<BinomialPrices, String> {
+    roll_back(inputs, binomial, |_| {})
+}
+
+/// Every node of the binomial tree, by step from today, each step's nodes lowest price first.
+/// Meant for showing small trees; the node count grows with the square of the steps.
+pub fn price_lattice(
+    inputs: &OptionInputs,
+    binomial: &BinomialInputs,
+) -> Result<Vec<Vec<LatticeNode>>, String> {
+    let mut lattice = Vec::with_capacity(binomial.steps as usize + 1);
+    roll_back(inputs, binomial, |nodes| lattice.push(nodes.to_vec()))?;
+    lattice.reverse();
+    Ok(lattice)
+}
@@ -0,0 +1,380 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::numeric_input_component::NumericInput;
+use crate::options::{
+    binomial_prices, black_scholes, normal_cdf, price_lattice, BinomialInputs, Exercise,
+    LatticeNode, OptionInputs, MAX_STEPS,
+};
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Everything the user has entered, as autosaved between visits. The option's fields stay at the
+/// top level so inputs saved before the binomial tree was added still restore.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct OptionsSession {
+    #[serde(flatten)]
+    inputs: OptionInputs,
+    #[serde(default)]
+    binomial: BinomialInputs,
+}
+
+/// An at-the-money option expiring in a year on a stock with no dividend, the textbook example
+const DEFAULT_SESSION: OptionsSession = OptionsSession {
+    inputs: OptionInputs {
+        spot: 100.0,
+        strike: 100.0,
+        volatility: 0.20,
+        risk_free_rate: 0.05,
+        dividend_yield: 0.0,
+        years: 1.0,
+    },
+    binomial: BinomialInputs {
+        steps: 100,
+        exercise: Exercise::American,
+    },
+};
+
+const EXERCISE_OPTIONS: &[(Exercise, &str, &str)] = &[
+    (Exercise::American, "american", "American"),
+    (Exercise::European, "european", "European"),
+];
+
+/// Most steps the price lattice is drawn for
+const LATTICE_MAX_STEPS: u32 = 6;
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+const LABEL_STYLE: &str = "text-align: left; padding: 2px 8px;";
+
+/// Shades a lattice node where exercising early beats holding
+fn node_style(node: &LatticeNode) -> &'static str {
+    if node.call_exercised || node.put_exercised {
+        "text-align: right; padding: 2px 8px; border: 1px solid #ccc; background: #fff3cd;"
+    } else {
+        "text-align: right; padding: 2px 8px; border: 1px solid #ccc;"
+    }
+}
+
+/// The tree laid out as a grid, today on the left and each step's highest price on top
+#[component]
+fn PriceLattice(lattice: Vec<Vec<LatticeNode>>) -> Element {
+    let steps = lattice.len() - 1;
+    // A node with `ups` up moves after `step` steps sits `2 * ups - step` rows above the middle
+    let node_at = |row: usize, step: usize| {
+        let height = steps as i64 - row as i64;
+        let doubled_ups = height + step as i64;
+        if doubled_ups < 0 || doubled_ups % 2 != 0 || doubled_ups / 2 > step as i64 {
+            return None;
+        }
+        Some(lattice[step][(doubled_ups / 2) as usize])
+    };
+
+    rsx! {
+        table { id: "OptionsLattice", style: "color: #333; border-collapse: collapse; font-size: 12px; margin-bottom: 15px;",
+            thead {
+                tr {
+                    for step in 0..=steps {
+                        th { scope: "col", style: CELL_STYLE, "Step {step}" }
+                    }
+                }
+            }
+            tbody {
+                for row in 0..=2 * steps {
+                    tr {
+                        for step in 0..=steps {
+                            if let Some(node) = node_at(row, step) {
+                                td {
+                                    style: node_style(&node),
+                                    div { style: "font-weight: bold;", "${format_dollars(node.spot)}" }
+                                    div {
+                                        "C ${format_dollars(node.call)}"
+                                        if node.call_exercised {
+                                            " (exercise)"
+                                        }
+                                    }
+                                    div {
+                                        "P ${format_dollars(node.put)}"
+                                        if node.put_exercised {
+                                            " (exercise)"
+                                        }
+                                    }
+                                }
+                            } else {
+                                td {}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn OptionsUI() -> Element {
+    let mut spot_signal = use_signal(|| 100.0 as f64);
+    let mut spot_input = use_signal(|| "100".to_string());
+    let spot_input_valid = use_signal(|| true);
+    let mut strike_signal = use_signal(|| 100.0 as f64);
+    let mut strike_input = use_signal(|| "100".to_string());
+    let strike_input_valid = use_signal(|| true);
+    // Volatility and rates are entered in percent
+    let mut volatility_signal = use_signal(|| 20.0 as f64);
+    let mut volatility_input = use_signal(|| "20.0".to_string());
+    let volatility_input_valid = use_signal(|| true);
+    let mut rate_signal = use_signal(|| 5.0 as f64);
+    let mut rate_input = use_signal(|| "5.0".to_string());
+    let rate_input_valid = use_signal(|| true);
+    let mut dividend_signal = use_signal(|| 0.0 as f64);
+    let mut dividend_input = use_signal(|| "0".to_string());
+    let dividend_input_valid = use_signal(|| true);
+    let mut years_signal = use_signal(|| 1.0 as f64);
+    let mut years_input = use_signal(|| "1".to_string());
+    let years_input_valid = use_signal(|| true);
+    let mut steps_signal = use_signal(|| 100.0 as f64);
+    let mut steps_input = use_signal(|| "100".to_string());
+    let steps_input_valid = use_signal(|| true);
+    let mut exercise_signal = use_signal(|| Exercise::American);
+    let mut show_lattice_signal = use_signal(|| false);
+
+    let inputs = move || OptionInputs {
+        spot: spot_signal(),
+        strike: strike_signal(),
+        volatility: volatility_signal() / 100.0,
+        risk_free_rate: rate_signal() / 100.0,
+        dividend_yield: dividend_signal() / 100.0,
+        years: years_signal(),
+    };
+    let binomial = move || BinomialInputs {
+        steps: steps_signal().round() as u32,
+        exercise: exercise_signal(),
+    };
+    let start_fresh = use_autosave(
+        "options",
+        DEFAULT_SESSION,
+        move || OptionsSession {
+            inputs: inputs(),
+            binomial: binomial(),
+        },
+        use_callback(move |session: OptionsSession| {
+            let inputs = session.inputs;
+            spot_signal.set(inputs.spot);
+            spot_input.set(format!("{}", inputs.spot));
+            strike_signal.set(inputs.strike);
+            strike_input.set(format!("{}", inputs.strike));
+            volatility_signal.set(inputs.volatility * 100.0);
+            volatility_input.set(format!("{}", inputs.volatility * 100.0));
+            rate_signal.set(inputs.risk_free_rate * 100.0);
+            rate_input.set(format!("{}", inputs.risk_free_rate * 100.0));
+            dividend_signal.set(inputs.dividend_yield * 100.0);
+            dividend_input.set(format!("{}", inputs.dividend_yield * 100.0));
+            years_signal.set(inputs.years);
+            years_input.set(format!("{}", inputs.years));
+            steps_signal.set(session.binomial.steps as f64);
+            steps_input.set(format!("{}", session.binomial.steps));
+            exercise_signal.set(session.binomial.exercise);
+        }),
+    );
+    use_track_calculator("options");
+    use_track_input_range("options", "spot", spot_signal, amount_bucket);
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Spot Price ($):".to_string(),
+            placeholder: "Enter the underlying's current price (e.g., 100)".to_string(),
+            input_signal: spot_input,
+            value_signal: spot_signal,
+            valid_signal: spot_input_valid,
+            field_name: "Spot price".to_string(),
+            css_prefix: "spot".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Strike Price ($):".to_string(),
+            placeholder: "Enter the price the option can be exercised at (e.g., 100)".to_string(),
+            input_signal: strike_input,
+            value_signal: strike_signal,
+            valid_signal: strike_input_valid,
+            field_name: "Strike price".to_string(),
+            css_prefix: "strike".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Volatility (%/yr):".to_string(),
+            placeholder: "Enter the annual volatility of the underlying (e.g., 20.0)".to_string(),
+            input_signal: volatility_input,
+            value_signal: volatility_signal,
+            valid_signal: volatility_input_valid,
+            field_name: "Volatility".to_string(),
+            css_prefix: "volatility".to_string(),
+            step: Some(1.0),
+        }
+        NumericInput {
+            label: "Risk-Free Rate (%/yr):".to_string(),
+            placeholder: "Enter the Treasury yield to expiration (e.g., 5.0)".to_string(),
+            input_signal: rate_input,
+            value_signal: rate_signal,
+            valid_signal: rate_input_valid,
+            field_name: "Risk-free rate".to_string(),
+            css_prefix: "rate".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+        NumericInput {
+            label: "Dividend Yield (%/yr):".to_string(),
+            placeholder: "Enter the underlying's dividend yield, or 0 (e.g., 0)".to_string(),
+            input_signal: dividend_input,
+            value_signal: dividend_signal,
+            valid_signal: dividend_input_valid,
+            field_name: "Dividend yield".to_string(),
+            css_prefix: "dividend".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+        NumericInput {
+            label: "Time to Expiration (years):".to_string(),
+            placeholder: "Enter the years until the option expires, e.g., 0.25 for 3 months".to_string(),
+            input_signal: years_input,
+            value_signal: years_signal,
+            valid_signal: years_input_valid,
+            field_name: "Time to expiration".to_string(),
+            css_prefix: "years".to_string(),
+            step: Some(0.25),
+        }
+
+        {
+            match black_scholes(&inputs()) {
+                Ok(prices) => rsx! {
+                    div {
+                        id: "OptionsCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        "Call: ${format_dollars(prices.call)}, Put: ${format_dollars(prices.put)}*"
+                    }
+                    table { style: "color: #333; border-collapse: collapse; font-size: 14px; margin-bottom: 15px;",
+                        tbody {
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "d1" }
+                                td { style: CELL_STYLE, "{prices.d1:.4}" }
+                                th { scope: "row", style: LABEL_STYLE, "N(d1)" }
+                                td { style: CELL_STYLE, "{normal_cdf(prices.d1):.4}" }
+                            }
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "d2" }
+                                td { style: CELL_STYLE, "{prices.d2:.4}" }
+                                th { scope: "row", style: LABEL_STYLE, "N(d2)" }
+                                td { style: CELL_STYLE, "{normal_cdf(prices.d2):.4}" }
+                            }
+                        }
+                    }
+                },
+                Err(error) => rsx! {
+                    div {
+                        id: "OptionsCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Exercise Style:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(exercise, _, _)) = EXERCISE_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        exercise_signal.set(exercise);
+                    }
+                },
+                for (exercise , value , display) in EXERCISE_OPTIONS.iter() {
+                    option { value: *value, selected: exercise_signal() == *exercise, {*display} }
+                }
+            }
+        }
+        NumericInput {
+            label: "Tree Steps:".to_string(),
+            placeholder: format!("Enter the number of time steps, 1 to {} (e.g., 100)", MAX_STEPS),
+            input_signal: steps_input,
+            value_signal: steps_signal,
+            valid_signal: steps_input_valid,
+            field_name: "Tree steps".to_string(),
+            css_prefix: "steps".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(0),
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Show Price Lattice:"
+            }
+            input {
+                r#type: "checkbox",
+                checked: show_lattice_signal(),
+                onchange: move |event| show_lattice_signal.set(event.checked()),
+            }
+        }
+
+        {
+            let exercise = EXERCISE_OPTIONS
+                .iter()
+                .find(|(exercise, _, _)| *exercise == exercise_signal())
+                .map_or("", |(_, _, display)| *display);
+            match binomial_prices(&inputs(), &binomial()) {
+                Ok(prices) => rsx! {
+                    div {
+                        id: "OptionsBinomial",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        "{exercise} call: ${format_dollars(prices.call)}, put: ${format_dollars(prices.put)}**"
+                    }
+                    div { style: "margin-bottom: 15px; font-size: 14px; color: #333;",
+                        "Each step the price moves up by {prices.up:.4}x or down by {prices.down:.4}x, up with a risk-neutral chance of {prices.probability * 100.0:.2}%."
+                    }
+                    if show_lattice_signal() {
+                        if binomial().steps <= LATTICE_MAX_STEPS {
+                            if let Ok(lattice) = price_lattice(&inputs(), &binomial()) {
+                                PriceLattice { lattice }
+                            }
+                        } else {
+                            div { style: "margin-bottom: 15px; font-size: 14px; color: #333;",
+                                "Set {LATTICE_MAX_STEPS} steps or fewer to see the price lattice."
+                            }
+                        }
+                    }
+                },
+                Err(error) => rsx! {
+                    div {
+                        id: "OptionsBinomial",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "options" }
+        AssumptionsFooter { calculator: "options" }
+        div { style: "font-size: 10px",
+            p {
+                "*Black-Scholes value of one European option on one share, exercisable only at expiration. Listed contracts usually cover 100 shares."
+            }
+            p {
+                "**Cox-Ross-Rubinstein binomial value. American options can be exercised at any step; more steps get closer to the continuous-time price."
+            }
+            br {}
+            br {}
+        }
+    }
+}
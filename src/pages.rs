@@ -0,0 +1,177 @@
+use crate::blog::{find_post, POSTS};
+use crate::calculators::{CalculatorInfo, CALCULATORS};
+use crate::Route;
+use serde_json::{json, Value};
+
+/// Where the site is published, for the absolute URLs sitemaps and structured data need. Set
+/// `SITE_URL` when building to publish elsewhere.
+pub const SITE_URL: &str = match option_env!("SITE_URL") {
+    Some(url) => url,
+    None => "https://aaelony.github.io/dioxus_fv_app",
+};
+
+/// Longest meta description, in characters; search engines cut off longer ones
+const MAX_DESCRIPTION_CHARS: usize = 155;
+
+/// Every page worth pre-rendering and indexing: the calculators compiled in, the blog index and
+/// each post, and the glossary. Settings and the verify page are left out; they have nothing to
+/// find.
+pub fn page_routes() -> Vec<Route> {
+    CALCULATORS
+        .iter()
+        .filter(|calculator| calculator.included)
+        .map(|calculator| calculator.route())
+        .chain([Route::BlogIndex {}, Route::GlossaryUI {}])
+        .chain(POSTS.iter().map(|post| Route::Blog {
+            slug: post.slug.to_string(),
+        }))
+        .collect()
+}
+
+/// The calculator a route shows, if it shows one
+fn calculator_for(route: &Route) -> Option<&'static CalculatorInfo> {
+    CALCULATORS
+        .iter()
+        .find(|calculator| calculator.included && calculator.route() == *route)
+}
+
+/// Page title for a route, without the site name
+pub fn page_title(route: &Route) -> String {
+    if let Some(calculator) = calculator_for(route) {
+        return calculator.name.to_string();
+    }
+    match route {
+        Route::Blog { slug } => find_post(slug)
+            .map(|(_, post)| post.title.to_string())
+            .unwrap_or_else(|| "Post not found".to_string()),
+        Route::BlogIndex {} => "Blog".to_string(),
+        Route::GlossaryUI {} => "Glossary".to_string(),
+        Route::SettingsUI {} => "Settings".to_string(),
+        Route::SharedScenarioUI { .. } => "Shared scenario".to_string(),
+        Route::VerifyUI {} => "Verify this build".to_string(),
+        _ => "Calculator not included".to_string(),
+    }
+}
+
+/// Meta description for a route: the calculator's description, or a post's opening sentences
+pub fn page_description(route: &Route) -> String {
+    if let Some(calculator) = calculator_for(route) {
+        return calculator.description.to_string();
+    }
+    match route {
+        Route::Blog { slug } => find_post(slug)
+            .map(|(_, post)| summarize(post.body))
+            .unwrap_or_default(),
+        Route::BlogIndex {} => "Articles on compound interest, retirement, bonds, and the math \
+                                behind the calculators."
+            .to_string(),
+        Route::GlossaryUI {} => {
+            "Plain-language definitions of the financial terms used across the calculators."
+                .to_string()
+        }
+        _ => String::new(),
+    }
+}
+
+/// The first paragraph of a Markdown post as plain text, cut at a word to fit a meta description
+pub fn summarize(markdown: &str) -> String {
+    let paragraph = markdown.split("\n\n").next().unwrap_or_default();
+    // Glossary links are written [[term]] or [[term|shown text]]
+    let mut text = String::new();
+    let mut rest = paragraph;
+    while let Some(start) = rest.find("[[") {
+        let Some(end) = rest[start..].find("]]") else {
+            break;
+        };
+        text.push_str(&rest[..start]);
+        let link = &rest[start + 2..start + end];
+        text.push_str(link.rsplit('|').next().unwrap_or(link));
+        rest = &rest[start + end + 2..];
+    }
+    text.push_str(rest);
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if text.chars().count() <= MAX_DESCRIPTION_CHARS {
+        return text;
+    }
+    let mut summary = String::new();
+    for word in text.split(' ') {
+        if summary.chars().count() + word.chars().count() + 1 >= MAX_DESCRIPTION_CHARS {
+            break;
+        }
+        if !summary.is_empty() {
+            summary.push(' ');
+        }
+        summary.push_str(word);
+    }
+    format!(
+        "{}…",
+        summary.trim_end_matches(|c: char| c.is_ascii_punctuation())
+    )
+}
+
+/// Absolute URL of a route
+pub fn page_url(route: &Route) -> String {
+    format!("{}{}", SITE_URL.trim_end_matches('/'), route)
+}
+
+/// Escapes the characters XML reserves
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// sitemap.xml listing every page in `page_routes`
+pub fn sitemap_xml() -> String {
+    let urls: String = page_routes()
+        .iter()
+        .map(|route| format!("  <url><loc>{}</loc></url>\n", escape_xml(&page_url(route))))
+        .collect();
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n{}</urlset>\n",
+        urls
+    )
+}
+
+/// schema.org JSON-LD for a route: a WebApplication for a calculator, an Article for a blog
+/// post, and a WebPage otherwise
+pub fn structured_data(route: &Route, site_name: &str) -> Value {
+    let url = page_url(route);
+    let name = page_title(route);
+    let description = page_description(route);
+    if calculator_for(route).is_some() {
+        return json!({
+            "@context": "https://schema.org",
+            "@type": "WebApplication",
+            "name": name,
+            "description": description,
+            "url": url,
+            "applicationCategory": "FinanceApplication",
+            "operatingSystem": "Any",
+            "isAccessibleForFree": true,
+            "offers": { "@type": "Offer", "price": "0", "priceCurrency": "USD" },
+        });
+    }
+    match route {
+        Route::Blog { slug } if find_post(slug).is_some() => json!({
+            "@context": "https://schema.org",
+            "@type": "Article",
+            "headline": name,
+            "description": description,
+            "url": url,
+            "mainEntityOfPage": url,
+            "publisher": { "@type": "Organization", "name": site_name },
+        }),
+        _ => json!({
+            "@context": "https://schema.org",
+            "@type": "WebPage",
+            "name": name,
+            "description": description,
+            "url": url,
+        }),
+    }
+}
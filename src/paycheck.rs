@@ -0,0 +1,259 @@
+use crate::tax::{tax_table, FilingStatus};
+use serde::{Deserialize, Serialize};
+
+/// Employee share of Social Security tax, on wages up to the year's wage base
+pub const SOCIAL_SECURITY_RATE: f64 = 0.062;
+
+/// Employee share of Medicare tax, on all wages
+pub const MEDICARE_RATE: f64 = 0.0145;
+
+/// Additional Medicare tax on wages above the filing status's threshold
+pub const ADDITIONAL_MEDICARE_RATE: f64 = 0.009;
+
+/// Social Security wage base by tax year, oldest first
+pub const SOCIAL_SECURITY_WAGE_BASES: &[(i32, f64)] = &[(2025, 176_100.0), (2026, 184_500.0)];
+
+/// Wages above which the additional Medicare tax applies. Married couples filing jointly
+/// count their wages together.
+pub fn additional_medicare_threshold(status: FilingStatus) -> f64 {
+    match status {
+        FilingStatus::Single => 200_000.0,
+        FilingStatus::MarriedFilingJointly => 250_000.0,
+    }
+}
+
+/// The Social Security wage base for a year, falling back to the nearest year on record
+pub fn social_security_wage_base(year: i32) -> f64 {
+    SOCIAL_SECURITY_WAGE_BASES
+        .iter()
+        .rev()
+        .find(|(base_year, _)| *base_year <= year)
+        .or(SOCIAL_SECURITY_WAGE_BASES.first())
+        .map_or(0.0, |(_, base)| *base)
+}
+
+/// One earner's pay for the year
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Earner {
+    pub salary: f64,
+    /// 401(k), HSA, and other deductions that lower taxable income, but not FICA wages
+    pub pretax_deductions: f64,
+}
+
+/// Inputs to the household paycheck calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PaycheckInputs {
+    pub status: FilingStatus,
+    pub first: Earner,
+    /// A spouse's pay; ignored unless filing jointly
+    pub second: Earner,
+    /// Paychecks per year, e.g., 26 for biweekly
+    pub periods_per_year: f64,
+}
+
+/// A household's federal taxes and take-home pay for the year
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaycheckOutputs {
+    pub gross: f64,
+    pub pretax_deductions: f64,
+    pub income_tax: f64,
+    /// Social Security and Medicare, including any additional Medicare tax
+    pub fica: f64,
+    /// Gross pay less deductions and taxes
+    pub take_home: f64,
+    pub take_home_per_paycheck: f64,
+    /// Bracket rate on the household's last dollar of taxable income
+    pub marginal_rate: f64,
+    /// Income tax and FICA the second income adds to the household's
+    pub second_income_tax: f64,
+    /// `second_income_tax` as a share of the second salary
+    pub second_income_rate: f64,
+    /// Take-home pay the second income adds
+    pub second_take_home: f64,
+}
+
+/// Income tax and FICA on a household's earners
+fn household_taxes(earners: &[Earner], status: FilingStatus, year: i32) -> (f64, f64) {
+    let table = tax_table(year, status);
+    let wage_base = social_security_wage_base(year);
+    let taxable_wages: f64 = earners
+        .iter()
+        .map(|earner| earner.salary - earner.pretax_deductions)
+        .sum();
+    let income_tax = table.ordinary_tax((taxable_wages - table.standard_deduction).max(0.0));
+    let wages: f64 = earners.iter().map(|earner| earner.salary).sum();
+    let fica = earners
+        .iter()
+        .map(|earner| {
+            earner.salary.min(wage_base) * SOCIAL_SECURITY_RATE + earner.salary * MEDICARE_RATE
+        })
+        .sum::<f64>()
+        + (wages - additional_medicare_threshold(status)).max(0.0) * ADDITIONAL_MEDICARE_RATE;
+    (income_tax, fica)
+}
+
+// Computes a household's federal taxes and take-home pay, and what a second income adds.
+///
+/// # Parameters:
+/// - `inputs`: Filing status, each earner's salary and pretax deductions, and paychecks a year
+/// - `year`: Tax year whose brackets and Social Security wage base apply
+///
+/// # Formula:
+/// income tax = brackets(sum(salary - pretax) - standard deduction)
+/// FICA = sum(min(salary, wage base) * 6.2% + salary * 1.45%)
+///      + (sum(salary) - threshold)+ * 0.9%
+/// second income's tax = taxes(both earners) - taxes(first earner alone)
+///
+/// # Returns:
+/// The household's taxes and take-home pay for the year and per paycheck. An error if an entry
+/// is negative, deductions exceed a salary, or there are no paychecks.
+pub fn compute_paycheck(inputs: &PaycheckInputs, year: i32) -> Result<PaycheckOutputs, String> {
+    let both = [inputs.first, inputs.second];
+    let earners = match inputs.status {
+        FilingStatus::Single => &both[..1],
+        FilingStatus::MarriedFilingJointly => &both[..],
+    };
+    if earners.iter().any(|earner| {
+        earner.salary.is_nan() || earner.salary < 0.0 || earner.pretax_deductions < 0.0
+    }) {
+        return Err("Salaries and deductions can't be negative".to_string());
+    }
+    if earners
+        .iter()
+        .any(|earner| earner.pretax_deductions > earner.salary)
+    {
+        return Err("Pretax deductions can't exceed the salary".to_string());
+    }
+    if inputs.periods_per_year.is_nan() || inputs.periods_per_year < 1.0 {
+        return Err("Enter at least one paycheck a year".to_string());
+    }
+
+    let (income_tax, fica) = household_taxes(earners, inputs.status, year);
+    let gross: f64 = earners.iter().map(|earner| earner.salary).sum();
+    let pretax_deductions: f64 = earners.iter().map(|earner| earner.pretax_deductions).sum();
+    let take_home = gross - pretax_deductions - income_tax - fica;
+    let table = tax_table(year, inputs.status);
+    let marginal_rate = table.marginal_rate(gross - pretax_deductions - table.standard_deduction);
+
+    let (second_income_tax, second_take_home) = if earners.len() > 1 {
+        let (first_tax, first_fica) = household_taxes(&earners[..1], inputs.status, year);
+        let added_tax = income_tax + fica - first_tax - first_fica;
+        let second = earners[1];
+        (
+            added_tax,
+            second.salary - second.pretax_deductions - added_tax,
+        )
+    } else {
+        (0.0, 0.0)
+    };
+
+    Ok(PaycheckOutputs {
+        gross,
+        pretax_deductions,
+        income_tax,
+        fica,
+        take_home,
+        take_home_per_paycheck: take_home / inputs.periods_per_year,
+        marginal_rate,
+        second_income_tax,
+        second_income_rate: if earners.len() > 1 && earners[1].salary > 0.0 {
+            second_income_tax / earners[1].salary
+        } else {
+            0.0
+        },
+        second_take_home,
+    })
+}
+
+/// Flat federal withholding rate on supplemental wages such as bonuses, up to
+/// `SUPPLEMENTAL_MILLION`
+pub const SUPPLEMENTAL_WITHHOLDING_RATE: f64 = 0.22;
+
+/// Mandatory withholding rate on supplemental wages above `SUPPLEMENTAL_MILLION` in a year
+pub const SUPPLEMENTAL_WITHHOLDING_RATE_ABOVE_MILLION: f64 = 0.37;
+
+/// Supplemental wages in a year withheld at the flat rate before the higher rate applies
+pub const SUPPLEMENTAL_MILLION: f64 = 1_000_000.0;
+
+/// Inputs to the bonus withholding explainer
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BonusInputs {
+    pub status: FilingStatus,
+    /// Regular pay for the year, and its pretax deductions
+    pub earner: Earner,
+    pub bonus: f64,
+}
+
+/// Federal income tax withheld from a bonus, against what it actually adds to the year's tax
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BonusTax {
+    /// Income tax withheld from the bonus at the flat supplemental rates
+    pub withheld: f64,
+    /// Income tax the bonus adds to the year's bill
+    pub actual_tax: f64,
+    /// `actual_tax` as a share of the bonus
+    pub actual_rate: f64,
+    /// Bracket rate on the last dollar of the bonus
+    pub marginal_rate: f64,
+    /// Income tax on the salary alone as a share of it, the rate a regular paycheck reflects
+    pub salary_average_rate: f64,
+    /// Social Security and Medicare on the bonus, withheld and owed alike
+    pub fica: f64,
+    /// Withheld less owed: positive comes back as a refund, negative is owed at tax time
+    pub difference: f64,
+}
+
+/// Federal income tax withheld from supplemental wages at the flat rates
+pub fn supplemental_withholding(bonus: f64) -> f64 {
+    bonus.min(SUPPLEMENTAL_MILLION) * SUPPLEMENTAL_WITHHOLDING_RATE
+        + (bonus - SUPPLEMENTAL_MILLION).max(0.0) * SUPPLEMENTAL_WITHHOLDING_RATE_ABOVE_MILLION
+}
+
+// Compares the flat withholding on a bonus with the tax it actually adds.
+///
+/// # Parameters:
+/// - `inputs`: Filing status, the regular salary and its pretax deductions, and the bonus
+/// - `year`: Tax year whose brackets and Social Security wage base apply
+///
+/// # Formula:
+/// withheld = min(bonus, $1M) * 22% + (bonus - $1M)+ * 37%
+/// actual tax = brackets(salary + bonus - pretax - standard deduction)
+///            - brackets(salary - pretax - standard deduction)
+///
+/// # Returns:
+/// The withholding, the actual tax and its rates, and the difference settled at tax time. An
+/// error if an entry is negative or deductions exceed the salary.
+pub fn compute_bonus_tax(inputs: &BonusInputs, year: i32) -> Result<BonusTax, String> {
+    if inputs.bonus.is_nan() || inputs.bonus < 0.0 {
+        return Err("The bonus can't be negative".to_string());
+    }
+    let paycheck = |bonus: f64| {
+        compute_paycheck(
+            &PaycheckInputs {
+                status: inputs.status,
+                first: Earner {
+                    salary: inputs.earner.salary + bonus,
+                    ..inputs.earner
+                },
+                second: Earner::default(),
+                periods_per_year: 1.0,
+            },
+            year,
+        )
+    };
+    let without = paycheck(0.0)?;
+    let with = paycheck(inputs.bonus)?;
+
+    let withheld = supplemental_withholding(inputs.bonus);
+    let actual_tax = with.income_tax - without.income_tax;
+    let rate_of = |amount: f64, base: f64| if base > 0.0 { amount / base } else { 0.0 };
+    Ok(BonusTax {
+        withheld,
+        actual_tax,
+        actual_rate: rate_of(actual_tax, inputs.bonus),
+        marginal_rate: with.marginal_rate,
+        salary_average_rate: rate_of(without.income_tax, inputs.earner.salary),
+        fica: with.fica - without.fica,
+        difference: withheld - actual_tax,
+    })
+}
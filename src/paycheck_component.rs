@@ -0,0 +1,259 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::data_year::{use_data_year, DataYearBanner};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::numeric_input_component::NumericInput;
+use crate::paycheck::{compute_paycheck, Earner, PaycheckInputs};
+use crate::tax::FilingStatus;
+use dioxus::prelude::*;
+
+/// Two earners filing jointly, paid biweekly, each saving some of their pay in a 401(k)
+const DEFAULT_INPUTS: PaycheckInputs = PaycheckInputs {
+    status: FilingStatus::MarriedFilingJointly,
+    first: Earner {
+        salary: 85_000.0,
+        pretax_deductions: 5_000.0,
+    },
+    second: Earner {
+        salary: 60_000.0,
+        pretax_deductions: 3_000.0,
+    },
+    periods_per_year: 26.0,
+};
+
+const STATUS_OPTIONS: &[(FilingStatus, &str)] = &[
+    (FilingStatus::Single, "single"),
+    (FilingStatus::MarriedFilingJointly, "joint"),
+];
+
+const FREQUENCY_OPTIONS: &[(f64, &str, &str)] = &[
+    (52.0, "weekly", "Weekly"),
+    (26.0, "biweekly", "Biweekly"),
+    (24.0, "semimonthly", "Semimonthly"),
+    (12.0, "monthly", "Monthly"),
+];
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+const LABEL_STYLE: &str = "text-align: left; padding: 2px 8px;";
+
+#[component]
+pub fn PaycheckUI() -> Element {
+    let tax_year = use_data_year("tax_brackets");
+    let mut status_signal = use_signal(|| FilingStatus::MarriedFilingJointly);
+    let mut salary_signal = use_signal(|| 85_000.0 as f64);
+    let mut salary_input = use_signal(|| "85000".to_string());
+    let salary_input_valid = use_signal(|| true);
+    let mut pretax_signal = use_signal(|| 5_000.0 as f64);
+    let mut pretax_input = use_signal(|| "5000".to_string());
+    let pretax_input_valid = use_signal(|| true);
+    let mut second_salary_signal = use_signal(|| 60_000.0 as f64);
+    let mut second_salary_input = use_signal(|| "60000".to_string());
+    let second_salary_input_valid = use_signal(|| true);
+    let mut second_pretax_signal = use_signal(|| 3_000.0 as f64);
+    let mut second_pretax_input = use_signal(|| "3000".to_string());
+    let second_pretax_input_valid = use_signal(|| true);
+    let mut frequency_signal = use_signal(|| 26.0 as f64);
+
+    let inputs = move || PaycheckInputs {
+        status: status_signal(),
+        first: Earner {
+            salary: salary_signal(),
+            pretax_deductions: pretax_signal(),
+        },
+        second: Earner {
+            salary: second_salary_signal(),
+            pretax_deductions: second_pretax_signal(),
+        },
+        periods_per_year: frequency_signal(),
+    };
+    let start_fresh = use_autosave(
+        "paycheck",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: PaycheckInputs| {
+            status_signal.set(inputs.status);
+            salary_signal.set(inputs.first.salary);
+            salary_input.set(format!("{}", inputs.first.salary));
+            pretax_signal.set(inputs.first.pretax_deductions);
+            pretax_input.set(format!("{}", inputs.first.pretax_deductions));
+            second_salary_signal.set(inputs.second.salary);
+            second_salary_input.set(format!("{}", inputs.second.salary));
+            second_pretax_signal.set(inputs.second.pretax_deductions);
+            second_pretax_input.set(format!("{}", inputs.second.pretax_deductions));
+            frequency_signal.set(inputs.periods_per_year);
+        }),
+    );
+    use_track_calculator("paycheck");
+    use_track_input_range("paycheck", "salary", salary_signal, amount_bucket);
+
+    let joint = status_signal() == FilingStatus::MarriedFilingJointly;
+    let frequency_name = FREQUENCY_OPTIONS
+        .iter()
+        .find(|(periods, _, _)| *periods == frequency_signal())
+        .map_or("", |(_, _, display)| *display)
+        .to_ascii_lowercase();
+
+    rsx! {
+        hr {}
+        br {}
+
+        DataYearBanner { data_set: "tax_brackets", year: tax_year }
+
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Filing Status:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(status, _)) = STATUS_OPTIONS
+                        .iter()
+                        .find(|(_, value_str)| *value_str == value.as_str())
+                    {
+                        status_signal.set(status);
+                    }
+                },
+                for (status , value) in STATUS_OPTIONS.iter() {
+                    option { value: *value, selected: status_signal() == *status, {status.label()} }
+                }
+            }
+        }
+        NumericInput {
+            label: if joint { "Your Salary ($/yr):".to_string() } else { "Salary ($/yr):".to_string() },
+            placeholder: "Enter gross pay before any deductions (e.g., 85000)".to_string(),
+            input_signal: salary_input,
+            value_signal: salary_signal,
+            valid_signal: salary_input_valid,
+            field_name: "Salary".to_string(),
+            css_prefix: "salary".to_string(),
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Pretax Deductions ($/yr):".to_string(),
+            placeholder: "Enter 401(k), HSA, and other pretax deductions (e.g., 5000)".to_string(),
+            input_signal: pretax_input,
+            value_signal: pretax_signal,
+            valid_signal: pretax_input_valid,
+            field_name: "Pretax deductions".to_string(),
+            css_prefix: "pretax".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+        if joint {
+            NumericInput {
+                label: "Spouse's Salary ($/yr):".to_string(),
+                placeholder: "Enter the second earner's gross pay, or 0 (e.g., 60000)".to_string(),
+                input_signal: second_salary_input,
+                value_signal: second_salary_signal,
+                valid_signal: second_salary_input_valid,
+                field_name: "Spouse's salary".to_string(),
+                css_prefix: "second-salary".to_string(),
+                step: Some(1000.0),
+                max_decimals: Some(2),
+            }
+            NumericInput {
+                label: "Spouse's Pretax ($/yr):".to_string(),
+                placeholder: "Enter the second earner's pretax deductions (e.g., 3000)".to_string(),
+                input_signal: second_pretax_input,
+                value_signal: second_pretax_signal,
+                valid_signal: second_pretax_input_valid,
+                field_name: "Spouse's pretax deductions".to_string(),
+                css_prefix: "second-pretax".to_string(),
+                step: Some(500.0),
+                max_decimals: Some(2),
+            }
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Paid:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(periods, _, _)) = FREQUENCY_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        frequency_signal.set(periods);
+                    }
+                },
+                for (periods , value , display) in FREQUENCY_OPTIONS.iter() {
+                    option { value: *value, selected: frequency_signal() == *periods, {*display} }
+                }
+            }
+        }
+
+        {
+            match compute_paycheck(&inputs(), tax_year()) {
+                Ok(outputs) => rsx! {
+                    div {
+                        id: "PaycheckCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        if joint {
+                            "Combined take-home: ${format_dollars(outputs.take_home_per_paycheck)} {frequency_name}*"
+                        } else {
+                            "Take-home: ${format_dollars(outputs.take_home_per_paycheck)} {frequency_name}*"
+                        }
+                    }
+                    table { style: "color: #333; border-collapse: collapse; font-size: 14px; margin-bottom: 15px;",
+                        tbody {
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Gross pay" }
+                                td { style: CELL_STYLE, {format_dollars(outputs.gross)} }
+                            }
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Pretax deductions" }
+                                td { style: CELL_STYLE, {format_dollars(outputs.pretax_deductions)} }
+                            }
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Federal income tax ({tax_year})" }
+                                td { style: CELL_STYLE, {format_dollars(outputs.income_tax)} }
+                            }
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Social Security & Medicare" }
+                                td { style: CELL_STYLE, {format_dollars(outputs.fica)} }
+                            }
+                            tr { style: "font-weight: bold;",
+                                th { scope: "row", style: LABEL_STYLE, "Take-home for the year" }
+                                td { style: CELL_STYLE, {format_dollars(outputs.take_home)} }
+                            }
+                        }
+                    }
+                    div { style: "margin-bottom: 15px; font-size: 16px;",
+                        "Marginal tax bracket: {outputs.marginal_rate * 100.0:.0}%"
+                    }
+                    if joint && second_salary_signal() > 0.0 {
+                        div {
+                            id: "SecondIncomeCalculation",
+                            style: "margin-bottom: 15px; font-size: 16px;",
+                            "The second income of ${format_dollars(second_salary_signal())} adds ${format_dollars(outputs.second_income_tax)} in federal taxes, {outputs.second_income_rate * 100.0:.1}% of it, and ${format_dollars(outputs.second_take_home)} to take-home pay."
+                        }
+                    }
+                },
+                Err(error) => rsx! {
+                    div {
+                        id: "PaycheckCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "paycheck" }
+        AssumptionsFooter { calculator: "paycheck" }
+        div { style: "font-size: 10px",
+            p {
+                "*Federal taxes only, figured for the whole year and spread evenly over the paychecks; withholding on each paycheck may differ."
+            }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::annuity::{value_perpetuity, PerpetuityInputs};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::math_component::MathFormula;
+use crate::numeric_input_component::NumericInput;
+use dioxus::prelude::*;
+
+/// A preferred share paying a fixed $5 a year, valued at a 6% required return
+const DEFAULT_INPUTS: PerpetuityInputs = PerpetuityInputs {
+    payment: 5.0,
+    discount_rate: 0.06,
+    growth_rate: 0.0,
+};
+
+#[component]
+pub fn PerpetuityUI() -> Element {
+    let mut payment_signal = use_signal(|| 5.0 as f64);
+    let mut payment_input = use_signal(|| "5".to_string());
+    let payment_input_valid = use_signal(|| true);
+    let mut rate_signal = use_signal(|| 6.0 as f64);
+    let mut rate_input = use_signal(|| "6.0".to_string());
+    let rate_input_valid = use_signal(|| true);
+    let mut growth_signal = use_signal(|| 0.0 as f64);
+    let mut growth_input = use_signal(|| "0".to_string());
+    let growth_input_valid = use_signal(|| true);
+
+    let inputs = move || PerpetuityInputs {
+        payment: payment_signal(),
+        discount_rate: rate_signal() / 100.0,
+        growth_rate: growth_signal() / 100.0,
+    };
+    let start_fresh = use_autosave(
+        "perpetuity",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: PerpetuityInputs| {
+            payment_signal.set(inputs.payment);
+            payment_input.set(format!("{}", inputs.payment));
+            rate_signal.set(inputs.discount_rate * 100.0);
+            rate_input.set(format!("{}", inputs.discount_rate * 100.0));
+            growth_signal.set(inputs.growth_rate * 100.0);
+            growth_input.set(format!("{}", inputs.growth_rate * 100.0));
+        }),
+    );
+    use_track_calculator("perpetuity");
+    use_track_input_range("perpetuity", "payment", payment_signal, amount_bucket);
+
+    let growing = growth_signal() != 0.0;
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Annual Payment ($):".to_string(),
+            placeholder: "Enter the first year's payment or dividend (e.g., 5)".to_string(),
+            input_signal: payment_input,
+            value_signal: payment_signal,
+            valid_signal: payment_input_valid,
+            field_name: "Annual payment".to_string(),
+            css_prefix: "payment".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Discount Rate (%):".to_string(),
+            placeholder: "Enter your required annual return (e.g., 6.0)".to_string(),
+            input_signal: rate_input,
+            value_signal: rate_signal,
+            valid_signal: rate_input_valid,
+            field_name: "Discount rate".to_string(),
+            css_prefix: "rate".to_string(),
+            step: Some(0.125),
+        }
+        NumericInput {
+            label: "Annual Growth (%):".to_string(),
+            placeholder: "Enter how fast the payment grows, or 0 (e.g., 2.0)".to_string(),
+            input_signal: growth_input,
+            value_signal: growth_signal,
+            valid_signal: growth_input_valid,
+            field_name: "Annual growth".to_string(),
+            css_prefix: "growth".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+
+        {
+            match value_perpetuity(&inputs()) {
+                Ok(value) => rsx! {
+                    div {
+                        id: "PerpetuityCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        "Value today: ${format_dollars(value)}*"
+                    }
+                    div { style: "margin-bottom: 15px; font-size: 16px;",
+                        if growing {
+                            "Payments start at ${format_dollars(payment_signal())} a year and grow {growth_signal()}% a year, forever, discounted at {rate_signal()}%."
+                        } else {
+                            "A level ${format_dollars(payment_signal())} a year, forever, discounted at {rate_signal()}%."
+                        }
+                    }
+                },
+                Err(error) => rsx! {
+                    div {
+                        id: "PerpetuityCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+        details { style: "color: #333; margin-bottom: 15px;",
+            summary { style: "cursor: pointer; font-weight: bold;", "Show the math" }
+            div { style: "margin-top: 10px;",
+                if growing {
+                    MathFormula { tex: r"PV = \frac{PMT}{r - g}".to_string(), display: true }
+                } else {
+                    MathFormula { tex: r"PV = \frac{PMT}{r}".to_string(), display: true }
+                }
+                p { style: "font-size: 12px;",
+                    "PV = value today, PMT = first year's payment, r = discount rate, g = annual growth"
+                }
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "perpetuity" }
+        AssumptionsFooter { calculator: "perpetuity" }
+        div { style: "font-size: 10px",
+            p { "*Truncated to the cent." }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::compounding::{checked_compute_pv, Compounding, ComputeError};
+use serde::{Deserialize, Serialize};
+
+/// Inputs to the present value calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PresentValueInputs {
+    /// Amount wanted at the end of the term
+    pub target_value: f64,
+    /// Annual interest rate as a decimal, e.g., 0.04 for 4%
+    pub annual_rate: f64,
+    pub years: f64,
+    pub compounding: Compounding,
+}
+
+/// Amount to invest today to reach the target at the given rate and years
+pub fn compute_present_value(inputs: &PresentValueInputs) -> Result<f64, ComputeError> {
+    checked_compute_pv(
+        inputs.target_value,
+        inputs.annual_rate,
+        inputs.compounding.periods_per_year(),
+        inputs.years,
+    )
+}
@@ -0,0 +1,165 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range, years_bucket};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::compounding::Compounding;
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::{format_dollars, CompoundingSelect};
+use crate::math_component::MathFormula;
+use crate::numeric_input_component::NumericInput;
+use crate::present_value::{compute_present_value, PresentValueInputs};
+use dioxus::prelude::*;
+use dioxus_primitives::slider::{Slider, SliderRange, SliderThumb, SliderTrack, SliderValue};
+
+const DEFAULT_INPUTS: PresentValueInputs = PresentValueInputs {
+    target_value: 2000.00,
+    annual_rate: 0.03875,
+    years: 7.0,
+    compounding: Compounding::Annual,
+};
+
+#[component]
+pub fn PresentValueUI() -> Element {
+    let mut rate_signal = use_signal(|| 0.03875);
+    let interest_rate = rate_signal();
+    let mut years_signal = use_signal(|| 7.0);
+    let mut years_input = use_signal(|| "7.0".to_string());
+    let years_input_valid = use_signal(|| true);
+    let mut periods_per_year_signal = use_signal(|| Compounding::Annual);
+    let mut target_signal = use_signal(|| 2000.00 as f64);
+    let mut target_input = use_signal(|| "2000.00".to_string());
+    let target_input_valid = use_signal(|| true);
+    let start_fresh = use_autosave(
+        "present_value",
+        DEFAULT_INPUTS,
+        move || PresentValueInputs {
+            target_value: target_signal(),
+            annual_rate: rate_signal(),
+            years: years_signal(),
+            compounding: periods_per_year_signal(),
+        },
+        use_callback(move |inputs: PresentValueInputs| {
+            target_signal.set(inputs.target_value);
+            target_input.set(format!("{:.2}", inputs.target_value));
+            rate_signal.set(inputs.annual_rate);
+            years_signal.set(inputs.years);
+            years_input.set(format!("{:.1}", inputs.years));
+            periods_per_year_signal.set(inputs.compounding);
+        }),
+    );
+    use_track_calculator("present_value");
+    use_track_input_range("present_value", "target", target_signal, amount_bucket);
+    use_track_input_range("present_value", "years", years_signal, years_bucket);
+
+    let target_value = target_signal();
+    let years = years_signal();
+    let pv = compute_present_value(&PresentValueInputs {
+        target_value,
+        annual_rate: interest_rate,
+        years,
+        compounding: periods_per_year_signal(),
+    })
+    .map(format_dollars);
+    let target = format_dollars(target_value);
+    let periods_string = periods_per_year_signal().to_string().to_ascii_lowercase();
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: asset!("/assets/slider.css") }
+        hr {}
+        br {}
+
+        CompoundingSelect { compounding: periods_per_year_signal }
+
+        NumericInput {
+            label: "Target Future Value ($):".to_string(),
+            placeholder: "Enter the amount you want to have (e.g., 20000.00)".to_string(),
+            input_signal: target_input,
+            value_signal: target_signal,
+            valid_signal: target_input_valid,
+            field_name: "Target future value".to_string(),
+            css_prefix: "target".to_string(),
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Number of Years:".to_string(),
+            placeholder: "Enter number of years (e.g. 5.0)".to_string(),
+            input_signal: years_input,
+            value_signal: years_signal,
+            valid_signal: years_input_valid,
+            field_name: "Number of years".to_string(),
+            css_prefix: "years".to_string(),
+            step: Some(1.0),
+        }
+
+        div { style: "color: #333; font-weight: bold;",
+            "Interest Rate:"
+            Slider {
+                class: "slider",
+                label: "Interest Rate Slider",
+                horizontal: true,
+                min: 0.0,
+                max: 50.0,
+                step: 0.01,
+                value: Some(SliderValue::Single(interest_rate * 100.0)),
+                default_value: SliderValue::Single(3.875),
+                on_value_change: move |value: SliderValue| {
+                    let SliderValue::Single(v) = value;
+                    rate_signal.set(v / 100.0);
+                },
+                SliderTrack { class: "slider-track",
+                    SliderRange { class: "slider-range" }
+                    SliderThumb { class: "slider-thumb" }
+                }
+            }
+        }
+        br {}
+        div {
+            id: "PresentValueCalculationConfig",
+            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+            "The amount to invest today to have ${target} in {years} years at {interest_rate * 100.0:.3}% with compounding {periods_string}: "
+        }
+        {
+            match pv {
+                Ok(pv) => rsx! {
+                    div {
+                        id: "PresentValueCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        " ${pv}*"
+                    }
+                },
+                Err(error) => rsx! {
+                    div {
+                        id: "PresentValueCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+        details { style: "color: #333; margin-bottom: 15px;",
+            summary { style: "cursor: pointer; font-weight: bold;", "Show the math" }
+            div { style: "margin-top: 10px;",
+                if periods_per_year_signal().is_continuous() {
+                    MathFormula { tex: r"PV = FV e^{-rt}".to_string(), display: true }
+                } else {
+                    MathFormula {
+                        tex: r"PV = \frac{FV}{\left(1 + \frac{r}{n}\right)^{nt}}".to_string(),
+                        display: true,
+                    }
+                }
+                p { style: "font-size: 12px;",
+                    "PV = present value, FV = target future value, r = annual rate, n = compounding periods per year, t = years"
+                }
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "present_value" }
+        AssumptionsFooter { calculator: "present_value" }
+        div { style: "font-size: 10px",
+            p { "*please verify all calculations before relying on any features for decision-making." }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,59 @@
+/// Parallel rate shifts shown by default, in basis points
+pub const DEFAULT_SHIFTS_BP: &[i32] = &[-200, -100, 100, 200];
+
+/// A value (price or payment) before and after a parallel rate shift
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateShock {
+    /// Rate shift in basis points, e.g., 100 for +1%
+    pub shift_bp: i32,
+    /// Value at the unshifted rate
+    pub base: f64,
+    /// First-order estimate: base * (1 + sensitivity * shift)
+    pub approximate: f64,
+    /// Value recomputed at the shifted rate
+    pub exact: f64,
+}
+
+impl RateShock {
+    pub fn exact_change(&self) -> f64 {
+        self.exact - self.base
+    }
+
+    pub fn approximate_change(&self) -> f64 {
+        self.approximate - self.base
+    }
+}
+
+/// Relative change in a value per unit change in rate, (dV/dr) / V, by central difference.
+/// For a bond price this is minus the modified duration.
+pub fn rate_sensitivity(value_at: impl Fn(f64) -> f64, rate: f64) -> f64 {
+    let bump = 1e-5;
+    let base = value_at(rate);
+    if base == 0.0 {
+        return 0.0;
+    }
+    (value_at(rate + bump) - value_at(rate - bump)) / (2.0 * bump) / base
+}
+
+/// Revalues `value_at` at each shifted rate, alongside the linear estimate from `sensitivity`
+/// ((dV/dr) / V, e.g., minus modified duration for a bond)
+pub fn shock_table(
+    value_at: impl Fn(f64) -> f64,
+    rate: f64,
+    sensitivity: f64,
+    shifts_bp: &[i32],
+) -> Vec<RateShock> {
+    let base = value_at(rate);
+    shifts_bp
+        .iter()
+        .map(|&shift_bp| {
+            let shift = shift_bp as f64 / 10_000.0;
+            RateShock {
+                shift_bp,
+                base,
+                approximate: base * (1.0 + sensitivity * shift),
+                exact: value_at(rate + shift),
+            }
+        })
+        .collect()
+}
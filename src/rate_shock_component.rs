@@ -0,0 +1,37 @@
+use crate::rate_shock::RateShock;
+use dioxus::prelude::*;
+
+/// Table of a price or payment under parallel rate shifts, with the duration estimate next to
+/// the exact repricing
+#[component]
+pub fn RateShockPanel(
+    /// What is being shocked, e.g., "Price" or "Monthly payment"
+    value_label: String,
+    shocks: Vec<RateShock>,
+) -> Element {
+    rsx! {
+        div { style: "color: #333; margin-bottom: 15px;",
+            div { style: "font-weight: bold; margin-bottom: 5px;", "Rate Shock" }
+            table { style: "border-collapse: collapse; font-family: monospace; font-size: 14px;",
+                thead {
+                    tr {
+                        th { style: "text-align: left; padding: 2px 10px;", "Shift" }
+                        th { style: "text-align: right; padding: 2px 10px;", "{value_label} (estimate)" }
+                        th { style: "text-align: right; padding: 2px 10px;", "{value_label} (exact)" }
+                        th { style: "text-align: right; padding: 2px 10px;", "Change" }
+                    }
+                }
+                tbody {
+                    for shock in shocks.iter() {
+                        tr {
+                            td { style: "padding: 2px 10px;", "{shock.shift_bp:+} bp" }
+                            td { style: "text-align: right; padding: 2px 10px;", "{shock.approximate:.2}" }
+                            td { style: "text-align: right; padding: 2px 10px;", "{shock.exact:.2}" }
+                            td { style: "text-align: right; padding: 2px 10px;", "{shock.exact_change():+.2}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
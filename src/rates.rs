@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+// Computes break-even inflation: the inflation rate at which a TIPS and a nominal Treasury of
+// the same maturity earn the same nominal return.
+///
+/// # Parameters:
+/// - `nominal_yield`: Annual yield of the nominal Treasury (n), e.g., 0.045 for 4.5%
+/// - `real_yield`: Annual real yield of the TIPS (r)
+///
+/// # Formula:
+/// π* = (1 + n) / (1 + r) - 1
+///
+/// # Returns:
+/// Break-even annual inflation as a decimal.
+pub fn break_even_inflation(nominal_yield: f64, real_yield: f64) -> f64 {
+    (1.0 + nominal_yield) / (1.0 + real_yield) - 1.0
+}
+
+// Computes the nominal annual return of a TIPS for a given inflation rate.
+///
+/// # Parameters:
+/// - `real_yield`: Annual real yield of the TIPS (r)
+/// - `inflation`: Annual inflation (π)
+///
+/// # Formula:
+/// (1 + r) * (1 + π) - 1
+///
+/// # Returns:
+/// Nominal annual return as a decimal.
+pub fn tips_nominal_return(real_yield: f64, inflation: f64) -> f64 {
+    (1.0 + real_yield) * (1.0 + inflation) - 1.0
+}
+
+/// Which bond comes out ahead under an inflation assumption
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BreakEvenWinner {
+    Tips,
+    Nominal,
+    Tie,
+}
+
+/// Inputs to the break-even inflation calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BreakEvenInputs {
+    pub amount: f64,
+    pub years: f64,
+    /// Nominal Treasury yield as a decimal
+    pub nominal_yield: f64,
+    /// TIPS real yield as a decimal (may be negative)
+    pub real_yield: f64,
+    /// The user's expected annual inflation as a decimal
+    pub expected_inflation: f64,
+}
+
+/// Results of the break-even inflation calculator
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BreakEvenOutputs {
+    pub break_even_inflation: f64,
+    /// TIPS nominal annual return at the expected inflation
+    pub tips_return: f64,
+    /// Value of `amount` in each bond after `years`, held to maturity with coupons reinvested
+    /// at the bond's yield
+    pub tips_value: f64,
+    pub nominal_value: f64,
+    pub winner: BreakEvenWinner,
+}
+
+/// Compares a TIPS and a nominal Treasury under the expected inflation
+pub fn compute_break_even(inputs: &BreakEvenInputs) -> BreakEvenOutputs {
+    let tips_return = tips_nominal_return(inputs.real_yield, inputs.expected_inflation);
+    let grow = |rate: f64| inputs.amount * (1.0 + rate).powf(inputs.years);
+    let tips_value = grow(tips_return);
+    let nominal_value = grow(inputs.nominal_yield);
+    let winner = if (tips_value - nominal_value).abs() < 0.005 {
+        BreakEvenWinner::Tie
+    } else if tips_value > nominal_value {
+        BreakEvenWinner::Tips
+    } else {
+        BreakEvenWinner::Nominal
+    };
+
+    BreakEvenOutputs {
+        break_even_inflation: break_even_inflation(inputs.nominal_yield, inputs.real_yield),
+        tips_return,
+        tips_value,
+        nominal_value,
+        winner,
+    }
+}
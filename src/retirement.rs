@@ -0,0 +1,221 @@
+use crate::contribution_limits::{employer_match, LimitedAccount};
+use crate::glide_path::GlidePath;
+use crate::inflation_scenarios::InflationAdjustable;
+use serde::{Deserialize, Serialize};
+
+/// Inputs to the retirement savings projection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetirementInputs {
+    pub current_age: f64,
+    pub retirement_age: f64,
+    /// Savings already invested today
+    pub current_balance: f64,
+    /// Deposited at the end of every month until retirement
+    pub monthly_contribution: f64,
+    /// Expected annual return as a decimal, e.g., 0.06 for 6%
+    pub annual_return: f64,
+    /// Expected annual inflation as a decimal, used for the balance in today's dollars
+    pub inflation_rate: f64,
+    /// Yearly raise in the monthly contribution as a decimal, e.g., 0.03 for +3%/yr
+    #[serde(default)]
+    pub annual_increase: f64,
+    /// Account the contributions go into, whose IRS limit caps each year's contributions
+    #[serde(default)]
+    pub account: LimitedAccount,
+    /// Whether to add the account's catch-up contribution in the years it's allowed (age 50+)
+    #[serde(default)]
+    pub catch_up: bool,
+    /// Yearly salary, held level, that a 401(k) employer match is capped against
+    #[serde(default)]
+    pub annual_salary: f64,
+    /// Portion of each contributed dollar the employer matches in a 401(k), e.g., 0.5 for 50%
+    #[serde(default)]
+    pub match_rate: f64,
+    /// Share of salary eligible for matching, e.g., 0.06 for "up to 6% of pay"
+    #[serde(default)]
+    pub match_cap: f64,
+    /// Expected return that changes with age, used instead of `annual_return` when set
+    #[serde(default)]
+    pub glide_path: Option<GlidePath>,
+}
+
+impl InflationAdjustable for RetirementInputs {
+    fn inflation_rate(&self) -> f64 {
+        self.inflation_rate
+    }
+
+    fn with_inflation_rate(&self, rate: f64) -> Self {
+        RetirementInputs {
+            inflation_rate: rate,
+            ..self.clone()
+        }
+    }
+}
+
+/// One year of saving, ending on the birthday at `age`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AccumulationRow {
+    /// 1-based year of the projection
+    pub year: usize,
+    /// Age at the end of the year
+    pub age: f64,
+    /// Deposited during the year
+    pub contributions: f64,
+    /// Planned contributions over the account's limit, left out of `contributions`
+    pub capped: f64,
+    /// Deposited by the employer during the year, matching `contributions`
+    pub employer_match: f64,
+    /// Investment growth earned during the year
+    pub growth: f64,
+    /// Balance at the end of the year
+    pub balance: f64,
+    /// `balance` in today's dollars
+    pub real_balance: f64,
+}
+
+/// The balance at retirement, with how it was built up year by year
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetirementProjection {
+    pub rows: Vec<AccumulationRow>,
+    /// Projected balance at retirement
+    pub balance: f64,
+    /// `balance` in today's dollars
+    pub real_balance: f64,
+    /// Everything deposited before retirement, including the starting balance but not the match
+    pub total_contributions: f64,
+    /// Everything the employer deposited before retirement
+    pub total_match: f64,
+    pub total_growth: f64,
+    /// Planned contributions left out over the years for exceeding the account's limit
+    pub total_capped: f64,
+}
+
+/// Projects retirement savings month by month up to the retirement age.
+///
+/// # Parameters:
+/// - `inputs`: Ages, starting balance (B), monthly contribution (C), annual return (r),
+///   inflation (i), annual contribution increase (g), the account whose limit applies, the
+///   salary (S), match rate (k), and match cap (c) of a 401(k)'s employer match, and an
+///   optional glide path for the return
+/// - `limits_year`: Tax year of the contribution limits, held at that year's amounts throughout
+///
+/// # Formula:
+/// r_m = (1 + r)^(1/12) - 1, the monthly rate that compounds to r over a year, where r is the
+///       glide path's return at the age each year of the projection starts, if there is one
+/// C_y = C * (1 + g)^y, the contribution through year y of the projection (y = 0, 1, ...),
+///       plus the catch-up / 12 when chosen, capped so a year's total stays within the limit
+///       for the age reached that year
+/// M_y = k * min(12 * C_y, c * S), the employer match on the year's allowed contributions, for
+///       a 401(k) only
+/// balance_m = balance_(m-1) * (1 + r_m) + C_y + M_y / 12
+/// real balance = balance / (1 + i)^(months/12)
+///
+/// # Returns:
+/// One row per year, rounded to the cent. A final partial year (when the ages are not whole
+/// years apart) gets a row of its own. An error if the inputs cannot be projected.
+pub fn accumulation_schedule(
+    inputs: &RetirementInputs,
+    limits_year: i32,
+) -> Result<RetirementProjection, String> {
+    if inputs.current_age.is_nan() || inputs.current_age < 0.0 {
+        return Err("Enter your current age".to_string());
+    }
+    if inputs.retirement_age.is_nan() || inputs.retirement_age <= inputs.current_age {
+        return Err("The retirement age must be after your current age".to_string());
+    }
+    if inputs.annual_return.is_nan() || inputs.annual_return <= -1.0 {
+        return Err("The expected return must be greater than -100%".to_string());
+    }
+    if inputs.inflation_rate.is_nan() || inputs.inflation_rate <= -1.0 {
+        return Err("Inflation must be greater than -100%".to_string());
+    }
+    if inputs.annual_increase.is_nan() || inputs.annual_increase <= -1.0 {
+        return Err("The annual increase must be greater than -100%".to_string());
+    }
+    if [inputs.annual_salary, inputs.match_rate, inputs.match_cap]
+        .iter()
+        .any(|value| value.is_nan() || *value < 0.0)
+    {
+        return Err("The salary and employer match cannot be negative".to_string());
+    }
+    let glide_points = inputs
+        .glide_path
+        .as_ref()
+        .map_or(&[][..], |path| path.points());
+    if glide_points
+        .iter()
+        .any(|point| point.annual_return.is_nan() || point.annual_return <= -1.0)
+    {
+        return Err("Every glide path return must be greater than -100%".to_string());
+    }
+    let round_cents = |value: f64| (value * 100.0).round() / 100.0;
+    let n_months = ((inputs.retirement_age - inputs.current_age) * 12.0).round() as usize;
+
+    let mut balance = inputs.current_balance;
+    let mut total_contributions = inputs.current_balance;
+    let mut total_match = 0.0;
+    let mut total_capped = 0.0;
+    let mut rows = Vec::with_capacity(n_months.div_ceil(12));
+    for (index, first_month) in (0..n_months).step_by(12).enumerate() {
+        let months = (n_months - first_month).min(12);
+        let starting_balance = balance;
+        let annual_return = inputs
+            .glide_path
+            .as_ref()
+            .map_or(inputs.annual_return, |path| {
+                path.return_at(inputs.current_age + first_month as f64 / 12.0)
+            });
+        let monthly_rate = (1.0 + annual_return).powf(1.0 / 12.0) - 1.0;
+        let elapsed_years = (first_month + months) as f64 / 12.0;
+        let age = inputs.current_age + elapsed_years;
+        let mut planned =
+            inputs.monthly_contribution * (1.0 + inputs.annual_increase).powi(index as i32);
+        if inputs.catch_up {
+            planned += inputs.account.catch_up(limits_year, age as u32) / 12.0;
+        }
+        let capped = inputs
+            .account
+            .cap(planned * months as f64, limits_year, age as u32);
+        // The match is only earned on what the limit lets in, against a partial year's salary
+        let matched = if inputs.account == LimitedAccount::Workplace {
+            employer_match(
+                inputs.annual_salary * months as f64 / 12.0,
+                capped.allowed,
+                inputs.match_rate,
+                inputs.match_cap,
+            )
+        } else {
+            0.0
+        };
+        let monthly_deposit = (capped.allowed + matched) / months as f64;
+        for _ in 0..months {
+            balance = balance * (1.0 + monthly_rate) + monthly_deposit;
+        }
+        let contributions = capped.allowed;
+        total_contributions += contributions;
+        total_match += matched;
+        total_capped += capped.excess;
+        rows.push(AccumulationRow {
+            year: index + 1,
+            age,
+            contributions: round_cents(contributions),
+            capped: round_cents(capped.excess),
+            employer_match: round_cents(matched),
+            growth: round_cents(balance - starting_balance - contributions - matched),
+            balance: round_cents(balance),
+            real_balance: round_cents(balance / (1.0 + inputs.inflation_rate).powf(elapsed_years)),
+        });
+    }
+
+    Ok(RetirementProjection {
+        balance: round_cents(balance),
+        real_balance: round_cents(
+            balance / (1.0 + inputs.inflation_rate).powf(n_months as f64 / 12.0),
+        ),
+        total_contributions: round_cents(total_contributions),
+        total_match: round_cents(total_match),
+        total_growth: round_cents(balance - total_contributions - total_match),
+        total_capped: round_cents(total_capped),
+        rows,
+    })
+}
@@ -0,0 +1,571 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::chart::{ChartSeries, InflationBandChart, LineChart};
+use crate::contribution_limits::LimitedAccount;
+use crate::data_year::{use_data_year, DataYearBanner};
+use crate::error_summary_component::FieldError;
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::glide_path::GlidePath;
+use crate::glide_path_component::GlidePathEditor;
+use crate::inflation_scenarios::{run_inflation_scenarios, InflationScenarios};
+use crate::inflation_scenarios_component::InflationScenarioControls;
+use crate::number_format::AxisFormat;
+use crate::numeric_input_component::{numeric_field_error, NumericInput};
+use crate::retirement::{accumulation_schedule, RetirementInputs};
+use crate::wizard_component::Wizard;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Everything the user has entered, as autosaved between visits. The projection's fields stay at
+/// the top level so inputs saved before inflation scenarios were added still restore.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct RetirementSession {
+    #[serde(flatten)]
+    inputs: RetirementInputs,
+    #[serde(default)]
+    inflation_scenarios: InflationScenarios,
+}
+
+const DEFAULT_SESSION: RetirementSession = RetirementSession {
+    inputs: RetirementInputs {
+        current_age: 35.0,
+        retirement_age: 65.0,
+        current_balance: 50_000.0,
+        monthly_contribution: 500.0,
+        annual_return: 0.06,
+        inflation_rate: 0.025,
+        annual_increase: 0.0,
+        account: LimitedAccount::Workplace,
+        catch_up: false,
+        annual_salary: 75_000.0,
+        match_rate: 0.0,
+        match_cap: 0.06,
+        glide_path: None,
+    },
+    inflation_scenarios: InflationScenarios {
+        enabled: false,
+        spread: 0.01,
+    },
+};
+
+const ACCOUNT_OPTIONS: &[(LimitedAccount, &str, &str)] = &[
+    (LimitedAccount::Workplace, "workplace", "401(k) / 403(b)"),
+    (LimitedAccount::Ira, "ira", "IRA"),
+    (LimitedAccount::Unlimited, "unlimited", "No limit"),
+];
+
+const WIZARD_TITLES: &[&str] = &[
+    "How old are you, and when do you plan to retire?",
+    "How much have you saved so far?",
+    "How much will you save, and in what kind of account?",
+    "What return do you expect on your savings?",
+    "What inflation do you expect?",
+];
+
+const REAL_BALANCE_COLOR: &str = "#229954";
+const RETURN_COLOR: &str = "#2e86c1";
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+
+#[component]
+pub fn RetirementUI() -> Element {
+    let mut age_signal = use_signal(|| 35.0 as f64);
+    let mut age_input = use_signal(|| "35".to_string());
+    let age_input_valid = use_signal(|| true);
+    let mut retirement_age_signal = use_signal(|| 65.0 as f64);
+    let mut retirement_age_input = use_signal(|| "65".to_string());
+    let retirement_age_input_valid = use_signal(|| true);
+    let mut balance_signal = use_signal(|| 50_000.0 as f64);
+    let mut balance_input = use_signal(|| "50000".to_string());
+    let balance_input_valid = use_signal(|| true);
+    let mut contribution_signal = use_signal(|| 500.0 as f64);
+    let mut contribution_input = use_signal(|| "500".to_string());
+    let contribution_input_valid = use_signal(|| true);
+    let mut return_signal = use_signal(|| 6.0 as f64);
+    let mut return_input = use_signal(|| "6.0".to_string());
+    let return_input_valid = use_signal(|| true);
+    let mut inflation_signal = use_signal(|| 2.5 as f64);
+    let mut inflation_input = use_signal(|| "2.5".to_string());
+    let inflation_input_valid = use_signal(|| true);
+    let mut increase_signal = use_signal(|| 0.0 as f64);
+    let mut increase_input = use_signal(|| "0".to_string());
+    let increase_input_valid = use_signal(|| true);
+    let mut account_signal = use_signal(|| LimitedAccount::Workplace);
+    let mut catch_up_signal = use_signal(|| false);
+    let mut salary_signal = use_signal(|| 75_000.0 as f64);
+    let mut salary_input = use_signal(|| "75000".to_string());
+    let salary_input_valid = use_signal(|| true);
+    let mut match_rate_signal = use_signal(|| 0.0 as f64);
+    let mut match_rate_input = use_signal(|| "0".to_string());
+    let match_rate_input_valid = use_signal(|| true);
+    let mut match_cap_signal = use_signal(|| 6.0 as f64);
+    let mut match_cap_input = use_signal(|| "6".to_string());
+    let match_cap_input_valid = use_signal(|| true);
+    let mut glide_signal = use_signal(|| None::<GlidePath>);
+    let mut scenarios_signal = use_signal(InflationScenarios::default);
+    let limits_year = use_data_year("contribution_limits");
+    let hover_index = use_signal(|| None);
+    let return_hover_index = use_signal(|| None);
+    let mut guided = use_signal(|| false);
+    let mut wizard_step = use_signal(|| 0usize);
+
+    let inputs = move || RetirementInputs {
+        current_age: age_signal(),
+        retirement_age: retirement_age_signal(),
+        current_balance: balance_signal(),
+        monthly_contribution: contribution_signal(),
+        annual_return: return_signal() / 100.0,
+        inflation_rate: inflation_signal() / 100.0,
+        annual_increase: increase_signal() / 100.0,
+        account: account_signal(),
+        catch_up: catch_up_signal(),
+        annual_salary: salary_signal(),
+        match_rate: match_rate_signal() / 100.0,
+        match_cap: match_cap_signal() / 100.0,
+        glide_path: glide_signal(),
+    };
+    let start_fresh = use_autosave(
+        "retirement",
+        DEFAULT_SESSION,
+        move || RetirementSession {
+            inputs: inputs(),
+            inflation_scenarios: scenarios_signal(),
+        },
+        use_callback(move |session: RetirementSession| {
+            let inputs = session.inputs;
+            age_signal.set(inputs.current_age);
+            age_input.set(format!("{}", inputs.current_age));
+            retirement_age_signal.set(inputs.retirement_age);
+            retirement_age_input.set(format!("{}", inputs.retirement_age));
+            balance_signal.set(inputs.current_balance);
+            balance_input.set(format!("{}", inputs.current_balance));
+            contribution_signal.set(inputs.monthly_contribution);
+            contribution_input.set(format!("{}", inputs.monthly_contribution));
+            return_signal.set(inputs.annual_return * 100.0);
+            return_input.set(format!("{}", inputs.annual_return * 100.0));
+            inflation_signal.set(inputs.inflation_rate * 100.0);
+            inflation_input.set(format!("{}", inputs.inflation_rate * 100.0));
+            increase_signal.set(inputs.annual_increase * 100.0);
+            increase_input.set(format!("{}", inputs.annual_increase * 100.0));
+            account_signal.set(inputs.account);
+            catch_up_signal.set(inputs.catch_up);
+            salary_signal.set(inputs.annual_salary);
+            salary_input.set(format!("{}", inputs.annual_salary));
+            match_rate_signal.set(inputs.match_rate * 100.0);
+            match_rate_input.set(format!("{}", inputs.match_rate * 100.0));
+            match_cap_signal.set(inputs.match_cap * 100.0);
+            match_cap_input.set(format!("{}", inputs.match_cap * 100.0));
+            glide_signal.set(inputs.glide_path);
+            scenarios_signal.set(session.inflation_scenarios);
+        }),
+    );
+    use_track_calculator("retirement");
+    use_track_input_range("retirement", "balance", balance_signal, amount_bucket);
+
+    let projection = accumulation_schedule(&inputs(), limits_year());
+    let final_contribution = projection.as_ref().map_or(0.0, |projection| {
+        let raises = projection.rows.len().saturating_sub(1) as i32;
+        contribution_signal() * (1.0 + increase_signal() / 100.0).powi(raises)
+    });
+    // The glide path's return at the start of each year of age, as the projection samples it
+    let glide_returns = glide_signal().map(|path| {
+        path.yearly_returns(age_signal() as u32, retirement_age_signal().ceil() as u32)
+    });
+    let scenarios = if scenarios_signal().enabled {
+        Some(run_inflation_scenarios(
+            &inputs(),
+            &scenarios_signal(),
+            |inputs| accumulation_schedule(inputs, limits_year()),
+        ))
+    } else {
+        None
+    };
+
+    // Problems with each wizard step's inputs, in the order of `WIZARD_TITLES`
+    let field_errors: [Vec<FieldError>; 5] = [
+        [
+            numeric_field_error(&age_input(), age_input_valid(), "Current age", "age"),
+            numeric_field_error(
+                &retirement_age_input(),
+                retirement_age_input_valid(),
+                "Retirement age",
+                "retirement-age",
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect(),
+        numeric_field_error(
+            &balance_input(),
+            balance_input_valid(),
+            "Current savings",
+            "balance",
+        )
+        .into_iter()
+        .collect(),
+        [
+            numeric_field_error(
+                &contribution_input(),
+                contribution_input_valid(),
+                "Monthly contribution",
+                "contribution",
+            ),
+            numeric_field_error(
+                &increase_input(),
+                increase_input_valid(),
+                "Annual increase",
+                "increase",
+            ),
+            numeric_field_error(&salary_input(), salary_input_valid(), "Salary", "salary"),
+            numeric_field_error(
+                &match_rate_input(),
+                match_rate_input_valid(),
+                "Employer match",
+                "match-rate",
+            ),
+            numeric_field_error(
+                &match_cap_input(),
+                match_cap_input_valid(),
+                "Match limit",
+                "match-cap",
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect(),
+        numeric_field_error(
+            &return_input(),
+            return_input_valid(),
+            "Expected return",
+            "return",
+        )
+        .into_iter()
+        .collect(),
+        numeric_field_error(
+            &inflation_input(),
+            inflation_input_valid(),
+            "Expected inflation",
+            "inflation",
+        )
+        .into_iter()
+        .collect(),
+    ];
+
+    let fields = [
+        rsx! {
+            NumericInput {
+                label: "Current Age:".to_string(),
+                placeholder: "Enter your age (e.g., 35)".to_string(),
+                input_signal: age_input,
+                value_signal: age_signal,
+                valid_signal: age_input_valid,
+                field_name: "Current age".to_string(),
+                css_prefix: "age".to_string(),
+                step: Some(1.0),
+            }
+            NumericInput {
+                label: "Retirement Age:".to_string(),
+                placeholder: "Enter the age you plan to retire (e.g., 65)".to_string(),
+                input_signal: retirement_age_input,
+                value_signal: retirement_age_signal,
+                valid_signal: retirement_age_input_valid,
+                field_name: "Retirement age".to_string(),
+                css_prefix: "retirement-age".to_string(),
+                step: Some(1.0),
+            }
+        },
+        rsx! {
+            NumericInput {
+                label: "Current Savings ($):".to_string(),
+                placeholder: "Enter what you have saved (e.g., 50000)".to_string(),
+                input_signal: balance_input,
+                value_signal: balance_signal,
+                valid_signal: balance_input_valid,
+                field_name: "Current savings".to_string(),
+                css_prefix: "balance".to_string(),
+                step: Some(1000.0),
+                max_decimals: Some(2),
+            }
+        },
+        rsx! {
+            NumericInput {
+                label: "Monthly Contribution ($):".to_string(),
+                placeholder: "Enter what you save each month (e.g., 500)".to_string(),
+                input_signal: contribution_input,
+                value_signal: contribution_signal,
+                valid_signal: contribution_input_valid,
+                field_name: "Monthly contribution".to_string(),
+                css_prefix: "contribution".to_string(),
+                step: Some(50.0),
+                max_decimals: Some(2),
+            }
+            NumericInput {
+                label: "Annual Increase (%):".to_string(),
+                placeholder: "Enter how much contributions rise each year (e.g., 3)".to_string(),
+                input_signal: increase_input,
+                value_signal: increase_signal,
+                valid_signal: increase_input_valid,
+                field_name: "Annual increase".to_string(),
+                css_prefix: "increase".to_string(),
+                step: Some(0.5),
+            }
+            div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+                label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                    "Account:"
+                }
+                select {
+                    style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                    onchange: move |event| {
+                        let value = event.value();
+                        if let Some(&(account, _, _)) = ACCOUNT_OPTIONS
+                            .iter()
+                            .find(|(_, value_str, _)| *value_str == value.as_str())
+                        {
+                            account_signal.set(account);
+                        }
+                    },
+                    for (account , value , display) in ACCOUNT_OPTIONS.iter() {
+                        option { value: *value, selected: account_signal() == *account, {*display} }
+                    }
+                }
+            }
+            div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+                label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                    "Catch-Up at 50+:"
+                }
+                input {
+                    r#type: "checkbox",
+                    "aria-label": "Add catch-up contributions from age 50",
+                    disabled: account_signal() == LimitedAccount::Unlimited,
+                    checked: catch_up_signal(),
+                    onchange: move |event| catch_up_signal.set(event.checked()),
+                }
+            }
+            if account_signal() == LimitedAccount::Workplace {
+                NumericInput {
+                    label: "Salary ($/yr):".to_string(),
+                    placeholder: "Enter your yearly salary (e.g., 75000)".to_string(),
+                    input_signal: salary_input,
+                    value_signal: salary_signal,
+                    valid_signal: salary_input_valid,
+                    field_name: "Salary".to_string(),
+                    css_prefix: "salary".to_string(),
+                    step: Some(1000.0),
+                    max_decimals: Some(2),
+                }
+                NumericInput {
+                    label: "Employer Match (%):".to_string(),
+                    placeholder: "Enter the share of each dollar saved that's matched (e.g., 50)".to_string(),
+                    input_signal: match_rate_input,
+                    value_signal: match_rate_signal,
+                    valid_signal: match_rate_input_valid,
+                    field_name: "Employer match".to_string(),
+                    css_prefix: "match-rate".to_string(),
+                    step: Some(25.0),
+                }
+                NumericInput {
+                    label: "Match Up To (% of pay):".to_string(),
+                    placeholder: "Enter the share of salary eligible for the match (e.g., 6)".to_string(),
+                    input_signal: match_cap_input,
+                    value_signal: match_cap_signal,
+                    valid_signal: match_cap_input_valid,
+                    field_name: "Match limit".to_string(),
+                    css_prefix: "match-cap".to_string(),
+                    step: Some(1.0),
+                }
+            }
+        },
+        rsx! {
+            NumericInput {
+                label: "Expected Return (%):".to_string(),
+                placeholder: "Enter the annual return (e.g., 6.0)".to_string(),
+                input_signal: return_input,
+                value_signal: return_signal,
+                valid_signal: return_input_valid,
+                field_name: "Expected return".to_string(),
+                css_prefix: "return".to_string(),
+                allow_negative: true,
+                step: Some(0.25),
+            }
+            GlidePathEditor {
+                glide_path: glide_signal,
+                start_age: age_signal(),
+                end_age: retirement_age_signal(),
+                annual_return: return_signal() / 100.0,
+            }
+        },
+        rsx! {
+            NumericInput {
+                label: "Expected Inflation (%):".to_string(),
+                placeholder: "Enter the annual inflation rate (e.g., 2.5)".to_string(),
+                input_signal: inflation_input,
+                value_signal: inflation_signal,
+                valid_signal: inflation_input_valid,
+                field_name: "Expected inflation".to_string(),
+                css_prefix: "inflation".to_string(),
+                allow_negative: true,
+                step: Some(0.25),
+            }
+            InflationScenarioControls { scenarios: scenarios_signal }
+        },
+    ];
+
+    rsx! {
+        hr {}
+        br {}
+
+        DataYearBanner { data_set: "contribution_limits", year: limits_year }
+
+        if guided() {
+            Wizard {
+                titles: WIZARD_TITLES.iter().map(|title| title.to_string()).collect::<Vec<_>>(),
+                step: wizard_step,
+                errors: field_errors[wizard_step().min(field_errors.len() - 1)].clone(),
+                on_finish: move |_| guided.set(false),
+                {fields[wizard_step().min(fields.len() - 1)].clone()}
+            }
+        } else {
+            button {
+                style: "padding: 6px 12px; border-radius: 4px; margin-bottom: 15px;",
+                onclick: move |_| {
+                    wizard_step.set(0);
+                    guided.set(true);
+                },
+                "Guided Setup"
+            }
+            for field in fields.iter() {
+                {field.clone()}
+            }
+
+            {
+                match projection {
+                    Ok(projection) => rsx! {
+                        div {
+                            id: "RetirementCalculation",
+                            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                            "Projected balance at age {retirement_age_signal()}: ${format_dollars(projection.balance)}*"
+                        }
+                        div { style: "margin-bottom: 15px; font-size: 16px;",
+                            "That is ${format_dollars(projection.real_balance)} in today's dollars. You put in ${format_dollars(projection.total_contributions)}; growth adds ${format_dollars(projection.total_growth)}."
+                        }
+                        if projection.total_match > 0.0 {
+                            div { style: "margin-bottom: 15px; font-size: 14px;",
+                                "Your employer's match adds ${format_dollars(projection.total_match)}, earned only on contributions within the {account_signal().label()} limit."
+                            }
+                        }
+                        if let Some(first) = projection.rows.iter().find(|row| row.capped > 0.0) {
+                            div {
+                                role: "status",
+                                style: "background: #fff4ce; color: #333; border-radius: 4px; padding: 6px 10px; margin-bottom: 15px; font-size: 14px;",
+                                "Your contributions pass the {account_signal().label()} limit from age {first.age.floor()}, so they're capped there: ${format_dollars(projection.total_capped)} of what you planned is left out of the projection."
+                            }
+                        }
+                        if increase_signal() > 0.0 {
+                            div { style: "margin-bottom: 15px; font-size: 14px;",
+                                "Rising {increase_signal()}% a year, your contribution reaches ${format_dollars(final_contribution)} a month in the last year."
+                            }
+                        }
+                        {
+                            match scenarios {
+                                Some(Ok((rates, results))) => rsx! {
+                                    div {
+                                        id: "RetirementInflationScenarios",
+                                        style: "margin-bottom: 15px; font-size: 14px;",
+                                        "In today's dollars: ${format_dollars(results.low.real_balance)} at {rates.low * 100.0:.2}% inflation, ${format_dollars(results.base.real_balance)} at {rates.base * 100.0:.2}%, and ${format_dollars(results.high.real_balance)} at {rates.high * 100.0:.2}%."
+                                    }
+                                    InflationBandChart {
+                                        title: "Balance in today's dollars".to_string(),
+                                        results: results.map(|scenario| {
+                                            scenario
+                                                .rows
+                                                .iter()
+                                                .map(|row| row.real_balance)
+                                                .collect::<Vec<f64>>()
+                                        }),
+                                        rates,
+                                        x_start: inputs().current_age + 1.0,
+                                        color: REAL_BALANCE_COLOR.to_string(),
+                                        x_label: "Age".to_string(),
+                                        hover_index,
+                                    }
+                                },
+                                Some(Err(error)) => rsx! {
+                                    div {
+                                        id: "RetirementInflationScenarios",
+                                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                                        "{error}"
+                                    }
+                                },
+                                None => rsx! {},
+                            }
+                        }
+                        if let Some(returns) = glide_returns.filter(|returns| !returns.is_empty()) {
+                            LineChart {
+                                title: "Expected return by age".to_string(),
+                                series: vec![
+                                    ChartSeries {
+                                        label: "Expected return".to_string(),
+                                        color: RETURN_COLOR.to_string(),
+                                        values: returns.iter().map(|(_, annual_return)| *annual_return).collect(),
+                                    },
+                                ],
+                                x_start: returns[0].0 as f64,
+                                hover_index: return_hover_index,
+                                y_max: None,
+                                y_format: AxisFormat::Percent,
+                                x_label: "Age".to_string(),
+                            }
+                        }
+
+                        div {
+                            style: "max-height: 400px; overflow-y: auto; margin-bottom: 15px;",
+                            tabindex: "0",
+                            table { style: "color: #333; border-collapse: collapse; font-size: 13px;",
+                                thead { style: "position: sticky; top: 0; background: #f4f4f4;",
+                                    tr {
+                                        th { scope: "col", style: CELL_STYLE, "Year" }
+                                        th { scope: "col", style: CELL_STYLE, "Age" }
+                                        th { scope: "col", style: CELL_STYLE, "Contributions" }
+                                        th { scope: "col", style: CELL_STYLE, "Growth" }
+                                        th { scope: "col", style: CELL_STYLE, "Balance" }
+                                        th { scope: "col", style: CELL_STYLE, "Today's Dollars" }
+                                    }
+                                }
+                                tbody {
+                                    for row in projection.rows.iter() {
+                                        tr { key: "{row.year}",
+                                            th { scope: "row", style: CELL_STYLE, "{row.year}" }
+                                            td { style: CELL_STYLE, "{row.age:.1}" }
+                                            td { style: CELL_STYLE, {format_dollars(row.contributions)} }
+                                            td { style: CELL_STYLE, {format_dollars(row.growth)} }
+                                            td { style: CELL_STYLE, {format_dollars(row.balance)} }
+                                            td { style: CELL_STYLE, {format_dollars(row.real_balance)} }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Err(error) => rsx! {
+                        div {
+                            id: "RetirementCalculation",
+                            style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                            "{error}"
+                        }
+                    },
+                }
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "retirement" }
+        AssumptionsFooter { calculator: "retirement" }
+        div { style: "font-size: 10px",
+            p { "*Before taxes and fees; actual returns vary from year to year." }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use crate::account_types::{after_tax_income, AccountSplit, AccountWithdrawals};
+use crate::annuity::{solve_annuity, AnnuityInputs};
+use crate::healthcare::{healthcare_cost, medicare_premiums, HealthcareCost, HealthcareInputs};
+use crate::inflation_scenarios::InflationAdjustable;
+use crate::tax::tax_table;
+use serde::{Deserialize, Serialize};
+
+/// Inputs to the retirement income dashboard. Monthly amounts are in dollars of the first year
+/// of retirement.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RetirementIncomeInputs {
+    pub retirement_age: f64,
+    /// Last birthday the plan has to pay for
+    pub plan_to_age: f64,
+    /// Monthly Social Security benefit from the claiming age
+    pub social_security: f64,
+    pub social_security_age: f64,
+    /// Monthly pension
+    pub pension: f64,
+    /// Yearly cost-of-living raise on the pension as a decimal; 0 for a flat pension
+    pub pension_cola: f64,
+    /// Savings turned into a fixed monthly payout at retirement
+    pub annuity_balance: f64,
+    /// Annual rate the annuity pays out at as a decimal
+    pub annuity_rate: f64,
+    /// Years the annuity pays
+    pub annuity_years: f64,
+    /// Invested savings drawn down for income
+    pub portfolio_balance: f64,
+    /// Share of the portfolio withdrawn in the first year as a decimal, then raised with inflation
+    pub withdrawal_rate: f64,
+    /// Expected annual return on the portfolio as a decimal
+    pub portfolio_return: f64,
+    /// Monthly spending other than healthcare
+    pub monthly_expenses: f64,
+    /// Yearly inflation as a decimal; Social Security, withdrawals, and expenses rise with it
+    pub inflation_rate: f64,
+    /// Medicare premiums and out-of-pocket costs, added to expenses. Its filing status is also
+    /// the one income is taxed under.
+    #[serde(default)]
+    pub healthcare: HealthcareInputs,
+    /// How the portfolio is split across taxable, traditional, and Roth accounts
+    #[serde(default)]
+    pub accounts: AccountSplit,
+    /// Share of taxable-account withdrawals that is gain rather than cost basis, as a decimal
+    #[serde(default)]
+    pub taxable_gain_share: f64,
+}
+
+/// Most of Social Security that can be taxable income; the projection assumes all of it is
+const SOCIAL_SECURITY_TAXABLE_SHARE: f64 = 0.85;
+
+impl InflationAdjustable for RetirementIncomeInputs {
+    fn inflation_rate(&self) -> f64 {
+        self.inflation_rate
+    }
+
+    /// Only general inflation changes; healthcare keeps its own rate
+    fn with_inflation_rate(&self, rate: f64) -> Self {
+        RetirementIncomeInputs {
+            inflation_rate: rate,
+            ..*self
+        }
+    }
+}
+
+/// Monthly income from each source, and spending, in one year of retirement
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IncomeYear {
+    /// Age at the start of the year
+    pub age: f64,
+    pub social_security: f64,
+    pub pension: f64,
+    pub annuity: f64,
+    pub portfolio: f64,
+    /// Income before taxes
+    pub total: f64,
+    /// Federal income tax on the year's income
+    pub federal_tax: f64,
+    /// `total` less `federal_tax`
+    pub net: f64,
+    pub healthcare: HealthcareCost,
+    /// Spending, healthcare included
+    pub expenses: f64,
+}
+
+/// Retirement income year by year, and when it stops covering expenses
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetirementIncome {
+    pub years: Vec<IncomeYear>,
+    /// Fixed monthly annuity payout
+    pub annuity_payout: f64,
+    /// IRMAA tier the income falls in, counting from 0 for the standard Medicare premium
+    pub irmaa_tier: usize,
+    /// First age whose after-tax income falls short of expenses, if any
+    pub shortfall_age: Option<f64>,
+    /// First age the portfolio can't pay its full withdrawal, if any
+    pub depletion_age: Option<f64>,
+}
+
+// Projects monthly retirement income from Social Security, a pension, an annuity, and portfolio
+// withdrawals, less federal income tax, against monthly expenses and healthcare costs, one year
+// at a time.
+///
+/// # Parameters:
+/// - `inputs`: Ages, each income source, the portfolio's account split, expenses, healthcare,
+///   and inflation (i)
+/// - `year`: Year of the Medicare premiums and tax brackets the projection starts from
+///
+/// # Formula:
+/// Social Security_t = benefit * (1 + i)^t, from the claiming age
+/// pension_t = pension * (1 + COLA)^t
+/// annuity = the level monthly payment that pays the balance down to zero over its term
+/// withdrawal_t = min(rate * portfolio * (1 + i)^t, balance_t), taken at the start of year t;
+/// balance_(t+1) = (balance_t - withdrawal_t) * (1 + return)
+/// expenses_t = expenses * (1 + i)^t + healthcare_t, with healthcare growing at its own rate
+/// tax_t = federal tax on the pension, annuity, 85% of Social Security, traditional withdrawals,
+/// and the gains in taxable withdrawals, with brackets that rise with inflation
+///
+/// # Returns:
+/// Monthly amounts for each year from retirement up to the plan-to age, and the first ages
+/// income falls short and the portfolio runs out. An error if an entry is negative, the ages
+/// are out of order, or the annuity can't be paid out.
+pub fn project_retirement_income(
+    inputs: &RetirementIncomeInputs,
+    year: i32,
+) -> Result<RetirementIncome, String> {
+    let entries = [
+        inputs.retirement_age,
+        inputs.social_security,
+        inputs.social_security_age,
+        inputs.pension,
+        inputs.annuity_balance,
+        inputs.annuity_years,
+        inputs.portfolio_balance,
+        inputs.withdrawal_rate,
+        inputs.monthly_expenses,
+        inputs.healthcare.income,
+        inputs.healthcare.out_of_pocket,
+    ];
+    if entries.iter().any(|entry| entry.is_nan() || *entry < 0.0) {
+        return Err("Ages, income, balances, and expenses can't be negative".to_string());
+    }
+    if inputs.plan_to_age.is_nan() || inputs.plan_to_age <= inputs.retirement_age {
+        return Err("The plan-to age must be after the retirement age".to_string());
+    }
+    let rates = [
+        inputs.pension_cola,
+        inputs.annuity_rate,
+        inputs.portfolio_return,
+        inputs.inflation_rate,
+        inputs.healthcare.healthcare_inflation,
+    ];
+    if rates.iter().any(|rate| rate.is_nan() || *rate <= -1.0) {
+        return Err("Rates must be greater than -100%".to_string());
+    }
+
+    let annuity_payout = if inputs.annuity_balance > 0.0 && inputs.annuity_years > 0.0 {
+        let outputs = solve_annuity(&AnnuityInputs {
+            payment: None,
+            present_value: Some(inputs.annuity_balance),
+            future_value: Some(0.0),
+            annual_rate: inputs.annuity_rate,
+            years: inputs.annuity_years,
+            periods_per_year: 12.0,
+            annuity_due: false,
+            growth_rate: 0.0,
+        })?;
+        // Drawing the balance down is a withdrawal, which the annuity solver returns as negative
+        -outputs.payment
+    } else {
+        0.0
+    };
+
+    let gain_share = inputs.taxable_gain_share;
+    if gain_share.is_nan() || !(0.0..=1.0).contains(&gain_share) {
+        return Err("The taxable gain share must be between 0% and 100%".to_string());
+    }
+    let shares = [
+        inputs.accounts.taxable,
+        inputs.accounts.traditional,
+        inputs.accounts.roth,
+    ];
+    if shares.iter().any(|share| share.is_nan() || *share < 0.0) {
+        return Err("Account shares can't be negative".to_string());
+    }
+
+    let status = inputs.healthcare.status;
+    let table = tax_table(year, status);
+    let premiums = medicare_premiums(year);
+    let (irmaa_tier, _) = premiums.irmaa_tier(inputs.healthcare.income, inputs.healthcare.status);
+    let n_years = ((inputs.plan_to_age - inputs.retirement_age).round() as usize).max(1);
+    let first_withdrawal = inputs.withdrawal_rate * inputs.portfolio_balance;
+    let mut balance = inputs.portfolio_balance;
+    let mut depletion_age = None;
+    let years: Vec<IncomeYear> = (0..n_years)
+        .map(|t| {
+            let age = inputs.retirement_age + t as f64;
+            let inflation = (1.0 + inputs.inflation_rate).powi(t as i32);
+            let social_security = if age >= inputs.social_security_age {
+                inputs.social_security * inflation
+            } else {
+                0.0
+            };
+            let pension = inputs.pension * (1.0 + inputs.pension_cola).powi(t as i32);
+            let annuity = if (t as f64) < inputs.annuity_years.round() {
+                annuity_payout
+            } else {
+                0.0
+            };
+            let planned = first_withdrawal * inflation;
+            let withdrawal = planned.min(balance);
+            if withdrawal < planned && depletion_age.is_none() {
+                depletion_age = Some(age);
+            }
+            balance = (balance - withdrawal) * (1.0 + inputs.portfolio_return);
+            let portfolio = withdrawal / 12.0;
+            let healthcare = healthcare_cost(&inputs.healthcare, premiums, age, t);
+
+            // Taxed in first-year dollars, which is what indexing the brackets to inflation does
+            let other_ordinary_income = 12.0
+                * (pension + annuity + SOCIAL_SECURITY_TAXABLE_SHARE * social_security)
+                / inflation;
+            let withdrawals =
+                AccountWithdrawals::from_split(withdrawal / inflation, inputs.accounts, gain_share);
+            let annual_tax = table.total_tax(other_ordinary_income, 0.0)
+                + after_tax_income(&withdrawals, other_ordinary_income, status, year).federal_tax;
+            let total = social_security + pension + annuity + portfolio;
+            let federal_tax = annual_tax * inflation / 12.0;
+
+            IncomeYear {
+                age,
+                social_security,
+                pension,
+                annuity,
+                portfolio,
+                total,
+                federal_tax,
+                net: total - federal_tax,
+                healthcare,
+                expenses: inputs.monthly_expenses * inflation + healthcare.total,
+            }
+        })
+        .collect();
+    let shortfall_age = years
+        .iter()
+        .find(|year| year.net < year.expenses)
+        .map(|year| year.age);
+
+    Ok(RetirementIncome {
+        years,
+        annuity_payout,
+        irmaa_tier,
+        shortfall_age,
+        depletion_age,
+    })
+}
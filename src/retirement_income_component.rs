@@ -0,0 +1,702 @@
+use crate::account_types::AccountSplit;
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::chart::{ChartSeries, InflationBandChart, LineChart, StackedBarChart};
+use crate::data_year::{use_data_year, DataYearBanner};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::healthcare::HealthcareInputs;
+use crate::inflation_scenarios::{run_inflation_scenarios, InflationCase, InflationScenarios};
+use crate::inflation_scenarios_component::InflationScenarioControls;
+use crate::number_format::AxisFormat;
+use crate::numeric_input_component::NumericInput;
+use crate::retirement_income::{
+    project_retirement_income, IncomeYear, RetirementIncome, RetirementIncomeInputs,
+};
+use crate::tax::FilingStatus;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Everything the user has entered, as autosaved between visits. The dashboard's fields stay at
+/// the top level so inputs saved before inflation scenarios were added still restore.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct RetirementIncomeSession {
+    #[serde(flatten)]
+    inputs: RetirementIncomeInputs,
+    #[serde(default)]
+    inflation_scenarios: InflationScenarios,
+}
+
+/// Retiring at 67 with Social Security, a small flat pension, a 20-year annuity, and a portfolio
+/// drawn at 4%, planning to 95. Healthcare is Medicare at the standard premium plus $400 a month out
+/// of pocket, growing faster than inflation. Most of the portfolio is in a traditional 401(k), with
+/// the rest split between a brokerage account that is half gains and a Roth IRA.
+const DEFAULT_SESSION: RetirementIncomeSession = RetirementIncomeSession {
+    inputs: RetirementIncomeInputs {
+        retirement_age: 67.0,
+        plan_to_age: 95.0,
+        social_security: 2_400.0,
+        social_security_age: 67.0,
+        pension: 800.0,
+        pension_cola: 0.0,
+        annuity_balance: 100_000.0,
+        annuity_rate: 0.05,
+        annuity_years: 20.0,
+        portfolio_balance: 500_000.0,
+        withdrawal_rate: 0.04,
+        portfolio_return: 0.06,
+        monthly_expenses: 4_400.0,
+        inflation_rate: 0.025,
+        healthcare: HealthcareInputs {
+            status: FilingStatus::Single,
+            income: 80_000.0,
+            out_of_pocket: 400.0,
+            healthcare_inflation: 0.05,
+        },
+        accounts: AccountSplit {
+            taxable: 0.2,
+            traditional: 0.6,
+            roth: 0.2,
+        },
+        taxable_gain_share: 0.5,
+    },
+    inflation_scenarios: InflationScenarios {
+        enabled: false,
+        spread: 0.01,
+    },
+};
+
+const STATUS_OPTIONS: &[(FilingStatus, &str)] = &[
+    (FilingStatus::Single, "single"),
+    (FilingStatus::MarriedFilingJointly, "joint"),
+];
+
+const SOCIAL_SECURITY_COLOR: &str = "#2471a3";
+const PENSION_COLOR: &str = "#7d3c98";
+const ANNUITY_COLOR: &str = "#d68910";
+const PORTFOLIO_COLOR: &str = "#229954";
+const INCOME_COLOR: &str = "#1f618d";
+const NET_INCOME_COLOR: &str = "#16a085";
+const EXPENSES_COLOR: &str = "#c0392b";
+const HEALTHCARE_COLOR: &str = "#e67e22";
+
+/// After-tax income as a share of expenses in each year
+fn coverage_ratios(income: &RetirementIncome) -> Vec<f64> {
+    income
+        .years
+        .iter()
+        .map(|year| {
+            if year.expenses > 0.0 {
+                year.net / year.expenses
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// When income first falls short, or that it never does
+fn shortfall_text(income: &RetirementIncome) -> String {
+    match income.shortfall_age {
+        Some(age) => format!("short from age {}", age),
+        None => "covered throughout".to_string(),
+    }
+}
+
+#[component]
+pub fn RetirementIncomeUI() -> Element {
+    let medicare_year = use_data_year("medicare_premiums");
+    let mut retirement_age_signal = use_signal(|| 67.0 as f64);
+    let mut retirement_age_input = use_signal(|| "67".to_string());
+    let retirement_age_input_valid = use_signal(|| true);
+    let mut plan_age_signal = use_signal(|| 95.0 as f64);
+    let mut plan_age_input = use_signal(|| "95".to_string());
+    let plan_age_input_valid = use_signal(|| true);
+    let mut social_security_signal = use_signal(|| 2_400.0 as f64);
+    let mut social_security_input = use_signal(|| "2400".to_string());
+    let social_security_input_valid = use_signal(|| true);
+    let mut claim_age_signal = use_signal(|| 67.0 as f64);
+    let mut claim_age_input = use_signal(|| "67".to_string());
+    let claim_age_input_valid = use_signal(|| true);
+    let mut pension_signal = use_signal(|| 800.0 as f64);
+    let mut pension_input = use_signal(|| "800".to_string());
+    let pension_input_valid = use_signal(|| true);
+    // Rates are entered in percent
+    let mut cola_signal = use_signal(|| 0.0 as f64);
+    let mut cola_input = use_signal(|| "0".to_string());
+    let cola_input_valid = use_signal(|| true);
+    let mut annuity_signal = use_signal(|| 100_000.0 as f64);
+    let mut annuity_input = use_signal(|| "100000".to_string());
+    let annuity_input_valid = use_signal(|| true);
+    let mut annuity_rate_signal = use_signal(|| 5.0 as f64);
+    let mut annuity_rate_input = use_signal(|| "5.0".to_string());
+    let annuity_rate_input_valid = use_signal(|| true);
+    let mut annuity_years_signal = use_signal(|| 20.0 as f64);
+    let mut annuity_years_input = use_signal(|| "20".to_string());
+    let annuity_years_input_valid = use_signal(|| true);
+    let mut portfolio_signal = use_signal(|| 500_000.0 as f64);
+    let mut portfolio_input = use_signal(|| "500000".to_string());
+    let portfolio_input_valid = use_signal(|| true);
+    let mut withdrawal_signal = use_signal(|| 4.0 as f64);
+    let mut withdrawal_input = use_signal(|| "4.0".to_string());
+    let withdrawal_input_valid = use_signal(|| true);
+    let mut return_signal = use_signal(|| 6.0 as f64);
+    let mut return_input = use_signal(|| "6.0".to_string());
+    let return_input_valid = use_signal(|| true);
+    let mut expenses_signal = use_signal(|| 4_400.0 as f64);
+    let mut expenses_input = use_signal(|| "4400".to_string());
+    let expenses_input_valid = use_signal(|| true);
+    let mut inflation_signal = use_signal(|| 2.5 as f64);
+    let mut inflation_input = use_signal(|| "2.5".to_string());
+    let inflation_input_valid = use_signal(|| true);
+    let mut status_signal = use_signal(|| FilingStatus::Single);
+    let mut magi_signal = use_signal(|| 80_000.0 as f64);
+    let mut magi_input = use_signal(|| "80000".to_string());
+    let magi_input_valid = use_signal(|| true);
+    let mut out_of_pocket_signal = use_signal(|| 400.0 as f64);
+    let mut out_of_pocket_input = use_signal(|| "400".to_string());
+    let out_of_pocket_input_valid = use_signal(|| true);
+    let mut health_inflation_signal = use_signal(|| 5.0 as f64);
+    let mut health_inflation_input = use_signal(|| "5.0".to_string());
+    let health_inflation_input_valid = use_signal(|| true);
+    let mut taxable_signal = use_signal(|| 20.0 as f64);
+    let mut taxable_input = use_signal(|| "20".to_string());
+    let taxable_input_valid = use_signal(|| true);
+    let mut traditional_signal = use_signal(|| 60.0 as f64);
+    let mut traditional_input = use_signal(|| "60".to_string());
+    let traditional_input_valid = use_signal(|| true);
+    let mut roth_signal = use_signal(|| 20.0 as f64);
+    let mut roth_input = use_signal(|| "20".to_string());
+    let roth_input_valid = use_signal(|| true);
+    let mut gain_share_signal = use_signal(|| 50.0 as f64);
+    let mut gain_share_input = use_signal(|| "50".to_string());
+    let gain_share_input_valid = use_signal(|| true);
+    let mut scenarios_signal = use_signal(InflationScenarios::default);
+    let hover_index = use_signal(|| None);
+
+    let inputs = move || RetirementIncomeInputs {
+        retirement_age: retirement_age_signal(),
+        plan_to_age: plan_age_signal(),
+        social_security: social_security_signal(),
+        social_security_age: claim_age_signal(),
+        pension: pension_signal(),
+        pension_cola: cola_signal() / 100.0,
+        annuity_balance: annuity_signal(),
+        annuity_rate: annuity_rate_signal() / 100.0,
+        annuity_years: annuity_years_signal(),
+        portfolio_balance: portfolio_signal(),
+        withdrawal_rate: withdrawal_signal() / 100.0,
+        portfolio_return: return_signal() / 100.0,
+        monthly_expenses: expenses_signal(),
+        inflation_rate: inflation_signal() / 100.0,
+        healthcare: HealthcareInputs {
+            status: status_signal(),
+            income: magi_signal(),
+            out_of_pocket: out_of_pocket_signal(),
+            healthcare_inflation: health_inflation_signal() / 100.0,
+        },
+        accounts: AccountSplit {
+            taxable: taxable_signal() / 100.0,
+            traditional: traditional_signal() / 100.0,
+            roth: roth_signal() / 100.0,
+        },
+        taxable_gain_share: gain_share_signal() / 100.0,
+    };
+    let start_fresh = use_autosave(
+        "retirement_income",
+        DEFAULT_SESSION,
+        move || RetirementIncomeSession {
+            inputs: inputs(),
+            inflation_scenarios: scenarios_signal(),
+        },
+        use_callback(move |session: RetirementIncomeSession| {
+            let inputs = session.inputs;
+            retirement_age_signal.set(inputs.retirement_age);
+            retirement_age_input.set(format!("{}", inputs.retirement_age));
+            plan_age_signal.set(inputs.plan_to_age);
+            plan_age_input.set(format!("{}", inputs.plan_to_age));
+            social_security_signal.set(inputs.social_security);
+            social_security_input.set(format!("{}", inputs.social_security));
+            claim_age_signal.set(inputs.social_security_age);
+            claim_age_input.set(format!("{}", inputs.social_security_age));
+            pension_signal.set(inputs.pension);
+            pension_input.set(format!("{}", inputs.pension));
+            cola_signal.set(inputs.pension_cola * 100.0);
+            cola_input.set(format!("{}", inputs.pension_cola * 100.0));
+            annuity_signal.set(inputs.annuity_balance);
+            annuity_input.set(format!("{}", inputs.annuity_balance));
+            annuity_rate_signal.set(inputs.annuity_rate * 100.0);
+            annuity_rate_input.set(format!("{}", inputs.annuity_rate * 100.0));
+            annuity_years_signal.set(inputs.annuity_years);
+            annuity_years_input.set(format!("{}", inputs.annuity_years));
+            portfolio_signal.set(inputs.portfolio_balance);
+            portfolio_input.set(format!("{}", inputs.portfolio_balance));
+            withdrawal_signal.set(inputs.withdrawal_rate * 100.0);
+            withdrawal_input.set(format!("{}", inputs.withdrawal_rate * 100.0));
+            return_signal.set(inputs.portfolio_return * 100.0);
+            return_input.set(format!("{}", inputs.portfolio_return * 100.0));
+            expenses_signal.set(inputs.monthly_expenses);
+            expenses_input.set(format!("{}", inputs.monthly_expenses));
+            inflation_signal.set(inputs.inflation_rate * 100.0);
+            inflation_input.set(format!("{}", inputs.inflation_rate * 100.0));
+            status_signal.set(inputs.healthcare.status);
+            magi_signal.set(inputs.healthcare.income);
+            magi_input.set(format!("{}", inputs.healthcare.income));
+            out_of_pocket_signal.set(inputs.healthcare.out_of_pocket);
+            out_of_pocket_input.set(format!("{}", inputs.healthcare.out_of_pocket));
+            health_inflation_signal.set(inputs.healthcare.healthcare_inflation * 100.0);
+            health_inflation_input.set(format!(
+                "{}",
+                inputs.healthcare.healthcare_inflation * 100.0
+            ));
+            taxable_signal.set(inputs.accounts.taxable * 100.0);
+            taxable_input.set(format!("{}", inputs.accounts.taxable * 100.0));
+            traditional_signal.set(inputs.accounts.traditional * 100.0);
+            traditional_input.set(format!("{}", inputs.accounts.traditional * 100.0));
+            roth_signal.set(inputs.accounts.roth * 100.0);
+            roth_input.set(format!("{}", inputs.accounts.roth * 100.0));
+            gain_share_signal.set(inputs.taxable_gain_share * 100.0);
+            gain_share_input.set(format!("{}", inputs.taxable_gain_share * 100.0));
+            scenarios_signal.set(session.inflation_scenarios);
+        }),
+    );
+    use_track_calculator("retirement_income");
+    use_track_input_range(
+        "retirement_income",
+        "portfolio_balance",
+        portfolio_signal,
+        amount_bucket,
+    );
+    let scenarios = if scenarios_signal().enabled {
+        Some(run_inflation_scenarios(
+            &inputs(),
+            &scenarios_signal(),
+            |inputs| project_retirement_income(inputs, medicare_year()),
+        ))
+    } else {
+        None
+    };
+
+    rsx! {
+        hr {}
+        br {}
+
+        DataYearBanner { data_set: "medicare_premiums", year: medicare_year }
+
+        NumericInput {
+            label: "Retirement Age:".to_string(),
+            placeholder: "Enter the age you stop working (e.g., 67)".to_string(),
+            input_signal: retirement_age_input,
+            value_signal: retirement_age_signal,
+            valid_signal: retirement_age_input_valid,
+            field_name: "Retirement age".to_string(),
+            css_prefix: "retirement-age".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(0),
+        }
+        NumericInput {
+            label: "Plan to Age:".to_string(),
+            placeholder: "Enter the age your income needs to last to (e.g., 95)".to_string(),
+            input_signal: plan_age_input,
+            value_signal: plan_age_signal,
+            valid_signal: plan_age_input_valid,
+            field_name: "Plan-to age".to_string(),
+            css_prefix: "plan-age".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(0),
+        }
+        NumericInput {
+            label: "Social Security ($/mo):".to_string(),
+            placeholder: "Enter your estimated benefit at your claiming age (e.g., 2400)".to_string(),
+            input_signal: social_security_input,
+            value_signal: social_security_signal,
+            valid_signal: social_security_input_valid,
+            field_name: "Social Security".to_string(),
+            css_prefix: "social-security".to_string(),
+            allow_negative: true,
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Claiming Age:".to_string(),
+            placeholder: "Enter the age Social Security starts (e.g., 67)".to_string(),
+            input_signal: claim_age_input,
+            value_signal: claim_age_signal,
+            valid_signal: claim_age_input_valid,
+            field_name: "Claiming age".to_string(),
+            css_prefix: "claim-age".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(0),
+        }
+        NumericInput {
+            label: "Pension ($/mo):".to_string(),
+            placeholder: "Enter your monthly pension, or 0 (e.g., 800)".to_string(),
+            input_signal: pension_input,
+            value_signal: pension_signal,
+            valid_signal: pension_input_valid,
+            field_name: "Pension".to_string(),
+            css_prefix: "pension".to_string(),
+            allow_negative: true,
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Pension COLA (%/yr):".to_string(),
+            placeholder: "Enter the pension's yearly cost-of-living raise, or 0 if flat (e.g., 0)".to_string(),
+            input_signal: cola_input,
+            value_signal: cola_signal,
+            valid_signal: cola_input_valid,
+            field_name: "Pension COLA".to_string(),
+            css_prefix: "cola".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+        NumericInput {
+            label: "Annuity Purchase ($):".to_string(),
+            placeholder: "Enter savings turned into a fixed payout, or 0 (e.g., 100000)".to_string(),
+            input_signal: annuity_input,
+            value_signal: annuity_signal,
+            valid_signal: annuity_input_valid,
+            field_name: "Annuity purchase".to_string(),
+            css_prefix: "annuity".to_string(),
+            allow_negative: true,
+            step: Some(10000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Annuity Rate (%):".to_string(),
+            placeholder: "Enter the annual rate the annuity pays out at (e.g., 5.0)".to_string(),
+            input_signal: annuity_rate_input,
+            value_signal: annuity_rate_signal,
+            valid_signal: annuity_rate_input_valid,
+            field_name: "Annuity rate".to_string(),
+            css_prefix: "annuity-rate".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+        NumericInput {
+            label: "Annuity Years:".to_string(),
+            placeholder: "Enter how many years the annuity pays (e.g., 20)".to_string(),
+            input_signal: annuity_years_input,
+            value_signal: annuity_years_signal,
+            valid_signal: annuity_years_input_valid,
+            field_name: "Annuity years".to_string(),
+            css_prefix: "annuity-years".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(0),
+        }
+        NumericInput {
+            label: "Portfolio ($):".to_string(),
+            placeholder: "Enter invested savings you'll draw on (e.g., 500000)".to_string(),
+            input_signal: portfolio_input,
+            value_signal: portfolio_signal,
+            valid_signal: portfolio_input_valid,
+            field_name: "Portfolio".to_string(),
+            css_prefix: "portfolio".to_string(),
+            allow_negative: true,
+            step: Some(10000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Withdrawal Rate (%):".to_string(),
+            placeholder: "Enter the share withdrawn the first year, then raised with inflation (e.g., 4.0)".to_string(),
+            input_signal: withdrawal_input,
+            value_signal: withdrawal_signal,
+            valid_signal: withdrawal_input_valid,
+            field_name: "Withdrawal rate".to_string(),
+            css_prefix: "withdrawal".to_string(),
+            step: Some(0.25),
+        }
+        NumericInput {
+            label: "Portfolio Return (%/yr):".to_string(),
+            placeholder: "Enter the expected annual return (e.g., 6.0)".to_string(),
+            input_signal: return_input,
+            value_signal: return_signal,
+            valid_signal: return_input_valid,
+            field_name: "Portfolio return".to_string(),
+            css_prefix: "return".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+        NumericInput {
+            label: "Taxable Accounts (%):".to_string(),
+            placeholder: "Enter the share of the portfolio in brokerage accounts (e.g., 20)".to_string(),
+            input_signal: taxable_input,
+            value_signal: taxable_signal,
+            valid_signal: taxable_input_valid,
+            field_name: "Taxable accounts".to_string(),
+            css_prefix: "taxable".to_string(),
+            step: Some(5.0),
+        }
+        NumericInput {
+            label: "Traditional (%):".to_string(),
+            placeholder: "Enter the share in traditional 401(k)s and IRAs (e.g., 60)".to_string(),
+            input_signal: traditional_input,
+            value_signal: traditional_signal,
+            valid_signal: traditional_input_valid,
+            field_name: "Traditional".to_string(),
+            css_prefix: "traditional".to_string(),
+            step: Some(5.0),
+        }
+        NumericInput {
+            label: "Roth (%):".to_string(),
+            placeholder: "Enter the share in Roth 401(k)s and IRAs (e.g., 20)".to_string(),
+            input_signal: roth_input,
+            value_signal: roth_signal,
+            valid_signal: roth_input_valid,
+            field_name: "Roth".to_string(),
+            css_prefix: "roth".to_string(),
+            step: Some(5.0),
+        }
+        NumericInput {
+            label: "Taxable Gains (%):".to_string(),
+            placeholder: "Enter the share of brokerage withdrawals that is gain rather than cost basis (e.g., 50)".to_string(),
+            input_signal: gain_share_input,
+            value_signal: gain_share_signal,
+            valid_signal: gain_share_input_valid,
+            field_name: "Taxable gains".to_string(),
+            css_prefix: "gain-share".to_string(),
+            step: Some(5.0),
+        }
+        NumericInput {
+            label: "Expenses ($/mo):".to_string(),
+            placeholder: "Enter your monthly spending in retirement, apart from healthcare (e.g., 4400)".to_string(),
+            input_signal: expenses_input,
+            value_signal: expenses_signal,
+            valid_signal: expenses_input_valid,
+            field_name: "Expenses".to_string(),
+            css_prefix: "expenses".to_string(),
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Inflation (%/yr):".to_string(),
+            placeholder: "Enter expected inflation (e.g., 2.5)".to_string(),
+            input_signal: inflation_input,
+            value_signal: inflation_signal,
+            valid_signal: inflation_input_valid,
+            field_name: "Inflation".to_string(),
+            css_prefix: "inflation".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+        InflationScenarioControls { scenarios: scenarios_signal }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Filing Status:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(status, _)) = STATUS_OPTIONS
+                        .iter()
+                        .find(|(_, value_str)| *value_str == value.as_str())
+                    {
+                        status_signal.set(status);
+                    }
+                },
+                for (status , value) in STATUS_OPTIONS.iter() {
+                    option { value: *value, selected: status_signal() == *status, {status.label()} }
+                }
+            }
+        }
+        NumericInput {
+            label: "Income for IRMAA ($/yr):".to_string(),
+            placeholder: "Enter your modified AGI, which sets Medicare surcharges (e.g., 80000)".to_string(),
+            input_signal: magi_input,
+            value_signal: magi_signal,
+            valid_signal: magi_input_valid,
+            field_name: "Income for IRMAA".to_string(),
+            css_prefix: "magi".to_string(),
+            allow_negative: true,
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Out-of-Pocket Health ($/mo):".to_string(),
+            placeholder: "Enter deductibles, copays, and Medigap and drug plan premiums (e.g., 400)".to_string(),
+            input_signal: out_of_pocket_input,
+            value_signal: out_of_pocket_signal,
+            valid_signal: out_of_pocket_input_valid,
+            field_name: "Out-of-pocket health costs".to_string(),
+            css_prefix: "out-of-pocket".to_string(),
+            allow_negative: true,
+            step: Some(50.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Healthcare Inflation (%/yr):".to_string(),
+            placeholder: "Enter how fast premiums and health costs grow (e.g., 5.0)".to_string(),
+            input_signal: health_inflation_input,
+            value_signal: health_inflation_signal,
+            valid_signal: health_inflation_input_valid,
+            field_name: "Healthcare inflation".to_string(),
+            css_prefix: "health-inflation".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+
+        {
+            match project_retirement_income(&inputs(), medicare_year()) {
+                Ok(income) => {
+                    let first = income.years[0];
+                    let last_age = income.years[income.years.len() - 1].age;
+                    let coverage = match income.shortfall_age {
+                        Some(age) => {
+                            format!("After-tax income falls short of expenses from age {}.", age)
+                        }
+                        None => format!("After-tax income covers expenses through age {}.", last_age),
+                    };
+                    let depletion = income
+                        .depletion_age
+                        .map(|age| format!(" The portfolio runs out at age {}.", age))
+                        .unwrap_or_default();
+                    let irmaa = if income.irmaa_tier > 0 {
+                        format!(
+                            ", ${} of IRMAA surcharges for income in tier {},",
+                            format_dollars(first.healthcare.irmaa),
+                            income.irmaa_tier,
+                        )
+                    } else {
+                        String::new()
+                    };
+                    let source = |value: fn(&IncomeYear) -> f64| {
+                        income.years.iter().map(value).collect::<Vec<f64>>()
+                    };
+                    let sources = vec![
+                        ChartSeries {
+                            label: "Social Security".to_string(),
+                            color: SOCIAL_SECURITY_COLOR.to_string(),
+                            values: source(|year| year.social_security),
+                        },
+                        ChartSeries {
+                            label: "Pension".to_string(),
+                            color: PENSION_COLOR.to_string(),
+                            values: source(|year| year.pension),
+                        },
+                        ChartSeries {
+                            label: "Annuity".to_string(),
+                            color: ANNUITY_COLOR.to_string(),
+                            values: source(|year| year.annuity),
+                        },
+                        ChartSeries {
+                            label: "Portfolio".to_string(),
+                            color: PORTFOLIO_COLOR.to_string(),
+                            values: source(|year| year.portfolio),
+                        },
+                    ];
+                    let income_vs_expenses = vec![
+                        ChartSeries {
+                            label: "Income".to_string(),
+                            color: INCOME_COLOR.to_string(),
+                            values: source(|year| year.total),
+                        },
+                        ChartSeries {
+                            label: "After federal tax".to_string(),
+                            color: NET_INCOME_COLOR.to_string(),
+                            values: source(|year| year.net),
+                        },
+                        ChartSeries {
+                            label: "Expenses".to_string(),
+                            color: EXPENSES_COLOR.to_string(),
+                            values: source(|year| year.expenses),
+                        },
+                        ChartSeries {
+                            label: "Healthcare".to_string(),
+                            color: HEALTHCARE_COLOR.to_string(),
+                            values: source(|year| year.healthcare.total),
+                        },
+                    ];
+                    rsx! {
+                        div {
+                            id: "RetirementIncomeCalculation",
+                            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                            "First year: ${format_dollars(first.total)} a month of income, ${format_dollars(first.net)} after federal tax, against ${format_dollars(first.expenses)} of expenses*"
+                        }
+                        div {
+                            id: "RetirementIncomeCoverage",
+                            style: "margin-bottom: 15px; font-size: 16px;",
+                            "{coverage}{depletion}"
+                        }
+                        div {
+                            id: "RetirementIncomeHealthcare",
+                            style: "margin-bottom: 15px; font-size: 14px;",
+                            "Healthcare is ${format_dollars(first.healthcare.total)} of the first year's monthly expenses: ${format_dollars(first.healthcare.medicare)} of Medicare premiums{irmaa} and ${format_dollars(first.healthcare.out_of_pocket)} out of pocket."
+                        }
+                        StackedBarChart {
+                            title: "Monthly income by source".to_string(),
+                            series: sources,
+                            x_start: first.age,
+                            x_label: "Age".to_string(),
+                            hover_index,
+                        }
+                        LineChart {
+                            title: "Monthly income vs expenses".to_string(),
+                            series: income_vs_expenses,
+                            x_start: first.age,
+                            x_label: "Age".to_string(),
+                            hover_index,
+                            y_max: None,
+                        }
+                        {
+                            match scenarios {
+                                Some(Ok((rates, results))) => {
+                                    let shortfalls = results.map(shortfall_text);
+                                    rsx! {
+                                        div {
+                                            id: "RetirementIncomeInflationScenarios",
+                                            style: "margin-bottom: 15px; font-size: 14px;",
+                                            for case in InflationCase::ALL {
+                                                div {
+                                                    "{case.label()} ({rates.get(case) * 100.0:.2}%): {shortfalls.get(case)}"
+                                                }
+                                            }
+                                        }
+                                        InflationBandChart {
+                                            title: "After-tax income as a share of expenses".to_string(),
+                                            results: results.map(coverage_ratios),
+                                            rates,
+                                            x_start: first.age,
+                                            color: INCOME_COLOR.to_string(),
+                                            y_format: AxisFormat::Percent,
+                                            x_label: "Age".to_string(),
+                                            hover_index,
+                                        }
+                                    }
+                                }
+                                Some(Err(error)) => rsx! {
+                                    div {
+                                        id: "RetirementIncomeInflationScenarios",
+                                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                                        "{error}"
+                                    }
+                                },
+                                None => rsx! {},
+                            }
+                        }
+                    }
+                }
+                Err(error) => rsx! {
+                    div {
+                        id: "RetirementIncomeCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "retirement_income" }
+        AssumptionsFooter { calculator: "retirement_income" }
+        div { style: "font-size: 10px",
+            p {
+                "*Federal income tax only, at this year's brackets raised with inflation; state taxes are left out. The pension, the annuity, traditional withdrawals, and 85% of Social Security are taxed as ordinary income, and the gains in brokerage withdrawals as long-term capital gains. Account shares are rescaled to add up to 100%. Enter amounts in dollars of your first year of retirement; Social Security, withdrawals, and expenses then rise with inflation, the pension with its COLA, and healthcare with healthcare inflation. Medicare premiums start at 65. Inflation scenarios change general inflation only; healthcare keeps its own rate."
+            }
+            br {}
+            br {}
+        }
+    }
+}
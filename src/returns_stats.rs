@@ -0,0 +1,165 @@
+use crate::table_paste::split_pasted_line;
+use serde::{Deserialize, Serialize};
+
+/// Inputs to the returns statistics calculator
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReturnsStatsInputs {
+    /// Pasted or uploaded returns, one period per line or all on one line
+    pub returns_text: String,
+    /// Return periods in a year, e.g., 12 for monthly returns
+    pub periods_per_year: f64,
+    /// Annual risk-free rate as a decimal; also the target return downside is measured against
+    pub risk_free_rate: f64,
+}
+
+/// Summary statistics of a return series, per period and annualized
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReturnStats {
+    pub count: usize,
+    /// Arithmetic mean return per period
+    pub mean: f64,
+    /// Sample standard deviation per period
+    pub std_dev: f64,
+    /// Root mean square of the shortfalls below the risk-free return per period
+    pub downside_deviation: f64,
+    /// Risk-free return per period, compounded down from the annual rate
+    pub risk_free: f64,
+    pub sharpe: f64,
+    /// None when no period falls below the risk-free return, leaving no downside to divide by
+    pub sortino: Option<f64>,
+    /// Mean * periods per year
+    pub annual_mean: f64,
+    /// Standard deviation * √(periods per year)
+    pub annual_std_dev: f64,
+    pub annual_downside_deviation: f64,
+    pub annual_sharpe: f64,
+    pub annual_sortino: Option<f64>,
+}
+
+/// Reads one return: values ending in `%` are percentages (`1.2%`), others decimals (`0.012`)
+fn parse_return(cell: &str) -> Result<f64, String> {
+    let cleaned: String = cell.chars().filter(|c| !c.is_whitespace()).collect();
+    let (number, scale) = match cleaned.strip_suffix('%') {
+        Some(number) => (number, 0.01),
+        None => (cleaned.as_str(), 1.0),
+    };
+    match number.parse::<f64>() {
+        Ok(value) if value.is_finite() && value * scale > -1.0 => Ok(value * scale),
+        Ok(_) => Err(format!("\"{}\" is not a possible return", cell)),
+        Err(_) => Err(format!("\"{}\" is not a valid number", cell)),
+    }
+}
+
+/// Parses a pasted or uploaded return series. A single line is read as a comma- or
+/// tab-separated list. Otherwise each line is one period and its last column the return, so a
+/// date or label column ahead of it is ignored, and a first line whose last column isn't a
+/// number is skipped as a header.
+pub fn parse_return_series(text: &str) -> Result<Vec<f64>, String> {
+    let lines: Vec<(usize, &str)> = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect();
+
+    let returns = match lines.as_slice() {
+        [(_, line)] => split_pasted_line(line)
+            .iter()
+            .filter(|cell| !cell.is_empty())
+            .map(|cell| parse_return(cell))
+            .collect::<Result<Vec<f64>, String>>()?,
+        _ => {
+            let mut returns = vec![];
+            for (position, (index, line)) in lines.iter().enumerate() {
+                let cells = split_pasted_line(line);
+                let cell = cells
+                    .iter()
+                    .rev()
+                    .find(|cell| !cell.is_empty())
+                    .map(String::as_str)
+                    .unwrap_or_default();
+                match parse_return(cell) {
+                    Ok(value) => returns.push(value),
+                    Err(_) if position == 0 => continue,
+                    Err(message) => return Err(format!("Line {}: {}", index + 1, message)),
+                }
+            }
+            returns
+        }
+    };
+
+    if returns.len() < 2 {
+        return Err("Please enter at least two periods of returns".to_string());
+    }
+    Ok(returns)
+}
+
+// Computes the mean, volatility, downside deviation, and Sharpe and Sortino ratios of a series of
+// periodic returns.
+///
+/// # Parameters:
+/// - `returns`: Return of each period as a decimal (r)
+/// - `periods_per_year`: Periods in a year (p)
+/// - `risk_free_rate`: Annual risk-free rate (Rf), converted to a per-period rate
+///   rf = (1 + Rf)^(1 / p) - 1
+///
+/// # Formula:
+/// mean = Σr / n
+/// standard deviation = √(Σ(r - mean)² / (n - 1))
+/// downside deviation = √(Σ min(r - rf, 0)² / n)
+/// Sharpe = (mean - rf) / standard deviation
+/// Sortino = (mean - rf) / downside deviation
+/// Annualized: mean * p, deviations * √p, ratios * √p
+///
+/// # Returns:
+/// The statistics per period and annualized. An error if there are fewer than two returns, the
+/// periods per year aren't positive, or the returns never vary.
+pub fn compute_return_stats(
+    returns: &[f64],
+    periods_per_year: f64,
+    risk_free_rate: f64,
+) -> Result<ReturnStats, String> {
+    if returns.len() < 2 {
+        return Err("Please enter at least two periods of returns".to_string());
+    }
+    if periods_per_year.is_nan() || periods_per_year <= 0.0 {
+        return Err("Periods per year must be greater than zero".to_string());
+    }
+    if risk_free_rate.is_nan() || risk_free_rate <= -1.0 {
+        return Err("The risk-free rate must be greater than -100%".to_string());
+    }
+
+    let n = returns.len() as f64;
+    let risk_free = (1.0 + risk_free_rate).powf(1.0 / periods_per_year) - 1.0;
+    let mean = returns.iter().sum::<f64>() / n;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    let std_dev = variance.sqrt();
+    if std_dev < 1e-12 {
+        return Err("The returns never vary, so there is no risk to measure".to_string());
+    }
+    let downside_deviation = (returns
+        .iter()
+        .map(|r| (r - risk_free).min(0.0).powi(2))
+        .sum::<f64>()
+        / n)
+        .sqrt();
+
+    let excess = mean - risk_free;
+    let sharpe = excess / std_dev;
+    let sortino = (downside_deviation > 0.0).then(|| excess / downside_deviation);
+    let scale = periods_per_year.sqrt();
+
+    Ok(ReturnStats {
+        count: returns.len(),
+        mean,
+        std_dev,
+        downside_deviation,
+        risk_free,
+        sharpe,
+        sortino,
+        annual_mean: mean * periods_per_year,
+        annual_std_dev: std_dev * scale,
+        annual_downside_deviation: downside_deviation * scale,
+        annual_sharpe: sharpe * scale,
+        annual_sortino: sortino.map(|ratio| ratio * scale),
+    })
+}
@@ -0,0 +1,215 @@
+use crate::analytics::use_track_calculator;
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::feedback_component::FeedbackWidget;
+use crate::numeric_input_component::NumericInput;
+use crate::returns_stats::{compute_return_stats, parse_return_series, ReturnsStatsInputs};
+use dioxus::prelude::*;
+
+/// A year of monthly returns in the two-column layout most brokerage exports use
+const DEFAULT_RETURNS: &str = "Month,Return
+Jan,1.2%
+Feb,-0.8%
+Mar,2.1%
+Apr,0.5%
+May,-1.5%
+Jun,3.0%
+Jul,0.9%
+Aug,-2.2%
+Sep,1.8%
+Oct,0.4%
+Nov,1.1%
+Dec,-0.3%";
+
+const FREQUENCY_OPTIONS: &[(f64, &str, &str)] = &[
+    (252.0, "daily", "Daily"),
+    (52.0, "weekly", "Weekly"),
+    (12.0, "monthly", "Monthly"),
+    (4.0, "quarterly", "Quarterly"),
+    (1.0, "annual", "Annual"),
+];
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+const LABEL_STYLE: &str = "text-align: left; padding: 2px 8px;";
+
+/// A ratio to two places, or a dash when it can't be computed
+fn ratio_text(ratio: Option<f64>) -> String {
+    ratio
+        .map(|ratio| format!("{:.2}", ratio))
+        .unwrap_or_else(|| "—".to_string())
+}
+
+#[component]
+pub fn ReturnsStatsUI() -> Element {
+    let mut returns_text = use_signal(|| DEFAULT_RETURNS.to_string());
+    let mut frequency_signal = use_signal(|| 12.0 as f64);
+    // The risk-free rate is entered in percent
+    let mut risk_free_signal = use_signal(|| 4.0 as f64);
+    let mut risk_free_input = use_signal(|| "4.0".to_string());
+    let risk_free_input_valid = use_signal(|| true);
+
+    let inputs = move || ReturnsStatsInputs {
+        returns_text: returns_text(),
+        periods_per_year: frequency_signal(),
+        risk_free_rate: risk_free_signal() / 100.0,
+    };
+    let start_fresh = use_autosave(
+        "returns_stats",
+        ReturnsStatsInputs {
+            returns_text: DEFAULT_RETURNS.to_string(),
+            periods_per_year: 12.0,
+            risk_free_rate: 0.04,
+        },
+        inputs,
+        use_callback(move |inputs: ReturnsStatsInputs| {
+            returns_text.set(inputs.returns_text);
+            frequency_signal.set(inputs.periods_per_year);
+            risk_free_signal.set(inputs.risk_free_rate * 100.0);
+            risk_free_input.set(format!("{}", inputs.risk_free_rate * 100.0));
+        }),
+    );
+    use_track_calculator("returns_stats");
+
+    let stats = parse_return_series(&returns_text()).and_then(|returns| {
+        compute_return_stats(&returns, frequency_signal(), risk_free_signal() / 100.0)
+    });
+
+    rsx! {
+        hr {}
+        br {}
+
+        div { style: "color: #333; margin-bottom: 15px;",
+            label { style: "font-weight: bold;", r#for: "returns-text", "Periodic Returns:" }
+            p { style: "font-size: 12px;",
+                "Paste one return per line, with any date or label columns first — tab- or comma-separated, as copied from a spreadsheet or CSV — or a single comma-separated line. Write percentages with a % sign (1.2%); other values are read as decimals (0.012). A header row is skipped."
+            }
+            textarea {
+                id: "returns-text",
+                rows: "8",
+                style: "width: 420px; font-family: monospace; border-radius: 4px;",
+                placeholder: "Jan,1.2%",
+                value: "{returns_text}",
+                oninput: move |event| returns_text.set(event.value()),
+            }
+            br {}
+            label { style: "font-size: 12px; margin-right: 10px;", "Or upload a CSV file:" }
+            input {
+                r#type: "file",
+                accept: ".csv,.tsv,.txt",
+                style: "font-size: 12px; margin-top: 5px;",
+                onchange: move |event| async move {
+                    let Some(file_engine) = event.files() else {
+                        return;
+                    };
+                    if let Some(name) = file_engine.files().first() {
+                        if let Some(text) = file_engine.read_file_to_string(name).await {
+                            returns_text.set(text);
+                        }
+                    }
+                },
+            }
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Return Periods:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(periods, _, _)) = FREQUENCY_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        frequency_signal.set(periods);
+                    }
+                },
+                for (periods , value , display) in FREQUENCY_OPTIONS.iter() {
+                    option { value: *value, selected: frequency_signal() == *periods, {*display} }
+                }
+            }
+        }
+        NumericInput {
+            label: "Risk-Free Rate (%/yr):".to_string(),
+            placeholder: "Enter the annual Treasury bill yield (e.g., 4.0)".to_string(),
+            input_signal: risk_free_input,
+            value_signal: risk_free_signal,
+            valid_signal: risk_free_input_valid,
+            field_name: "Risk-free rate".to_string(),
+            css_prefix: "risk-free".to_string(),
+            allow_negative: true,
+            step: Some(0.25),
+        }
+
+        {
+            match stats {
+                Ok(stats) => rsx! {
+                    div {
+                        id: "ReturnsStatsCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        "Sharpe ratio: {stats.annual_sharpe:.2}, Sortino ratio: {ratio_text(stats.annual_sortino)} (annualized)*"
+                    }
+                    p { style: "font-size: 14px; color: #333;",
+                        "From {stats.count} returns, against a risk-free return of {stats.risk_free * 100.0:.3}% a period."
+                    }
+                    table { style: "color: #333; border-collapse: collapse; font-size: 14px; margin-bottom: 15px;",
+                        thead {
+                            tr {
+                                th {}
+                                th { scope: "col", style: CELL_STYLE, "Per period" }
+                                th { scope: "col", style: CELL_STYLE, "Annualized" }
+                            }
+                        }
+                        tbody {
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Mean return" }
+                                td { style: CELL_STYLE, "{stats.mean * 100.0:.2}%" }
+                                td { style: CELL_STYLE, "{stats.annual_mean * 100.0:.2}%" }
+                            }
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Standard deviation" }
+                                td { style: CELL_STYLE, "{stats.std_dev * 100.0:.2}%" }
+                                td { style: CELL_STYLE, "{stats.annual_std_dev * 100.0:.2}%" }
+                            }
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Downside deviation" }
+                                td { style: CELL_STYLE, "{stats.downside_deviation * 100.0:.2}%" }
+                                td { style: CELL_STYLE,
+                                    "{stats.annual_downside_deviation * 100.0:.2}%"
+                                }
+                            }
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Sharpe ratio" }
+                                td { style: CELL_STYLE, "{stats.sharpe:.2}" }
+                                td { style: CELL_STYLE, "{stats.annual_sharpe:.2}" }
+                            }
+                            tr {
+                                th { scope: "row", style: LABEL_STYLE, "Sortino ratio" }
+                                td { style: CELL_STYLE, "{ratio_text(stats.sortino)}" }
+                                td { style: CELL_STYLE, "{ratio_text(stats.annual_sortino)}" }
+                            }
+                        }
+                    }
+                },
+                Err(error) => rsx! {
+                    div {
+                        id: "ReturnsStatsCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "returns_stats" }
+        AssumptionsFooter { calculator: "returns_stats" }
+        div { style: "font-size: 10px",
+            p {
+                "*Excess return over the risk-free rate per unit of risk: total volatility for Sharpe, volatility below the risk-free return for Sortino. Higher is better; a dash means no period fell below the risk-free return."
+            }
+            br {}
+            br {}
+        }
+    }
+}
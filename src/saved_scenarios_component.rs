@@ -0,0 +1,97 @@
+use crate::scenario::{put_scenario, update_scenarios, use_scenarios, Scenario};
+use crate::scenario_comparison_component::ScenarioComparison;
+use dioxus::prelude::*;
+
+const CELL_STYLE: &str = "text-align: left; padding: 2px 8px;";
+
+/// The scenarios saved for this calculator, kept current when another tab saves or deletes one.
+/// Saves the current inputs (`scenario`) under a name, replacing a scenario already saved under
+/// it, and hands a saved scenario to `on_load` to fill the form back in. Once two are saved,
+/// they can be compared.
+#[component]
+pub fn SavedScenarios(scenario: Scenario, on_load: Callback<Scenario>) -> Element {
+    let mut scenarios = use_scenarios();
+    let mut name = use_signal(String::new);
+
+    let calculator = scenario.calculator.clone();
+    let saved: Vec<Scenario> = scenarios()
+        .into_iter()
+        .filter(|saved| saved.calculator == calculator)
+        .collect();
+    let trimmed_name = name().trim().to_string();
+
+    rsx! {
+        div { style: "color: #333; margin-bottom: 15px;",
+            div { style: "font-weight: bold; margin-bottom: 5px;", "My Scenarios" }
+            div { style: "display: flex; align-items: center; gap: 10px; margin-bottom: 10px;",
+                input {
+                    r#type: "text",
+                    "aria-label": "Scenario name",
+                    style: "padding: 6px 8px; width: 200px; border-radius: 4px;",
+                    placeholder: "Name these inputs (e.g., Retire at 62)",
+                    value: "{name}",
+                    oninput: move |event| name.set(event.value()),
+                }
+                button {
+                    style: "padding: 6px 12px; border-radius: 4px;",
+                    disabled: trimmed_name.is_empty(),
+                    onclick: move |_| {
+                        let scenario = Scenario {
+                            name: trimmed_name.clone(),
+                            ..scenario.clone()
+                        };
+                        async move {
+                            scenarios.set(update_scenarios(|list| put_scenario(list, scenario)).await);
+                            name.set(String::new());
+                        }
+                    },
+                    "Save Scenario"
+                }
+            }
+            if saved.is_empty() {
+                div { style: "font-size: 12px;", "No saved scenarios yet." }
+            } else {
+                table { style: "border-collapse: collapse; font-size: 13px;",
+                    tbody {
+                        for saved_scenario in saved.iter().cloned() {
+                            tr { key: "{saved_scenario.name}",
+                                th { scope: "row", style: CELL_STYLE, "{saved_scenario.name}" }
+                                td { style: CELL_STYLE,
+                                    button {
+                                        style: "padding: 2px 8px; border-radius: 4px;",
+                                        onclick: {
+                                            let saved_scenario = saved_scenario.clone();
+                                            move |_| on_load.call(saved_scenario.clone())
+                                        },
+                                        "Load"
+                                    }
+                                }
+                                td { style: CELL_STYLE,
+                                    button {
+                                        style: "padding: 2px 8px; border-radius: 4px;",
+                                        onclick: move |_| {
+                                            let saved_scenario = saved_scenario.clone();
+                                            async move {
+                                                scenarios
+                                                    .set(
+                                                        update_scenarios(|list| {
+                                                            list.retain(|other| *other != saved_scenario)
+                                                        })
+                                                            .await,
+                                                    );
+                                            }
+                                        },
+                                        "Delete"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            if saved.len() > 1 {
+                ScenarioComparison { scenarios: saved.clone() }
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+
+/// Months until the Treasury guarantees an EE bond has doubled
+pub const EE_DOUBLING_MONTHS: u32 = 240;
+/// Months until an EE bond stops earning interest
+pub const EE_FINAL_MATURITY_MONTHS: u32 = 360;
+/// EE bonds can't be cashed in the first year
+pub const EE_MIN_HOLDING_MONTHS: u32 = 12;
+/// Cashing before five years forfeits the last three months of interest
+pub const EE_PENALTY_MONTHS: u32 = 60;
+
+// Computes the value of a Series EE savings bond (issued May 2005 or later) after a number of
+// months, before any early-redemption penalty.
+///
+/// # Parameters:
+/// - `purchase_price`: Amount paid (P); electronic EE bonds are bought at face value
+/// - `fixed_rate`: Fixed annual rate (r), e.g., 0.027 for 2.7%
+/// - `months`: Months since issue (m)
+///
+/// # Formula:
+/// V(m) = P * (1 + r/2)^(m/6)                     m < 240
+/// V(m) = max(2P, V(240)) * (1 + r/2)^((m - 240)/6)  m >= 240
+/// Interest stops at 30 years (m = 360).
+///
+/// # Returns:
+/// The accrued value, not rounded.
+pub fn ee_bond_value(purchase_price: f64, fixed_rate: f64, months: u32) -> f64 {
+    let months = months.min(EE_FINAL_MATURITY_MONTHS);
+    let semiannual_growth = 1.0 + fixed_rate / 2.0;
+    let accrue = |value: f64, months: u32| value * semiannual_growth.powf(months as f64 / 6.0);
+
+    if months < EE_DOUBLING_MONTHS {
+        return accrue(purchase_price, months);
+    }
+    // The one-time adjustment at 20 years tops the bond up to twice its price if needed
+    let at_doubling = accrue(purchase_price, EE_DOUBLING_MONTHS).max(2.0 * purchase_price);
+    accrue(at_doubling, months - EE_DOUBLING_MONTHS)
+}
+
+// Computes what an EE bond pays if cashed after a number of months.
+///
+/// # Parameters:
+/// - `purchase_price`: Amount paid (P)
+/// - `fixed_rate`: Fixed annual rate (r)
+/// - `months`: Months held (m)
+///
+/// # Formula:
+/// V(m - 3) if m < 60 (three months of interest forfeited), otherwise V(m)
+///
+/// # Returns:
+/// The redemption value, or `None` in the first 12 months, when the bond can't be cashed.
+pub fn ee_redemption_value(purchase_price: f64, fixed_rate: f64, months: u32) -> Option<f64> {
+    if months < EE_MIN_HOLDING_MONTHS {
+        return None;
+    }
+    let months_credited = if months < EE_PENALTY_MONTHS {
+        months - 3
+    } else {
+        months
+    };
+    Some(ee_bond_value(purchase_price, fixed_rate, months_credited))
+}
+
+/// Inputs to the savings bond calculator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SavingsBondInputs {
+    pub purchase_price: f64,
+    /// Fixed annual rate as a decimal
+    pub fixed_rate: f64,
+    pub years_held: f64,
+}
+
+/// Results of the savings bond calculator
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavingsBondOutputs {
+    /// Whole months held
+    pub months_held: u32,
+    /// What the bond pays when cashed, or `None` in the first year
+    pub redemption_value: Option<f64>,
+    /// Plain semiannual compounding at the stated rate, ignoring EE rules
+    pub naive_value: f64,
+    /// Annual yield of the redemption value over the holding period
+    pub effective_yield: Option<f64>,
+    /// Whether the 20-year guarantee raised the value above the stated rate
+    pub doubling_applied: bool,
+    /// Value at the end of each year, from issue to final maturity
+    pub yearly_values: Vec<f64>,
+}
+
+/// Values an EE bond held for `years_held` under the EE rules
+pub fn compute_savings_bond(inputs: &SavingsBondInputs) -> SavingsBondOutputs {
+    let months_held = (inputs.years_held * 12.0).round().max(0.0) as u32;
+    let redemption_value =
+        ee_redemption_value(inputs.purchase_price, inputs.fixed_rate, months_held);
+    let naive_value =
+        inputs.purchase_price * (1.0 + inputs.fixed_rate / 2.0).powf(2.0 * inputs.years_held);
+    let effective_yield = redemption_value
+        .filter(|_| inputs.purchase_price > 0.0)
+        .map(|value| (value / inputs.purchase_price).powf(12.0 / months_held.max(1) as f64) - 1.0);
+    let stated_at_doubling = inputs.purchase_price
+        * (1.0 + inputs.fixed_rate / 2.0).powf(EE_DOUBLING_MONTHS as f64 / 6.0);
+
+    SavingsBondOutputs {
+        months_held,
+        redemption_value,
+        naive_value,
+        effective_yield,
+        doubling_applied: months_held >= EE_DOUBLING_MONTHS
+            && stated_at_doubling < 2.0 * inputs.purchase_price,
+        yearly_values: (0..=EE_FINAL_MATURITY_MONTHS / 12)
+            .map(|year| ee_bond_value(inputs.purchase_price, inputs.fixed_rate, year * 12))
+            .collect(),
+    }
+}
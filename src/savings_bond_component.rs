@@ -0,0 +1,155 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::chart::{ChartSeries, LineChart};
+use crate::feedback_component::FeedbackWidget;
+use crate::number_format::dollars;
+use crate::numeric_input_component::NumericInput;
+use crate::savings_bond::{compute_savings_bond, SavingsBondInputs};
+use dioxus::prelude::*;
+
+const DEFAULT_INPUTS: SavingsBondInputs = SavingsBondInputs {
+    purchase_price: 1_000.0,
+    fixed_rate: 0.027,
+    years_held: 20.0,
+};
+
+const EE_COLOR: &str = "#2471a3";
+const NAIVE_COLOR: &str = "#999999";
+
+#[component]
+pub fn SavingsBondUI() -> Element {
+    let mut price_signal = use_signal(|| 1_000.0 as f64);
+    let mut price_input = use_signal(|| "1000".to_string());
+    let price_input_valid = use_signal(|| true);
+    let mut rate_signal = use_signal(|| 2.7 as f64);
+    let mut rate_input = use_signal(|| "2.7".to_string());
+    let rate_input_valid = use_signal(|| true);
+    let mut years_signal = use_signal(|| 20.0 as f64);
+    let mut years_input = use_signal(|| "20".to_string());
+    let years_input_valid = use_signal(|| true);
+    let hover_index = use_signal(|| None::<usize>);
+    let start_fresh = use_autosave(
+        "savings_bond",
+        DEFAULT_INPUTS,
+        move || SavingsBondInputs {
+            purchase_price: price_signal(),
+            fixed_rate: rate_signal() / 100.0,
+            years_held: years_signal(),
+        },
+        use_callback(move |inputs: SavingsBondInputs| {
+            price_signal.set(inputs.purchase_price);
+            price_input.set(format!("{}", inputs.purchase_price));
+            rate_signal.set(inputs.fixed_rate * 100.0);
+            rate_input.set(format!("{:.2}", inputs.fixed_rate * 100.0));
+            years_signal.set(inputs.years_held);
+            years_input.set(format!("{}", inputs.years_held));
+        }),
+    );
+    use_track_calculator("savings_bond");
+    use_track_input_range("savings_bond", "price", price_signal, amount_bucket);
+
+    let inputs = SavingsBondInputs {
+        purchase_price: price_signal(),
+        fixed_rate: rate_signal() / 100.0,
+        years_held: years_signal(),
+    };
+    let outputs = compute_savings_bond(&inputs);
+    let naive_value = dollars(outputs.naive_value);
+    let naive_path: Vec<f64> = (0..outputs.yearly_values.len())
+        .map(|year| inputs.purchase_price * (1.0 + inputs.fixed_rate / 2.0).powf(2.0 * year as f64))
+        .collect();
+    let held_years = outputs.months_held / 12;
+    let held_months = outputs.months_held % 12;
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Purchase Price ($):".to_string(),
+            placeholder: "Enter amount paid (e.g., 1000)".to_string(),
+            input_signal: price_input,
+            value_signal: price_signal,
+            valid_signal: price_input_valid,
+            field_name: "Purchase price".to_string(),
+            css_prefix: "price".to_string(),
+            step: Some(25.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Fixed Rate (%):".to_string(),
+            placeholder: "Enter the bond's fixed rate (e.g., 2.7)".to_string(),
+            input_signal: rate_input,
+            value_signal: rate_signal,
+            valid_signal: rate_input_valid,
+            field_name: "Fixed rate".to_string(),
+            css_prefix: "rate".to_string(),
+            step: Some(0.1),
+        }
+        NumericInput {
+            label: "Years Held:".to_string(),
+            placeholder: "Enter years until cashing (e.g., 20)".to_string(),
+            input_signal: years_input,
+            value_signal: years_signal,
+            valid_signal: years_input_valid,
+            field_name: "Years held".to_string(),
+            css_prefix: "years".to_string(),
+            step: Some(1.0),
+        }
+
+        div {
+            id: "SavingsBondCalculation",
+            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+            {
+                match outputs.redemption_value {
+                    Some(value) => {
+                        let value = dollars(value);
+                        rsx! {
+                            "Cashed after {held_years} years, {held_months} months, the bond pays {value}*"
+                        }
+                    }
+                    None => rsx! { "EE bonds can't be cashed in the first 12 months" },
+                }
+            }
+        }
+        if let Some(effective_yield) = outputs.effective_yield {
+            div { style: "margin-bottom: 15px; font-size: 16px;",
+                "Effective annual yield: {effective_yield * 100.0:.3}%. Plain compounding at the stated rate would give {naive_value}."
+            }
+        }
+        if outputs.doubling_applied {
+            div { style: "margin-bottom: 15px; font-size: 16px;",
+                "The 20-year guarantee doubled the bond, more than the stated rate alone would have earned."
+            }
+        }
+
+        LineChart {
+            title: "EE bond value by year vs. the stated rate alone".to_string(),
+            series: vec![
+                ChartSeries {
+                    label: "EE bond".to_string(),
+                    color: EE_COLOR.to_string(),
+                    values: outputs.yearly_values,
+                },
+                ChartSeries {
+                    label: "Stated rate only".to_string(),
+                    color: NAIVE_COLOR.to_string(),
+                    values: naive_path,
+                },
+            ],
+            x_start: 0.0,
+            hover_index,
+            y_max: None,
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "savings_bond" }
+        AssumptionsFooter { calculator: "savings_bond" }
+        div { style: "font-size: 10px",
+            p { "*Check TreasuryDirect for the official value of a specific bond." }
+            br {}
+            br {}
+        }
+    }
+}
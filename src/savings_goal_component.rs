@@ -0,0 +1,246 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range, years_bucket};
+use crate::annuity::{solve_savings_goal, SavingsGoalInputs};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::compounding::Compounding;
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::{format_dollars, CompoundingSelect};
+use crate::numeric_input_component::NumericInput;
+use dioxus::prelude::*;
+
+/// $25,000 in five years, starting from $2,000, at 4% compounded monthly
+const DEFAULT_INPUTS: SavingsGoalInputs = SavingsGoalInputs {
+    target: 25_000.0,
+    current_savings: 2_000.0,
+    annual_rate: 0.04,
+    compounding: Compounding::Monthly,
+    deposits_per_year: 12.0,
+    years: 5.0,
+    annuity_due: false,
+    annual_increase: 0.0,
+};
+
+const FREQUENCY_OPTIONS: &[(f64, &str, &str)] = &[
+    (12.0, "monthly", "Monthly"),
+    (26.0, "biweekly", "Biweekly"),
+    (52.0, "weekly", "Weekly"),
+    (4.0, "quarterly", "Quarterly"),
+    (1.0, "annual", "Annual"),
+];
+
+const TIMING_OPTIONS: &[(bool, &str, &str)] = &[
+    (false, "end", "End of period"),
+    (true, "begin", "Start of period"),
+];
+
+#[component]
+pub fn SavingsGoalUI() -> Element {
+    let mut target_signal = use_signal(|| 25_000.0 as f64);
+    let mut target_input = use_signal(|| "25000".to_string());
+    let target_input_valid = use_signal(|| true);
+    let mut savings_signal = use_signal(|| 2_000.0 as f64);
+    let mut savings_input = use_signal(|| "2000".to_string());
+    let savings_input_valid = use_signal(|| true);
+    let mut rate_signal = use_signal(|| 4.0 as f64);
+    let mut rate_input = use_signal(|| "4.0".to_string());
+    let rate_input_valid = use_signal(|| true);
+    let mut years_signal = use_signal(|| 5.0 as f64);
+    let mut years_input = use_signal(|| "5".to_string());
+    let years_input_valid = use_signal(|| true);
+    let mut compounding_signal = use_signal(|| Compounding::Monthly);
+    let mut frequency_signal = use_signal(|| 12.0 as f64);
+    let mut due_signal = use_signal(|| false);
+    let mut increase_signal = use_signal(|| 0.0 as f64);
+    let mut increase_input = use_signal(|| "0".to_string());
+    let increase_input_valid = use_signal(|| true);
+
+    let inputs = move || SavingsGoalInputs {
+        target: target_signal(),
+        current_savings: savings_signal(),
+        annual_rate: rate_signal() / 100.0,
+        compounding: compounding_signal(),
+        deposits_per_year: frequency_signal(),
+        years: years_signal(),
+        annuity_due: due_signal(),
+        annual_increase: increase_signal() / 100.0,
+    };
+    let start_fresh = use_autosave(
+        "savings_goal",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: SavingsGoalInputs| {
+            target_signal.set(inputs.target);
+            target_input.set(format!("{}", inputs.target));
+            savings_signal.set(inputs.current_savings);
+            savings_input.set(format!("{}", inputs.current_savings));
+            rate_signal.set(inputs.annual_rate * 100.0);
+            rate_input.set(format!("{}", inputs.annual_rate * 100.0));
+            compounding_signal.set(inputs.compounding);
+            frequency_signal.set(inputs.deposits_per_year);
+            years_signal.set(inputs.years);
+            years_input.set(format!("{}", inputs.years));
+            due_signal.set(inputs.annuity_due);
+            increase_signal.set(inputs.annual_increase * 100.0);
+            increase_input.set(format!("{}", inputs.annual_increase * 100.0));
+        }),
+    );
+    use_track_calculator("savings_goal");
+    use_track_input_range("savings_goal", "target", target_signal, amount_bucket);
+    use_track_input_range("savings_goal", "years", years_signal, years_bucket);
+
+    let frequency_name = FREQUENCY_OPTIONS
+        .iter()
+        .find(|(periods, _, _)| *periods == frequency_signal())
+        .map_or("", |(_, _, display)| *display)
+        .to_ascii_lowercase();
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Savings Goal ($):".to_string(),
+            placeholder: "Enter the amount you want (e.g., 25000)".to_string(),
+            input_signal: target_input,
+            value_signal: target_signal,
+            valid_signal: target_input_valid,
+            field_name: "Savings goal".to_string(),
+            css_prefix: "target".to_string(),
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Saved So Far ($):".to_string(),
+            placeholder: "Enter what you have already (e.g., 2000)".to_string(),
+            input_signal: savings_input,
+            value_signal: savings_signal,
+            valid_signal: savings_input_valid,
+            field_name: "Saved so far".to_string(),
+            css_prefix: "savings".to_string(),
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Interest Rate (%):".to_string(),
+            placeholder: "Enter the annual rate (e.g., 4.0)".to_string(),
+            input_signal: rate_input,
+            value_signal: rate_signal,
+            valid_signal: rate_input_valid,
+            field_name: "Interest rate".to_string(),
+            css_prefix: "rate".to_string(),
+            allow_negative: true,
+            step: Some(0.125),
+        }
+        CompoundingSelect { compounding: compounding_signal }
+        NumericInput {
+            label: "Years to Goal:".to_string(),
+            placeholder: "Enter the years until you need it (e.g., 5)".to_string(),
+            input_signal: years_input,
+            value_signal: years_signal,
+            valid_signal: years_input_valid,
+            field_name: "Years to goal".to_string(),
+            css_prefix: "years".to_string(),
+            step: Some(1.0),
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Deposits:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(periods, _, _)) = FREQUENCY_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        frequency_signal.set(periods);
+                    }
+                },
+                for (periods , value , display) in FREQUENCY_OPTIONS.iter() {
+                    option { value: *value, selected: frequency_signal() == *periods, {*display} }
+                }
+            }
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Deposited At:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(due, _, _)) = TIMING_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        due_signal.set(due);
+                    }
+                },
+                for (due , value , display) in TIMING_OPTIONS.iter() {
+                    option { value: *value, selected: due_signal() == *due, {*display} }
+                }
+            }
+        }
+        NumericInput {
+            label: "Annual Increase (%):".to_string(),
+            placeholder: "Enter how much deposits rise each year (e.g., 3)".to_string(),
+            input_signal: increase_input,
+            value_signal: increase_signal,
+            valid_signal: increase_input_valid,
+            field_name: "Annual increase".to_string(),
+            css_prefix: "increase".to_string(),
+            step: Some(0.5),
+        }
+
+        {
+            match solve_savings_goal(&inputs()) {
+                Ok(outputs) if outputs.payment > 0.0 && increase_signal() > 0.0 => rsx! {
+                    div {
+                        id: "SavingsGoalCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        "Deposit needed: ${format_dollars(outputs.payment)} {frequency_name} to start*"
+                    }
+                    div { style: "margin-bottom: 15px; font-size: 16px;",
+                        "Rising {increase_signal()}% a year to ${format_dollars(outputs.final_payment)} in the final year: {outputs.n_deposits} deposits totaling ${format_dollars(outputs.total_deposits)}; interest adds ${format_dollars(outputs.interest)}."
+                    }
+                },
+                Ok(outputs) if outputs.payment > 0.0 => rsx! {
+                    div {
+                        id: "SavingsGoalCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        "Deposit needed: ${format_dollars(outputs.payment)} {frequency_name}*"
+                    }
+                    div { style: "margin-bottom: 15px; font-size: 16px;",
+                        "{outputs.n_deposits} deposits totaling ${format_dollars(outputs.total_deposits)}; interest adds ${format_dollars(outputs.interest)}."
+                    }
+                },
+                Ok(outputs) => rsx! {
+                    div {
+                        id: "SavingsGoalCalculation",
+                        style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                        "No deposits needed: your savings grow to ${format_dollars(outputs.ending_balance)} on their own*"
+                    }
+                },
+                Err(error) => rsx! {
+                    div {
+                        id: "SavingsGoalCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "savings_goal" }
+        AssumptionsFooter { calculator: "savings_goal" }
+        div { style: "font-size: 10px",
+            p {
+                "*Deposits are rounded to the cent, so the balance may miss or pass the goal by a few cents."
+            }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::storage::{load_item, save_item, use_storage_listener};
+use dioxus::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const SCENARIOS_KEY: &str = "fintools.scenarios";
+
+/// Schema version written with every scenario. Bump it whenever a calculator's input struct
+/// changes shape, and add a migration from the previous version to `MIGRATIONS`.
+pub const SCHEMA_VERSION: u64 = 1;
+
+/// Upgrades scenario JSON from version N to N + 1; `MIGRATIONS[N]` handles version N
+type Migration = fn(Value) -> Result<Value, String>;
+
+const MIGRATIONS: &[Migration] = &[migrate_v0_to_v1];
+
+/// A saved set of calculator inputs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Scenario {
+    pub schema_version: u64,
+    /// Calculator slug, e.g., "future_value"
+    pub calculator: String,
+    pub name: String,
+    /// The calculator's input struct, serialized
+    pub inputs: Value,
+}
+
+impl Scenario {
+    pub fn new<T: Serialize>(calculator: &str, name: &str, inputs: &T) -> Result<Self, String> {
+        Ok(Scenario {
+            schema_version: SCHEMA_VERSION,
+            calculator: calculator.to_string(),
+            name: name.to_string(),
+            inputs: serde_json::to_value(inputs).map_err(|e| e.to_string())?,
+        })
+    }
+
+    /// Deserializes the stored inputs into the calculator's input struct
+    pub fn inputs<T: DeserializeOwned>(&self) -> Result<T, String> {
+        serde_json::from_value(self.inputs.clone())
+            .map_err(|e| format!("Scenario \"{}\" has unreadable inputs: {}", self.name, e))
+    }
+}
+
+/// Scenarios saved before versioning had no `schema_version` field; they are otherwise version 1
+fn migrate_v0_to_v1(mut value: Value) -> Result<Value, String> {
+    let object = value
+        .as_object_mut()
+        .ok_or_else(|| "A saved scenario is not a JSON object".to_string())?;
+    object.insert("schema_version".to_string(), Value::from(1u64));
+    Ok(value)
+}
+
+/// Upgrades scenario JSON of any earlier version to the current schema and parses it
+pub fn migrate(mut value: Value) -> Result<Scenario, String> {
+    let mut version = value
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0);
+    if version > SCHEMA_VERSION {
+        return Err(format!(
+            "This scenario was saved by a newer version of the app (schema {})",
+            version
+        ));
+    }
+
+    while version < SCHEMA_VERSION {
+        let migration = MIGRATIONS
+            .get(version as usize)
+            .ok_or_else(|| format!("No migration from scenario schema {}", version))?;
+        value = migration(value)?;
+        version += 1;
+        if let Some(object) = value.as_object_mut() {
+            object.insert("schema_version".to_string(), Value::from(version));
+        }
+    }
+
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+/// Parses a stored scenario list, migrating each entry. Entries that can't be migrated are
+/// returned as errors rather than dropping the whole list.
+pub fn parse_scenarios(json: &str) -> (Vec<Scenario>, Vec<String>) {
+    let entries = match serde_json::from_str::<Vec<Value>>(json) {
+        Ok(entries) => entries,
+        Err(e) => {
+            return (
+                vec![],
+                vec![format!("Saved scenarios are unreadable: {}", e)],
+            )
+        }
+    };
+    let (mut scenarios, mut errors) = (vec![], vec![]);
+    for entry in entries {
+        match migrate(entry) {
+            Ok(scenario) => scenarios.push(scenario),
+            Err(error) => errors.push(error),
+        }
+    }
+    (scenarios, errors)
+}
+
+/// Loads and migrates the saved scenario list from localStorage
+pub async fn load_scenarios() -> (Vec<Scenario>, Vec<String>) {
+    match load_item(SCENARIOS_KEY).await {
+        Some(json) => parse_scenarios(&json),
+        None => (vec![], vec![]),
+    }
+}
+
+/// Saves the scenario list to localStorage
+pub fn save_scenarios(scenarios: &[Scenario]) {
+    if let Ok(json) = serde_json::to_string(scenarios) {
+        save_item(SCENARIOS_KEY, &json);
+    }
+}
+
+/// Re-reads the latest saved list, applies `change`, and saves it. Always modifying the stored
+/// list (rather than a copy loaded earlier) keeps a stale tab from overwriting scenarios saved
+/// in another tab. Returns the updated list.
+pub async fn update_scenarios(change: impl FnOnce(&mut Vec<Scenario>)) -> Vec<Scenario> {
+    let (mut scenarios, _) = load_scenarios().await;
+    change(&mut scenarios);
+    save_scenarios(&scenarios);
+    scenarios
+}
+
+/// The saved scenario list, loaded on mount and kept current when another tab changes it
+pub fn use_scenarios() -> Signal<Vec<Scenario>> {
+    let mut scenarios = use_signal(Vec::<Scenario>::new);
+    use_future(move || async move {
+        scenarios.set(load_scenarios().await.0);
+    });
+    use_storage_listener(
+        SCENARIOS_KEY,
+        use_callback(move |json: Option<String>| {
+            scenarios.set(
+                json.map(|json| parse_scenarios(&json).0)
+                    .unwrap_or_default(),
+            );
+        }),
+    );
+    scenarios
+}
+
+/// Adds `scenario` to the list, replacing a saved scenario of the same calculator and name
+pub fn put_scenario(scenarios: &mut Vec<Scenario>, scenario: Scenario) {
+    match scenarios
+        .iter_mut()
+        .find(|saved| saved.calculator == scenario.calculator && saved.name == scenario.name)
+    {
+        Some(saved) => *saved = scenario,
+        None => scenarios.push(scenario),
+    }
+}
@@ -0,0 +1,158 @@
+use crate::chart::{ChartSeries, LineChart};
+use crate::scenario::Scenario;
+use crate::scenario_report::{diff_scenarios, scenario_series, ScenarioDiffExport};
+use dioxus::prelude::*;
+
+const BEFORE_COLOR: &str = "#999999";
+const AFTER_COLOR: &str = "#2471a3";
+
+const CELL_STYLE: &str = "text-align: left; padding: 2px 8px;";
+const NUMBER_STYLE: &str = "text-align: right; padding: 2px 8px; font-family: monospace;";
+const SELECT_STYLE: &str = "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ";
+
+/// Picks the scenario named `name`, or the one at `fallback` when no scenario has that name
+fn pick<'a>(scenarios: &'a [Scenario], name: &str, fallback: usize) -> Option<&'a Scenario> {
+    scenarios
+        .iter()
+        .find(|scenario| scenario.name == name)
+        .or_else(|| scenarios.get(fallback))
+}
+
+/// Side-by-side comparison of two saved scenarios of one calculator: the inputs that differ,
+/// each result with its change, and the year-by-year results of both charted over each other.
+/// The comparison exports as CSV or a printable report.
+#[component]
+pub fn ScenarioComparison(scenarios: Vec<Scenario>) -> Element {
+    let mut before_name = use_signal(String::new);
+    let mut after_name = use_signal(String::new);
+    let hover_index = use_signal(|| None::<usize>);
+
+    let (Some(before), Some(after)) = (
+        pick(&scenarios, &before_name(), 0),
+        pick(&scenarios, &after_name(), 1),
+    ) else {
+        return rsx! {};
+    };
+    let diff = diff_scenarios(before, after);
+    let before_series = scenario_series(before).unwrap_or_default();
+    let after_series = scenario_series(after).unwrap_or_default();
+    let charts: Vec<(String, Vec<ChartSeries>)> = before_series
+        .into_iter()
+        .zip(after_series)
+        .map(|((label, before_values), (_, after_values))| {
+            (
+                label,
+                vec![
+                    ChartSeries {
+                        label: before.name.clone(),
+                        color: BEFORE_COLOR.to_string(),
+                        values: before_values,
+                    },
+                    ChartSeries {
+                        label: after.name.clone(),
+                        color: AFTER_COLOR.to_string(),
+                        values: after_values,
+                    },
+                ],
+            )
+        })
+        .collect();
+    let (selected_before, selected_after) = (before.name.clone(), after.name.clone());
+
+    rsx! {
+        div { style: "color: #333; margin-bottom: 15px;",
+            div { style: "font-weight: bold; margin-bottom: 5px;", "Compare Scenarios" }
+            div { style: "display: flex; align-items: center; gap: 10px; margin-bottom: 10px;",
+                select {
+                    "aria-label": "Scenario to compare from",
+                    style: SELECT_STYLE,
+                    onchange: move |event| before_name.set(event.value()),
+                    for scenario in scenarios.iter() {
+                        option {
+                            value: "{scenario.name}",
+                            selected: scenario.name == selected_before,
+                            "{scenario.name}"
+                        }
+                    }
+                }
+                span { "vs" }
+                select {
+                    "aria-label": "Scenario to compare to",
+                    style: SELECT_STYLE,
+                    onchange: move |event| after_name.set(event.value()),
+                    for scenario in scenarios.iter() {
+                        option {
+                            value: "{scenario.name}",
+                            selected: scenario.name == selected_after,
+                            "{scenario.name}"
+                        }
+                    }
+                }
+            }
+            match diff {
+                Ok(diff) => rsx! {
+                    if diff.changed_inputs.is_empty() {
+                        div { style: "font-size: 12px; margin-bottom: 10px;",
+                            "These scenarios have the same inputs."
+                        }
+                    } else {
+                        table { style: "border-collapse: collapse; font-size: 13px; margin-bottom: 10px;",
+                            thead {
+                                tr {
+                                    th { scope: "col", style: CELL_STYLE, "Input" }
+                                    th { scope: "col", style: CELL_STYLE, "{diff.before_name}" }
+                                    th { scope: "col", style: CELL_STYLE, "{diff.after_name}" }
+                                }
+                            }
+                            tbody {
+                                for change in diff.changed_inputs.iter() {
+                                    tr { key: "{change.field}",
+                                        th { scope: "row", style: CELL_STYLE, "{change.field}" }
+                                        td { style: CELL_STYLE, "{change.before}" }
+                                        td { style: CELL_STYLE, "{change.after}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if !diff.result_deltas.is_empty() {
+                        table { style: "border-collapse: collapse; font-size: 13px; margin-bottom: 10px;",
+                            thead {
+                                tr {
+                                    th { scope: "col", style: CELL_STYLE, "Result" }
+                                    th { scope: "col", style: NUMBER_STYLE, "{diff.before_name}" }
+                                    th { scope: "col", style: NUMBER_STYLE, "{diff.after_name}" }
+                                    th { scope: "col", style: NUMBER_STYLE, "Change" }
+                                }
+                            }
+                            tbody {
+                                for result in diff.result_deltas.iter() {
+                                    tr { key: "{result.label}",
+                                        th { scope: "row", style: CELL_STYLE, "{result.label}" }
+                                        td { style: NUMBER_STYLE, "{result.before:.2}" }
+                                        td { style: NUMBER_STYLE, "{result.after:.2}" }
+                                        td { style: NUMBER_STYLE, "{result.delta():+.2}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    for (title , series) in charts {
+                        LineChart {
+                            key: "{title}",
+                            title,
+                            series,
+                            x_start: 0.0,
+                            hover_index,
+                            y_max: None,
+                        }
+                    }
+                    ScenarioDiffExport { diff }
+                },
+                Err(error) => rsx! {
+                    div { style: "color: #ff0000; font-size: 12px;", "{error}" }
+                },
+            }
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use crate::future_value::{compute_future_value, FutureValueInputs};
+use crate::scenario::Scenario;
+#[cfg(feature = "simulation")]
+use crate::sequence_risk::{compute_sequence_risk, SequenceRiskInputs};
+use dioxus::prelude::*;
+use serde_json::Value;
+
+/// An input whose value differs between two scenarios
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputChange {
+    /// Dotted path to the input, e.g., "compounding" or "inputs.principal"
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// A result computed for both scenarios
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultDelta {
+    pub label: String,
+    pub before: f64,
+    pub after: f64,
+}
+
+impl ResultDelta {
+    pub fn delta(&self) -> f64 {
+        self.after - self.before
+    }
+}
+
+/// What changed between two scenarios of the same calculator, and what that did to the results
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScenarioDiff {
+    pub calculator: String,
+    pub before_name: String,
+    pub after_name: String,
+    pub changed_inputs: Vec<InputChange>,
+    pub result_deltas: Vec<ResultDelta>,
+}
+
+/// Flattens nested JSON objects into (dotted path, value) pairs, in key order
+fn flatten(prefix: &str, value: &Value, out: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(object) => {
+            for (key, child) in object {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten(&path, child, out);
+            }
+        }
+        Value::String(text) => out.push((prefix.to_string(), text.clone())),
+        other => out.push((prefix.to_string(), other.to_string())),
+    }
+}
+
+/// Every input of a serialized input struct as (dotted path, value) pairs, in key order
+pub fn input_fields(inputs: &Value) -> Vec<(String, String)> {
+    let mut fields = vec![];
+    flatten("", inputs, &mut fields);
+    fields
+}
+
+/// Lists the inputs that differ between two serialized input structs. An input missing from
+/// one side is shown as empty.
+pub fn diff_inputs(before: &Value, after: &Value) -> Vec<InputChange> {
+    let (mut before_fields, mut after_fields) = (vec![], vec![]);
+    flatten("", before, &mut before_fields);
+    flatten("", after, &mut after_fields);
+
+    let mut fields: Vec<&String> = before_fields
+        .iter()
+        .chain(after_fields.iter())
+        .map(|(field, _)| field)
+        .collect();
+    fields.sort();
+    fields.dedup();
+
+    let lookup = |fields: &[(String, String)], field: &str| {
+        fields
+            .iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, value)| value.clone())
+            .unwrap_or_default()
+    };
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let before = lookup(&before_fields, field);
+            let after = lookup(&after_fields, field);
+            (before != after).then(|| InputChange {
+                field: field.clone(),
+                before,
+                after,
+            })
+        })
+        .collect()
+}
+
+/// The headline results of a scenario, by calculator. Unknown calculators have none.
+pub fn scenario_results(scenario: &Scenario) -> Result<Vec<(String, f64)>, String> {
+    match scenario.calculator.as_str() {
+        "future_value" => {
+            let outputs = compute_future_value(&scenario.inputs::<FutureValueInputs>()?);
+            Ok(vec![
+                (
+                    "Future value ($)".to_string(),
+                    outputs.future_value.unwrap_or(f64::NAN),
+                ),
+                (
+                    "Required rate (%)".to_string(),
+                    outputs.required_rate * 100.0,
+                ),
+                ("Required years".to_string(), outputs.required_years),
+                (
+                    "Future value in today's dollars ($)".to_string(),
+                    outputs.real_future_value.unwrap_or(f64::NAN),
+                ),
+            ])
+        }
+        #[cfg(feature = "simulation")]
+        "sequence_risk" => {
+            let outputs = compute_sequence_risk(&scenario.inputs::<SequenceRiskInputs>()?);
+            let ending = |path: &[f64]| path.last().copied().unwrap_or(0.0);
+            Ok(vec![
+                (
+                    "Ending balance, worst years first ($)".to_string(),
+                    ending(&outputs.bad_first_path),
+                ),
+                (
+                    "Ending balance, best years first ($)".to_string(),
+                    ending(&outputs.good_first_path),
+                ),
+            ])
+        }
+        _ => Ok(vec![]),
+    }
+}
+
+/// The results of a scenario tracked year by year from year 0, by calculator, for charting two
+/// scenarios over each other. Unknown calculators have none.
+pub fn scenario_series(scenario: &Scenario) -> Result<Vec<(String, Vec<f64>)>, String> {
+    match scenario.calculator.as_str() {
+        "future_value" => {
+            let inputs = scenario.inputs::<FutureValueInputs>()?;
+            // Whole years, then the final part year if there is one
+            let balances = (0..=inputs.years.max(0.0).ceil() as usize)
+                .map(|year| {
+                    compute_future_value(&FutureValueInputs {
+                        years: (year as f64).min(inputs.years),
+                        ..inputs
+                    })
+                    .future_value
+                    .unwrap_or(f64::NAN)
+                })
+                .collect();
+            Ok(vec![("Balance ($)".to_string(), balances)])
+        }
+        #[cfg(feature = "simulation")]
+        "sequence_risk" => {
+            let outputs = compute_sequence_risk(&scenario.inputs::<SequenceRiskInputs>()?);
+            Ok(vec![
+                (
+                    "Balance, worst years first ($)".to_string(),
+                    outputs.bad_first_path,
+                ),
+                (
+                    "Balance, best years first ($)".to_string(),
+                    outputs.good_first_path,
+                ),
+            ])
+        }
+        _ => Ok(vec![]),
+    }
+}
+
+/// Compares two scenarios of the same calculator
+pub fn diff_scenarios(before: &Scenario, after: &Scenario) -> Result<ScenarioDiff, String> {
+    if before.calculator != after.calculator {
+        return Err(format!(
+            "Can't compare a {} scenario with a {} scenario",
+            before.calculator, after.calculator
+        ));
+    }
+    let result_deltas = scenario_results(before)?
+        .into_iter()
+        .zip(scenario_results(after)?)
+        .map(|((label, before), (_, after))| ResultDelta {
+            label,
+            before,
+            after,
+        })
+        .collect();
+
+    Ok(ScenarioDiff {
+        calculator: before.calculator.clone(),
+        before_name: before.name.clone(),
+        after_name: after.name.clone(),
+        changed_inputs: diff_inputs(&before.inputs, &after.inputs),
+        result_deltas,
+    })
+}
+
+/// Quotes a CSV field when it contains a comma, quote, or line break
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Escapes text for use inside HTML
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl ScenarioDiff {
+    /// Diff report as CSV: one row per changed input, then one row per result with its delta
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!(
+            "section,item,{},{},change\n",
+            csv_field(&self.before_name),
+            csv_field(&self.after_name)
+        );
+        for change in &self.changed_inputs {
+            csv.push_str(&format!(
+                "input,{},{},{},\n",
+                csv_field(&change.field),
+                csv_field(&change.before),
+                csv_field(&change.after)
+            ));
+        }
+        for result in &self.result_deltas {
+            csv.push_str(&format!(
+                "result,{},{:.2},{:.2},{:+.2}\n",
+                csv_field(&result.label),
+                result.before,
+                result.after,
+                result.delta()
+            ));
+        }
+        csv
+    }
+
+    /// Diff report as a standalone HTML page, laid out for printing or saving as PDF
+    pub fn to_html(&self) -> String {
+        let before = html_escape(&self.before_name);
+        let after = html_escape(&self.after_name);
+        let mut html = format!(
+            "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{before} vs {after}</title>\
+             <style>body{{font-family:sans-serif}}table{{border-collapse:collapse;margin-bottom:20px}}\
+             td,th{{border:1px solid #999;padding:4px 8px;text-align:left}}</style></head><body>\
+             <h1>{before} vs {after}</h1>"
+        );
+        html.push_str(&format!(
+            "<h2>Inputs that changed</h2><table><tr><th>Input</th><th>{before}</th><th>{after}</th></tr>"
+        ));
+        for change in &self.changed_inputs {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                html_escape(&change.field),
+                html_escape(&change.before),
+                html_escape(&change.after)
+            ));
+        }
+        html.push_str(&format!(
+            "</table><h2>Results</h2><table><tr><th>Result</th><th>{before}</th><th>{after}</th><th>Change</th></tr>"
+        ));
+        for result in &self.result_deltas {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{:.2}</td><td>{:.2}</td><td>{:+.2}</td></tr>",
+                html_escape(&result.label),
+                result.before,
+                result.after,
+                result.delta()
+            ));
+        }
+        html.push_str("</table></body></html>");
+        html
+    }
+}
+
+/// Offers `contents` to the user as a file download
+pub fn download_file(file_name: &str, mime_type: &str, contents: &str) {
+    let js = format!(
+        "const blob = new Blob([{}], {{ type: {} }});
+         const link = document.createElement('a');
+         link.href = URL.createObjectURL(blob);
+         link.download = {};
+         link.click();
+         URL.revokeObjectURL(link.href);",
+        serde_json::to_string(contents).unwrap_or_default(),
+        serde_json::to_string(mime_type).unwrap_or_default(),
+        serde_json::to_string(file_name).unwrap_or_default()
+    );
+    let _ = document::eval(&js);
+}
+
+/// Opens `html` in a new window and shows the print dialog, where it can be saved as PDF
+pub fn print_html(html: &str) {
+    let js = format!(
+        "const report = window.open('', '_blank');
+         report.document.write({});
+         report.document.close();
+         report.print();",
+        serde_json::to_string(html).unwrap_or_default()
+    );
+    let _ = document::eval(&js);
+}
+
+/// Buttons that export a scenario comparison as CSV or as a printable (PDF) report
+#[component]
+pub fn ScenarioDiffExport(diff: ScenarioDiff) -> Element {
+    let file_stem = format!("{}-vs-{}", diff.before_name, diff.after_name)
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect::<String>();
+    let csv = diff.to_csv();
+    let html = diff.to_html();
+
+    rsx! {
+        div { style: "display: flex; gap: 10px; margin-bottom: 15px;",
+            button {
+                style: "padding: 6px 12px; border-radius: 4px;",
+                onclick: move |_| download_file(&format!("{}.csv", file_stem), "text/csv", &csv),
+                "Export CSV"
+            }
+            button {
+                style: "padding: 6px 12px; border-radius: 4px;",
+                onclick: move |_| print_html(&html),
+                "Export PDF"
+            }
+        }
+    }
+}
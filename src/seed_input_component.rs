@@ -0,0 +1,40 @@
+use crate::numeric_input_component::input_width_style;
+use crate::simulation::{seed_from_str, RandomSource, Rng};
+use dioxus::prelude::*;
+
+/// Seed field for simulation tools: the same seed always reproduces the same simulated paths.
+/// Accepts a number or any word; "New Seed" picks a fresh number.
+#[component]
+pub fn SeedInput(seed_signal: Signal<u64>) -> Element {
+    let mut seed_input = use_signal(|| seed_signal().to_string());
+
+    rsx! {
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Random Seed:"
+            }
+            input {
+                placeholder: "Enter a number or word to reproduce results",
+                value: "{seed_input}",
+                style: format!(
+                    "padding: 6px 8px; {} border-radius: 4px; font-family: monospace;",
+                    input_width_style(&seed_input()),
+                ),
+                oninput: move |event| {
+                    let input_text = event.value();
+                    seed_input.set(input_text.clone());
+                    seed_signal.set(seed_from_str(&input_text));
+                },
+            }
+            button {
+                style: "margin-left: 10px; padding: 6px 8px; border-radius: 4px;",
+                onclick: move |_| {
+                    let next_seed = Rng::new(seed_signal()).next_u64() % 1_000_000;
+                    seed_input.set(next_seed.to_string());
+                    seed_signal.set(next_seed);
+                },
+                "New Seed"
+            }
+        }
+    }
+}
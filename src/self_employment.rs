@@ -0,0 +1,241 @@
+use crate::contribution_limits::employer_match;
+use crate::paycheck::{
+    additional_medicare_threshold, compute_paycheck, social_security_wage_base, Earner,
+    PaycheckInputs, ADDITIONAL_MEDICARE_RATE, MEDICARE_RATE, SOCIAL_SECURITY_RATE,
+};
+use crate::tax::{tax_table, FilingStatus};
+use serde::{Deserialize, Serialize};
+
+/// Share of net self-employment profit that self-employment tax applies to, which leaves out
+/// the employer half of the tax as an employee's wages would
+pub const SE_EARNINGS_SHARE: f64 = 0.9235;
+
+/// Inputs to the W-2 vs 1099 comparison
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ContractorComparisonInputs {
+    pub status: FilingStatus,
+    /// W-2 salary offered
+    pub salary: f64,
+    /// Yearly value of employer-paid benefits, e.g., health insurance premiums, which a
+    /// contractor buys for themselves
+    pub benefits_value: f64,
+    /// Portion of each contributed dollar the employer matches, e.g., 0.5 for 50%
+    pub match_rate: f64,
+    /// Share of salary eligible for matching, e.g., 0.04 for "up to 4% of pay"
+    pub match_cap: f64,
+    /// Pretax retirement savings, the same either way: a 401(k) as an employee, a solo 401(k)
+    /// or SEP-IRA as a contractor
+    pub retirement_contribution: f64,
+    /// 1099 contract rate per hour
+    pub hourly_rate: f64,
+    /// Hours billed in a year, after time off and unbilled work
+    pub billable_hours: f64,
+    /// Yearly business expenses a contractor deducts, e.g., equipment and software
+    pub business_expenses: f64,
+}
+
+/// A year as an employee
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct W2Compensation {
+    pub salary: f64,
+    pub income_tax: f64,
+    /// The employee's half of Social Security and Medicare
+    pub fica: f64,
+    pub employer_match: f64,
+    pub benefits_value: f64,
+    /// Salary after taxes, plus the match and benefits
+    pub net_value: f64,
+}
+
+/// A year as a contractor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContractorCompensation {
+    /// Hourly rate times billable hours
+    pub revenue: f64,
+    /// Revenue less business expenses
+    pub net_profit: f64,
+    /// Both halves of Social Security and Medicare
+    pub self_employment_tax: f64,
+    pub income_tax: f64,
+    /// Net profit after taxes
+    pub net_value: f64,
+}
+
+/// The two offers side by side, and what would make each match the other
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContractorComparison {
+    pub w2: W2Compensation,
+    pub contractor: ContractorCompensation,
+    /// Contract rate whose net value matches the W-2 offer's, if any
+    pub break_even_rate: Option<f64>,
+    /// W-2 salary whose net value matches the contract's, if any
+    pub equivalent_salary: Option<f64>,
+}
+
+/// Self-employment tax on a year's net profit, and the half of it deductible from income
+fn self_employment_tax(net_profit: f64, status: FilingStatus, year: i32) -> (f64, f64) {
+    let earnings = net_profit.max(0.0) * SE_EARNINGS_SHARE;
+    let social_security =
+        earnings.min(social_security_wage_base(year)) * SOCIAL_SECURITY_RATE * 2.0;
+    let medicare = earnings * MEDICARE_RATE * 2.0;
+    let additional_medicare =
+        (earnings - additional_medicare_threshold(status)).max(0.0) * ADDITIONAL_MEDICARE_RATE;
+    (
+        social_security + medicare + additional_medicare,
+        (social_security + medicare) / 2.0,
+    )
+}
+
+fn w2_compensation(
+    inputs: &ContractorComparisonInputs,
+    salary: f64,
+    year: i32,
+) -> Result<W2Compensation, String> {
+    let paycheck = compute_paycheck(
+        &PaycheckInputs {
+            status: inputs.status,
+            first: Earner {
+                salary,
+                pretax_deductions: inputs.retirement_contribution,
+            },
+            second: Earner::default(),
+            periods_per_year: 12.0,
+        },
+        year,
+    )?;
+    let employer_match = employer_match(
+        salary,
+        inputs.retirement_contribution,
+        inputs.match_rate,
+        inputs.match_cap,
+    );
+    Ok(W2Compensation {
+        salary,
+        income_tax: paycheck.income_tax,
+        fica: paycheck.fica,
+        employer_match,
+        benefits_value: inputs.benefits_value,
+        net_value: salary - paycheck.income_tax - paycheck.fica
+            + employer_match
+            + inputs.benefits_value,
+    })
+}
+
+fn contractor_compensation(
+    inputs: &ContractorComparisonInputs,
+    hourly_rate: f64,
+    year: i32,
+) -> ContractorCompensation {
+    let table = tax_table(year, inputs.status);
+    let revenue = hourly_rate * inputs.billable_hours;
+    let net_profit = revenue - inputs.business_expenses;
+    let (self_employment_tax, deductible_half) =
+        self_employment_tax(net_profit, inputs.status, year);
+    let taxable =
+        net_profit - deductible_half - inputs.retirement_contribution - table.standard_deduction;
+    let income_tax = table.ordinary_tax(taxable.max(0.0));
+    ContractorCompensation {
+        revenue,
+        net_profit,
+        self_employment_tax,
+        income_tax,
+        net_value: net_profit - self_employment_tax - income_tax,
+    }
+}
+
+/// Smallest input at or above `low` where a rising `value` reaches `target`, by bisection. None
+/// if `value` is already past the target at `low` or never reaches it.
+fn solve_rising(value: impl Fn(f64) -> Option<f64>, target: f64, low: f64) -> Option<f64> {
+    if value(low)? > target {
+        return None;
+    }
+    let mut low = low;
+    let mut high = (low * 2.0).max(1.0);
+    // Doubling 60 times reaches far past any pay, so a target still out of reach never will be
+    for _ in 0..60 {
+        if value(high)? >= target {
+            break;
+        }
+        low = high;
+        high *= 2.0;
+    }
+    if value(high)? < target {
+        return None;
+    }
+    for _ in 0..200 {
+        let middle = (low + high) / 2.0;
+        if value(middle)? < target {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    Some(high)
+}
+
+// Compares a W-2 salary with a 1099 contract rate for the same work.
+///
+/// # Parameters:
+/// - `inputs`: The salary, benefits, and match offered; the contract rate, billable hours, and
+///   expenses; and the retirement savings and filing status that apply to both
+/// - `year`: Tax year whose brackets and Social Security wage base apply
+///
+/// # Formula:
+/// W-2 value = salary - income tax - FICA + match + benefits
+/// SE tax = min(0.9235 * profit, wage base) * 12.4% + 0.9235 * profit * 2.9% (+ 0.9% above the
+///          threshold)
+/// 1099 value = profit - SE tax - brackets(profit - SE tax / 2 - retirement - standard deduction)
+/// The break-even rate and equivalent salary solve value(x) = the other offer's value by
+/// bisection, as each side's value rises with its pay.
+///
+/// # Returns:
+/// Both offers' taxes and net value, and the pay that would make each match the other. An error
+/// if an entry is negative, the retirement savings exceed the salary, or there are no billable
+/// hours.
+pub fn compare_w2_and_contractor(
+    inputs: &ContractorComparisonInputs,
+    year: i32,
+) -> Result<ContractorComparison, String> {
+    let entries = [
+        inputs.salary,
+        inputs.benefits_value,
+        inputs.match_rate,
+        inputs.match_cap,
+        inputs.retirement_contribution,
+        inputs.hourly_rate,
+        inputs.business_expenses,
+    ];
+    if entries.iter().any(|entry| entry.is_nan() || *entry < 0.0) {
+        return Err("Pay, benefits, savings, and expenses can't be negative".to_string());
+    }
+    if inputs.retirement_contribution > inputs.salary {
+        return Err("Retirement savings can't exceed the salary".to_string());
+    }
+    if inputs.billable_hours.is_nan() || inputs.billable_hours <= 0.0 {
+        return Err("Enter the hours billed in a year".to_string());
+    }
+
+    let w2 = w2_compensation(inputs, inputs.salary, year)?;
+    let contractor = contractor_compensation(inputs, inputs.hourly_rate, year);
+    let break_even_rate = solve_rising(
+        |rate| Some(contractor_compensation(inputs, rate, year).net_value),
+        w2.net_value,
+        0.0,
+    );
+    let equivalent_salary = solve_rising(
+        |salary| {
+            w2_compensation(inputs, salary, year)
+                .ok()
+                .map(|w2| w2.net_value)
+        },
+        contractor.net_value,
+        inputs.retirement_contribution,
+    );
+
+    Ok(ContractorComparison {
+        w2,
+        contractor,
+        break_even_rate,
+        equivalent_salary,
+    })
+}
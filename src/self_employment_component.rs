@@ -0,0 +1,327 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::data_year::{use_data_year, DataYearBanner};
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::format_dollars;
+use crate::number_format::dollars;
+use crate::numeric_input_component::NumericInput;
+use crate::self_employment::{compare_w2_and_contractor, ContractorComparisonInputs};
+use crate::tax::FilingStatus;
+use dioxus::prelude::*;
+
+/// A $100,000 salary with $12,000 of benefits and a dollar-for-dollar match up to 4% of pay,
+/// against a $75/hour contract billing 1,800 hours a year
+const DEFAULT_INPUTS: ContractorComparisonInputs = ContractorComparisonInputs {
+    status: FilingStatus::Single,
+    salary: 100_000.0,
+    benefits_value: 12_000.0,
+    match_rate: 1.0,
+    match_cap: 0.04,
+    retirement_contribution: 10_000.0,
+    hourly_rate: 75.0,
+    billable_hours: 1_800.0,
+    business_expenses: 5_000.0,
+};
+
+const STATUS_OPTIONS: &[(FilingStatus, &str)] = &[
+    (FilingStatus::Single, "single"),
+    (FilingStatus::MarriedFilingJointly, "joint"),
+];
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+const LABEL_STYLE: &str = "text-align: left; padding: 2px 8px;";
+const BEST_CELL_STYLE: &str =
+    "text-align: right; padding: 2px 8px; background: #d5f5e3; font-weight: bold;";
+const HEADING_STYLE: &str = "margin: 10px 0; font-weight: bold; color: #333;";
+
+#[component]
+pub fn SelfEmploymentUI() -> Element {
+    let tax_year = use_data_year("tax_brackets");
+    let mut status_signal = use_signal(|| FilingStatus::Single);
+    let mut salary_signal = use_signal(|| 100_000.0 as f64);
+    let mut salary_input = use_signal(|| "100000".to_string());
+    let salary_input_valid = use_signal(|| true);
+    let mut benefits_signal = use_signal(|| 12_000.0 as f64);
+    let mut benefits_input = use_signal(|| "12000".to_string());
+    let benefits_input_valid = use_signal(|| true);
+    let mut match_rate_signal = use_signal(|| 100.0 as f64);
+    let mut match_rate_input = use_signal(|| "100".to_string());
+    let match_rate_input_valid = use_signal(|| true);
+    let mut match_cap_signal = use_signal(|| 4.0 as f64);
+    let mut match_cap_input = use_signal(|| "4".to_string());
+    let match_cap_input_valid = use_signal(|| true);
+    let mut retirement_signal = use_signal(|| 10_000.0 as f64);
+    let mut retirement_input = use_signal(|| "10000".to_string());
+    let retirement_input_valid = use_signal(|| true);
+    let mut rate_signal = use_signal(|| 75.0 as f64);
+    let mut rate_input = use_signal(|| "75".to_string());
+    let rate_input_valid = use_signal(|| true);
+    let mut hours_signal = use_signal(|| 1_800.0 as f64);
+    let mut hours_input = use_signal(|| "1800".to_string());
+    let hours_input_valid = use_signal(|| true);
+    let mut expenses_signal = use_signal(|| 5_000.0 as f64);
+    let mut expenses_input = use_signal(|| "5000".to_string());
+    let expenses_input_valid = use_signal(|| true);
+
+    let inputs = move || ContractorComparisonInputs {
+        status: status_signal(),
+        salary: salary_signal(),
+        benefits_value: benefits_signal(),
+        match_rate: match_rate_signal() / 100.0,
+        match_cap: match_cap_signal() / 100.0,
+        retirement_contribution: retirement_signal(),
+        hourly_rate: rate_signal(),
+        billable_hours: hours_signal(),
+        business_expenses: expenses_signal(),
+    };
+    let start_fresh = use_autosave(
+        "self_employment",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: ContractorComparisonInputs| {
+            status_signal.set(inputs.status);
+            salary_signal.set(inputs.salary);
+            salary_input.set(format!("{}", inputs.salary));
+            benefits_signal.set(inputs.benefits_value);
+            benefits_input.set(format!("{}", inputs.benefits_value));
+            match_rate_signal.set(inputs.match_rate * 100.0);
+            match_rate_input.set(format!("{}", inputs.match_rate * 100.0));
+            match_cap_signal.set(inputs.match_cap * 100.0);
+            match_cap_input.set(format!("{}", inputs.match_cap * 100.0));
+            retirement_signal.set(inputs.retirement_contribution);
+            retirement_input.set(format!("{}", inputs.retirement_contribution));
+            rate_signal.set(inputs.hourly_rate);
+            rate_input.set(format!("{}", inputs.hourly_rate));
+            hours_signal.set(inputs.billable_hours);
+            hours_input.set(format!("{}", inputs.billable_hours));
+            expenses_signal.set(inputs.business_expenses);
+            expenses_input.set(format!("{}", inputs.business_expenses));
+        }),
+    );
+    use_track_calculator("self_employment");
+    use_track_input_range("self_employment", "salary", salary_signal, amount_bucket);
+
+    rsx! {
+        hr {}
+        br {}
+
+        DataYearBanner { data_set: "tax_brackets", year: tax_year }
+
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Filing Status:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(status, _)) = STATUS_OPTIONS
+                        .iter()
+                        .find(|(_, value_str)| *value_str == value.as_str())
+                    {
+                        status_signal.set(status);
+                    }
+                },
+                for (status , value) in STATUS_OPTIONS.iter() {
+                    option { value: *value, selected: status_signal() == *status, {status.label()} }
+                }
+            }
+        }
+        NumericInput {
+            label: "Retirement Savings ($/yr):".to_string(),
+            placeholder: "Enter what you'd save pretax either way (e.g., 10000)".to_string(),
+            input_signal: retirement_input,
+            value_signal: retirement_signal,
+            valid_signal: retirement_input_valid,
+            field_name: "Retirement savings".to_string(),
+            css_prefix: "retirement".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+
+        div { style: HEADING_STYLE, "W-2 offer" }
+        NumericInput {
+            label: "Salary ($/yr):".to_string(),
+            placeholder: "Enter the salary offered (e.g., 100000)".to_string(),
+            input_signal: salary_input,
+            value_signal: salary_signal,
+            valid_signal: salary_input_valid,
+            field_name: "Salary".to_string(),
+            css_prefix: "salary".to_string(),
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Benefits ($/yr):".to_string(),
+            placeholder: "Enter what the employer pays for health insurance and other benefits (e.g., 12000)".to_string(),
+            input_signal: benefits_input,
+            value_signal: benefits_signal,
+            valid_signal: benefits_input_valid,
+            field_name: "Benefits".to_string(),
+            css_prefix: "benefits".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Employer Match (%):".to_string(),
+            placeholder: "Enter the share of each dollar saved that's matched (e.g., 100)".to_string(),
+            input_signal: match_rate_input,
+            value_signal: match_rate_signal,
+            valid_signal: match_rate_input_valid,
+            field_name: "Employer match".to_string(),
+            css_prefix: "match-rate".to_string(),
+            step: Some(25.0),
+        }
+        NumericInput {
+            label: "Match Up To (% of pay):".to_string(),
+            placeholder: "Enter the share of salary eligible for the match (e.g., 4)".to_string(),
+            input_signal: match_cap_input,
+            value_signal: match_cap_signal,
+            valid_signal: match_cap_input_valid,
+            field_name: "Match limit".to_string(),
+            css_prefix: "match-cap".to_string(),
+            step: Some(1.0),
+        }
+
+        div { style: HEADING_STYLE, "1099 contract" }
+        NumericInput {
+            label: "Contract Rate ($/hr):".to_string(),
+            placeholder: "Enter the hourly rate offered (e.g., 75)".to_string(),
+            input_signal: rate_input,
+            value_signal: rate_signal,
+            valid_signal: rate_input_valid,
+            field_name: "Contract rate".to_string(),
+            css_prefix: "rate".to_string(),
+            step: Some(5.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Billable Hours (/yr):".to_string(),
+            placeholder: "Enter the hours billed in a year, after time off (e.g., 1800)".to_string(),
+            input_signal: hours_input,
+            value_signal: hours_signal,
+            valid_signal: hours_input_valid,
+            field_name: "Billable hours".to_string(),
+            css_prefix: "hours".to_string(),
+            step: Some(40.0),
+        }
+        NumericInput {
+            label: "Business Expenses ($/yr):".to_string(),
+            placeholder: "Enter deductible costs of doing the work (e.g., 5000)".to_string(),
+            input_signal: expenses_input,
+            value_signal: expenses_signal,
+            valid_signal: expenses_input_valid,
+            field_name: "Business expenses".to_string(),
+            css_prefix: "expenses".to_string(),
+            step: Some(500.0),
+            max_decimals: Some(2),
+        }
+
+        {
+            match compare_w2_and_contractor(&inputs(), tax_year()) {
+                Ok(comparison) => {
+                    let (w2, contractor) = (comparison.w2, comparison.contractor);
+                    // Rounded so a difference of whole cents isn't shown a cent short
+                    let difference =
+                        ((w2.net_value - contractor.net_value) * 100.0).round() / 100.0;
+                    let summary = if difference > 0.0 {
+                        format!(
+                            "The W-2 offer is worth ${} more a year*",
+                            format_dollars(difference)
+                        )
+                    } else if difference < 0.0 {
+                        format!(
+                            "The contract is worth ${} more a year*",
+                            format_dollars(-difference)
+                        )
+                    } else {
+                        "The two offers are worth the same*".to_string()
+                    };
+                    let rows = [
+                        ("Pay", dollars(w2.salary), dollars(contractor.revenue)),
+                        (
+                            "Business expenses",
+                            "-".to_string(),
+                            dollars(inputs().business_expenses),
+                        ),
+                        (
+                            "Federal income tax",
+                            dollars(w2.income_tax),
+                            dollars(contractor.income_tax),
+                        ),
+                        (
+                            "Social Security & Medicare",
+                            dollars(w2.fica),
+                            dollars(contractor.self_employment_tax),
+                        ),
+                        ("Employer match", dollars(w2.employer_match), "-".to_string()),
+                        ("Benefits", dollars(w2.benefits_value), "-".to_string()),
+                    ];
+                    rsx! {
+                        div {
+                            id: "SelfEmploymentCalculation",
+                            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                            "{summary}"
+                        }
+                        table { style: "color: #333; border-collapse: collapse; font-size: 14px; margin-bottom: 15px;",
+                            thead {
+                                tr {
+                                    th {}
+                                    th { scope: "col", style: CELL_STYLE, "W-2" }
+                                    th { scope: "col", style: CELL_STYLE, "1099" }
+                                }
+                            }
+                            tbody {
+                                for (label , w2_cell , contractor_cell) in rows.into_iter() {
+                                    tr { key: "{label}",
+                                        th { scope: "row", style: LABEL_STYLE, "{label}" }
+                                        td { style: CELL_STYLE, "{w2_cell}" }
+                                        td { style: CELL_STYLE, "{contractor_cell}" }
+                                    }
+                                }
+                                tr {
+                                    th { scope: "row", style: LABEL_STYLE, "Net value" }
+                                    td { style: if difference > 0.0 { BEST_CELL_STYLE } else { CELL_STYLE },
+                                        {dollars(w2.net_value)}
+                                    }
+                                    td { style: if difference < 0.0 { BEST_CELL_STYLE } else { CELL_STYLE },
+                                        {dollars(contractor.net_value)}
+                                    }
+                                }
+                            }
+                        }
+                        if let Some(rate) = comparison.break_even_rate {
+                            div { id: "BreakEvenRate", style: "margin-bottom: 15px; font-size: 16px;",
+                                "A contract rate of ${format_dollars(rate)}/hour matches the W-2 offer."
+                            }
+                        }
+                        if let Some(salary) = comparison.equivalent_salary {
+                            div { style: "margin-bottom: 15px; font-size: 16px;",
+                                "The contract matches a W-2 salary of ${format_dollars(salary)}."
+                            }
+                        }
+                    }
+                }
+                Err(error) => rsx! {
+                    div {
+                        id: "SelfEmploymentCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "self_employment" }
+        AssumptionsFooter { calculator: "self_employment" }
+        div { style: "font-size: 10px",
+            p {
+                "*Net value is pay after federal taxes and business expenses, plus the employer's match and benefits. A contractor buys their own benefits out of it."
+            }
+            br {}
+            br {}
+        }
+    }
+}
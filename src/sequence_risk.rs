@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+/// Balance at the start of each year, plus the ending balance, for a retiree who withdraws
+/// `annual_withdrawal` at the start of every year and then earns `returns[i]` during year `i`.
+/// The balance never goes below zero.
+pub fn withdrawal_path(starting_balance: f64, annual_withdrawal: f64, returns: &[f64]) -> Vec<f64> {
+    let mut balance = starting_balance;
+    let mut path = Vec::with_capacity(returns.len() + 1);
+    path.push(balance);
+
+    for annual_return in returns {
+        balance = (balance - annual_withdrawal).max(0.0) * (1.0 + annual_return);
+        path.push(balance);
+    }
+    path
+}
+
+/// Annual returns rising evenly from `average - spread` to `average + spread`.
+///
+/// Any ordering of the same returns has the same arithmetic mean and the same compound
+/// growth; only the order in which they arrive differs.
+pub fn ramp_returns(average: f64, spread: f64, years: usize) -> Vec<f64> {
+    if years < 2 {
+        return vec![average; years];
+    }
+    (0..years)
+        .map(|i| average - spread + 2.0 * spread * i as f64 / (years - 1) as f64)
+        .collect()
+}
+
+/// Arithmetic mean of a return series
+pub fn arithmetic_mean(returns: &[f64]) -> f64 {
+    if returns.is_empty() {
+        return 0.0;
+    }
+    returns.iter().sum::<f64>() / returns.len() as f64
+}
+
+/// Compound annual growth rate of a return series (independent of the order of the returns)
+pub fn compound_annual_return(returns: &[f64]) -> f64 {
+    if returns.is_empty() {
+        return 0.0;
+    }
+    let growth: f64 = returns.iter().map(|r| 1.0 + r).product();
+    growth.powf(1.0 / returns.len() as f64) - 1.0
+}
+
+/// First year (counting from zero) in which the balance is exhausted, if it ever is
+pub fn depletion_year(path: &[f64]) -> Option<usize> {
+    path.iter().position(|balance| *balance <= 0.0)
+}
+
+/// Inputs to the sequence-of-returns demo
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SequenceRiskInputs {
+    pub starting_balance: f64,
+    pub annual_withdrawal: f64,
+    /// Average annual return as a decimal
+    pub average_return: f64,
+    /// Distance of the best and worst years from the average, as a decimal
+    pub spread: f64,
+    pub years: usize,
+}
+
+/// Balance paths for the two retirees and the statistics they share
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequenceRiskOutputs {
+    /// Worst years first
+    pub bad_first_path: Vec<f64>,
+    /// Best years first
+    pub good_first_path: Vec<f64>,
+    pub arithmetic_mean: f64,
+    pub compound_annual_return: f64,
+}
+
+/// Runs the same returns in both orders for two otherwise identical retirees
+pub fn compute_sequence_risk(inputs: &SequenceRiskInputs) -> SequenceRiskOutputs {
+    let bad_first = ramp_returns(inputs.average_return, inputs.spread, inputs.years);
+    let good_first: Vec<f64> = bad_first.iter().rev().copied().collect();
+
+    SequenceRiskOutputs {
+        bad_first_path: withdrawal_path(
+            inputs.starting_balance,
+            inputs.annual_withdrawal,
+            &bad_first,
+        ),
+        good_first_path: withdrawal_path(
+            inputs.starting_balance,
+            inputs.annual_withdrawal,
+            &good_first,
+        ),
+        arithmetic_mean: arithmetic_mean(&bad_first),
+        compound_annual_return: compound_annual_return(&bad_first),
+    }
+}
@@ -0,0 +1,307 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::chart::{ChartSeries, LineChart};
+use crate::feedback_component::FeedbackWidget;
+use crate::link::{use_link_receiver, CalculatorLink};
+use crate::numeric_input_component::{numeric_field_error, NumericInput};
+use crate::saved_scenarios_component::SavedScenarios;
+use crate::scenario::Scenario;
+use crate::sequence_risk::{compute_sequence_risk, depletion_year, SequenceRiskInputs};
+use crate::wizard_component::Wizard;
+use dioxus::prelude::*;
+use num_format::{Locale, ToFormattedString};
+
+const DEFAULT_INPUTS: SequenceRiskInputs = SequenceRiskInputs {
+    starting_balance: 1_000_000.0,
+    annual_withdrawal: 50_000.0,
+    average_return: 0.06,
+    spread: 0.15,
+    years: 30,
+};
+
+const WIZARD_TITLES: &[&str] = &[
+    "How much will you have saved when you retire?",
+    "How much will you withdraw each year?",
+    "What average annual return do you expect?",
+    "How far might good and bad years stray from that average?",
+    "How many years should the money last?",
+];
+
+const BAD_FIRST_COLOR: &str = "#c0392b";
+const GOOD_FIRST_COLOR: &str = "#2471a3";
+
+/// Describes how a withdrawal path ends
+fn outcome(path: &[f64]) -> String {
+    match depletion_year(path) {
+        Some(year) => format!("runs out of money after {} years", year),
+        None => format!(
+            "ends with ${}",
+            (path.last().copied().unwrap_or(0.0) as i64).to_formatted_string(&Locale::en)
+        ),
+    }
+}
+
+#[component]
+pub fn SequenceRiskUI() -> Element {
+    let mut balance_signal = use_signal(|| 1_000_000.0 as f64);
+    let mut balance_input = use_signal(|| "1000000".to_string());
+    let balance_input_valid = use_signal(|| true);
+    let mut withdrawal_signal = use_signal(|| 50_000.0 as f64);
+    let mut withdrawal_input = use_signal(|| "50000".to_string());
+    let withdrawal_input_valid = use_signal(|| true);
+    let mut average_signal = use_signal(|| 6.0 as f64);
+    let mut average_input = use_signal(|| "6.0".to_string());
+    let average_input_valid = use_signal(|| true);
+    let mut spread_signal = use_signal(|| 15.0 as f64);
+    let mut spread_input = use_signal(|| "15.0".to_string());
+    let spread_input_valid = use_signal(|| true);
+    let mut years_signal = use_signal(|| 30.0 as f64);
+    let mut years_input = use_signal(|| "30".to_string());
+    let years_input_valid = use_signal(|| true);
+    let hover_index = use_signal(|| None::<usize>);
+    let mut guided = use_signal(|| false);
+    let mut wizard_step = use_signal(|| 0usize);
+    let apply_link = use_callback(move |link: CalculatorLink| match link {
+        CalculatorLink::RetirementBalance(balance) => {
+            balance_signal.set(balance);
+            balance_input.set(format!("{:.2}", balance));
+            true
+        }
+        _ => false,
+    });
+    let received_link = use_link_receiver(apply_link);
+    let restore_inputs = use_callback(move |inputs: SequenceRiskInputs| {
+        balance_signal.set(inputs.starting_balance);
+        balance_input.set(format!("{}", inputs.starting_balance));
+        withdrawal_signal.set(inputs.annual_withdrawal);
+        withdrawal_input.set(format!("{}", inputs.annual_withdrawal));
+        average_signal.set(inputs.average_return * 100.0);
+        average_input.set(format!("{:.1}", inputs.average_return * 100.0));
+        spread_signal.set(inputs.spread * 100.0);
+        spread_input.set(format!("{:.1}", inputs.spread * 100.0));
+        years_signal.set(inputs.years as f64);
+        years_input.set(format!("{}", inputs.years));
+    });
+    let start_fresh = use_autosave(
+        "sequence_risk",
+        DEFAULT_INPUTS,
+        move || SequenceRiskInputs {
+            starting_balance: balance_signal(),
+            annual_withdrawal: withdrawal_signal(),
+            average_return: average_signal() / 100.0,
+            spread: spread_signal() / 100.0,
+            years: years_signal().round() as usize,
+        },
+        use_callback(move |inputs: SequenceRiskInputs| {
+            restore_inputs.call(inputs);
+            if let Some(link) = received_link() {
+                apply_link.call(link);
+            }
+        }),
+    );
+    use_track_calculator("sequence_risk");
+    use_track_input_range("sequence_risk", "balance", balance_signal, amount_bucket);
+
+    let inputs = SequenceRiskInputs {
+        starting_balance: balance_signal(),
+        annual_withdrawal: withdrawal_signal(),
+        average_return: average_signal() / 100.0,
+        spread: spread_signal() / 100.0,
+        years: years_signal().round() as usize,
+    };
+    let outputs = compute_sequence_risk(&inputs);
+    let scenario = Scenario::new("sequence_risk", "Sequence of returns", &inputs).ok();
+    let bad_first_path = outputs.bad_first_path;
+    let good_first_path = outputs.good_first_path;
+    let y_max = bad_first_path
+        .iter()
+        .chain(good_first_path.iter())
+        .copied()
+        .fold(0.0, f64::max);
+
+    let mean = outputs.arithmetic_mean * 100.0;
+    let compound = outputs.compound_annual_return * 100.0;
+    let bad_first_outcome = outcome(&bad_first_path);
+    let field_errors = [
+        numeric_field_error(
+            &balance_input(),
+            balance_input_valid(),
+            "Starting balance",
+            "balance",
+        ),
+        numeric_field_error(
+            &withdrawal_input(),
+            withdrawal_input_valid(),
+            "Annual withdrawal",
+            "withdrawal",
+        ),
+        numeric_field_error(
+            &average_input(),
+            average_input_valid(),
+            "Average return",
+            "average",
+        ),
+        numeric_field_error(
+            &spread_input(),
+            spread_input_valid(),
+            "Return spread",
+            "spread",
+        ),
+        numeric_field_error(
+            &years_input(),
+            years_input_valid(),
+            "Years in retirement",
+            "years",
+        ),
+    ];
+    let fields = vec![
+        rsx! {
+            NumericInput {
+                label: "Starting Balance ($):".to_string(),
+                placeholder: "Enter savings at retirement (e.g., 1000000)".to_string(),
+                input_signal: balance_input,
+                value_signal: balance_signal,
+                valid_signal: balance_input_valid,
+                field_name: "Starting balance".to_string(),
+                css_prefix: "balance".to_string(),
+                step: Some(10000.0),
+                max_decimals: Some(2),
+            }
+        },
+        rsx! {
+            NumericInput {
+                label: "Annual Withdrawal ($):".to_string(),
+                placeholder: "Enter yearly withdrawal (e.g., 50000)".to_string(),
+                input_signal: withdrawal_input,
+                value_signal: withdrawal_signal,
+                valid_signal: withdrawal_input_valid,
+                field_name: "Annual withdrawal".to_string(),
+                css_prefix: "withdrawal".to_string(),
+                step: Some(1000.0),
+                max_decimals: Some(2),
+            }
+        },
+        rsx! {
+            NumericInput {
+                label: "Average Return (%):".to_string(),
+                placeholder: "Enter average annual return (e.g., 6.0)".to_string(),
+                input_signal: average_input,
+                value_signal: average_signal,
+                valid_signal: average_input_valid,
+                field_name: "Average return".to_string(),
+                css_prefix: "average".to_string(),
+                step: Some(0.5),
+            }
+        },
+        rsx! {
+            NumericInput {
+                label: "Return Spread (%):".to_string(),
+                placeholder: "Enter best/worst distance from average (e.g., 15.0)".to_string(),
+                input_signal: spread_input,
+                value_signal: spread_signal,
+                valid_signal: spread_input_valid,
+                field_name: "Return spread".to_string(),
+                css_prefix: "spread".to_string(),
+                step: Some(0.5),
+            }
+        },
+        rsx! {
+            NumericInput {
+                label: "Years in Retirement:".to_string(),
+                placeholder: "Enter number of years (e.g., 30)".to_string(),
+                input_signal: years_input,
+                value_signal: years_signal,
+                valid_signal: years_input_valid,
+                field_name: "Years in retirement".to_string(),
+                css_prefix: "years".to_string(),
+                step: Some(1.0),
+            }
+        },
+    ];
+
+    let good_first_outcome = outcome(&good_first_path);
+
+    rsx! {
+        hr {}
+        br {}
+
+        div { style: "color: #333; margin-bottom: 15px; max-width: 600px;",
+            "Two retirees start with the same savings, withdraw the same amount every year, and earn exactly the same returns — just in reverse order. Their average and compound returns are identical, yet their outcomes are not."
+        }
+
+        if guided() {
+            Wizard {
+                titles: WIZARD_TITLES.iter().map(|title| title.to_string()).collect::<Vec<_>>(),
+                step: wizard_step,
+                errors: field_errors[wizard_step().min(field_errors.len() - 1)]
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>(),
+                on_finish: move |_| guided.set(false),
+                {fields[wizard_step().min(fields.len() - 1)].clone()}
+            }
+        } else {
+            button {
+                style: "padding: 6px 12px; border-radius: 4px; margin-bottom: 15px;",
+                onclick: move |_| {
+                    wizard_step.set(0);
+                    guided.set(true);
+                },
+                "Guided Setup"
+            }
+            for field in fields.iter() {
+                {field.clone()}
+            }
+            div { style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                "Both sequences: average return {mean:.2}%, compound annual return {compound:.2}%"
+            }
+
+            LineChart {
+                title: format!("Retiree A (worst years first) {}", bad_first_outcome),
+                series: vec![
+                    ChartSeries {
+                        label: "Retiree A".to_string(),
+                        color: BAD_FIRST_COLOR.to_string(),
+                        values: bad_first_path,
+                    },
+                ],
+                x_start: 0.0,
+                hover_index,
+                y_max: Some(y_max),
+            }
+            LineChart {
+                title: format!("Retiree B (best years first) {}", good_first_outcome),
+                series: vec![
+                    ChartSeries {
+                        label: "Retiree B".to_string(),
+                        color: GOOD_FIRST_COLOR.to_string(),
+                        values: good_first_path,
+                    },
+                ],
+                x_start: 0.0,
+                hover_index,
+                y_max: Some(y_max),
+            }
+
+            if let Some(scenario) = scenario {
+                SavedScenarios {
+                    scenario,
+                    on_load: move |scenario: Scenario| {
+                        if let Ok(inputs) = scenario.inputs::<SequenceRiskInputs>() {
+                            restore_inputs.call(inputs);
+                        }
+                    },
+                }
+            }
+            StartFreshButton { start_fresh }
+            FeedbackWidget { calculator: "sequence_risk" }
+            AssumptionsFooter { calculator: "sequence_risk" }
+        }
+        div { style: "font-size: 10px",
+            p { "*Returns are illustrative, not a forecast. Withdrawals are taken at the start of each year." }
+            br {}
+            br {}
+        }
+    }
+}
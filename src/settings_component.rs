@@ -0,0 +1,142 @@
+use crate::analytics::{use_analytics, Consent};
+use crate::build_info::{build_label, BUILD_TIME};
+use crate::data_year::{find_data_set, set_data_year, use_data_year, DataYearBanner, DATA_SETS};
+use crate::number_format::{
+    set_number_format, use_number_format, NumberFormat, CURRENCIES, LOCALES,
+};
+use crate::tour_component::start_tour;
+use crate::Route;
+use dioxus::prelude::*;
+
+/// App settings page
+#[component]
+pub fn SettingsUI() -> Element {
+    let mut analytics = use_analytics();
+    let consent = analytics().consent;
+    let number_format = use_number_format();
+    let locale_format = number_format.clone();
+    let currency_format = number_format.clone();
+
+    rsx! {
+        hr {}
+        br {}
+
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Usage Analytics:"
+            }
+            input {
+                r#type: "checkbox",
+                checked: consent == Consent::Granted,
+                onchange: move |event| {
+                    let consent = if event.checked() { Consent::Granted } else { Consent::Denied };
+                    analytics.write().set_consent(consent);
+                },
+            }
+            span { style: "margin-left: 10px; color: #333; font-size: 14px;",
+                "Count which calculators are used and rough input ranges, never exact values"
+            }
+        }
+
+        if consent == Consent::Granted {
+            div { style: "color: #333; font-size: 12px; margin-left: 160px; margin-bottom: 15px;",
+                if analytics().counts().is_empty() {
+                    "Nothing recorded yet this session."
+                }
+                for (event , count) in analytics().counts().clone() {
+                    div { "{event} ({count})" }
+                }
+            }
+        }
+
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Number Format:"
+            }
+            select {
+                style: "padding: 4px 6px; border-radius: 4px;",
+                onchange: move |event| {
+                    set_number_format(NumberFormat {
+                        locale: event.value(),
+                        ..locale_format.clone()
+                    })
+                },
+                for (name , example) in LOCALES.iter() {
+                    option { value: *name, selected: number_format.locale == *name, {*example} }
+                }
+            }
+        }
+
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Currency:"
+            }
+            select {
+                style: "padding: 4px 6px; border-radius: 4px;",
+                onchange: move |event| {
+                    set_number_format(NumberFormat {
+                        currency: event.value(),
+                        ..currency_format.clone()
+                    })
+                },
+                for (code , symbol) in CURRENCIES.iter() {
+                    option { value: *code, selected: number_format.currency == *code, "{code} ({symbol})" }
+                }
+            }
+            span { style: "margin-left: 10px; color: #333; font-size: 14px;",
+                "Used for chart axes; amounts are not converted"
+            }
+        }
+
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Guided Tour:"
+            }
+            button {
+                style: "padding: 4px 10px; border-radius: 4px;",
+                onclick: move |_| {
+                    navigator().push(Route::FutureValueUI {});
+                    start_tour();
+                },
+                "Take the Tour"
+            }
+        }
+
+        for data_set in DATA_SETS.iter() {
+            DataYearSetting { data_set: data_set.slug }
+        }
+
+        div { id: "BuildInfo", style: "color: #666; font-size: 12px; margin-top: 30px;",
+            "Build {build_label()}, built {BUILD_TIME}. Please include it when reporting a problem."
+        }
+    }
+}
+
+/// Year picker for one annual data set, with a banner when the calendar has moved past it
+#[component]
+fn DataYearSetting(data_set: &'static str) -> Element {
+    let year = use_data_year(data_set);
+    let Some(info) = find_data_set(data_set) else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "{info.name}:"
+            }
+            select {
+                style: "padding: 4px 6px; border-radius: 4px;",
+                onchange: move |event| {
+                    if let Ok(selected) = event.value().parse::<i32>() {
+                        set_data_year(data_set, year, selected);
+                    }
+                },
+                for option_year in (info.years)().into_iter().rev() {
+                    option { value: "{option_year}", selected: year() == option_year, "{option_year}" }
+                }
+            }
+        }
+        DataYearBanner { data_set, year }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::scenario::Scenario;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Start of every share token, so a shared link is easy to recognize
+pub const TOKEN_PREFIX: &str = "shr_";
+
+/// How long a shared link works when the advisor doesn't say
+pub const DEFAULT_SHARE_DAYS: u64 = 30;
+
+/// Longest a shared link can be made to work
+pub const MAX_SHARE_DAYS: u64 = 365;
+
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Environment variable naming the file the server keeps shared scenarios in
+pub const SHARES_FILE_VAR: &str = "SHARES_FILE";
+
+/// A scenario frozen for a read-only link
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SharedScenario {
+    pub scenario: Scenario,
+    /// Seconds since the Unix epoch when the link was made
+    pub created_at: u64,
+    /// Seconds since the Unix epoch after which the link stops working
+    pub expires_at: u64,
+}
+
+impl SharedScenario {
+    /// Whole days left before the link stops working, rounded up
+    pub fn days_left(&self, now: u64) -> u64 {
+        self.expires_at.saturating_sub(now).div_ceil(SECS_PER_DAY)
+    }
+}
+
+/// Why a shared link can't be opened
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShareError {
+    /// No scenario was shared under the token
+    NotFound,
+    /// The link's time ran out
+    Expired,
+}
+
+impl std::fmt::Display for ShareError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShareError::NotFound => write!(f, "This shared link doesn't exist"),
+            ShareError::Expired => write!(f, "This shared link has expired; ask for a new one"),
+        }
+    }
+}
+
+/// Scenarios shared by token. Shares are never edited after they are made, so a link always
+/// shows what the advisor sent.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShareStore {
+    shares: HashMap<String, SharedScenario>,
+}
+
+impl ShareStore {
+    /// Shares `scenario` under `token` for `days` days (clamped to 1..=`MAX_SHARE_DAYS`)
+    pub fn share(
+        &mut self,
+        token: &str,
+        scenario: Scenario,
+        now: u64,
+        days: u64,
+    ) -> SharedScenario {
+        let shared = SharedScenario {
+            scenario,
+            created_at: now,
+            expires_at: now + days.clamp(1, MAX_SHARE_DAYS) * SECS_PER_DAY,
+        };
+        self.shares.insert(token.to_string(), shared.clone());
+        shared
+    }
+
+    /// The scenario shared under `token`, if its link still works at `now`
+    pub fn get(&self, token: &str, now: u64) -> Result<&SharedScenario, ShareError> {
+        let shared = self.shares.get(token.trim()).ok_or(ShareError::NotFound)?;
+        if now >= shared.expires_at {
+            return Err(ShareError::Expired);
+        }
+        Ok(shared)
+    }
+
+    /// Forgets expired shares. Returns how many were removed.
+    pub fn remove_expired(&mut self, now: u64) -> usize {
+        let before = self.shares.len();
+        self.shares.retain(|_, shared| now < shared.expires_at);
+        before - self.shares.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.shares.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shares.is_empty()
+    }
+}
+
+/// A new token from 16 random bytes: the prefix and 32 hex digits. Anyone with the token can
+/// read the scenario, so it must come from a secure random source.
+pub fn generate_token(entropy: [u8; 16]) -> String {
+    let hex: String = entropy.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}{}", TOKEN_PREFIX, hex)
+}
+
+/// Seconds since the Unix epoch on the server's clock
+#[cfg(feature = "server")]
+pub fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// The server's shares, read from `SHARES_FILE` (default `shares.json`) on first use. A missing
+/// or unreadable file starts an empty store.
+#[cfg(feature = "server")]
+static SERVER_SHARES: std::sync::LazyLock<std::sync::Mutex<ShareStore>> =
+    std::sync::LazyLock::new(|| {
+        let store = std::fs::read_to_string(shares_file())
+            .ok()
+            .and_then(|json| serde_json::from_str::<ShareStore>(&json).ok())
+            .unwrap_or_default();
+        std::sync::Mutex::new(store)
+    });
+
+#[cfg(feature = "server")]
+fn shares_file() -> String {
+    std::env::var(SHARES_FILE_VAR).unwrap_or_else(|_| "shares.json".to_string())
+}
+
+/// Shares `scenario` on the server for `days` days and returns its token. Expired shares are
+/// dropped from the file at the same time.
+#[cfg(feature = "server")]
+pub fn server_share(scenario: Scenario, days: u64) -> Result<String, String> {
+    let token = generate_token(crate::api_keys::os_entropy().map_err(|e| e.to_string())?);
+    let now = unix_now();
+    let mut shares = SERVER_SHARES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    shares.remove_expired(now);
+    shares.share(&token, scenario, now, days);
+    let json = serde_json::to_string(&*shares).map_err(|e| e.to_string())?;
+    std::fs::write(shares_file(), json)
+        .map_err(|e| format!("Could not save the shared scenario: {}", e))?;
+    Ok(token)
+}
+
+/// The scenario shared on the server under `token`
+#[cfg(feature = "server")]
+pub fn server_shared(token: &str) -> Result<SharedScenario, ShareError> {
+    let shares = SERVER_SHARES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    shares.get(token, unix_now()).cloned()
+}
@@ -0,0 +1,195 @@
+use crate::calculators::find_calculator;
+#[cfg(feature = "fullstack")]
+use crate::pages::page_url;
+use crate::scenario::{update_scenarios, Scenario};
+use crate::scenario_report::{input_fields, scenario_results};
+#[cfg(feature = "fullstack")]
+use crate::share::DEFAULT_SHARE_DAYS;
+#[cfg(feature = "fullstack")]
+use crate::Route;
+use dioxus::prelude::*;
+
+const CELL_STYLE: &str = "text-align: left; padding: 2px 8px;";
+
+#[cfg(not(feature = "fullstack"))]
+const NO_SERVER: &str = "Shared links need the app's server, and this build doesn't have one.";
+
+/// Shares a scenario on the server for `days` days and returns the link's token
+#[cfg(feature = "fullstack")]
+#[server(endpoint = "share_scenario", output = server_fn::codec::Json)]
+async fn share_scenario(scenario: Scenario, days: u64) -> Result<String, ServerFnError> {
+    crate::share::server_share(scenario, days).map_err(ServerFnError::new)
+}
+
+/// The scenario shared under `token` and the whole days left before its link expires
+#[cfg(feature = "fullstack")]
+#[server(endpoint = "shared_scenario", output = server_fn::codec::Json)]
+async fn shared_scenario(token: String) -> Result<(Scenario, u64), ServerFnError> {
+    let shared =
+        crate::share::server_shared(&token).map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok((
+        shared.scenario.clone(),
+        shared.days_left(crate::share::unix_now()),
+    ))
+}
+
+/// Shares `scenario` and returns the address of its read-only view
+async fn share_link(scenario: Scenario) -> Result<String, String> {
+    #[cfg(feature = "fullstack")]
+    return share_scenario(scenario, DEFAULT_SHARE_DAYS)
+        .await
+        .map(|token| page_url(&Route::SharedScenarioUI { token }))
+        .map_err(|e| e.to_string());
+    #[cfg(not(feature = "fullstack"))]
+    {
+        let _ = scenario;
+        Err(NO_SERVER.to_string())
+    }
+}
+
+/// The scenario shared under `token` and the days left on its link
+async fn load_shared(token: String) -> Result<(Scenario, u64), String> {
+    #[cfg(feature = "fullstack")]
+    return shared_scenario(token).await.map_err(|e| e.to_string());
+    #[cfg(not(feature = "fullstack"))]
+    {
+        let _ = token;
+        Err(NO_SERVER.to_string())
+    }
+}
+
+/// Button that shares the current inputs as a read-only link, for an advisor to send a client.
+/// Links are kept on the server, so builds without one show nothing.
+#[component]
+pub fn ShareLinkButton(scenario: Scenario) -> Element {
+    let mut link = use_signal(|| None::<Result<String, String>>);
+
+    rsx! {
+        if cfg!(feature = "fullstack") {
+            div { style: "display: flex; align-items: center; gap: 10px; margin-bottom: 15px;",
+                button {
+                    id: "ShareLinkButton",
+                    style: "padding: 6px 12px; border-radius: 4px;",
+                    onclick: move |_| {
+                        let scenario = scenario.clone();
+                        async move {
+                            link.set(Some(share_link(scenario).await));
+                        }
+                    },
+                    "Share Read-Only Link"
+                }
+                match link() {
+                    Some(Ok(url)) => rsx! {
+                        input {
+                            readonly: true,
+                            value: "{url}",
+                            style: "padding: 6px 8px; width: 60ch; border-radius: 4px; font-family: monospace;",
+                        }
+                    },
+                    Some(Err(error)) => rsx! {
+                        span { style: "color: #ff0000; font-size: 12px;", "{error}" }
+                    },
+                    None => rsx! {},
+                }
+            }
+        }
+    }
+}
+
+/// Locked view of a shared scenario: its inputs and results, with no way to edit or save over
+/// them. The client can copy it into their own saved scenarios.
+#[component]
+pub fn SharedScenarioUI(token: String) -> Element {
+    let shared = use_resource(use_reactive!(|token| load_shared(token)));
+    let mut copied = use_signal(|| false);
+
+    let Some(shared) = shared() else {
+        return rsx! {
+            hr {}
+            br {}
+            div { "Loading the shared scenario…" }
+        };
+    };
+    let (scenario, days_left) = match shared {
+        Ok(shared) => shared,
+        Err(error) => {
+            return rsx! {
+                hr {}
+                br {}
+                div { style: "color: #ff0000;", "{error}" }
+            };
+        }
+    };
+    let calculator = find_calculator(&scenario.calculator);
+    let calculator_name = calculator.map_or(scenario.calculator.clone(), |calculator| {
+        calculator.name.to_string()
+    });
+    let inputs = input_fields(&scenario.inputs);
+    let results = scenario_results(&scenario).unwrap_or_default();
+
+    rsx! {
+        hr {}
+        br {}
+        h2 { "{scenario.name}" }
+        p { style: "color: #333; font-size: 14px;",
+            "A read-only {calculator_name} scenario shared with you. The link expires in {days_left} days."
+        }
+
+        table { style: "border-collapse: collapse; margin-bottom: 15px;",
+            thead {
+                tr {
+                    th { style: CELL_STYLE, "Input" }
+                    th { style: CELL_STYLE, "Value" }
+                }
+            }
+            tbody {
+                for (field , value) in inputs {
+                    tr {
+                        td { style: CELL_STYLE, "{field}" }
+                        td { style: CELL_STYLE, "{value}" }
+                    }
+                }
+            }
+        }
+        if !results.is_empty() {
+            table { style: "border-collapse: collapse; margin-bottom: 15px;",
+                thead {
+                    tr {
+                        th { style: CELL_STYLE, "Result" }
+                        th { style: CELL_STYLE, "Value" }
+                    }
+                }
+                tbody {
+                    for (label , value) in results {
+                        tr {
+                            td { style: CELL_STYLE, "{label}" }
+                            td { style: CELL_STYLE, "{value:.2}" }
+                        }
+                    }
+                }
+            }
+        }
+
+        div { style: "display: flex; align-items: center; gap: 10px; margin-bottom: 15px;",
+            button {
+                style: "padding: 6px 12px; border-radius: 4px;",
+                disabled: copied(),
+                onclick: move |_| {
+                    let scenario = scenario.clone();
+                    async move {
+                        update_scenarios(|scenarios| scenarios.push(scenario)).await;
+                        copied.set(true);
+                    }
+                },
+                if copied() {
+                    "Copied to My Scenarios"
+                } else {
+                    "Copy to My Scenarios"
+                }
+            }
+            if let Some(calculator) = calculator.filter(|calculator| calculator.included) {
+                Link { to: calculator.route(), "Open {calculator.name}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,362 @@
+/// Source of random numbers for simulations. Implement this to plug in a different generator;
+/// every simulation function takes one so results are reproducible from a seed.
+pub trait RandomSource {
+    fn next_u64(&mut self) -> u64;
+
+    /// Uniform sample in the open interval (0, 1)
+    fn next_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    }
+
+    /// Standard normal sample (mean 0, standard deviation 1) via the Box-Muller transform
+    fn standard_normal(&mut self) -> f64 {
+        let u1 = self.next_f64();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+/// Seed used when the user hasn't chosen one
+pub const DEFAULT_SEED: u64 = 20_250_101;
+
+/// Small deterministic pseudo-random number generator (SplitMix64), so simulations don't need
+/// an external crate and behave identically on desktop and web.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+}
+
+impl RandomSource for Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// Turns a user-entered seed into a number: digits are used as-is, anything else (e.g., a word)
+/// is hashed (FNV-1a) so any text reproduces the same simulation
+pub fn seed_from_str(text: &str) -> u64 {
+    let text = text.trim();
+    text.parse::<u64>().unwrap_or_else(|_| {
+        text.bytes().fold(0xCBF2_9CE4_8422_2325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01B3)
+        })
+    })
+}
+
+/// An asset class with a normally distributed annual return
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssetClass {
+    pub name: &'static str,
+    /// Expected annual return, e.g., 0.07 for 7%
+    pub mean: f64,
+    /// Annual standard deviation of returns, e.g., 0.15 for 15%
+    pub volatility: f64,
+}
+
+pub const US_STOCKS: AssetClass = AssetClass {
+    name: "US Stocks",
+    mean: 0.10,
+    volatility: 0.16,
+};
+pub const INTERNATIONAL_STOCKS: AssetClass = AssetClass {
+    name: "International Stocks",
+    mean: 0.08,
+    volatility: 0.18,
+};
+pub const US_BONDS: AssetClass = AssetClass {
+    name: "US Bonds",
+    mean: 0.045,
+    volatility: 0.06,
+};
+pub const CASH: AssetClass = AssetClass {
+    name: "Cash",
+    mean: 0.03,
+    volatility: 0.01,
+};
+
+/// A portfolio: asset classes, their weights, and the correlation matrix between them
+#[derive(Debug, Clone, PartialEq)]
+pub struct Allocation {
+    pub name: &'static str,
+    pub assets: Vec<AssetClass>,
+    /// Portfolio weights, one per asset, summing to 1.0
+    pub weights: Vec<f64>,
+    /// Symmetric correlation matrix with ones on the diagonal
+    pub correlations: Vec<Vec<f64>>,
+}
+
+/// Common allocations over US stocks, international stocks, bonds, and cash
+pub fn allocation_presets() -> Vec<Allocation> {
+    let assets = vec![US_STOCKS, INTERNATIONAL_STOCKS, US_BONDS, CASH];
+    let correlations = vec![
+        vec![1.0, 0.75, 0.10, 0.0],
+        vec![0.75, 1.0, 0.10, 0.0],
+        vec![0.10, 0.10, 1.0, 0.20],
+        vec![0.0, 0.0, 0.20, 1.0],
+    ];
+    [
+        ("All Equity", vec![0.7, 0.3, 0.0, 0.0]),
+        ("80/20", vec![0.55, 0.25, 0.2, 0.0]),
+        ("60/40", vec![0.4, 0.2, 0.4, 0.0]),
+        ("Three-Fund", vec![0.5, 0.2, 0.3, 0.0]),
+        ("Conservative", vec![0.2, 0.1, 0.5, 0.2]),
+    ]
+    .into_iter()
+    .map(|(name, weights)| Allocation {
+        name,
+        assets: assets.clone(),
+        weights,
+        correlations: correlations.clone(),
+    })
+    .collect()
+}
+
+/// Cholesky decomposition of a symmetric positive-definite matrix: returns lower-triangular `L`
+/// with `L * Lᵀ = matrix`, or `None` if the matrix is not positive-definite (e.g., an
+/// inconsistent user-edited correlation matrix).
+pub fn cholesky(matrix: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let n = matrix.len();
+    let mut lower = vec![vec![0.0; n]; n];
+
+    for i in 0..n {
+        if matrix[i].len() != n {
+            return None;
+        }
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| lower[i][k] * lower[j][k]).sum();
+            if i == j {
+                let diagonal = matrix[i][i] - sum;
+                if diagonal <= 0.0 {
+                    return None;
+                }
+                lower[i][j] = diagonal.sqrt();
+            } else {
+                lower[i][j] = (matrix[i][j] - sum) / lower[j][j];
+            }
+        }
+    }
+    Some(lower)
+}
+
+/// Draws jointly normal annual returns for several asset classes with a given correlation structure
+#[derive(Debug, Clone)]
+pub struct CorrelatedSampler {
+    assets: Vec<AssetClass>,
+    lower: Vec<Vec<f64>>,
+}
+
+impl CorrelatedSampler {
+    /// Returns `None` if the correlation matrix is the wrong size or not positive-definite
+    pub fn new(assets: Vec<AssetClass>, correlations: &[Vec<f64>]) -> Option<Self> {
+        if correlations.len() != assets.len() {
+            return None;
+        }
+        let lower = cholesky(correlations)?;
+        Some(CorrelatedSampler { assets, lower })
+    }
+
+    /// One year of returns, one per asset class
+    pub fn sample<R: RandomSource>(&self, rng: &mut R) -> Vec<f64> {
+        let z: Vec<f64> = self.assets.iter().map(|_| rng.standard_normal()).collect();
+        self.assets
+            .iter()
+            .enumerate()
+            .map(|(i, asset)| {
+                let shock: f64 = (0..=i).map(|j| self.lower[i][j] * z[j]).sum();
+                asset.mean + asset.volatility * shock
+            })
+            .collect()
+    }
+}
+
+impl Allocation {
+    pub fn sampler(&self) -> Option<CorrelatedSampler> {
+        CorrelatedSampler::new(self.assets.clone(), &self.correlations)
+    }
+
+    /// Weighted portfolio return for one year of per-asset returns
+    pub fn portfolio_return(&self, asset_returns: &[f64]) -> f64 {
+        self.weights
+            .iter()
+            .zip(asset_returns)
+            .map(|(weight, r)| weight * r)
+            .sum()
+    }
+
+    /// Sets a pairwise correlation, keeping the matrix symmetric
+    pub fn set_correlation(&mut self, i: usize, j: usize, value: f64) {
+        if i == j || i >= self.correlations.len() || j >= self.correlations.len() {
+            return;
+        }
+        let value = value.clamp(-1.0, 1.0);
+        self.correlations[i][j] = value;
+        self.correlations[j][i] = value;
+    }
+}
+
+/// Simulates `n_paths` sequences of `n_years` annual portfolio returns
+pub fn simulate_portfolio_returns<R: RandomSource>(
+    allocation: &Allocation,
+    n_years: usize,
+    n_paths: usize,
+    rng: &mut R,
+) -> Option<Vec<Vec<f64>>> {
+    let sampler = allocation.sampler()?;
+    Some(
+        (0..n_paths)
+            .map(|_| {
+                (0..n_years)
+                    .map(|_| allocation.portfolio_return(&sampler.sample(rng)))
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+/// Resamples a historical return series in contiguous blocks (moving block bootstrap), which keeps
+/// the fat tails and short-run momentum of real data instead of assuming normal returns.
+/// Blocks wrap around the end of the history.
+pub fn block_bootstrap<R: RandomSource>(
+    history: &[f64],
+    block_size: usize,
+    n_years: usize,
+    rng: &mut R,
+) -> Vec<f64> {
+    if history.is_empty() {
+        return vec![0.0; n_years];
+    }
+    let block_size = block_size.clamp(1, history.len());
+    let mut path = Vec::with_capacity(n_years);
+
+    while path.len() < n_years {
+        let start = (rng.next_u64() % history.len() as u64) as usize;
+        path.extend(
+            (0..block_size)
+                .map(|offset| history[(start + offset) % history.len()])
+                .take(n_years - path.len()),
+        );
+    }
+    path
+}
+
+/// Parses pasted or uploaded historical returns: numbers separated by commas, whitespace, or new
+/// lines. Values ending in `%` are percentages (`7.5%`); other values are decimals (`0.075`).
+pub fn parse_returns(text: &str) -> Result<Vec<f64>, String> {
+    let returns = text
+        .split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            let (number, scale) = match token.strip_suffix('%') {
+                Some(number) => (number, 0.01),
+                None => (token, 1.0),
+            };
+            match number.parse::<f64>() {
+                Ok(value) if value.is_finite() && value * scale > -1.0 => Ok(value * scale),
+                Ok(_) => Err(format!("\"{}\" is not a possible annual return", token)),
+                Err(_) => Err(format!("\"{}\" is not a valid number", token)),
+            }
+        })
+        .collect::<Result<Vec<f64>, String>>()?;
+
+    if returns.is_empty() {
+        return Err("Please enter at least one annual return".to_string());
+    }
+    Ok(returns)
+}
+
+/// How simulated annual returns are generated
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReturnModel {
+    /// Independent normal returns with a fixed mean and volatility
+    Normal { mean: f64, volatility: f64 },
+    /// Block bootstrap from a historical return series
+    Bootstrap {
+        history: Vec<f64>,
+        block_size: usize,
+    },
+    /// Correlated normal returns for each asset class, combined by the allocation's weights
+    Portfolio(Allocation),
+}
+
+impl ReturnModel {
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            ReturnModel::Normal { .. } => "Normal distribution",
+            ReturnModel::Bootstrap { .. } => "Historical resampling",
+            ReturnModel::Portfolio(_) => "Asset allocation",
+        }
+    }
+
+    /// One simulated sequence of `n_years` annual returns. A portfolio whose correlations can't
+    /// be sampled returns nothing but zeros; check `Allocation::sampler` first.
+    pub fn sample_path<R: RandomSource>(&self, n_years: usize, rng: &mut R) -> Vec<f64> {
+        match self {
+            ReturnModel::Normal { mean, volatility } => (0..n_years)
+                .map(|_| mean + volatility * rng.standard_normal())
+                .collect(),
+            ReturnModel::Bootstrap {
+                history,
+                block_size,
+            } => block_bootstrap(history, *block_size, n_years, rng),
+            ReturnModel::Portfolio(allocation) => {
+                simulate_portfolio_returns(allocation, n_years, 1, rng)
+                    .and_then(|paths| paths.into_iter().next())
+                    .unwrap_or_else(|| vec![0.0; n_years])
+            }
+        }
+    }
+}
+
+/// Value below which `p` (0.0 to 1.0) of the sorted samples fall, with linear interpolation
+pub fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let position = p.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    let fraction = position - lower as f64;
+    sorted[lower] + fraction * (sorted[upper] - sorted[lower])
+}
+
+/// 10th/25th/50th/75th/90th percentile of simulated values at each point in time
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PercentileBands {
+    pub p10: Vec<f64>,
+    pub p25: Vec<f64>,
+    pub p50: Vec<f64>,
+    pub p75: Vec<f64>,
+    pub p90: Vec<f64>,
+}
+
+impl PercentileBands {
+    /// Computes the bands across simulated paths (each path indexed by year)
+    pub fn from_paths(paths: &[Vec<f64>]) -> Self {
+        let n_points = paths.iter().map(|path| path.len()).min().unwrap_or(0);
+        let mut bands = PercentileBands::default();
+
+        for t in 0..n_points {
+            let mut values: Vec<f64> = paths.iter().map(|path| path[t]).collect();
+            values.sort_by(|a, b| a.total_cmp(b));
+            bands.p10.push(percentile(&values, 0.10));
+            bands.p25.push(percentile(&values, 0.25));
+            bands.p50.push(percentile(&values, 0.50));
+            bands.p75.push(percentile(&values, 0.75));
+            bands.p90.push(percentile(&values, 0.90));
+        }
+        bands
+    }
+
+    pub fn len(&self) -> usize {
+        self.p50.len()
+    }
+}
@@ -0,0 +1,97 @@
+use crate::annuity::{solve_savings_goal, SavingsGoalInputs};
+use crate::compounding;
+
+/// One deposit of a sinking fund schedule
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SinkingFundRow {
+    /// 1-based deposit number
+    pub period: usize,
+    pub deposit: f64,
+    /// Interest the fund earns over the deposit's period
+    pub interest: f64,
+    /// Fund balance at the end of the period
+    pub balance: f64,
+}
+
+/// Every deposit into a sinking fund, with totals
+#[derive(Debug, Clone, PartialEq)]
+pub struct SinkingFundSchedule {
+    /// The level deposit; the last deposit may differ by the rounding of earlier ones
+    pub payment: f64,
+    pub rows: Vec<SinkingFundRow>,
+    pub total_deposits: f64,
+    pub total_interest: f64,
+    /// Balance on the due date: the obligation, or more if the fund outgrows it on its own
+    pub ending_balance: f64,
+}
+
+// Computes the deposits that build a sinking fund up to a known future obligation, such as a
+// bond redemption or a roof replacement, by its due date.
+///
+/// # Parameters:
+/// - `inputs`: Obligation (FV), fund balance today (PV), annual rate (r), compounding (n),
+///   deposits per year (d), years to the due date (t), and the deposit timing
+///
+/// # Formula:
+/// i = (1 + r/n)^(n/d) - 1, the rate per deposit period
+/// PMT = (FV - PV * (1 + i)^N) * i / ((1 + i)^N - 1), as in `savings_goal_payment`
+/// interest_k = balance_(k-1) * i   ((balance_(k-1) + PMT) * i for deposits at the start)
+/// balance_k = balance_(k-1) + PMT + interest_k
+///
+/// # Returns:
+/// One row per deposit, rounded to the cent. The last deposit makes up whatever the rounding
+/// left, so the fund ends at exactly the obligation. An error if the savings goal solver
+/// rejects the inputs.
+pub fn sinking_fund_schedule(inputs: &SavingsGoalInputs) -> Result<SinkingFundSchedule, String> {
+    let outputs = solve_savings_goal(inputs)?;
+    let round_cents = |value: f64| (value * 100.0).round() / 100.0;
+    let deposit_rate = compounding::growth_factor(
+        inputs.annual_rate,
+        inputs.compounding.periods_per_year(),
+        1.0 / inputs.deposits_per_year,
+    ) - 1.0;
+    let n_deposits = outputs.n_deposits as usize;
+
+    let mut balance = round_cents(inputs.current_savings);
+    let mut total_deposits = 0.0;
+    let mut total_interest = 0.0;
+    let mut rows = Vec::with_capacity(n_deposits);
+    for period in 1..=n_deposits {
+        let last = period == n_deposits && outputs.payment > 0.0;
+        let (deposit, interest) = match (last, inputs.annuity_due) {
+            (true, false) => {
+                let interest = round_cents(balance * deposit_rate);
+                (
+                    round_cents(inputs.target - balance - interest).max(0.0),
+                    interest,
+                )
+            }
+            (true, true) => {
+                let deposit = round_cents(inputs.target / (1.0 + deposit_rate) - balance).max(0.0);
+                (deposit, round_cents(inputs.target - balance - deposit))
+            }
+            (false, false) => (outputs.payment, round_cents(balance * deposit_rate)),
+            (false, true) => (
+                outputs.payment,
+                round_cents((balance + outputs.payment) * deposit_rate),
+            ),
+        };
+        balance = round_cents(balance + deposit + interest);
+        total_deposits = round_cents(total_deposits + deposit);
+        total_interest = round_cents(total_interest + interest);
+        rows.push(SinkingFundRow {
+            period,
+            deposit,
+            interest,
+            balance,
+        });
+    }
+
+    Ok(SinkingFundSchedule {
+        payment: outputs.payment,
+        rows,
+        total_deposits,
+        total_interest,
+        ending_balance: balance,
+    })
+}
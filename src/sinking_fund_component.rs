@@ -0,0 +1,248 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range, years_bucket};
+use crate::annuity::SavingsGoalInputs;
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::compounding::Compounding;
+use crate::feedback_component::FeedbackWidget;
+use crate::future_value_component::{format_dollars, CompoundingSelect};
+use crate::numeric_input_component::NumericInput;
+use crate::sinking_fund::sinking_fund_schedule;
+use dioxus::prelude::*;
+
+/// A $40,000 roof replacement in eight years, with $5,000 set aside, at 4.5% compounded monthly
+const DEFAULT_INPUTS: SavingsGoalInputs = SavingsGoalInputs {
+    target: 40_000.0,
+    current_savings: 5_000.0,
+    annual_rate: 0.045,
+    compounding: Compounding::Monthly,
+    deposits_per_year: 12.0,
+    years: 8.0,
+    annuity_due: false,
+    annual_increase: 0.0,
+};
+
+const FREQUENCY_OPTIONS: &[(f64, &str, &str)] = &[
+    (12.0, "monthly", "Monthly"),
+    (4.0, "quarterly", "Quarterly"),
+    (2.0, "semiannual", "Semiannual"),
+    (1.0, "annual", "Annual"),
+];
+
+const TIMING_OPTIONS: &[(bool, &str, &str)] = &[
+    (false, "end", "End of period"),
+    (true, "begin", "Start of period"),
+];
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+
+#[component]
+pub fn SinkingFundUI() -> Element {
+    let mut target_signal = use_signal(|| 40_000.0 as f64);
+    let mut target_input = use_signal(|| "40000".to_string());
+    let target_input_valid = use_signal(|| true);
+    let mut balance_signal = use_signal(|| 5_000.0 as f64);
+    let mut balance_input = use_signal(|| "5000".to_string());
+    let balance_input_valid = use_signal(|| true);
+    let mut rate_signal = use_signal(|| 4.5 as f64);
+    let mut rate_input = use_signal(|| "4.5".to_string());
+    let rate_input_valid = use_signal(|| true);
+    let mut years_signal = use_signal(|| 8.0 as f64);
+    let mut years_input = use_signal(|| "8".to_string());
+    let years_input_valid = use_signal(|| true);
+    let mut compounding_signal = use_signal(|| Compounding::Monthly);
+    let mut frequency_signal = use_signal(|| 12.0 as f64);
+    let mut due_signal = use_signal(|| false);
+
+    let inputs = move || SavingsGoalInputs {
+        target: target_signal(),
+        current_savings: balance_signal(),
+        annual_rate: rate_signal() / 100.0,
+        compounding: compounding_signal(),
+        deposits_per_year: frequency_signal(),
+        years: years_signal(),
+        annuity_due: due_signal(),
+        annual_increase: 0.0,
+    };
+    let start_fresh = use_autosave(
+        "sinking_fund",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: SavingsGoalInputs| {
+            target_signal.set(inputs.target);
+            target_input.set(format!("{}", inputs.target));
+            balance_signal.set(inputs.current_savings);
+            balance_input.set(format!("{}", inputs.current_savings));
+            rate_signal.set(inputs.annual_rate * 100.0);
+            rate_input.set(format!("{}", inputs.annual_rate * 100.0));
+            compounding_signal.set(inputs.compounding);
+            frequency_signal.set(inputs.deposits_per_year);
+            years_signal.set(inputs.years);
+            years_input.set(format!("{}", inputs.years));
+            due_signal.set(inputs.annuity_due);
+        }),
+    );
+    use_track_calculator("sinking_fund");
+    use_track_input_range("sinking_fund", "obligation", target_signal, amount_bucket);
+    use_track_input_range("sinking_fund", "years", years_signal, years_bucket);
+
+    let frequency_name = FREQUENCY_OPTIONS
+        .iter()
+        .find(|(periods, _, _)| *periods == frequency_signal())
+        .map_or("", |(_, _, display)| *display)
+        .to_ascii_lowercase();
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Obligation ($):".to_string(),
+            placeholder: "Enter the amount due (e.g., 40000)".to_string(),
+            input_signal: target_input,
+            value_signal: target_signal,
+            valid_signal: target_input_valid,
+            field_name: "Obligation".to_string(),
+            css_prefix: "target".to_string(),
+            step: Some(1000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Fund Balance ($):".to_string(),
+            placeholder: "Enter what the fund holds today (e.g., 5000)".to_string(),
+            input_signal: balance_input,
+            value_signal: balance_signal,
+            valid_signal: balance_input_valid,
+            field_name: "Fund balance".to_string(),
+            css_prefix: "balance".to_string(),
+            step: Some(100.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Interest Rate (%):".to_string(),
+            placeholder: "Enter the annual rate (e.g., 4.5)".to_string(),
+            input_signal: rate_input,
+            value_signal: rate_signal,
+            valid_signal: rate_input_valid,
+            field_name: "Interest rate".to_string(),
+            css_prefix: "rate".to_string(),
+            allow_negative: true,
+            step: Some(0.125),
+        }
+        CompoundingSelect { compounding: compounding_signal }
+        NumericInput {
+            label: "Years Until Due:".to_string(),
+            placeholder: "Enter the years until it's due (e.g., 8)".to_string(),
+            input_signal: years_input,
+            value_signal: years_signal,
+            valid_signal: years_input_valid,
+            field_name: "Years until due".to_string(),
+            css_prefix: "years".to_string(),
+            step: Some(1.0),
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Deposits:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(periods, _, _)) = FREQUENCY_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        frequency_signal.set(periods);
+                    }
+                },
+                for (periods , value , display) in FREQUENCY_OPTIONS.iter() {
+                    option { value: *value, selected: frequency_signal() == *periods, {*display} }
+                }
+            }
+        }
+        div { style: "display: flex; align-items: center; margin-bottom: 15px;",
+            label { style: "margin-right: 10px; font-weight: bold; color: #333; min-width: 150px;",
+                "Deposited At:"
+            }
+            select {
+                style: "border: 1px solid #ccc; background: gray; padding: 6px 8px; width: 150px; border-radius: 4px; font-family: monospace; ",
+                onchange: move |event| {
+                    let value = event.value();
+                    if let Some(&(due, _, _)) = TIMING_OPTIONS
+                        .iter()
+                        .find(|(_, value_str, _)| *value_str == value.as_str())
+                    {
+                        due_signal.set(due);
+                    }
+                },
+                for (due , value , display) in TIMING_OPTIONS.iter() {
+                    option { value: *value, selected: due_signal() == *due, {*display} }
+                }
+            }
+        }
+
+        {
+            match sinking_fund_schedule(&inputs()) {
+                Ok(schedule) => rsx! {
+                    if schedule.payment > 0.0 {
+                        div {
+                            id: "SinkingFundCalculation",
+                            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                            "Deposit needed: ${format_dollars(schedule.payment)} {frequency_name}*"
+                        }
+                    } else {
+                        div {
+                            id: "SinkingFundCalculation",
+                            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                            "No deposits needed: the fund grows to ${format_dollars(schedule.ending_balance)} on its own"
+                        }
+                    }
+                    div { style: "margin-bottom: 15px; font-size: 16px;",
+                        "{schedule.rows.len()} deposits totaling ${format_dollars(schedule.total_deposits)}; interest adds ${format_dollars(schedule.total_interest)}."
+                    }
+                    div {
+                        style: "max-height: 400px; overflow-y: auto; margin-bottom: 15px;",
+                        tabindex: "0",
+                        table { style: "color: #333; border-collapse: collapse; font-size: 13px;",
+                            thead { style: "position: sticky; top: 0; background: #f4f4f4;",
+                                tr {
+                                    th { scope: "col", style: CELL_STYLE, "Deposit #" }
+                                    th { scope: "col", style: CELL_STYLE, "Deposit" }
+                                    th { scope: "col", style: CELL_STYLE, "Interest" }
+                                    th { scope: "col", style: CELL_STYLE, "Fund Balance" }
+                                }
+                            }
+                            tbody {
+                                for row in schedule.rows.iter() {
+                                    tr { key: "{row.period}",
+                                        th { scope: "row", style: CELL_STYLE, "{row.period}" }
+                                        td { style: CELL_STYLE, {format_dollars(row.deposit)} }
+                                        td { style: CELL_STYLE, {format_dollars(row.interest)} }
+                                        td { style: CELL_STYLE, {format_dollars(row.balance)} }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Err(error) => rsx! {
+                    div {
+                        id: "SinkingFundCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "sinking_fund" }
+        AssumptionsFooter { calculator: "sinking_fund" }
+        div { style: "font-size: 10px",
+            p {
+                "*Deposits are rounded to the cent; the last deposit makes up the difference so the fund ends at exactly the obligation."
+            }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::scenario::Scenario;
+use crate::scenario_report::scenario_results;
+use crate::storage::{load_item, save_item};
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const SNAPSHOTS_KEY: &str = "fintools.snapshots";
+
+/// Days after the last snapshot before the user is prompted to take another
+pub const SNAPSHOT_INTERVAL_DAYS: u64 = 30;
+
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// A scenario's headline results as they stood at one moment, with the inputs behind them
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResultSnapshot {
+    /// Seconds since the Unix epoch when the snapshot was taken
+    pub taken_at: u64,
+    pub scenario: Scenario,
+    /// (label, value) pairs from `scenario_results`; `null` in the JSON when not computable
+    pub results: Vec<(String, Option<f64>)>,
+}
+
+impl ResultSnapshot {
+    /// Computes the scenario's results now, to keep them
+    pub fn take(scenario: &Scenario, taken_at: u64) -> Result<Self, String> {
+        let results = scenario_results(scenario)?
+            .into_iter()
+            .map(|(label, value)| (label, Some(value).filter(|value| value.is_finite())))
+            .collect();
+        Ok(ResultSnapshot {
+            taken_at,
+            scenario: scenario.clone(),
+            results,
+        })
+    }
+
+    /// The value of the result labelled `label`, if it was computable
+    pub fn result(&self, label: &str) -> Option<f64> {
+        self.results
+            .iter()
+            .find(|(name, _)| name == label)
+            .and_then(|(_, value)| *value)
+    }
+}
+
+/// The snapshots of one scenario (matched by calculator and name), oldest first
+pub fn snapshot_history<'a>(
+    snapshots: &'a [ResultSnapshot],
+    calculator: &str,
+    name: &str,
+) -> Vec<&'a ResultSnapshot> {
+    let mut history: Vec<&ResultSnapshot> = snapshots
+        .iter()
+        .filter(|snapshot| {
+            snapshot.scenario.calculator == calculator && snapshot.scenario.name == name
+        })
+        .collect();
+    history.sort_by_key(|snapshot| snapshot.taken_at);
+    history
+}
+
+/// Whether a scenario is due for its periodic snapshot at `now`. Only scenarios snapshotted at
+/// least once are tracked, so nobody is prompted before taking the first one.
+pub fn snapshot_due(history: &[&ResultSnapshot], now: u64) -> bool {
+    history.last().is_some_and(|latest| {
+        now.saturating_sub(latest.taken_at) >= SNAPSHOT_INTERVAL_DAYS * SECS_PER_DAY
+    })
+}
+
+/// ISO date (YYYY-MM-DD, UTC) of a Unix timestamp
+pub fn format_date(unix_secs: u64) -> String {
+    // Civil date from days since 1970-01-01, counting years from March so leap days fall at the
+    // end (the inverse of `npv::parse_date`)
+    let days = (unix_secs / SECS_PER_DAY) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Parses a stored snapshot list; an unreadable list is treated as empty
+pub fn parse_snapshots(json: &str) -> Vec<ResultSnapshot> {
+    serde_json::from_str(json).unwrap_or_default()
+}
+
+/// Loads every saved snapshot from localStorage
+pub async fn load_snapshots() -> Vec<ResultSnapshot> {
+    load_item(SNAPSHOTS_KEY)
+        .await
+        .map(|json| parse_snapshots(&json))
+        .unwrap_or_default()
+}
+
+/// Re-reads the saved snapshots, adds `snapshot`, and saves them. Returns the updated list.
+pub async fn add_snapshot(snapshot: ResultSnapshot) -> Vec<ResultSnapshot> {
+    let mut snapshots = load_snapshots().await;
+    snapshots.push(snapshot);
+    if let Ok(json) = serde_json::to_string(&snapshots) {
+        save_item(SNAPSHOTS_KEY, &json);
+    }
+    snapshots
+}
+
+/// Seconds since the Unix epoch on the browser's clock
+pub async fn browser_now() -> u64 {
+    document::eval("return Math.floor(Date.now() / 1000);")
+        .join::<u64>()
+        .await
+        .unwrap_or_default()
+}
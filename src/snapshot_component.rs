@@ -0,0 +1,109 @@
+use crate::chart::{ChartSeries, LineChart};
+use crate::scenario::Scenario;
+use crate::snapshot::{
+    add_snapshot, browser_now, format_date, load_snapshots, snapshot_due, snapshot_history,
+    ResultSnapshot, SNAPSHOT_INTERVAL_DAYS,
+};
+use dioxus::prelude::*;
+
+const SERIES_COLORS: &[&str] = &["#2471a3", "#1e8449", "#c0392b", "#999999"];
+
+/// Takes snapshots of a scenario's results and charts how they have changed from one snapshot to
+/// the next. Once a snapshot is `SNAPSHOT_INTERVAL_DAYS` old, it prompts for a new one.
+///
+/// Only the dollar results are charted, so they share an axis.
+#[component]
+pub fn SnapshotHistory(scenario: Scenario) -> Element {
+    let mut snapshots = use_signal(Vec::<ResultSnapshot>::new);
+    let mut error = use_signal(|| None::<String>);
+    let hover_index = use_signal(|| None::<usize>);
+    let mut now = use_signal(|| 0u64);
+
+    use_future(move || async move {
+        snapshots.set(load_snapshots().await);
+        now.set(browser_now().await);
+    });
+
+    let all_snapshots = snapshots();
+    let history = snapshot_history(&all_snapshots, &scenario.calculator, &scenario.name);
+    let due = snapshot_due(&history, now());
+    let labels: Vec<String> = history
+        .first()
+        .map(|first| {
+            first
+                .results
+                .iter()
+                .map(|(label, _)| label.clone())
+                .filter(|label| label.ends_with("($)"))
+                .collect()
+        })
+        .unwrap_or_default();
+    let series: Vec<ChartSeries> = labels
+        .iter()
+        .zip(SERIES_COLORS.iter().cycle())
+        .map(|(label, color)| ChartSeries {
+            label: label.clone(),
+            color: color.to_string(),
+            values: history
+                .iter()
+                .map(|snapshot| snapshot.result(label).unwrap_or(0.0))
+                .collect(),
+        })
+        .collect();
+    let dates: Vec<String> = history
+        .iter()
+        .map(|snapshot| format_date(snapshot.taken_at))
+        .collect();
+
+    rsx! {
+        div { style: "margin-bottom: 15px;",
+            div { style: "display: flex; align-items: center; gap: 10px; margin-bottom: 10px;",
+                button {
+                    style: "padding: 6px 12px; border-radius: 4px;",
+                    onclick: move |_| {
+                        let scenario = scenario.clone();
+                        async move {
+                            let taken_at = browser_now().await;
+                            match ResultSnapshot::take(&scenario, taken_at) {
+                                Ok(snapshot) => {
+                                    now.set(taken_at);
+                                    snapshots.set(add_snapshot(snapshot).await);
+                                    error.set(None);
+                                }
+                                Err(message) => error.set(Some(message)),
+                            }
+                        }
+                    },
+                    "Snapshot Result"
+                }
+                span { style: "color: #333; font-size: 12px;",
+                    if history.is_empty() {
+                        "Save today's result to compare against later."
+                    } else if due {
+                        "Your last snapshot is more than {SNAPSHOT_INTERVAL_DAYS} days old. Take a new one to track how this projection is changing."
+                    } else {
+                        "{history.len()} snapshots so far."
+                    }
+                }
+            }
+            if let Some(message) = error() {
+                div { style: "color: #ff0000; font-size: 12px; margin-bottom: 10px;", "{message}" }
+            }
+            if history.len() > 1 {
+                LineChart {
+                    title: "How this projection has changed across snapshots".to_string(),
+                    series,
+                    x_start: 1.0,
+                    hover_index,
+                    y_max: None,
+                    x_label: "Snapshot".to_string(),
+                }
+                div { style: "color: #333; font-size: 12px;",
+                    for (i , date) in dates.iter().enumerate() {
+                        span { style: "margin-right: 10px;", "#{i + 1}: {date}" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::npv::{compute_irr, CashFlow, CashFlowIndexing, CashFlowSeries};
+use serde::{Deserialize, Serialize};
+
+/// Ages at death each quote's return is found for
+pub const LONGEVITY_AGES: &[f64] = &[80.0, 85.0, 90.0, 95.0, 100.0];
+
+/// Oldest age searched for a quote's break-even
+const LAST_AGE: f64 = 120.0;
+
+/// One insurer's quote for a single premium immediate annuity (SPIA)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpiaQuote {
+    pub insurer: String,
+    /// Single premium paid today
+    pub premium: f64,
+    /// First year's monthly payout, starting a month after purchase
+    pub monthly_payout: f64,
+    /// Yearly raise from a cost-of-living rider as a decimal; 0 for a level payout
+    pub cola: f64,
+}
+
+/// Inputs to the annuity quote comparison
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpiaInputs {
+    /// Age the annuity is bought at
+    pub purchase_age: f64,
+    pub quotes: Vec<SpiaQuote>,
+}
+
+/// What a quote pays back, and its return if payments stop at each of `LONGEVITY_AGES`
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuoteReturns {
+    /// First year's payouts as a share of the premium
+    pub payout_rate: f64,
+    /// Age the payouts add up to the premium, ignoring interest, if by 120
+    pub break_even_age: Option<f64>,
+    /// Annual IRR at each longevity age, or `None` for ages at or before the purchase
+    pub irrs: Vec<Option<f64>>,
+}
+
+/// Monthly payout in month `month` (1 for the first), raised by the COLA each year
+fn payout(quote: &SpiaQuote, month: u32) -> f64 {
+    quote.monthly_payout * (1.0 + quote.cola).powi(((month - 1) / 12) as i32)
+}
+
+/// The premium today and each monthly payout through `months` months, by month
+pub fn quote_cash_flows(quote: &SpiaQuote, months: u32) -> CashFlowSeries {
+    let flow = |period: u32, amount: f64| CashFlow {
+        period,
+        date: String::new(),
+        amount,
+    };
+    CashFlowSeries {
+        indexing: CashFlowIndexing::Period,
+        flows: std::iter::once(flow(0, -quote.premium))
+            .chain((1..=months).map(|month| flow(month, payout(quote, month))))
+            .collect(),
+    }
+}
+
+// Compares immediate annuity quotes by the return each earns, depending on how long the buyer
+// lives.
+///
+/// # Parameters:
+/// - `inputs`: Purchase age, and each quote's premium (P), monthly payout (A), and COLA (c)
+///
+/// # Formula:
+/// payout_m = A * (1 + c)^floor((m - 1) / 12) for months m = 1..n, n = 12 * (age - purchase age)
+/// 0 = -P + Σ payout_m / (1 + i)^m, solved for the monthly IRR i with the cash-flow solver
+/// annual IRR = (1 + i)^12 - 1
+///
+/// # Returns:
+/// Each quote's payout rate, break-even age, and annual IRR at each longevity age, in quote
+/// order. An error naming the quote if its premium or payout isn't positive or its COLA is -100%
+/// or less.
+pub fn compare_quotes(inputs: &SpiaInputs) -> Result<Vec<QuoteReturns>, String> {
+    if inputs.purchase_age.is_nan() || inputs.purchase_age < 0.0 {
+        return Err("The purchase age can't be negative".to_string());
+    }
+    if inputs.quotes.is_empty() {
+        return Err("Enter at least one quote".to_string());
+    }
+
+    inputs
+        .quotes
+        .iter()
+        .enumerate()
+        .map(|(i, quote)| {
+            let name = if quote.insurer.trim().is_empty() {
+                format!("Quote {}", i + 1)
+            } else {
+                quote.insurer.trim().to_string()
+            };
+            if quote.premium.is_nan() || quote.premium <= 0.0 {
+                return Err(format!("{}: the premium must be greater than zero", name));
+            }
+            if quote.monthly_payout.is_nan() || quote.monthly_payout <= 0.0 {
+                return Err(format!("{}: the payout must be greater than zero", name));
+            }
+            if quote.cola.is_nan() || quote.cola <= -1.0 {
+                return Err(format!("{}: the COLA must be greater than -100%", name));
+            }
+
+            let last_month = ((LAST_AGE - inputs.purchase_age) * 12.0).max(0.0) as u32;
+            let mut paid = 0.0;
+            let break_even_month = (1..=last_month).find(|month| {
+                paid += payout(quote, *month);
+                paid >= quote.premium
+            });
+            let irrs = LONGEVITY_AGES
+                .iter()
+                .map(|age| {
+                    let months = ((age - inputs.purchase_age) * 12.0).round();
+                    if months < 1.0 {
+                        return None;
+                    }
+                    compute_irr(&quote_cash_flows(quote, months as u32))
+                        .ok()
+                        .map(|monthly| (1.0 + monthly).powi(12) - 1.0)
+                })
+                .collect();
+
+            Ok(QuoteReturns {
+                payout_rate: quote.monthly_payout * 12.0 / quote.premium,
+                break_even_age: break_even_month
+                    .map(|month| inputs.purchase_age + month as f64 / 12.0),
+                irrs,
+            })
+        })
+        .collect()
+}
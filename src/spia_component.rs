@@ -0,0 +1,401 @@
+use crate::analytics::use_track_calculator;
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::error_summary_component::{ErrorSummary, FieldError};
+use crate::feedback_component::FeedbackWidget;
+use crate::numeric_input_component::NumericInput;
+use crate::spia::{compare_quotes, SpiaInputs, SpiaQuote, LONGEVITY_AGES};
+use dioxus::prelude::*;
+
+/// Three quotes on a $100,000 premium at 65, the last with a 2% COLA rider
+fn default_inputs() -> SpiaInputs {
+    let quote = |insurer: &str, monthly_payout: f64, cola: f64| SpiaQuote {
+        insurer: insurer.to_string(),
+        premium: 100_000.0,
+        monthly_payout,
+        cola,
+    };
+    SpiaInputs {
+        purchase_age: 65.0,
+        quotes: vec![
+            quote("Insurer A", 620.0, 0.0),
+            quote("Insurer B", 600.0, 0.0),
+            quote("Insurer C", 480.0, 0.02),
+        ],
+    }
+}
+
+/// Age the headline compares the quotes' returns at, near a 65-year-old's life expectancy
+const HEADLINE_AGE: f64 = 90.0;
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+const LABEL_STYLE: &str = "text-align: left; padding: 2px 8px;";
+
+/// An editable column of the quotes table
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum QuoteColumn {
+    Insurer,
+    Premium,
+    Payout,
+    Cola,
+}
+
+const QUOTE_COLUMNS: &[QuoteColumn] = &[
+    QuoteColumn::Insurer,
+    QuoteColumn::Premium,
+    QuoteColumn::Payout,
+    QuoteColumn::Cola,
+];
+
+impl QuoteColumn {
+    fn name(&self) -> &'static str {
+        match self {
+            QuoteColumn::Insurer => "Insurer",
+            QuoteColumn::Premium => "Premium ($)",
+            QuoteColumn::Payout => "Monthly payout ($)",
+            QuoteColumn::Cola => "COLA (%/yr)",
+        }
+    }
+
+    /// The number in a cell, if it's one this column accepts; the insurer column takes any text
+    fn parse(&self, text: &str) -> Option<f64> {
+        let value = text.replace(",", "").trim().parse::<f64>().ok()?;
+        match self {
+            QuoteColumn::Insurer => None,
+            QuoteColumn::Premium | QuoteColumn::Payout => Some(value).filter(|v| *v > 0.0),
+            QuoteColumn::Cola => Some(value).filter(|v| *v >= 0.0),
+        }
+    }
+
+    fn is_valid(&self, text: &str) -> bool {
+        *self == QuoteColumn::Insurer || self.parse(text).is_some()
+    }
+
+    fn hint(&self) -> &'static str {
+        match self {
+            QuoteColumn::Insurer => "",
+            QuoteColumn::Premium | QuoteColumn::Payout => "enter an amount greater than zero",
+            QuoteColumn::Cola => "enter a percent of zero or more",
+        }
+    }
+
+    /// Id of a quote cell, which the error summary links to
+    fn cell_id(&self, row_id: u64) -> String {
+        let column = QUOTE_COLUMNS
+            .iter()
+            .position(|column| column == self)
+            .unwrap_or_default();
+        format!("quote-{}-{}", row_id, column)
+    }
+
+    /// The cell's text for a quote
+    fn text(&self, quote: &SpiaQuote) -> String {
+        match self {
+            QuoteColumn::Insurer => quote.insurer.clone(),
+            QuoteColumn::Premium => quote.premium.to_string(),
+            QuoteColumn::Payout => quote.monthly_payout.to_string(),
+            QuoteColumn::Cola => (quote.cola * 100.0).to_string(),
+        }
+    }
+
+    /// Stores a valid cell's text in a quote
+    fn apply(&self, quote: &mut SpiaQuote, text: &str) {
+        match (self, self.parse(text)) {
+            (QuoteColumn::Insurer, _) => quote.insurer = text.to_string(),
+            (QuoteColumn::Premium, Some(value)) => quote.premium = value,
+            (QuoteColumn::Payout, Some(value)) => quote.monthly_payout = value,
+            (QuoteColumn::Cola, Some(value)) => quote.cola = value / 100.0,
+            _ => {}
+        }
+    }
+}
+
+/// Editable quote cell; `on_value` is called with every valid entry, `on_valid` with whether
+/// each entry is valid
+#[component]
+fn QuoteCell(
+    id: String,
+    label: String,
+    initial: String,
+    column: QuoteColumn,
+    on_value: EventHandler<String>,
+    on_valid: EventHandler<bool>,
+) -> Element {
+    let mut text = use_signal(|| initial);
+    let valid = column.is_valid(&text());
+    let width = if column == QuoteColumn::Insurer {
+        "140px"
+    } else {
+        "90px"
+    };
+    let style = if valid {
+        format!(
+            "padding: 4px 6px; width: {}; border-radius: 4px; font-family: monospace; \
+             border: 1px solid #ccc;",
+            width
+        )
+    } else {
+        format!(
+            "padding: 4px 6px; width: {}; border-radius: 4px; font-family: monospace; \
+             border: 2px solid #ff0000; background-color: #ffe6e6; color: #333;",
+            width
+        )
+    };
+
+    rsx! {
+        td { style: "padding: 2px 5px;",
+            input {
+                id,
+                value: "{text}",
+                "aria-label": "{label}",
+                "aria-invalid": "{!valid}",
+                style: "{style}",
+                oninput: move |event| {
+                    text.set(event.value());
+                    let valid = column.is_valid(&event.value());
+                    on_valid.call(valid);
+                    if valid {
+                        on_value.call(event.value());
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+pub fn SpiaUI() -> Element {
+    // Each row keeps a stable id so its cells keep their text when other rows are removed
+    let mut quotes = use_signal(|| {
+        default_inputs()
+            .quotes
+            .into_iter()
+            .enumerate()
+            .map(|(i, quote)| (i as u64, quote))
+            .collect::<Vec<_>>()
+    });
+    let mut next_id = use_signal(|| 3u64);
+    let mut age_signal = use_signal(|| 65.0 as f64);
+    let mut age_input = use_signal(|| "65".to_string());
+    let age_input_valid = use_signal(|| true);
+    // Cells, as (row id, column), whose text isn't valid
+    let mut invalid_cells = use_signal(Vec::<(u64, QuoteColumn)>::new);
+
+    let inputs = move || SpiaInputs {
+        purchase_age: age_signal(),
+        quotes: quotes().into_iter().map(|(_, quote)| quote).collect(),
+    };
+    let start_fresh = use_autosave(
+        "spia",
+        default_inputs(),
+        inputs,
+        use_callback(move |saved: SpiaInputs| {
+            age_signal.set(saved.purchase_age);
+            age_input.set(format!("{}", saved.purchase_age));
+            // New ids remount the rows so their cells show the restored values
+            let first_id = *next_id.peek();
+            next_id.set(first_id + saved.quotes.len() as u64);
+            invalid_cells.set(vec![]);
+            quotes.set(
+                saved
+                    .quotes
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, quote)| (first_id + i as u64, quote))
+                    .collect(),
+            );
+        }),
+    );
+    use_track_calculator("spia");
+
+    let mut update = move |id: u64, column: QuoteColumn, text: String| {
+        if let Some((_, quote)) = quotes.write().iter_mut().find(|(row_id, _)| *row_id == id) {
+            column.apply(quote, &text);
+        }
+    };
+    let mut set_valid = move |cell: (u64, QuoteColumn), valid: bool| {
+        let mut invalid = invalid_cells.write();
+        invalid.retain(|invalid_cell| *invalid_cell != cell);
+        if !valid {
+            invalid.push(cell);
+        }
+    };
+    // Listed in table order, row by row
+    let invalid = invalid_cells();
+    let mut errors = vec![];
+    for (row, (id, _)) in quotes().iter().enumerate() {
+        for column in QUOTE_COLUMNS.iter() {
+            if invalid.contains(&(*id, *column)) {
+                errors.push(FieldError {
+                    field_id: column.cell_id(*id),
+                    message: format!("Quote {}, {}: {}", row + 1, column.name(), column.hint()),
+                });
+            }
+        }
+    }
+    let headline_index = LONGEVITY_AGES
+        .iter()
+        .position(|age| *age == HEADLINE_AGE)
+        .unwrap_or_default();
+
+    rsx! {
+        hr {}
+        br {}
+
+        ErrorSummary { errors }
+
+        NumericInput {
+            label: "Purchase Age:".to_string(),
+            placeholder: "Enter your age when you buy the annuity (e.g., 65)".to_string(),
+            input_signal: age_input,
+            value_signal: age_signal,
+            valid_signal: age_input_valid,
+            field_name: "Purchase age".to_string(),
+            css_prefix: "age".to_string(),
+            step: Some(1.0),
+            max_decimals: Some(0),
+        }
+
+        table { style: "color: #333; border-collapse: collapse; margin-bottom: 15px;",
+            thead {
+                tr {
+                    for column in QUOTE_COLUMNS.iter() {
+                        th { style: "text-align: left; padding: 2px 5px;", {column.name()} }
+                    }
+                    th {}
+                }
+            }
+            tbody {
+                for (row , (id , quote)) in quotes().into_iter().enumerate() {
+                    tr { key: "{id}",
+                        for column in QUOTE_COLUMNS.iter().copied() {
+                            QuoteCell {
+                                key: "{column.cell_id(id)}",
+                                id: column.cell_id(id),
+                                label: format!("Quote {} {}", row + 1, column.name()),
+                                initial: column.text(&quote),
+                                column,
+                                on_value: move |text: String| update(id, column, text),
+                                on_valid: move |valid| set_valid((id, column), valid),
+                            }
+                        }
+                        td {
+                            button {
+                                style: "padding: 2px 8px; border-radius: 4px;",
+                                onclick: move |_| {
+                                    quotes.write().retain(|(row_id, _)| *row_id != id);
+                                    invalid_cells.write().retain(|(row_id, _)| *row_id != id);
+                                },
+                                "Remove"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        button {
+            style: "padding: 6px 12px; border-radius: 4px; margin-bottom: 15px;",
+            onclick: move |_| {
+                let id = next_id();
+                next_id.set(id + 1);
+                let n_quotes = quotes.peek().len();
+                quotes
+                    .write()
+                    .push((
+                        id,
+                        SpiaQuote {
+                            insurer: format!("Quote {}", n_quotes + 1),
+                            premium: 100_000.0,
+                            monthly_payout: 600.0,
+                            cola: 0.0,
+                        },
+                    ));
+            },
+            "Add Quote"
+        }
+
+        {
+            match compare_quotes(&inputs()) {
+                Ok(returns) => {
+                    let best = returns
+                        .iter()
+                        .zip(quotes())
+                        .filter_map(|(result, (_, quote))| {
+                            result.irrs[headline_index].map(|irr| (irr, quote.insurer))
+                        })
+                        .max_by(|a, b| a.0.total_cmp(&b.0));
+                    let rows: Vec<(String, _)> = quotes()
+                        .into_iter()
+                        .map(|(_, quote)| quote.insurer)
+                        .zip(returns)
+                        .collect();
+                    rsx! {
+                        div {
+                            id: "SpiaCalculation",
+                            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                            if let Some((irr, insurer)) = best {
+                                "Best return if you live to {HEADLINE_AGE}: {insurer}, {irr * 100.0:.2}% a year*"
+                            } else {
+                                "No quote pays out by age {HEADLINE_AGE}*"
+                            }
+                        }
+                        table { style: "color: #333; border-collapse: collapse; font-size: 14px; margin-bottom: 15px;",
+                            thead {
+                                tr {
+                                    th { scope: "col", style: LABEL_STYLE, "Insurer" }
+                                    th { scope: "col", style: CELL_STYLE, "Payout rate" }
+                                    th { scope: "col", style: CELL_STYLE, "Break-even age" }
+                                    for age in LONGEVITY_AGES.iter() {
+                                        th { scope: "col", style: CELL_STYLE, "IRR to {age}" }
+                                    }
+                                }
+                            }
+                            tbody {
+                                for (insurer , result) in rows.into_iter() {
+                                    tr {
+                                        th { scope: "row", style: LABEL_STYLE, "{insurer}" }
+                                        td { style: CELL_STYLE, "{result.payout_rate * 100.0:.2}%" }
+                                        td { style: CELL_STYLE,
+                                            {
+                                                result
+                                                    .break_even_age
+                                                    .map(|age| format!("{:.1}", age))
+                                                    .unwrap_or_else(|| "—".to_string())
+                                            }
+                                        }
+                                        for irr in result.irrs.iter() {
+                                            td { style: CELL_STYLE,
+                                                {
+                                                    irr.map(|irr| format!("{:.2}%", irr * 100.0))
+                                                        .unwrap_or_else(|| "—".to_string())
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                Err(error) => rsx! {
+                    div {
+                        id: "SpiaCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "spia" }
+        AssumptionsFooter { calculator: "spia" }
+        div { style: "font-size: 10px",
+            p {
+                "*The yearly return the premium earns if payouts stop at that age. The longer you live, the better an annuity pays; a COLA rider starts lower and catches up late in life."
+            }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use dioxus::prelude::*;
+
+/// Reads a value from the browser's localStorage (the webview's on desktop)
+pub async fn load_item(key: &str) -> Option<String> {
+    let js = format!(
+        "return localStorage.getItem({});",
+        serde_json::to_string(key).ok()?
+    );
+    document::eval(&js)
+        .join::<Option<String>>()
+        .await
+        .ok()
+        .flatten()
+}
+
+/// Writes a value to localStorage
+pub fn save_item(key: &str, value: &str) {
+    if let (Ok(key), Ok(value)) = (serde_json::to_string(key), serde_json::to_string(value)) {
+        let _ = document::eval(&format!("localStorage.setItem({}, {});", key, value));
+    }
+}
+
+/// Deletes a value from localStorage
+pub fn remove_item(key: &str) {
+    if let Ok(key) = serde_json::to_string(key) {
+        let _ = document::eval(&format!("localStorage.removeItem({});", key));
+    }
+}
+
+/// Calls `on_change` with the new value whenever another tab or window changes `key` in
+/// localStorage (the browser's `storage` event only fires in the other tabs, never the writer).
+pub fn use_storage_listener(key: &'static str, on_change: Callback<Option<String>>) {
+    use_future(move || async move {
+        let Ok(key_json) = serde_json::to_string(key) else {
+            return;
+        };
+        let mut listener = document::eval(&format!(
+            "window.addEventListener('storage', (event) => {{
+                if (event.key === {}) {{
+                    dioxus.send(event.newValue);
+                }}
+            }});",
+            key_json
+        ));
+        while let Ok(new_value) = listener.recv::<Option<String>>().await {
+            on_change.call(new_value);
+        }
+    });
+}
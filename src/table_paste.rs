@@ -0,0 +1,149 @@
+/// A pasted line that could not be turned into a row
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasteError {
+    /// 1-based line number in the pasted text
+    pub line: usize,
+    pub message: String,
+}
+
+/// Rows parsed from a pasted spreadsheet range, and the lines that were rejected
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PastedRows<T = f64> {
+    pub rows: Vec<Vec<T>>,
+    /// 1-based line number of each row
+    pub lines: Vec<usize>,
+    pub errors: Vec<PasteError>,
+}
+
+impl<T> PastedRows<T> {
+    /// Moves every row after the first `count` to the errors, for a table with limited room
+    pub fn keep_first(&mut self, count: usize, message: &str) {
+        if self.rows.len() <= count {
+            return;
+        }
+        self.rows.truncate(count);
+        for line in self.lines.drain(count..) {
+            self.errors.push(PasteError {
+                line,
+                message: message.to_string(),
+            });
+        }
+        self.errors.sort_by_key(|error| error.line);
+    }
+
+    /// How many rows were added and which lines were not
+    pub fn report(&self) -> PasteReport {
+        PasteReport {
+            added: self.rows.len(),
+            errors: self.errors.clone(),
+        }
+    }
+}
+
+/// What a paste added to a table, for showing under the paste box
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PasteReport {
+    pub added: usize,
+    pub errors: Vec<PasteError>,
+}
+
+/// Splits one pasted line into cells. Spreadsheets copy ranges tab-delimited; lines without a
+/// tab are read as CSV, where double quotes protect commas (e.g., "10,000").
+pub fn split_pasted_line(line: &str) -> Vec<String> {
+    if line.contains('\t') {
+        return line
+            .split('\t')
+            .map(|cell| cell.trim().to_string())
+            .collect();
+    }
+    let mut cells = vec![];
+    let mut cell = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                cell.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+/// Removes currency symbols, percent signs, and thousands separators a spreadsheet may include
+fn clean_cell(cell: &str) -> String {
+    cell.chars()
+        .filter(|c| !matches!(c, '$' | '%' | ',' | ' '))
+        .collect()
+}
+
+/// Parses a pasted range into rows of `columns.len()` numbers. Blank lines are skipped, as is a
+/// first line with no numbers in it (a header row). `parse` validates each cleaned cell.
+pub fn parse_pasted_table(
+    text: &str,
+    columns: &[&str],
+    parse: impl Fn(&str) -> Option<f64>,
+) -> PastedRows {
+    parse_pasted_columns(text, columns, |_, cell| parse(cell))
+}
+
+/// Like `parse_pasted_table`, for tables whose columns hold different kinds of values (e.g., a
+/// date and an amount). `parse` is given each cleaned cell's column index along with its text.
+pub fn parse_pasted_columns<T>(
+    text: &str,
+    columns: &[&str],
+    parse: impl Fn(usize, &str) -> Option<T>,
+) -> PastedRows<T> {
+    let mut pasted = PastedRows {
+        rows: vec![],
+        lines: vec![],
+        errors: vec![],
+    };
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut cells = split_pasted_line(line);
+        while cells.len() > columns.len() && cells.last().is_some_and(|cell| cell.is_empty()) {
+            cells.pop();
+        }
+        let is_header = cells
+            .iter()
+            .all(|cell| clean_cell(cell).parse::<f64>().is_err());
+        if is_header && pasted.rows.is_empty() && pasted.errors.is_empty() {
+            continue;
+        }
+        if cells.len() != columns.len() {
+            pasted.errors.push(PasteError {
+                line: index + 1,
+                message: format!("expected {} values, found {}", columns.len(), cells.len()),
+            });
+            continue;
+        }
+        let values: Result<Vec<T>, String> = cells
+            .iter()
+            .zip(columns)
+            .enumerate()
+            .map(|(column_index, (cell, column))| {
+                parse(column_index, &clean_cell(cell))
+                    .ok_or_else(|| format!("{}: \"{}\" is not valid", column, cell))
+            })
+            .collect();
+        match values {
+            Ok(values) => {
+                pasted.rows.push(values);
+                pasted.lines.push(index + 1);
+            }
+            Err(message) => pasted.errors.push(PasteError {
+                line: index + 1,
+                message,
+            }),
+        }
+    }
+    pasted
+}
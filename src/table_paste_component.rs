@@ -0,0 +1,58 @@
+use crate::table_paste::PasteReport;
+use dioxus::prelude::*;
+
+/// Collapsible "Paste from a spreadsheet" box for a table editor. `on_paste` parses the text,
+/// adds the rows it accepts to the table, and reports back; only the rejected lines are left in
+/// the box, so the added rows aren't pasted twice.
+#[component]
+pub fn TablePasteSection(
+    /// The columns a pasted row holds, in order, as a phrase, e.g., "Balance ($) and Rate (%)"
+    columns: String,
+    /// An example row, shown in the empty box
+    placeholder: String,
+    on_paste: Callback<String, PasteReport>,
+) -> Element {
+    let mut paste_text = use_signal(String::new);
+    let mut paste_report = use_signal(|| None::<PasteReport>);
+
+    rsx! {
+        details { style: "color: #333; margin-bottom: 15px;",
+            summary { style: "cursor: pointer; font-weight: bold;", "Paste from a spreadsheet" }
+            p { style: "font-size: 12px;",
+                "Copy rows with the columns {columns} — tab- or comma-separated — and paste them below. A header row is skipped."
+            }
+            textarea {
+                rows: "5",
+                style: "width: 420px; font-family: monospace; border-radius: 4px;",
+                placeholder,
+                value: "{paste_text}",
+                oninput: move |event| paste_text.set(event.value()),
+            }
+            br {}
+            button {
+                style: "padding: 6px 12px; border-radius: 4px; margin-top: 5px;",
+                disabled: paste_text().trim().is_empty(),
+                onclick: move |_| {
+                    let text = paste_text();
+                    let report = on_paste.call(text.clone());
+                    let rejected: Vec<&str> = report
+                        .errors
+                        .iter()
+                        .filter_map(|error| text.lines().nth(error.line - 1))
+                        .collect();
+                    paste_text.set(rejected.join("\n"));
+                    paste_report.set(Some(report));
+                },
+                "Add Rows"
+            }
+            if let Some(report) = paste_report() {
+                div { style: "font-size: 12px; margin-top: 5px;",
+                    "Added {report.added} rows."
+                    for error in report.errors.iter() {
+                        div { style: "color: #ff0000;", "Line {error.line}: {error.message}" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+
+/// Federal income tax filing status
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum FilingStatus {
+    #[default]
+    Single,
+    MarriedFilingJointly,
+}
+
+impl FilingStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            FilingStatus::Single => "Single",
+            FilingStatus::MarriedFilingJointly => "Married filing jointly",
+        }
+    }
+}
+
+/// A marginal bracket: income above `floor` (up to the next bracket's floor) is taxed at `rate`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bracket {
+    pub floor: f64,
+    pub rate: f64,
+}
+
+/// Federal tax parameters for a single tax year and filing status
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TaxTable {
+    pub year: i32,
+    pub status: FilingStatus,
+    pub standard_deduction: f64,
+    pub ordinary: &'static [Bracket],
+    /// Long-term capital gains / qualified dividend brackets, stacked on top of ordinary income
+    pub capital_gains: &'static [Bracket],
+}
+
+const fn bracket(floor: f64, rate: f64) -> Bracket {
+    Bracket { floor, rate }
+}
+
+/// Federal tax tables by year, oldest first.
+pub const TAX_TABLES: &[TaxTable] = &[
+    TaxTable {
+        year: 2025,
+        status: FilingStatus::Single,
+        standard_deduction: 15_750.0,
+        ordinary: &[
+            bracket(0.0, 0.10),
+            bracket(11_925.0, 0.12),
+            bracket(48_475.0, 0.22),
+            bracket(103_350.0, 0.24),
+            bracket(197_300.0, 0.32),
+            bracket(250_525.0, 0.35),
+            bracket(626_350.0, 0.37),
+        ],
+        capital_gains: &[
+            bracket(0.0, 0.0),
+            bracket(48_350.0, 0.15),
+            bracket(533_400.0, 0.20),
+        ],
+    },
+    TaxTable {
+        year: 2025,
+        status: FilingStatus::MarriedFilingJointly,
+        standard_deduction: 31_500.0,
+        ordinary: &[
+            bracket(0.0, 0.10),
+            bracket(23_850.0, 0.12),
+            bracket(96_950.0, 0.22),
+            bracket(206_700.0, 0.24),
+            bracket(394_600.0, 0.32),
+            bracket(501_050.0, 0.35),
+            bracket(751_600.0, 0.37),
+        ],
+        capital_gains: &[
+            bracket(0.0, 0.0),
+            bracket(96_700.0, 0.15),
+            bracket(600_050.0, 0.20),
+        ],
+    },
+    TaxTable {
+        year: 2026,
+        status: FilingStatus::Single,
+        standard_deduction: 16_100.0,
+        ordinary: &[
+            bracket(0.0, 0.10),
+            bracket(12_400.0, 0.12),
+            bracket(50_400.0, 0.22),
+            bracket(105_700.0, 0.24),
+            bracket(201_775.0, 0.32),
+            bracket(256_225.0, 0.35),
+            bracket(640_600.0, 0.37),
+        ],
+        capital_gains: &[
+            bracket(0.0, 0.0),
+            bracket(49_450.0, 0.15),
+            bracket(545_500.0, 0.20),
+        ],
+    },
+    TaxTable {
+        year: 2026,
+        status: FilingStatus::MarriedFilingJointly,
+        standard_deduction: 32_200.0,
+        ordinary: &[
+            bracket(0.0, 0.10),
+            bracket(24_800.0, 0.12),
+            bracket(100_800.0, 0.22),
+            bracket(211_400.0, 0.24),
+            bracket(403_550.0, 0.32),
+            bracket(512_450.0, 0.35),
+            bracket(768_700.0, 0.37),
+        ],
+        capital_gains: &[
+            bracket(0.0, 0.0),
+            bracket(98_900.0, 0.15),
+            bracket(613_700.0, 0.20),
+        ],
+    },
+];
+
+/// Looks up the tax table for a year and filing status, falling back to the most recent year on record
+pub fn tax_table(year: i32, status: FilingStatus) -> &'static TaxTable {
+    TAX_TABLES
+        .iter()
+        .rev()
+        .filter(|table| table.status == status)
+        .find(|table| table.year <= year)
+        .or_else(|| TAX_TABLES.iter().find(|table| table.status == status))
+        .expect("every filing status has at least one tax table")
+}
+
+/// Tax on `amount` of income stacked on top of `already_taxed` income, using marginal brackets
+fn tax_in_brackets(brackets: &[Bracket], already_taxed: f64, amount: f64) -> f64 {
+    let start = already_taxed.max(0.0);
+    let end = start + amount.max(0.0);
+
+    brackets
+        .iter()
+        .enumerate()
+        .map(|(i, b)| {
+            let ceiling = brackets.get(i + 1).map_or(f64::INFINITY, |next| next.floor);
+            let taxed = end.min(ceiling) - start.max(b.floor);
+            taxed.max(0.0) * b.rate
+        })
+        .sum()
+}
+
+impl TaxTable {
+    /// Federal tax on ordinary taxable income (after deductions)
+    pub fn ordinary_tax(&self, taxable_income: f64) -> f64 {
+        tax_in_brackets(self.ordinary, 0.0, taxable_income)
+    }
+
+    /// Federal tax on long-term gains stacked on top of `ordinary_taxable_income`
+    pub fn capital_gains_tax(&self, ordinary_taxable_income: f64, gains: f64) -> f64 {
+        tax_in_brackets(self.capital_gains, ordinary_taxable_income, gains)
+    }
+
+    /// Marginal ordinary rate at a given taxable income
+    pub fn marginal_rate(&self, taxable_income: f64) -> f64 {
+        self.ordinary
+            .iter()
+            .rev()
+            .find(|b| taxable_income >= b.floor)
+            .map_or(0.0, |b| b.rate)
+    }
+
+    /// Total federal tax on gross ordinary income plus long-term gains, taking the standard deduction
+    pub fn total_tax(&self, gross_ordinary_income: f64, long_term_gains: f64) -> f64 {
+        let deduction_left = (self.standard_deduction - gross_ordinary_income).max(0.0);
+        let ordinary_taxable = (gross_ordinary_income - self.standard_deduction).max(0.0);
+        let taxable_gains = (long_term_gains - deduction_left).max(0.0);
+
+        self.ordinary_tax(ordinary_taxable)
+            + self.capital_gains_tax(ordinary_taxable, taxable_gains)
+    }
+}
@@ -0,0 +1,107 @@
+use dioxus::prelude::*;
+use serde::Deserialize;
+
+/// Theme compiled into the app; edit `theme.json` at the repository root to rebrand a build
+const BUNDLED_THEME: &str = include_str!("../theme.json");
+
+/// Fetched at startup from next to the deployed app, so a branded instance can be configured
+/// by replacing one file on the web server instead of rebuilding
+const RUNTIME_THEME_URL: &str = "theme.json";
+
+/// Branding for a white-label deployment. Missing fields fall back to the default theme.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    /// Shown in the navbar when there is no logo
+    pub brand_name: String,
+    /// URL of a logo image shown in the navbar
+    pub logo: Option<String>,
+    /// Navbar and button color
+    pub primary_color: String,
+    /// Link hover color
+    pub accent_color: String,
+    pub background_color: String,
+    /// Shown at the bottom of every page, e.g., an advisor's disclosures
+    pub footer_text: String,
+    /// Slugs of the calculators to show (see `calculators::CALCULATORS`); `None` shows all
+    pub enabled_calculators: Option<Vec<String>>,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        ThemeConfig {
+            brand_name: "dx_fintools_fs".to_string(),
+            logo: None,
+            primary_color: "#1e222d".to_string(),
+            accent_color: "#91a4d2".to_string(),
+            background_color: "gray".to_string(),
+            footer_text: String::new(),
+            enabled_calculators: None,
+        }
+    }
+}
+
+impl ThemeConfig {
+    /// Parses a theme file, reporting what is wrong with it
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid theme: {}", e))
+    }
+
+    /// The theme compiled into the app
+    pub fn bundled() -> Self {
+        Self::from_json(BUNDLED_THEME).unwrap_or_default()
+    }
+
+    pub fn calculator_enabled(&self, slug: &str) -> bool {
+        self.enabled_calculators
+            .as_ref()
+            .map_or(true, |enabled| enabled.iter().any(|s| s == slug))
+    }
+
+    /// CSS applying the theme's colors on top of main.css
+    pub fn css(&self) -> String {
+        format!(
+            ":root {{ --primary-color: {primary}; --accent-color: {accent}; }}
+             body {{ background-color: {background}; }}
+             #navbar {{ background-color: {primary}; padding: 8px; border-radius: 4px; }}
+             #navbar a:hover {{ color: {accent}; }}
+             button {{ background-color: {primary}; color: #ffffff; border: none; }}
+             button:disabled {{ opacity: 0.5; }}",
+            primary = self.primary_color,
+            accent = self.accent_color,
+            background = self.background_color,
+        )
+    }
+}
+
+/// Provides the theme: the bundled one at first, replaced by the deployed `theme.json` if the
+/// web server has one
+pub fn use_theme_provider() -> Signal<ThemeConfig> {
+    let mut theme = use_context_provider(|| Signal::new(ThemeConfig::bundled()));
+    use_future(move || async move {
+        let js = format!(
+            "try {{
+                 const response = await fetch({});
+                 return response.ok ? await response.text() : null;
+             }} catch (e) {{
+                 return null;
+             }}",
+            serde_json::to_string(RUNTIME_THEME_URL).unwrap_or_default()
+        );
+        let json = document::eval(&js).join::<Option<String>>().await;
+        if let Ok(Some(json)) = json {
+            match ThemeConfig::from_json(&json) {
+                Ok(runtime_theme) => theme.set(runtime_theme),
+                Err(error) => tracing::warn!("{}", error),
+            }
+        }
+    });
+    theme
+}
+
+/// The current theme, or the bundled one when no provider is mounted (e.g., in tests)
+pub fn use_theme() -> ThemeConfig {
+    try_use_context::<Signal<ThemeConfig>>()
+        .map(|theme| theme())
+        .unwrap_or_else(ThemeConfig::bundled)
+}
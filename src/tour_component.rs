@@ -0,0 +1,191 @@
+use crate::storage::{load_item, save_item};
+use dioxus::prelude::*;
+
+const TOUR_SEEN_KEY: &str = "fintools.tour_seen";
+
+/// One stop on the guided tour: the element to spotlight and what to say about it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TourStep {
+    /// CSS selector of the element to spotlight. When nothing matches, the step is shown in the
+    /// middle of the screen instead.
+    pub target: &'static str,
+    pub title: &'static str,
+    pub body: &'static str,
+}
+
+/// Steps every page starts with, before the page's own
+const NAVBAR_STEPS: &[TourStep] = &[TourStep {
+    target: "#navbar",
+    title: "Pick a calculator",
+    body: "Every calculator is a link up here. Your inputs are saved as you go, so you can \
+           switch and come back. Settings has the number format and can replay this tour.",
+}];
+
+/// The tour's shared state: the steps registered by the page being shown, and the step on
+/// screen, if the tour is running
+#[derive(Clone, Copy)]
+struct TourState {
+    page_steps: Signal<Option<(&'static str, Vec<TourStep>)>>,
+    active: Signal<Option<usize>>,
+}
+
+impl TourState {
+    fn steps(&self) -> Vec<TourStep> {
+        let page_steps = (self.page_steps)()
+            .map(|(_, steps)| steps)
+            .unwrap_or_default();
+        NAVBAR_STEPS.iter().copied().chain(page_steps).collect()
+    }
+}
+
+/// Provides the tour and starts it on the first visit
+pub fn use_tour_provider() {
+    let mut tour = use_context_provider(|| TourState {
+        page_steps: Signal::new(None),
+        active: Signal::new(None),
+    });
+    use_future(move || async move {
+        if load_item(TOUR_SEEN_KEY).await.is_none() {
+            tour.active.set(Some(0));
+        }
+    });
+}
+
+/// Registers the steps a page adds to the tour, after the navbar's. They are dropped when the
+/// page is left.
+pub fn use_tour_steps(page: &'static str, steps: impl FnOnce() -> Vec<TourStep>) {
+    let tour = try_use_context::<TourState>();
+    use_hook(move || {
+        if let Some(mut tour) = tour {
+            tour.page_steps.set(Some((page, steps())));
+        }
+    });
+    use_drop(move || {
+        if let Some(mut tour) = tour {
+            // The next page may have registered its steps already
+            if tour.page_steps.peek().as_ref().map(|(name, _)| *name) == Some(page) {
+                tour.page_steps.set(None);
+            }
+        }
+    });
+}
+
+/// Starts the tour from its first step
+pub fn start_tour() {
+    if let Some(mut tour) = try_consume_context::<TourState>() {
+        tour.active.set(Some(0));
+    }
+}
+
+/// Scrolls the first element matching `selector` into view and returns its position on screen
+/// as (left, top, width, height)
+async fn measure(selector: &str) -> Option<(f64, f64, f64, f64)> {
+    let selector = serde_json::to_string(selector).ok()?;
+    document::eval(&format!(
+        "const element = document.querySelector({});
+        if (!element) return null;
+        element.scrollIntoView({{ block: 'center' }});
+        const rect = element.getBoundingClientRect();
+        return [rect.left, rect.top, rect.width, rect.height];",
+        selector
+    ))
+    .join::<Option<(f64, f64, f64, f64)>>()
+    .await
+    .ok()
+    .flatten()
+}
+
+/// Marks the tour seen and closes it
+fn finish_tour(mut tour: TourState) {
+    save_item(TOUR_SEEN_KEY, "1");
+    tour.active.set(None);
+}
+
+/// The guided tour: dims the page, spotlights each step's element, and explains it in a card
+/// with Back, Next, and Skip. Finishing or skipping it marks it seen, so it only starts by
+/// itself once.
+#[component]
+pub fn Tour() -> Element {
+    let tour = try_use_context::<TourState>();
+    let spotlight = use_resource(move || async move {
+        let tour = tour?;
+        let step = tour.steps().get((tour.active)()?).copied()?;
+        measure(step.target).await
+    });
+
+    let Some(mut tour) = tour else {
+        return rsx! {};
+    };
+    let steps = tour.steps();
+    let n_steps = steps.len();
+    let Some((index, step)) =
+        (tour.active)().and_then(|index| steps.get(index).map(|step| (index, *step)))
+    else {
+        return rsx! {};
+    };
+    let is_last = index + 1 >= n_steps;
+    let (spotlight_style, card_style) = match spotlight().flatten() {
+        Some((left, top, width, height)) => (
+            format!(
+                "left: {}px; top: {}px; width: {}px; height: {}px;",
+                left - 4.0,
+                top - 4.0,
+                width + 8.0,
+                height + 8.0
+            ),
+            format!(
+                "left: {}px; top: {}px;",
+                left.max(10.0),
+                top + height + 12.0
+            ),
+        ),
+        None => (
+            "left: 50%; top: 50%; width: 0; height: 0;".to_string(),
+            "left: 50%; top: 50%; transform: translate(-50%, -50%);".to_string(),
+        ),
+    };
+
+    rsx! {
+        div {
+            style: "position: fixed; border-radius: 6px; box-shadow: 0 0 0 9999px rgba(0, 0, 0, 0.5); pointer-events: none; z-index: 1000; {spotlight_style}",
+        }
+        div {
+            role: "dialog",
+            "aria-label": "Tour",
+            style: "position: fixed; max-width: 320px; background: white; color: #333; border-radius: 6px; padding: 12px 16px; z-index: 1001; font-size: 14px; {card_style}",
+            div { style: "font-size: 12px; margin-bottom: 5px;", "Step {index + 1} of {n_steps}" }
+            div { style: "font-weight: bold; margin-bottom: 5px;", {step.title} }
+            p { style: "margin-bottom: 10px;", {step.body} }
+            div { style: "display: flex; gap: 10px;",
+                button {
+                    style: "padding: 4px 10px; border-radius: 4px;",
+                    disabled: index == 0,
+                    onclick: move |_| tour.active.set(Some(index.saturating_sub(1))),
+                    "Back"
+                }
+                button {
+                    style: "padding: 4px 10px; border-radius: 4px;",
+                    onclick: move |_| {
+                        if is_last {
+                            finish_tour(tour);
+                        } else {
+                            tour.active.set(Some(index + 1));
+                        }
+                    },
+                    if is_last {
+                        "Done"
+                    } else {
+                        "Next"
+                    }
+                }
+                if !is_last {
+                    button {
+                        style: "padding: 4px 10px; border-radius: 4px;",
+                        onclick: move |_| finish_tour(tour),
+                        "Skip Tour"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+#[cfg(feature = "bonds")]
+use crate::bond::bond_price;
+use crate::compounding::{compute_fv, compute_pv, fv_annuity, nominal_to_effective};
+#[cfg(feature = "loans")]
+use crate::loan::loan_payment;
+use crate::npv::{compute_irr, compute_npv, CashFlow, CashFlowIndexing, CashFlowSeries};
+
+/// A textbook calculation with a published answer, run against the deployed build
+#[derive(Debug, Clone, Copy)]
+pub struct VerifyCase {
+    pub name: &'static str,
+    /// Where the expected value comes from
+    pub source: &'static str,
+    pub expected: f64,
+    /// Largest difference from `expected` that still passes
+    pub tolerance: f64,
+    pub compute: fn() -> Result<f64, String>,
+}
+
+/// The outcome of one `VerifyCase`
+#[derive(Debug, Clone)]
+pub struct VerifyResult {
+    pub case: VerifyCase,
+    pub actual: Result<f64, String>,
+}
+
+impl VerifyResult {
+    pub fn passed(&self) -> bool {
+        self.actual
+            .as_ref()
+            .is_ok_and(|actual| (actual - self.case.expected).abs() <= self.case.tolerance)
+    }
+}
+
+/// A series of flows at periods 0, 1, 2, ...
+fn by_period(amounts: &[f64]) -> CashFlowSeries {
+    CashFlowSeries {
+        indexing: CashFlowIndexing::Period,
+        flows: amounts
+            .iter()
+            .enumerate()
+            .map(|(period, amount)| CashFlow {
+                period: period as u32,
+                date: String::new(),
+                amount: *amount,
+            })
+            .collect(),
+    }
+}
+
+/// The XIRR and XNPV example from the spreadsheet documentation
+fn dated_example() -> CashFlowSeries {
+    let flows = [
+        ("2008-01-01", -10_000.0),
+        ("2008-03-01", 2_750.0),
+        ("2008-10-30", 4_250.0),
+        ("2009-02-15", 3_250.0),
+        ("2009-04-01", 2_750.0),
+    ];
+    CashFlowSeries {
+        indexing: CashFlowIndexing::Date,
+        flows: flows
+            .iter()
+            .map(|(date, amount)| CashFlow {
+                period: 0,
+                date: date.to_string(),
+                amount: *amount,
+            })
+            .collect(),
+    }
+}
+
+/// Every check the verify page runs
+pub const VERIFY_CASES: &[VerifyCase] = &[
+    VerifyCase {
+        name: "Future value: $1,000 at 5% compounded annually for 10 years",
+        source: "1000 x 1.05^10",
+        expected: 1_628.89,
+        tolerance: 0.005,
+        compute: || Ok(compute_fv(1_000.0, 0.05, 1.0, 10.0)),
+    },
+    VerifyCase {
+        name: "Future value: $10,000 at 6% compounded monthly for 5 years",
+        source: "10000 x 1.005^60",
+        expected: 13_488.50,
+        tolerance: 0.005,
+        compute: || Ok(compute_fv(10_000.0, 0.06, 12.0, 5.0)),
+    },
+    VerifyCase {
+        name: "Present value: $10,000 in 10 years at 8% compounded annually",
+        source: "10000 / 1.08^10",
+        expected: 4_631.93,
+        tolerance: 0.005,
+        compute: || Ok(compute_pv(10_000.0, 0.08, 1.0, 10.0)),
+    },
+    VerifyCase {
+        name: "Annuity: $100 a month for 10 years at 6% compounded monthly",
+        source: "100 x (1.005^120 - 1) / 0.005",
+        expected: 16_387.93,
+        tolerance: 0.005,
+        compute: || Ok(fv_annuity(100.0, 0.06 / 12.0, 120.0, false)),
+    },
+    VerifyCase {
+        name: "APY of a 6% APR compounded monthly",
+        source: "1.005^12 - 1",
+        expected: 0.061_677_812,
+        tolerance: 1e-9,
+        compute: || Ok(nominal_to_effective(0.06, 12.0)),
+    },
+    VerifyCase {
+        name: "IRR of -70,000 then 12,000, 15,000, 18,000, 21,000, 26,000",
+        source: "Spreadsheet IRR documentation example",
+        expected: 0.086_630_948,
+        tolerance: 1e-8,
+        compute: || {
+            compute_irr(&by_period(&[
+                -70_000.0, 12_000.0, 15_000.0, 18_000.0, 21_000.0, 26_000.0,
+            ]))
+        },
+    },
+    VerifyCase {
+        name: "XNPV of the dated example at 9%",
+        source: "Spreadsheet XNPV documentation example",
+        expected: 2_086.647_602,
+        tolerance: 1e-5,
+        compute: || compute_npv(&dated_example(), 0.09).map(|outputs| outputs.npv),
+    },
+    VerifyCase {
+        name: "XIRR of the dated example",
+        source: "Spreadsheet XIRR documentation example",
+        expected: 0.373_362_535,
+        tolerance: 1e-8,
+        compute: || compute_irr(&dated_example()),
+    },
+    #[cfg(feature = "bonds")]
+    VerifyCase {
+        name: "Bond price: $1,000 face, 5% semiannual coupon, 10 years, 4% yield",
+        source: "25 x (1 - 1.02^-20) / 0.02 + 1000 / 1.02^20",
+        expected: 1_081.757_167,
+        tolerance: 1e-5,
+        compute: || Ok(bond_price(1_000.0, 0.05, 0.04, 10.0, 2.0)),
+    },
+    #[cfg(feature = "loans")]
+    VerifyCase {
+        name: "Mortgage payment: $200,000 over 30 years at 6%",
+        source: "200000 x 0.005 / (1 - 1.005^-360)",
+        expected: 1_199.10,
+        tolerance: 0.005,
+        compute: || Ok(loan_payment(200_000.0, 0.06, 30.0, 12.0)),
+    },
+];
+
+/// Runs every check in `VERIFY_CASES`
+pub fn run_checks() -> Vec<VerifyResult> {
+    VERIFY_CASES
+        .iter()
+        .map(|case| VerifyResult {
+            case: *case,
+            actual: (case.compute)(),
+        })
+        .collect()
+}
@@ -0,0 +1,74 @@
+use crate::build_info::build_label;
+use crate::verify::run_checks;
+use dioxus::prelude::*;
+
+const CELL_STYLE: &str = "text-align: left; padding: 2px 8px;";
+
+/// Formats a value to show every digit a check's tolerance looks at
+fn format_value(value: f64, tolerance: f64) -> String {
+    let decimals = (-tolerance.log10()).ceil().clamp(2.0, 12.0) as usize;
+    format!("{:.*}", decimals, value)
+}
+
+/// Unlisted page that runs textbook calculations through this build's math and shows whether
+/// each matches its published answer, for users and auditors checking a deployment
+#[component]
+pub fn VerifyUI() -> Element {
+    let results = run_checks();
+    let n_failed = results.iter().filter(|result| !result.passed()).count();
+
+    rsx! {
+        hr {}
+        br {}
+
+        div {
+            id: "VerifySummary",
+            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+            if n_failed == 0 {
+                "All {results.len()} checks passed"
+            } else {
+                "{n_failed} of {results.len()} checks failed"
+            }
+        }
+        div { style: "color: #333; font-size: 12px; margin-bottom: 15px;",
+            "Build {build_label()}. The checks ran in this browser, with the same code the calculators use."
+        }
+
+        table { style: "color: #333; border-collapse: collapse; font-size: 13px; margin-bottom: 15px;",
+            thead {
+                tr {
+                    th { scope: "col", style: CELL_STYLE, "Check" }
+                    th { scope: "col", style: CELL_STYLE, "Expected" }
+                    th { scope: "col", style: CELL_STYLE, "Computed" }
+                    th { scope: "col", style: CELL_STYLE, "Result" }
+                }
+            }
+            tbody {
+                for result in results.iter() {
+                    tr { key: "{result.case.name}",
+                        th { scope: "row", style: CELL_STYLE,
+                            div { {result.case.name} }
+                            div { style: "font-size: 11px; font-weight: normal;", {result.case.source} }
+                        }
+                        td { style: "{CELL_STYLE} font-family: monospace;",
+                            {format_value(result.case.expected, result.case.tolerance)}
+                        }
+                        td { style: "{CELL_STYLE} font-family: monospace;",
+                            {
+                                match &result.actual {
+                                    Ok(actual) => format_value(*actual, result.case.tolerance),
+                                    Err(error) => error.clone(),
+                                }
+                            }
+                        }
+                        if result.passed() {
+                            td { style: "{CELL_STYLE} color: #1e8449; font-weight: bold;", "Pass" }
+                        } else {
+                            td { style: "{CELL_STYLE} color: #ff0000; font-weight: bold;", "Fail" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use crate::analytics::{amount_bucket, use_track_calculator, use_track_input_range};
+use crate::assumptions_component::AssumptionsFooter;
+use crate::autosave::{use_autosave, StartFreshButton};
+use crate::business_math::{compute_wacc, WaccInputs};
+use crate::feedback_component::FeedbackWidget;
+use crate::link::{use_link_receiver, CalculatorLink, SendLinkButton};
+use crate::numeric_input_component::NumericInput;
+use dioxus::prelude::*;
+
+/// A company funded 60/40 by equity and debt, with a 21% corporate tax rate
+const DEFAULT_INPUTS: WaccInputs = WaccInputs {
+    equity_value: 600_000.0,
+    debt_value: 400_000.0,
+    cost_of_equity: 0.10,
+    cost_of_debt: 0.06,
+    tax_rate: 0.21,
+};
+
+const CELL_STYLE: &str = "text-align: right; padding: 2px 8px;";
+const LABEL_STYLE: &str = "text-align: left; padding: 2px 8px;";
+
+#[component]
+pub fn WaccUI() -> Element {
+    let mut equity_signal = use_signal(|| 600_000.0 as f64);
+    let mut equity_input = use_signal(|| "600000".to_string());
+    let equity_input_valid = use_signal(|| true);
+    let mut debt_signal = use_signal(|| 400_000.0 as f64);
+    let mut debt_input = use_signal(|| "400000".to_string());
+    let debt_input_valid = use_signal(|| true);
+    // Costs and the tax rate are entered in percent
+    let mut equity_cost_signal = use_signal(|| 10.0 as f64);
+    let mut equity_cost_input = use_signal(|| "10.0".to_string());
+    let equity_cost_input_valid = use_signal(|| true);
+    let mut debt_cost_signal = use_signal(|| 6.0 as f64);
+    let mut debt_cost_input = use_signal(|| "6.0".to_string());
+    let debt_cost_input_valid = use_signal(|| true);
+    let mut tax_signal = use_signal(|| 21.0 as f64);
+    let mut tax_input = use_signal(|| "21.0".to_string());
+    let tax_input_valid = use_signal(|| true);
+    let apply_link = use_callback(move |link: CalculatorLink| match link {
+        CalculatorLink::CostOfEquity(rate) => {
+            equity_cost_signal.set(rate * 100.0);
+            equity_cost_input.set(format!("{:.2}", rate * 100.0));
+            true
+        }
+        _ => false,
+    });
+    let received_link = use_link_receiver(apply_link);
+
+    let inputs = move || WaccInputs {
+        equity_value: equity_signal(),
+        debt_value: debt_signal(),
+        cost_of_equity: equity_cost_signal() / 100.0,
+        cost_of_debt: debt_cost_signal() / 100.0,
+        tax_rate: tax_signal() / 100.0,
+    };
+    let start_fresh = use_autosave(
+        "wacc",
+        DEFAULT_INPUTS,
+        inputs,
+        use_callback(move |inputs: WaccInputs| {
+            equity_signal.set(inputs.equity_value);
+            equity_input.set(format!("{}", inputs.equity_value));
+            debt_signal.set(inputs.debt_value);
+            debt_input.set(format!("{}", inputs.debt_value));
+            equity_cost_signal.set(inputs.cost_of_equity * 100.0);
+            equity_cost_input.set(format!("{}", inputs.cost_of_equity * 100.0));
+            debt_cost_signal.set(inputs.cost_of_debt * 100.0);
+            debt_cost_input.set(format!("{}", inputs.cost_of_debt * 100.0));
+            tax_signal.set(inputs.tax_rate * 100.0);
+            tax_input.set(format!("{}", inputs.tax_rate * 100.0));
+            if let Some(link) = received_link() {
+                apply_link.call(link);
+            }
+        }),
+    );
+    use_track_calculator("wacc");
+    use_track_input_range("wacc", "equity_value", equity_signal, amount_bucket);
+
+    rsx! {
+        hr {}
+        br {}
+
+        NumericInput {
+            label: "Equity Value ($):".to_string(),
+            placeholder: "Enter the market value of the company's shares (e.g., 600000)".to_string(),
+            input_signal: equity_input,
+            value_signal: equity_signal,
+            valid_signal: equity_input_valid,
+            field_name: "Equity value".to_string(),
+            css_prefix: "equity".to_string(),
+            step: Some(10000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Debt Value ($):".to_string(),
+            placeholder: "Enter the market value of its debt (e.g., 400000)".to_string(),
+            input_signal: debt_input,
+            value_signal: debt_signal,
+            valid_signal: debt_input_valid,
+            field_name: "Debt value".to_string(),
+            css_prefix: "debt".to_string(),
+            step: Some(10000.0),
+            max_decimals: Some(2),
+        }
+        NumericInput {
+            label: "Cost of Equity (%):".to_string(),
+            placeholder: "Enter the return shareholders require (e.g., 10.0)".to_string(),
+            input_signal: equity_cost_input,
+            value_signal: equity_cost_signal,
+            valid_signal: equity_cost_input_valid,
+            field_name: "Cost of equity".to_string(),
+            css_prefix: "equity-cost".to_string(),
+            step: Some(0.25),
+        }
+        NumericInput {
+            label: "Cost of Debt (%):".to_string(),
+            placeholder: "Enter the interest rate on the debt before tax (e.g., 6.0)".to_string(),
+            input_signal: debt_cost_input,
+            value_signal: debt_cost_signal,
+            valid_signal: debt_cost_input_valid,
+            field_name: "Cost of debt".to_string(),
+            css_prefix: "debt-cost".to_string(),
+            step: Some(0.25),
+        }
+        NumericInput {
+            label: "Tax Rate (%):".to_string(),
+            placeholder: "Enter the corporate tax rate interest is deducted at (e.g., 21.0)".to_string(),
+            input_signal: tax_input,
+            value_signal: tax_signal,
+            valid_signal: tax_input_valid,
+            field_name: "Tax rate".to_string(),
+            css_prefix: "tax".to_string(),
+            step: Some(1.0),
+        }
+
+        {
+            match compute_wacc(&inputs()) {
+                Ok(wacc) => {
+                    let equity_share = wacc.equity_weight * inputs().cost_of_equity;
+                    let debt_share = wacc.debt_weight * wacc.after_tax_cost_of_debt;
+                    rsx! {
+                        div {
+                            id: "WaccCalculation",
+                            style: "margin-bottom: 15px; font-size: 16px; font-weight: bold;",
+                            "WACC: {wacc.wacc * 100.0:.2}%*"
+                        }
+                        table { style: "color: #333; border-collapse: collapse; font-size: 14px; margin-bottom: 15px;",
+                            thead {
+                                tr {
+                                    th {}
+                                    th { scope: "col", style: CELL_STYLE, "Weight" }
+                                    th { scope: "col", style: CELL_STYLE, "Cost" }
+                                    th { scope: "col", style: CELL_STYLE, "Weighted cost" }
+                                }
+                            }
+                            tbody {
+                                tr {
+                                    th { scope: "row", style: LABEL_STYLE, "Equity" }
+                                    td { style: CELL_STYLE, "{wacc.equity_weight * 100.0:.1}%" }
+                                    td { style: CELL_STYLE, "{inputs().cost_of_equity * 100.0:.2}%" }
+                                    td { style: CELL_STYLE, "{equity_share * 100.0:.2}%" }
+                                }
+                                tr {
+                                    th { scope: "row", style: LABEL_STYLE, "Debt, after tax" }
+                                    td { style: CELL_STYLE, "{wacc.debt_weight * 100.0:.1}%" }
+                                    td { style: CELL_STYLE, "{wacc.after_tax_cost_of_debt * 100.0:.2}%" }
+                                    td { style: CELL_STYLE, "{debt_share * 100.0:.2}%" }
+                                }
+                            }
+                        }
+                        SendLinkButton { link: CalculatorLink::DiscountRate(wacc.wacc) }
+                    }
+                }
+                Err(error) => rsx! {
+                    div {
+                        id: "WaccCalculation",
+                        style: "margin-bottom: 15px; font-size: 14px; color: #ff0000;",
+                        "{error}"
+                    }
+                },
+            }
+        }
+
+        StartFreshButton { start_fresh }
+        FeedbackWidget { calculator: "wacc" }
+        AssumptionsFooter { calculator: "wacc" }
+        div { style: "font-size: 10px",
+            p {
+                "*The return the company must earn on its capital to pay both shareholders and lenders. Interest is tax-deductible, so debt costs less than its rate."
+            }
+            br {}
+            br {}
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use crate::scenario::Scenario;
+use crate::scenario_report::scenario_results;
+use serde::Serialize;
+
+/// Header carrying the signature of a webhook request
+pub const SIGNATURE_HEADER: &str = "X-Fintools-Signature";
+
+/// Oldest signature, in seconds, a receiver should accept; older requests may be replays
+pub const SIGNATURE_TOLERANCE_SECS: u64 = 300;
+
+const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A headline result of the exported scenario
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebhookResult {
+    pub name: String,
+    /// `null` in the JSON when the result can't be computed
+    pub value: Option<f64>,
+}
+
+/// Body POSTed to a webhook when a scenario is exported
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebhookPayload {
+    /// Always "scenario.exported", so one endpoint can tell future events apart
+    pub event: &'static str,
+    pub app_version: &'static str,
+    /// Seconds since the Unix epoch when the payload was built
+    pub sent_at: u64,
+    pub scenario: Scenario,
+    pub results: Vec<WebhookResult>,
+}
+
+impl WebhookPayload {
+    /// The scenario's inputs and its headline results at `sent_at`
+    pub fn new(scenario: &Scenario, sent_at: u64) -> Result<Self, String> {
+        let results = scenario_results(scenario)?
+            .into_iter()
+            .map(|(name, value)| WebhookResult {
+                name,
+                value: Some(value).filter(|value| value.is_finite()),
+            })
+            .collect();
+        Ok(WebhookPayload {
+            event: "scenario.exported",
+            app_version: APP_VERSION,
+            sent_at,
+            scenario: scenario.clone(),
+            results,
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Value of `SIGNATURE_HEADER` for `body` sent at `timestamp`: "t=<timestamp>,v1=<hex>", where
+/// the hex is HMAC-SHA256 of "<timestamp>.<body>" keyed with the shared secret. Signing the
+/// timestamp too lets receivers reject replayed requests.
+pub fn sign(secret: &str, timestamp: u64, body: &str) -> String {
+    let mac = hmac_sha256(
+        secret.as_bytes(),
+        format!("{}.{}", timestamp, body).as_bytes(),
+    );
+    format!("t={},v1={}", timestamp, to_hex(&mac))
+}
+
+/// Checks a signature header the way a receiver should: the signature must match the body, and
+/// its timestamp must be within `SIGNATURE_TOLERANCE_SECS` of `now`
+pub fn verify(secret: &str, header: &str, body: &str, now: u64) -> Result<(), String> {
+    let mut timestamp = None;
+    let mut signature = None;
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", value)) => timestamp = value.parse::<u64>().ok(),
+            Some(("v1", value)) => signature = Some(value),
+            _ => {}
+        }
+    }
+    let timestamp = timestamp.ok_or("The signature has no timestamp")?;
+    let signature = signature.ok_or("The signature has no v1 value")?;
+    if now.abs_diff(timestamp) > SIGNATURE_TOLERANCE_SECS {
+        return Err("The signature has expired".to_string());
+    }
+    let expected = sign(secret, timestamp, body);
+    let expected = &expected[expected.rfind('=').map_or(0, |i| i + 1)..];
+    if !constant_time_eq(expected.as_bytes(), signature.as_bytes()) {
+        return Err("The signature does not match".to_string());
+    }
+    Ok(())
+}
+
+/// Compares without stopping at the first difference, so timing doesn't reveal the signature
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// HMAC-SHA256 (RFC 2104)
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK: usize = 64;
+    let mut block_key = [0u8; BLOCK];
+    if key.len() > BLOCK {
+        block_key[..32].copy_from_slice(&sha256(key));
+    } else {
+        block_key[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Vec::with_capacity(BLOCK + message.len());
+    inner.extend(block_key.iter().map(|byte| byte ^ 0x36));
+    inner.extend_from_slice(message);
+    let mut outer = Vec::with_capacity(BLOCK + 32);
+    outer.extend(block_key.iter().map(|byte| byte ^ 0x5c));
+    outer.extend_from_slice(&sha256(&inner));
+    sha256(&outer)
+}
+
+/// SHA-256 (FIPS 180-4). Written out here, like the simulation RNG, so signing needs no crate.
+pub fn sha256(message: &[u8]) -> [u8; 32] {
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4,
+        0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe,
+        0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f,
+        0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7,
+        0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc,
+        0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
+        0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070, 0x19a4c116,
+        0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+    let mut state: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    // Pad with a 1 bit, zeros, and the message length in bits to a multiple of 64 bytes
+    let mut data = message.to_vec();
+    data.push(0x80);
+    while data.len() % 64 != 56 {
+        data.push(0);
+    }
+    data.extend_from_slice(&((message.len() as u64) * 8).to_be_bytes());
+
+    for chunk in data.chunks(64) {
+        let mut w = [0u32; 64];
+        for (i, word) in chunk.chunks(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let choice = (e & f) ^ (!e & g);
+            let temp1 = h
+                .wrapping_add(s1)
+                .wrapping_add(choice)
+                .wrapping_add(K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let majority = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(majority);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+        for (value, add) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *value = value.wrapping_add(add);
+        }
+    }
+
+    let mut digest = [0u8; 32];
+    for (bytes, word) in digest.chunks_mut(4).zip(state) {
+        bytes.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
@@ -0,0 +1,87 @@
+use crate::error_summary_component::{focus_element, ErrorSummary, FieldError, ERROR_SUMMARY_ID};
+use dioxus::prelude::*;
+
+/// Step-by-step flow for calculators with many inputs: one question per screen, a progress bar,
+/// and Back/Next buttons. The caller renders the current step's inputs as `children`, so the
+/// wizard fills the same signals as the regular form. Next stays enabled: pressing it with
+/// problems in the step lists them in an error summary and moves focus there.
+#[component]
+pub fn Wizard(
+    /// Title of each step, in order
+    titles: Vec<String>,
+    /// Index of the step being shown
+    step: Signal<usize>,
+    /// Validation problems in the current step's inputs; Next won't move on until there are none
+    errors: Vec<FieldError>,
+    /// Called when Next is pressed on the last step
+    on_finish: EventHandler<()>,
+    children: Element,
+) -> Element {
+    let n_steps = titles.len();
+    let current = step().min(n_steps.saturating_sub(1));
+    let progress = (current + 1) as f64 / n_steps.max(1) as f64 * 100.0;
+    let is_last = current + 1 >= n_steps;
+    // The summary appears only once Next has been pressed on this step, then tracks the fixes
+    let mut attempted = use_signal(|| None::<usize>);
+    // Focus the summary after it renders, so screen readers read it out
+    use_effect(move || {
+        if attempted().is_some() {
+            focus_element(ERROR_SUMMARY_ID);
+        }
+    });
+    let can_advance = errors.is_empty();
+    let shown_errors = if attempted() == Some(current) {
+        errors
+    } else {
+        vec![]
+    };
+
+    rsx! {
+        div { style: "max-width: 600px; margin-bottom: 15px;",
+            div { style: "color: #333; font-size: 12px; margin-bottom: 5px;",
+                "Step {current + 1} of {n_steps}"
+            }
+            div { style: "background: #b0b0b0; border-radius: 9999px; height: 6px; margin-bottom: 15px;",
+                div { style: "background: #1e222d; border-radius: 9999px; height: 6px; width: {progress}%;" }
+            }
+            div { style: "color: #333; font-size: 18px; font-weight: bold; margin-bottom: 15px;",
+                {titles.get(current).cloned().unwrap_or_default()}
+            }
+
+            ErrorSummary { errors: shown_errors }
+
+            {children}
+
+            div { style: "display: flex; gap: 10px;",
+                button {
+                    style: "padding: 6px 12px; border-radius: 4px;",
+                    disabled: current == 0,
+                    onclick: move |_| {
+                        attempted.set(None);
+                        step.set(current.saturating_sub(1));
+                    },
+                    "Back"
+                }
+                button {
+                    style: "padding: 6px 12px; border-radius: 4px;",
+                    onclick: move |_| {
+                        if !can_advance {
+                            attempted.set(Some(current));
+                        } else if is_last {
+                            attempted.set(None);
+                            on_finish.call(());
+                        } else {
+                            attempted.set(None);
+                            step.set(current + 1);
+                        }
+                    },
+                    if is_last {
+                        "See Results"
+                    } else {
+                        "Next"
+                    }
+                }
+            }
+        }
+    }
+}
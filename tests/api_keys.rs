@@ -0,0 +1,110 @@
+//! API key and rate limit tests. The app is a single binary crate, so the module is compiled in
+//! directly rather than imported.
+
+#[path = "../src/api_keys.rs"]
+#[allow(dead_code)]
+mod api_keys;
+
+use api_keys::{config_entry, generate_key, ApiKeyError, ApiKeyStore, RateLimit, KEY_PREFIX};
+use std::time::{Duration, Instant};
+
+const KEY: &str = "fvk_00112233445566778899aabbccddeeff";
+
+fn store(requests_per_minute: u32) -> ApiKeyStore {
+    let mut store = ApiKeyStore::default();
+    store.add(KEY, "partner", RateLimit::per_minute(requests_per_minute));
+    store
+}
+
+#[test]
+fn generated_keys_are_prefixed_hex() {
+    let key = generate_key([0xAB; 16]);
+
+    assert!(key.starts_with(KEY_PREFIX));
+    assert_eq!(key.len(), KEY_PREFIX.len() + 32);
+    assert!(key[KEY_PREFIX.len()..]
+        .chars()
+        .all(|c| c.is_ascii_hexdigit()));
+}
+
+#[test]
+fn issued_config_entries_parse_back() {
+    let key = generate_key([7; 16]);
+    let store = ApiKeyStore::parse(&config_entry("crm", &key, 30)).unwrap();
+
+    assert_eq!(store.len(), 1);
+    assert_eq!(store.label(&key), Some("crm"));
+}
+
+#[test]
+fn config_accepts_commas_newlines_and_default_limits() {
+    let config = format!("a:{}\n\nb:{}:10, ", KEY, generate_key([1; 16]));
+    let store = ApiKeyStore::parse(&config).unwrap();
+
+    assert_eq!(store.len(), 2);
+    assert!(ApiKeyStore::parse("").unwrap().is_empty());
+}
+
+#[test]
+fn config_rejects_malformed_entries() {
+    assert!(ApiKeyStore::parse("just-a-key").is_err());
+    assert!(ApiKeyStore::parse(&format!("a:{}:0", KEY)).is_err());
+    assert!(ApiKeyStore::parse(&format!("a:{}:lots", KEY)).is_err());
+    assert!(ApiKeyStore::parse("a:not-an-issued-key").is_err());
+}
+
+#[test]
+fn missing_and_unknown_keys_are_refused() {
+    let mut store = store(60);
+    let now = Instant::now();
+
+    assert_eq!(store.check(None, now), Err(ApiKeyError::Missing));
+    assert_eq!(store.check(Some("  "), now), Err(ApiKeyError::Missing));
+    assert_eq!(
+        store.check(Some("fvk_nope"), now),
+        Err(ApiKeyError::Unknown)
+    );
+}
+
+#[test]
+fn keys_can_burst_to_their_limit_then_wait() {
+    let mut store = store(3);
+    let start = Instant::now();
+
+    assert_eq!(store.check(Some(KEY), start), Ok(2));
+    assert_eq!(store.check(Some(KEY), start), Ok(1));
+    assert_eq!(store.check(Some(KEY), start), Ok(0));
+    match store.check(Some(KEY), start) {
+        Err(ApiKeyError::RateLimited { retry_after }) => {
+            // 3 a minute refills one request every 20 seconds
+            assert!((retry_after.as_secs_f64() - 20.0).abs() < 1e-6);
+        }
+        other => panic!("expected a rate limit, got {:?}", other),
+    }
+    assert_eq!(
+        store.check(Some(KEY), start + Duration::from_secs(20)),
+        Ok(0)
+    );
+}
+
+#[test]
+fn requests_refill_up_to_the_limit_only() {
+    let mut store = store(3);
+    let start = Instant::now();
+    store.check(Some(KEY), start).unwrap();
+
+    let much_later = start + Duration::from_secs(3600);
+    assert_eq!(store.check(Some(KEY), much_later), Ok(2));
+}
+
+#[test]
+fn each_key_has_its_own_limit() {
+    let mut store = store(1);
+    let other = generate_key([9; 16]);
+    store.add(&other, "other", RateLimit::per_minute(1));
+    let now = Instant::now();
+
+    assert!(store.check(Some(KEY), now).is_ok());
+    assert!(store.check(Some(KEY), now).is_err());
+    assert!(store.check(Some(&other), now).is_ok());
+}
@@ -0,0 +1,2876 @@
+//! Unit tests for the pure compute functions behind each calculator component. The app is a
+//! single binary crate, so the modules are compiled in directly rather than imported.
+
+#[path = "../src/account_types.rs"]
+#[allow(dead_code)]
+mod account_types;
+#[path = "../src/amortization.rs"]
+#[allow(dead_code)]
+mod amortization;
+#[path = "../src/annuity.rs"]
+#[allow(dead_code)]
+mod annuity;
+#[path = "../src/apr_apy.rs"]
+#[allow(dead_code)]
+mod apr_apy;
+#[path = "../src/audit.rs"]
+#[allow(dead_code)]
+mod audit;
+#[path = "../src/bond.rs"]
+#[allow(dead_code)]
+mod bond;
+#[path = "../src/business_math.rs"]
+#[allow(dead_code)]
+mod business_math;
+#[path = "../src/cd.rs"]
+#[allow(dead_code)]
+mod cd;
+#[path = "../src/charitable.rs"]
+#[allow(dead_code)]
+mod charitable;
+#[path = "../src/closing_costs.rs"]
+#[allow(dead_code)]
+mod closing_costs;
+#[path = "../src/compounding.rs"]
+#[allow(dead_code)]
+mod compounding;
+#[path = "../src/contribution_limits.rs"]
+#[allow(dead_code)]
+mod contribution_limits;
+#[path = "../src/data_year.rs"]
+#[allow(dead_code)]
+mod data_year;
+#[path = "../src/debt_payoff.rs"]
+#[allow(dead_code)]
+mod debt_payoff;
+#[path = "../src/fin_numeric.rs"]
+#[allow(dead_code)]
+mod fin_numeric;
+#[path = "../src/future_value.rs"]
+#[allow(dead_code)]
+mod future_value;
+#[path = "../src/glide_path.rs"]
+#[allow(dead_code)]
+mod glide_path;
+#[path = "../src/healthcare.rs"]
+#[allow(dead_code)]
+mod healthcare;
+#[path = "../src/inflation_scenarios.rs"]
+#[allow(dead_code)]
+mod inflation_scenarios;
+#[path = "../src/lease.rs"]
+#[allow(dead_code)]
+mod lease;
+#[path = "../src/loan.rs"]
+#[allow(dead_code)]
+mod loan;
+#[path = "../src/long_term_care.rs"]
+#[allow(dead_code)]
+mod long_term_care;
+#[path = "../src/monte_carlo.rs"]
+#[allow(dead_code)]
+mod monte_carlo;
+#[path = "../src/npv.rs"]
+#[allow(dead_code)]
+mod npv;
+#[path = "../src/options.rs"]
+#[allow(dead_code)]
+mod options;
+#[path = "../src/paycheck.rs"]
+#[allow(dead_code)]
+mod paycheck;
+#[path = "../src/present_value.rs"]
+#[allow(dead_code)]
+mod present_value;
+#[path = "../src/rate_shock.rs"]
+#[allow(dead_code)]
+mod rate_shock;
+#[path = "../src/rates.rs"]
+#[allow(dead_code)]
+mod rates;
+#[path = "../src/retirement.rs"]
+#[allow(dead_code)]
+mod retirement;
+#[path = "../src/retirement_income.rs"]
+#[allow(dead_code)]
+mod retirement_income;
+#[path = "../src/returns_stats.rs"]
+#[allow(dead_code)]
+mod returns_stats;
+#[path = "../src/savings_bond.rs"]
+#[allow(dead_code)]
+mod savings_bond;
+#[path = "../src/self_employment.rs"]
+#[allow(dead_code)]
+mod self_employment;
+#[path = "../src/sequence_risk.rs"]
+#[allow(dead_code)]
+mod sequence_risk;
+#[path = "../src/simulation.rs"]
+#[allow(dead_code)]
+mod simulation;
+#[path = "../src/sinking_fund.rs"]
+#[allow(dead_code)]
+mod sinking_fund;
+#[path = "../src/spia.rs"]
+#[allow(dead_code)]
+mod spia;
+#[path = "../src/storage.rs"]
+#[allow(dead_code)]
+mod storage;
+#[path = "../src/table_paste.rs"]
+#[allow(dead_code)]
+mod table_paste;
+#[path = "../src/tax.rs"]
+#[allow(dead_code)]
+mod tax;
+#[path = "../src/verify.rs"]
+#[allow(dead_code)]
+mod verify;
+
+use account_types::AccountSplit;
+use amortization::{
+    amortization_schedule, escrow_analysis, payoff_extra_payment, AmortizationInputs, EscrowInputs,
+};
+use annuity::{
+    fv_escalating_contributions, fv_growing_annuity, pv_growing_annuity, pv_growing_perpetuity,
+    pv_perpetuity, savings_goal_payment, solve_annuity, solve_savings_goal, value_perpetuity,
+    AnnuityInputs, AnnuityUnknown, PerpetuityInputs, SavingsGoalInputs,
+};
+use apr_apy::{convert_rate, AprApyInputs, RateKind};
+use audit::{audit_future_value, audit_required_rate, audit_required_years};
+use bond::{
+    bond_price, bond_price_shocks, ladder_calendar, ladder_yield, modified_duration, price_bond,
+    BondPriceInputs, LadderBond,
+};
+use business_math::{
+    capm_expected_return, compute_unit_break_even, compute_wacc, cost_revenue_curves, CapmInputs,
+    UnitBreakEvenInputs, WaccInputs,
+};
+use cd::{compute_cd, CdInputs};
+use charitable::{compute_charitable_benefit, CharitableInputs};
+use closing_costs::{estimate_closing_costs, ClosingCostInputs, CLOSING_COST_PRESETS};
+use compounding::{
+    annuity_payment_for_fv, annuity_payment_for_pv, fv_annuity, pv_annuity, Compounding,
+    ComputeError, ContributionTiming,
+};
+use contribution_limits::LimitedAccount;
+use data_year::{data_year_notice, find_data_set, DataYearNotice};
+use debt_payoff::{payoff_order, simulate_payoff, Debt, PayoffStrategy};
+use future_value::{compute_future_value, FutureValueInputs};
+use glide_path::{GlidePath, GlidePoint};
+use healthcare::{medicare_premiums, HealthcareInputs};
+use inflation_scenarios::{run_inflation_scenarios, InflationScenarios};
+use lease::{apr_to_money_factor, compare_lease_vs_buy, money_factor_to_apr, LeaseVsBuyInputs};
+use loan::{
+    apr_with_costs, loan_offer_costs, loan_payment, loan_payment_shocks, lowest_offer, LoanOffer,
+    PrepaymentPenalty,
+};
+use long_term_care::{project_long_term_care, CareRate, LongTermCareInputs, DEFAULT_CARE_RATES};
+use monte_carlo::{simulate_retirement, MonteCarloInputs};
+use npv::{
+    compute_dcf, compute_irr, compute_npv, parse_date, CashFlow, CashFlowIndexing, CashFlowSeries,
+    DcfInputs,
+};
+use options::{
+    binomial_prices, black_scholes, normal_cdf, price_lattice, BinomialInputs, Exercise,
+    OptionInputs,
+};
+use paycheck::{
+    compute_bonus_tax, compute_paycheck, supplemental_withholding, BonusInputs, Earner,
+    PaycheckInputs,
+};
+use present_value::{compute_present_value, PresentValueInputs};
+use rates::{break_even_inflation, compute_break_even, BreakEvenInputs, BreakEvenWinner};
+use retirement::{accumulation_schedule, RetirementInputs};
+use retirement_income::{project_retirement_income, RetirementIncomeInputs};
+use returns_stats::{compute_return_stats, parse_return_series};
+use savings_bond::{ee_bond_value, ee_redemption_value};
+use self_employment::{compare_w2_and_contractor, ContractorComparisonInputs};
+use sequence_risk::{compute_sequence_risk, SequenceRiskInputs};
+use simulation::{allocation_presets, Allocation, AssetClass, ReturnModel, Rng};
+use sinking_fund::sinking_fund_schedule;
+use spia::{compare_quotes, quote_cash_flows, SpiaInputs, SpiaQuote};
+use tax::{tax_table, FilingStatus};
+
+fn fv_inputs() -> FutureValueInputs {
+    FutureValueInputs {
+        principal: 1000.0,
+        target_value: 2000.0,
+        annual_rate: 0.03875,
+        years: 7.0,
+        compounding: Compounding::Annual,
+        monthly_contribution: 0.0,
+        contribution_timing: ContributionTiming::End,
+        inflation_rate: 0.0,
+    }
+}
+
+#[test]
+fn future_value_defaults() {
+    let outputs = compute_future_value(&fv_inputs());
+    assert_eq!(outputs.future_value, Ok(1304.90));
+}
+
+#[test]
+fn required_rate_reaches_target() {
+    let inputs = fv_inputs();
+    let outputs = compute_future_value(&inputs);
+    let reached =
+        compounding::compute_fv(inputs.principal, outputs.required_rate, 1.0, inputs.years);
+    assert!((reached - inputs.target_value).abs() <= 0.01);
+}
+
+#[test]
+fn required_years_reaches_target() {
+    let inputs = FutureValueInputs {
+        compounding: Compounding::Monthly,
+        ..fv_inputs()
+    };
+    let outputs = compute_future_value(&inputs);
+    let reached = compounding::compute_fv(
+        inputs.principal,
+        inputs.annual_rate,
+        12.0,
+        outputs.required_years,
+    );
+    assert!((reached - inputs.target_value).abs() <= 0.01);
+}
+
+#[test]
+fn future_value_rejects_negative_years() {
+    let outputs = compute_future_value(&FutureValueInputs {
+        years: -1.0,
+        ..fv_inputs()
+    });
+    assert!(matches!(
+        outputs.future_value,
+        Err(ComputeError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn contributions_follow_the_annuity_formula() {
+    let end = compounding::compute_fv_with_contributions(
+        0.0,
+        100.0,
+        0.12,
+        12.0,
+        12.0,
+        1.0,
+        ContributionTiming::End,
+    );
+    assert_eq!(end, 1268.25);
+
+    let begin = compounding::compute_fv_with_contributions(
+        0.0,
+        100.0,
+        0.12,
+        12.0,
+        12.0,
+        1.0,
+        ContributionTiming::Begin,
+    );
+    assert_eq!(begin, 1280.93);
+
+    let no_interest = compounding::compute_fv_with_contributions(
+        500.0,
+        100.0,
+        0.0,
+        4.0,
+        12.0,
+        2.0,
+        ContributionTiming::End,
+    );
+    assert_eq!(no_interest, 2900.0);
+}
+
+#[test]
+fn future_value_adds_monthly_contributions() {
+    let lump_sum = compute_future_value(&fv_inputs()).future_value.unwrap();
+    let with_deposits = compute_future_value(&FutureValueInputs {
+        monthly_contribution: 100.0,
+        ..fv_inputs()
+    })
+    .future_value
+    .unwrap();
+    // 84 deposits of $100, each earning some interest
+    assert!(with_deposits > lump_sum + 8400.0);
+
+    let negative = compute_future_value(&FutureValueInputs {
+        monthly_contribution: -100.0,
+        ..fv_inputs()
+    });
+    assert!(matches!(
+        negative.future_value,
+        Err(ComputeError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn real_future_value_takes_out_inflation() {
+    // $1,000 of future dollars, 10 years of 3% inflation
+    assert_eq!(compounding::compute_real_fv(1000.0, 0.03, 10.0), 744.09);
+    assert_eq!(compounding::compute_real_fv(1000.0, 0.0, 10.0), 1000.0);
+
+    let outputs = compute_future_value(&FutureValueInputs {
+        inflation_rate: 0.03875,
+        ..fv_inputs()
+    });
+    // Growing at exactly the inflation rate keeps the principal's buying power
+    assert_eq!(outputs.future_value, Ok(1304.90));
+    assert!((outputs.real_future_value.unwrap() - 1000.0).abs() <= 0.01);
+
+    let no_inflation = compute_future_value(&fv_inputs());
+    assert_eq!(no_inflation.real_future_value, no_inflation.future_value);
+
+    let impossible = compute_future_value(&FutureValueInputs {
+        inflation_rate: -1.0,
+        ..fv_inputs()
+    });
+    assert!(matches!(
+        impossible.real_future_value,
+        Err(ComputeError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn present_value_grows_back_to_target() {
+    let inputs = PresentValueInputs {
+        target_value: 2000.0,
+        annual_rate: 0.03875,
+        years: 7.0,
+        compounding: Compounding::Monthly,
+    };
+    let pv = compute_present_value(&inputs).unwrap();
+    let reached = compounding::compute_fv(pv, inputs.annual_rate, 12.0, inputs.years);
+    assert!((reached - inputs.target_value).abs() <= 0.02);
+}
+
+#[test]
+fn present_value_rejects_negative_years() {
+    let pv = compute_present_value(&PresentValueInputs {
+        target_value: 2000.0,
+        annual_rate: 0.04,
+        years: -1.0,
+        compounding: Compounding::Annual,
+    });
+    assert!(matches!(pv, Err(ComputeError::InvalidInput(_))));
+}
+
+#[test]
+fn years_months_formatting() {
+    assert_eq!(compounding::format_years_months(7.5), "7 years, 6 months");
+    assert_eq!(
+        compounding::format_years_months(1.0 + 1.0 / 12.0),
+        "1 year, 1 month"
+    );
+}
+
+#[test]
+fn sequence_risk_orders_matter_but_averages_do_not() {
+    let outputs = compute_sequence_risk(&SequenceRiskInputs {
+        starting_balance: 1_000_000.0,
+        annual_withdrawal: 50_000.0,
+        average_return: 0.06,
+        spread: 0.15,
+        years: 30,
+    });
+
+    assert_eq!(outputs.bad_first_path.len(), 31);
+    assert!((outputs.arithmetic_mean - 0.06).abs() < 1e-12);
+    assert_eq!(
+        sequence_risk::depletion_year(&outputs.bad_first_path),
+        Some(15)
+    );
+    assert_eq!(
+        sequence_risk::depletion_year(&outputs.good_first_path),
+        None
+    );
+}
+
+#[test]
+fn audit_record_reproduces_the_future_value() {
+    let record = audit_future_value(&fv_inputs());
+
+    assert_eq!(record.result, Some(1304.90));
+    let unrounded = record
+        .intermediate_values
+        .iter()
+        .find(|value| value.name == "P(1 + r/n)^(nt) (before truncation)")
+        .unwrap();
+    assert_eq!((unrounded.value * 100.0).trunc() / 100.0, 1304.90);
+
+    let json: serde_json::Value = serde_json::from_str(&record.to_json()).unwrap();
+    assert_eq!(json["inputs"]["principal"], 1000.0);
+    assert_eq!(json["math_core_version"], audit::MATH_CORE_VERSION);
+}
+
+#[test]
+fn required_rate_includes_monthly_contributions() {
+    let inputs = FutureValueInputs {
+        target_value: 20_000.0,
+        years: 10.0,
+        compounding: Compounding::Monthly,
+        monthly_contribution: 100.0,
+        ..fv_inputs()
+    };
+    let rate = compute_future_value(&inputs).required_rate;
+    let reached = compounding::compute_fv_with_contributions(
+        1000.0,
+        100.0,
+        rate,
+        12.0,
+        12.0,
+        10.0,
+        ContributionTiming::End,
+    );
+
+    assert!((reached - 20_000.0).abs() <= 0.01, "reached {}", reached);
+    assert!((rate - 0.075791).abs() < 1e-5, "rate was {}", rate);
+
+    let record = audit_required_rate(&inputs);
+    assert_eq!(record.result, Some(rate));
+    assert_eq!(record.formulas.len(), 2);
+}
+
+#[test]
+fn required_rate_is_nan_when_no_rate_reaches_the_target() {
+    let rate = compounding::solve_rate_with_contributions(
+        1000.0,
+        100.0,
+        1e12,
+        12.0,
+        12.0,
+        1.0,
+        ContributionTiming::End,
+    );
+
+    assert!(rate.is_nan());
+}
+
+#[test]
+fn audit_record_of_an_impossible_rate_has_no_result() {
+    let record = audit_required_rate(&FutureValueInputs {
+        principal: 0.0,
+        ..fv_inputs()
+    });
+
+    assert_eq!(record.result, None);
+}
+
+#[test]
+fn bond_at_par_prices_at_face_value() {
+    let price = bond_price(1000.0, 0.05, 0.05, 10.0, 2.0);
+    assert!((price - 1000.0).abs() < 1e-9);
+
+    let duration = modified_duration(1000.0, 0.05, 0.05, 10.0, 2.0);
+    assert!((duration - 7.79).abs() < 0.01, "duration was {}", duration);
+}
+
+#[test]
+fn bond_shocks_show_convexity() {
+    let inputs = BondPriceInputs {
+        face_value: 1000.0,
+        coupon_rate: 0.05,
+        frequency: 2.0,
+        yield_rate: 0.05,
+        years_to_maturity: 10.0,
+    };
+    let shocks = bond_price_shocks(&inputs, &[-100, 100]).unwrap();
+
+    let duration = modified_duration(1000.0, 0.05, 0.05, 10.0, 2.0);
+    for shock in &shocks {
+        // Duration underestimates the price in both directions
+        assert!(shock.exact > shock.approximate);
+        assert!((shock.exact - shock.approximate).abs() < 5.0);
+        let estimate = 1000.0 * (1.0 - duration * shock.shift_bp as f64 / 10_000.0);
+        assert!((shock.approximate - estimate).abs() < 1e-3);
+    }
+    assert!((shocks[1].exact - 925.61).abs() < 0.01);
+
+    // Between coupon dates, the shocks start from the dirty price
+    let between = BondPriceInputs {
+        years_to_maturity: 9.75,
+        ..inputs
+    };
+    let shocks = bond_price_shocks(&between, &[100]).unwrap();
+    let pricing = price_bond(&between).unwrap();
+    assert!((shocks[0].base - pricing.dirty_price).abs() < 1e-9);
+    let shocked = price_bond(&BondPriceInputs {
+        yield_rate: 0.06,
+        ..between
+    })
+    .unwrap();
+    assert!((shocks[0].exact - shocked.dirty_price).abs() < 1e-9);
+    // Duration is the slope of the dirty price, so a tiny shift is estimated almost exactly
+    let tiny = bond_price_shocks(&between, &[1]).unwrap();
+    assert!((tiny[0].exact - tiny[0].approximate).abs() < 1e-3);
+}
+
+#[test]
+fn loan_payment_and_shocks() {
+    let payment = loan_payment(200_000.0, 0.06, 30.0, 12.0);
+    assert!((payment - 1199.10).abs() < 0.01);
+
+    let shocks = loan_payment_shocks(200_000.0, 0.06, 30.0, 12.0, &[100]);
+    assert!((shocks[0].exact - loan_payment(200_000.0, 0.07, 30.0, 12.0)).abs() < 1e-9);
+    assert!(shocks[0].approximate > payment);
+}
+
+#[test]
+fn tips_and_nominal_tie_at_break_even_inflation() {
+    let break_even = break_even_inflation(0.045, 0.02);
+    assert!((break_even - 0.0245098).abs() < 1e-6);
+
+    let outputs = compute_break_even(&BreakEvenInputs {
+        amount: 10_000.0,
+        years: 10.0,
+        nominal_yield: 0.045,
+        real_yield: 0.02,
+        expected_inflation: break_even,
+    });
+    assert_eq!(outputs.winner, BreakEvenWinner::Tie);
+    assert!((outputs.tips_return - 0.045).abs() < 1e-12);
+}
+
+#[test]
+fn ladder_calendar_combines_coupons_and_maturities() {
+    let ladder = [
+        LadderBond {
+            face_value: 10_000.0,
+            coupon_rate: 0.04,
+            years_to_maturity: 1.0,
+            price: 100.0,
+        },
+        LadderBond {
+            face_value: 10_000.0,
+            coupon_rate: 0.05,
+            years_to_maturity: 2.0,
+            price: 100.0,
+        },
+    ];
+
+    let calendar = ladder_calendar(&ladder);
+    assert_eq!(calendar.len(), 2);
+    assert!((calendar[0].coupons - 900.0).abs() < 1e-9);
+    assert!((calendar[0].principal - 10_000.0).abs() < 1e-9);
+    assert!((calendar[1].coupons - 500.0).abs() < 1e-9);
+
+    // A single bond bought at par yields its coupon
+    let single_yield = ladder_yield(&ladder[1..]).unwrap();
+    assert!((single_yield - 0.05).abs() < 1e-9);
+    let both = ladder_yield(&ladder).unwrap();
+    assert!(both > 0.04 && both < 0.05);
+}
+
+#[test]
+fn ee_bonds_double_at_twenty_years() {
+    // 2.7% alone would reach only about $1,708 after 20 years
+    assert!(ee_bond_value(1000.0, 0.027, 239) < 1710.0);
+    assert_eq!(ee_bond_value(1000.0, 0.027, 240), 2000.0);
+    // The bond keeps earning its fixed rate after the adjustment, until 30 years
+    let at_thirty = 2000.0 * (1.0 + 0.027 / 2.0_f64).powf(20.0);
+    assert!((ee_bond_value(1000.0, 0.027, 360) - at_thirty).abs() < 1e-9);
+    assert_eq!(
+        ee_bond_value(1000.0, 0.027, 400),
+        ee_bond_value(1000.0, 0.027, 360)
+    );
+}
+
+#[test]
+fn ee_bonds_cashed_early_lose_three_months_of_interest() {
+    assert_eq!(ee_redemption_value(1000.0, 0.027, 11), None);
+    assert_eq!(
+        ee_redemption_value(1000.0, 0.027, 24),
+        Some(ee_bond_value(1000.0, 0.027, 21))
+    );
+    assert_eq!(
+        ee_redemption_value(1000.0, 0.027, 60),
+        Some(ee_bond_value(1000.0, 0.027, 60))
+    );
+}
+
+#[test]
+fn breaking_a_cd_at_the_break_even_rate_ties() {
+    let inputs = CdInputs {
+        deposit: 10_000.0,
+        apy: 0.03,
+        term_months: 60.0,
+        months_elapsed: 12.0,
+        penalty_months: 6.0,
+        new_apy: 0.05,
+    };
+    let outputs = compute_cd(&inputs);
+    assert!((outputs.penalty - 150.0).abs() < 1e-9);
+    assert!(outputs.breaking_wins());
+
+    let tie = compute_cd(&CdInputs {
+        new_apy: outputs.break_even_apy,
+        ..inputs
+    });
+    assert!((tie.break_value - tie.hold_value).abs() < 1e-6);
+}
+
+#[test]
+fn money_factor_round_trips_through_apr() {
+    assert!((apr_to_money_factor(0.06) - 0.0025).abs() < 1e-15);
+    assert!((money_factor_to_apr(0.00125) - 0.03).abs() < 1e-15);
+    assert!((money_factor_to_apr(apr_to_money_factor(0.0499)) - 0.0499).abs() < 1e-15);
+}
+
+#[test]
+fn amortization_pays_the_loan_off_exactly() {
+    let schedule = amortization_schedule(&AmortizationInputs {
+        principal: 250_000.0,
+        annual_rate: 0.06,
+        years: 30.0,
+        periods_per_year: 12.0,
+        extra_payment: 0.0,
+    });
+
+    assert_eq!(schedule.rows.len(), 360);
+    assert_eq!(schedule.payment, 1498.88);
+    assert_eq!(schedule.rows[0].interest, 1250.0);
+    assert_eq!(schedule.rows[0].principal, 248.88);
+    assert_eq!(schedule.rows.last().unwrap().balance, 0.0);
+    assert_eq!(schedule.total_interest, 289_593.37);
+}
+
+#[test]
+fn amortization_without_interest_splits_the_principal() {
+    let schedule = amortization_schedule(&AmortizationInputs {
+        principal: 1000.0,
+        annual_rate: 0.0,
+        years: 1.0,
+        periods_per_year: 12.0,
+        extra_payment: 0.0,
+    });
+
+    assert_eq!(schedule.payment, 83.33);
+    assert_eq!(schedule.rows.last().unwrap().payment, 83.37);
+    assert_eq!(schedule.total_payments, 1000.0);
+    assert_eq!(schedule.total_interest, 0.0);
+}
+
+#[test]
+fn amortization_rows_carry_running_totals_and_the_term_left() {
+    let schedule = amortization_schedule(&AmortizationInputs {
+        principal: 250_000.0,
+        annual_rate: 0.06,
+        years: 30.0,
+        periods_per_year: 12.0,
+        extra_payment: 0.0,
+    });
+    let second = schedule.rows[1];
+    let last = schedule.rows.last().unwrap();
+
+    assert!((second.accrual_days - 365.0 / 12.0).abs() < 1e-12);
+    assert_eq!(second.annual_rate, 0.06);
+    assert_eq!(second.cumulative_interest, 2498.76);
+    assert_eq!(second.cumulative_principal, 499.0);
+    assert_eq!(second.remaining_periods, 358);
+    assert_eq!(second.remaining_years(12.0), 358.0 / 12.0);
+    assert_eq!(last.remaining_periods, 0);
+    assert_eq!(last.cumulative_principal, 250_000.0);
+    assert!((last.cumulative_interest - schedule.total_interest).abs() < 0.005);
+}
+
+#[test]
+fn amortization_extra_payments_end_the_loan_early() {
+    let schedule = amortization_schedule(&AmortizationInputs {
+        principal: 250_000.0,
+        annual_rate: 0.06,
+        years: 30.0,
+        periods_per_year: 12.0,
+        extra_payment: 200.0,
+    });
+    let last = schedule.rows.last().unwrap();
+
+    assert_eq!(schedule.payment, 1498.88);
+    assert_eq!(schedule.rows.len(), 267);
+    assert_eq!(last.balance, 0.0);
+    assert_eq!(last.payment, 1459.31);
+    assert_eq!(schedule.rows[0].remaining_periods, 266);
+    assert_eq!(schedule.total_interest, 203_361.39);
+}
+
+#[test]
+fn payoff_goal_seek_finds_the_smallest_extra_payment() {
+    let loan = AmortizationInputs {
+        principal: 250_000.0,
+        annual_rate: 0.06,
+        years: 30.0,
+        periods_per_year: 12.0,
+        extra_payment: 0.0,
+    };
+    let extra = payoff_extra_payment(&loan, 20.0).unwrap();
+    let with_extra = |extra_payment| {
+        amortization_schedule(&AmortizationInputs {
+            extra_payment,
+            ..loan
+        })
+    };
+
+    assert_eq!(extra, 292.2);
+    assert_eq!(with_extra(extra).rows.len(), 240);
+    assert_eq!(with_extra(extra - 0.01).rows.len(), 241);
+    assert_eq!(payoff_extra_payment(&loan, 30.0), Ok(0.0));
+    assert!(payoff_extra_payment(&loan, 0.0).is_err());
+}
+
+#[test]
+fn amortization_groups_payments_by_year() {
+    let schedule = amortization_schedule(&AmortizationInputs {
+        principal: 250_000.0,
+        annual_rate: 0.06,
+        years: 30.0,
+        periods_per_year: 12.0,
+        extra_payment: 0.0,
+    });
+    let years = schedule.by_year(12.0);
+
+    assert_eq!(years.len(), 30);
+    assert_eq!((years[0].first_period, years[0].last_period), (1, 12));
+    assert_eq!(years[0].interest, 14_916.5);
+    assert_eq!(years[0].principal, 3_070.06);
+    assert_eq!(years[0].balance, 246_929.94);
+    assert_eq!((years[29].first_period, years[29].last_period), (349, 360));
+    assert_eq!(years[29].balance, 0.0);
+    let total_interest: f64 = years.iter().map(|year| year.interest).sum();
+    assert!((total_interest - schedule.total_interest).abs() < 0.005);
+}
+
+#[test]
+fn escrow_analysis_spreads_shortages_from_rising_bills() {
+    let escrow = EscrowInputs {
+        annual_tax: 3_000.0,
+        annual_insurance: 1_200.0,
+        insurance_growth: 0.03,
+        tax_growth: 0.03,
+        tax_growth_cap: None,
+    };
+    let years = escrow_analysis(&escrow, 12.0, 4);
+
+    assert_eq!(years.len(), 4);
+    // Year 1's bills are known at closing, so the account ends the year at its cushion
+    assert_eq!(years[0].deposit, 350.0);
+    assert_eq!(years[0].balance, 700.0);
+    assert_eq!(years[0].shortage, 0.0);
+    // Year 2's deposits were set from year 1's bills, so the 3% rise leaves a shortage
+    assert_eq!(years[1].disbursements, 4_326.0);
+    assert_eq!(years[1].balance, 574.0);
+    assert_eq!(years[1].shortage, 147.0);
+    // Year 3 collects the new bills plus the shortage spread over 12 payments
+    assert_eq!(years[2].deposit, 372.75);
+    assert_eq!(years[2].shortage_repayment, 12.25);
+    assert_eq!(years[3].deposit, 383.93);
+}
+
+#[test]
+fn escrow_analysis_refunds_large_surpluses() {
+    let escrow = EscrowInputs {
+        annual_tax: 3_000.0,
+        annual_insurance: 1_200.0,
+        insurance_growth: -0.2,
+        tax_growth: -0.2,
+        tax_growth_cap: None,
+    };
+    let years = escrow_analysis(&escrow, 12.0, 2);
+
+    assert_eq!(years[1].disbursements, 3_360.0);
+    assert_eq!(years[1].refund, 980.0);
+    assert_eq!(years[1].balance, 560.0);
+    assert!(escrow_analysis(&EscrowInputs::default(), 12.0, 0).is_empty());
+}
+
+#[test]
+fn capped_reassessment_limits_property_tax_growth() {
+    let uncapped = EscrowInputs {
+        annual_tax: 3_000.0,
+        annual_insurance: 1_200.0,
+        insurance_growth: 0.0,
+        tax_growth: 0.06,
+        tax_growth_cap: None,
+    };
+    assert_eq!(uncapped.bills_in(1), 4_200.0);
+    assert_eq!(uncapped.bills_in(3), 4_570.8);
+
+    // Proposition 13 holds the assessed value's rise to 2% a year
+    let capped = EscrowInputs {
+        tax_growth_cap: Some(0.02),
+        ..uncapped
+    };
+    assert_eq!(capped.capped_tax_growth(), 0.02);
+    assert_eq!(capped.bills_in(3), 4_321.2);
+    // A cap above the growth changes nothing
+    let loose = EscrowInputs {
+        tax_growth_cap: Some(0.1),
+        ..uncapped
+    };
+    assert_eq!(loose.bills_in(3), uncapped.bills_in(3));
+    assert!(
+        escrow_analysis(&capped, 12.0, 3)[2].disbursements
+            < escrow_analysis(&uncapped, 12.0, 3)[2].disbursements
+    );
+}
+
+fn cash_flows(indexing: CashFlowIndexing, flows: &[(u32, &str, f64)]) -> CashFlowSeries {
+    CashFlowSeries {
+        indexing,
+        flows: flows
+            .iter()
+            .map(|(period, date, amount)| CashFlow {
+                period: *period,
+                date: date.to_string(),
+                amount: *amount,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn npv_discounts_each_period() {
+    let series = cash_flows(
+        CashFlowIndexing::Period,
+        &[
+            (0, "", -10_000.0),
+            (1, "", 3000.0),
+            (2, "", 4000.0),
+            (3, "", 5000.0),
+        ],
+    );
+    let outputs = compute_npv(&series, 0.08).unwrap();
+
+    assert!((outputs.npv - 176.294264).abs() < 1e-6);
+    assert_eq!(outputs.present_values[0], -10_000.0);
+    assert!((outputs.present_values[1] - 2777.777778).abs() < 1e-6);
+    assert!(compute_npv(&series, -1.0).is_err());
+}
+
+#[test]
+fn npv_measures_dated_flows_in_years_from_the_earliest() {
+    let series = cash_flows(
+        CashFlowIndexing::Date,
+        &[(0, "2026-01-01", 1100.0), (0, "2025-01-01", -1000.0)],
+    );
+    let outputs = compute_npv(&series, 0.10).unwrap();
+
+    assert!(outputs.npv.abs() < 1e-9);
+    assert_eq!(outputs.present_values[1], -1000.0);
+
+    let bad_date = cash_flows(CashFlowIndexing::Date, &[(0, "2025-13-01", 100.0)]);
+    assert!(compute_npv(&bad_date, 0.10).is_err());
+}
+
+#[test]
+fn parse_date_checks_the_calendar() {
+    assert_eq!(parse_date("1970-01-01"), Some(0));
+    assert_eq!(parse_date("2000-03-01"), Some(11_017));
+    assert_eq!(parse_date("2024-02-29"), Some(19_782));
+    assert_eq!(parse_date("2023-02-29"), None);
+    assert_eq!(parse_date("2025-04-31"), None);
+    assert_eq!(parse_date("next week"), None);
+}
+
+#[test]
+fn irr_zeroes_the_npv_of_periodic_flows() {
+    let series = cash_flows(
+        CashFlowIndexing::Period,
+        &[
+            (0, "", -10_000.0),
+            (1, "", 3000.0),
+            (2, "", 4000.0),
+            (3, "", 5000.0),
+        ],
+    );
+    let irr = compute_irr(&series).unwrap();
+
+    assert!((irr - 0.088963).abs() < 1e-6);
+    assert!(compute_npv(&series, irr).unwrap().npv.abs() < 1e-6);
+}
+
+#[test]
+fn xirr_uses_actual_days_between_dates() {
+    let series = cash_flows(
+        CashFlowIndexing::Date,
+        &[
+            (0, "2024-01-15", -5000.0),
+            (0, "2024-06-01", -2000.0),
+            (0, "2025-03-10", -1000.0),
+            (0, "2026-01-15", 9000.0),
+        ],
+    );
+    let xirr = compute_irr(&series).unwrap();
+
+    assert!((xirr - 0.068843).abs() < 1e-6);
+    assert!(compute_npv(&series, xirr).unwrap().npv.abs() < 1e-6);
+}
+
+#[test]
+fn irr_needs_flows_of_both_signs() {
+    let inflows_only = cash_flows(CashFlowIndexing::Period, &[(0, "", 100.0), (1, "", 100.0)]);
+    assert!(compute_irr(&inflows_only).is_err());
+
+    // Losing 99.9% in one period is below every bracket
+    let total_loss = cash_flows(CashFlowIndexing::Period, &[(0, "", -1000.0), (1, "", 1.0)]);
+    assert!(compute_irr(&total_loss).is_err());
+}
+
+#[test]
+fn annuity_functions_match_the_closed_forms() {
+    let i = 0.05 / 12.0;
+
+    assert_eq!(fv_annuity(500.0, i, 120.0, false), 77_641.14);
+    assert_eq!(fv_annuity(500.0, i, 120.0, true), 77_964.64);
+    assert_eq!(pv_annuity(500.0, i, 120.0, false), 47_140.68);
+    assert_eq!(fv_annuity(500.0, 0.0, 120.0, true), 60_000.0);
+    assert_eq!(
+        annuity_payment_for_pv(250_000.0, 0.005, 360.0, false),
+        1498.88
+    );
+    assert_eq!(
+        annuity_payment_for_pv(250_000.0, 0.005, 360.0, true),
+        1491.42
+    );
+    assert_eq!(annuity_payment_for_fv(77_641.14, i, 120.0, false), 500.0);
+    assert_eq!(annuity_payment_for_fv(1200.0, 0.0, 12.0, false), 100.0);
+}
+
+fn annuity_inputs(payment: Option<f64>, pv: Option<f64>, fv: Option<f64>) -> AnnuityInputs {
+    AnnuityInputs {
+        payment,
+        present_value: pv,
+        future_value: fv,
+        annual_rate: 0.06,
+        years: 30.0,
+        periods_per_year: 12.0,
+        annuity_due: false,
+        growth_rate: 0.0,
+    }
+}
+
+#[test]
+fn annuity_solves_for_the_missing_amount() {
+    let loan = solve_annuity(&annuity_inputs(None, Some(-250_000.0), Some(0.0))).unwrap();
+    assert_eq!(loan.unknown, AnnuityUnknown::Payment);
+    assert_eq!(loan.payment, 1498.88);
+
+    let drawdown = solve_annuity(&annuity_inputs(None, Some(250_000.0), Some(0.0))).unwrap();
+    assert_eq!(drawdown.payment, -1498.88);
+
+    let balance = solve_annuity(&annuity_inputs(Some(1498.88), Some(-250_000.0), None)).unwrap();
+    assert_eq!(balance.unknown, AnnuityUnknown::FutureValue);
+    // The payment is rounded to the cent, so the balance can miss zero by up to half a cent a
+    // payment, grown to the end of the term
+    let rounding = 0.005 * fv_annuity(1.0, 0.06 / 12.0, 360.0, false);
+    assert!(balance.future_value.abs() < rounding);
+
+    let needed = solve_annuity(&annuity_inputs(Some(-1498.88), None, Some(0.0))).unwrap();
+    assert_eq!(needed.unknown, AnnuityUnknown::PresentValue);
+    assert!((needed.present_value - 250_000.0).abs() < 1.0);
+
+    assert!(solve_annuity(&annuity_inputs(Some(100.0), Some(0.0), Some(0.0))).is_err());
+    assert!(solve_annuity(&annuity_inputs(None, None, Some(0.0))).is_err());
+}
+
+#[test]
+fn growing_annuity_discounts_payments_that_rise_each_period() {
+    // $1,000 a year rising 3% a year for ten years at 8%
+    assert_eq!(pv_growing_annuity(1_000.0, 0.08, 0.03, 10.0), 7_550.13);
+    assert_eq!(fv_growing_annuity(1_000.0, 0.08, 0.03, 10.0), 16_300.17);
+    // When payments grow as fast as the rate, each is worth PMT / (1 + i) today
+    assert_eq!(pv_growing_annuity(1_000.0, 0.05, 0.05, 10.0), 9_523.81);
+}
+
+#[test]
+fn annuity_with_payment_growth_solves_both_ways() {
+    let raises = AnnuityInputs {
+        periods_per_year: 1.0,
+        years: 10.0,
+        annual_rate: 0.08,
+        growth_rate: 0.03,
+        ..annuity_inputs(Some(1_000.0), Some(0.0), None)
+    };
+    let balance = solve_annuity(&raises).unwrap();
+    assert_eq!(balance.future_value, 16_300.17);
+    assert_eq!(balance.last_payment, 1_304.77);
+
+    let deposit = solve_annuity(&AnnuityInputs {
+        payment: None,
+        future_value: Some(16_300.17),
+        ..raises
+    })
+    .unwrap();
+    assert_eq!(deposit.unknown, AnnuityUnknown::Payment);
+    assert_eq!(deposit.payment, 1_000.0);
+
+    let level = solve_annuity(&annuity_inputs(Some(1498.88), Some(-250_000.0), None)).unwrap();
+    assert_eq!(level.last_payment, 1498.88);
+}
+
+#[test]
+fn required_years_with_contributions_reaches_target() {
+    for timing in [ContributionTiming::End, ContributionTiming::Begin] {
+        let inputs = FutureValueInputs {
+            target_value: 20_000.0,
+            annual_rate: 0.06,
+            compounding: Compounding::Quarterly,
+            monthly_contribution: 100.0,
+            contribution_timing: timing,
+            ..fv_inputs()
+        };
+        let years = compute_future_value(&inputs).required_years;
+        let reached = compounding::compute_fv_with_contributions(
+            inputs.principal,
+            inputs.monthly_contribution,
+            inputs.annual_rate,
+            4.0,
+            12.0,
+            years,
+            timing,
+        );
+
+        assert!(years > 0.0 && years < 13.0);
+        assert!((reached - inputs.target_value).abs() <= 0.01);
+    }
+}
+
+#[test]
+fn required_years_with_contributions_at_zero_rate_is_linear() {
+    let years = compounding::solve_years_with_contributions(
+        1000.0,
+        100.0,
+        2200.0,
+        0.0,
+        12.0,
+        12.0,
+        ContributionTiming::End,
+    );
+
+    assert!((years - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn required_years_is_nan_when_deposits_cannot_keep_up() {
+    // At -10% a year, $10 a month settles near $1,150 and never reaches $5,000
+    let inputs = FutureValueInputs {
+        target_value: 5000.0,
+        annual_rate: -0.10,
+        monthly_contribution: 10.0,
+        ..fv_inputs()
+    };
+
+    assert!(compute_future_value(&inputs).required_years.is_nan());
+    assert_eq!(audit_required_years(&inputs).result, None);
+}
+
+#[test]
+fn required_years_is_nan_when_the_target_is_already_reached() {
+    for monthly_contribution in [0.0, 100.0] {
+        let inputs = FutureValueInputs {
+            target_value: 500.0,
+            monthly_contribution,
+            ..fv_inputs()
+        };
+        assert!(compute_future_value(&inputs).required_years.is_nan());
+    }
+    assert_eq!(compounding::solve_years(1000.0, 500.0, 0.05, 12.0), None);
+    // A shrinking balance never grows to a larger target
+    assert_eq!(compounding::solve_years(1000.0, 2000.0, -0.05, 12.0), None);
+}
+
+#[test]
+fn continuous_compounding_grows_by_e_to_the_rt() {
+    let fv = compounding::compute_fv(
+        1000.0,
+        0.05,
+        Compounding::Continuous.periods_per_year(),
+        10.0,
+    );
+    assert_eq!(fv, 1648.72);
+
+    // Daily compounding comes within a few cents of the limit
+    let daily = compounding::compute_fv(1000.0, 0.05, 365.0, 10.0);
+    assert!(fv > daily && fv - daily < 0.1);
+}
+
+#[test]
+fn continuous_compounding_solves_and_round_trips() {
+    let inputs = FutureValueInputs {
+        compounding: Compounding::Continuous,
+        monthly_contribution: 0.0,
+        ..fv_inputs()
+    };
+    let outputs = compute_future_value(&inputs);
+
+    assert_eq!(outputs.future_value, Ok(1311.60));
+    assert!((outputs.required_rate - 2.0f64.ln() / 7.0).abs() < 1e-12);
+    assert!((outputs.required_years - 2.0f64.ln() / 0.03875).abs() < 1e-12);
+    let pv = compute_present_value(&PresentValueInputs {
+        target_value: 1311.60,
+        annual_rate: inputs.annual_rate,
+        years: inputs.years,
+        compounding: Compounding::Continuous,
+    });
+    assert!((pv.unwrap() - 1000.0).abs() <= 0.01);
+}
+
+#[test]
+fn continuous_compounding_with_contributions_uses_the_equivalent_monthly_rate() {
+    let inputs = FutureValueInputs {
+        compounding: Compounding::Continuous,
+        monthly_contribution: 100.0,
+        target_value: 20_000.0,
+        ..fv_inputs()
+    };
+    let outputs = compute_future_value(&inputs);
+    let monthly_rate = (0.03875f64 / 12.0).exp() - 1.0;
+    let expected = 1000.0 * (0.03875f64 * 7.0).exp()
+        + 100.0 * ((1.0 + monthly_rate).powf(84.0) - 1.0) / monthly_rate;
+
+    assert!((outputs.future_value.unwrap() - expected).abs() <= 0.01);
+    let record = audit_future_value(&inputs);
+    assert!(record.formulas.contains(&r"i = e^{r/m} - 1"));
+    assert!(record
+        .intermediate_values
+        .iter()
+        .all(|value| value.value.is_finite()));
+    let reached = compounding::compute_fv_with_contributions(
+        1000.0,
+        100.0,
+        0.03875,
+        f64::INFINITY,
+        12.0,
+        outputs.required_years,
+        ContributionTiming::End,
+    );
+    assert!((reached - 20_000.0).abs() <= 0.01);
+}
+
+#[test]
+fn nominal_and_effective_rates_round_trip() {
+    for compounding in [
+        Compounding::Annual,
+        Compounding::Monthly,
+        Compounding::Daily,
+        Compounding::Continuous,
+    ] {
+        let n = compounding.periods_per_year();
+        let apy = compounding::nominal_to_effective(0.05, n);
+        let apr = compounding::effective_to_nominal(apy, n);
+        assert!((apr - 0.05).abs() < 1e-12, "{:?}", compounding);
+    }
+    assert!((compounding::nominal_to_effective(0.05, 12.0) - 0.051161897881733).abs() < 1e-12);
+    assert!(
+        (compounding::nominal_to_effective(0.05, f64::INFINITY) - (0.05f64.exp() - 1.0)).abs()
+            < 1e-15
+    );
+    assert!((compounding::nominal_to_effective(0.05, 1.0) - 0.05).abs() < 1e-15);
+}
+
+#[test]
+fn apr_apy_converter_works_in_both_directions() {
+    let from_apr = convert_rate(&AprApyInputs {
+        rate: 0.06,
+        entered: RateKind::Apr,
+        compounding: Compounding::Quarterly,
+    })
+    .unwrap();
+    let from_apy = convert_rate(&AprApyInputs {
+        rate: from_apr.apy,
+        entered: RateKind::Apy,
+        compounding: Compounding::Quarterly,
+    })
+    .unwrap();
+
+    assert!((from_apr.apy - 0.06136355062500).abs() < 1e-12);
+    assert!((from_apy.apr - 0.06).abs() < 1e-12);
+}
+
+#[test]
+fn apr_apy_converter_rejects_rates_at_or_below_minus_100_percent() {
+    let apy = AprApyInputs {
+        rate: -1.0,
+        entered: RateKind::Apy,
+        compounding: Compounding::Monthly,
+    };
+    assert!(convert_rate(&apy).is_err());
+    assert!(convert_rate(&AprApyInputs {
+        rate: -12.0,
+        entered: RateKind::Apr,
+        ..apy
+    })
+    .is_err());
+    // Continuous compounding accepts any APR
+    assert!(convert_rate(&AprApyInputs {
+        rate: -12.0,
+        entered: RateKind::Apr,
+        compounding: Compounding::Continuous,
+    })
+    .is_ok());
+}
+
+#[test]
+fn data_years_are_current_until_the_calendar_passes_them() {
+    assert_eq!(data_year_notice(2026, &[2025, 2026], 2026), None);
+    // Tables published ahead of their year aren't stale
+    assert_eq!(data_year_notice(2026, &[2025, 2026], 2025), None);
+}
+
+#[test]
+fn stale_data_years_offer_the_newest_started_year() {
+    assert_eq!(
+        data_year_notice(2024, &[2024, 2025, 2026, 2027], 2026),
+        Some(DataYearNotice::NewerAvailable {
+            selected: 2024,
+            newer: 2026
+        })
+    );
+    assert_eq!(
+        data_year_notice(2026, &[2025, 2026], 2027),
+        Some(DataYearNotice::NoneNewer {
+            selected: 2026,
+            current: 2027
+        })
+    );
+}
+
+#[test]
+fn data_sets_list_each_year_once_oldest_first() {
+    let tax_years = (find_data_set("tax_brackets").unwrap().years)();
+    assert_eq!(tax_years, vec![2025, 2026]);
+
+    let limit_years = (find_data_set("contribution_limits").unwrap().years)();
+    assert!(limit_years.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+fn retirement_inputs() -> RetirementInputs {
+    RetirementInputs {
+        current_age: 30.0,
+        retirement_age: 31.5,
+        current_balance: 1000.0,
+        monthly_contribution: 100.0,
+        annual_return: 0.0,
+        inflation_rate: 0.0,
+        annual_increase: 0.0,
+        account: LimitedAccount::Unlimited,
+        catch_up: false,
+        annual_salary: 0.0,
+        match_rate: 0.0,
+        match_cap: 0.0,
+        glide_path: None,
+    }
+}
+
+#[test]
+fn retirement_schedule_gives_a_partial_last_year_its_own_row() {
+    let projection = accumulation_schedule(&retirement_inputs(), 2025).unwrap();
+
+    assert_eq!(projection.rows.len(), 2);
+    assert_eq!(projection.rows[0].balance, 2200.0);
+    assert_eq!(projection.rows[1].contributions, 600.0);
+    assert_eq!(projection.rows[1].age, 31.5);
+    assert_eq!(projection.balance, 2800.0);
+    assert_eq!(projection.total_contributions, 2800.0);
+    assert_eq!(projection.total_growth, 0.0);
+}
+
+#[test]
+fn retirement_schedule_compounds_to_the_annual_return() {
+    let projection = accumulation_schedule(
+        &RetirementInputs {
+            retirement_age: 40.0,
+            monthly_contribution: 0.0,
+            annual_return: 0.06,
+            inflation_rate: 0.06,
+            ..retirement_inputs()
+        },
+        2025,
+    )
+    .unwrap();
+
+    assert_eq!(projection.rows[0].balance, 1060.0);
+    assert_eq!(projection.rows[0].growth, 60.0);
+    assert_eq!(projection.rows[0].real_balance, 1000.0);
+    assert_eq!(projection.balance, 1790.85);
+    assert_eq!(projection.real_balance, 1000.0);
+}
+
+#[test]
+fn retirement_contributions_rise_once_a_year() {
+    let inputs = RetirementInputs {
+        current_balance: 0.0,
+        retirement_age: 60.0,
+        annual_return: 0.06,
+        annual_increase: 0.03,
+        ..retirement_inputs()
+    };
+    let projection = accumulation_schedule(&inputs, 2025).unwrap();
+
+    assert_eq!(projection.rows[0].contributions, 1200.0);
+    assert_eq!(projection.rows[1].contributions, 1236.0);
+    let monthly_rate = 1.06_f64.powf(1.0 / 12.0) - 1.0;
+    let expected = fv_escalating_contributions(100.0, monthly_rate * 12.0, 0.03, 12.0, 30.0);
+    assert!((projection.balance - expected).abs() < 0.02);
+}
+
+#[test]
+fn retirement_contributions_are_capped_at_the_account_limit() {
+    let inputs = RetirementInputs {
+        current_age: 45.0,
+        retirement_age: 55.0,
+        monthly_contribution: 2_500.0,
+        account: LimitedAccount::Workplace,
+        ..retirement_inputs()
+    };
+    let projection = accumulation_schedule(&inputs, 2025).unwrap();
+
+    // $30,000 a year against the $23,500 limit until the catch-up raises it to $31,000 at 50
+    assert_eq!(projection.rows[0].contributions, 23_500.0);
+    assert_eq!(projection.rows[0].capped, 6_500.0);
+    assert_eq!(projection.rows[4].age, 50.0);
+    assert_eq!(projection.rows[4].contributions, 30_000.0);
+    assert_eq!(projection.rows[4].capped, 0.0);
+    assert_eq!(projection.total_capped, 26_000.0);
+
+    let unlimited = RetirementInputs {
+        account: LimitedAccount::Unlimited,
+        ..inputs
+    };
+    assert_eq!(
+        accumulation_schedule(&unlimited, 2025)
+            .unwrap()
+            .total_capped,
+        0.0
+    );
+}
+
+#[test]
+fn retirement_employer_match_is_earned_only_within_the_limit() {
+    let inputs = RetirementInputs {
+        current_age: 45.0,
+        retirement_age: 46.5,
+        current_balance: 0.0,
+        monthly_contribution: 2_500.0,
+        account: LimitedAccount::Workplace,
+        annual_salary: 500_000.0,
+        match_rate: 1.0,
+        match_cap: 0.06,
+        ..retirement_inputs()
+    };
+    let projection = accumulation_schedule(&inputs, 2025).unwrap();
+
+    // $30,000 planned is capped at $23,500, all of it under 6% of pay, so the match is $23,500;
+    // the last half year matches up to 6% of half a year's pay
+    assert_eq!(projection.rows[0].contributions, 23_500.0);
+    assert_eq!(projection.rows[0].employer_match, 23_500.0);
+    assert_eq!(projection.rows[0].balance, 47_000.0);
+    assert_eq!(projection.rows[1].contributions, 15_000.0);
+    assert_eq!(projection.rows[1].employer_match, 15_000.0);
+    assert_eq!(projection.total_match, 38_500.0);
+    assert_eq!(projection.total_growth, 0.0);
+
+    // A smaller salary caps the match below the contributions
+    let modest = RetirementInputs {
+        annual_salary: 100_000.0,
+        ..inputs.clone()
+    };
+    let projection = accumulation_schedule(&modest, 2025).unwrap();
+    assert_eq!(projection.rows[0].employer_match, 6_000.0);
+    assert_eq!(projection.rows[1].employer_match, 3_000.0);
+
+    // IRAs have no employer match
+    let ira = RetirementInputs {
+        account: LimitedAccount::Ira,
+        ..inputs
+    };
+    assert_eq!(accumulation_schedule(&ira, 2025).unwrap().total_match, 0.0);
+}
+
+#[test]
+fn retirement_catch_up_contributions_start_at_50() {
+    let inputs = RetirementInputs {
+        current_age: 48.0,
+        retirement_age: 52.0,
+        current_balance: 0.0,
+        monthly_contribution: 500.0,
+        account: LimitedAccount::Ira,
+        catch_up: true,
+        ..retirement_inputs()
+    };
+    let projection = accumulation_schedule(&inputs, 2025).unwrap();
+
+    let contributions: Vec<f64> = projection
+        .rows
+        .iter()
+        .map(|row| row.contributions)
+        .collect();
+    assert_eq!(contributions, vec![6_000.0, 7_000.0, 7_000.0, 7_000.0]);
+    assert_eq!(projection.total_capped, 0.0);
+
+    // The 401(k) catch-up is larger from 60 through 63
+    assert_eq!(LimitedAccount::Workplace.catch_up(2025, 61), 11_250.0);
+    assert_eq!(LimitedAccount::Workplace.catch_up(2025, 64), 7_500.0);
+    assert_eq!(LimitedAccount::Ira.cap(9_000.0, 2026, 40).excess, 1_500.0);
+}
+
+#[test]
+fn retirement_returns_follow_the_glide_path_by_age() {
+    let inputs = RetirementInputs {
+        retirement_age: 33.0,
+        monthly_contribution: 0.0,
+        annual_return: 0.06,
+        glide_path: Some(GlidePath::linear(30.0, 0.10, 32.0, 0.0)),
+        ..retirement_inputs()
+    };
+    let projection = accumulation_schedule(&inputs, 2025).unwrap();
+
+    // 10% from 30, 5% from 31 (halfway down the line), and nothing from 32
+    let balances: Vec<f64> = projection.rows.iter().map(|row| row.balance).collect();
+    assert_eq!(balances, vec![1100.0, 1155.0, 1155.0]);
+
+    let crash = RetirementInputs {
+        glide_path: Some(GlidePath::constant(-1.0)),
+        ..inputs
+    };
+    assert!(accumulation_schedule(&crash, 2025).is_err());
+}
+
+#[test]
+fn glide_path_breakpoints_are_replaced_and_removed_by_age() {
+    let mut path = GlidePath::linear(40.0, 0.08, 65.0, 0.05);
+    path.set_point(GlidePoint {
+        age: 50.0,
+        annual_return: 0.07,
+    });
+    path.set_point(GlidePoint {
+        age: 65.0,
+        annual_return: 0.04,
+    });
+
+    let ages: Vec<f64> = path.points().iter().map(|point| point.age).collect();
+    assert_eq!(ages, vec![40.0, 50.0, 65.0]);
+    assert_eq!(path.return_at(70.0), 0.04);
+    let yearly = path.yearly_returns(49, 52);
+    let expected = [(49, 0.071), (50, 0.07), (51, 0.068)];
+    assert_eq!(yearly.len(), expected.len());
+    for ((age, annual_return), (expected_age, expected_return)) in yearly.iter().zip(expected) {
+        assert_eq!(*age, expected_age);
+        assert!((annual_return - expected_return).abs() < 1e-12);
+    }
+
+    path.remove_point(0);
+    path.remove_point(0);
+    path.remove_point(0);
+    assert_eq!(path.points().len(), 1);
+    assert_eq!(path.return_at(30.0), 0.04);
+}
+
+#[test]
+fn retirement_age_must_come_after_the_current_age() {
+    let inputs = RetirementInputs {
+        retirement_age: 30.0,
+        ..retirement_inputs()
+    };
+
+    assert!(accumulation_schedule(&inputs, 2025).is_err());
+}
+
+#[test]
+fn retirement_inflation_scenarios_bracket_the_base_rate() {
+    let inputs = RetirementInputs {
+        retirement_age: 40.0,
+        monthly_contribution: 0.0,
+        annual_return: 0.06,
+        inflation_rate: 0.06,
+        ..retirement_inputs()
+    };
+    let scenarios = InflationScenarios {
+        enabled: true,
+        spread: 0.01,
+    };
+    let (rates, results) = run_inflation_scenarios(&inputs, &scenarios, |inputs| {
+        accumulation_schedule(inputs, 2025)
+    })
+    .unwrap();
+
+    assert!((rates.low - 0.05).abs() < 1e-12);
+    assert!((rates.high - 0.07).abs() < 1e-12);
+    // Inflation only changes today's dollars, not the balance itself
+    assert_eq!(results.low.balance, results.high.balance);
+    assert_eq!(results.low.real_balance, 1099.43);
+    assert_eq!(results.base.real_balance, 1000.0);
+    assert_eq!(results.high.real_balance, 910.38);
+
+    let negative = InflationScenarios {
+        spread: -0.01,
+        ..scenarios
+    };
+    assert!(run_inflation_scenarios(&inputs, &negative, |inputs| {
+        accumulation_schedule(inputs, 2025)
+    })
+    .is_err());
+}
+
+#[test]
+fn savings_goal_payment_matches_the_sinking_fund_formula() {
+    // $12,000 in 10 years at 5% compounded annually, one deposit a year
+    assert_eq!(
+        savings_goal_payment(12_000.0, 0.0, 0.05, 1.0, 1.0, 10.0, false),
+        954.05
+    );
+    // Without interest the goal is split evenly
+    assert_eq!(
+        savings_goal_payment(12_000.0, 0.0, 0.0, 12.0, 12.0, 10.0, false),
+        100.0
+    );
+    // Savings that outgrow the goal need no deposits
+    assert_eq!(
+        savings_goal_payment(1_000.0, 1_000.0, 0.05, 12.0, 12.0, 1.0, false),
+        0.0
+    );
+}
+
+#[test]
+fn savings_goal_deposits_earn_the_equivalent_rate_between_compounding_dates() {
+    // Monthly deposits into an account compounding annually at 12.68% earn 1% a month
+    let payment = savings_goal_payment(10_000.0, 0.0, 0.126825, 1.0, 12.0, 5.0, false);
+    let expected = 10_000.0 * 0.01 / (1.01_f64.powf(60.0) - 1.0);
+    assert!((payment - expected).abs() < 0.01);
+}
+
+#[test]
+fn savings_goal_reports_deposits_and_interest() {
+    let inputs = SavingsGoalInputs {
+        target: 25_000.0,
+        current_savings: 2_000.0,
+        annual_rate: 0.04,
+        compounding: Compounding::Monthly,
+        deposits_per_year: 12.0,
+        years: 5.0,
+        annuity_due: false,
+        annual_increase: 0.0,
+    };
+    let outputs = solve_savings_goal(&inputs).unwrap();
+
+    assert_eq!(outputs.payment, 340.25);
+    assert_eq!(outputs.final_payment, 340.25);
+    assert_eq!(outputs.n_deposits, 60.0);
+    assert_eq!(outputs.total_deposits, 20_415.0);
+    assert_eq!(outputs.interest, 2_585.0);
+
+    let too_soon = SavingsGoalInputs {
+        years: 0.01,
+        ..inputs
+    };
+    assert!(solve_savings_goal(&too_soon).is_err());
+}
+
+#[test]
+fn savings_goal_deposits_that_rise_each_year_start_lower() {
+    let inputs = SavingsGoalInputs {
+        target: 25_000.0,
+        current_savings: 2_000.0,
+        annual_rate: 0.04,
+        compounding: Compounding::Monthly,
+        deposits_per_year: 12.0,
+        years: 5.0,
+        annuity_due: false,
+        annual_increase: 0.03,
+    };
+    let outputs = solve_savings_goal(&inputs).unwrap();
+
+    assert!(outputs.payment < 340.25);
+    assert_eq!(
+        outputs.final_payment,
+        (outputs.payment * 1.03_f64.powi(4) * 100.0).trunc() / 100.0
+    );
+    // The rising deposits and the savings already set aside reach the goal
+    let savings = 2_000.0 * (1.0 + 0.04 / 12.0_f64).powi(60);
+    let deposits = fv_escalating_contributions(outputs.payment, 0.04, 0.03, 12.0, 5.0);
+    assert!((savings + deposits - 25_000.0).abs() < 1.0);
+    let total: f64 = (0..5)
+        .map(|year| outputs.payment * 12.0 * 1.03_f64.powi(year))
+        .sum();
+    assert!((outputs.total_deposits - total).abs() < 0.01);
+
+    let partial_year = SavingsGoalInputs {
+        years: 4.5,
+        ..inputs
+    };
+    assert!(solve_savings_goal(&partial_year).is_err());
+}
+
+fn bond_price_inputs(years_to_maturity: f64) -> BondPriceInputs {
+    BondPriceInputs {
+        face_value: 1000.0,
+        coupon_rate: 0.05,
+        frequency: 2.0,
+        yield_rate: 0.04,
+        years_to_maturity,
+    }
+}
+
+#[test]
+fn bonds_priced_on_a_coupon_date_have_no_accrued_interest() {
+    let pricing = price_bond(&bond_price_inputs(10.0)).unwrap();
+
+    assert_eq!(pricing.accrued_interest, 0.0);
+    assert!((pricing.clean_price - bond_price(1000.0, 0.05, 0.04, 10.0, 2.0)).abs() < 1e-9);
+    assert_eq!(pricing.cash_flows.len(), 20);
+    assert_eq!(pricing.cash_flows[19].principal, 1000.0);
+}
+
+#[test]
+fn bonds_priced_between_coupons_accrue_interest() {
+    // Three months into a six-month coupon period
+    let pricing = price_bond(&bond_price_inputs(9.75)).unwrap();
+
+    assert_eq!(pricing.cash_flows.len(), 20);
+    assert_eq!(pricing.cash_flows[0].years, 0.25);
+    assert!((pricing.accrued_interest - 12.5).abs() < 1e-9);
+    assert!((pricing.dirty_price - 1092.5212).abs() < 1e-4);
+    assert!((pricing.clean_price - 1080.0212).abs() < 1e-4);
+    // The dirty price is the full-period price grown by half a period
+    let full_period = price_bond(&bond_price_inputs(10.0)).unwrap().dirty_price;
+    assert!((pricing.dirty_price - full_period * 1.02_f64.sqrt()).abs() < 1e-6);
+}
+
+#[test]
+fn bond_pricing_rejects_a_matured_bond() {
+    assert!(price_bond(&bond_price_inputs(0.0)).is_err());
+}
+
+#[test]
+fn verify_cases_match_their_published_answers() {
+    let failed: Vec<_> = verify::run_checks()
+        .into_iter()
+        .filter(|result| !result.passed())
+        .map(|result| (result.case.name, result.actual))
+        .collect();
+
+    assert!(failed.is_empty(), "failed checks: {:?}", failed);
+}
+
+#[test]
+fn perpetuity_divides_the_payment_by_the_rate() {
+    assert_eq!(pv_perpetuity(5.0, 0.06), Ok(83.33));
+    assert_eq!(pv_perpetuity(100.0, 0.05), Ok(2_000.0));
+    assert!(pv_perpetuity(100.0, 0.0).is_err());
+    assert!(pv_perpetuity(100.0, -0.01).is_err());
+}
+
+#[test]
+fn growing_perpetuity_requires_growth_below_the_rate() {
+    // Gordon growth: a $2 dividend growing 4% a year at a 9% required return
+    assert_eq!(pv_growing_perpetuity(2.0, 0.09, 0.04), Ok(40.0));
+    assert!(pv_growing_perpetuity(2.0, 0.09, 0.09).is_err());
+    assert!(pv_growing_perpetuity(2.0, 0.09, 0.12).is_err());
+    // Shrinking payments are worth less than level ones
+    assert_eq!(pv_growing_perpetuity(100.0, 0.05, -0.05), Ok(1_000.0));
+
+    let level = PerpetuityInputs {
+        payment: 100.0,
+        discount_rate: 0.05,
+        growth_rate: 0.0,
+    };
+    assert_eq!(value_perpetuity(&level), Ok(2_000.0));
+}
+
+#[test]
+fn loan_offer_costs_count_fees_and_points() {
+    let offer = LoanOffer {
+        amount: 300_000.0,
+        annual_rate: 0.065,
+        years: 30.0,
+        fees: 1_500.0,
+        points: 0.0,
+        prepayment_penalty: PrepaymentPenalty::None,
+    };
+    let costs = loan_offer_costs(&offer).unwrap();
+
+    assert!((costs.monthly_payment - 1_896.204_07).abs() < 1e-5);
+    assert!((costs.apr - 0.065_482_46).abs() < 1e-8);
+    assert!((costs.total_interest - 382_633.465).abs() < 1e-3);
+    assert!((costs.horizon_cost - 96_105.177).abs() < 1e-3);
+    assert_eq!(costs.horizon_penalty, 0.0);
+
+    // Without fees or points, the APR is the note rate and the 5-year cost is only interest
+    let no_fees = loan_offer_costs(&LoanOffer { fees: 0.0, ..offer }).unwrap();
+    assert!((no_fees.apr - 0.065).abs() < 1e-10);
+    assert!((no_fees.horizon_cost - (costs.horizon_cost - 1_500.0)).abs() < 1e-6);
+
+    // A point is 1% of the amount
+    let bought_down = LoanOffer {
+        annual_rate: 0.06125,
+        points: 1.0,
+        ..offer
+    };
+    assert_eq!(bought_down.upfront_cost(), 4_500.0);
+    assert!((loan_offer_costs(&bought_down).unwrap().apr - 0.062_676_18).abs() < 1e-8);
+
+    let no_term = LoanOffer {
+        years: 0.0,
+        ..offer
+    };
+    assert!(loan_offer_costs(&no_term).is_err());
+    let all_points = LoanOffer {
+        points: 100.0,
+        ..offer
+    };
+    assert!(loan_offer_costs(&all_points).is_err());
+}
+
+#[test]
+fn prepayment_penalties_apply_only_within_their_years() {
+    let fixed = PrepaymentPenalty::FixedFee {
+        fee: 3_000.0,
+        years: 3.0,
+    };
+    assert_eq!(fixed.charge(100_000.0, 35), 3_000.0);
+    assert_eq!(fixed.charge(100_000.0, 36), 0.0);
+
+    let percent = PrepaymentPenalty::PercentOfBalance {
+        rate: 0.02,
+        years: 5.0,
+    };
+    assert!((percent.charge(100_000.0, 12) - 2_000.0).abs() < 1e-9);
+    assert_eq!(percent.charge(100_000.0, 60), 0.0);
+
+    // 5% over five years: 5%, 4%, 3%, 2%, then 1%
+    let step_down = PrepaymentPenalty::StepDown {
+        initial_rate: 0.05,
+        years: 5.0,
+    };
+    for (year, expected) in [5_000.0, 4_000.0, 3_000.0, 2_000.0, 1_000.0]
+        .iter()
+        .enumerate()
+    {
+        assert!((step_down.charge(100_000.0, year * 12 + 6) - expected).abs() < 1e-9);
+    }
+    assert_eq!(step_down.charge(100_000.0, 60), 0.0);
+    assert_eq!(PrepaymentPenalty::None.charge(100_000.0, 0), 0.0);
+}
+
+#[test]
+fn horizon_cost_includes_the_prepayment_penalty() {
+    let offer = LoanOffer {
+        amount: 300_000.0,
+        annual_rate: 0.065,
+        years: 30.0,
+        fees: 1_500.0,
+        points: 0.0,
+        prepayment_penalty: PrepaymentPenalty::PercentOfBalance {
+            rate: 0.02,
+            years: 7.0,
+        },
+    };
+    let costs = loan_offer_costs(&offer).unwrap();
+    // 2% of the $280,832.93 left after 60 payments
+    assert!((costs.horizon_penalty - 5_616.659).abs() < 1e-3);
+    assert!((costs.horizon_cost - (96_105.177 + 5_616.659)).abs() < 1e-2);
+
+    // A 5-year loan matures at the horizon, so its penalty never applies
+    let short = LoanOffer {
+        years: 5.0,
+        ..offer
+    };
+    assert_eq!(loan_offer_costs(&short).unwrap().horizon_penalty, 0.0);
+
+    let negative = LoanOffer {
+        prepayment_penalty: PrepaymentPenalty::FixedFee {
+            fee: -1.0,
+            years: 3.0,
+        },
+        ..offer
+    };
+    assert!(loan_offer_costs(&negative).is_err());
+}
+
+#[test]
+fn apr_with_costs_is_zero_for_interest_free_loans() {
+    assert_eq!(apr_with_costs(12_000.0, 1_000.0, 12.0), 0.0);
+}
+
+#[test]
+fn lowest_offer_skips_offers_that_failed() {
+    assert_eq!(
+        lowest_offer(&[Some(3.0), None, Some(2.0), Some(2.0)]),
+        Some(2)
+    );
+    assert_eq!(lowest_offer(&[None, None]), None);
+    // Nothing stands out when every offer ties
+    assert_eq!(lowest_offer(&[Some(0.0), None, Some(0.0)]), None);
+    assert_eq!(lowest_offer(&[Some(0.0)]), Some(0));
+}
+
+fn sinking_fund_inputs(annuity_due: bool) -> SavingsGoalInputs {
+    SavingsGoalInputs {
+        target: 40_000.0,
+        current_savings: 5_000.0,
+        annual_rate: 0.045,
+        compounding: Compounding::Monthly,
+        deposits_per_year: 12.0,
+        years: 8.0,
+        annuity_due,
+        annual_increase: 0.0,
+    }
+}
+
+#[test]
+fn sinking_fund_schedule_ends_at_the_obligation() {
+    let schedule = sinking_fund_schedule(&sinking_fund_inputs(false)).unwrap();
+
+    assert_eq!(schedule.payment, 284.81);
+    assert_eq!(schedule.rows.len(), 96);
+    // 5,000 * 4.5% / 12 in the first month
+    assert_eq!(schedule.rows[0].interest, 18.75);
+    assert_eq!(schedule.rows[0].balance, 5_303.56);
+    // The last deposit makes up the rounding of the earlier ones
+    let last = schedule.rows.last().unwrap();
+    assert!((last.deposit - schedule.payment).abs() < 1.0);
+    assert_eq!(last.balance, 40_000.0);
+    assert_eq!(schedule.ending_balance, 40_000.0);
+    assert!((schedule.total_deposits + schedule.total_interest - 35_000.0).abs() < 0.01);
+}
+
+#[test]
+fn sinking_fund_deposits_at_the_start_earn_a_period_more_interest() {
+    let end = sinking_fund_schedule(&sinking_fund_inputs(false)).unwrap();
+    let start = sinking_fund_schedule(&sinking_fund_inputs(true)).unwrap();
+
+    assert_eq!(start.payment, 283.75);
+    // (5,000 + 283.75) * 4.5% / 12
+    assert_eq!(start.rows[0].interest, 19.81);
+    assert_eq!(start.ending_balance, 40_000.0);
+    assert!(start.total_interest > end.total_interest);
+
+    let already_funded = SavingsGoalInputs {
+        current_savings: 40_000.0,
+        ..sinking_fund_inputs(false)
+    };
+    let schedule = sinking_fund_schedule(&already_funded).unwrap();
+    assert_eq!(schedule.payment, 0.0);
+    assert!(schedule.rows.iter().all(|row| row.deposit == 0.0));
+    assert!(schedule.ending_balance > 40_000.0);
+}
+
+#[test]
+fn closing_costs_itemize_fees_and_prepaids() {
+    let inputs = ClosingCostInputs {
+        loan_amount: 300_000.0,
+        annual_rate: 0.065,
+        lender_fees: 0.0,
+        title_fees: 0.0,
+        government_fees: 0.0,
+        prepaid_interest_days: 15.0,
+        annual_escrow_bills: 4_200.0,
+        escrow_months: 3.0,
+    }
+    .with_preset(&CLOSING_COST_PRESETS[0]);
+    assert_eq!(
+        (
+            inputs.lender_fees,
+            inputs.title_fees,
+            inputs.government_fees
+        ),
+        (3_000.0, 1_800.0, 1_200.0)
+    );
+
+    let costs = estimate_closing_costs(&inputs).unwrap();
+    // 300,000 x 6.5% / 365 a day for 15 days
+    assert_eq!(costs.prepaid_interest, 801.37);
+    assert_eq!(costs.initial_escrow, 1_050.0);
+    assert_eq!(costs.fees(), 6_000.0);
+    assert!((costs.total() - 7_851.37).abs() < 1e-9);
+
+    let negative = ClosingCostInputs {
+        title_fees: -1.0,
+        ..inputs
+    };
+    assert!(estimate_closing_costs(&negative).is_err());
+}
+
+fn sample_debts() -> Vec<Debt> {
+    let debt = |balance, annual_rate, minimum_payment| Debt {
+        balance,
+        annual_rate,
+        minimum_payment,
+    };
+    vec![
+        debt(8_000.0, 0.24, 200.0),
+        debt(4_000.0, 0.06, 150.0),
+        debt(1_200.0, 0.18, 40.0),
+        debt(6_000.0, 0.11, 180.0),
+    ]
+}
+
+#[test]
+fn snowball_and_avalanche_order_debts_differently() {
+    let debts = sample_debts();
+    assert_eq!(
+        payoff_order(&debts, PayoffStrategy::Snowball),
+        vec![2, 1, 3, 0]
+    );
+    assert_eq!(
+        payoff_order(&debts, PayoffStrategy::Avalanche),
+        vec![0, 2, 3, 1]
+    );
+}
+
+#[test]
+fn avalanche_pays_less_interest_than_snowball() {
+    let debts = sample_debts();
+    let snowball = simulate_payoff(&debts, 900.0, PayoffStrategy::Snowball).unwrap();
+    let avalanche = simulate_payoff(&debts, 900.0, PayoffStrategy::Avalanche).unwrap();
+
+    assert_eq!(snowball.months, 27);
+    assert_eq!(snowball.total_interest, 4_209.63);
+    assert_eq!(snowball.payoff_months, vec![27, 11, 4, 18]);
+    assert_eq!(avalanche.months, 25);
+    assert_eq!(avalanche.total_interest, 3_140.11);
+    assert_eq!(avalanche.payoff_months, vec![19, 25, 20, 25]);
+    // Everything paid is the balances plus the interest
+    assert!((avalanche.total_paid - (19_200.0 + avalanche.total_interest)).abs() < 0.005);
+}
+
+#[test]
+fn debt_payoff_rejects_budgets_that_cannot_finish() {
+    let debts = sample_debts();
+    // The minimums add up to $570
+    assert!(simulate_payoff(&debts, 569.0, PayoffStrategy::Avalanche).is_err());
+    assert!(simulate_payoff(&[], 500.0, PayoffStrategy::Snowball).is_err());
+    // A minimum below the interest never pays the debt off
+    let growing = [Debt {
+        balance: 10_000.0,
+        annual_rate: 0.24,
+        minimum_payment: 100.0,
+    }];
+    assert!(simulate_payoff(&growing, 100.0, PayoffStrategy::Avalanche).is_err());
+}
+
+fn couple() -> PaycheckInputs {
+    PaycheckInputs {
+        status: FilingStatus::MarriedFilingJointly,
+        first: Earner {
+            salary: 85_000.0,
+            pretax_deductions: 5_000.0,
+        },
+        second: Earner {
+            salary: 60_000.0,
+            pretax_deductions: 3_000.0,
+        },
+        periods_per_year: 26.0,
+    }
+}
+
+#[test]
+fn paycheck_combines_a_couple_filing_jointly() {
+    let outputs = compute_paycheck(&couple(), 2026).unwrap();
+    // $137,000 of wages after pretax deductions, less the $32,200 standard deduction
+    assert_eq!(outputs.income_tax, 12_480.0);
+    assert_eq!(outputs.fica, 11_092.5);
+    assert_eq!(outputs.take_home, 113_427.5);
+    assert!((outputs.take_home_per_paycheck - 4_362.596).abs() < 0.001);
+    assert_eq!(outputs.marginal_rate, 0.22);
+    // Alone, the first earner would owe $5,240 of income tax and $6,502.50 of FICA
+    assert_eq!(outputs.second_income_tax, 11_830.0);
+    assert!((outputs.second_income_rate - 0.19717).abs() < 0.00001);
+    assert_eq!(outputs.second_take_home, 45_170.0);
+}
+
+#[test]
+fn paycheck_ignores_the_second_earner_when_filing_single() {
+    let single = PaycheckInputs {
+        status: FilingStatus::Single,
+        ..couple()
+    };
+    let outputs = compute_paycheck(&single, 2026).unwrap();
+    assert_eq!(outputs.gross, 85_000.0);
+    assert_eq!(outputs.second_income_tax, 0.0);
+    assert_eq!(outputs.second_take_home, 0.0);
+}
+
+#[test]
+fn paycheck_applies_the_wage_base_and_additional_medicare_tax() {
+    let inputs = PaycheckInputs {
+        first: Earner {
+            salary: 200_000.0,
+            pretax_deductions: 0.0,
+        },
+        second: Earner {
+            salary: 100_000.0,
+            pretax_deductions: 0.0,
+        },
+        ..couple()
+    };
+    let outputs = compute_paycheck(&inputs, 2026).unwrap();
+    // Social Security stops at $184,500 for the first earner; 0.9% applies above $250,000
+    let expected = 184_500.0 * 0.062 + 100_000.0 * 0.062 + 300_000.0 * 0.0145 + 50_000.0 * 0.009;
+    assert!((outputs.fica - expected).abs() < 0.005);
+
+    let over = PaycheckInputs {
+        first: Earner {
+            salary: 50_000.0,
+            pretax_deductions: 60_000.0,
+        },
+        ..couple()
+    };
+    assert!(compute_paycheck(&over, 2026).is_err());
+}
+
+#[test]
+fn bonus_withholding_is_flat_but_the_tax_follows_the_brackets() {
+    let inputs = BonusInputs {
+        status: FilingStatus::Single,
+        earner: Earner {
+            salary: 50_000.0,
+            pretax_deductions: 3_000.0,
+        },
+        bonus: 10_000.0,
+    };
+    let bonus = compute_bonus_tax(&inputs, 2026).unwrap();
+    assert_eq!(bonus.withheld, 2_200.0);
+    // Taxable income goes from $30,900 to $40,900, all in the 12% bracket
+    assert_eq!(bonus.actual_tax, 1_200.0);
+    assert_eq!(bonus.marginal_rate, 0.12);
+    assert_eq!(bonus.difference, 1_000.0);
+    assert_eq!(bonus.fica, 765.0);
+    assert!((bonus.salary_average_rate - 0.0692).abs() < 1e-12);
+
+    // A bonus reaching into the 24% bracket is under-withheld
+    let high_earner = BonusInputs {
+        earner: Earner {
+            salary: 120_000.0,
+            pretax_deductions: 0.0,
+        },
+        ..inputs
+    };
+    assert!(compute_bonus_tax(&high_earner, 2026).unwrap().difference < 0.0);
+}
+
+#[test]
+fn supplemental_withholding_rises_to_37_percent_above_a_million() {
+    assert_eq!(supplemental_withholding(100_000.0), 22_000.0);
+    assert_eq!(supplemental_withholding(1_500_000.0), 220_000.0 + 185_000.0);
+}
+
+fn lease_vs_buy_inputs() -> LeaseVsBuyInputs {
+    LeaseVsBuyInputs {
+        price: 35_000.0,
+        down_payment: 3_000.0,
+        residual_value: 21_000.0,
+        money_factor: 0.0025,
+        loan_rate: 0.065,
+        term_months: 36.0,
+        discount_rate: 0.05,
+    }
+}
+
+#[test]
+fn lease_vs_buy_counts_the_owners_ending_value() {
+    let comparison = compare_lease_vs_buy(&lease_vs_buy_inputs()).unwrap();
+    // (32,000 - 21,000) / 36 + (32,000 + 21,000) * 0.0025
+    assert!((comparison.lease.monthly_payment - 438.0556).abs() < 0.0001);
+    assert!((comparison.lease.total_paid - 18_770.0).abs() < 1e-9);
+    assert_eq!(comparison.lease.ending_value, 0.0);
+    assert!((comparison.buy.monthly_payment - 980.768).abs() < 0.001);
+    assert!((comparison.buy.net_cost - 17_307.65).abs() < 0.01);
+    assert!((comparison.lease.present_cost - 17_676.93).abs() < 0.01);
+    assert!((comparison.buy.present_cost - 17_643.51).abs() < 0.01);
+}
+
+#[test]
+fn lease_vs_buy_without_discounting_matches_the_net_costs() {
+    let inputs = LeaseVsBuyInputs {
+        discount_rate: 0.0,
+        ..lease_vs_buy_inputs()
+    };
+    let comparison = compare_lease_vs_buy(&inputs).unwrap();
+    assert!((comparison.lease.present_cost - comparison.lease.net_cost).abs() < 1e-9);
+    assert!((comparison.buy.present_cost - comparison.buy.net_cost).abs() < 1e-9);
+
+    let underwater = LeaseVsBuyInputs {
+        residual_value: 40_000.0,
+        ..lease_vs_buy_inputs()
+    };
+    assert!(compare_lease_vs_buy(&underwater).is_err());
+    let no_term = LeaseVsBuyInputs {
+        term_months: 0.0,
+        ..lease_vs_buy_inputs()
+    };
+    assert!(compare_lease_vs_buy(&no_term).is_err());
+}
+
+#[test]
+fn break_even_divides_fixed_costs_by_the_contribution_margin() {
+    let inputs = UnitBreakEvenInputs {
+        fixed_costs: 50_000.0,
+        variable_cost: 15.0,
+        price: 40.0,
+    };
+    let break_even = compute_unit_break_even(&inputs).unwrap();
+    assert_eq!(break_even.units, 2_000.0);
+    assert_eq!(break_even.revenue, 80_000.0);
+    assert_eq!(break_even.contribution_margin_ratio, 0.625);
+
+    // A fractional break-even rounds up to the next whole unit
+    let uneven = UnitBreakEvenInputs {
+        fixed_costs: 10_000.0,
+        variable_cost: 4.0,
+        price: 7.0,
+    };
+    let break_even = compute_unit_break_even(&uneven).unwrap();
+    assert!((break_even.units - 3_333.333).abs() < 0.001);
+    assert_eq!(break_even.whole_units, 3_334.0);
+    assert!((break_even.revenue - 23_333.333).abs() < 0.001);
+
+    let unprofitable = UnitBreakEvenInputs {
+        price: 15.0,
+        ..inputs
+    };
+    assert!(compute_unit_break_even(&unprofitable).is_err());
+}
+
+#[test]
+fn cost_and_revenue_curves_cross_at_break_even() {
+    let inputs = UnitBreakEvenInputs {
+        fixed_costs: 50_000.0,
+        variable_cost: 15.0,
+        price: 40.0,
+    };
+    let (cost, revenue) = cost_revenue_curves(&inputs, 200.0, 21);
+    assert_eq!(cost[0], 50_000.0);
+    assert_eq!(revenue[0], 0.0);
+    assert_eq!(cost[10], revenue[10]);
+    assert_eq!(revenue[20], 160_000.0);
+}
+
+fn contract_offer() -> ContractorComparisonInputs {
+    ContractorComparisonInputs {
+        status: FilingStatus::Single,
+        salary: 100_000.0,
+        benefits_value: 12_000.0,
+        match_rate: 1.0,
+        match_cap: 0.04,
+        retirement_contribution: 10_000.0,
+        hourly_rate: 75.0,
+        billable_hours: 1_800.0,
+        business_expenses: 5_000.0,
+    }
+}
+
+#[test]
+fn contractor_pays_both_halves_of_fica() {
+    let comparison = compare_w2_and_contractor(&contract_offer(), 2026).unwrap();
+    // $100,000 - $10,000 saved - $16,100 standard deduction = $73,900 taxable
+    assert_eq!(comparison.w2.income_tax, 10_970.0);
+    assert_eq!(comparison.w2.fica, 7_650.0);
+    assert_eq!(comparison.w2.employer_match, 4_000.0);
+    assert_eq!(comparison.w2.net_value, 97_380.0);
+    // $130,000 of profit; SE tax on 92.35% of it
+    assert_eq!(comparison.contractor.net_profit, 130_000.0);
+    assert!((comparison.contractor.self_employment_tax - 18_368.415).abs() < 1e-6);
+    assert!((comparison.contractor.income_tax - 15_549.474).abs() < 0.001);
+    assert!((comparison.contractor.net_value - 96_082.111).abs() < 0.001);
+}
+
+#[test]
+fn break_even_rate_and_equivalent_salary_match_the_other_offer() {
+    let inputs = contract_offer();
+    let comparison = compare_w2_and_contractor(&inputs, 2026).unwrap();
+    let rate = comparison.break_even_rate.unwrap();
+    assert!((rate - 76.1021).abs() < 0.0001);
+    let at_rate = ContractorComparisonInputs {
+        hourly_rate: rate,
+        ..inputs
+    };
+    let matched = compare_w2_and_contractor(&at_rate, 2026).unwrap();
+    assert!((matched.contractor.net_value - comparison.w2.net_value).abs() < 1e-6);
+
+    let salary = comparison.equivalent_salary.unwrap();
+    assert!((salary - 98_254.35).abs() < 0.01);
+
+    let no_hours = ContractorComparisonInputs {
+        billable_hours: 0.0,
+        ..inputs
+    };
+    assert!(compare_w2_and_contractor(&no_hours, 2026).is_err());
+}
+
+#[test]
+fn dcf_adds_a_gordon_terminal_value_to_the_projected_flows() {
+    let inputs = DcfInputs {
+        discount_rate: 0.09,
+        terminal_growth: 0.025,
+        series: cash_flows(
+            CashFlowIndexing::Period,
+            &[
+                (1, "", 100_000.0),
+                (2, "", 110_000.0),
+                (3, "", 120_000.0),
+                (4, "", 130_000.0),
+                (5, "", 140_000.0),
+            ],
+        ),
+    };
+    let outputs = compute_dcf(&inputs).unwrap();
+
+    assert!((outputs.present_values[0] - 91_743.119266).abs() < 1e-6);
+    assert!((outputs.terminal_value - 2_207_692.307692).abs() < 1e-6);
+    assert!((outputs.terminal_present_value - 1_434_848.522059).abs() < 1e-6);
+    assert!((outputs.enterprise_value - 1_894_924.129712).abs() < 1e-6);
+
+    let no_spread = DcfInputs {
+        terminal_growth: 0.09,
+        ..inputs.clone()
+    };
+    assert!(compute_dcf(&no_spread).is_err());
+    let no_flows = DcfInputs {
+        series: CashFlowSeries::default(),
+        ..inputs
+    };
+    assert!(compute_dcf(&no_flows).is_err());
+}
+
+fn charitable_inputs() -> CharitableInputs {
+    CharitableInputs {
+        status: FilingStatus::MarriedFilingJointly,
+        income: 150_000.0,
+        other_itemized: 15_000.0,
+        cash_gift: 8_000.0,
+        stock_value: 4_000.0,
+        stock_basis: 1_000.0,
+        bunch_years: 3,
+    }
+}
+
+#[test]
+fn bunching_gifts_itemizes_when_a_usual_year_would_not() {
+    let benefit = compute_charitable_benefit(&charitable_inputs(), 2026).unwrap();
+
+    // $27,000 of itemized deductions falls short of the $32,200 standard deduction
+    assert!(!benefit.annual.itemizes);
+    assert_eq!(benefit.annual_savings, 0.0);
+    assert!((benefit.baseline_tax - 15_340.0).abs() < 1e-6);
+    assert!((benefit.annual.capital_gains_avoided - 450.0).abs() < 1e-6);
+
+    assert!(benefit.bunched.itemizes);
+    assert_eq!(benefit.bunched.deduction, 51_000.0);
+    assert!((benefit.bunched.tax - 11_384.0).abs() < 1e-6);
+    assert!((benefit.bunching_savings - 3_956.0).abs() < 1e-6);
+    assert!((benefit.bunched.capital_gains_avoided - 1_350.0).abs() < 1e-6);
+}
+
+#[test]
+fn charitable_deductions_are_capped_by_agi() {
+    let inputs = CharitableInputs {
+        status: FilingStatus::Single,
+        income: 100_000.0,
+        other_itemized: 0.0,
+        cash_gift: 50_000.0,
+        stock_value: 40_000.0,
+        stock_basis: 40_000.0,
+        bunch_years: 1,
+    };
+    let benefit = compute_charitable_benefit(&inputs, 2026).unwrap();
+
+    // Cash takes the whole 60% limit, leaving no room for the stock
+    assert_eq!(benefit.annual.gifts_deducted, 60_000.0);
+    assert_eq!(benefit.annual.capital_gains_avoided, 0.0);
+    assert!(benefit.bunching_savings.abs() < 1e-9);
+
+    let no_years = CharitableInputs {
+        bunch_years: 0,
+        ..inputs
+    };
+    assert!(compute_charitable_benefit(&no_years, 2026).is_err());
+}
+
+#[test]
+fn wacc_weights_each_cost_by_market_value() {
+    let inputs = WaccInputs {
+        equity_value: 600_000.0,
+        debt_value: 400_000.0,
+        cost_of_equity: 0.10,
+        cost_of_debt: 0.06,
+        tax_rate: 0.21,
+    };
+    let wacc = compute_wacc(&inputs).unwrap();
+
+    assert!((wacc.equity_weight - 0.6).abs() < 1e-12);
+    assert!((wacc.after_tax_cost_of_debt - 0.0474).abs() < 1e-12);
+    assert!((wacc.wacc - 0.07896).abs() < 1e-12);
+
+    // An all-equity company's WACC is its cost of equity
+    let no_debt = WaccInputs {
+        debt_value: 0.0,
+        ..inputs
+    };
+    assert!((compute_wacc(&no_debt).unwrap().wacc - 0.10).abs() < 1e-12);
+
+    let no_capital = WaccInputs {
+        equity_value: 0.0,
+        ..no_debt
+    };
+    assert!(compute_wacc(&no_capital).is_err());
+    let over_taxed = WaccInputs {
+        tax_rate: 1.5,
+        ..inputs
+    };
+    assert!(compute_wacc(&over_taxed).is_err());
+}
+
+#[test]
+fn capm_adds_beta_times_the_premium_to_the_risk_free_rate() {
+    let inputs = CapmInputs {
+        risk_free_rate: 0.04,
+        beta: 1.2,
+        market_risk_premium: 0.055,
+    };
+    assert!((capm_expected_return(&inputs) - 0.106).abs() < 1e-12);
+
+    // A beta of zero earns the risk-free rate, and a negative beta less than it
+    let riskless = CapmInputs {
+        beta: 0.0,
+        ..inputs
+    };
+    assert_eq!(capm_expected_return(&riskless), 0.04);
+    let hedge = CapmInputs {
+        beta: -0.5,
+        ..inputs
+    };
+    assert!(capm_expected_return(&hedge) < 0.04);
+}
+
+fn retirement_income_inputs() -> RetirementIncomeInputs {
+    RetirementIncomeInputs {
+        retirement_age: 67.0,
+        plan_to_age: 95.0,
+        social_security: 2_400.0,
+        social_security_age: 67.0,
+        pension: 800.0,
+        pension_cola: 0.0,
+        annuity_balance: 100_000.0,
+        annuity_rate: 0.05,
+        annuity_years: 20.0,
+        portfolio_balance: 500_000.0,
+        withdrawal_rate: 0.04,
+        portfolio_return: 0.06,
+        monthly_expenses: 5_000.0,
+        inflation_rate: 0.025,
+        healthcare: HealthcareInputs::default(),
+        accounts: AccountSplit {
+            taxable: 0.0,
+            traditional: 0.0,
+            roth: 1.0,
+        },
+        taxable_gain_share: 0.0,
+    }
+}
+
+#[test]
+fn retirement_income_adds_every_source_each_year() {
+    let income = project_retirement_income(&retirement_income_inputs(), 2026).unwrap();
+
+    assert_eq!(income.years.len(), 28);
+    assert_eq!(income.annuity_payout, 659.96);
+    let first = income.years[0];
+    assert!((first.portfolio - 20_000.0 / 12.0).abs() < 1e-9);
+    assert!((first.total - 5_526.626667).abs() < 1e-6);
+    assert!((income.years[1].social_security - 2_460.0).abs() < 1e-9);
+    // The annuity stops after 20 years, and the flat pension falls behind inflation
+    assert_eq!(income.years[20].annuity, 0.0);
+    assert_eq!(income.shortfall_age, Some(71.0));
+    assert_eq!(income.depletion_age, None);
+}
+
+#[test]
+fn retirement_income_taxes_traditional_withdrawals_and_taxable_gains_but_not_roth() {
+    let roth = project_retirement_income(&retirement_income_inputs(), 2026).unwrap();
+    // Only the pension, the annuity, and 85% of Social Security are taxed
+    let ordinary = 12.0 * (800.0 + 659.96 + 0.85 * 2_400.0);
+    let first = roth.years[0];
+    let expected = tax_table(2026, FilingStatus::Single).total_tax(ordinary, 0.0) / 12.0;
+    assert!((first.federal_tax - expected).abs() < 1e-6);
+    assert!((first.net - (first.total - first.federal_tax)).abs() < 1e-9);
+
+    let traditional = RetirementIncomeInputs {
+        accounts: AccountSplit::default(),
+        ..retirement_income_inputs()
+    };
+    let traditional = project_retirement_income(&traditional, 2026).unwrap();
+    assert!(traditional.years[0].federal_tax > first.federal_tax);
+    assert!(traditional.shortfall_age < roth.shortfall_age);
+
+    // $40,000 a year out of a brokerage account, enough to push gains past the 0% bracket
+    let brokerage = |taxable_gain_share| RetirementIncomeInputs {
+        withdrawal_rate: 0.08,
+        accounts: AccountSplit {
+            taxable: 1.0,
+            traditional: 0.0,
+            roth: 0.0,
+        },
+        taxable_gain_share,
+        ..retirement_income_inputs()
+    };
+    let basis = project_retirement_income(&brokerage(0.0), 2026).unwrap();
+    assert!((basis.years[0].federal_tax - first.federal_tax).abs() < 1e-9);
+    let gains = project_retirement_income(&brokerage(1.0), 2026).unwrap();
+    assert!(gains.years[0].federal_tax > first.federal_tax);
+
+    let oversold = brokerage(1.5);
+    assert!(project_retirement_income(&oversold, 2026).is_err());
+}
+
+#[test]
+fn retirement_income_waits_for_social_security_and_stops_withdrawals_when_savings_run_out() {
+    let inputs = RetirementIncomeInputs {
+        retirement_age: 62.0,
+        social_security_age: 70.0,
+        withdrawal_rate: 0.10,
+        portfolio_return: 0.0,
+        inflation_rate: 0.0,
+        ..retirement_income_inputs()
+    };
+    let income = project_retirement_income(&inputs, 2026).unwrap();
+
+    assert_eq!(income.years[7].social_security, 0.0);
+    assert_eq!(income.years[8].social_security, 2_400.0);
+    // $50,000 a year empties $500,000 after ten withdrawals
+    assert_eq!(income.depletion_age, Some(72.0));
+    assert_eq!(income.years[10].portfolio, 0.0);
+
+    let backwards = RetirementIncomeInputs {
+        plan_to_age: 60.0,
+        ..inputs
+    };
+    assert!(project_retirement_income(&backwards, 2026).is_err());
+}
+
+#[test]
+fn irmaa_tiers_start_above_each_floor() {
+    assert_eq!(medicare_premiums(2024).year, 2025);
+    assert_eq!(medicare_premiums(2030).year, 2026);
+    let premiums = medicare_premiums(2026);
+    let joint = FilingStatus::MarriedFilingJointly;
+    assert_eq!(premiums.irmaa_tier(109_000.0, FilingStatus::Single).0, 0);
+    assert_eq!(premiums.irmaa_tier(109_000.01, FilingStatus::Single).0, 1);
+    assert_eq!(premiums.irmaa_tier(218_000.0, joint).0, 0);
+    assert_eq!(premiums.irmaa_tier(600_000.0, FilingStatus::Single).0, 5);
+    assert_eq!(premiums.irmaa_tier(600_000.0, joint).0, 4);
+}
+
+#[test]
+fn retirement_income_adds_medicare_from_65_with_irmaa_for_both_spouses() {
+    let inputs = RetirementIncomeInputs {
+        retirement_age: 63.0,
+        healthcare: HealthcareInputs {
+            status: FilingStatus::MarriedFilingJointly,
+            income: 300_000.0,
+            out_of_pocket: 500.0,
+            healthcare_inflation: 0.05,
+        },
+        ..retirement_income_inputs()
+    };
+    let income = project_retirement_income(&inputs, 2026).unwrap();
+
+    assert_eq!(income.irmaa_tier, 2);
+    let first = income.years[0];
+    assert_eq!(first.healthcare.medicare, 0.0);
+    assert_eq!(first.healthcare.total, 500.0);
+    assert!((first.expenses - 5_500.0).abs() < 1e-9);
+    // Two standard Part B premiums, and each spouse's $202.90 + $37.50 surcharge, two years on
+    let at_65 = income.years[2].healthcare;
+    assert!((at_65.medicare - 405.80 * 1.1025).abs() < 1e-9);
+    assert!((at_65.irmaa - 480.80 * 1.1025).abs() < 1e-9);
+    assert!((at_65.total - (405.80 + 480.80 + 500.0) * 1.1025).abs() < 1e-9);
+}
+
+#[test]
+fn return_series_reads_labeled_columns_or_a_single_line() {
+    let pasted = "Month,Return\nJan,1.2%\nFeb,-0.8%\n\nMar,\"2.1%\"\n";
+    let returns = parse_return_series(pasted).unwrap();
+    assert_eq!(returns.len(), 3);
+    assert!((returns[1] + 0.008).abs() < 1e-12);
+
+    assert_eq!(parse_return_series("0.01, -0.02,0.03").unwrap().len(), 3);
+    assert_eq!(
+        parse_return_series("Month\tReturn\n2024-01\t0.01\n2024-02\tn/a"),
+        Err("Line 3: \"n/a\" is not a valid number".to_string())
+    );
+    assert!(parse_return_series("0.01").is_err());
+}
+
+#[test]
+fn return_stats_annualize_sharpe_and_sortino() {
+    let returns = [
+        0.012, -0.008, 0.021, 0.005, -0.015, 0.030, 0.009, -0.022, 0.018, 0.004, 0.011, -0.003,
+    ];
+    let stats = compute_return_stats(&returns, 12.0, 0.04).unwrap();
+
+    assert!((stats.mean - 0.0051666667).abs() < 1e-9);
+    assert!((stats.std_dev - 0.0151167176).abs() < 1e-9);
+    assert!((stats.downside_deviation - 0.0097431536).abs() < 1e-9);
+    assert!((stats.annual_sharpe - 0.4337774).abs() < 1e-6);
+    assert!((stats.annual_sortino.unwrap() - 0.6730153).abs() < 1e-6);
+
+    // Nothing below the risk-free return leaves no downside to divide by
+    let steady_gains = compute_return_stats(&[0.01, 0.02], 12.0, 0.0).unwrap();
+    assert_eq!(steady_gains.sortino, None);
+    assert!(compute_return_stats(&[0.01, 0.01], 12.0, 0.0).is_err());
+}
+
+#[test]
+fn normal_cdf_matches_tables_and_is_symmetric() {
+    assert_eq!(normal_cdf(0.0), 0.5);
+    assert!((normal_cdf(1.96) - 0.9750021048517795).abs() < 1e-14);
+    assert!((normal_cdf(-3.0) - 0.0013498980316301).abs() < 1e-15);
+    assert!((normal_cdf(1.2) + normal_cdf(-1.2) - 1.0).abs() < 1e-15);
+    assert!(normal_cdf(-40.0) == 0.0 && normal_cdf(40.0) == 1.0);
+}
+
+#[test]
+fn black_scholes_prices_satisfy_put_call_parity() {
+    let inputs = OptionInputs {
+        spot: 100.0,
+        strike: 95.0,
+        volatility: 0.25,
+        risk_free_rate: 0.04,
+        dividend_yield: 0.02,
+        years: 0.5,
+    };
+    let prices = black_scholes(&inputs).unwrap();
+
+    assert!((prices.call - 10.110349335).abs() < 1e-8);
+    assert!((prices.put - 4.224239924).abs() < 1e-8);
+    // C - P = S e^(-qT) - K e^(-rT)
+    let parity = 100.0 * (-0.01f64).exp() - 95.0 * (-0.02f64).exp();
+    assert!((prices.call - prices.put - parity).abs() < 1e-10);
+
+    let expired = OptionInputs {
+        years: 0.0,
+        ..inputs
+    };
+    assert!(black_scholes(&expired).is_err());
+}
+
+fn at_the_money_option() -> OptionInputs {
+    OptionInputs {
+        spot: 100.0,
+        strike: 100.0,
+        volatility: 0.20,
+        risk_free_rate: 0.05,
+        dividend_yield: 0.0,
+        years: 1.0,
+    }
+}
+
+#[test]
+fn binomial_tree_prices_early_exercise() {
+    let inputs = at_the_money_option();
+    let american = BinomialInputs {
+        steps: 100,
+        exercise: Exercise::American,
+    };
+    let european = BinomialInputs {
+        exercise: Exercise::European,
+        ..american
+    };
+    let early = binomial_prices(&inputs, &american).unwrap();
+    let held = binomial_prices(&inputs, &european).unwrap();
+
+    assert!((early.put - 6.082354409).abs() < 1e-8);
+    assert!((held.put - 5.553554112).abs() < 1e-8);
+    // Without a dividend an American call is never exercised early
+    assert!((early.call - held.call).abs() < 1e-10);
+    assert!((early.call - 10.430611662).abs() < 1e-8);
+    // The European tree converges on Black-Scholes
+    let exact = black_scholes(&inputs).unwrap();
+    let fine = BinomialInputs {
+        steps: 2000,
+        ..european
+    };
+    assert!((binomial_prices(&inputs, &fine).unwrap().put - exact.put).abs() < 2e-3);
+
+    let flat = BinomialInputs {
+        steps: 0,
+        ..american
+    };
+    assert!(binomial_prices(&inputs, &flat).is_err());
+}
+
+#[test]
+fn price_lattice_marks_early_exercise() {
+    let inputs = at_the_money_option();
+    let binomial = BinomialInputs {
+        steps: 2,
+        exercise: Exercise::American,
+    };
+    let lattice = price_lattice(&inputs, &binomial).unwrap();
+
+    assert_eq!(lattice.len(), 3);
+    assert_eq!(lattice[2].len(), 3);
+    assert!((lattice[0][0].put - 5.737654377).abs() < 1e-8);
+    // After one down move the put is worth more exercised than held
+    assert!(lattice[1][0].put_exercised);
+    assert!((lattice[1][0].put - (100.0 - lattice[1][0].spot)).abs() < 1e-12);
+    assert!(!lattice[1][1].put_exercised);
+    assert!(lattice.iter().flatten().all(|node| !node.call_exercised));
+}
+
+fn long_term_care_inputs() -> LongTermCareInputs {
+    LongTermCareInputs {
+        current_age: 60.0,
+        annual_cost: 100_000.0,
+        cost_growth: 0.03,
+        care_years: 3.0,
+        annual_premium: 3_000.0,
+        annual_benefit: 72_000.0,
+        benefit_years: 3.0,
+        inflation_protection: 0.03,
+        discount_rate: 0.04,
+        rates: DEFAULT_CARE_RATES.to_vec(),
+    }
+}
+
+#[test]
+fn long_term_care_weights_each_year_by_its_chances() {
+    // Two years left, an even chance of needing care each year, and one-year stays
+    let inputs = LongTermCareInputs {
+        current_age: 108.0,
+        annual_cost: 1_000.0,
+        cost_growth: 0.0,
+        care_years: 1.0,
+        annual_premium: 100.0,
+        annual_benefit: 600.0,
+        benefit_years: 1.0,
+        inflation_protection: 0.0,
+        discount_rate: 0.0,
+        rates: vec![CareRate {
+            from_age: 0.0,
+            mortality: 0.0,
+            incidence: 0.5,
+        }],
+    };
+    let projection = project_long_term_care(&inputs).unwrap();
+
+    assert_eq!(projection.years.len(), 2);
+    assert_eq!(projection.years[1].healthy, 0.5);
+    assert_eq!(projection.lifetime_probability, 0.75);
+    assert_eq!(projection.pv_costs, 750.0);
+    assert_eq!(projection.pv_premiums, 150.0);
+    assert_eq!(projection.pv_benefits, 450.0);
+    assert_eq!(projection.pv_uncovered, 300.0);
+}
+
+#[test]
+fn long_term_care_defaults_discount_a_three_year_stay() {
+    let projection = project_long_term_care(&long_term_care_inputs()).unwrap();
+
+    assert_eq!(projection.years.len(), 50);
+    assert!((projection.lifetime_probability - 0.4150752541).abs() < 1e-9);
+    assert!((projection.pv_costs - 102_077.0672839).abs() < 1e-6);
+    assert!((projection.pv_premiums - 41_237.3448183).abs() < 1e-6);
+    assert!((projection.pv_benefits - 73_495.4884444).abs() < 1e-6);
+    // A stay that started at 85 is under way with the two that started just before it
+    assert!((projection.years[25].in_care - 0.0540586665).abs() < 1e-9);
+
+    let mut impossible = long_term_care_inputs();
+    impossible.rates[0].incidence = 0.995;
+    assert!(project_long_term_care(&impossible).is_err());
+}
+
+fn spia_quote(monthly_payout: f64, cola: f64) -> SpiaQuote {
+    SpiaQuote {
+        insurer: "Insurer".to_string(),
+        premium: 100_000.0,
+        monthly_payout,
+        cola,
+    }
+}
+
+#[test]
+fn spia_cash_flows_raise_the_payout_each_year() {
+    let series = quote_cash_flows(&spia_quote(500.0, 0.02), 13);
+
+    assert_eq!(series.flows.len(), 14);
+    assert_eq!(series.flows[0].amount, -100_000.0);
+    assert_eq!(series.flows[12].amount, 500.0);
+    assert_eq!(series.flows[13].amount, 510.0);
+}
+
+#[test]
+fn spia_returns_grow_with_longevity() {
+    let inputs = SpiaInputs {
+        purchase_age: 65.0,
+        quotes: vec![spia_quote(620.0, 0.0), spia_quote(480.0, 0.02)],
+    };
+    let returns = compare_quotes(&inputs).unwrap();
+
+    let level = &returns[0];
+    assert!((level.payout_rate - 0.0744).abs() < 1e-12);
+    assert_eq!(level.break_even_age, Some(78.5));
+    assert!((level.irrs[2].unwrap() - 0.0574470).abs() < 1e-6);
+    assert!(level.irrs.windows(2).all(|pair| pair[0] < pair[1]));
+    // The COLA quote trails at first and catches up by 100
+    let cola = &returns[1];
+    assert!(cola.irrs[0].unwrap() < 0.0);
+    assert!(cola.irrs[4].unwrap() > returns[0].irrs[4].unwrap() - 0.003);
+
+    let free = SpiaInputs {
+        quotes: vec![spia_quote(0.0, 0.0)],
+        ..inputs
+    };
+    assert!(compare_quotes(&free).is_err());
+}
+
+#[test]
+fn retirement_income_falls_short_sooner_at_higher_inflation() {
+    let scenarios = InflationScenarios {
+        enabled: true,
+        spread: 0.01,
+    };
+    let (_, results) = run_inflation_scenarios(&retirement_income_inputs(), &scenarios, |inputs| {
+        project_retirement_income(inputs, 2026)
+    })
+    .unwrap();
+
+    // The flat pension and annuity fall behind expenses faster when prices rise faster
+    assert_eq!(results.low.shortfall_age, Some(73.0));
+    assert_eq!(results.base.shortfall_age, Some(71.0));
+    assert_eq!(results.high.shortfall_age, Some(70.0));
+    // Healthcare keeps its own rate in every scenario
+    assert_eq!(
+        results.low.years[10].healthcare,
+        results.high.years[10].healthcare
+    );
+}
+
+fn monte_carlo_inputs(model: ReturnModel) -> MonteCarloInputs {
+    MonteCarloInputs {
+        starting_balance: 1_000_000.0,
+        annual_withdrawal: 40_000.0,
+        inflation_rate: 0.0,
+        years: 30,
+        paths: 100,
+        model,
+    }
+}
+
+#[test]
+fn monte_carlo_without_volatility_matches_the_fixed_return_plan() {
+    let steady = ReturnModel::Normal {
+        mean: 0.05,
+        volatility: 0.0,
+    };
+    let result =
+        simulate_retirement(&monte_carlo_inputs(steady.clone()), &mut Rng::new(1)).unwrap();
+
+    assert_eq!(result.success_rate, 1.0);
+    assert_eq!(result.median_shortfall_year, None);
+    assert_eq!(result.bands.len(), 31);
+    assert!((result.bands.p50[30] - 1_531_510.780024).abs() < 1e-4);
+    assert_eq!(result.bands.p10, result.bands.p90);
+    // Resampling a history of one return gives the same plan
+    let history = ReturnModel::Bootstrap {
+        history: vec![0.05],
+        block_size: 3,
+    };
+    let resampled = simulate_retirement(&monte_carlo_inputs(history), &mut Rng::new(1)).unwrap();
+    assert_eq!(resampled.bands, result.bands);
+
+    // $100,000 a year can't be paid in full in year 13
+    let overdrawn = MonteCarloInputs {
+        annual_withdrawal: 100_000.0,
+        ..monte_carlo_inputs(steady)
+    };
+    let result = simulate_retirement(&overdrawn, &mut Rng::new(1)).unwrap();
+    assert_eq!(result.success_rate, 0.0);
+    assert_eq!(result.median_shortfall_year, Some(13));
+    assert_eq!(result.bands.p50[30], 0.0);
+}
+
+#[test]
+fn monte_carlo_portfolio_weights_its_asset_class_returns() {
+    let flat = |name, mean| AssetClass {
+        name,
+        mean,
+        volatility: 0.0,
+    };
+    let allocation = Allocation {
+        name: "Flat",
+        assets: vec![flat("Stocks", 0.07), flat("Bonds", 0.03)],
+        weights: vec![0.5, 0.5],
+        correlations: vec![vec![1.0, 0.0], vec![0.0, 1.0]],
+    };
+    let portfolio = simulate_retirement(
+        &monte_carlo_inputs(ReturnModel::Portfolio(allocation.clone())),
+        &mut Rng::new(1),
+    )
+    .unwrap();
+    let steady = ReturnModel::Normal {
+        mean: 0.05,
+        volatility: 0.0,
+    };
+    let expected = simulate_retirement(&monte_carlo_inputs(steady), &mut Rng::new(1)).unwrap();
+    assert!((portfolio.bands.p50[30] - expected.bands.p50[30]).abs() < 1e-6);
+
+    let lopsided = Allocation {
+        weights: vec![0.5, 0.6],
+        ..allocation
+    };
+    let error = simulate_retirement(
+        &monte_carlo_inputs(ReturnModel::Portfolio(lopsided)),
+        &mut Rng::new(1),
+    );
+    assert!(error.unwrap_err().contains("add up to 100%"));
+}
+
+#[test]
+fn monte_carlo_simulates_presets_and_rejects_correlations_that_cannot_all_hold() {
+    let mut allocation = allocation_presets()
+        .into_iter()
+        .find(|preset| preset.name == "60/40")
+        .unwrap();
+    let inputs = monte_carlo_inputs(ReturnModel::Portfolio(allocation.clone()));
+    let first = simulate_retirement(&inputs, &mut Rng::new(7)).unwrap();
+    assert_eq!(
+        first,
+        simulate_retirement(&inputs, &mut Rng::new(7)).unwrap()
+    );
+    assert!(first.success_rate > 0.0 && first.success_rate <= 1.0);
+
+    // US and international stocks can't both track bonds closely while moving opposite each other
+    allocation.set_correlation(0, 1, -0.9);
+    allocation.set_correlation(0, 2, 0.9);
+    allocation.set_correlation(1, 2, 0.9);
+    assert_eq!(allocation.correlations[2][1], 0.9);
+    let error = simulate_retirement(
+        &monte_carlo_inputs(ReturnModel::Portfolio(allocation)),
+        &mut Rng::new(7),
+    );
+    assert!(error.unwrap_err().contains("correlations"));
+}
+
+#[test]
+fn monte_carlo_repeats_with_the_same_seed() {
+    let inputs = MonteCarloInputs {
+        inflation_rate: 0.025,
+        ..monte_carlo_inputs(ReturnModel::Normal {
+            mean: 0.06,
+            volatility: 0.12,
+        })
+    };
+    let first = simulate_retirement(&inputs, &mut Rng::new(42)).unwrap();
+    let again = simulate_retirement(&inputs, &mut Rng::new(42)).unwrap();
+
+    assert_eq!(first, again);
+    assert!(first.success_rate > 0.0 && first.success_rate <= 1.0);
+    assert!(first.bands.p10[30] <= first.bands.p50[30]);
+    assert!(first.bands.p50[30] <= first.bands.p90[30]);
+
+    let no_paths = MonteCarloInputs { paths: 0, ..inputs };
+    assert!(simulate_retirement(&no_paths, &mut Rng::new(42)).is_err());
+}
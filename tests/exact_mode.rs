@@ -0,0 +1,133 @@
+//! Compares the f64 and Decimal (exact) modes of the math core. The app is a single binary
+//! crate, so the modules are compiled in directly rather than imported.
+
+#[path = "../src/annuity.rs"]
+#[allow(dead_code)]
+mod annuity;
+#[path = "../src/compounding.rs"]
+#[allow(dead_code)]
+mod compounding;
+#[path = "../src/fin_numeric.rs"]
+#[allow(dead_code)]
+mod fin_numeric;
+#[path = "../src/future_value.rs"]
+#[allow(dead_code)]
+mod future_value;
+
+use fin_numeric::FinNumeric;
+use rust_decimal::Decimal;
+
+fn dec(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+#[test]
+fn compute_fv_is_exact_in_decimal_mode() {
+    let fv = compounding::compute_fv(dec("1000"), dec("0.05"), dec("1"), dec("2"));
+    assert_eq!(fv, dec("1102.50"));
+}
+
+#[test]
+fn compute_fv_modes_agree_to_the_cent() {
+    for (principal, rate, n, years) in [
+        (1000.0, 0.03875, 1.0, 7.0),
+        (250_000.0, 0.045, 12.0, 30.0),
+        (10_000_000.0, 0.0525, 365.0, 10.0),
+        (0.01, 0.10, 4.0, 50.0),
+    ] {
+        let approx = compounding::compute_fv(principal, rate, n, years);
+        let exact = compounding::compute_fv(
+            Decimal::from_f64(principal),
+            Decimal::from_f64(rate),
+            Decimal::from_f64(n),
+            Decimal::from_f64(years),
+        );
+        assert!(
+            (approx - exact.to_f64()).abs() <= 0.01,
+            "f64 {} vs Decimal {} for {:?}",
+            approx,
+            exact,
+            (principal, rate, n, years)
+        );
+    }
+}
+
+#[test]
+fn compute_pv_round_trips_in_decimal_mode() {
+    let pv = compounding::compute_pv(dec("1102.50"), dec("0.05"), dec("1"), dec("2"));
+    assert_eq!(pv, dec("1000.00"));
+}
+
+#[test]
+fn fv_annuity_modes_agree_to_the_cent() {
+    let approx = annuity::fv_annuity(100.0, 0.005, 360.0);
+    let exact = annuity::fv_annuity(dec("100"), dec("0.005"), dec("360"));
+    assert!((approx - exact.to_f64()).abs() <= 0.01);
+}
+
+#[test]
+fn solve_rate_modes_agree() {
+    let approx = compounding::solve_rate(1000.0, 2000.0, 12.0, 10.0);
+    let exact = compounding::solve_rate(dec("1000"), dec("2000"), dec("12"), dec("10"));
+    assert!((approx - exact.to_f64()).abs() < 1e-9);
+}
+
+#[test]
+fn checked_compute_fv_reports_overflow_in_both_modes() {
+    let approx = compounding::checked_compute_fv(1e6, 0.5, 365.0, 5000.0);
+    assert_eq!(approx, Err(compounding::ComputeError::Overflow));
+
+    let exact = compounding::checked_compute_fv(dec("1000000"), dec("0.5"), dec("365"), dec("500"));
+    assert_eq!(exact, Err(compounding::ComputeError::Overflow));
+}
+
+#[test]
+fn daily_compounding_over_centuries_stays_finite() {
+    let fv = compounding::checked_compute_fv(1000.0, 0.01, 365.0, 500.0).unwrap();
+    assert!(fv.is_finite());
+    assert!((fv / (1000.0 * (0.01f64 * 500.0).exp()) - 1.0).abs() < 1e-3);
+}
+
+#[test]
+fn exact_future_value_keeps_every_cent_of_a_large_principal() {
+    let inputs = future_value::FutureValueInputs {
+        principal: 123_456_789_012.34,
+        target_value: 0.0,
+        annual_rate: 0.05,
+        years: 3.0,
+        compounding: compounding::Compounding::Annual,
+        monthly_contribution: 0.0,
+        contribution_timing: compounding::ContributionTiming::End,
+        inflation_rate: 0.0,
+    };
+
+    assert_eq!(
+        future_value::exact_future_value(&inputs),
+        Ok(dec("142916665380.41"))
+    );
+}
+
+#[test]
+fn exact_future_value_reports_overflow_from_the_contributions() {
+    // A $1 principal passes the lump sum's check, but 500 years of $1,000 a month doesn't fit in
+    // a Decimal
+    let inputs = future_value::FutureValueInputs {
+        principal: 1.0,
+        target_value: 0.0,
+        annual_rate: 0.13,
+        years: 500.0,
+        compounding: compounding::Compounding::Annual,
+        monthly_contribution: 1_000.0,
+        contribution_timing: compounding::ContributionTiming::End,
+        inflation_rate: 0.0,
+    };
+
+    assert_eq!(
+        future_value::exact_future_value(&inputs),
+        Err(compounding::ComputeError::Overflow)
+    );
+    let approx = future_value::compute_future_value(&inputs)
+        .future_value
+        .unwrap();
+    assert!((approx / 3.38e31 - 1.0).abs() < 0.01);
+}
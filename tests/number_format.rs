@@ -0,0 +1,80 @@
+//! Chart axis formatting tests. The app is a single binary crate, so the modules are compiled in
+//! directly rather than imported.
+
+#[path = "../src/number_format.rs"]
+#[allow(dead_code)]
+mod number_format;
+#[path = "../src/storage.rs"]
+#[allow(dead_code)]
+mod storage;
+
+use number_format::{dollars, AxisFormat, NumberFormat};
+
+fn german_euros() -> NumberFormat {
+    NumberFormat {
+        locale: "de".to_string(),
+        currency: "EUR".to_string(),
+    }
+}
+
+#[test]
+fn currency_axis_follows_locale_and_currency() {
+    assert_eq!(
+        AxisFormat::Currency.format(1_234_567.4, &NumberFormat::default()),
+        "$1,234,567"
+    );
+    assert_eq!(
+        AxisFormat::Currency.format(1_234_567.4, &german_euros()),
+        "€1.234.567"
+    );
+}
+
+#[test]
+fn currency_puts_the_sign_ahead_of_the_symbol() {
+    let format = NumberFormat::default();
+    assert_eq!(format.currency(-1_234.5, 2), "-$1,234.50");
+    assert_eq!(format.currency(-0.001, 2), "$0.00");
+    assert_eq!(german_euros().currency(-1_234.5, 2), "-€1.234,50");
+    assert_eq!(
+        AxisFormat::Currency.format(-250_000.0, &format),
+        "-$250,000"
+    );
+}
+
+#[test]
+fn dollars_truncate_to_the_cent() {
+    assert_eq!(dollars(1_234.567), "$1,234.56");
+    assert_eq!(dollars(-12.345), "-$12.34");
+    assert_eq!(dollars(-0.004), "$0.00");
+}
+
+#[test]
+fn percent_axis_scales_fractions() {
+    assert_eq!(
+        AxisFormat::Percent.format(0.045, &NumberFormat::default()),
+        "4.5%"
+    );
+    assert_eq!(AxisFormat::Percent.format(0.045, &german_euros()), "4,5%");
+    assert_eq!(
+        AxisFormat::Percent.format(-0.0001, &NumberFormat::default()),
+        "0.0%"
+    );
+}
+
+#[test]
+fn compact_axis_abbreviates_large_values() {
+    let format = NumberFormat::default();
+    assert_eq!(AxisFormat::Compact.format(950.0, &format), "950");
+    assert_eq!(AxisFormat::Compact.format(12_500.0, &format), "12.5K");
+    assert_eq!(AxisFormat::Compact.format(3_040_000.0, &format), "3.0M");
+    assert_eq!(AxisFormat::Compact.format(2.5e9, &format), "2.5B");
+}
+
+#[test]
+fn unknown_settings_fall_back() {
+    let format = NumberFormat {
+        locale: "xx".to_string(),
+        currency: "CHF".to_string(),
+    };
+    assert_eq!(AxisFormat::Currency.format(1500.0, &format), "CHF1,500");
+}
@@ -0,0 +1,351 @@
+//! Scenario schema migration, comparison, sharing, snapshot, and webhook export tests. The app is a single binary crate, so the modules are
+//! compiled in directly rather than imported.
+
+#[path = "../src/compounding.rs"]
+#[allow(dead_code)]
+mod compounding;
+#[path = "../src/fin_numeric.rs"]
+#[allow(dead_code)]
+mod fin_numeric;
+#[path = "../src/future_value.rs"]
+#[allow(dead_code)]
+mod future_value;
+#[path = "../src/scenario.rs"]
+#[allow(dead_code)]
+mod scenario;
+#[path = "../src/scenario_report.rs"]
+#[allow(dead_code)]
+mod scenario_report;
+#[path = "../src/sequence_risk.rs"]
+#[allow(dead_code)]
+mod sequence_risk;
+#[path = "../src/share.rs"]
+#[allow(dead_code)]
+mod share;
+#[path = "../src/snapshot.rs"]
+#[allow(dead_code)]
+mod snapshot;
+#[path = "../src/storage.rs"]
+#[allow(dead_code)]
+mod storage;
+#[path = "../src/webhook.rs"]
+#[allow(dead_code)]
+mod webhook;
+
+use compounding::{Compounding, ContributionTiming};
+use future_value::FutureValueInputs;
+use scenario::{parse_scenarios, put_scenario, Scenario, SCHEMA_VERSION};
+use scenario_report::{diff_scenarios, scenario_results, scenario_series};
+use share::{generate_token, ShareError, ShareStore, MAX_SHARE_DAYS};
+use snapshot::{
+    format_date, parse_snapshots, snapshot_due, snapshot_history, ResultSnapshot,
+    SNAPSHOT_INTERVAL_DAYS,
+};
+use webhook::{hmac_sha256, sha256, sign, verify, WebhookPayload, SIGNATURE_TOLERANCE_SECS};
+
+fn inputs() -> FutureValueInputs {
+    FutureValueInputs {
+        principal: 1000.0,
+        target_value: 2000.0,
+        annual_rate: 0.04,
+        years: 7.0,
+        compounding: Compounding::Monthly,
+        monthly_contribution: 0.0,
+        contribution_timing: ContributionTiming::End,
+        inflation_rate: 0.0,
+    }
+}
+
+#[test]
+fn scenarios_round_trip() {
+    let saved = Scenario::new("future_value", "Base case", &inputs()).unwrap();
+    let json = serde_json::to_string(&vec![saved.clone()]).unwrap();
+
+    let (scenarios, errors) = parse_scenarios(&json);
+    assert!(errors.is_empty());
+    assert_eq!(scenarios, vec![saved]);
+    assert_eq!(
+        scenarios[0].inputs::<FutureValueInputs>().unwrap(),
+        inputs()
+    );
+}
+
+#[test]
+fn unversioned_scenarios_are_migrated() {
+    let json = r#"[{"calculator": "future_value", "name": "Old", "inputs": {
+        "principal": 1000.0, "target_value": 2000.0, "annual_rate": 0.04,
+        "years": 7.0, "compounding": "Monthly"}}]"#;
+
+    let (scenarios, errors) = parse_scenarios(json);
+    assert!(errors.is_empty());
+    assert_eq!(scenarios[0].schema_version, SCHEMA_VERSION);
+    assert_eq!(
+        scenarios[0].inputs::<FutureValueInputs>().unwrap(),
+        inputs()
+    );
+}
+
+#[test]
+fn newer_scenarios_are_reported_not_dropped_silently() {
+    let json = r#"[{"schema_version": 999, "calculator": "future_value", "name": "Future",
+        "inputs": {}}]"#;
+
+    let (scenarios, errors) = parse_scenarios(json);
+    assert!(scenarios.is_empty());
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn saving_under_a_taken_name_replaces_that_scenario() {
+    let mut scenarios = vec![
+        Scenario::new("future_value", "Base case", &inputs()).unwrap(),
+        Scenario::new("sequence_risk", "Higher rate", &inputs()).unwrap(),
+    ];
+    let higher_rate = FutureValueInputs {
+        annual_rate: 0.06,
+        ..inputs()
+    };
+
+    put_scenario(
+        &mut scenarios,
+        Scenario::new("future_value", "Higher rate", &higher_rate).unwrap(),
+    );
+    assert_eq!(scenarios.len(), 3);
+
+    put_scenario(
+        &mut scenarios,
+        Scenario::new("future_value", "Base case", &higher_rate).unwrap(),
+    );
+    assert_eq!(scenarios.len(), 3);
+    assert_eq!(
+        scenarios[0]
+            .inputs::<FutureValueInputs>()
+            .unwrap()
+            .annual_rate,
+        0.06
+    );
+    // Another calculator's scenario of the same name is left alone
+    assert_eq!(scenarios[1].calculator, "sequence_risk");
+}
+
+#[test]
+fn scenario_diff_lists_changed_inputs_and_result_deltas() {
+    let before = Scenario::new("future_value", "Base case", &inputs()).unwrap();
+    let after = Scenario::new(
+        "future_value",
+        "Higher rate",
+        &FutureValueInputs {
+            annual_rate: 0.06,
+            ..inputs()
+        },
+    )
+    .unwrap();
+
+    let diff = diff_scenarios(&before, &after).unwrap();
+    assert_eq!(diff.changed_inputs.len(), 1);
+    assert_eq!(diff.changed_inputs[0].field, "annual_rate");
+    assert_eq!(diff.changed_inputs[0].before, "0.04");
+    assert_eq!(diff.changed_inputs[0].after, "0.06");
+
+    let future_value = &diff.result_deltas[0];
+    assert_eq!(future_value.label, "Future value ($)");
+    assert!(future_value.delta() > 0.0);
+
+    let csv = diff.to_csv();
+    assert!(csv.starts_with("section,item,Base case,Higher rate,change\n"));
+    assert!(csv.contains("input,annual_rate,0.04,0.06,\n"));
+}
+
+#[test]
+fn scenario_series_chart_the_balance_year_by_year() {
+    let scenario = Scenario::new(
+        "future_value",
+        "Part year",
+        &FutureValueInputs {
+            years: 6.5,
+            ..inputs()
+        },
+    )
+    .unwrap();
+
+    let series = scenario_series(&scenario).unwrap();
+    assert_eq!(series.len(), 1);
+    let (label, balances) = &series[0];
+    assert_eq!(label, "Balance ($)");
+    // Years 0 through 6, then the half year to 6.5
+    assert_eq!(balances.len(), 8);
+    assert_eq!(balances[0], 1000.0);
+    assert!(balances.windows(2).all(|pair| pair[1] > pair[0]));
+    let future_value = scenario_results(&scenario).unwrap()[0].1;
+    assert!((balances[7] - future_value).abs() < 1e-9);
+
+    let unknown = Scenario::new("loan", "Car", &inputs()).unwrap();
+    assert!(scenario_series(&unknown).unwrap().is_empty());
+}
+
+#[test]
+fn scenarios_of_different_calculators_are_not_compared() {
+    let before = Scenario::new("future_value", "Base case", &inputs()).unwrap();
+    let after = Scenario::new("sequence_risk", "Retirement", &inputs()).unwrap();
+
+    assert!(diff_scenarios(&before, &after).is_err());
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[test]
+fn sha256_matches_the_standard_test_vectors() {
+    assert_eq!(
+        hex(&sha256(b"")),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    assert_eq!(
+        hex(&sha256(b"abc")),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    // Long enough to need several blocks
+    assert_eq!(
+        hex(&sha256("a".repeat(1000).as_bytes())),
+        "41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3"
+    );
+}
+
+#[test]
+fn hmac_sha256_matches_known_values() {
+    assert_eq!(
+        hex(&hmac_sha256(
+            b"key",
+            b"The quick brown fox jumps over the lazy dog"
+        )),
+        "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+    );
+    // RFC 4231 test case 6: a key longer than the block is hashed first
+    assert_eq!(
+        hex(&hmac_sha256(
+            &[0xaa; 131],
+            b"Test Using Larger Than Block-Size Key - Hash Key First"
+        )),
+        "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+    );
+}
+
+#[test]
+fn webhook_payload_carries_inputs_and_results() {
+    let scenario = Scenario::new("future_value", "Base case", &inputs()).unwrap();
+    let payload = WebhookPayload::new(&scenario, 1_700_000_000).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&payload.to_json()).unwrap();
+
+    assert_eq!(json["event"], "scenario.exported");
+    assert_eq!(json["sent_at"], 1_700_000_000u64);
+    assert_eq!(json["scenario"]["name"], "Base case");
+    assert_eq!(json["scenario"]["inputs"]["principal"], 1000.0);
+    assert_eq!(json["results"][0]["name"], "Future value ($)");
+    assert!(json["results"][0]["value"].as_f64().unwrap() > 1000.0);
+}
+
+#[test]
+fn signed_webhooks_verify_and_reject_tampering_and_replays() {
+    let body = r#"{"event":"scenario.exported"}"#;
+    let header = sign("shh", 1_700_000_000, body);
+
+    assert!(header.starts_with("t=1700000000,v1="));
+    assert_eq!(verify("shh", &header, body, 1_700_000_010), Ok(()));
+    assert!(verify("wrong", &header, body, 1_700_000_010).is_err());
+    assert!(verify("shh", &header, "{}", 1_700_000_010).is_err());
+    assert!(verify(
+        "shh",
+        &header,
+        body,
+        1_700_000_000 + SIGNATURE_TOLERANCE_SECS + 1
+    )
+    .is_err());
+    assert!(verify("shh", "v1=abc", body, 1_700_000_000).is_err());
+}
+
+#[test]
+fn shared_scenarios_open_until_they_expire() {
+    let scenario = Scenario::new("future_value", "For the client", &inputs()).unwrap();
+    let mut store = ShareStore::default();
+    let shared = store.share("shr_abc", scenario.clone(), 1_000, 30);
+
+    assert_eq!(shared.expires_at, 1_000 + 30 * 86_400);
+    assert_eq!(store.get("shr_abc", 1_000).unwrap().scenario, scenario);
+    assert_eq!(store.get("shr_abc", 1_000).unwrap().days_left(1_000), 30);
+    assert_eq!(store.get("shr_abc", 1_001).unwrap().days_left(1_001), 30);
+    assert_eq!(store.get("shr_xyz", 1_000), Err(ShareError::NotFound));
+    assert_eq!(
+        store.get("shr_abc", shared.expires_at),
+        Err(ShareError::Expired)
+    );
+
+    assert_eq!(store.remove_expired(shared.expires_at), 1);
+    assert!(store.is_empty());
+}
+
+#[test]
+fn share_lifetimes_are_clamped() {
+    let scenario = Scenario::new("future_value", "Clamped", &inputs()).unwrap();
+    let mut store = ShareStore::default();
+
+    assert_eq!(store.share("a", scenario.clone(), 0, 0).expires_at, 86_400);
+    assert_eq!(
+        store.share("b", scenario, 0, 10_000).expires_at,
+        MAX_SHARE_DAYS * 86_400
+    );
+}
+
+#[test]
+fn share_tokens_are_prefixed_hex() {
+    let token = generate_token([0xab; 16]);
+
+    assert_eq!(token, format!("shr_{}", "ab".repeat(16)));
+}
+
+#[test]
+fn snapshots_keep_results_as_they_stood() {
+    let scenario = Scenario::new("future_value", "Plan", &inputs()).unwrap();
+    let snapshot = ResultSnapshot::take(&scenario, 1_700_000_000).unwrap();
+
+    assert_eq!(snapshot.taken_at, 1_700_000_000);
+    assert!(snapshot.result("Future value ($)").unwrap() > 1000.0);
+    assert_eq!(snapshot.result("No such result"), None);
+
+    let json = serde_json::to_string(&vec![snapshot.clone()]).unwrap();
+    assert_eq!(parse_snapshots(&json), vec![snapshot]);
+    assert!(parse_snapshots("not json").is_empty());
+}
+
+#[test]
+fn snapshot_history_is_per_scenario_and_oldest_first() {
+    let plan = Scenario::new("future_value", "Plan", &inputs()).unwrap();
+    let other = Scenario::new("future_value", "Other", &inputs()).unwrap();
+    let snapshots = vec![
+        ResultSnapshot::take(&plan, 300).unwrap(),
+        ResultSnapshot::take(&other, 200).unwrap(),
+        ResultSnapshot::take(&plan, 100).unwrap(),
+    ];
+
+    let history = snapshot_history(&snapshots, "future_value", "Plan");
+    let times: Vec<u64> = history.iter().map(|snapshot| snapshot.taken_at).collect();
+    assert_eq!(times, vec![100, 300]);
+}
+
+#[test]
+fn periodic_snapshots_start_after_the_first() {
+    let plan = Scenario::new("future_value", "Plan", &inputs()).unwrap();
+    let interval = SNAPSHOT_INTERVAL_DAYS * 86_400;
+    let first = ResultSnapshot::take(&plan, 1_000).unwrap();
+
+    assert!(!snapshot_due(&[], 1_000 + interval));
+    assert!(!snapshot_due(&[&first], 1_000 + interval - 1));
+    assert!(snapshot_due(&[&first], 1_000 + interval));
+}
+
+#[test]
+fn snapshot_dates_are_utc_calendar_dates() {
+    assert_eq!(format_date(0), "1970-01-01");
+    assert_eq!(format_date(951_782_400), "2000-02-29");
+    assert_eq!(format_date(1_700_000_000), "2023-11-14");
+}
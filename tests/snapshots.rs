@@ -0,0 +1,1195 @@
+//! Renders each calculator to HTML with its default inputs and checks the results shown, so
+//! formatting and validation changes are caught. The app is a single binary crate, so the
+//! component modules are compiled in directly rather than imported.
+
+#[path = "../src/account_types.rs"]
+#[allow(dead_code)]
+mod account_types;
+#[path = "../src/allocation_component.rs"]
+#[allow(dead_code)]
+mod allocation_component;
+#[path = "../src/amortization.rs"]
+#[allow(dead_code)]
+mod amortization;
+#[path = "../src/amortization_component.rs"]
+#[allow(dead_code)]
+mod amortization_component;
+#[path = "../src/analytics.rs"]
+#[allow(dead_code)]
+mod analytics;
+#[path = "../src/annuity.rs"]
+#[allow(dead_code)]
+mod annuity;
+#[path = "../src/annuity_component.rs"]
+#[allow(dead_code)]
+mod annuity_component;
+#[path = "../src/apr_apy.rs"]
+#[allow(dead_code)]
+mod apr_apy;
+#[path = "../src/apr_apy_component.rs"]
+#[allow(dead_code)]
+mod apr_apy_component;
+#[path = "../src/assumptions_component.rs"]
+#[allow(dead_code)]
+mod assumptions_component;
+#[path = "../src/audit.rs"]
+#[allow(dead_code)]
+mod audit;
+#[path = "../src/audit_component.rs"]
+#[allow(dead_code)]
+mod audit_component;
+#[path = "../src/autosave.rs"]
+#[allow(dead_code)]
+mod autosave;
+#[path = "../src/blog.rs"]
+#[allow(dead_code)]
+mod blog;
+#[path = "../src/bond.rs"]
+#[allow(dead_code)]
+mod bond;
+#[path = "../src/bond_ladder_component.rs"]
+#[allow(dead_code)]
+mod bond_ladder_component;
+#[path = "../src/bond_price_component.rs"]
+#[allow(dead_code)]
+mod bond_price_component;
+#[path = "../src/bonus_component.rs"]
+#[allow(dead_code)]
+mod bonus_component;
+#[path = "../src/break_even_component.rs"]
+#[allow(dead_code)]
+mod break_even_component;
+#[path = "../src/break_even_units_component.rs"]
+#[allow(dead_code)]
+mod break_even_units_component;
+#[path = "../src/build_info.rs"]
+#[allow(dead_code)]
+mod build_info;
+#[path = "../src/business_math.rs"]
+#[allow(dead_code)]
+mod business_math;
+#[path = "../src/calculators.rs"]
+#[allow(dead_code)]
+mod calculators;
+#[path = "../src/capm_component.rs"]
+#[allow(dead_code)]
+mod capm_component;
+#[path = "../src/cd.rs"]
+#[allow(dead_code)]
+mod cd;
+#[path = "../src/cd_component.rs"]
+#[allow(dead_code)]
+mod cd_component;
+#[path = "../src/charitable.rs"]
+#[allow(dead_code)]
+mod charitable;
+#[path = "../src/charitable_component.rs"]
+#[allow(dead_code)]
+mod charitable_component;
+#[path = "../src/chart.rs"]
+#[allow(dead_code)]
+mod chart;
+#[path = "../src/closing_costs.rs"]
+#[allow(dead_code)]
+mod closing_costs;
+#[path = "../src/closing_costs_component.rs"]
+#[allow(dead_code)]
+mod closing_costs_component;
+#[path = "../src/compounding.rs"]
+#[allow(dead_code)]
+mod compounding;
+#[path = "../src/contribution_limits.rs"]
+#[allow(dead_code)]
+mod contribution_limits;
+#[path = "../src/data_year.rs"]
+#[allow(dead_code)]
+mod data_year;
+#[path = "../src/dcf_component.rs"]
+#[allow(dead_code)]
+mod dcf_component;
+#[path = "../src/debt_payoff.rs"]
+#[allow(dead_code)]
+mod debt_payoff;
+#[path = "../src/debt_payoff_component.rs"]
+#[allow(dead_code)]
+mod debt_payoff_component;
+#[path = "../src/error_summary_component.rs"]
+#[allow(dead_code)]
+mod error_summary_component;
+#[path = "../src/feedback_component.rs"]
+#[allow(dead_code)]
+mod feedback_component;
+#[path = "../src/fin_numeric.rs"]
+#[allow(dead_code)]
+mod fin_numeric;
+#[path = "../src/future_value.rs"]
+#[allow(dead_code)]
+mod future_value;
+#[path = "../src/future_value_component.rs"]
+#[allow(dead_code)]
+mod future_value_component;
+#[path = "../src/glide_path.rs"]
+#[allow(dead_code)]
+mod glide_path;
+#[path = "../src/glide_path_component.rs"]
+#[allow(dead_code)]
+mod glide_path_component;
+#[path = "../src/glossary.rs"]
+#[allow(dead_code)]
+mod glossary;
+#[path = "../src/glossary_component.rs"]
+#[allow(dead_code)]
+mod glossary_component;
+#[path = "../src/healthcare.rs"]
+#[allow(dead_code)]
+mod healthcare;
+#[path = "../src/inflation_scenarios.rs"]
+#[allow(dead_code)]
+mod inflation_scenarios;
+#[path = "../src/inflation_scenarios_component.rs"]
+#[allow(dead_code)]
+mod inflation_scenarios_component;
+#[path = "../src/lease.rs"]
+#[allow(dead_code)]
+mod lease;
+#[path = "../src/lease_component.rs"]
+#[allow(dead_code)]
+mod lease_component;
+#[path = "../src/lease_vs_buy_component.rs"]
+#[allow(dead_code)]
+mod lease_vs_buy_component;
+#[path = "../src/link.rs"]
+#[allow(dead_code)]
+mod link;
+#[path = "../src/loan.rs"]
+#[allow(dead_code)]
+mod loan;
+#[path = "../src/loan_compare_component.rs"]
+#[allow(dead_code)]
+mod loan_compare_component;
+#[path = "../src/long_term_care.rs"]
+#[allow(dead_code)]
+mod long_term_care;
+#[path = "../src/long_term_care_component.rs"]
+#[allow(dead_code)]
+mod long_term_care_component;
+#[path = "../src/markdown.rs"]
+#[allow(dead_code)]
+mod markdown;
+#[path = "../src/math_component.rs"]
+#[allow(dead_code)]
+mod math_component;
+#[path = "../src/mathml.rs"]
+#[allow(dead_code)]
+mod mathml;
+#[path = "../src/monte_carlo.rs"]
+#[allow(dead_code)]
+mod monte_carlo;
+#[path = "../src/monte_carlo_component.rs"]
+#[allow(dead_code)]
+mod monte_carlo_component;
+#[path = "../src/npv.rs"]
+#[allow(dead_code)]
+mod npv;
+#[path = "../src/npv_component.rs"]
+#[allow(dead_code)]
+mod npv_component;
+#[path = "../src/number_format.rs"]
+#[allow(dead_code)]
+mod number_format;
+#[path = "../src/numeric_input_component.rs"]
+#[allow(dead_code)]
+mod numeric_input_component;
+#[path = "../src/options.rs"]
+#[allow(dead_code)]
+mod options;
+#[path = "../src/options_component.rs"]
+#[allow(dead_code)]
+mod options_component;
+#[path = "../src/pages.rs"]
+#[allow(dead_code)]
+mod pages;
+#[path = "../src/paycheck.rs"]
+#[allow(dead_code)]
+mod paycheck;
+#[path = "../src/paycheck_component.rs"]
+#[allow(dead_code)]
+mod paycheck_component;
+#[path = "../src/perpetuity_component.rs"]
+#[allow(dead_code)]
+mod perpetuity_component;
+#[path = "../src/present_value.rs"]
+#[allow(dead_code)]
+mod present_value;
+#[path = "../src/present_value_component.rs"]
+#[allow(dead_code)]
+mod present_value_component;
+#[path = "../src/rate_shock.rs"]
+#[allow(dead_code)]
+mod rate_shock;
+#[path = "../src/rate_shock_component.rs"]
+#[allow(dead_code)]
+mod rate_shock_component;
+#[path = "../src/rates.rs"]
+#[allow(dead_code)]
+mod rates;
+#[path = "../src/retirement.rs"]
+#[allow(dead_code)]
+mod retirement;
+#[path = "../src/retirement_component.rs"]
+#[allow(dead_code)]
+mod retirement_component;
+#[path = "../src/retirement_income.rs"]
+#[allow(dead_code)]
+mod retirement_income;
+#[path = "../src/retirement_income_component.rs"]
+#[allow(dead_code)]
+mod retirement_income_component;
+#[path = "../src/returns_stats.rs"]
+#[allow(dead_code)]
+mod returns_stats;
+#[path = "../src/returns_stats_component.rs"]
+#[allow(dead_code)]
+mod returns_stats_component;
+#[path = "../src/saved_scenarios_component.rs"]
+#[allow(dead_code)]
+mod saved_scenarios_component;
+#[path = "../src/savings_bond.rs"]
+#[allow(dead_code)]
+mod savings_bond;
+#[path = "../src/savings_bond_component.rs"]
+#[allow(dead_code)]
+mod savings_bond_component;
+#[path = "../src/savings_goal_component.rs"]
+#[allow(dead_code)]
+mod savings_goal_component;
+#[path = "../src/scenario.rs"]
+#[allow(dead_code)]
+mod scenario;
+#[path = "../src/scenario_comparison_component.rs"]
+#[allow(dead_code)]
+mod scenario_comparison_component;
+#[path = "../src/scenario_report.rs"]
+#[allow(dead_code)]
+mod scenario_report;
+#[path = "../src/seed_input_component.rs"]
+#[allow(dead_code)]
+mod seed_input_component;
+#[path = "../src/self_employment.rs"]
+#[allow(dead_code)]
+mod self_employment;
+#[path = "../src/self_employment_component.rs"]
+#[allow(dead_code)]
+mod self_employment_component;
+#[path = "../src/sequence_risk.rs"]
+#[allow(dead_code)]
+mod sequence_risk;
+#[path = "../src/sequence_risk_component.rs"]
+#[allow(dead_code)]
+mod sequence_risk_component;
+#[path = "../src/settings_component.rs"]
+#[allow(dead_code)]
+mod settings_component;
+#[path = "../src/share.rs"]
+#[allow(dead_code)]
+mod share;
+#[path = "../src/share_component.rs"]
+#[allow(dead_code)]
+mod share_component;
+#[path = "../src/simulation.rs"]
+#[allow(dead_code)]
+mod simulation;
+#[path = "../src/sinking_fund.rs"]
+#[allow(dead_code)]
+mod sinking_fund;
+#[path = "../src/sinking_fund_component.rs"]
+#[allow(dead_code)]
+mod sinking_fund_component;
+#[path = "../src/snapshot.rs"]
+#[allow(dead_code)]
+mod snapshot;
+#[path = "../src/snapshot_component.rs"]
+#[allow(dead_code)]
+mod snapshot_component;
+#[path = "../src/spia.rs"]
+#[allow(dead_code)]
+mod spia;
+#[path = "../src/spia_component.rs"]
+#[allow(dead_code)]
+mod spia_component;
+#[path = "../src/storage.rs"]
+#[allow(dead_code)]
+mod storage;
+#[path = "../src/table_paste.rs"]
+#[allow(dead_code)]
+mod table_paste;
+#[path = "../src/table_paste_component.rs"]
+#[allow(dead_code)]
+mod table_paste_component;
+#[path = "../src/tax.rs"]
+#[allow(dead_code)]
+mod tax;
+#[path = "../src/theme.rs"]
+#[allow(dead_code)]
+mod theme;
+#[path = "../src/tour_component.rs"]
+#[allow(dead_code)]
+mod tour_component;
+#[path = "../src/verify.rs"]
+#[allow(dead_code)]
+mod verify;
+#[path = "../src/verify_component.rs"]
+#[allow(dead_code)]
+mod verify_component;
+#[path = "../src/wacc_component.rs"]
+#[allow(dead_code)]
+mod wacc_component;
+#[path = "../src/wizard_component.rs"]
+#[allow(dead_code)]
+mod wizard_component;
+
+use amortization_component::AmortizationUI;
+use annuity_component::AnnuityUI;
+use apr_apy_component::AprApyUI;
+use blog::{Blog, BlogIndex};
+use bond_ladder_component::BondLadderUI;
+use bond_price_component::BondPriceUI;
+use bonus_component::BonusUI;
+use break_even_component::BreakEvenInflationUI;
+use break_even_units_component::BreakEvenUnitsUI;
+use capm_component::CapmUI;
+use cd_component::CdUI;
+use charitable_component::CharitableUI;
+use closing_costs_component::ClosingCostsUI;
+use dcf_component::DcfUI;
+use debt_payoff_component::DebtPayoffUI;
+use dioxus::prelude::*;
+use future_value_component::FutureValueUI;
+use glossary_component::GlossaryUI;
+use lease_component::LeaseUI;
+use lease_vs_buy_component::LeaseVsBuyUI;
+use loan_compare_component::LoanCompareUI;
+use long_term_care_component::LongTermCareUI;
+use monte_carlo_component::MonteCarloUI;
+use npv_component::NpvUI;
+use options_component::OptionsUI;
+use paycheck_component::PaycheckUI;
+use perpetuity_component::PerpetuityUI;
+use present_value_component::PresentValueUI;
+use retirement_component::RetirementUI;
+use retirement_income_component::RetirementIncomeUI;
+use returns_stats_component::ReturnsStatsUI;
+use savings_bond_component::SavingsBondUI;
+use savings_goal_component::SavingsGoalUI;
+use self_employment_component::SelfEmploymentUI;
+use sequence_risk_component::SequenceRiskUI;
+use settings_component::SettingsUI;
+use share_component::SharedScenarioUI;
+use sinking_fund_component::SinkingFundUI;
+use spia_component::SpiaUI;
+use verify_component::VerifyUI;
+use wacc_component::WaccUI;
+
+/// The routes the rendered components link to (the app's `Route` lives in main.rs)
+#[derive(Debug, Clone, Routable, PartialEq)]
+enum Route {
+    #[route("/")]
+    FutureValueUI {},
+    #[route("/present-value")]
+    PresentValueUI {},
+    #[route("/annuity")]
+    AnnuityUI {},
+    #[route("/perpetuity")]
+    PerpetuityUI {},
+    #[route("/apr-apy")]
+    AprApyUI {},
+    #[route("/retirement")]
+    RetirementUI {},
+    #[route("/retirement-income")]
+    RetirementIncomeUI {},
+    #[route("/long-term-care")]
+    LongTermCareUI {},
+    #[route("/annuity-quotes")]
+    SpiaUI {},
+    #[route("/savings-goal")]
+    SavingsGoalUI {},
+    #[route("/sinking-fund")]
+    SinkingFundUI {},
+    #[route("/paycheck")]
+    PaycheckUI {},
+    #[route("/w2-vs-1099")]
+    SelfEmploymentUI {},
+    #[route("/bonus-withholding")]
+    BonusUI {},
+    #[route("/charitable-giving")]
+    CharitableUI {},
+    #[route("/sequence-risk")]
+    SequenceRiskUI {},
+    #[route("/monte-carlo")]
+    MonteCarloUI {},
+    #[route("/break-even-inflation")]
+    BreakEvenInflationUI {},
+    #[route("/bond-ladder")]
+    BondLadderUI {},
+    #[route("/bond-price")]
+    BondPriceUI {},
+    #[route("/savings-bond")]
+    SavingsBondUI {},
+    #[route("/cd")]
+    CdUI {},
+    #[route("/lease")]
+    LeaseUI {},
+    #[route("/lease-vs-buy")]
+    LeaseVsBuyUI {},
+    #[route("/amortization")]
+    AmortizationUI {},
+    #[route("/loan-compare")]
+    LoanCompareUI {},
+    #[route("/closing-costs")]
+    ClosingCostsUI {},
+    #[route("/debt-payoff")]
+    DebtPayoffUI {},
+    #[route("/npv")]
+    NpvUI {},
+    #[route("/dcf")]
+    DcfUI {},
+    #[route("/capm")]
+    CapmUI {},
+    #[route("/wacc")]
+    WaccUI {},
+    #[route("/returns-statistics")]
+    ReturnsStatsUI {},
+    #[route("/options")]
+    OptionsUI {},
+    #[route("/break-even-units")]
+    BreakEvenUnitsUI {},
+    #[route("/settings")]
+    SettingsUI {},
+    #[route("/glossary")]
+    GlossaryUI {},
+    #[route("/blog")]
+    BlogIndex {},
+    #[route("/blog/:slug")]
+    Blog { slug: String },
+    #[route("/shared/:token")]
+    SharedScenarioUI { token: String },
+    #[route("/verify")]
+    VerifyUI {},
+}
+
+fn render(component: fn() -> Element) -> String {
+    let mut dom = VirtualDom::new(component);
+    dom.rebuild_in_place();
+    dioxus_ssr::render(&dom)
+}
+
+#[test]
+fn future_value_renders_default_result() {
+    let html = render(future_value_component::FutureValueUI);
+
+    assert!(html.contains("id=\"FutureValueCalculation\""));
+    assert!(
+        html.contains("The future value of 1000 at 3.875% for 7 years with compounding annually")
+    );
+    assert!(html.contains("$1,304.90*"));
+    assert!(html.contains("class=\"principal-input-valid\""));
+    assert!(html.contains("class=\"years-input-valid\""));
+    assert!(html.contains("Save Scenario"));
+    assert!(html.contains("No saved scenarios yet."));
+}
+
+#[test]
+fn future_value_starts_in_future_value_mode() {
+    let html = render(future_value_component::FutureValueUI);
+
+    assert!(!html.contains("id=\"SolveRateCalculation\""));
+    assert!(!html.contains("id=\"SolveYearsCalculation\""));
+    assert!(!html.contains("Target Future Value"));
+}
+
+#[test]
+fn sequence_risk_renders_both_retirees() {
+    let html = render(sequence_risk_component::SequenceRiskUI);
+
+    assert!(html.contains("average return 6.00%, compound annual return 5.62%"));
+    assert!(html.contains("Retiree A (worst years first) runs out of money after 15 years"));
+    assert!(html.contains("Retiree B (best years first) ends with $3,041,799"));
+    assert!(html.contains("Save Scenario"));
+}
+
+#[test]
+fn monte_carlo_reports_the_success_rate_with_a_fan_chart() {
+    let html = render(monte_carlo_component::MonteCarloUI);
+
+    assert!(html.contains("id=\"MonteCarloCalculation\""));
+    assert!(html.contains("Success rate: "));
+    assert!(html.contains("% of 2,000 simulations*"));
+    assert!(html.contains("After 30 years the median balance is $"));
+    assert!(html.contains("Balance in today's dollars"));
+    // The fan chart draws two percentile bands around the median
+    assert_eq!(html.matches("<polygon").count(), 2);
+    assert!(html.contains("Random Seed:"));
+    assert!(html.contains("Asset allocation"));
+}
+
+#[test]
+fn calculators_list_their_assumptions() {
+    let html = render(future_value_component::FutureValueUI);
+
+    assert!(html.contains("disclaimers"));
+    assert!(html.contains("Daily compounding uses 365 periods a year"));
+}
+
+#[test]
+fn future_value_shows_its_precision() {
+    let html = render(future_value_component::FutureValueUI);
+
+    assert!(html.contains("Computed in 64-bit floating point"));
+}
+
+#[test]
+fn format_dollars_puts_the_sign_before_the_dollars() {
+    assert_eq!(future_value_component::format_dollars(1234.56), "1,234.56");
+    assert_eq!(
+        future_value_component::format_dollars(-1234.56),
+        "-1,234.56"
+    );
+    assert_eq!(future_value_component::format_dollars(-12.34), "-12.34");
+    assert_eq!(future_value_component::format_dollars(-0.5), "-0.50");
+    assert_eq!(future_value_component::format_dollars(-0.001), "0.00");
+}
+
+#[test]
+fn break_even_inflation_renders_default_result() {
+    let html = render(break_even_component::BreakEvenInflationUI);
+
+    assert!(html.contains("Break-even inflation: 2.45%*"));
+    assert!(html.contains("TIPS come out ahead"));
+}
+
+#[test]
+fn bond_ladder_renders_default_ladder() {
+    let html = render(bond_ladder_component::BondLadderUI);
+
+    assert!(html.contains("Portfolio yield: 4."));
+    assert!(html.contains("Cash received by year"));
+    assert!(html.contains("Cost $50,000"));
+}
+
+#[test]
+fn savings_bond_applies_the_doubling_guarantee() {
+    let html = render(savings_bond_component::SavingsBondUI);
+
+    assert!(html.contains("Cashed after 20 years, 0 months, the bond pays $2,000.00*"));
+    assert!(html.contains("The 20-year guarantee doubled the bond"));
+}
+
+#[test]
+fn cd_compares_breaking_with_holding() {
+    let html = render(cd_component::CdUI);
+
+    assert!(html.contains("Breaking the CD and reinvesting comes out ahead"));
+    assert!(html.contains("$150.00 penalty and pays out $10,150.00"));
+}
+
+#[test]
+fn lease_converts_the_money_factor_to_apr() {
+    let html = render(lease_component::LeaseUI);
+
+    assert!(html.contains("Monthly payment: $528.89*"));
+    assert!(html.contains("borrowing at 6.00% APR"));
+}
+
+#[test]
+fn lease_vs_buy_compares_the_costs_in_todays_dollars() {
+    let html = render(lease_vs_buy_component::LeaseVsBuyUI);
+
+    assert!(html.contains("id=\"LeaseVsBuyCalculation\""));
+    assert!(html.contains("Buying costs $33.42 less than leasing in today's dollars*"));
+    assert!(html.contains("$438.05"));
+    assert!(html.contains("$980.76"));
+    assert!(html.contains("$17,676.93"));
+    assert!(html.contains("$17,643.51"));
+    // The lease's payment and total paid are lower; the purchase's net and present costs are
+    assert_eq!(html.matches("background: #d5f5e3").count(), 4);
+}
+
+#[test]
+fn loan_compare_highlights_the_best_offer_in_each_row() {
+    let html = render(loan_compare_component::LoanCompareUI);
+
+    assert!(html.contains("id=\"LoanCompareCalculation\""));
+    assert!(html.contains("$1,822.83"));
+    assert!(html.contains("6.005%"));
+    assert_eq!(html.matches("background: #d5f5e3").count(), 4);
+}
+
+#[test]
+fn closing_costs_itemizes_the_default_loan() {
+    let html = render(closing_costs_component::ClosingCostsUI);
+
+    assert!(html.contains("id=\"ClosingCostsCalculation\""));
+    assert!(html.contains("Due at closing: $7,851.37*"));
+    // Lender, title, and government fees, apart from the prepaids
+    assert!(html.contains("6,000.00"));
+    assert!(html.contains("1,851.37"));
+}
+
+#[test]
+fn debt_payoff_compares_snowball_and_avalanche() {
+    let html = render(debt_payoff_component::DebtPayoffUI);
+
+    assert!(html.contains("id=\"DebtPayoffCalculation\""));
+    assert!(html.contains("The avalanche saves $1,069.51 in interest over the snowball*"));
+    assert!(html.contains("2 years 3 months"));
+    assert!(html.contains("2 years 1 month"));
+    // The avalanche wins on time, interest, and total paid
+    assert_eq!(html.matches("background: #d5f5e3").count(), 3);
+    assert!(html.contains("Paste from a spreadsheet"));
+    assert!(html.contains("Balance ($), Rate (%), and Minimum ($)"));
+}
+
+#[test]
+fn perpetuity_values_the_default_preferred_share() {
+    let html = render(perpetuity_component::PerpetuityUI);
+
+    assert!(html.contains("id=\"PerpetuityCalculation\""));
+    assert!(html.contains("Value today: $83.33*"));
+}
+
+#[test]
+fn present_value_renders_default_result() {
+    let html = render(present_value_component::PresentValueUI);
+
+    assert!(html.contains("id=\"PresentValueCalculation\""));
+    assert!(html.contains("The amount to invest today to have $2,000.00 in 7 years"));
+    assert!(html.contains("$1,532.68*"));
+    assert!(html.contains("class=\"target-input-valid\""));
+}
+
+#[test]
+fn charts_offer_a_table_view() {
+    let html = render(sequence_risk_component::SequenceRiskUI);
+
+    assert!(html.contains("View as table"));
+    assert!(html.contains("aria-pressed=\"false\""));
+    assert!(html.contains("role=\"img\""));
+}
+
+#[test]
+fn inflation_band_chart_shades_between_low_and_high() {
+    use chart::InflationBandChart;
+    use inflation_scenarios::ScenarioResults;
+
+    fn app() -> Element {
+        let hover_index = use_signal(|| None);
+        rsx! {
+            InflationBandChart {
+                title: "Balance in today's dollars".to_string(),
+                results: ScenarioResults {
+                    low: vec![100.0, 150.0, 200.0],
+                    base: vec![100.0, 120.0, 140.0],
+                    high: vec![100.0, 90.0, 80.0],
+                },
+                rates: ScenarioResults {
+                    low: 0.015,
+                    base: 0.025,
+                    high: 0.035,
+                },
+                x_start: 36.0,
+                color: "#229954".to_string(),
+                hover_index,
+            }
+        }
+    }
+    let html = render(app);
+
+    assert_eq!(html.matches("<polygon").count(), 1);
+    assert_eq!(html.matches("<polyline").count(), 3);
+    assert!(html.contains("Low inflation (1.50%)"));
+    assert!(html.contains("Base inflation (2.50%)"));
+    assert!(html.contains("High inflation (3.50%)"));
+}
+
+#[test]
+fn amortization_renders_the_schedule_with_totals() {
+    let html = render(amortization_component::AmortizationUI);
+
+    assert!(html.contains("Payment: $1,498.88 monthly*"));
+    assert!(html.contains("360 payments totaling $539,593.37, of which $289,593.37 is interest."));
+    assert!(html.contains("Total"));
+    // Payments are grouped under a subtotal row for each year
+    assert!(html.contains("▾ Year 1"));
+    assert!(html.contains("14,916.50"));
+    assert!(html.contains("▾ Year 30"));
+    // The payment if the rate reset a point higher
+    assert!(html.contains("Rate Shock"));
+    assert!(html.contains("+100 bp"));
+    assert!(html.contains("1663.26"));
+}
+
+#[test]
+fn apr_apy_converts_the_default_rate() {
+    let html = render(apr_apy_component::AprApyUI);
+
+    assert!(html.contains("id=\"AprApyCalculation\""));
+    assert!(html.contains("The APY for a 5.000% APR with compounding monthly"));
+    assert!(html.contains("APY: 5.116%*"));
+    // The comparison table lists every compounding frequency
+    assert!(html.contains("5.000%"));
+    assert!(html.contains("5.127%"));
+}
+
+#[test]
+fn retirement_projects_the_default_savings_year_by_year() {
+    let html = render(retirement_component::RetirementUI);
+
+    assert!(html.contains("id=\"RetirementCalculation\""));
+    assert!(html.contains("Projected balance at age 65: $774,431."));
+    assert!(html.contains("in today's dollars"));
+    // One row per year from 35 to 65
+    assert!(html.contains(">30</th>"));
+    assert!(html.contains("65.0"));
+    assert!(html.contains("Annual Increase (%):"));
+    assert!(html.contains("Guided Setup"));
+    assert!(!html.contains("your contribution reaches"));
+    // $500 a month is well within the 401(k) limit
+    assert!(html.contains("401(k) / 403(b)"));
+    assert!(!html.contains("so they're capped there"));
+    assert!(html.contains("Employer Match (%):"));
+    assert!(!html.contains("Your employer's match adds"));
+    // The return holds constant until a glide path is turned on
+    assert!(html.contains("Glide Path:"));
+    assert!(!html.contains("Expected return by age"));
+    // Inflation scenarios are off until checked
+    assert!(html.contains("Inflation Scenarios:"));
+    assert!(!html.contains("id=\"RetirementInflationScenarios\""));
+}
+
+#[test]
+fn retirement_income_charts_income_against_expenses() {
+    let html = render(retirement_income_component::RetirementIncomeUI);
+
+    assert!(html.contains("id=\"RetirementIncomeCalculation\""));
+    assert!(html.contains(
+        "First year: $5,526.62 a month of income, $5,168.29 after federal tax, against $5,002.90"
+    ));
+    assert!(html.contains("After-tax income falls short of expenses from age 71."));
+    assert!(html.contains("Healthcare is $602.90 of the first year's monthly expenses"));
+    assert!(html.contains("$202.90 of Medicare premiums and $400.00 out of pocket."));
+    assert!(html.contains("Monthly income by source"));
+    assert!(html.contains("Monthly income vs expenses"));
+    assert!(html.contains("After federal tax"));
+}
+
+#[test]
+fn long_term_care_weighs_benefits_against_premiums() {
+    let html = render(long_term_care_component::LongTermCareUI);
+
+    assert!(html.contains("id=\"LongTermCareCalculation\""));
+    assert!(html.contains("Expected cost of care, in present value: $102,077.07*"));
+    assert!(html.contains("Chance of ever needing care: 41.5%."));
+    assert!(html.contains("leaving $28,581.58 of care to pay yourself."));
+    assert!(html.contains("outweigh the premiums by $32,258.15."));
+    assert!(html.contains("Mortality and care rates by age"));
+}
+
+#[test]
+fn spia_compares_quote_returns_by_age() {
+    let html = render(spia_component::SpiaUI);
+
+    assert!(html.contains("id=\"SpiaCalculation\""));
+    assert!(html.contains("Best return if you live to 90: Insurer A, 5.74% a year*"));
+    assert!(html.contains("IRR to 100"));
+    assert!(html.contains("7.44%"));
+    assert!(html.contains("78.5"));
+    assert!(html.contains("-0.05%"));
+}
+
+#[test]
+fn savings_goal_solves_for_the_deposit() {
+    let html = render(savings_goal_component::SavingsGoalUI);
+
+    assert!(html.contains("id=\"SavingsGoalCalculation\""));
+    assert!(html.contains("Deposit needed: $340.2"));
+    assert!(html.contains("60 deposits totaling"));
+    // Deposits stay level until an annual increase is entered
+    assert!(html.contains("Annual Increase (%):"));
+    assert!(!html.contains("to start*"));
+}
+
+#[test]
+fn sinking_fund_schedules_every_deposit() {
+    let html = render(sinking_fund_component::SinkingFundUI);
+
+    assert!(html.contains("id=\"SinkingFundCalculation\""));
+    assert!(html.contains("Deposit needed: $284.81 monthly"));
+    assert!(html.contains("96 deposits totaling"));
+    assert!(html.contains("Fund Balance"));
+    assert!(html.contains("5,303.56"));
+    assert!(html.contains("40,000.00"));
+}
+
+#[test]
+fn paycheck_shows_combined_take_home_and_the_second_income() {
+    let html = render(paycheck_component::PaycheckUI);
+
+    assert!(html.contains("id=\"PaycheckCalculation\""));
+    assert!(html.contains("Combined take-home: $4,362.59 biweekly"));
+    assert!(html.contains("113,427.50"));
+    assert!(html.contains("Marginal tax bracket: 22%"));
+    assert!(html.contains("id=\"SecondIncomeCalculation\""));
+    assert!(html.contains("adds $11,830.00 in federal taxes, 19.7% of it"));
+}
+
+#[test]
+fn self_employment_finds_the_break_even_contract_rate() {
+    let html = render(self_employment_component::SelfEmploymentUI);
+
+    assert!(html.contains("id=\"SelfEmploymentCalculation\""));
+    assert!(html.contains("The W-2 offer is worth $1,297.89 more a year*"));
+    assert!(html.contains("$97,380.00"));
+    assert!(html.contains("$96,082.11"));
+    assert!(html.contains("A contract rate of $76.10/hour matches the W-2 offer."));
+    assert!(html.contains("The contract matches a W-2 salary of $98,254.35."));
+}
+
+#[test]
+fn bonus_compares_withholding_with_the_tax_owed() {
+    let html = render(bonus_component::BonusUI);
+
+    assert!(html.contains("id=\"BonusCalculation\""));
+    assert!(html.contains("The bonus adds $1,200.00 to your federal income tax, 12.0% of it*"));
+    assert!(html.contains("$2,200.00"));
+    assert!(html.contains("$1,000.00 more is withheld than the bonus adds to your tax"));
+    assert!(html.contains("lands in your 12% bracket"));
+    assert!(html.contains("withhold about 6.9%"));
+}
+
+#[test]
+fn bond_price_shows_the_clean_price_and_cash_flows() {
+    let html = render(bond_price_component::BondPriceUI);
+
+    assert!(html.contains("id=\"BondPriceCalculation\""));
+    assert!(html.contains("Clean price: $1,080.02"));
+    assert!(html.contains("(108.002 per 100 of face)"));
+    assert!(html.contains("Accrued interest: $12.50"));
+    // Twenty coupons remain, the first in three months
+    assert!(html.contains(">20</th>"));
+    assert!(html.contains("0.25"));
+    // A point more yield takes $80.10 off the dirty price of $1,092.52
+    assert!(html.contains("Dirty price (exact)"));
+    assert!(html.contains("1012.42"));
+    assert!(html.contains("-80.10"));
+}
+
+#[test]
+fn verify_page_passes_every_check() {
+    let html = render(verify_component::VerifyUI);
+
+    assert!(html.contains("id=\"VerifySummary\""));
+    assert!(html.contains("All 10 checks passed"));
+    assert!(!html.contains("Fail"));
+}
+
+#[test]
+fn annuity_solves_for_the_blank_field() {
+    let html = render(annuity_component::AnnuityUI);
+
+    assert!(html.contains("id=\"AnnuityCalculation\""));
+    assert!(html.contains("Ending balance: $94,111.23*"));
+}
+
+#[test]
+fn npv_renders_the_default_cash_flows() {
+    let html = render(npv_component::NpvUI);
+
+    assert!(html.contains("id=\"NpvCalculation\""));
+    assert!(html.contains("Net present value: $176.29*"));
+    assert!(html.contains("-$10,000.00"));
+    assert!(html.contains("$2,777.77"));
+    assert!(html.contains("Add Cash Flow"));
+    assert!(html.contains("Period and Amount ($)"));
+    assert!(html.contains("IRR: 8.896% per period"));
+}
+
+#[test]
+fn charitable_giving_compares_bunching_with_yearly_gifts() {
+    let html = render(charitable_component::CharitableUI);
+
+    assert!(html.contains("id=\"CharitableCalculation\""));
+    assert!(html.contains("Giving every year saves $0.00 a year in federal income tax*"));
+    assert!(html.contains("Bunching 3 years of gifts into one saves $3,956.00 more"));
+    assert!(html.contains("$32,200.00 standard"));
+    assert!(html.contains("$51,000.00 itemized"));
+    assert!(html.contains("$1,350.00"));
+}
+
+#[test]
+fn dcf_breaks_enterprise_value_down_by_year() {
+    let html = render(dcf_component::DcfUI);
+
+    assert!(html.contains("id=\"DcfCalculation\""));
+    assert!(html.contains("Enterprise value: $1,894,924.12*"));
+    assert!(html.contains("$91,743.11"));
+    assert!(html.contains("$2,207,692.30"));
+    assert!(html.contains("$1,434,848.52"));
+    assert!(html.contains("0.6499"));
+    assert!(html.contains("The terminal value makes up 75.7% of it."));
+    assert!(html.contains("Add Year"));
+    assert!(html.contains("Free Cash Flow ($), one year to a row"));
+    // The guided setup starts closed, with the full form showing
+    assert!(html.contains("Guided Setup"));
+    assert!(!html.contains("Step 1 of 3"));
+}
+
+#[test]
+fn capm_shows_the_formula_with_the_inputs() {
+    let html = render(capm_component::CapmUI);
+
+    assert!(html.contains("id=\"CapmCalculation\""));
+    assert!(html.contains("Expected return: 10.60%*"));
+    assert!(html.contains("= 4.00% + 1.20 × 5.50% = 10.60%"));
+}
+
+#[test]
+fn wacc_weights_equity_and_after_tax_debt() {
+    let html = render(wacc_component::WaccUI);
+
+    assert!(html.contains("id=\"WaccCalculation\""));
+    assert!(html.contains("WACC: 7.90%*"));
+    assert!(html.contains("60.0%"));
+    assert!(html.contains("4.74%"));
+    assert!(html.contains("1.90%"));
+}
+
+#[test]
+fn returns_stats_reads_a_pasted_csv() {
+    let html = render(returns_stats_component::ReturnsStatsUI);
+
+    assert!(html.contains("id=\"ReturnsStatsCalculation\""));
+    assert!(html.contains("Sharpe ratio: 0.43, Sortino ratio: 0.67 (annualized)*"));
+    assert!(html.contains("From 12 returns"));
+    assert!(html.contains("5.24%"));
+}
+
+#[test]
+fn options_prices_the_call_and_put() {
+    let html = render(options_component::OptionsUI);
+
+    assert!(html.contains("id=\"OptionsCalculation\""));
+    assert!(html.contains("Call: $10.45, Put: $5.57*"));
+    assert!(html.contains("0.3500"));
+    assert!(html.contains("0.1500"));
+    // An American put is worth more than the European price from Black-Scholes
+    assert!(html.contains("id=\"OptionsBinomial\""));
+    assert!(html.contains("American call: $10.43, put: $6.08**"));
+    assert!(html.contains("up by 1.0202x or down by 0.9802x"));
+    assert!(!html.contains("id=\"OptionsLattice\""));
+}
+
+#[test]
+fn break_even_units_charts_cost_against_revenue() {
+    let html = render(break_even_units_component::BreakEvenUnitsUI);
+
+    assert!(html.contains("id=\"BreakEvenUnitsCalculation\""));
+    assert!(html.contains("Break-even: 2,000 units, or $80,000.00 of revenue*"));
+    assert!(html.contains("Each unit contributes $25.00 toward fixed costs, 62.5% of its price."));
+    // The units axis runs to twice the break-even volume in steps of 200
+    assert!(html.contains(">4000</text>"));
+    assert_eq!(html.matches("<polyline").count(), 2);
+}
+
+#[test]
+fn error_summary_links_each_problem_to_its_field() {
+    use error_summary_component::{ErrorSummary, FieldError};
+
+    fn app() -> Element {
+        rsx! {
+            ErrorSummary {
+                errors: vec![
+                    FieldError {
+                        field_id: "ladder-0-1".to_string(),
+                        message: "Row 1, Coupon (%): enter a number of zero or more".to_string(),
+                    },
+                    FieldError {
+                        field_id: "rate-input".to_string(),
+                        message: "Discount rate must be greater than zero".to_string(),
+                    },
+                ],
+            }
+        }
+    }
+    fn empty() -> Element {
+        rsx! {
+            ErrorSummary { errors: vec![] }
+        }
+    }
+    let html = render(app);
+
+    assert!(html.contains("role=\"alert\""));
+    assert!(html.contains("There are 2 problems"));
+    assert!(html.contains("href=\"#ladder-0-1\""));
+    assert!(html.contains("href=\"#rate-input\""));
+    assert!(!render(empty).contains("error-summary"));
+}
+
+#[test]
+fn numeric_input_labels_its_field_for_error_links() {
+    use numeric_input_component::numeric_field_error;
+
+    let html = render(present_value_component::PresentValueUI);
+    assert!(html.contains("id=\"years-input\""));
+    assert!(html.contains("for=\"years-input\""));
+
+    let error = numeric_field_error("-5", false, "Years", "years").unwrap();
+    assert_eq!(error.field_id, "years-input");
+    assert_eq!(error.message, "Years must be greater than zero");
+    assert_eq!(numeric_field_error("", false, "Years", "years"), None);
+    assert_eq!(numeric_field_error("5", true, "Years", "years"), None);
+}
+
+#[test]
+fn pages_list_every_indexable_route() {
+    let routes: Vec<String> = pages::page_routes()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    assert!(routes.contains(&"/".to_string()));
+    assert!(routes.contains(&"/npv".to_string()));
+    assert!(routes.contains(&"/blog/how-compound-interest-works".to_string()));
+    assert!(routes.contains(&"/glossary".to_string()));
+    assert!(!routes.contains(&"/settings".to_string()));
+}
+
+#[test]
+fn pages_title_and_describe_each_route() {
+    let post = Route::Blog {
+        slug: "how-compound-interest-works".to_string(),
+    };
+
+    assert_eq!(pages::page_title(&Route::NpvUI {}), "NPV & IRR");
+    assert_eq!(pages::page_title(&post), "How compound interest works");
+    assert_eq!(pages::page_title(&Route::SettingsUI {}), "Settings");
+    assert!(pages::page_description(&Route::PresentValueUI {}).starts_with("Find how much"));
+    // Glossary links are reduced to their text
+    assert!(pages::page_description(&post).starts_with("The future value calculator answers"));
+}
+
+#[test]
+fn sitemap_lists_each_page_url() {
+    let sitemap = pages::sitemap_xml();
+
+    assert!(sitemap.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+    assert!(sitemap.contains(&format!("<loc>{}/npv</loc>", pages::SITE_URL)));
+    assert!(sitemap.contains("/blog/sequence-of-returns-risk</loc>"));
+    assert!(!sitemap.contains("/settings"));
+    assert_eq!(sitemap.matches("<url>").count(), pages::page_routes().len());
+}
+
+#[test]
+fn structured_data_describes_calculators_and_articles() {
+    let calculator = pages::structured_data(&Route::AnnuityUI {}, "Fintools");
+    assert_eq!(calculator["@type"], "WebApplication");
+    assert_eq!(calculator["name"], "Annuity");
+    assert_eq!(calculator["applicationCategory"], "FinanceApplication");
+    assert_eq!(calculator["url"], format!("{}/annuity", pages::SITE_URL));
+
+    let post = Route::Blog {
+        slug: "sequence-of-returns-risk".to_string(),
+    };
+    let article = pages::structured_data(&post, "Fintools");
+    assert_eq!(article["@type"], "Article");
+    assert_eq!(
+        article["headline"],
+        "Why the order of returns matters in retirement"
+    );
+    assert_eq!(article["publisher"]["name"], "Fintools");
+
+    assert_eq!(
+        pages::structured_data(&Route::GlossaryUI {}, "Fintools")["@type"],
+        "WebPage"
+    );
+}
+
+#[test]
+fn summaries_fit_a_meta_description() {
+    let long = "word ".repeat(100);
+    let summary = pages::summarize(&long);
+
+    assert!(summary.chars().count() <= 156);
+    assert!(summary.ends_with("word…"));
+    assert_eq!(
+        pages::summarize("How [[compounding|compounded]] interest adds up.\n\nMore."),
+        "How compounded interest adds up."
+    );
+    assert_eq!(pages::summarize("An [[unclosed link"), "An [[unclosed link");
+}
+
+#[test]
+fn numeric_input_width_follows_the_text_in_ch() {
+    use numeric_input_component::input_width_style;
+
+    assert!(input_width_style("").contains("width: 1ch;"));
+    assert!(input_width_style("1,234.56").contains("width: 9ch;"));
+    // Non-ASCII separators count once, not per byte
+    assert!(input_width_style("1\u{a0}234").contains("width: 6ch;"));
+    assert!(input_width_style("1").contains("min-width: 100px; max-width: 320px;"));
+}
+
+#[test]
+fn numeric_input_steps_from_the_typed_value() {
+    use numeric_input_component::step_input;
+
+    assert_eq!(
+        step_input("1,000", 5.0, 100.0, 1.0, false),
+        Some("1100".to_string())
+    );
+    assert_eq!(
+        step_input("3.875", 0.0, 0.125, -1.0, false),
+        Some("3.750".to_string())
+    );
+    assert_eq!(
+        step_input("", 6.0, 0.125, 10.0, false),
+        Some("7.250".to_string())
+    );
+    assert_eq!(step_input("0.1", 0.1, 0.125, -1.0, false), None);
+    assert_eq!(
+        step_input("0.1", 0.1, 0.125, -1.0, true),
+        Some("-0.025".to_string())
+    );
+}
+
+#[test]
+fn numeric_input_masks_while_typing() {
+    use numeric_input_component::mask_numeric_input;
+
+    assert_eq!(
+        mask_numeric_input("1,234.5678", None, Some(2), false),
+        "1,234.56"
+    );
+    assert_eq!(mask_numeric_input("-12a.3.4", None, None, false), "12.34");
+    assert_eq!(mask_numeric_input("-12a.3.4", None, None, true), "-12.34");
+    assert_eq!(
+        mask_numeric_input(&"9".repeat(60), Some(15), None, false),
+        "9".repeat(15)
+    );
+    assert_eq!(mask_numeric_input("5.", Some(15), Some(0), false), "5");
+}
+
+#[test]
+fn build_info_reports_the_version_and_features() {
+    let info = build_info::build_info();
+
+    assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+    assert!(!info.git_sha.is_empty());
+    assert_eq!(info.features.contains(&"bonds"), cfg!(feature = "bonds"));
+    assert_eq!(info.features.contains(&"server"), cfg!(feature = "server"));
+    assert!(build_info::build_label().starts_with(&format!("{} (", info.version)));
+
+    let json = serde_json::to_value(&info).unwrap();
+    for key in ["version", "git_sha", "build_time", "features"] {
+        assert!(json.get(key).is_some(), "missing {}", key);
+    }
+}
@@ -0,0 +1,88 @@
+//! Spreadsheet paste parsing tests. The app is a single binary crate, so the module is compiled
+//! in directly rather than imported.
+
+#[path = "../src/table_paste.rs"]
+#[allow(dead_code)]
+mod table_paste;
+
+use table_paste::{parse_pasted_columns, parse_pasted_table, split_pasted_line, PasteReport};
+
+const COLUMNS: &[&str] = &["Face ($)", "Coupon (%)"];
+
+fn non_negative(text: &str) -> Option<f64> {
+    text.parse::<f64>().ok().filter(|value| *value >= 0.0)
+}
+
+#[test]
+fn tab_delimited_ranges_become_rows() {
+    let pasted = parse_pasted_table(
+        "Face\tCoupon\n$10,000\t4.5%\n\n20000\t5\t\n",
+        COLUMNS,
+        non_negative,
+    );
+
+    assert_eq!(pasted.rows, vec![vec![10_000.0, 4.5], vec![20_000.0, 5.0]]);
+    assert!(pasted.errors.is_empty());
+}
+
+#[test]
+fn csv_quotes_protect_commas() {
+    assert_eq!(
+        split_pasted_line("\"10,000\", 4.5,\"say \"\"hi\"\"\""),
+        vec!["10,000", "4.5", "say \"hi\""]
+    );
+
+    let pasted = parse_pasted_table("\"10,000\",4.5", COLUMNS, non_negative);
+    assert_eq!(pasted.rows, vec![vec![10_000.0, 4.5]]);
+}
+
+#[test]
+fn bad_lines_are_reported_and_skipped() {
+    let pasted = parse_pasted_table(
+        "10000\t4.5\n10000\n10000\tabc\n10000\t-1\n5000\t3",
+        COLUMNS,
+        non_negative,
+    );
+
+    assert_eq!(pasted.rows, vec![vec![10_000.0, 4.5], vec![5000.0, 3.0]]);
+    let lines: Vec<usize> = pasted.errors.iter().map(|error| error.line).collect();
+    assert_eq!(lines, vec![2, 3, 4]);
+    assert_eq!(pasted.errors[0].message, "expected 2 values, found 1");
+    assert_eq!(pasted.errors[1].message, "Coupon (%): \"abc\" is not valid");
+}
+
+#[test]
+fn columns_can_hold_different_kinds_of_values() {
+    let pasted = parse_pasted_columns(
+        "Date\tAmount\n2025-01-01\t-10000\nsoon\t500\n2026-01-01\t$4,000",
+        &["Date", "Amount ($)"],
+        |column, cell| match column {
+            0 => (cell.len() == 10).then(|| cell.to_string()),
+            _ => cell.parse::<f64>().ok().map(|amount| amount.to_string()),
+        },
+    );
+
+    assert_eq!(
+        pasted.rows,
+        vec![vec!["2025-01-01", "-10000"], vec!["2026-01-01", "4000"]]
+    );
+    assert_eq!(pasted.lines, vec![2, 4]);
+    assert_eq!(pasted.errors[0].message, "Date: \"soon\" is not valid");
+}
+
+#[test]
+fn rows_past_the_room_left_are_reported() {
+    let mut pasted = parse_pasted_table("1\t1\nx\t2\n3\t3\n4\t4", COLUMNS, non_negative);
+    pasted.keep_first(1, "the table is full");
+
+    assert_eq!(pasted.rows, vec![vec![1.0, 1.0]]);
+    let report = pasted.report();
+    assert_eq!(report.added, 1);
+    let lines: Vec<usize> = report.errors.iter().map(|error| error.line).collect();
+    assert_eq!(lines, vec![2, 3, 4]);
+    assert_eq!(report.errors[2].message, "the table is full");
+
+    pasted.keep_first(5, "unused");
+    assert_eq!(pasted.report(), report);
+    assert_eq!(PasteReport::default().added, 0);
+}